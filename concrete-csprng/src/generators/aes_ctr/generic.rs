@@ -2,7 +2,8 @@ use crate::generators::aes_ctr::block_cipher::{AesBlockCipher, AesKey};
 use crate::generators::aes_ctr::index::TableIndex;
 use crate::generators::aes_ctr::states::{BufferPointer, ShiftAction, State};
 use crate::generators::aes_ctr::BYTES_PER_BATCH;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
+use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState};
+use crate::seeders::Seed;
 
 // Usually, to work with iterators and parallel iterators, we would use opaque types such as
 // `impl Iterator<..>`. Unfortunately, it is not yet possible to return existential types in
@@ -10,14 +11,15 @@ use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
 // full type name where needed. Hence the following trait aliases definition:
 
 /// A type alias for the children iterator closure type.
-pub type ChildrenClosure<BlockCipher> =
-    fn((usize, (Box<BlockCipher>, TableIndex, BytesPerChild))) -> AesCtrGenerator<BlockCipher>;
+pub type ChildrenClosure<BlockCipher> = fn(
+    (usize, (Box<BlockCipher>, AesKey, TableIndex, BytesPerChild)),
+) -> AesCtrGenerator<BlockCipher>;
 
 /// A type alias for the children iterator type.
 pub type ChildrenIterator<BlockCipher> = std::iter::Map<
     std::iter::Zip<
         std::ops::Range<usize>,
-        std::iter::Repeat<(Box<BlockCipher>, TableIndex, BytesPerChild)>,
+        std::iter::Repeat<(Box<BlockCipher>, AesKey, TableIndex, BytesPerChild)>,
     >,
     ChildrenClosure<BlockCipher>,
 >;
@@ -27,6 +29,8 @@ pub type ChildrenIterator<BlockCipher> = std::iter::Map<
 pub struct AesCtrGenerator<BlockCipher: AesBlockCipher> {
     // The block cipher used in the background
     pub(crate) block_cipher: Box<BlockCipher>,
+    // The key of the block cipher, kept to be able to snapshot the generator.
+    pub(crate) key: AesKey,
     // The state corresponding to the latest outputted byte.
     pub(crate) state: State,
     // The last legal index. This makes bound check faster.
@@ -59,23 +63,32 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
     ) -> AesCtrGenerator<BlockCipher> {
         AesCtrGenerator::from_block_cipher(
             Box::new(BlockCipher::new(key)),
+            key,
             start_index.unwrap_or(TableIndex::SECOND),
             bound_index.unwrap_or(TableIndex::LAST),
         )
     }
 
-    /// Generates a csprng from an existing block cipher.
+    /// Generates a csprng from an existing block cipher, built from `key`.
+    ///
+    /// Note :
+    /// ------
+    ///
+    /// A `start_index` equal to the `bound_index` yields an exhausted generator, which is needed
+    /// to restore the snapshot of a generator which outputted all its bytes.
     pub fn from_block_cipher(
         block_cipher: Box<BlockCipher>,
+        key: AesKey,
         start_index: TableIndex,
         bound_index: TableIndex,
     ) -> AesCtrGenerator<BlockCipher> {
-        assert!(start_index < bound_index);
+        assert!(start_index <= bound_index);
         let last = bound_index.decremented();
         let buffer = [0u8; BYTES_PER_BATCH];
         let state = State::new(start_index);
         AesCtrGenerator {
             block_cipher,
+            key,
             state,
             last,
             buffer,
@@ -111,6 +124,40 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         TableIndex::distance(&self.last, &self.state.table_index()).unwrap()
     }
 
+    /// Returns a snapshot of the generator state.
+    pub fn state(&self) -> GeneratorState {
+        // The stored table index points to the previous byte, the snapshot points to the next one
+        let position = self.state.table_index().incremented();
+        GeneratorState {
+            seed: Seed(self.key.0),
+            position: position
+                .to_stream_offset()
+                .expect("The generator position is before the start of the stream."),
+            bound: self
+                .is_bounded()
+                .then(|| self.get_bound().to_stream_offset().unwrap()),
+        }
+    }
+
+    /// Generates a csprng from a snapshot of its state.
+    pub fn from_state(state: GeneratorState) -> AesCtrGenerator<BlockCipher> {
+        let start_index = TableIndex::from_stream_offset(state.position);
+        let bound_index = state
+            .bound
+            .map_or(TableIndex::LAST, TableIndex::from_stream_offset);
+        assert!(
+            start_index <= bound_index,
+            "The generator state position is greater than its bound."
+        );
+        let key = AesKey(state.seed.0);
+        AesCtrGenerator::from_block_cipher(
+            Box::new(BlockCipher::new(key)),
+            key,
+            start_index,
+            bound_index,
+        )
+    }
+
     /// Outputs the next random byte.
     pub fn generate_next(&mut self) -> u8 {
         self.next()
@@ -140,6 +187,7 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         let output = (0..n_children.0)
             .zip(std::iter::repeat((
                 self.block_cipher.clone(),
+                self.key,
                 first_index,
                 n_bytes,
             )))
@@ -148,7 +196,7 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
                 // that matches the signature of `ChildrenIterator<BlockCipher>`.
                 // Unfortunately, the compiler does not manage to coerce this one
                 // automatically.
-                (|(i, (block_cipher, first_index, n_bytes))| {
+                (|(i, (block_cipher, key, first_index, n_bytes))| {
                     // The first index to be outputted by the child is the `first_index` shifted by
                     // the proper amount of `child_bytes`.
                     let child_first_index = first_index.increased(n_bytes.0 * i);
@@ -156,6 +204,7 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
                     let child_bound_index = first_index.increased(n_bytes.0 * (i + 1));
                     AesCtrGenerator::from_block_cipher(
                         block_cipher,
+                        key,
                         child_first_index,
                         child_bound_index,
                     )
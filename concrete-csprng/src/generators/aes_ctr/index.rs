@@ -138,6 +138,35 @@ impl TableIndex {
             }
         }
     }
+
+    /// Returns the table index located `offset` bytes after the second table index.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The second table index is the first byte outputted by a freshly created generator, which
+    /// makes offsets relative to it the natural way of pointing into the stream of a seed.
+    pub fn from_stream_offset(offset: ByteCount) -> Self {
+        let bytes_per_aes_call = BYTES_PER_AES_CALL as u128;
+        // The shift is split to avoid overflowing when adding the one byte of the second index
+        let byte_index = offset.0 % bytes_per_aes_call + 1;
+        TableIndex {
+            aes_index: AesIndex(offset.0 / bytes_per_aes_call + byte_index / bytes_per_aes_call),
+            byte_index: ByteIndex((byte_index % bytes_per_aes_call) as usize),
+        }
+    }
+
+    /// Returns the offset of the table index in bytes, relative to the second table index.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// This is the inverse of [`TableIndex::from_stream_offset`]. The first table index has no
+    /// such offset, in which case the method returns `None`. As for [`TableIndex::distance`], the
+    /// offset saturates at 2¹²⁸ - 1.
+    pub fn to_stream_offset(self) -> Option<ByteCount> {
+        TableIndex::distance(&self, &TableIndex::SECOND)
+    }
 }
 
 impl Eq for TableIndex {}
@@ -386,4 +415,51 @@ mod test {
             TableIndex::new(AesIndex(total_full_aes_shifts), ByteIndex(0))
         );
     }
+
+    #[test]
+    /// Check the property:
+    ///     For all positive integer i,
+    ///         from_stream_offset(i) = SECOND.increased(i).
+    fn prop_table_index_from_stream_offset() {
+        for _ in 0..REPEATS {
+            let i = any_usize().next().unwrap();
+            assert_eq!(
+                TableIndex::from_stream_offset(ByteCount(i as u128)),
+                TableIndex::SECOND.increased(i)
+            );
+        }
+    }
+
+    #[test]
+    /// Check the property:
+    ///     For all table indices t different from the first one, whose offset does not saturate,
+    ///         from_stream_offset(to_stream_offset(t)) = t.
+    fn prop_table_index_stream_offset_round_trip() {
+        for _ in 0..REPEATS {
+            let t = any_table_index()
+                .find(|t| {
+                    *t != TableIndex::FIRST
+                        && t.aes_index.0 < u128::MAX / BYTES_PER_AES_CALL as u128
+                })
+                .unwrap();
+            assert_eq!(
+                TableIndex::from_stream_offset(t.to_stream_offset().unwrap()),
+                t
+            );
+        }
+    }
+
+    #[test]
+    /// Check that the largest stream offset does not overflow and that the first index has no
+    /// offset.
+    fn test_table_index_stream_offset_edges() {
+        assert_eq!(TableIndex::FIRST.to_stream_offset(), None);
+        assert_eq!(
+            TableIndex::from_stream_offset(ByteCount(u128::MAX)),
+            TableIndex::new(
+                AesIndex(u128::MAX / BYTES_PER_AES_CALL as u128 + 1),
+                ByteIndex(0)
+            )
+        );
+    }
 }
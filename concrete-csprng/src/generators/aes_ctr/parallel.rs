@@ -1,5 +1,5 @@
 use crate::generators::aes_ctr::{
-    AesBlockCipher, AesCtrGenerator, ChildrenClosure, State, TableIndex,
+    AesBlockCipher, AesCtrGenerator, AesKey, ChildrenClosure, State, TableIndex,
 };
 use crate::generators::{BytesPerChild, ChildrenCount, ForkError};
 
@@ -7,9 +7,9 @@ use crate::generators::{BytesPerChild, ChildrenCount, ForkError};
 pub type ParallelChildrenIterator<BlockCipher> = rayon::iter::Map<
     rayon::iter::Zip<
         rayon::range::Iter<usize>,
-        rayon::iter::RepeatN<(Box<BlockCipher>, TableIndex, BytesPerChild)>,
+        rayon::iter::RepeatN<(Box<BlockCipher>, AesKey, TableIndex, BytesPerChild)>,
     >,
-    fn((usize, (Box<BlockCipher>, TableIndex, BytesPerChild))) -> AesCtrGenerator<BlockCipher>,
+    ChildrenClosure<BlockCipher>,
 >;
 
 impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
@@ -45,14 +45,14 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         let output = (0..n_children.0)
             .into_par_iter()
             .zip(rayon::iter::repeatn(
-                (self.block_cipher.clone(), first_index, n_bytes),
+                (self.block_cipher.clone(), self.key, first_index, n_bytes),
                 n_children.0,
             ))
             .map(
                 // This map is a little weird because we need to cast the closure to a fn pointer
                 // that matches the signature of `ChildrenIterator<BlockCipher>`. Unfortunately,
                 // the compiler does not manage to coerce this one automatically.
-                (|(i, (block_cipher, first_index, n_bytes))| {
+                (|(i, (block_cipher, key, first_index, n_bytes))| {
                     // The first index to be outputted by the child is the `first_index` shifted by
                     // the proper amount of `child_bytes`.
                     let child_first_index = first_index.increased(n_bytes.0 * i);
@@ -60,6 +60,7 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
                     let child_bound_index = first_index.increased(n_bytes.0 * (i + 1));
                     AesCtrGenerator::from_block_cipher(
                         block_cipher,
+                        key,
                         child_first_index,
                         child_bound_index,
                     )
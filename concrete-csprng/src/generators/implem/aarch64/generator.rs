@@ -1,6 +1,8 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::aarch64::block_cipher::ArmAesBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using the `aesni` instructions.
@@ -27,6 +29,12 @@ impl RandomGenerator for NeonAesRandomGenerator {
     fn remaining_bytes(&self) -> ByteCount {
         self.0.remaining_bytes()
     }
+    fn state(&self) -> GeneratorState {
+        self.0.state()
+    }
+    fn from_state(state: GeneratorState) -> Self {
+        NeonAesRandomGenerator(AesCtrGenerator::from_state(state))
+    }
     fn try_fork(
         &mut self,
         n_children: ChildrenCount,
@@ -92,6 +100,16 @@ mod test {
         generator_generic_test::test_roughly_uniform::<NeonAesRandomGenerator>();
     }
 
+    #[test]
+    fn test_state_round_trip() {
+        generator_generic_test::test_state_round_trip::<NeonAesRandomGenerator>();
+    }
+
+    #[test]
+    fn test_new_at_offset() {
+        generator_generic_test::test_new_at_offset::<NeonAesRandomGenerator>();
+    }
+
     #[test]
     fn test_generator_determinism() {
         generator_generic_test::test_generator_determinism::<NeonAesRandomGenerator>();
@@ -9,7 +9,7 @@ use std::mem::transmute;
 #[derive(Clone)]
 pub struct AesniBlockCipher {
     // The set of round keys used for the aes encryption
-    round_keys: [__m128i; 11],
+    pub(crate) round_keys: [__m128i; 11],
 }
 
 impl AesBlockCipher for AesniBlockCipher {
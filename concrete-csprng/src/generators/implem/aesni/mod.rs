@@ -4,7 +4,7 @@
 //! (CS-PRNG), using a fast block cipher. The implementation is based on the
 //! [intel aesni white paper 323641-001 revision 3.0](https://www.intel.com/content/dam/doc/white-paper/advanced-encryption-standard-new-instructions-set-paper.pdf).
 
-mod block_cipher;
+pub(super) mod block_cipher;
 
 mod generator;
 pub use generator::*;
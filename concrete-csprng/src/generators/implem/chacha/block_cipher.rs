@@ -0,0 +1,291 @@
+use crate::generators::aes_ctr::{
+    AesBlockCipher, AesIndex, AesKey, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
+use std::convert::TryInto;
+
+/// The number of 32 bits words in a ChaCha20 block.
+const WORDS_PER_BLOCK: usize = 16;
+/// The number of bytes in a ChaCha20 block.
+const BYTES_PER_BLOCK: usize = WORDS_PER_BLOCK * 4;
+/// The number of aes indices covered by a single ChaCha20 block.
+const AES_INDICES_PER_BLOCK: u128 = (BYTES_PER_BLOCK / BYTES_PER_AES_CALL) as u128;
+/// The number of ChaCha20 blocks computed at once. A batch starting at any aes index spans at most
+/// three consecutive blocks, four blocks fill the lanes of 128 bits simd registers.
+const BLOCKS_PER_CALL: usize = 4;
+/// The block counter is reduced so that the keystream wraps together with the aes indices.
+const BLOCK_COUNTER_MASK: u128 = u128::MAX / AES_INDICES_PER_BLOCK;
+/// The "expand 16-byte k" constant, used with 128 bits keys.
+const TAU: [u32; 4] = [0x61707865, 0x3120646e, 0x79622d36, 0x6b206574];
+
+/// The ChaCha20 stream cipher, used as a block cipher in counter mode.
+///
+/// The 128 bits key is used with the original ChaCha20 key expansion, and the four last words of
+/// the state hold a 128 bits block counter. Each 64 bytes block of keystream covers four
+/// consecutive aes indices, which keeps the table index semantics of the aes based generators.
+#[derive(Clone)]
+pub struct ChaCha20BlockCipher {
+    key: [u32; 4],
+}
+
+impl AesBlockCipher for ChaCha20BlockCipher {
+    fn new(key: AesKey) -> ChaCha20BlockCipher {
+        let key = key.0.to_le_bytes();
+        let key =
+            [0, 1, 2, 3].map(|i| u32::from_le_bytes(key[4 * i..4 * i + 4].try_into().unwrap()));
+        ChaCha20BlockCipher { key }
+    }
+
+    fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
+        let blocks = chacha20_blocks(&self.key, aes_ctr / AES_INDICES_PER_BLOCK);
+        let offset = (aes_ctr % AES_INDICES_PER_BLOCK) as usize * BYTES_PER_AES_CALL;
+        blocks[offset..offset + BYTES_PER_BATCH].try_into().unwrap()
+    }
+}
+
+// Returns the initial states of the `BLOCKS_PER_CALL` blocks starting at `first_block`.
+fn initial_states(key: &[u32; 4], first_block: u128) -> [[u32; WORDS_PER_BLOCK]; BLOCKS_PER_CALL] {
+    let mut states = [[0u32; WORDS_PER_BLOCK]; BLOCKS_PER_CALL];
+    for (i, state) in states.iter_mut().enumerate() {
+        let counter = first_block.wrapping_add(i as u128) & BLOCK_COUNTER_MASK;
+        state[0..4].copy_from_slice(&TAU);
+        state[4..8].copy_from_slice(key);
+        state[8..12].copy_from_slice(key);
+        state[12] = counter as u32;
+        state[13] = (counter >> 32) as u32;
+        state[14] = (counter >> 64) as u32;
+        state[15] = (counter >> 96) as u32;
+    }
+    states
+}
+
+// Serializes the words of the blocks in little endian order.
+fn serialize_blocks(
+    blocks: &[[u32; WORDS_PER_BLOCK]; BLOCKS_PER_CALL],
+) -> [u8; BYTES_PER_BLOCK * BLOCKS_PER_CALL] {
+    let mut output = [0u8; BYTES_PER_BLOCK * BLOCKS_PER_CALL];
+    for (word, bytes) in blocks.iter().flatten().zip(output.chunks_exact_mut(4)) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    output
+}
+
+// Computes the keystream of the `BLOCKS_PER_CALL` blocks starting at `first_block`.
+fn chacha20_blocks(key: &[u32; 4], first_block: u128) -> [u8; BYTES_PER_BLOCK * BLOCKS_PER_CALL] {
+    let states = initial_states(key, first_block);
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: sse2 is part of the x86_64 baseline.
+    let blocks = unsafe { sse2::chacha20_blocks(&states) };
+    #[cfg(target_arch = "aarch64")]
+    // SAFETY: neon is part of the aarch64 baseline.
+    let blocks = unsafe { neon::chacha20_blocks(&states) };
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    let blocks = states.map(|state| chacha20_block(&state));
+    serialize_blocks(&blocks)
+}
+
+#[allow(unused)] // only used as a fallback and in tests on simd enabled platforms
+#[inline(always)]
+fn quarter_round(state: &mut [u32; WORDS_PER_BLOCK], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+// The ChaCha20 block function, as specified in RFC 8439.
+#[allow(unused)] // only used as a fallback and in tests on simd enabled platforms
+fn chacha20_block(input: &[u32; WORDS_PER_BLOCK]) -> [u32; WORDS_PER_BLOCK] {
+    let mut state = *input;
+    for _ in 0..10 {
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+    for (word, input_word) in state.iter_mut().zip(input.iter()) {
+        *word = word.wrapping_add(*input_word);
+    }
+    state
+}
+
+// The simd implementations compute the blocks "vertically": the i-th register holds the i-th word
+// of each of the four blocks, one block per 32 bits lane.
+macro_rules! vertical_chacha20_blocks {
+    ($vector: ty, $load: ident, $store: ident, $add: ident, $xor: ident, $rotl: ident) => {
+        #[inline(always)]
+        unsafe fn vertical_quarter_round(
+            v: &mut [$vector; WORDS_PER_BLOCK],
+            a: usize,
+            b: usize,
+            c: usize,
+            d: usize,
+        ) {
+            v[a] = $add(v[a], v[b]);
+            v[d] = $rotl!($xor(v[d], v[a]), 16);
+            v[c] = $add(v[c], v[d]);
+            v[b] = $rotl!($xor(v[b], v[c]), 12);
+            v[a] = $add(v[a], v[b]);
+            v[d] = $rotl!($xor(v[d], v[a]), 8);
+            v[c] = $add(v[c], v[d]);
+            v[b] = $rotl!($xor(v[b], v[c]), 7);
+        }
+
+        pub(super) unsafe fn chacha20_blocks(
+            states: &[[u32; WORDS_PER_BLOCK]; BLOCKS_PER_CALL],
+        ) -> [[u32; WORDS_PER_BLOCK]; BLOCKS_PER_CALL] {
+            let input: [$vector; WORDS_PER_BLOCK] = std::array::from_fn(|i| {
+                let words = [states[0][i], states[1][i], states[2][i], states[3][i]];
+                $load(&words)
+            });
+            let mut v = input;
+            for _ in 0..10 {
+                vertical_quarter_round(&mut v, 0, 4, 8, 12);
+                vertical_quarter_round(&mut v, 1, 5, 9, 13);
+                vertical_quarter_round(&mut v, 2, 6, 10, 14);
+                vertical_quarter_round(&mut v, 3, 7, 11, 15);
+                vertical_quarter_round(&mut v, 0, 5, 10, 15);
+                vertical_quarter_round(&mut v, 1, 6, 11, 12);
+                vertical_quarter_round(&mut v, 2, 7, 8, 13);
+                vertical_quarter_round(&mut v, 3, 4, 9, 14);
+            }
+            let mut blocks = [[0u32; WORDS_PER_BLOCK]; BLOCKS_PER_CALL];
+            for (i, (word, input_word)) in v.iter().zip(input.iter()).enumerate() {
+                let words: [u32; BLOCKS_PER_CALL] = $store($add(*word, *input_word));
+                for (block, lane) in blocks.iter_mut().zip(words) {
+                    block[i] = lane;
+                }
+            }
+            blocks
+        }
+    };
+}
+
+#[cfg(target_arch = "x86_64")]
+mod sse2 {
+    use super::{BLOCKS_PER_CALL, WORDS_PER_BLOCK};
+    use std::arch::x86_64::{
+        __m128i, _mm_add_epi32, _mm_loadu_si128, _mm_or_si128, _mm_slli_epi32, _mm_srli_epi32,
+        _mm_storeu_si128, _mm_xor_si128,
+    };
+
+    macro_rules! rotl {
+        ($x: expr, $n: literal) => {{
+            let x = $x;
+            _mm_or_si128(_mm_slli_epi32(x, $n), _mm_srli_epi32(x, 32 - $n))
+        }};
+    }
+
+    unsafe fn load(words: &[u32; 4]) -> __m128i {
+        _mm_loadu_si128(words.as_ptr() as *const __m128i)
+    }
+
+    unsafe fn store(vector: __m128i) -> [u32; 4] {
+        let mut words = [0u32; 4];
+        _mm_storeu_si128(words.as_mut_ptr() as *mut __m128i, vector);
+        words
+    }
+
+    vertical_chacha20_blocks!(__m128i, load, store, _mm_add_epi32, _mm_xor_si128, rotl);
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use super::{BLOCKS_PER_CALL, WORDS_PER_BLOCK};
+    use std::arch::aarch64::{
+        uint32x4_t, vaddq_u32, veorq_u32, vld1q_u32, vshlq_n_u32, vsriq_n_u32, vst1q_u32,
+    };
+
+    macro_rules! rotl {
+        ($x: expr, $n: literal) => {{
+            let x = $x;
+            vsriq_n_u32::<{ 32 - $n }>(vshlq_n_u32::<$n>(x), x)
+        }};
+    }
+
+    unsafe fn load(words: &[u32; 4]) -> uint32x4_t {
+        vld1q_u32(words.as_ptr())
+    }
+
+    unsafe fn store(vector: uint32x4_t) -> [u32; 4] {
+        let mut words = [0u32; 4];
+        vst1q_u32(words.as_mut_ptr(), vector);
+        words
+    }
+
+    vertical_chacha20_blocks!(uint32x4_t, load, store, vaddq_u32, veorq_u32, rotl);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quarter_round() {
+        // Test vector from RFC 8439, section 2.1.1
+        let mut state = [0u32; WORDS_PER_BLOCK];
+        state[..4].copy_from_slice(&[0x11111111, 0x01020304, 0x9b8d6f43, 0x01234567]);
+        quarter_round(&mut state, 0, 1, 2, 3);
+        assert_eq!(state[..4], [0xea2a92f4, 0xcb1cf8ce, 0x4581472e, 0x5881c4bb]);
+    }
+
+    #[test]
+    fn test_chacha20_block() {
+        // Test vector from RFC 8439, section 2.3.2
+        let input = [
+            0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, 0x03020100, 0x07060504, 0x0b0a0908,
+            0x0f0e0d0c, 0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c, 0x00000001, 0x09000000,
+            0x4a000000, 0x00000000,
+        ];
+        let expected = [
+            0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033, 0x9aaa2204,
+            0x4e6cd4c3, 0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9, 0xd19c12b5, 0xb94e16de,
+            0xe883d0cb, 0x4e3c50a2,
+        ];
+        assert_eq!(chacha20_block(&input), expected);
+    }
+
+    #[test]
+    fn test_chacha20_blocks_matches_scalar() {
+        // Checks that the simd implementation, if any, matches the scalar block function.
+        let key = [0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c];
+        for first_block in [
+            0,
+            1,
+            0xdead_beef,
+            BLOCK_COUNTER_MASK - 1,
+            BLOCK_COUNTER_MASK,
+        ] {
+            let expected = initial_states(&key, first_block).map(|state| chacha20_block(&state));
+            assert_eq!(
+                chacha20_blocks(&key, first_block),
+                serialize_blocks(&expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_batches_are_consistent() {
+        // Checks that overlapping batches agree on the bytes of the aes indices they share.
+        let mut cipher = ChaCha20BlockCipher::new(AesKey(0x000102030405060708090a0b0c0d0e0f));
+        let aes_indices_per_batch = BYTES_PER_BATCH / BYTES_PER_AES_CALL;
+        for first_index in [0, 1, 2, 3, 4, 5, u128::MAX - 7, u128::MAX - 1] {
+            let batch = cipher.generate_batch(AesIndex(first_index));
+            for shift in 1..aes_indices_per_batch {
+                let shifted_batch =
+                    cipher.generate_batch(AesIndex(first_index.wrapping_add(shift as u128)));
+                assert_eq!(
+                    batch[shift * BYTES_PER_AES_CALL..],
+                    shifted_batch[..BYTES_PER_BATCH - shift * BYTES_PER_AES_CALL]
+                );
+            }
+        }
+    }
+}
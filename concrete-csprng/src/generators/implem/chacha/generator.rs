@@ -0,0 +1,128 @@
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::implem::chacha::block_cipher::ChaCha20BlockCipher;
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+};
+use crate::seeders::Seed;
+
+/// A random number generator using the ChaCha20 stream cipher.
+pub struct ChaCha20RandomGenerator(pub(super) AesCtrGenerator<ChaCha20BlockCipher>);
+
+/// The children iterator used by [`ChaCha20RandomGenerator`].
+///
+/// Outputs children generators one by one.
+pub struct ChaCha20ChildrenIterator(ChildrenIterator<ChaCha20BlockCipher>);
+
+impl Iterator for ChaCha20ChildrenIterator {
+    type Item = ChaCha20RandomGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ChaCha20RandomGenerator)
+    }
+}
+
+impl RandomGenerator for ChaCha20RandomGenerator {
+    type ChildrenIter = ChaCha20ChildrenIterator;
+    fn new(seed: Seed) -> Self {
+        ChaCha20RandomGenerator(AesCtrGenerator::new(AesKey(seed.0), None, None))
+    }
+    fn remaining_bytes(&self) -> ByteCount {
+        self.0.remaining_bytes()
+    }
+    fn state(&self) -> GeneratorState {
+        self.0.state()
+    }
+    fn from_state(state: GeneratorState) -> Self {
+        ChaCha20RandomGenerator(AesCtrGenerator::from_state(state))
+    }
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        self.0
+            .try_fork(n_children, n_bytes)
+            .map(ChaCha20ChildrenIterator)
+    }
+}
+
+impl Iterator for ChaCha20RandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::generator_generic_test;
+
+    #[test]
+    fn prop_fork_first_state_table_index() {
+        aes_ctr_generic_test::prop_fork_first_state_table_index::<ChaCha20BlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_last_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_last_bound_table_index::<ChaCha20BlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_bound_table_index::<ChaCha20BlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_state_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_state_table_index::<ChaCha20BlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork() {
+        aes_ctr_generic_test::prop_fork::<ChaCha20BlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_children_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_children_remaining_bytes::<ChaCha20BlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<ChaCha20BlockCipher>();
+    }
+
+    #[test]
+    fn test_roughly_uniform() {
+        generator_generic_test::test_roughly_uniform::<ChaCha20RandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork() {
+        generator_generic_test::test_fork_children::<ChaCha20RandomGenerator>();
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        generator_generic_test::test_state_round_trip::<ChaCha20RandomGenerator>();
+    }
+
+    #[test]
+    fn test_new_at_offset() {
+        generator_generic_test::test_new_at_offset::<ChaCha20RandomGenerator>();
+    }
+
+    #[test]
+    fn test_generator_determinism() {
+        generator_generic_test::test_generator_determinism::<ChaCha20RandomGenerator>();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected test panic")]
+    fn test_bounded_panic() {
+        generator_generic_test::test_bounded_none_should_panic::<ChaCha20RandomGenerator>();
+    }
+}
@@ -0,0 +1,17 @@
+//! A module implementing a random number generator, using the ChaCha20 stream cipher.
+//!
+//! This module implements a cryptographically secure pseudorandom number generator (CS-PRNG),
+//! using the ChaCha20 block function of [RFC 8439](https://www.rfc-editor.org/rfc/rfc8439) in
+//! counter mode. Contrary to software aes, ChaCha20 only uses additions, rotations and xors, which
+//! makes it fast and constant-time on platforms without aes instructions. The blocks are computed
+//! with simd instructions on x86_64 and aarch64.
+
+mod block_cipher;
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
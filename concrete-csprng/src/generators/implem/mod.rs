@@ -3,6 +3,11 @@ mod aesni;
 #[cfg(feature = "generator_x86_64_aesni")]
 pub use aesni::*;
 
+#[cfg(feature = "generator_x86_64_vaes")]
+mod vaes;
+#[cfg(feature = "generator_x86_64_vaes")]
+pub use vaes::*;
+
 #[cfg(feature = "generator_aarch64_aes")]
 mod aarch64;
 #[cfg(feature = "generator_aarch64_aes")]
@@ -12,3 +17,8 @@ pub use aarch64::*;
 mod soft;
 #[cfg(feature = "generator_fallback")]
 pub use soft::*;
+
+#[cfg(feature = "generator_chacha20")]
+mod chacha;
+#[cfg(feature = "generator_chacha20")]
+pub use chacha::*;
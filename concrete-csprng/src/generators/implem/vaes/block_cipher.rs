@@ -0,0 +1,251 @@
+use crate::generators::aes_ctr::{
+    AesBlockCipher, AesIndex, AesKey, AES_CALLS_PER_BATCH, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
+use crate::generators::implem::aesni::block_cipher::AesniBlockCipher;
+use std::arch::x86_64::{
+    __m128i, __m256i, __m512i, _mm256_aesenc_epi128, _mm256_aesenclast_epi128,
+    _mm256_broadcastsi128_si256, _mm256_xor_si256, _mm512_aesenc_epi128, _mm512_aesenclast_epi128,
+    _mm512_broadcast_i32x4, _mm512_xor_si512,
+};
+use std::mem::transmute;
+
+/// The number of aes calls computed at once by the wide implementations.
+///
+/// Eight 512 bits registers (or sixteen 256 bits ones) are processed in parallel, which is enough
+/// to hide the latency of the `vaesenc` instruction.
+const AES_CALLS_PER_WIDE_BATCH: usize = 32;
+const BYTES_PER_WIDE_BATCH: usize = BYTES_PER_AES_CALL * AES_CALLS_PER_WIDE_BATCH;
+
+/// The width of the vectors used to evaluate aes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lanes {
+    // 128 bits lanes with `aesni`, used when `vaes` is not available.
+    Narrow,
+    // 256 bits lanes with `vaes` and `avx2`.
+    Ymm,
+    // 512 bits lanes with `vaes` and `avx512f`.
+    Zmm,
+}
+
+impl Lanes {
+    fn detect() -> Lanes {
+        if !is_x86_feature_detected!("vaes") {
+            Lanes::Narrow
+        } else if is_x86_feature_detected!("avx512f") {
+            Lanes::Zmm
+        } else if is_x86_feature_detected!("avx2") {
+            Lanes::Ymm
+        } else {
+            Lanes::Narrow
+        }
+    }
+}
+
+/// An aes block cipher implementation which uses the `vaes` instructions on 512 or 256 bits
+/// vectors.
+///
+/// The widest available vectors are detected at runtime, and the `aesni` implementation is used
+/// when `vaes` is not supported by the CPU. Batches are computed four at a time and cached, the
+/// outputs are identical to the ones of the `aesni` implementation.
+#[derive(Clone)]
+pub struct VaesBlockCipher {
+    aesni: AesniBlockCipher,
+    lanes: Lanes,
+    // The aes index of the first aes call stored in the cache, if any
+    cache_start: Option<AesIndex>,
+    cache: [u8; BYTES_PER_WIDE_BATCH],
+}
+
+impl VaesBlockCipher {
+    fn with_lanes(key: AesKey, lanes: Lanes) -> VaesBlockCipher {
+        VaesBlockCipher {
+            aesni: AesniBlockCipher::new(key),
+            lanes,
+            cache_start: None,
+            cache: [0u8; BYTES_PER_WIDE_BATCH],
+        }
+    }
+
+    fn fill_cache(&mut self, AesIndex(aes_ctr): AesIndex) {
+        let counters: [u128; AES_CALLS_PER_WIDE_BATCH] =
+            std::array::from_fn(|i| aes_ctr.wrapping_add(i as u128));
+        let round_keys = &self.aesni.round_keys;
+        // SAFETY: the lanes are only set to a wide variant if the required CPU features were
+        // detected in `Self::new`
+        self.cache = unsafe {
+            match self.lanes {
+                Lanes::Zmm => {
+                    m512arr_to_u8arr(aes_encrypt_zmm(u128arr_to_m512arr(counters), round_keys))
+                }
+                Lanes::Ymm => {
+                    m256arr_to_u8arr(aes_encrypt_ymm(u128arr_to_m256arr(counters), round_keys))
+                }
+                Lanes::Narrow => unreachable!(),
+            }
+        };
+        self.cache_start = Some(AesIndex(aes_ctr));
+    }
+}
+
+impl AesBlockCipher for VaesBlockCipher {
+    fn new(key: AesKey) -> VaesBlockCipher {
+        Self::with_lanes(key, Lanes::detect())
+    }
+
+    fn generate_batch(&mut self, aes_index: AesIndex) -> [u8; BYTES_PER_BATCH] {
+        if self.lanes == Lanes::Narrow {
+            return self.aesni.generate_batch(aes_index);
+        }
+        let cached_offset = self
+            .cache_start
+            .map(|AesIndex(start)| aes_index.0.wrapping_sub(start))
+            .filter(|offset| *offset <= (AES_CALLS_PER_WIDE_BATCH - AES_CALLS_PER_BATCH) as u128);
+        let offset = match cached_offset {
+            Some(offset) => offset as usize,
+            None => {
+                self.fill_cache(aes_index);
+                0
+            }
+        };
+        let start = offset * BYTES_PER_AES_CALL;
+        let mut output = [0u8; BYTES_PER_BATCH];
+        output.copy_from_slice(&self.cache[start..start + BYTES_PER_BATCH]);
+        output
+    }
+}
+
+#[target_feature(enable = "avx512f,vaes")]
+unsafe fn aes_encrypt_zmm(
+    messages: [__m512i; AES_CALLS_PER_WIDE_BATCH / 4],
+    keys: &[__m128i; 11],
+) -> [__m512i; AES_CALLS_PER_WIDE_BATCH / 4] {
+    let mut tmp = messages;
+    let first_key = _mm512_broadcast_i32x4(keys[0]);
+    for block in tmp.iter_mut() {
+        *block = _mm512_xor_si512(*block, first_key);
+    }
+    for key in keys[1..10].iter() {
+        let key = _mm512_broadcast_i32x4(*key);
+        for block in tmp.iter_mut() {
+            *block = _mm512_aesenc_epi128(*block, key);
+        }
+    }
+    let last_key = _mm512_broadcast_i32x4(keys[10]);
+    for block in tmp.iter_mut() {
+        *block = _mm512_aesenclast_epi128(*block, last_key);
+    }
+    tmp
+}
+
+#[target_feature(enable = "avx2,vaes")]
+unsafe fn aes_encrypt_ymm(
+    messages: [__m256i; AES_CALLS_PER_WIDE_BATCH / 2],
+    keys: &[__m128i; 11],
+) -> [__m256i; AES_CALLS_PER_WIDE_BATCH / 2] {
+    let mut tmp = messages;
+    let first_key = _mm256_broadcastsi128_si256(keys[0]);
+    for block in tmp.iter_mut() {
+        *block = _mm256_xor_si256(*block, first_key);
+    }
+    for key in keys[1..10].iter() {
+        let key = _mm256_broadcastsi128_si256(*key);
+        for block in tmp.iter_mut() {
+            *block = _mm256_aesenc_epi128(*block, key);
+        }
+    }
+    let last_key = _mm256_broadcastsi128_si256(keys[10]);
+    for block in tmp.iter_mut() {
+        *block = _mm256_aesenclast_epi128(*block, last_key);
+    }
+    tmp
+}
+
+#[inline(always)]
+fn u128arr_to_m512arr(
+    input: [u128; AES_CALLS_PER_WIDE_BATCH],
+) -> [__m512i; AES_CALLS_PER_WIDE_BATCH / 4] {
+    unsafe { transmute(input) }
+}
+
+#[inline(always)]
+fn u128arr_to_m256arr(
+    input: [u128; AES_CALLS_PER_WIDE_BATCH],
+) -> [__m256i; AES_CALLS_PER_WIDE_BATCH / 2] {
+    unsafe { transmute(input) }
+}
+
+#[inline(always)]
+fn m512arr_to_u8arr(input: [__m512i; AES_CALLS_PER_WIDE_BATCH / 4]) -> [u8; BYTES_PER_WIDE_BATCH] {
+    unsafe { transmute(input) }
+}
+
+#[inline(always)]
+fn m256arr_to_u8arr(input: [__m256i; AES_CALLS_PER_WIDE_BATCH / 2]) -> [u8; BYTES_PER_WIDE_BATCH] {
+    unsafe { transmute(input) }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    // Test vector for aes128, from the FIPS publication 197
+    const CIPHER_KEY: u128 = u128::from_be(0x000102030405060708090a0b0c0d0e0f);
+    const PLAINTEXT: u128 = u128::from_be(0x00112233445566778899aabbccddeeff);
+    const CIPHERTEXT: u128 = u128::from_be(0x69c4e0d86a7b0430d8cdb78070b4c55a);
+
+    fn available_wide_lanes() -> Vec<Lanes> {
+        let mut lanes = Vec::new();
+        if is_x86_feature_detected!("vaes") && is_x86_feature_detected!("avx2") {
+            lanes.push(Lanes::Ymm);
+        }
+        if is_x86_feature_detected!("vaes") && is_x86_feature_detected!("avx512f") {
+            lanes.push(Lanes::Zmm);
+        }
+        lanes
+    }
+
+    #[test]
+    fn test_encrypt_many_messages() {
+        // Checks that encrypting many plaintext at the same time gives the correct output.
+        let keys = AesniBlockCipher::new(AesKey(CIPHER_KEY)).round_keys;
+        let messages = [PLAINTEXT; AES_CALLS_PER_WIDE_BATCH];
+        for lanes in available_wide_lanes() {
+            let ciphertexts = unsafe {
+                match lanes {
+                    Lanes::Zmm => {
+                        m512arr_to_u8arr(aes_encrypt_zmm(u128arr_to_m512arr(messages), &keys))
+                    }
+                    Lanes::Ymm => {
+                        m256arr_to_u8arr(aes_encrypt_ymm(u128arr_to_m256arr(messages), &keys))
+                    }
+                    Lanes::Narrow => unreachable!(),
+                }
+            };
+            for ct in ciphertexts.chunks_exact(BYTES_PER_AES_CALL) {
+                assert_eq!(CIPHERTEXT.to_ne_bytes(), ct);
+            }
+        }
+    }
+
+    #[test]
+    fn test_batches_match_aesni() {
+        // Checks that batches are the same as the aesni ones, for sequential and random accesses.
+        for lanes in available_wide_lanes() {
+            for _ in 0..100 {
+                let key = AesKey(thread_rng().gen());
+                let mut aesni = AesniBlockCipher::new(key);
+                let mut vaes = VaesBlockCipher::with_lanes(key, lanes);
+                let start: u128 = thread_rng().gen::<u128>() >> 1;
+                for i in 0..20 {
+                    let index = AesIndex(start + i * AES_CALLS_PER_BATCH as u128);
+                    assert_eq!(aesni.generate_batch(index), vaes.generate_batch(index));
+                }
+                for _ in 0..20 {
+                    let index = AesIndex(start + thread_rng().gen_range(0..1000));
+                    assert_eq!(aesni.generate_batch(index), vaes.generate_batch(index));
+                }
+            }
+        }
+    }
+}
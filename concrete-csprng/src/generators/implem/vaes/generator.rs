@@ -0,0 +1,147 @@
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::implem::vaes::block_cipher::VaesBlockCipher;
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+};
+use crate::seeders::Seed;
+
+/// A random number generator using the `vaes` instructions when available.
+///
+/// It outputs the same stream as [`AesniRandomGenerator`](crate::generators::AesniRandomGenerator)
+/// for a given seed.
+pub struct VaesRandomGenerator(pub(super) AesCtrGenerator<VaesBlockCipher>);
+
+/// The children iterator used by [`VaesRandomGenerator`].
+///
+/// Outputs children generators one by one.
+pub struct VaesChildrenIterator(ChildrenIterator<VaesBlockCipher>);
+
+impl Iterator for VaesChildrenIterator {
+    type Item = VaesRandomGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(VaesRandomGenerator)
+    }
+}
+
+impl RandomGenerator for VaesRandomGenerator {
+    type ChildrenIter = VaesChildrenIterator;
+    fn new(seed: Seed) -> Self {
+        VaesRandomGenerator(AesCtrGenerator::new(AesKey(seed.0), None, None))
+    }
+    fn remaining_bytes(&self) -> ByteCount {
+        self.0.remaining_bytes()
+    }
+    fn state(&self) -> GeneratorState {
+        self.0.state()
+    }
+    fn from_state(state: GeneratorState) -> Self {
+        VaesRandomGenerator(AesCtrGenerator::from_state(state))
+    }
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        self.0
+            .try_fork(n_children, n_bytes)
+            .map(VaesChildrenIterator)
+    }
+}
+
+impl Iterator for VaesRandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::implem::vaes::block_cipher::VaesBlockCipher;
+    use crate::generators::{
+        generator_generic_test, AesniRandomGenerator, ByteCount, RandomGenerator,
+        VaesRandomGenerator,
+    };
+    use crate::seeders::Seed;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn prop_fork_first_state_table_index() {
+        aes_ctr_generic_test::prop_fork_first_state_table_index::<VaesBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_last_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_last_bound_table_index::<VaesBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_bound_table_index::<VaesBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_state_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_state_table_index::<VaesBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork() {
+        aes_ctr_generic_test::prop_fork::<VaesBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_children_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_children_remaining_bytes::<VaesBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<VaesBlockCipher>();
+    }
+
+    #[test]
+    fn test_roughly_uniform() {
+        generator_generic_test::test_roughly_uniform::<VaesRandomGenerator>();
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        generator_generic_test::test_state_round_trip::<VaesRandomGenerator>();
+    }
+
+    #[test]
+    fn test_new_at_offset() {
+        generator_generic_test::test_new_at_offset::<VaesRandomGenerator>();
+    }
+
+    #[test]
+    fn test_generator_determinism() {
+        generator_generic_test::test_generator_determinism::<VaesRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork() {
+        generator_generic_test::test_fork_children::<VaesRandomGenerator>();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected test panic")]
+    fn test_bounded_panic() {
+        generator_generic_test::test_bounded_none_should_panic::<VaesRandomGenerator>();
+    }
+
+    #[test]
+    fn test_same_stream_as_aesni() {
+        for _ in 0..100 {
+            let seed = Seed(thread_rng().gen());
+            let offset = ByteCount(thread_rng().gen_range(0..10_000));
+            let aesni = AesniRandomGenerator::new_at_offset(seed, offset);
+            let vaes = VaesRandomGenerator::new_at_offset(seed, offset);
+            assert!(aesni.take(10_000).eq(vaes.take(10_000)));
+        }
+    }
+}
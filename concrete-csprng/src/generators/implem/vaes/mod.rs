@@ -0,0 +1,15 @@
+//! A module implementing a random number generator, using the x86_64 `vaes` instructions.
+//!
+//! This module implements the same aes-ctr random number generator as the `aesni` one, but
+//! evaluates aes on 512 or 256 bits vectors when the CPU supports the `vaes` extension. The
+//! available instructions are detected at runtime, falling back to `aesni` otherwise.
+
+mod block_cipher;
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
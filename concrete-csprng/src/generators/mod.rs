@@ -17,6 +17,20 @@ pub struct BytesPerChild(pub usize);
 #[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub struct ByteCount(pub u128);
 
+/// A snapshot of the state of a generator, from which it can be restored.
+///
+/// Positions are counted in bytes from the first byte outputted by a generator freshly created
+/// from `seed`. As such, the state of a forked child refers to the stream of its root generator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GeneratorState {
+    /// The seed of the root generator.
+    pub seed: Seed,
+    /// The position of the next byte to be outputted.
+    pub position: ByteCount,
+    /// The position of the first byte that can not be outputted, if the generator is bounded.
+    pub bound: Option<ByteCount>,
+}
+
 /// An error occurring during a generator fork.
 #[derive(Debug)]
 pub enum ForkError {
@@ -77,6 +91,42 @@ pub trait RandomGenerator: Iterator<Item = u8> {
     /// `min(2¹²⁸-1, remaining_bytes)`.
     fn remaining_bytes(&self) -> ByteCount;
 
+    /// Returns a snapshot of the state of the generator.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The snapshot contains the seed of the generator, and must therefore be handled with the same
+    /// care as the seed itself.
+    fn state(&self) -> GeneratorState;
+
+    /// Creates a generator from a snapshot of its state.
+    ///
+    /// The restored generator outputs the exact same bytes as the generator the snapshot was taken
+    /// from would have.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position of the state is greater than its bound.
+    fn from_state(state: GeneratorState) -> Self
+    where
+        Self: Sized;
+
+    /// Creates a new generator from a seed, starting `offset` bytes into the stream.
+    ///
+    /// This is equivalent to creating a generator with [`RandomGenerator::new`] and skipping its
+    /// first `offset` bytes, without the cost of generating them.
+    fn new_at_offset(seed: Seed, offset: ByteCount) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_state(GeneratorState {
+            seed,
+            position: offset,
+            bound: None,
+        })
+    }
+
     /// Returns the next byte of the stream, if the generator did not yet reach its bound.
     fn next_byte(&mut self) -> Option<u8> {
         self.next()
@@ -191,6 +241,61 @@ pub mod generator_generic_test {
         }
     }
 
+    /// Checks that a generator restored from a snapshot of its state, outputs the same bytes as
+    /// the original generator, both for a root generator and for a forked child.
+    pub fn test_state_round_trip<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut generator = G::new(seed);
+            for _ in 0..rand::thread_rng().gen::<usize>() % 1024 {
+                generator.next().unwrap();
+            }
+            let state = generator.state();
+            assert_eq!(state.seed, seed);
+            assert_eq!(state.bound, None);
+            let mut restored = G::from_state(state);
+            for _ in 0..1024 {
+                assert_eq!(generator.next(), restored.next());
+            }
+
+            let mut child = generator
+                .try_fork(n_children, n_bytes)
+                .unwrap()
+                .last()
+                .unwrap();
+            child.next().unwrap();
+            let mut restored_child = G::from_state(child.state());
+            assert_eq!(restored_child.remaining_bytes(), child.remaining_bytes());
+            for _ in 0..n_bytes.0 {
+                assert_eq!(child.next(), restored_child.next());
+            }
+            // The exhausted child can also be restored
+            assert!(G::from_state(child.state()).next().is_none());
+        }
+    }
+
+    /// Checks that a generator created at a given offset outputs the same bytes as a fresh
+    /// generator which skipped as many bytes.
+    pub fn test_new_at_offset<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let seed = any_seed().next().unwrap();
+            let offset = rand::thread_rng().gen::<usize>() % 4096;
+            let mut generator = G::new(seed);
+            for _ in 0..offset {
+                generator.next().unwrap();
+            }
+            let mut offset_generator = G::new_at_offset(seed, ByteCount(offset as u128));
+            assert_eq!(offset_generator.state(), generator.state());
+            for _ in 0..1024 {
+                assert_eq!(generator.next(), offset_generator.next());
+            }
+        }
+    }
+
     /// Checks that forks returns a bounded child, and that the proper number of bytes can be
     /// generated.
     pub fn test_fork_children<G: RandomGenerator>() {
@@ -1,4 +1,5 @@
 #![deny(rustdoc::broken_intra_doc_links)]
+#![cfg_attr(feature = "generator_x86_64_vaes", feature(stdsimd, avx512_target_feature))]
 //! Cryptographically secure pseudo random number generator.
 //!
 //! Welcome to the `concrete-csprng` documentation.
@@ -0,0 +1,103 @@
+use crate::seeders::{Seed, Seeder};
+use sha3::{Digest, Sha3_256};
+
+/// Prefixed to the hashed seed material, so that the outputs of this seeder can not collide with
+/// another use of the hash function.
+const DOMAIN_SEPARATOR: &[u8] = b"concrete-csprng/conditioned-seeder";
+
+/// A seeder mixing the seeds of several other seeders through a hash based conditioner.
+///
+/// Each call to [`seed`](Seeder::seed) draws a seed from every inner seeder, and hashes their
+/// concatenation with SHA3-256, a vetted conditioning function of NIST SP 800-90B. The output seed
+/// is the first 128 bits of the digest. As a consequence, the output is unpredictable as long as at
+/// least one of the inner seeders is.
+///
+/// Note:
+/// -----
+///
+/// To run health tests on the sources, wrap each inner seeder in a
+/// [`HealthTestedSeeder`](crate::seeders::HealthTestedSeeder) before mixing them.
+pub struct ConditionedSeeder {
+    seeders: Vec<Box<dyn Seeder>>,
+}
+
+impl ConditionedSeeder {
+    /// Creates a new seeder mixing the outputs of `seeders`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seeders` is empty.
+    pub fn new(seeders: Vec<Box<dyn Seeder>>) -> ConditionedSeeder {
+        assert!(
+            !seeders.is_empty(),
+            "A conditioned seeder needs at least one inner seeder."
+        );
+        ConditionedSeeder { seeders }
+    }
+}
+
+impl Seeder for ConditionedSeeder {
+    fn seed(&mut self) -> Seed {
+        let mut hasher = Sha3_256::new();
+        hasher.update(DOMAIN_SEPARATOR);
+        for seeder in self.seeders.iter_mut() {
+            hasher.update(seeder.seed().0.to_le_bytes());
+        }
+        let digest = hasher.finalize();
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        Seed(u128::from_le_bytes(bytes))
+    }
+
+    fn is_available() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::generic_tests::check_seeder_fixed_sequences_different;
+
+    struct CounterSeeder(u128);
+
+    impl Seeder for CounterSeeder {
+        fn seed(&mut self) -> Seed {
+            self.0 = self.0.wrapping_add(1);
+            Seed(self.0)
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn check_bounded_sequence_difference() {
+        check_seeder_fixed_sequences_different(|i| {
+            ConditionedSeeder::new(vec![Box::new(CounterSeeder(i)), Box::new(CounterSeeder(0))])
+        });
+    }
+
+    #[test]
+    fn test_seed_is_hash_of_inner_seeds() {
+        let mut seeder = ConditionedSeeder::new(vec![
+            Box::new(CounterSeeder(0)),
+            Box::new(CounterSeeder(41)),
+        ]);
+        let seed = seeder.seed();
+
+        let mut hasher = Sha3_256::new();
+        hasher.update(DOMAIN_SEPARATOR);
+        hasher.update(1u128.to_le_bytes());
+        hasher.update(42u128.to_le_bytes());
+        let digest = hasher.finalize();
+        assert_eq!(seed.0.to_le_bytes(), digest[..16]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one inner seeder")]
+    fn test_empty_seeders_panics() {
+        ConditionedSeeder::new(Vec::new());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::seeders::{Seed, Seeder};
+use std::io::{Error, ErrorKind};
+
+/// A seeder which uses the `getrandom(2)` system call on Linux.
+///
+/// Contrary to reading from `/dev/random`, the system call does not need a file descriptor and
+/// blocks until the kernel entropy pool has been initialized, after which it never blocks again.
+/// See <https://man7.org/linux/man-pages/man2/getrandom.2.html> .
+pub struct GetrandomSeeder;
+
+impl Seeder for GetrandomSeeder {
+    fn seed(&mut self) -> Seed {
+        let mut bytes = [0u8; 16];
+        getrandom(&mut bytes).expect("Failed to read from getrandom(2) .");
+        Seed(u128::from_ne_bytes(bytes))
+    }
+
+    fn is_available() -> bool {
+        // An empty request only checks that the system call is implemented by the running kernel.
+        let res = unsafe { libc::getrandom(std::ptr::null_mut(), 0, libc::GRND_NONBLOCK) };
+        res == 0 || Error::last_os_error().raw_os_error() != Some(libc::ENOSYS)
+    }
+}
+
+fn getrandom(bytes: &mut [u8]) -> std::io::Result<()> {
+    let mut filled = 0;
+    while filled < bytes.len() {
+        let remaining = &mut bytes[filled..];
+        let res = unsafe { libc::getrandom(remaining.as_mut_ptr().cast(), remaining.len(), 0) };
+        if res < 0 {
+            let err = Error::last_os_error();
+            if err.kind() == ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(err);
+        }
+        filled += res as usize;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::generic_tests::check_seeder_fixed_sequences_different;
+
+    #[test]
+    fn check_bounded_sequence_difference() {
+        check_seeder_fixed_sequences_different(|_| GetrandomSeeder);
+    }
+}
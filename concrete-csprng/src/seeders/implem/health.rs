@@ -0,0 +1,350 @@
+use crate::seeders::{Seed, Seeder};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The binary logarithm of the inverse of the false positive probability of the health tests.
+///
+/// NIST SP 800-90B recommends a false positive probability between 2⁻²⁰ and 2⁻⁴⁰, we pick the
+/// lowest one so that a healthy source practically never trips the tests.
+const FALSE_POSITIVE_LOG2: f64 = 40.;
+
+/// The window size of the adaptive proportion test for non-binary samples.
+const ADAPTIVE_PROPORTION_WINDOW: usize = 512;
+
+/// The number of samples which must pass the health tests before the first seed is outputted.
+const STARTUP_SAMPLES: usize = 1024;
+
+/// The number of samples contained in a seed, a sample being a byte.
+const SAMPLES_PER_SEED: usize = std::mem::size_of::<u128>();
+
+/// An error returned when the raw seed material fails an entropy health test.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HealthTestError {
+    RepetitionCount,
+    AdaptiveProportion,
+}
+
+impl Display for HealthTestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthTestError::RepetitionCount => {
+                write!(
+                    f,
+                    "The repetition count test failed, the entropy source repeats the same value."
+                )
+            }
+            HealthTestError::AdaptiveProportion => {
+                write!(
+                    f,
+                    "The adaptive proportion test failed, the entropy source outputs a value too \
+                    often."
+                )
+            }
+        }
+    }
+}
+impl Error for HealthTestError {}
+
+/// The repetition count test of NIST SP 800-90B section 4.4.1, detecting a stuck source.
+struct RepetitionCountTest {
+    cutoff: usize,
+    previous: Option<u8>,
+    run_length: usize,
+}
+
+impl RepetitionCountTest {
+    fn new(min_entropy_per_sample: f64) -> Self {
+        let cutoff = 1 + (FALSE_POSITIVE_LOG2 / min_entropy_per_sample).ceil() as usize;
+        RepetitionCountTest {
+            cutoff,
+            previous: None,
+            run_length: 0,
+        }
+    }
+
+    fn feed(&mut self, sample: u8) -> Result<(), HealthTestError> {
+        if self.previous == Some(sample) {
+            self.run_length += 1;
+            if self.run_length >= self.cutoff {
+                return Err(HealthTestError::RepetitionCount);
+            }
+        } else {
+            self.previous = Some(sample);
+            self.run_length = 1;
+        }
+        Ok(())
+    }
+}
+
+/// The adaptive proportion test of NIST SP 800-90B section 4.4.2, detecting a large loss of
+/// entropy.
+struct AdaptiveProportionTest {
+    cutoff: usize,
+    reference: u8,
+    occurrences: usize,
+    window_position: usize,
+}
+
+impl AdaptiveProportionTest {
+    fn new(min_entropy_per_sample: f64) -> Self {
+        let probability = (-min_entropy_per_sample).exp2();
+        let cutoff = 1 + critical_binomial(
+            ADAPTIVE_PROPORTION_WINDOW,
+            probability,
+            (-FALSE_POSITIVE_LOG2).exp2(),
+        );
+        AdaptiveProportionTest {
+            cutoff,
+            reference: 0,
+            occurrences: 0,
+            window_position: 0,
+        }
+    }
+
+    fn feed(&mut self, sample: u8) -> Result<(), HealthTestError> {
+        if self.window_position == 0 {
+            self.reference = sample;
+            self.occurrences = 1;
+        } else if sample == self.reference {
+            self.occurrences += 1;
+            if self.occurrences >= self.cutoff {
+                return Err(HealthTestError::AdaptiveProportion);
+            }
+        }
+        self.window_position = (self.window_position + 1) % ADAPTIVE_PROPORTION_WINDOW;
+        Ok(())
+    }
+}
+
+/// Returns the smallest `k` such that a binomial variable of parameters `trials` and `probability`
+/// is larger than `k` with probability at most `tail`.
+fn critical_binomial(trials: usize, probability: f64, tail: f64) -> usize {
+    let mut masses = Vec::with_capacity(trials + 1);
+    let mut mass = (1. - probability).powi(trials as i32);
+    for k in 0..=trials {
+        masses.push(mass);
+        mass *= (trials - k) as f64 / (k + 1) as f64 * probability / (1. - probability);
+    }
+    // Summing from the largest values keeps the tail accurate far below the f64 epsilon.
+    let mut upper_tail = 0.;
+    for k in (0..=trials).rev() {
+        if upper_tail + masses[k] > tail {
+            return k;
+        }
+        upper_tail += masses[k];
+    }
+    0
+}
+
+/// A seeder wrapper running continuous entropy health tests on the seeds of another seeder.
+///
+/// Every byte of the seeds produced by the inner seeder goes through the repetition count and
+/// adaptive proportion tests from NIST SP 800-90B. Before the first seed is returned, the start-up
+/// tests are run on 1024 bytes which are then discarded.
+///
+/// The seeder fails closed: once a test failed, every subsequent call to
+/// [`try_seed`](HealthTestedSeeder::try_seed) returns the same error, and
+/// [`seed`](Seeder::seed) panics.
+///
+/// Note:
+/// -----
+///
+/// The tests are meaningful on raw entropy, they should wrap the seeder closest to the noise
+/// source, e.g. [`GetrandomSeeder`](crate::seeders::GetrandomSeeder) rather than a seeder mixing
+/// several sources.
+pub struct HealthTestedSeeder<S: Seeder> {
+    inner: S,
+    repetition_count: RepetitionCountTest,
+    adaptive_proportion: AdaptiveProportionTest,
+    started: bool,
+    failure: Option<HealthTestError>,
+}
+
+impl<S: Seeder> HealthTestedSeeder<S> {
+    /// Creates a new health tested seeder, assuming the inner seeder outputs full entropy bytes.
+    pub fn new(inner: S) -> HealthTestedSeeder<S> {
+        Self::with_min_entropy(inner, 8.)
+    }
+
+    /// Creates a new health tested seeder, for an inner seeder whose assessed min-entropy is
+    /// `min_entropy_per_byte` bits per output byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_entropy_per_byte` is not between 1 and 8.
+    pub fn with_min_entropy(inner: S, min_entropy_per_byte: f64) -> HealthTestedSeeder<S> {
+        assert!(
+            (1. ..=8.).contains(&min_entropy_per_byte),
+            "The min-entropy per byte must be between 1 and 8, got {min_entropy_per_byte}."
+        );
+        HealthTestedSeeder {
+            inner,
+            repetition_count: RepetitionCountTest::new(min_entropy_per_byte),
+            adaptive_proportion: AdaptiveProportionTest::new(min_entropy_per_byte),
+            started: false,
+            failure: None,
+        }
+    }
+
+    /// Generates a new seed, or returns the health test which failed.
+    pub fn try_seed(&mut self) -> Result<Seed, HealthTestError> {
+        if let Some(failure) = self.failure {
+            return Err(failure);
+        }
+        if !self.started {
+            for _ in 0..STARTUP_SAMPLES / SAMPLES_PER_SEED {
+                self.tested_seed()?;
+            }
+            self.started = true;
+        }
+        self.tested_seed()
+    }
+
+    fn tested_seed(&mut self) -> Result<Seed, HealthTestError> {
+        let seed = self.inner.seed();
+        for sample in seed.0.to_le_bytes() {
+            let result = self
+                .repetition_count
+                .feed(sample)
+                .and(self.adaptive_proportion.feed(sample));
+            if let Err(failure) = result {
+                self.failure = Some(failure);
+                return Err(failure);
+            }
+        }
+        Ok(seed)
+    }
+}
+
+impl<S: Seeder> Seeder for HealthTestedSeeder<S> {
+    fn seed(&mut self) -> Seed {
+        self.try_seed()
+            .unwrap_or_else(|err| panic!("Entropy health test failure: {err}"))
+    }
+
+    fn is_available() -> bool {
+        S::is_available()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::generic_tests::check_seeder_fixed_sequences_different;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    struct RngSeeder(StdRng);
+
+    impl Seeder for RngSeeder {
+        fn seed(&mut self) -> Seed {
+            Seed(self.0.gen())
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    struct ConstantSeeder(u128);
+
+    impl Seeder for ConstantSeeder {
+        fn seed(&mut self) -> Seed {
+            Seed(self.0)
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    /// A seeder which gets stuck on zero after a given number of seeds.
+    struct StuckSeeder {
+        rng: StdRng,
+        remaining: usize,
+    }
+
+    impl Seeder for StuckSeeder {
+        fn seed(&mut self) -> Seed {
+            if self.remaining == 0 {
+                return Seed(0);
+            }
+            self.remaining -= 1;
+            Seed(self.rng.gen())
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn check_bounded_sequence_difference() {
+        check_seeder_fixed_sequences_different(|i| {
+            HealthTestedSeeder::new(RngSeeder(StdRng::seed_from_u64(i as u64)))
+        });
+    }
+
+    #[test]
+    fn test_cutoffs() {
+        // Values from NIST SP 800-90B for a false positive probability of 2⁻⁴⁰.
+        assert_eq!(RepetitionCountTest::new(8.).cutoff, 6);
+        assert_eq!(RepetitionCountTest::new(1.).cutoff, 41);
+        // The cutoffs must lie between the expected and the maximal number of occurrences.
+        for min_entropy in [1., 2., 4., 8.] {
+            let cutoff = AdaptiveProportionTest::new(min_entropy).cutoff;
+            let expected = ADAPTIVE_PROPORTION_WINDOW as f64 * (-min_entropy).exp2();
+            assert!(cutoff as f64 > expected);
+            assert!(cutoff <= ADAPTIVE_PROPORTION_WINDOW);
+        }
+    }
+
+    #[test]
+    fn test_critical_binomial() {
+        // Two fair coin flips: P(X > 1) = 1/4 and P(X > 2) = 0.
+        assert_eq!(critical_binomial(2, 0.5, 0.3), 1);
+        assert_eq!(critical_binomial(2, 0.5, 0.2), 2);
+        assert_eq!(critical_binomial(2, 0.5, 0.), 2);
+    }
+
+    #[test]
+    fn test_healthy_source_passes() {
+        let mut seeder = HealthTestedSeeder::new(RngSeeder(StdRng::seed_from_u64(0)));
+        for _ in 0..100_000 {
+            seeder.try_seed().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_stuck_source_fails_repetition_count() {
+        let mut seeder = HealthTestedSeeder::new(ConstantSeeder(0));
+        assert_eq!(seeder.try_seed(), Err(HealthTestError::RepetitionCount));
+    }
+
+    #[test]
+    fn test_biased_source_fails_adaptive_proportion() {
+        // All the bytes of a seed are different, but each appears once every 16 bytes.
+        let bytes: [u8; 16] = std::array::from_fn(|i| i as u8);
+        let mut seeder = HealthTestedSeeder::new(ConstantSeeder(u128::from_le_bytes(bytes)));
+        assert_eq!(seeder.try_seed(), Err(HealthTestError::AdaptiveProportion));
+    }
+
+    #[test]
+    fn test_failure_is_latched() {
+        let mut seeder = HealthTestedSeeder::new(StuckSeeder {
+            rng: StdRng::seed_from_u64(0),
+            remaining: 1000,
+        });
+        while seeder.try_seed().is_ok() {}
+        // Even if the source recovers, the seeder keeps failing.
+        seeder.inner.remaining = usize::MAX;
+        assert_eq!(seeder.try_seed(), Err(HealthTestError::RepetitionCount));
+    }
+
+    #[test]
+    #[should_panic(expected = "Entropy health test failure")]
+    fn test_seed_panics_on_failure() {
+        let mut seeder = HealthTestedSeeder::new(ConstantSeeder(0));
+        seeder.seed();
+    }
+}
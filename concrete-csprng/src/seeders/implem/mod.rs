@@ -12,3 +12,16 @@ pub use rdseed::RdseedSeeder;
 mod unix;
 #[cfg(feature = "seeder_unix")]
 pub use unix::UnixSeeder;
+
+#[cfg(all(feature = "seeder_unix", target_os = "linux"))]
+mod getrandom;
+#[cfg(all(feature = "seeder_unix", target_os = "linux"))]
+pub use getrandom::GetrandomSeeder;
+
+mod health;
+pub use health::{HealthTestError, HealthTestedSeeder};
+
+#[cfg(feature = "seeder_conditioned")]
+mod conditioned;
+#[cfg(feature = "seeder_conditioned")]
+pub use conditioned::ConditionedSeeder;
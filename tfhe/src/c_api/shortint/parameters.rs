@@ -61,6 +61,7 @@ impl TryFrom<ShortintPBSParameters> for crate::shortint::ClassicPBSParameters {
                 c_params.modulus_power_of_2_exponent,
             )?,
             encryption_key_choice: c_params.encryption_key_choice.into(),
+            secret_key_distribution: crate::shortint::parameters::SecretKeyDistribution::Binary,
         })
     }
 }
@@ -7,7 +7,9 @@ use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
-use crate::core_crypto::commons::parameters::PlaintextCount;
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, PlaintextCount,
+};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use rayon::prelude::*;
@@ -111,19 +113,16 @@ pub fn encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     let decomp_base_log = output.decomposition_base_log();
     let ciphertext_modulus = output.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     for (level_index, (mut level_matrix, mut generator)) in
         output.iter_mut().zip(gen_iter).enumerate()
     {
         let decomp_level = DecompositionLevel(level_index + 1);
-        // We scale the factor down from the native torus to whatever our torus is, the
-        // encryption process will scale it back up
-        let factor = encoded
-            .0
-            .wrapping_neg()
-            .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
-            .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+        let factor = ggsw_encryption_multiplicative_factor(
+            ciphertext_modulus,
+            decomp_level,
+            decomp_base_log,
+            encoded,
+        );
 
         // We iterate over the rows of the level matrix, the last row needs special treatment
         let gen_iter = generator
@@ -251,18 +250,15 @@ pub fn par_encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     let decomp_base_log = output.decomposition_base_log();
     let ciphertext_modulus = output.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     output.par_iter_mut().zip(gen_iter).enumerate().for_each(
         |(level_index, (mut level_matrix, mut generator))| {
             let decomp_level = DecompositionLevel(level_index + 1);
-            // We scale the factor down from the native torus to whatever our torus is, the
-            // encryption process will scale it back up
-            let factor = encoded
-                .0
-                .wrapping_neg()
-                .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
-                .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+            let factor = ggsw_encryption_multiplicative_factor(
+                ciphertext_modulus,
+                decomp_level,
+                decomp_base_log,
+                encoded,
+            );
 
             // We iterate over the rows of the level matrix, the last row needs special
             // treatment
@@ -310,6 +306,8 @@ fn encrypt_constant_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Gen>(
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let ciphertext_modulus = row_as_glwe.ciphertext_modulus();
+
     if row_index < last_row_index {
         // Not the last row
         let sk_poly_list = glwe_secret_key.as_polynomial_list();
@@ -319,17 +317,61 @@ fn encrypt_constant_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Gen>(
         let mut body = row_as_glwe.get_mut_body();
         body.as_mut().copy_from_slice(sk_poly.as_ref());
 
-        slice_wrapping_scalar_mul_assign(body.as_mut(), factor);
+        if ciphertext_modulus.is_compatible_with_native_modulus() {
+            slice_wrapping_scalar_mul_assign(body.as_mut(), factor);
+        } else {
+            slice_wrapping_scalar_mul_assign_custom_mod(
+                body.as_mut(),
+                factor,
+                ciphertext_modulus.get_custom_modulus().cast_into(),
+            );
+        }
     } else {
         // The last row needs a slightly different treatment
         let mut body = row_as_glwe.get_mut_body();
 
         body.as_mut().fill(Scalar::ZERO);
-        body.as_mut()[0] = factor.wrapping_neg();
+        body.as_mut()[0] = if ciphertext_modulus.is_compatible_with_native_modulus() {
+            factor.wrapping_neg()
+        } else {
+            factor.wrapping_neg_custom_mod(ciphertext_modulus.get_custom_modulus().cast_into())
+        };
     }
     encrypt_glwe_ciphertext_assign(glwe_secret_key, row_as_glwe, noise_parameters, generator);
 }
 
+/// Compute the factor by which the secret key polynomials are multiplied when encrypting the
+/// level matrix of a given [`DecompositionLevel`] of a [`GGSW ciphertext`](`GgswCiphertext`).
+///
+/// For moduli compatible with the native modulus the factor is $-\mu \frac{q}{B^{level}}$ scaled
+/// down to the custom torus, the encryption process scaling it back up. For other moduli (e.g.
+/// primes) the factor is $-\mu \lfloor \frac{q}{B^{level}} \rfloor \bmod q$, matching the
+/// [`non native decomposition`](`crate::core_crypto::commons::math::decomposition::SignedDecomposerNonNative`).
+fn ggsw_encryption_multiplicative_factor<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    decomp_level: DecompositionLevel,
+    decomp_base_log: DecompositionBaseLog,
+    encoded: Plaintext<Scalar>,
+) -> Scalar {
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        // We scale the factor down from the native torus to whatever our torus is, the
+        // encryption process will scale it back up
+        encoded
+            .0
+            .wrapping_neg()
+            .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
+            .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus())
+    } else {
+        let custom_modulus = ciphertext_modulus.get_custom_modulus();
+        let digit_radix = Scalar::cast_from(custom_modulus >> (decomp_base_log.0 * decomp_level.0));
+        let custom_modulus: Scalar = custom_modulus.cast_into();
+        encoded
+            .0
+            .wrapping_mul_custom_mod(digit_radix, custom_modulus)
+            .wrapping_neg_custom_mod(custom_modulus)
+    }
+}
+
 /// Convenience function to share the core logic of the seeded GGSW encryption between all
 /// functions needing it.
 ///
@@ -374,13 +416,12 @@ pub fn encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
         output.iter_mut().zip(gen_iter).enumerate()
     {
         let decomp_level = DecompositionLevel(level_index + 1);
-        // We scale the factor down from the native torus to whatever our torus is, the
-        // encryption process will scale it back up
-        let factor = encoded
-            .0
-            .wrapping_neg()
-            .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
-            .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+        let factor = ggsw_encryption_multiplicative_factor(
+            ciphertext_modulus,
+            decomp_level,
+            decomp_base_log,
+            encoded,
+        );
 
         // We iterate over the rows of the level matrix, the last row needs special treatment
         let gen_iter = loop_generator
@@ -550,13 +591,12 @@ pub fn par_encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
     output.par_iter_mut().zip(gen_iter).enumerate().for_each(
         |(level_index, (mut level_matrix, mut generator))| {
             let decomp_level = DecompositionLevel(level_index + 1);
-            // We scale the factor down from the native torus to whatever our torus is, the
-            // encryption process will scale it back up
-            let factor = encoded
-                .0
-                .wrapping_neg()
-                .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
-                .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+            let factor = ggsw_encryption_multiplicative_factor(
+                ciphertext_modulus,
+                decomp_level,
+                decomp_base_log,
+                encoded,
+            );
 
             // We iterate over the rows of the level matrix, the last row needs special treatment
             let gen_iter = generator
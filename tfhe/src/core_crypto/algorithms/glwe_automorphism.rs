@@ -0,0 +1,775 @@
+//! Module containing primitives pertaining to the evaluation of automorphisms $X \mapsto X^{k}$ on
+//! [`GLWE ciphertexts`](`GlweCiphertext`), the homomorphic trace and the packing of
+//! [`LWE ciphertexts`](`LweCiphertext`) in a [`GLWE ciphertext`](`GlweCiphertext`) using
+//! automorphisms, usually referred to as _ring packing_ in the literature.
+
+use crate::core_crypto::algorithms::glwe_linear_algebra::{
+    glwe_ciphertext_add_assign, glwe_ciphertext_sub_assign,
+};
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Evaluate the automorphism $X \mapsto X^{k}$ on all the polynomials of an input
+/// [`GLWE ciphertext`](`GlweCiphertext`), $k$ being the odd `automorphism_index`.
+///
+/// If the input is encrypted under the secret key $(S\_1, \ldots, S\_k)$ and encrypts $M(X)$, the
+/// output encrypts $M(X^{k})$ under the secret key $(S\_1(X^{k}), \ldots, S\_k(X^{k}))$. Use
+/// [`glwe_ciphertext_automorphism_with_keyswitch`] to get an output encrypted under the original
+/// secret key.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext, the monomial 3 * X
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *plaintext_list.get_mut(1).0 = 3 << 60;
+///
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let automorphism_index = 5;
+///
+/// let mut output_glwe = glwe.clone();
+/// glwe_ciphertext_automorphism(&glwe, &mut output_glwe, automorphism_index);
+///
+/// // The output is encrypted under the image of the secret key by the automorphism
+/// let mut output_secret_key = GlweSecretKey::new_empty_key(
+///     0u64,
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+/// );
+/// for (output_chunk, input_polynomial) in output_secret_key
+///     .as_mut()
+///     .chunks_exact_mut(polynomial_size.0)
+///     .zip(glwe_secret_key.as_polynomial_list().iter())
+/// {
+///     polynomial_wrapping_automorphism(
+///         &mut Polynomial::from_container(output_chunk),
+///         &input_polynomial,
+///         automorphism_index,
+///     );
+/// }
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&output_secret_key, &output_glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the monomial 3 * X^5
+/// assert_eq!(*output_plaintext_list.get(5).0, 3);
+/// assert!(output_plaintext_list
+///     .iter()
+///     .enumerate()
+///     .all(|(index, x)| index == 5 || *x.0 == 0));
+/// ```
+pub fn glwe_ciphertext_automorphism<Scalar, InputCont, OutputCont>(
+    input: &GlweCiphertext<InputCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    automorphism_index: usize,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?}) GlweCiphertext",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+    assert!(
+        input.ciphertext_modulus().is_native_modulus(),
+        "GLWE automorphism only supports the native modulus, got {:?}",
+        input.ciphertext_modulus()
+    );
+    assert_eq!(
+        input.glwe_size(),
+        output.glwe_size(),
+        "Mismatched GlweSize between input ({:?}) and output ({:?}) GlweCiphertext",
+        input.glwe_size(),
+        output.glwe_size()
+    );
+    assert_eq!(
+        input.polynomial_size(),
+        output.polynomial_size(),
+        "Mismatched PolynomialSize between input ({:?}) and output ({:?}) GlweCiphertext",
+        input.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    for (mut output_polynomial, input_polynomial) in output
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(input.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_automorphism(
+            &mut output_polynomial,
+            &input_polynomial,
+            automorphism_index,
+        );
+    }
+}
+
+/// Evaluate the automorphism $X \mapsto X^{k}$ on an input [`GLWE ciphertext`](`GlweCiphertext`)
+/// and keyswitch the result back to the original secret key using a
+/// [`GLWE automorphism key`](`GlweAutomorphismKey`), $k$ being the automorphism index of the key.
+///
+/// If the input encrypts $M(X)$, the output encrypts $M(X^{k})$ under the secret key used to
+/// generate the automorphism key.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey and the GlweAutomorphismKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let automorphism_index = 5;
+///
+/// let automorphism_key = allocate_and_generate_new_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     automorphism_index,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext, the monomial 3 * X
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *plaintext_list.get_mut(1).0 = 3 << 60;
+///
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = glwe.clone();
+/// glwe_ciphertext_automorphism_with_keyswitch(&glwe, &automorphism_key, &mut output_glwe);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &output_glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the monomial 3 * X^5
+/// assert_eq!(*output_plaintext_list.get(5).0, 3);
+/// assert!(output_plaintext_list
+///     .iter()
+///     .enumerate()
+///     .all(|(index, x)| index == 5 || *x.0 == 0));
+/// ```
+pub fn glwe_ciphertext_automorphism_with_keyswitch<Scalar, InputCont, KeyCont, OutputCont>(
+    input: &GlweCiphertext<InputCont>,
+    glwe_automorphism_key: &GlweAutomorphismKey<KeyCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        glwe_automorphism_key.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and GlweAutomorphismKey ({:?})",
+        input.ciphertext_modulus(),
+        glwe_automorphism_key.ciphertext_modulus()
+    );
+    assert_eq!(
+        output.ciphertext_modulus(),
+        glwe_automorphism_key.ciphertext_modulus(),
+        "Mismatched moduli between output ({:?}) and GlweAutomorphismKey ({:?})",
+        output.ciphertext_modulus(),
+        glwe_automorphism_key.ciphertext_modulus()
+    );
+    assert_eq!(
+        input.glwe_size(),
+        glwe_automorphism_key.glwe_size(),
+        "Mismatched GlweSize between input ({:?}) and GlweAutomorphismKey ({:?})",
+        input.glwe_size(),
+        glwe_automorphism_key.glwe_size()
+    );
+    assert_eq!(
+        output.glwe_size(),
+        glwe_automorphism_key.glwe_size(),
+        "Mismatched GlweSize between output ({:?}) and GlweAutomorphismKey ({:?})",
+        output.glwe_size(),
+        glwe_automorphism_key.glwe_size()
+    );
+    assert_eq!(
+        input.polynomial_size(),
+        glwe_automorphism_key.polynomial_size(),
+        "Mismatched PolynomialSize between input ({:?}) and GlweAutomorphismKey ({:?})",
+        input.polynomial_size(),
+        glwe_automorphism_key.polynomial_size()
+    );
+    assert_eq!(
+        output.polynomial_size(),
+        glwe_automorphism_key.polynomial_size(),
+        "Mismatched PolynomialSize between output ({:?}) and GlweAutomorphismKey ({:?})",
+        output.polynomial_size(),
+        glwe_automorphism_key.polynomial_size()
+    );
+
+    let glwe_dimension = glwe_automorphism_key.glwe_dimension().0;
+    let polynomial_size = glwe_automorphism_key.polynomial_size();
+    let decomp_level_count = glwe_automorphism_key.decomposition_level_count();
+
+    // The input with the automorphism applied, encrypted under the image of the secret key
+    let mut automorphism_image = GlweCiphertext::new(
+        Scalar::ZERO,
+        input.glwe_size(),
+        polynomial_size,
+        input.ciphertext_modulus(),
+    );
+    glwe_ciphertext_automorphism(
+        input,
+        &mut automorphism_image,
+        glwe_automorphism_key.automorphism_index(),
+    );
+
+    // The body is copied as is, the mask is rebuilt from the automorphism key
+    output.as_mut().fill(Scalar::ZERO);
+    output
+        .as_mut_polynomial_list()
+        .get_mut(glwe_dimension)
+        .as_mut()
+        .copy_from_slice(
+            automorphism_image
+                .as_polynomial_list()
+                .get(glwe_dimension)
+                .as_ref(),
+        );
+
+    // We instantiate a decomposer
+    let decomposer = SignedDecomposer::new(
+        glwe_automorphism_key.decomposition_base_log(),
+        decomp_level_count,
+    );
+
+    // The decomposition of a polynomial, one polynomial per level
+    let mut decomposition = PolynomialList::new(
+        Scalar::ZERO,
+        polynomial_size,
+        PolynomialCount(decomp_level_count.0),
+    );
+
+    for (mask_polynomial, automorphism_key_block) in automorphism_image
+        .as_polynomial_list()
+        .iter()
+        .take(glwe_dimension)
+        .zip(glwe_automorphism_key.iter())
+    {
+        // We decompose
+        for (coefficient_index, &coefficient) in mask_polynomial.as_ref().iter().enumerate() {
+            let rounded = decomposer.closest_representable(coefficient);
+            for decomposed in decomposer.decompose(rounded) {
+                decomposition.get_mut(decomposed.level().0 - 1).as_mut()[coefficient_index] =
+                    decomposed.value();
+            }
+        }
+
+        // Loop over the levels, multiply the ciphertexts of the automorphism key with the
+        // corresponding piece of the decomposition and subtract it to the output
+        for (level_key_ciphertext, level_decomposition) in automorphism_key_block
+            .iter()
+            .rev()
+            .zip(decomposition.iter())
+        {
+            for (mut output_polynomial, key_polynomial) in output
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(level_key_ciphertext.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_sub_mul_assign(
+                    &mut output_polynomial,
+                    &key_polynomial,
+                    &level_decomposition,
+                );
+            }
+        }
+    }
+}
+
+/// Return the key evaluating the automorphism of index `automorphism_index` from a slice of
+/// [`GLWE automorphism keys`](`GlweAutomorphismKey`).
+fn find_glwe_automorphism_key<KeyCont: Container>(
+    glwe_automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+    automorphism_index: usize,
+) -> &GlweAutomorphismKey<KeyCont>
+where
+    KeyCont::Element: UnsignedInteger,
+{
+    glwe_automorphism_keys
+        .iter()
+        .find(|key| key.automorphism_index() == automorphism_index)
+        .unwrap_or_else(|| {
+            panic!(
+                "No GlweAutomorphismKey provided for the automorphism index {automorphism_index}"
+            )
+        })
+}
+
+/// Compute the partial trace from $\mathbb{Z}\_q[X]/(X^{N}+1)$ to the subring generated by
+/// $X^{N / 2^{l}}$, $l$ being `output_log_degree`, i.e. apply
+/// $c \leftarrow c + \tau\_{2^{j} + 1}(c)$ for $j$ going from $\log\_2(N)$ down to $l + 1$.
+fn glwe_ciphertext_partial_trace_assign<Scalar, InputCont, KeyCont>(
+    glwe: &mut GlweCiphertext<InputCont>,
+    glwe_automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+    output_log_degree: u32,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: ContainerMut<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    let mut automorphism_image = GlweCiphertext::new(
+        Scalar::ZERO,
+        glwe.glwe_size(),
+        glwe.polynomial_size(),
+        glwe.ciphertext_modulus(),
+    );
+
+    for log_index in (output_log_degree + 1..=glwe.polynomial_size().0.ilog2()).rev() {
+        let automorphism_key =
+            find_glwe_automorphism_key(glwe_automorphism_keys, (1 << log_index) + 1);
+
+        glwe_ciphertext_automorphism_with_keyswitch(
+            &*glwe,
+            automorphism_key,
+            &mut automorphism_image,
+        );
+        glwe_ciphertext_add_assign(glwe, &automorphism_image);
+    }
+}
+
+/// Compute the homomorphic trace of a [`GLWE ciphertext`](`GlweCiphertext`) in place, using the
+/// [`GLWE automorphism keys`](`GlweAutomorphismKey`) for the automorphisms
+/// $X \mapsto X^{2^{l} + 1}$ for $1 \leq l \leq \log\_2(N)$, as generated by
+/// [`allocate_and_generate_new_glwe_trace_automorphism_keys`](`super::allocate_and_generate_new_glwe_trace_automorphism_keys`).
+///
+/// If the input encrypts $M(X) = \sum\_{i} m\_i X^{i}$, the output encrypts the constant
+/// polynomial $N \cdot m\_0$, all other coefficients are cancelled. The encoding of the input must
+/// leave room for the $\log\_2(N)$ additional bits of the result.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey and the automorphism keys
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let automorphism_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // 4 bits of message without padding, the trace multiplies the message by N = 2^10
+/// let delta = 1u64 << (60 - 10);
+/// let msg = 3u64;
+///
+/// // Create the plaintext, all coefficients are set to the message
+/// let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+///
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// glwe_ciphertext_trace_assign(&mut glwe, &automorphism_keys);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Only the constant coefficient is kept
+/// assert_eq!(*output_plaintext_list.get(0).0, msg);
+/// assert!(output_plaintext_list.iter().skip(1).all(|x| *x.0 == 0));
+/// ```
+pub fn glwe_ciphertext_trace_assign<Scalar, InputCont, KeyCont>(
+    glwe: &mut GlweCiphertext<InputCont>,
+    glwe_automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+) where
+    Scalar: UnsignedTorus,
+    InputCont: ContainerMut<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    assert!(
+        glwe.polynomial_size().0.is_power_of_two(),
+        "GLWE trace requires a power of two PolynomialSize, got {:?}",
+        glwe.polynomial_size()
+    );
+
+    glwe_ciphertext_partial_trace_assign(glwe, glwe_automorphism_keys, 0);
+}
+
+/// Write in a [`GLWE ciphertext`](`GlweCiphertext`) an encryption of a polynomial whose constant
+/// coefficient is the message encrypted by an input [`LWE ciphertext`](`LweCiphertext`), the LWE
+/// secret key being the GLWE secret key seen as an LWE secret key. This is the inverse of a sample
+/// extraction of the constant coefficient, the other coefficients of the output are not
+/// meaningful.
+fn lwe_ciphertext_to_constant_coefficient_glwe_ciphertext<Scalar, InputCont, OutputCont>(
+    input_lwe: &LweCiphertext<InputCont>,
+    output_glwe: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = output_glwe.polynomial_size().0;
+    let (input_mask, input_body) = input_lwe.get_mask_and_body();
+    let (mut output_mask, mut output_body) = output_glwe.get_mut_mask_and_body();
+
+    // A_i[0] = a_{i, 0} and A_i[N - j] = -a_{i, j} for 0 < j < N
+    for (output_mask_polynomial, input_mask_chunk) in output_mask
+        .as_mut()
+        .chunks_exact_mut(polynomial_size)
+        .zip(input_mask.as_ref().chunks_exact(polynomial_size))
+    {
+        output_mask_polynomial[0] = input_mask_chunk[0];
+        for (output_coefficient, &input_coefficient) in output_mask_polynomial[1..]
+            .iter_mut()
+            .zip(input_mask_chunk[1..].iter().rev())
+        {
+            *output_coefficient = input_coefficient.wrapping_neg();
+        }
+    }
+
+    output_body.as_mut().fill(Scalar::ZERO);
+    output_body.as_mut()[0] = *input_body.data;
+}
+
+/// Pack an [`LWE ciphertext list`](`LweCiphertextList`) in a
+/// [`GLWE ciphertext`](`GlweCiphertext`) using $\log\_2(N)$ levels of automorphisms instead of one
+/// keyswitch per input ciphertext.
+///
+/// The input LWE ciphertexts must be encrypted under the GLWE secret key seen as an LWE secret
+/// key, their count $n$ must be a power of two smaller than or equal to the polynomial size $N$.
+/// The [`GLWE automorphism keys`](`GlweAutomorphismKey`) for the automorphisms
+/// $X \mapsto X^{2^{l} + 1}$ for $1 \leq l \leq \log\_2(N)$ must be provided, see
+/// [`allocate_and_generate_new_glwe_trace_automorphism_keys`](`super::allocate_and_generate_new_glwe_trace_automorphism_keys`).
+///
+/// If the $j$-th input encrypts $m\_j$, the output encrypts
+/// $\sum\_{j} N \cdot m\_j X^{j \cdot N / n}$, other coefficients are zero. The encoding of the
+/// inputs must leave room for the $\log\_2(N)$ additional bits of the result.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey and the automorphism keys
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let automorphism_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // 4 bits of message without padding, the packing multiplies the messages by N = 2^10
+/// let delta = 1u64 << (60 - 10);
+/// let msgs = [1u64, 3, 5, 7];
+///
+/// // Encrypt the messages under the GLWE secret key seen as an LWE secret key
+/// let lwe_secret_key = glwe_secret_key.as_lwe_secret_key();
+/// let mut lwe_list = LweCiphertextList::new(
+///     0u64,
+///     lwe_secret_key.lwe_dimension().to_lwe_size(),
+///     LweCiphertextCount(msgs.len()),
+///     ciphertext_modulus,
+/// );
+/// let plaintext_list =
+///     PlaintextList::from_container(msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>());
+///
+/// encrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &mut lwe_list,
+///     &plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut packed_glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// pack_lwe_ciphertext_list_into_glwe_ciphertext(&lwe_list, &automorphism_keys, &mut packed_glwe);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &packed_glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // The messages are spread over the coefficients with a stride of N / 4
+/// let stride = polynomial_size.0 / msgs.len();
+/// for (index, x) in output_plaintext_list.iter().enumerate() {
+///     if index % stride == 0 {
+///         assert_eq!(*x.0, msgs[index / stride]);
+///     } else {
+///         assert_eq!(*x.0, 0);
+///     }
+/// }
+/// ```
+pub fn pack_lwe_ciphertext_list_into_glwe_ciphertext<Scalar, InputCont, KeyCont, OutputCont>(
+    input_lwe_list: &LweCiphertextList<InputCont>,
+    glwe_automorphism_keys: &[GlweAutomorphismKey<KeyCont>],
+    output_glwe: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let glwe_size = output_glwe.glwe_size();
+    let polynomial_size = output_glwe.polynomial_size();
+    let ciphertext_modulus = output_glwe.ciphertext_modulus();
+    let lwe_count = input_lwe_list.lwe_ciphertext_count().0;
+
+    assert_eq!(
+        input_lwe_list.ciphertext_modulus(),
+        ciphertext_modulus,
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input_lwe_list.ciphertext_modulus(),
+        ciphertext_modulus
+    );
+    assert!(
+        polynomial_size.0.is_power_of_two(),
+        "LWE ring packing requires a power of two PolynomialSize, got {polynomial_size:?}"
+    );
+    assert!(
+        lwe_count.is_power_of_two() && lwe_count <= polynomial_size.0,
+        "LWE ring packing requires a power of two input LweCiphertextCount smaller than or \
+        equal to the output PolynomialSize {polynomial_size:?}, got {lwe_count}"
+    );
+    assert_eq!(
+        input_lwe_list.lwe_size().to_lwe_dimension().0,
+        glwe_size.to_glwe_dimension().0 * polynomial_size.0,
+        "Mismatched input LweDimension {:?}, expected the GlweDimension {:?} times the \
+        PolynomialSize {:?} of the output",
+        input_lwe_list.lwe_size().to_lwe_dimension(),
+        glwe_size.to_glwe_dimension(),
+        polynomial_size
+    );
+
+    let mut glwe_list = GlweCiphertextList::new(
+        Scalar::ZERO,
+        glwe_size,
+        polynomial_size,
+        GlweCiphertextCount(lwe_count),
+        ciphertext_modulus,
+    );
+
+    for (input_lwe, mut glwe) in input_lwe_list.iter().zip(glwe_list.iter_mut()) {
+        lwe_ciphertext_to_constant_coefficient_glwe_ciphertext(&input_lwe, &mut glwe);
+    }
+
+    // Buffers for X^{N / 2^l} odd, even - X^{N / 2^l} odd and its image by the automorphism
+    let mut rotated_odd =
+        GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+    let mut difference = rotated_odd.clone();
+    let mut automorphism_image = rotated_odd.clone();
+
+    // At step l the ciphertexts j and j + n / 2^l of the current list are merged in the
+    // ciphertext j, which ends with the messages j + k * n / 2^l at the coefficients
+    // k * N / 2^l, multiplied by 2^l:
+    // even + X^{N / 2^l} odd + tau_{2^l + 1}(even - X^{N / 2^l} odd)
+    let mut current_count = lwe_count;
+    let mut log_step = 1u32;
+    while current_count > 1 {
+        let half_count = current_count / 2;
+        let monomial_degree = MonomialDegree(polynomial_size.0 >> log_step);
+        let automorphism_key =
+            find_glwe_automorphism_key(glwe_automorphism_keys, (1 << log_step) + 1);
+
+        for even_index in 0..half_count {
+            let odd_glwe = glwe_list.get(even_index + half_count);
+            for (mut rotated_polynomial, odd_polynomial) in rotated_odd
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(odd_glwe.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_monic_monomial_mul(
+                    &mut rotated_polynomial,
+                    &odd_polynomial,
+                    monomial_degree,
+                );
+            }
+
+            let mut even_glwe = glwe_list.get_mut(even_index);
+
+            difference.as_mut().copy_from_slice(even_glwe.as_ref());
+            glwe_ciphertext_sub_assign(&mut difference, &rotated_odd);
+
+            glwe_ciphertext_automorphism_with_keyswitch(
+                &difference,
+                automorphism_key,
+                &mut automorphism_image,
+            );
+
+            glwe_ciphertext_add_assign(&mut even_glwe, &rotated_odd);
+            glwe_ciphertext_add_assign(&mut even_glwe, &automorphism_image);
+        }
+
+        current_count = half_count;
+        log_step += 1;
+    }
+
+    output_glwe
+        .as_mut()
+        .copy_from_slice(glwe_list.get(0).as_ref());
+
+    // The remaining coefficients are cancelled by the partial trace, which multiplies the messages
+    // by N / n
+    glwe_ciphertext_partial_trace_assign(output_glwe, glwe_automorphism_keys, lwe_count.ilog2());
+}
@@ -0,0 +1,233 @@
+//! Module containing primitives pertaining to the generation of
+//! [`GLWE automorphism keys`](`GlweAutomorphismKey`).
+
+use crate::core_crypto::algorithms::encrypt_glwe_ciphertext_list;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::core_crypto::commons::math::random::ByteRandomGenerator;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::{
+    GlweAutomorphismKey, GlweAutomorphismKeyOwned, GlweSecretKey, PlaintextListOwned, Polynomial,
+};
+
+/// Fill a [`GLWE automorphism key`](`GlweAutomorphismKey`) with an actual automorphism key
+/// constructed from a [`GLWE secret key`](`GlweSecretKey`).
+///
+/// For each secret key polynomial $S\_i$, one GLWE ciphertext per decomposition level is generated,
+/// encrypting $S\_i(X^{k})$ multiplied by the corresponding power of the decomposition base, $k$
+/// being the automorphism index of the output key.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_dimension = GlweDimension(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let automorphism_index = 5;
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut automorphism_key = GlweAutomorphismKey::new(
+///     0u64,
+///     automorphism_index,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// generate_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     &mut automorphism_key,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// assert!(automorphism_key.as_ref().iter().all(|&x| x == 0) == false);
+/// ```
+pub fn generate_glwe_automorphism_key<Scalar, KeyCont, AkCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    glwe_automorphism_key: &mut GlweAutomorphismKey<AkCont>,
+    noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    AkCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        glwe_automorphism_key.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "The destination GlweAutomorphismKey GlweDimension is not equal \
+    to the GlweSecretKey GlweDimension. Destination: {:?}, key: {:?}",
+        glwe_automorphism_key.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    assert!(
+        glwe_automorphism_key.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "The destination GlweAutomorphismKey PolynomialSize is not equal \
+        to the GlweSecretKey PolynomialSize. Destination: {:?}, key: {:?}",
+        glwe_automorphism_key.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let automorphism_index = glwe_automorphism_key.automorphism_index();
+    let decomp_base_log = glwe_automorphism_key.decomposition_base_log();
+    let decomp_level_count = glwe_automorphism_key.decomposition_level_count();
+    let polynomial_size = glwe_automorphism_key.polynomial_size();
+    let ciphertext_modulus = glwe_automorphism_key.ciphertext_modulus();
+    assert!(
+        ciphertext_modulus.is_native_modulus(),
+        "GlweAutomorphismKey generation only supports the native modulus, got {:?}",
+        ciphertext_modulus
+    );
+
+    // The image of a secret key polynomial by the automorphism will be stored in this buffer
+    let mut automorphism_image = Polynomial::new(Scalar::ZERO, polynomial_size);
+
+    // The plaintexts used to encrypt the image of a secret key polynomial will be stored in this
+    // buffer
+    let mut decomposition_plaintexts_buffer = PlaintextListOwned::new(
+        Scalar::ZERO,
+        PlaintextCount(decomp_level_count.0 * polynomial_size.0),
+    );
+
+    // Iterate over the secret key polynomials and the destination glwe_automorphism_key memory
+    for (secret_polynomial, mut automorphism_key_block) in glwe_secret_key
+        .as_polynomial_list()
+        .iter()
+        .zip(glwe_automorphism_key.iter_mut())
+    {
+        polynomial_wrapping_automorphism(
+            &mut automorphism_image,
+            &secret_polynomial,
+            automorphism_index,
+        );
+
+        // We fill the buffer with the image scaled by the powers of the decomposition base
+        for (level, mut messages) in (1..=decomp_level_count.0)
+            .rev()
+            .map(DecompositionLevel)
+            .zip(decomposition_plaintexts_buffer.chunks_exact_mut(polynomial_size.0))
+        {
+            for (message, &secret_coefficient) in
+                messages.iter_mut().zip(automorphism_image.as_ref().iter())
+            {
+                *message.0 = DecompositionTerm::new(level, decomp_base_log, secret_coefficient)
+                    .to_recomposition_summand();
+            }
+        }
+
+        encrypt_glwe_ciphertext_list(
+            glwe_secret_key,
+            &mut automorphism_key_block,
+            &decomposition_plaintexts_buffer,
+            noise_parameters,
+            generator,
+        );
+    }
+}
+
+/// Allocate a new [`GLWE automorphism key`](`GlweAutomorphismKey`) and fill it with an actual
+/// automorphism key constructed from a [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`glwe_ciphertext_automorphism_with_keyswitch`](`super::glwe_ciphertext_automorphism_with_keyswitch`)
+/// for usage.
+pub fn allocate_and_generate_new_glwe_automorphism_key<Scalar, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_index: usize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweAutomorphismKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_glwe_automorphism_key = GlweAutomorphismKeyOwned::new(
+        Scalar::ZERO,
+        automorphism_index,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        glwe_secret_key.polynomial_size(),
+        ciphertext_modulus,
+    );
+
+    generate_glwe_automorphism_key(
+        glwe_secret_key,
+        &mut new_glwe_automorphism_key,
+        noise_parameters,
+        generator,
+    );
+
+    new_glwe_automorphism_key
+}
+
+/// Allocate and generate the [`GLWE automorphism keys`](`GlweAutomorphismKey`) required by
+/// [`glwe_ciphertext_trace_assign`](`super::glwe_ciphertext_trace_assign`) and
+/// [`pack_lwe_ciphertext_list_into_glwe_ciphertext`](`super::pack_lwe_ciphertext_list_into_glwe_ciphertext`),
+/// i.e. the keys for the automorphisms $X \mapsto X^{2^{l} + 1}$ for $1 \leq l \leq \log\_2(N)$.
+///
+/// The keys are returned in increasing order of automorphism index.
+///
+/// See [`glwe_ciphertext_trace_assign`](`super::glwe_ciphertext_trace_assign`) for usage.
+pub fn allocate_and_generate_new_glwe_trace_automorphism_keys<Scalar, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Vec<GlweAutomorphismKeyOwned<Scalar>>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let polynomial_size = glwe_secret_key.polynomial_size();
+    assert!(
+        polynomial_size.0.is_power_of_two(),
+        "Trace automorphism keys require a power of two PolynomialSize, got {polynomial_size:?}"
+    );
+
+    (1..=polynomial_size.0.ilog2())
+        .map(|log_index| {
+            allocate_and_generate_new_glwe_automorphism_key(
+                glwe_secret_key,
+                (1 << log_index) + 1,
+                decomp_base_log,
+                decomp_level_count,
+                noise_parameters,
+                ciphertext_modulus,
+                generator,
+            )
+        })
+        .collect()
+}
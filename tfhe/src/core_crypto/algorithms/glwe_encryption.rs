@@ -1,12 +1,15 @@
 //! Module containing primitives pertaining to [`GLWE ciphertext
 //! encryption`](`GlweCiphertext#glwe-encryption`).
 
+use crate::core_crypto::algorithms::decompress_seeded_glwe_ciphertext_with_existing_generator;
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::{
     slice_wrapping_scalar_div_assign, slice_wrapping_scalar_mul_assign,
 };
 use crate::core_crypto::commons::dispersion::DispersionParameter;
-use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::generators::{
+    EncryptionRandomGenerator, MaskRandomGenerator, SecretRandomGenerator,
+};
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
@@ -37,8 +40,6 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
 
     let ciphertext_modulus = output_body.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     generator.fill_slice_with_random_mask_custom_mod(output_mask.as_mut(), ciphertext_modulus);
     generator.unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign(
         output_body.as_mut(),
@@ -46,6 +47,16 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
         ciphertext_modulus,
     );
 
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        polynomial_wrapping_add_multisum_assign_custom_mod(
+            &mut output_body.as_mut_polynomial(),
+            &output_mask.as_polynomial_list(),
+            &glwe_secret_key.as_polynomial_list(),
+            ciphertext_modulus.get_custom_modulus().cast_into(),
+        );
+        return;
+    }
+
     if !ciphertext_modulus.is_native_modulus() {
         let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
         slice_wrapping_scalar_mul_assign(output_mask.as_mut(), torus_scaling);
@@ -252,8 +263,6 @@ pub fn fill_glwe_mask_and_body_for_encryption<KeyCont, InputCont, BodyCont, Mask
 
     let ciphertext_modulus = output_body.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     generator.fill_slice_with_random_mask_custom_mod(output_mask.as_mut(), ciphertext_modulus);
     generator.fill_slice_with_random_noise_custom_mod(
         output_body.as_mut(),
@@ -261,6 +270,23 @@ pub fn fill_glwe_mask_and_body_for_encryption<KeyCont, InputCont, BodyCont, Mask
         ciphertext_modulus,
     );
 
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        // Plaintexts are already expressed modulo the ciphertext modulus
+        let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+        polynomial_wrapping_add_assign_custom_mod(
+            &mut output_body.as_mut_polynomial(),
+            &encoded.as_polynomial(),
+            custom_modulus,
+        );
+        polynomial_wrapping_add_multisum_assign_custom_mod(
+            &mut output_body.as_mut_polynomial(),
+            &output_mask.as_polynomial_list(),
+            &glwe_secret_key.as_polynomial_list(),
+            custom_modulus,
+        );
+        return;
+    }
+
     polynomial_wrapping_add_assign(
         &mut output_body.as_mut_polynomial(),
         &encoded.as_polynomial(),
@@ -576,12 +602,21 @@ pub fn decrypt_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
 
     let ciphertext_modulus = input_glwe_ciphertext.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     let (mask, body) = input_glwe_ciphertext.get_mask_and_body();
     output_plaintext_list
         .as_mut()
         .copy_from_slice(body.as_ref());
+
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        polynomial_wrapping_sub_multisum_assign_custom_mod(
+            &mut output_plaintext_list.as_mut_polynomial(),
+            &mask.as_polynomial_list(),
+            &glwe_secret_key.as_polynomial_list(),
+            ciphertext_modulus.get_custom_modulus().cast_into(),
+        );
+        return;
+    }
+
     polynomial_wrapping_sub_multisum_assign(
         &mut output_plaintext_list.as_mut_polynomial(),
         &mask.as_polynomial_list(),
@@ -726,9 +761,11 @@ pub fn trivially_encrypt_glwe_ciphertext<Scalar, InputCont, OutputCont>(
 
     let ciphertext_modulus = body.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
-    if !ciphertext_modulus.is_native_modulus() {
+    // Plaintexts for moduli which are not compatible with the native torus are already expressed
+    // modulo the ciphertext modulus and need no scaling
+    if ciphertext_modulus.is_compatible_with_native_modulus()
+        && !ciphertext_modulus.is_native_modulus()
+    {
         slice_wrapping_scalar_mul_assign(
             body.as_mut(),
             ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
@@ -809,8 +846,6 @@ where
     Scalar: UnsignedTorus,
     InputCont: Container<Element = Scalar>,
 {
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     let polynomial_size = PolynomialSize(encoded.plaintext_count().0);
 
     let mut new_ct =
@@ -819,7 +854,11 @@ where
     let mut body = new_ct.get_mut_body();
     body.as_mut().copy_from_slice(encoded.as_ref());
 
-    if !ciphertext_modulus.is_native_modulus() {
+    // Plaintexts for moduli which are not compatible with the native torus are already expressed
+    // modulo the ciphertext modulus and need no scaling
+    if ciphertext_modulus.is_compatible_with_native_modulus()
+        && !ciphertext_modulus.is_native_modulus()
+    {
         slice_wrapping_scalar_mul_assign(
             body.as_mut(),
             ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
@@ -1178,3 +1217,349 @@ pub fn encrypt_seeded_glwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCon
         &mut generator,
     );
 }
+
+/// Add the product of a [`GLWE ciphertext`](`GlweCiphertext`) with a polynomial to an output
+/// [`GLWE ciphertext`](`GlweCiphertext`).
+fn glwe_ciphertext_polynomial_add_mul_assign<Scalar, OutputCont, InputCont, PolyCont>(
+    output: &mut GlweCiphertext<OutputCont>,
+    input: &GlweCiphertext<InputCont>,
+    polynomial: &Polynomial<PolyCont>,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    PolyCont: Container<Element = Scalar>,
+{
+    for (mut output_polynomial, input_polynomial) in output
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(input.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_add_mul_assign(&mut output_polynomial, &input_polynomial, polynomial);
+    }
+}
+
+/// Add an encoded [`PlaintextList`] to the body of a [`GLWE ciphertext`](`GlweCiphertext`) whose
+/// mask and body already contain an encryption of zero.
+fn glwe_ciphertext_add_encoded_to_body_assign<Scalar, OutputCont, InputCont>(
+    output: &mut GlweCiphertext<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    let ciphertext_modulus = output.ciphertext_modulus();
+    let mut body = output.get_mut_body();
+
+    if ciphertext_modulus.is_native_modulus() {
+        for (body_coefficient, &plaintext) in body.as_mut().iter_mut().zip(encoded.as_ref()) {
+            *body_coefficient = (*body_coefficient).wrapping_add(plaintext);
+        }
+    } else {
+        let scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+        for (body_coefficient, &plaintext) in body.as_mut().iter_mut().zip(encoded.as_ref()) {
+            *body_coefficient = (*body_coefficient).wrapping_add(plaintext.wrapping_mul(scaling));
+        }
+    }
+}
+
+/// Encrypt an input [`PlaintextList`] in an output [`GLWE ciphertext`](`GlweCiphertext`) using a
+/// [`GLWE public key`](`GlwePublicKey`). The ciphertext can be decrypted using the
+/// [`GLWE secret key`](`GlweSecretKey`) that was used to generate the public key.
+///
+/// The output is the sum of the products of each encryption of zero contained in the public key
+/// with a fresh uniformly random binary polynomial, to which the plaintexts are added.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let zero_encryption_count = GlwePublicKeyZeroEncryptionCount(glwe_size.0 * 64 + 128);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let glwe_public_key = allocate_and_generate_new_glwe_public_key(
+///     &glwe_secret_key,
+///     zero_encryption_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext_with_public_key(
+///     &glwe_public_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     &mut secret_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0));
+///
+/// // Get the raw vector
+/// let mut cleartext_list = output_plaintext_list.into_container();
+/// // Remove the encoding
+/// cleartext_list.iter_mut().for_each(|elt| *elt >>= 60);
+/// // Get the list immutably
+/// let cleartext_list = cleartext_list;
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// ```
+pub fn encrypt_glwe_ciphertext_with_public_key<Scalar, KeyCont, InputCont, OutputCont, Gen>(
+    glwe_public_key: &GlwePublicKey<KeyCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        glwe_public_key.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between glwe_public_key ({:?}) and output ({:?})",
+        glwe_public_key.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+    assert!(
+        output.glwe_size() == glwe_public_key.glwe_size(),
+        "Mismatch between GlweSize of output ciphertext and input public key. \
+        Got {:?} in output, and {:?} in public key.",
+        output.glwe_size(),
+        glwe_public_key.glwe_size()
+    );
+    assert!(
+        output.polynomial_size() == glwe_public_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input public key. \
+        Got {:?} in output, and {:?} in public key.",
+        output.polynomial_size(),
+        glwe_public_key.polynomial_size()
+    );
+    assert!(
+        output.polynomial_size().0 == encoded.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
+    Got {:?} in output, and {:?} in input.",
+        output.polynomial_size(),
+        encoded.plaintext_count()
+    );
+
+    let ciphertext_modulus = output.ciphertext_modulus();
+
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    output.as_mut().fill(Scalar::ZERO);
+
+    let mut polynomial_choice = Polynomial::new(Scalar::ZERO, output.polynomial_size());
+
+    // Add the public encryptions of zero multiplied by random binary polynomials to get the zero
+    // encryption
+    for public_encryption_of_zero in glwe_public_key.iter() {
+        generator.fill_slice_with_random_uniform_binary(polynomial_choice.as_mut());
+        glwe_ciphertext_polynomial_add_mul_assign(
+            output,
+            &public_encryption_of_zero,
+            &polynomial_choice,
+        );
+    }
+
+    glwe_ciphertext_add_encoded_to_body_assign(output, encoded);
+}
+
+/// Encrypt an input [`PlaintextList`] in an output [`GLWE ciphertext`](`GlweCiphertext`) using a
+/// [`seeded GLWE public key`](`SeededGlwePublicKey`). The ciphertext can be decrypted using the
+/// [`GLWE secret key`](`GlweSecretKey`) that was used to generate the public key.
+///
+/// The masks of the encryptions of zero contained in the public key are regenerated on the fly
+/// from its seed, see [`encrypt_glwe_ciphertext_with_public_key`] for details on the encryption.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let zero_encryption_count = GlwePublicKeyZeroEncryptionCount(glwe_size.0 * 64 + 128);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let seeded_glwe_public_key = allocate_and_generate_new_seeded_glwe_public_key(
+///     &glwe_secret_key,
+///     zero_encryption_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     seeder,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext_with_seeded_public_key(
+///     &seeded_glwe_public_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     &mut secret_generator,
+/// );
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0));
+///
+/// // Get the raw vector
+/// let mut cleartext_list = output_plaintext_list.into_container();
+/// // Remove the encoding
+/// cleartext_list.iter_mut().for_each(|elt| *elt >>= 60);
+/// // Get the list immutably
+/// let cleartext_list = cleartext_list;
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// cleartext_list.iter().for_each(|&elt| assert_eq!(elt, msg));
+/// ```
+pub fn encrypt_glwe_ciphertext_with_seeded_public_key<Scalar, KeyCont, InputCont, OutputCont, Gen>(
+    glwe_public_key: &SeededGlwePublicKey<KeyCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        glwe_public_key.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between glwe_public_key ({:?}) and output ({:?})",
+        glwe_public_key.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+    assert!(
+        output.glwe_size() == glwe_public_key.glwe_size(),
+        "Mismatch between GlweSize of output ciphertext and input public key. \
+        Got {:?} in output, and {:?} in public key.",
+        output.glwe_size(),
+        glwe_public_key.glwe_size()
+    );
+    assert!(
+        output.polynomial_size() == glwe_public_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input public key. \
+        Got {:?} in output, and {:?} in public key.",
+        output.polynomial_size(),
+        glwe_public_key.polynomial_size()
+    );
+    assert!(
+        output.polynomial_size().0 == encoded.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext PlaintextCount of input. \
+    Got {:?} in output, and {:?} in input.",
+        output.polynomial_size(),
+        encoded.plaintext_count()
+    );
+
+    let ciphertext_modulus = output.ciphertext_modulus();
+
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    output.as_mut().fill(Scalar::ZERO);
+
+    let mut polynomial_choice = Polynomial::new(Scalar::ZERO, output.polynomial_size());
+
+    let mut tmp_zero_encryption = GlweCiphertext::new(
+        Scalar::ZERO,
+        glwe_public_key.glwe_size(),
+        glwe_public_key.polynomial_size(),
+        ciphertext_modulus,
+    );
+
+    // Same mask generation as the decompression of the public key
+    let mut mask_generator = MaskRandomGenerator::<ActivatedRandomGenerator>::new(
+        glwe_public_key.compression_seed().seed,
+    );
+
+    // Add the public encryptions of zero multiplied by random binary polynomials to get the zero
+    // encryption
+    for public_encryption_of_zero in glwe_public_key.iter() {
+        decompress_seeded_glwe_ciphertext_with_existing_generator(
+            &mut tmp_zero_encryption,
+            &public_encryption_of_zero,
+            &mut mask_generator,
+        );
+
+        generator.fill_slice_with_random_uniform_binary(polynomial_choice.as_mut());
+        glwe_ciphertext_polynomial_add_mul_assign(output, &tmp_zero_encryption, &polynomial_choice);
+    }
+
+    glwe_ciphertext_add_encoded_to_body_assign(output, encoded);
+}
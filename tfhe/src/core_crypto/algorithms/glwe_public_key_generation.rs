@@ -0,0 +1,149 @@
+//! Module containing primitives pertaining to [`GLWE public key
+//! generation`](`GlwePublicKey#glwe-public-key`) and [`seeded GLWE public key
+//! generation`](`SeededGlwePublicKey#glwe-public-key`).
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::CompressionSeed;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Fill a [`GLWE public key`](`GlwePublicKey`) with an actual public key constructed from a private
+/// [`GLWE secret key`](`GlweSecretKey`).
+pub fn generate_glwe_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    output: &mut GlwePublicKey<OutputKeyCont>,
+    noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        glwe_secret_key.glwe_dimension() == output.glwe_size().to_glwe_dimension(),
+        "Mismatched GlweDimension between input GlweSecretKey {:?} and output GlwePublicKey {:?}",
+        glwe_secret_key.glwe_dimension(),
+        output.glwe_size().to_glwe_dimension(),
+    );
+    assert!(
+        glwe_secret_key.polynomial_size() == output.polynomial_size(),
+        "Mismatched PolynomialSize between input GlweSecretKey {:?} and output GlwePublicKey {:?}",
+        glwe_secret_key.polynomial_size(),
+        output.polynomial_size(),
+    );
+
+    let zeros = PlaintextListOwned::new(
+        Scalar::ZERO,
+        PlaintextCount(output.zero_encryption_count().0 * output.polynomial_size().0),
+    );
+
+    encrypt_glwe_ciphertext_list(glwe_secret_key, output, &zeros, noise_parameters, generator)
+}
+
+/// Allocate a new [`GLWE public key`](`GlwePublicKey`) and fill it with an actual public key
+/// constructed from a private [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`encrypt_glwe_ciphertext_with_public_key`] for usage.
+pub fn allocate_and_generate_new_glwe_public_key<Scalar, InputKeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    noise_parameters: impl DispersionParameter,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlwePublicKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut pk = GlwePublicKeyOwned::new(
+        Scalar::ZERO,
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        glwe_secret_key.polynomial_size(),
+        zero_encryption_count,
+        ciphertext_modulus,
+    );
+
+    generate_glwe_public_key(glwe_secret_key, &mut pk, noise_parameters, generator);
+
+    pk
+}
+
+/// Fill a [`seeded GLWE public key`](`SeededGlwePublicKey`) with an actual public key.
+pub fn generate_seeded_glwe_public_key<Scalar, InputKeyCont, OutputKeyCont, NoiseSeeder>(
+    glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    output: &mut SeededGlwePublicKey<OutputKeyCont>,
+    noise_parameters: impl DispersionParameter,
+    noise_seeder: &mut NoiseSeeder,
+) where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: ContainerMut<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    assert!(
+        glwe_secret_key.glwe_dimension() == output.glwe_size().to_glwe_dimension(),
+        "Mismatched GlweDimension between input GlweSecretKey {:?} \
+        and output SeededGlwePublicKey {:?}",
+        glwe_secret_key.glwe_dimension(),
+        output.glwe_size().to_glwe_dimension(),
+    );
+    assert!(
+        glwe_secret_key.polynomial_size() == output.polynomial_size(),
+        "Mismatched PolynomialSize between input GlweSecretKey {:?} \
+        and output SeededGlwePublicKey {:?}",
+        glwe_secret_key.polynomial_size(),
+        output.polynomial_size(),
+    );
+
+    let zeros = PlaintextListOwned::new(
+        Scalar::ZERO,
+        PlaintextCount(output.zero_encryption_count().0 * output.polynomial_size().0),
+    );
+
+    encrypt_seeded_glwe_ciphertext_list(
+        glwe_secret_key,
+        output,
+        &zeros,
+        noise_parameters,
+        noise_seeder,
+    );
+}
+
+/// Allocate a new [`seeded GLWE public key`](`SeededGlwePublicKey`) and fill it with an actual
+/// seeded public key.
+///
+/// See [`encrypt_glwe_ciphertext_with_seeded_public_key`] for usage.
+pub fn allocate_and_generate_new_seeded_glwe_public_key<Scalar, InputKeyCont, NoiseSeeder>(
+    glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    noise_parameters: impl DispersionParameter,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    noise_seeder: &mut NoiseSeeder,
+) -> SeededGlwePublicKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    let mut pk = SeededGlwePublicKeyOwned::new(
+        Scalar::ZERO,
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        glwe_secret_key.polynomial_size(),
+        zero_encryption_count,
+        CompressionSeed {
+            seed: noise_seeder.seed(),
+        },
+        ciphertext_modulus,
+    );
+
+    generate_seeded_glwe_public_key(glwe_secret_key, &mut pk, noise_parameters, noise_seeder);
+
+    pk
+}
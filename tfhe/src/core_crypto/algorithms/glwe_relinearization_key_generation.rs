@@ -0,0 +1,190 @@
+//! Module containing primitives pertaining to the generation of
+//! [`GLWE relinearization keys`](`GlweRelinearizationKey`).
+
+use crate::core_crypto::algorithms::encrypt_glwe_ciphertext_list;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_mul;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::core_crypto::commons::math::random::ByteRandomGenerator;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::{
+    GlweRelinearizationKey, GlweRelinearizationKeyOwned, GlweSecretKey, PlaintextListOwned,
+    Polynomial,
+};
+
+/// Fill a [`GLWE relinearization key`](`GlweRelinearizationKey`) with an actual relinearization key
+/// constructed from a [`GLWE secret key`](`GlweSecretKey`).
+///
+/// For each pair of secret key polynomials $S\_i, S\_j$ with $i \leq j$, one GLWE ciphertext per
+/// decomposition level is generated, encrypting $S\_i S\_j$ multiplied by the corresponding power
+/// of the decomposition base.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweRelinearizationKey creation
+/// let glwe_dimension = GlweDimension(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut rlk = GlweRelinearizationKey::new(
+///     0u64,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// generate_glwe_relinearization_key(
+///     &glwe_secret_key,
+///     &mut rlk,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// assert!(rlk.as_ref().iter().all(|&x| x == 0) == false);
+/// ```
+pub fn generate_glwe_relinearization_key<Scalar, KeyCont, RlkCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    glwe_relinearization_key: &mut GlweRelinearizationKey<RlkCont>,
+    noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    RlkCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        glwe_relinearization_key.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "The destination GlweRelinearizationKey GlweDimension is not equal \
+    to the GlweSecretKey GlweDimension. Destination: {:?}, key: {:?}",
+        glwe_relinearization_key.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    assert!(
+        glwe_relinearization_key.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "The destination GlweRelinearizationKey PolynomialSize is not equal \
+        to the GlweSecretKey PolynomialSize. Destination: {:?}, key: {:?}",
+        glwe_relinearization_key.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let decomp_base_log = glwe_relinearization_key.decomposition_base_log();
+    let decomp_level_count = glwe_relinearization_key.decomposition_level_count();
+    let polynomial_size = glwe_relinearization_key.polynomial_size();
+    let ciphertext_modulus = glwe_relinearization_key.ciphertext_modulus();
+    assert!(
+        ciphertext_modulus.is_native_modulus(),
+        "GlweRelinearizationKey generation only supports the native modulus, got {:?}",
+        ciphertext_modulus
+    );
+
+    // The product of two secret key polynomials will be stored in this buffer
+    let mut secret_product = Polynomial::new(Scalar::ZERO, polynomial_size);
+
+    // The plaintexts used to encrypt a product of secret key polynomials will be stored in this
+    // buffer
+    let mut decomposition_plaintexts_buffer = PlaintextListOwned::new(
+        Scalar::ZERO,
+        PlaintextCount(decomp_level_count.0 * polynomial_size.0),
+    );
+
+    let glwe_secret_key_polynomials = glwe_secret_key.as_polynomial_list();
+    let secret_polynomial_pairs = (0..glwe_secret_key.glwe_dimension().0)
+        .flat_map(|i| (i..glwe_secret_key.glwe_dimension().0).map(move |j| (i, j)));
+
+    // Iterate over the pairs of secret key polynomials and the destination
+    // glwe_relinearization_key memory
+    for ((i, j), mut relinearization_key_block) in
+        secret_polynomial_pairs.zip(glwe_relinearization_key.iter_mut())
+    {
+        polynomial_wrapping_mul(
+            &mut secret_product,
+            &glwe_secret_key_polynomials.get(i),
+            &glwe_secret_key_polynomials.get(j),
+        );
+
+        // We fill the buffer with the product scaled by the powers of the decomposition base
+        for (level, mut messages) in (1..=decomp_level_count.0)
+            .rev()
+            .map(DecompositionLevel)
+            .zip(decomposition_plaintexts_buffer.chunks_exact_mut(polynomial_size.0))
+        {
+            for (message, &secret_coefficient) in
+                messages.iter_mut().zip(secret_product.as_ref().iter())
+            {
+                *message.0 = DecompositionTerm::new(level, decomp_base_log, secret_coefficient)
+                    .to_recomposition_summand();
+            }
+        }
+
+        encrypt_glwe_ciphertext_list(
+            glwe_secret_key,
+            &mut relinearization_key_block,
+            &decomposition_plaintexts_buffer,
+            noise_parameters,
+            generator,
+        );
+    }
+}
+
+/// Allocate a new [`GLWE relinearization key`](`GlweRelinearizationKey`) and fill it with an
+/// actual relinearization key constructed from a [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`glwe_tensor_product_with_relinearization`](`super::glwe_tensor_product_with_relinearization`)
+/// for usage.
+pub fn allocate_and_generate_new_glwe_relinearization_key<Scalar, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweRelinearizationKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_glwe_relinearization_key = GlweRelinearizationKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        glwe_secret_key.polynomial_size(),
+        ciphertext_modulus,
+    );
+
+    generate_glwe_relinearization_key(
+        glwe_secret_key,
+        &mut new_glwe_relinearization_key,
+        noise_parameters,
+        generator,
+    );
+
+    new_glwe_relinearization_key
+}
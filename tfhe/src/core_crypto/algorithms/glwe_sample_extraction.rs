@@ -119,6 +119,8 @@ pub fn extract_lwe_sample_from_glwe_ciphertext<Scalar, InputCont, OutputCont>(
         output_lwe.ciphertext_modulus()
     );
 
+    let ciphertext_modulus = input_glwe.ciphertext_modulus();
+
     // We retrieve the bodies and masks of the two ciphertexts.
     let (mut lwe_mask, lwe_body) = output_lwe.get_mut_mask_and_body();
     let (glwe_mask, glwe_body) = input_glwe.get_mask_and_body();
@@ -141,7 +143,14 @@ pub fn extract_lwe_sample_from_glwe_ciphertext<Scalar, InputCont, OutputCont>(
         // We reverse the polynomial
         lwe_mask_poly.reverse();
         // We compute the opposite of the proper coefficients
-        slice_wrapping_opposite_assign(&mut lwe_mask_poly[0..opposite_count]);
+        if ciphertext_modulus.is_compatible_with_native_modulus() {
+            slice_wrapping_opposite_assign(&mut lwe_mask_poly[0..opposite_count]);
+        } else {
+            slice_wrapping_opposite_assign_custom_mod(
+                &mut lwe_mask_poly[0..opposite_count],
+                ciphertext_modulus.get_custom_modulus().cast_into(),
+            );
+        }
         // We rotate the polynomial properly
         lwe_mask_poly.rotate_left(opposite_count);
     }
@@ -390,6 +399,7 @@ pub fn par_extract_lwe_sample_from_glwe_ciphertext_with_thread_count<
     );
 
     let polynomial_size = input_glwe.polynomial_size();
+    let ciphertext_modulus = input_glwe.ciphertext_modulus();
     let (glwe_mask, glwe_body) = input_glwe.get_mask_and_body();
 
     let thread_count = thread_count.0.min(rayon::current_num_threads());
@@ -429,7 +439,14 @@ pub fn par_extract_lwe_sample_from_glwe_ciphertext_with_thread_count<
                         // We reverse the polynomial
                         lwe_mask_poly.reverse();
                         // We compute the opposite of the proper coefficients
-                        slice_wrapping_opposite_assign(&mut lwe_mask_poly[0..opposite_count]);
+                        if ciphertext_modulus.is_compatible_with_native_modulus() {
+                            slice_wrapping_opposite_assign(&mut lwe_mask_poly[0..opposite_count]);
+                        } else {
+                            slice_wrapping_opposite_assign_custom_mod(
+                                &mut lwe_mask_poly[0..opposite_count],
+                                ciphertext_modulus.get_custom_modulus().cast_into(),
+                            );
+                        }
                         // We rotate the polynomial properly
                         lwe_mask_poly.rotate_left(opposite_count);
                     }
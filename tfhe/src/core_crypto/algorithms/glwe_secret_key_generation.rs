@@ -2,8 +2,8 @@
 //! [`GLWE secret keys`](`GlweSecretKey`).
 
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
-use crate::core_crypto::commons::numeric::Numeric;
+use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary, UniformTernary};
+use crate::core_crypto::commons::numeric::{Numeric, UnsignedInteger};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -69,3 +69,168 @@ pub fn generate_binary_glwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(glwe_secret_key.as_mut())
 }
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with uniformly random ternary
+/// coefficients, i.e. coefficients in $\{-1, 0, 1\}$, negative coefficients being represented
+/// modulo $2^{Scalar::BITS}$.
+///
+/// See [`generate_ternary_glwe_secret_key`] for usage.
+pub fn allocate_and_generate_new_ternary_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformTernary> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_ternary_glwe_secret_key(&mut glwe_secret_key, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with uniformly random ternary coefficients.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_ternary_glwe_secret_key(&mut glwe_secret_key, &mut secret_generator);
+///
+/// // All coefficients are in {-1, 0, 1}
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn generate_ternary_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformTernary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_uniform_ternary(glwe_secret_key.as_mut())
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with coefficients sampled from
+/// a centered discrete gaussian distribution, negative coefficients being represented modulo
+/// $2^{Scalar::BITS}$.
+///
+/// See [`generate_gaussian_glwe_secret_key`] for usage.
+pub fn allocate_and_generate_new_gaussian_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    std_dev: f64,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_gaussian_glwe_secret_key(&mut glwe_secret_key, std_dev, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with coefficients sampled from a centered discrete
+/// gaussian distribution with standard deviation `std_dev`, the standard deviation is expressed on
+/// the integers, i.e. it is not normalized by the ciphertext modulus.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let std_dev = 3.2;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_gaussian_glwe_secret_key(&mut glwe_secret_key, std_dev, &mut secret_generator);
+///
+/// // Check all coefficients are within 10 standard deviations
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| (elt as i64).unsigned_abs() <= 32));
+/// ```
+pub fn generate_gaussian_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    std_dev: f64,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_gaussian_integers(glwe_secret_key.as_mut(), std_dev)
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with coefficients following the
+/// given [`SecretKeyDistribution`].
+pub fn allocate_and_generate_new_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    secret_key_distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary> + RandomGenerable<UniformTernary>,
+    Gen: ByteRandomGenerator,
+{
+    match secret_key_distribution {
+        SecretKeyDistribution::Binary => allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            generator,
+        ),
+        SecretKeyDistribution::Ternary => allocate_and_generate_new_ternary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            generator,
+        ),
+        SecretKeyDistribution::Gaussian { std_dev } => {
+            allocate_and_generate_new_gaussian_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                std_dev,
+                generator,
+            )
+        }
+    }
+}
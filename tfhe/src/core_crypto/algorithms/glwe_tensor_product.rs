@@ -0,0 +1,683 @@
+//! Module containing primitives pertaining to the leveled multiplication of
+//! [`GLWE ciphertexts`](`GlweCiphertext`), i.e. the tensor product of two
+//! [`GLWE ciphertexts`](`GlweCiphertext`) and its relinearization using a
+//! [`GLWE relinearization key`](`GlweRelinearizationKey`).
+
+use crate::core_crypto::algorithms::misc::divide_ceil;
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
+use concrete_fft::fft128::f128;
+
+/// Return the [`GlweSize`] of the output of a [`glwe_tensor_product`] for input ciphertexts of
+/// the given [`GlweSize`].
+///
+/// For input ciphertexts of [`GlweDimension`] $k$, the output has one mask polynomial per secret
+/// key polynomial $S\_i$ and one mask polynomial per product $S\_i S\_j$ with $i \leq j$, that is
+/// $k + \frac{k(k+1)}{2}$ mask polynomials.
+pub fn glwe_tensor_product_output_glwe_size(input_glwe_size: GlweSize) -> GlweSize {
+    let glwe_dimension = input_glwe_size.to_glwe_dimension();
+    GlweDimension(glwe_dimension.0 + glwe_relinearization_key_secret_product_count(glwe_dimension))
+        .to_glwe_size()
+}
+
+/// Number of bits of the signed digits used to split the left-hand side polynomials before their
+/// multiplication in the 128 bits fourier domain.
+const TENSOR_PRODUCT_DIGIT_BITS: usize = 16;
+
+/// A polynomial in the 128 bits fourier domain, stored as the four components of its coefficients.
+struct Fourier128Polynomial {
+    re0: Vec<f64>,
+    re1: Vec<f64>,
+    im0: Vec<f64>,
+    im1: Vec<f64>,
+}
+
+impl Fourier128Polynomial {
+    fn new(polynomial_size: PolynomialSize) -> Self {
+        let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+        Self {
+            re0: vec![0.0; fourier_polynomial_size],
+            re1: vec![0.0; fourier_polynomial_size],
+            im0: vec![0.0; fourier_polynomial_size],
+            im1: vec![0.0; fourier_polynomial_size],
+        }
+    }
+
+    fn fill_with_forward_as_torus(&mut self, standard: &[u128], fft: Fft128View<'_>) {
+        fft.forward_as_torus(
+            &mut self.re0,
+            &mut self.re1,
+            &mut self.im0,
+            &mut self.im1,
+            standard,
+        );
+    }
+
+    fn fill_with_forward_as_integer(&mut self, standard: &[u128], fft: Fft128View<'_>) {
+        fft.forward_as_integer(
+            &mut self.re0,
+            &mut self.re1,
+            &mut self.im0,
+            &mut self.im1,
+            standard,
+        );
+    }
+
+    fn fill_with_zero(&mut self) {
+        self.re0.fill(0.0);
+        self.re1.fill(0.0);
+        self.im0.fill(0.0);
+        self.im1.fill(0.0);
+    }
+
+    /// Add the coefficient-wise product of `lhs` and `rhs` to `self`.
+    fn add_mul_assign(&mut self, lhs: &Self, rhs: &Self) {
+        for (
+            out_re0,
+            out_re1,
+            out_im0,
+            out_im1,
+            lhs_re0,
+            lhs_re1,
+            lhs_im0,
+            lhs_im1,
+            rhs_re0,
+            rhs_re1,
+            rhs_im0,
+            rhs_im1,
+        ) in izip!(
+            self.re0.iter_mut(),
+            self.re1.iter_mut(),
+            self.im0.iter_mut(),
+            self.im1.iter_mut(),
+            lhs.re0.iter(),
+            lhs.re1.iter(),
+            lhs.im0.iter(),
+            lhs.im1.iter(),
+            rhs.re0.iter(),
+            rhs.re1.iter(),
+            rhs.im0.iter(),
+            rhs.im1.iter(),
+        ) {
+            let lhs_re = f128(*lhs_re0, *lhs_re1);
+            let lhs_im = f128(*lhs_im0, *lhs_im1);
+            let rhs_re = f128(*rhs_re0, *rhs_re1);
+            let rhs_im = f128(*rhs_im0, *rhs_im1);
+
+            let mut out_re = f128(*out_re0, *out_re1);
+            let mut out_im = f128(*out_im0, *out_im1);
+
+            out_re += lhs_re * rhs_re - lhs_im * rhs_im;
+            out_im += lhs_im * rhs_re + lhs_re * rhs_im;
+
+            *out_re0 = out_re.0;
+            *out_re1 = out_re.1;
+            *out_im0 = out_im.0;
+            *out_im1 = out_im.1;
+        }
+    }
+}
+
+/// Sign extend a value viewed as a signed integer of `Scalar::BITS` bits to 128 bits.
+fn sign_extend_to_u128<Scalar: UnsignedInteger>(value: Scalar) -> u128 {
+    let shift = 128 - Scalar::BITS;
+    let value: u128 = value.cast_into();
+    (((value << shift) as i128) >> shift) as u128
+}
+
+/// Compute the products of the polynomials of two [`GLWE ciphertexts`](`GlweCiphertext`) over the
+/// integers, divided by a power of two scaling factor and rounded.
+///
+/// The polynomials of both ciphertexts are lifted to signed integers. Each left-hand side
+/// polynomial is split in small signed digits, and each right-hand side polynomial is divided by
+/// $q \cdot \Delta$ and viewed as an element of the 128 bits torus: the products of the digits with
+/// the torus polynomials are then computed with the 128 bits FFT, which is precise enough to get
+/// the result rounded to the nearest integer modulo $q$ with an overwhelming probability.
+struct ScaledPolynomialProducts {
+    lhs_digits: Vec<Vec<Fourier128Polynomial>>,
+    rhs_torus: Vec<Vec<Fourier128Polynomial>>,
+    accumulator: Fourier128Polynomial,
+    output_buffer: Vec<u128>,
+    buffers: ComputationBuffers,
+    fft: Fft128,
+}
+
+impl ScaledPolynomialProducts {
+    fn new<Scalar, LhsCont, RhsCont>(
+        lhs: &GlweCiphertext<LhsCont>,
+        rhs: &GlweCiphertext<RhsCont>,
+        scaling_factor_log: usize,
+    ) -> Self
+    where
+        Scalar: UnsignedTorus,
+        LhsCont: Container<Element = Scalar>,
+        RhsCont: Container<Element = Scalar>,
+    {
+        let polynomial_size = lhs.polynomial_size();
+        let fft = Fft128::new(polynomial_size);
+        let fft_view = fft.as_view();
+
+        // Enough signed digits to represent any signed value on Scalar::BITS bits
+        let digit_count = divide_ceil(Scalar::BITS, TENSOR_PRODUCT_DIGIT_BITS) + 1;
+        let digit_modulus = 1i128 << TENSOR_PRODUCT_DIGIT_BITS;
+        let half_digit_modulus = digit_modulus >> 1;
+
+        let mut digits = vec![vec![0u128; polynomial_size.0]; digit_count];
+        let lhs_digits = lhs
+            .as_polynomial_list()
+            .iter()
+            .map(|polynomial| {
+                for (coefficient_index, &coefficient) in polynomial.as_ref().iter().enumerate() {
+                    let mut remainder = sign_extend_to_u128(coefficient) as i128;
+                    for digit in digits.iter_mut() {
+                        // Balanced digit in [-digit_modulus / 2, digit_modulus / 2)
+                        let value = ((remainder + half_digit_modulus) & (digit_modulus - 1))
+                            - half_digit_modulus;
+                        digit[coefficient_index] = value as u128;
+                        remainder = (remainder - value) >> TENSOR_PRODUCT_DIGIT_BITS;
+                    }
+                    debug_assert_eq!(remainder, 0);
+                }
+
+                digits
+                    .iter()
+                    .map(|digit| {
+                        let mut fourier_digit = Fourier128Polynomial::new(polynomial_size);
+                        fourier_digit.fill_with_forward_as_integer(digit, fft_view);
+                        fourier_digit
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // rhs / (q * delta) on the 128 bits torus, then multiplied by the weight of each digit
+        let torus_shift = 128 - Scalar::BITS - scaling_factor_log;
+        let mut torus_polynomial = vec![0u128; polynomial_size.0];
+        let rhs_torus = rhs
+            .as_polynomial_list()
+            .iter()
+            .map(|polynomial| {
+                (0..digit_count)
+                    .map(|digit_index| {
+                        let shift = torus_shift + digit_index * TENSOR_PRODUCT_DIGIT_BITS;
+                        for (torus_coefficient, &coefficient) in
+                            torus_polynomial.iter_mut().zip(polynomial.as_ref().iter())
+                        {
+                            *torus_coefficient = if shift < 128 {
+                                sign_extend_to_u128(coefficient) << shift
+                            } else {
+                                0
+                            };
+                        }
+
+                        let mut fourier_torus = Fourier128Polynomial::new(polynomial_size);
+                        fourier_torus.fill_with_forward_as_torus(&torus_polynomial, fft_view);
+                        fourier_torus
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let mut buffers = ComputationBuffers::new();
+        buffers.resize(
+            fft_view
+                .backward_scratch()
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+
+        Self {
+            lhs_digits,
+            rhs_torus,
+            accumulator: Fourier128Polynomial::new(polynomial_size),
+            output_buffer: vec![0u128; polynomial_size.0],
+            buffers,
+            fft,
+        }
+    }
+
+    /// Write in `output` the sum of the scaled products of the `lhs_index`-th left-hand side
+    /// polynomial with the `rhs_index`-th right-hand side polynomial, for each pair of indices in
+    /// `index_pairs`.
+    fn write_sum_of_products<Scalar, OutputCont>(
+        &mut self,
+        output: &mut Polynomial<OutputCont>,
+        index_pairs: &[(usize, usize)],
+    ) where
+        Scalar: UnsignedTorus,
+        OutputCont: ContainerMut<Element = Scalar>,
+    {
+        self.accumulator.fill_with_zero();
+        for &(lhs_index, rhs_index) in index_pairs {
+            for (lhs_digit, rhs_torus) in self.lhs_digits[lhs_index]
+                .iter()
+                .zip(self.rhs_torus[rhs_index].iter())
+            {
+                self.accumulator.add_mul_assign(lhs_digit, rhs_torus);
+            }
+        }
+
+        self.fft.as_view().backward_as_torus(
+            &mut self.output_buffer,
+            &self.accumulator.re0,
+            &self.accumulator.re1,
+            &self.accumulator.im0,
+            &self.accumulator.im1,
+            self.buffers.stack(),
+        );
+
+        // Round the 128 bits torus result to the Scalar torus
+        let output_shift = 128 - Scalar::BITS;
+        let rounding_bit = 1u128 << (output_shift - 1);
+        for (output_coefficient, &torus_coefficient) in
+            output.as_mut().iter_mut().zip(self.output_buffer.iter())
+        {
+            *output_coefficient =
+                Scalar::cast_from(torus_coefficient.wrapping_add(rounding_bit) >> output_shift);
+        }
+    }
+}
+
+/// Compute the tensor product of two [`GLWE ciphertexts`](`GlweCiphertext`) encrypted under the
+/// same [`GLWE secret key`](`GlweSecretKey`), divided by the scaling factor `delta` of the
+/// encoding.
+///
+/// If the inputs encrypt $\Delta \cdot M$ and $\Delta \cdot M'$, the output encrypts
+/// $\Delta \cdot M \cdot M'$ (the product being computed modulo $X^N + 1$ and modulo the message
+/// modulus $\frac{q}{\Delta}$) under the tensor product of the secret key with itself. For a secret
+/// key $(S\_1, \ldots, S\_k)$ the output mask polynomials correspond, in this order, to the secret
+/// polynomials $S\_1, \ldots, S\_k$ and to the products $S\_i S\_j$ for $i \leq j$ in lexicographic
+/// order. The output can be brought back under the original secret key using
+/// [`glwe_relinearization`].
+///
+/// The polynomial products are computed using the 128 bits FFT.
+///
+/// # Panics
+///
+/// Panics if `delta` is not a power of two, if the ciphertext modulus is not the native modulus or
+/// if the Scalar type has more than 64 bits.
+///
+/// See [`glwe_tensor_product_with_relinearization`] for usage.
+pub fn glwe_tensor_product<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    delta: Scalar,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus(),
+        "Mismatched moduli between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus()
+    );
+    assert_eq!(
+        lhs.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between lhs ({:?}) and output ({:?}) GlweCiphertext",
+        lhs.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+    assert!(
+        lhs.ciphertext_modulus().is_native_modulus(),
+        "GLWE tensor product only supports the native modulus, got {:?}",
+        lhs.ciphertext_modulus()
+    );
+    assert!(
+        Scalar::BITS <= 64,
+        "GLWE tensor product only supports Scalar types of at most 64 bits, got {} bits",
+        Scalar::BITS
+    );
+    assert!(
+        delta.is_power_of_two(),
+        "GLWE tensor product requires a power of two delta, got {delta:?}"
+    );
+    assert_eq!(
+        lhs.glwe_size(),
+        rhs.glwe_size(),
+        "Mismatched GlweSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.glwe_size(),
+        rhs.glwe_size()
+    );
+    assert_eq!(
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+        "Mismatched PolynomialSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext",
+        lhs.polynomial_size(),
+        rhs.polynomial_size()
+    );
+    assert_eq!(
+        output.glwe_size(),
+        glwe_tensor_product_output_glwe_size(lhs.glwe_size()),
+        "Mismatched output GlweSize, expected {:?} for input GlweSize {:?}, got {:?}",
+        glwe_tensor_product_output_glwe_size(lhs.glwe_size()),
+        lhs.glwe_size(),
+        output.glwe_size()
+    );
+    assert_eq!(
+        output.polynomial_size(),
+        lhs.polynomial_size(),
+        "Mismatched PolynomialSize between output ({:?}) and input ({:?}) GlweCiphertext",
+        output.polynomial_size(),
+        lhs.polynomial_size()
+    );
+
+    let glwe_dimension = lhs.glwe_size().to_glwe_dimension().0;
+    // Index of the body polynomial in the input ciphertexts
+    let body = glwe_dimension;
+
+    let mut products = ScaledPolynomialProducts::new(lhs, rhs, delta.ilog2() as usize);
+
+    // With B - <A, S> = phase, the product of the phases is
+    // B B' - sum_i (A_i B' + A'_i B) S_i + sum_{i <= j} (A_i A'_j + A_j A'_i) S_i S_j
+    // (with a single product for i == j), the quadratic terms are negated to keep the convention
+    // body - <mask, key> of the decryption
+    let mut output_polynomials = output.as_mut_polynomial_list();
+    let mut output_polynomials = output_polynomials.iter_mut();
+
+    for i in 0..glwe_dimension {
+        let mut output_polynomial = output_polynomials.next().unwrap();
+        products.write_sum_of_products(&mut output_polynomial, &[(i, body), (body, i)]);
+    }
+
+    for i in 0..glwe_dimension {
+        for j in i..glwe_dimension {
+            let mut output_polynomial = output_polynomials.next().unwrap();
+            if i == j {
+                products.write_sum_of_products(&mut output_polynomial, &[(i, i)]);
+            } else {
+                products.write_sum_of_products(&mut output_polynomial, &[(i, j), (j, i)]);
+            }
+            output_polynomial
+                .as_mut()
+                .iter_mut()
+                .for_each(|coefficient| *coefficient = coefficient.wrapping_neg());
+        }
+    }
+
+    let mut output_body = output_polynomials.next().unwrap();
+    products.write_sum_of_products(&mut output_body, &[(body, body)]);
+}
+
+/// Relinearize the output of a [`glwe_tensor_product`] using a
+/// [`GLWE relinearization key`](`GlweRelinearizationKey`), the output
+/// [`GLWE ciphertext`](`GlweCiphertext`) is encrypted under the secret key used to generate the
+/// relinearization key.
+///
+/// The mask polynomials corresponding to the products of secret key polynomials are decomposed
+/// and multiplied with the relinearization key, in the same fashion as a keyswitch.
+///
+/// See [`glwe_tensor_product_with_relinearization`] for usage.
+pub fn glwe_relinearization<Scalar, InputCont, KeyCont, OutputCont>(
+    input_tensor_product: &GlweCiphertext<InputCont>,
+    glwe_relinearization_key: &GlweRelinearizationKey<KeyCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_tensor_product.ciphertext_modulus(),
+        glwe_relinearization_key.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and GlweRelinearizationKey ({:?})",
+        input_tensor_product.ciphertext_modulus(),
+        glwe_relinearization_key.ciphertext_modulus()
+    );
+    assert_eq!(
+        output.ciphertext_modulus(),
+        glwe_relinearization_key.ciphertext_modulus(),
+        "Mismatched moduli between output ({:?}) and GlweRelinearizationKey ({:?})",
+        output.ciphertext_modulus(),
+        glwe_relinearization_key.ciphertext_modulus()
+    );
+    assert!(
+        glwe_relinearization_key
+            .ciphertext_modulus()
+            .is_native_modulus(),
+        "GLWE relinearization only supports the native modulus, got {:?}",
+        glwe_relinearization_key.ciphertext_modulus()
+    );
+    assert_eq!(
+        output.glwe_size(),
+        glwe_relinearization_key.glwe_size(),
+        "Mismatched GlweSize between output ({:?}) and GlweRelinearizationKey ({:?})",
+        output.glwe_size(),
+        glwe_relinearization_key.glwe_size()
+    );
+    assert_eq!(
+        input_tensor_product.glwe_size(),
+        glwe_tensor_product_output_glwe_size(glwe_relinearization_key.glwe_size()),
+        "Mismatched input GlweSize, expected {:?} for GlweRelinearizationKey GlweSize {:?}, \
+        got {:?}",
+        glwe_tensor_product_output_glwe_size(glwe_relinearization_key.glwe_size()),
+        glwe_relinearization_key.glwe_size(),
+        input_tensor_product.glwe_size()
+    );
+    assert_eq!(
+        input_tensor_product.polynomial_size(),
+        glwe_relinearization_key.polynomial_size(),
+        "Mismatched PolynomialSize between input ({:?}) and GlweRelinearizationKey ({:?})",
+        input_tensor_product.polynomial_size(),
+        glwe_relinearization_key.polynomial_size()
+    );
+    assert_eq!(
+        output.polynomial_size(),
+        glwe_relinearization_key.polynomial_size(),
+        "Mismatched PolynomialSize between output ({:?}) and GlweRelinearizationKey ({:?})",
+        output.polynomial_size(),
+        glwe_relinearization_key.polynomial_size()
+    );
+
+    let glwe_dimension = glwe_relinearization_key.glwe_dimension().0;
+    let polynomial_size = glwe_relinearization_key.polynomial_size();
+    let decomp_level_count = glwe_relinearization_key.decomposition_level_count();
+
+    let input_polynomials = input_tensor_product.as_polynomial_list();
+
+    // The linear part of the mask and the body are copied as is
+    {
+        let mut output_polynomials = output.as_mut_polynomial_list();
+        for (mut output_polynomial, input_polynomial) in output_polynomials
+            .iter_mut()
+            .zip(input_polynomials.iter().take(glwe_dimension))
+        {
+            output_polynomial
+                .as_mut()
+                .copy_from_slice(input_polynomial.as_ref());
+        }
+        output_polynomials
+            .get_mut(glwe_dimension)
+            .as_mut()
+            .copy_from_slice(
+                input_polynomials
+                    .get(input_polynomials.polynomial_count().0 - 1)
+                    .as_ref(),
+            );
+    }
+
+    // We instantiate a decomposer
+    let decomposer = SignedDecomposer::new(
+        glwe_relinearization_key.decomposition_base_log(),
+        decomp_level_count,
+    );
+
+    // The decomposition of a polynomial, one polynomial per level
+    let mut decomposition = PolynomialList::new(
+        Scalar::ZERO,
+        polynomial_size,
+        PolynomialCount(decomp_level_count.0),
+    );
+
+    for (quadratic_polynomial, relinearization_key_block) in input_polynomials
+        .iter()
+        .skip(glwe_dimension)
+        .take(glwe_relinearization_key.secret_product_count())
+        .zip(glwe_relinearization_key.iter())
+    {
+        // We decompose
+        for (coefficient_index, &coefficient) in quadratic_polynomial.as_ref().iter().enumerate() {
+            let rounded = decomposer.closest_representable(coefficient);
+            for decomposed in decomposer.decompose(rounded) {
+                decomposition.get_mut(decomposed.level().0 - 1).as_mut()[coefficient_index] =
+                    decomposed.value();
+            }
+        }
+
+        // Loop over the levels, multiply the ciphertexts of the relinearization key with the
+        // corresponding piece of the decomposition and subtract it to the output
+        for (level_key_ciphertext, level_decomposition) in relinearization_key_block
+            .iter()
+            .rev()
+            .zip(decomposition.iter())
+        {
+            for (mut output_polynomial, key_polynomial) in output
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(level_key_ciphertext.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_sub_mul_assign(
+                    &mut output_polynomial,
+                    &key_polynomial,
+                    &level_decomposition,
+                );
+            }
+        }
+    }
+}
+
+/// Compute the product of two [`GLWE ciphertexts`](`GlweCiphertext`) encrypted under the same
+/// [`GLWE secret key`](`GlweSecretKey`) and encoded with the scaling factor `delta`: the
+/// [`glwe_tensor_product`] of the inputs is computed and then relinearized using a
+/// [`GLWE relinearization key`](`GlweRelinearizationKey`) with [`glwe_relinearization`].
+///
+/// If the inputs encrypt $\Delta \cdot M$ and $\Delta \cdot M'$, the output encrypts
+/// $\Delta \cdot M \cdot M'$, the product being computed modulo $X^N + 1$ and modulo the message
+/// modulus $\frac{q}{\Delta}$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(4);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey and the GlweRelinearizationKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let rlk = allocate_and_generate_new_glwe_relinearization_key(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // 4 bits of message without padding
+/// let delta = 1u64 << 60;
+///
+/// // Encrypt the constant polynomials 3 and 5
+/// let mut lhs = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+/// let mut lhs_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *lhs_plaintext_list.get_mut(0).0 = 3 * delta;
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut lhs,
+///     &lhs_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut rhs = lhs.clone();
+/// let mut rhs_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *rhs_plaintext_list.get_mut(0).0 = 5 * delta;
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut rhs,
+///     &rhs_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut product = lhs.clone();
+/// glwe_tensor_product_with_relinearization(&lhs, &rhs, &rlk, &mut product, delta);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &product, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Round and remove encoding in the output plaintext list
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the expected result, 3 * 5 = 15
+/// assert_eq!(*output_plaintext_list.get(0).0, 15);
+/// assert!(output_plaintext_list.iter().skip(1).all(|x| *x.0 == 0));
+/// ```
+pub fn glwe_tensor_product_with_relinearization<Scalar, LhsCont, RhsCont, KeyCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    glwe_relinearization_key: &GlweRelinearizationKey<KeyCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    delta: Scalar,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut tensor_product = GlweCiphertext::new(
+        Scalar::ZERO,
+        glwe_tensor_product_output_glwe_size(lhs.glwe_size()),
+        lhs.polynomial_size(),
+        lhs.ciphertext_modulus(),
+    );
+
+    glwe_tensor_product(lhs, rhs, &mut tensor_product, delta);
+
+    glwe_relinearization(&tensor_product, glwe_relinearization_key, output);
+}
@@ -36,6 +36,42 @@ pub fn fill_lwe_mask_and_body_for_encryption<Scalar, KeyCont, OutputCont, Gen>(
 
     let ciphertext_modulus = output_mask.ciphertext_modulus();
 
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        fill_lwe_mask_and_body_for_encryption_native_mod_compatible(
+            lwe_secret_key,
+            output_mask,
+            output_body,
+            encoded,
+            noise_parameters,
+            generator,
+        )
+    } else {
+        fill_lwe_mask_and_body_for_encryption_other_mod(
+            lwe_secret_key,
+            output_mask,
+            output_body,
+            encoded,
+            noise_parameters,
+            generator,
+        )
+    }
+}
+
+fn fill_lwe_mask_and_body_for_encryption_native_mod_compatible<Scalar, KeyCont, OutputCont, Gen>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    output_mask: &mut LweMask<OutputCont>,
+    output_body: LweBodyRefMut<Scalar>,
+    encoded: Plaintext<Scalar>,
+    noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let ciphertext_modulus = output_mask.ciphertext_modulus();
+
     assert!(ciphertext_modulus.is_compatible_with_native_modulus());
 
     generator.fill_slice_with_random_mask_custom_mod(output_mask.as_mut(), ciphertext_modulus);
@@ -57,6 +93,43 @@ pub fn fill_lwe_mask_and_body_for_encryption<Scalar, KeyCont, OutputCont, Gen>(
     ));
 }
 
+fn fill_lwe_mask_and_body_for_encryption_other_mod<Scalar, KeyCont, OutputCont, Gen>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    output_mask: &mut LweMask<OutputCont>,
+    output_body: LweBodyRefMut<Scalar>,
+    encoded: Plaintext<Scalar>,
+    noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let ciphertext_modulus = output_mask.ciphertext_modulus();
+
+    assert!(!ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+
+    generator.fill_slice_with_random_mask_custom_mod(output_mask.as_mut(), ciphertext_modulus);
+
+    // generate an error from the normal distribution described by std_dev, the plaintext is
+    // already expressed modulo the custom modulus
+    *output_body.data = generator.random_noise_custom_mod(noise_parameters, ciphertext_modulus);
+    *output_body.data = (*output_body.data).wrapping_add_custom_mod(encoded.0, custom_modulus);
+
+    // compute the multisum between the secret key and the mask
+    *output_body.data = (*output_body.data).wrapping_add_custom_mod(
+        slice_wrapping_dot_product_custom_mod(
+            output_mask.as_ref(),
+            lwe_secret_key.as_ref(),
+            custom_modulus,
+        ),
+        custom_modulus,
+    );
+}
+
 /// Encrypt an input plaintext in an output [`LWE ciphertext`](`LweCiphertext`).
 ///
 /// See the [`LWE ciphertext formal definition`](`LweCiphertext#lwe-encryption`) for the definition
@@ -298,8 +371,11 @@ pub fn trivially_encrypt_lwe_ciphertext<Scalar, OutputCont>(
     *output_body.data = encoded.0;
 
     let ciphertext_modulus = output_body.ciphertext_modulus();
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-    if !ciphertext_modulus.is_native_modulus() {
+    // Plaintexts for moduli which are not compatible with the native torus are already expressed
+    // modulo the ciphertext modulus and need no scaling
+    if ciphertext_modulus.is_compatible_with_native_modulus()
+        && !ciphertext_modulus.is_native_modulus()
+    {
         *output_body.data = (*output_body.data)
             .wrapping_mul(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
     }
@@ -377,8 +453,11 @@ where
     *output_body.data = encoded.0;
 
     let ciphertext_modulus = output_body.ciphertext_modulus();
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-    if !ciphertext_modulus.is_native_modulus() {
+    // Plaintexts for moduli which are not compatible with the native torus are already expressed
+    // modulo the ciphertext modulus and need no scaling
+    if ciphertext_modulus.is_compatible_with_native_modulus()
+        && !ciphertext_modulus.is_native_modulus()
+    {
         *output_body.data = (*output_body.data)
             .wrapping_mul(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
     }
@@ -413,11 +492,19 @@ where
 
     let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     let (mask, body) = lwe_ciphertext.get_mask_and_body();
 
-    if ciphertext_modulus.is_native_modulus() {
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+        Plaintext((*body.data).wrapping_sub_custom_mod(
+            slice_wrapping_dot_product_custom_mod(
+                mask.as_ref(),
+                lwe_secret_key.as_ref(),
+                custom_modulus,
+            ),
+            custom_modulus,
+        ))
+    } else if ciphertext_modulus.is_native_modulus() {
         Plaintext((*body.data).wrapping_sub(slice_wrapping_dot_product(
             mask.as_ref(),
             lwe_secret_key.as_ref(),
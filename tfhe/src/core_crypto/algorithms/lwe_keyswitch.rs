@@ -1,11 +1,11 @@
 //! Module containing primitives pertaining to [`LWE ciphertext
 //! keyswitch`](`LweKeyswitchKey#lwe-keyswitch`).
 
-use crate::core_crypto::algorithms::misc::divide_ceil;
+use crate::core_crypto::algorithms::misc::{divide_ceil, modulus_switch};
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
-use crate::core_crypto::commons::numeric::UnsignedInteger;
-use crate::core_crypto::commons::parameters::ThreadCount;
+use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
+use crate::core_crypto::commons::parameters::{CiphertextModulusLog, ThreadCount};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use rayon::prelude::*;
@@ -169,6 +169,206 @@ pub fn keyswitch_lwe_ciphertext<Scalar, KSKCont, InputCont, OutputCont>(
     }
 }
 
+/// Keyswitch an [`LWE ciphertext`](`LweCiphertext`) encrypted under an
+/// [`LWE secret key`](`LweSecretKey`) to another [`LWE secret key`](`LweSecretKey`), while
+/// switching from the native modulus of `InputScalar` to the native modulus of a smaller
+/// `OutputScalar`, e.g. from $2^{64}$ to $2^{32}$.
+///
+/// The [`LWE keyswitch key`](`LweKeyswitchKey`) has `OutputScalar` coefficients, it is generated
+/// from the input [`LWE secret key`](`LweSecretKey`) with its coefficients cast to `OutputScalar`.
+/// The decomposition of the input mask is done on the `InputScalar` coefficients, so the
+/// decomposition must fit in `OutputScalar`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweKeyswitchKey creation
+/// let input_lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let output_lwe_dimension = LweDimension(2048);
+/// let decomp_base_log = DecompositionBaseLog(3);
+/// let decomp_level_count = DecompositionLevelCount(5);
+/// let input_ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+/// let output_ciphertext_modulus = CiphertextModulus::<u32>::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let input_lwe_secret_key: LweSecretKeyOwned<u64> =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_lwe_secret_key: LweSecretKeyOwned<u32> =
+///     allocate_and_generate_new_binary_lwe_secret_key(
+///         output_lwe_dimension,
+///         &mut secret_generator,
+///     );
+///
+/// // The keyswitch key is generated in u32, from the input key cast to u32
+/// let input_lwe_secret_key_u32 = LweSecretKey::from_container(
+///     input_lwe_secret_key
+///         .as_ref()
+///         .iter()
+///         .map(|&coeff| coeff as u32)
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+///     &input_lwe_secret_key_u32,
+///     &output_lwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     lwe_modular_std_dev,
+///     output_ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// // Create a new LweCiphertext
+/// let input_lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &input_lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     input_ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_lwe = LweCiphertext::new(
+///     0u32,
+///     output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+///     output_ciphertext_modulus,
+/// );
+///
+/// keyswitch_lwe_ciphertext_with_scalar_change(&ksk, &input_lwe, &mut output_lwe);
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&output_lwe_secret_key, &output_lwe);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 28;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg as u32);
+/// ```
+pub fn keyswitch_lwe_ciphertext_with_scalar_change<
+    InputScalar,
+    OutputScalar,
+    KSKCont,
+    InputCont,
+    OutputCont,
+>(
+    lwe_keyswitch_key: &LweKeyswitchKey<KSKCont>,
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+) where
+    InputScalar: UnsignedInteger + CastInto<OutputScalar>,
+    OutputScalar: UnsignedInteger,
+    KSKCont: Container<Element = OutputScalar>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+{
+    assert!(
+        OutputScalar::BITS < InputScalar::BITS,
+        "The output integer type ({} bits) must be smaller than the input one ({} bits)",
+        OutputScalar::BITS,
+        InputScalar::BITS
+    );
+    assert!(
+        lwe_keyswitch_key.input_key_lwe_dimension()
+            == input_lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        "Mismatched input LweDimension. \
+        LweKeyswitchKey input LweDimension: {:?}, input LweCiphertext LweDimension {:?}.",
+        lwe_keyswitch_key.input_key_lwe_dimension(),
+        input_lwe_ciphertext.lwe_size().to_lwe_dimension(),
+    );
+    assert!(
+        lwe_keyswitch_key.output_key_lwe_dimension()
+            == output_lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        "Mismatched output LweDimension. \
+        LweKeyswitchKey output LweDimension: {:?}, output LweCiphertext LweDimension {:?}.",
+        lwe_keyswitch_key.output_key_lwe_dimension(),
+        output_lwe_ciphertext.lwe_size().to_lwe_dimension(),
+    );
+    assert!(
+        lwe_keyswitch_key.ciphertext_modulus() == output_lwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        LweKeyswitchKey CiphertextModulus: {:?}, output LweCiphertext CiphertextModulus {:?}.",
+        lwe_keyswitch_key.ciphertext_modulus(),
+        output_lwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        input_lwe_ciphertext
+            .ciphertext_modulus()
+            .is_native_modulus(),
+        "Only the native modulus is supported for the input ciphertext, got {:?}",
+        input_lwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        lwe_keyswitch_key.ciphertext_modulus().is_native_modulus(),
+        "Only the native modulus is supported for the output ciphertext, got {:?}",
+        lwe_keyswitch_key.ciphertext_modulus()
+    );
+    assert!(
+        lwe_keyswitch_key.decomposition_base_log().0
+            * lwe_keyswitch_key.decomposition_level_count().0
+            <= OutputScalar::BITS,
+        "The keyswitch decomposition ({:?}, {:?}) does not fit in the output integer type \
+        ({} bits)",
+        lwe_keyswitch_key.decomposition_base_log(),
+        lwe_keyswitch_key.decomposition_level_count(),
+        OutputScalar::BITS
+    );
+
+    // Clear the output ciphertext, as it will get updated gradually
+    output_lwe_ciphertext.as_mut().fill(OutputScalar::ZERO);
+
+    // Switch the input body to the output modulus
+    *output_lwe_ciphertext.get_mut_body().data = modulus_switch(
+        *input_lwe_ciphertext.get_body().data,
+        CiphertextModulusLog(OutputScalar::BITS),
+    )
+    .cast_into();
+
+    // The decomposition is done on the input coefficients, only keeping the bits which are also
+    // the most significant bits of the output modulus
+    let decomposer = SignedDecomposer::<InputScalar>::new(
+        lwe_keyswitch_key.decomposition_base_log(),
+        lwe_keyswitch_key.decomposition_level_count(),
+    );
+
+    for (keyswitch_key_block, &input_mask_element) in lwe_keyswitch_key
+        .iter()
+        .zip(input_lwe_ciphertext.get_mask().as_ref())
+    {
+        let decomposition_iter = decomposer.decompose(input_mask_element);
+        // Loop over the levels
+        for (level_key_ciphertext, decomposed) in keyswitch_key_block.iter().zip(decomposition_iter)
+        {
+            slice_wrapping_sub_scalar_mul_assign(
+                output_lwe_ciphertext.as_mut(),
+                level_key_ciphertext.as_ref(),
+                decomposed.value().cast_into(),
+            );
+        }
+    }
+}
+
 /// Parallel variant of [`keyswitch_lwe_ciphertext`].
 ///
 /// This will use all threads available in the current rayon thread pool.
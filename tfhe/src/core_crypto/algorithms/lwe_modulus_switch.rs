@@ -0,0 +1,127 @@
+//! Module containing primitives pertaining to the modulus switch of
+//! [`LWE ciphertexts`](`LweCiphertext`) to a smaller integer type.
+
+use crate::core_crypto::algorithms::misc::modulus_switch;
+use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
+use crate::core_crypto::commons::parameters::CiphertextModulusLog;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Switch an [`LWE ciphertext`](`LweCiphertext`) from the native modulus of `InputScalar` to the
+/// native modulus of a smaller `OutputScalar`, e.g. from $2^{64}$ to $2^{32}$, rounding each
+/// coefficient to the closest representable value.
+///
+/// The output ciphertext decrypts under the same secret key, with its coefficients cast to
+/// `OutputScalar`. The rounding adds noise which grows with the square root of the LWE dimension,
+/// so this is best done after keyswitching the ciphertext to a small
+/// [`LWE secret key`](`LweSecretKey`).
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key: LweSecretKeyOwned<u64> =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut switched_lwe = LweCiphertext::new(
+///     0u32,
+///     lwe_dimension.to_lwe_size(),
+///     CiphertextModulus::new_native(),
+/// );
+///
+/// modulus_switch_lwe_ciphertext(&lwe, &mut switched_lwe);
+///
+/// // The binary secret key can be used with the smaller integer type
+/// let switched_lwe_secret_key = LweSecretKey::from_container(
+///     lwe_secret_key
+///         .as_ref()
+///         .iter()
+///         .map(|&coeff| coeff as u32)
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&switched_lwe_secret_key, &switched_lwe);
+///
+/// // Round and remove encoding
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+/// let cleartext = decomposer.closest_representable(decrypted_plaintext.0) >> 28;
+///
+/// assert_eq!(cleartext, msg as u32);
+/// ```
+pub fn modulus_switch_lwe_ciphertext<InputScalar, OutputScalar, InputCont, OutputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+) where
+    InputScalar: UnsignedInteger + CastInto<OutputScalar>,
+    OutputScalar: UnsignedInteger,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+{
+    assert!(
+        OutputScalar::BITS < InputScalar::BITS,
+        "The output integer type ({} bits) must be smaller than the input one ({} bits)",
+        OutputScalar::BITS,
+        InputScalar::BITS
+    );
+    assert!(
+        input_lwe_ciphertext
+            .ciphertext_modulus()
+            .is_native_modulus(),
+        "Only the native modulus is supported for the input ciphertext, got {:?}",
+        input_lwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        output_lwe_ciphertext
+            .ciphertext_modulus()
+            .is_native_modulus(),
+        "Only the native modulus is supported for the output ciphertext, got {:?}",
+        output_lwe_ciphertext.ciphertext_modulus()
+    );
+    assert_eq!(
+        input_lwe_ciphertext.lwe_size(),
+        output_lwe_ciphertext.lwe_size(),
+        "Mismatched LweSize between input ciphertext ({:?}) and output ciphertext ({:?})",
+        input_lwe_ciphertext.lwe_size(),
+        output_lwe_ciphertext.lwe_size()
+    );
+
+    let log_modulus = CiphertextModulusLog(OutputScalar::BITS);
+
+    for (output, &input) in output_lwe_ciphertext
+        .as_mut()
+        .iter_mut()
+        .zip(input_lwe_ciphertext.as_ref().iter())
+    {
+        *output = modulus_switch(input, log_modulus).cast_into();
+    }
+}
@@ -20,8 +20,13 @@ use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
 };
 use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::blind_rotate_assign_scratch;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::ntt_impl::ntt64::crypto::bootstrap::{
+    bootstrap_scratch as bootstrap_scratch_ntt64, NttLweBootstrapKey,
+};
+use crate::core_crypto::ntt_impl::ntt64::math::ntt::{Ntt64, Ntt64View};
 use concrete_fft::c64;
-use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+use rayon::prelude::*;
 
 /// Perform a blind rotation given an input [`LWE ciphertext`](`LweCiphertext`), modifying a look-up
 /// table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
@@ -1119,14 +1124,17 @@ pub fn programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
     bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)
 }
 
-/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
-/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
-/// key`](`LweBootstrapKey`) in the fourier domain using f128 see [`fourier LWE bootstrap
-/// key`](`Fourier128LweBootstrapKey`). The result is written in the provided
-/// output [`LWE ciphertext`](`LweCiphertext`).
+/// Perform a programmable bootstrap on an [`LWE ciphertext`](`LweCiphertext`) using a different
+/// integer type than the accumulator and the output ciphertext, e.g. bootstrapping a `u32` input
+/// ciphertext to a `u64` output ciphertext.
+///
+/// This is used after a keyswitch to a smaller integer type, see
+/// [`super::keyswitch_lwe_ciphertext_with_scalar_change`].
+/// The input ciphertext must be encrypted under the input key of the bootstrapping key with its
+/// coefficients cast to `InputScalar`.
 ///
 /// If you want to manage the computation memory manually you can use
-/// [`programmable_bootstrap_f128_lwe_ciphertext_mem_optimized`].
+/// [`programmable_bootstrap_lwe_ciphertext_with_scalar_change_mem_optimized`].
 ///
 /// # Example
 ///
@@ -1135,221 +1143,149 @@ pub fn programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
 ///
 /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
 /// // computations
-/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// // Define the parameters for a 4 bits message space
 /// let small_lwe_dimension = LweDimension(742);
 /// let glwe_dimension = GlweDimension(1);
 /// let polynomial_size = PolynomialSize(2048);
-/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433 * 0.000007069849454709433);
-/// let glwe_modular_std_dev =
-///     StandardDev(0.00000000000000029403601535432533 * 0.00000000000000029403601535432533);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
 /// let pbs_base_log = DecompositionBaseLog(23);
 /// let pbs_level = DecompositionLevelCount(1);
-/// let ciphertext_modulus = CiphertextModulus::new_native();
-///
-/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
-/// // /dev/random on Unix systems if enabled via cargo features
-/// let mut boxed_seeder = new_seeder();
-/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
-/// let seeder = boxed_seeder.as_mut();
-///
-/// // Create a generator which uses a CSPRNG to generate secret keys
-/// let mut secret_generator =
-///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
 ///
-/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
-/// // noise
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
 /// let mut encryption_generator =
 ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
 ///
-/// println!("Generating keys...");
-///
-/// // Generate an LweSecretKey with binary coefficients
-/// let small_lwe_sk =
+/// // Generate the secret keys
+/// let small_lwe_sk: LweSecretKeyOwned<u64> =
 ///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
-///
-/// // Generate a GlweSecretKey with binary coefficients
 /// let glwe_sk =
 ///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
-///
-/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
 /// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
 ///
-/// // Generate the seeded bootstrapping key to show how to handle entity decompression,
-/// // we use the parallel variant for performance reason
-/// let std_bootstrapping_key = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
+/// // The input ciphertexts are encrypted on u32 under the small key cast to u32
+/// let small_lwe_sk_u32 = LweSecretKey::from_container(
+///     small_lwe_sk
+///         .as_ref()
+///         .iter()
+///         .map(|&coeff| coeff as u32)
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// // Generate the bootstrapping key and convert it to the Fourier domain
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
 ///     &small_lwe_sk,
 ///     &glwe_sk,
 ///     pbs_base_log,
 ///     pbs_level,
 ///     glwe_modular_std_dev,
 ///     ciphertext_modulus,
-///     seeder,
+///     &mut encryption_generator,
 /// );
 ///
-/// // We decompress the bootstrapping key
-/// let std_bootstrapping_key: LweBootstrapKeyOwned<u128> =
-///     std_bootstrapping_key.decompress_into_lwe_bootstrap_key();
-///
-/// // Create the empty bootstrapping key in the Fourier domain
-/// let mut fourier_bsk = Fourier128LweBootstrapKey::new(
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
 ///     std_bootstrapping_key.input_lwe_dimension(),
 ///     std_bootstrapping_key.glwe_size(),
 ///     std_bootstrapping_key.polynomial_size(),
 ///     std_bootstrapping_key.decomposition_base_log(),
 ///     std_bootstrapping_key.decomposition_level_count(),
 /// );
-///
-/// // Use the conversion function (a memory optimized version also exists but is more complicated
-/// // to use) to convert the standard bootstrapping key to the Fourier domain
-/// convert_standard_lwe_bootstrap_key_to_fourier_128(&std_bootstrapping_key, &mut fourier_bsk);
-/// // We don't need the standard bootstrapping key anymore
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
 /// drop(std_bootstrapping_key);
 ///
 /// // Our 4 bits message space
-/// let message_modulus = 1u128 << 4;
-///
-/// // Our input message
-/// let input_message = 3u128;
+/// let message_modulus = 1usize << 4;
+/// let input_message = 3u32;
 ///
-/// // Delta used to encode 4 bits of message + a bit of padding on u128
-/// let delta = (1_u128 << 127) / message_modulus;
-///
-/// // Apply our encoding
-/// let plaintext = Plaintext(input_message * delta);
-///
-/// // Allocate a new LweCiphertext and encrypt our plaintext
-/// let lwe_ciphertext_in: LweCiphertextOwned<u128> = allocate_and_encrypt_new_lwe_ciphertext(
-///     &small_lwe_sk,
-///     plaintext,
+/// // Encode the message with a bit of padding on u32
+/// let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk_u32,
+///     Plaintext(input_message * ((1u32 << 31) / message_modulus as u32)),
 ///     lwe_modular_std_dev,
-///     ciphertext_modulus,
+///     CiphertextModulus::<u32>::new_native(),
 ///     &mut encryption_generator,
 /// );
 ///
-/// // Now we will use a PBS to compute a multiplication by 2, it is NOT the recommended way of
-/// // doing this operation in terms of performance as it's much more costly than a multiplication
-/// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
-/// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
-///
-/// // Here we will define a helper function to generate an accumulator for a PBS
-/// fn generate_accumulator<F>(
-///     polynomial_size: PolynomialSize,
-///     glwe_size: GlweSize,
-///     message_modulus: usize,
-///     ciphertext_modulus: CiphertextModulus<u128>,
-///     delta: u128,
-///     f: F,
-/// ) -> GlweCiphertextOwned<u128>
-/// where
-///     F: Fn(u128) -> u128,
-/// {
-///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
-///     // box, which manages redundancy to yield a denoised value for several noisy values around
-///     // a true input value.
-///     let box_size = polynomial_size.0 / message_modulus;
-///
-///     // Create the accumulator
-///     let mut accumulator_u128 = vec![0_u128; polynomial_size.0];
-///
-///     // Fill each box with the encoded denoised value
-///     for i in 0..message_modulus {
-///         let index = i * box_size;
-///         accumulator_u128[index..index + box_size]
-///             .iter_mut()
-///             .for_each(|a| *a = f(i as u128) * delta);
-///     }
-///
-///     let half_box_size = box_size / 2;
-///
-///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-///     for a_i in accumulator_u128[0..half_box_size].iter_mut() {
-///         *a_i = (*a_i).wrapping_neg();
-///     }
-///
-///     // Rotate the accumulator
-///     accumulator_u128.rotate_left(half_box_size);
-///
-///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u128);
-///
-///     let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
-///         glwe_size,
-///         &accumulator_plaintext,
-///         ciphertext_modulus,
-///     );
+/// // The accumulator and the output use the u64 encoding
+/// let delta = (1_u64 << 63) / message_modulus as u64;
+/// let box_size = polynomial_size.0 / message_modulus;
+/// let half_box_size = box_size / 2;
+///
+/// // Fill each box with the encoded denoised value of a multiplication by 2
+/// let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+/// for i in 0..message_modulus {
+///     let index = i * box_size;
+///     accumulator_u64[index..index + box_size]
+///         .iter_mut()
+///         .for_each(|a| *a = 2 * i as u64 * delta);
+/// }
 ///
-///     accumulator
+/// // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
 /// }
+/// accumulator_u64.rotate_left(half_box_size);
 ///
-/// // Generate the accumulator for our multiplication by 2 using a simple closure
-/// let accumulator: GlweCiphertextOwned<u128> = generate_accumulator(
-///     polynomial_size,
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
 ///     glwe_dimension.to_glwe_size(),
-///     message_modulus as usize,
+///     &PlaintextList::from_container(accumulator_u64),
 ///     ciphertext_modulus,
-///     delta,
-///     |x: u128| 2 * x,
 /// );
 ///
-/// // Allocate the LweCiphertext to store the result of the PBS
 /// let mut pbs_multiplication_ct = LweCiphertext::new(
-///     0u128,
+///     0u64,
 ///     big_lwe_sk.lwe_dimension().to_lwe_size(),
 ///     ciphertext_modulus,
 /// );
-/// println!("Computing PBS...");
-/// programmable_bootstrap_f128_lwe_ciphertext(
+///
+/// programmable_bootstrap_lwe_ciphertext_with_scalar_change(
 ///     &lwe_ciphertext_in,
 ///     &mut pbs_multiplication_ct,
 ///     &accumulator,
 ///     &fourier_bsk,
 /// );
 ///
-/// // Decrypt the PBS multiplication result
-/// let pbs_multiplication_plaintext: Plaintext<u128> =
-///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
-///
-/// // Create a SignedDecomposer to perform the rounding of the decrypted plaintext
-/// // We pass a DecompositionBaseLog of 5 and a DecompositionLevelCount of 1 indicating we want to
-/// // round the 5 MSB, 1 bit of padding plus our 4 bits of message
+/// // Round the 5 MSB, 1 bit of padding plus our 4 bits of message
 /// let signed_decomposer =
 ///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
 ///
-/// // Round and remove our encoding
-/// let pbs_multiplication_result: u128 =
-///     signed_decomposer.closest_representable(pbs_multiplication_plaintext.0) / delta;
-///
-/// println!("Checking result...");
-/// assert_eq!(6, pbs_multiplication_result);
-/// println!(
-///     "Multiplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
-/// );
+/// let plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+/// let result = signed_decomposer.closest_representable(plaintext.0) / delta;
+/// assert_eq!(result, 2 * input_message as u64);
 /// ```
-pub fn programmable_bootstrap_f128_lwe_ciphertext<Scalar, InputCont, OutputCont, AccCont, KeyCont>(
+pub fn programmable_bootstrap_lwe_ciphertext_with_scalar_change<
+    InputScalar,
+    OutputScalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
     input: &LweCiphertext<InputCont>,
     output: &mut LweCiphertext<OutputCont>,
     accumulator: &GlweCiphertext<AccCont>,
-    fourier_bsk: &Fourier128LweBootstrapKey<KeyCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
 ) where
     // CastInto required for PBS modulus switch which returns a usize
-    Scalar: UnsignedTorus + CastInto<usize>,
-    InputCont: Container<Element = Scalar>,
-    OutputCont: ContainerMut<Element = Scalar>,
-    AccCont: Container<Element = Scalar>,
-    KeyCont: Container<Element = f64>,
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    AccCont: Container<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
 {
-    assert_eq!(input.ciphertext_modulus(), output.ciphertext_modulus());
-    assert_eq!(
-        output.ciphertext_modulus(),
-        accumulator.ciphertext_modulus()
-    );
-
     let mut buffers = ComputationBuffers::new();
 
-    let fft = Fft128::new(fourier_bsk.polynomial_size());
+    let fft = Fft::new(fourier_bsk.polynomial_size());
     let fft = fft.as_view();
 
     buffers.resize(
-        programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+        programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<OutputScalar>(
             fourier_bsk.glwe_size(),
             fourier_bsk.polynomial_size(),
             fft,
@@ -1358,24 +1294,23 @@ pub fn programmable_bootstrap_f128_lwe_ciphertext<Scalar, InputCont, OutputCont,
         .unaligned_bytes_required(),
     );
 
-    let stack = buffers.stack();
-
-    programmable_bootstrap_f128_lwe_ciphertext_mem_optimized(
+    programmable_bootstrap_lwe_ciphertext_with_scalar_change_mem_optimized(
         input,
         output,
         accumulator,
         fourier_bsk,
         fft,
-        stack,
+        buffers.stack(),
     )
 }
 
-/// Memory optimized version of [`programmable_bootstrap_f128_lwe_ciphertext`], the caller must
-/// provide a properly configured [`Fft128View`] object and a `PodStack` used as a memory buffer
-/// having a capacity at least as large as the result of
-/// [`programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement`].
-pub fn programmable_bootstrap_f128_lwe_ciphertext_mem_optimized<
-    Scalar,
+/// Memory optimized version of [`programmable_bootstrap_lwe_ciphertext_with_scalar_change`], the
+/// caller must provide a properly configured [`FftView`] object and a `PodStack` used as a memory
+/// buffer having a capacity at least as large as the result of
+/// [`programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`] for `OutputScalar`.
+pub fn programmable_bootstrap_lwe_ciphertext_with_scalar_change_mem_optimized<
+    InputScalar,
+    OutputScalar,
     InputCont,
     OutputCont,
     AccCont,
@@ -1384,25 +1319,1245 @@ pub fn programmable_bootstrap_f128_lwe_ciphertext_mem_optimized<
     input: &LweCiphertext<InputCont>,
     output: &mut LweCiphertext<OutputCont>,
     accumulator: &GlweCiphertext<AccCont>,
-    fourier_bsk: &Fourier128LweBootstrapKey<KeyCont>,
-    fft: Fft128View<'_>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
     stack: PodStack<'_>,
 ) where
     // CastInto required for PBS modulus switch which returns a usize
-    Scalar: UnsignedTorus + CastInto<usize>,
-    InputCont: Container<Element = Scalar>,
-    OutputCont: ContainerMut<Element = Scalar>,
-    AccCont: Container<Element = Scalar>,
-    KeyCont: Container<Element = f64>,
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    AccCont: Container<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
 {
-    fourier_bsk.bootstrap(output, input, accumulator, fft, stack);
+    assert!(
+        input.ciphertext_modulus().is_native_modulus(),
+        "Only the native modulus is supported for the input ciphertext, got {:?}",
+        input.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    fourier_bsk.as_view().bootstrap(
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
 }
 
-/// Return the required memory for [`programmable_bootstrap_f128_lwe_ciphertext_mem_optimized`].
-pub fn programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement<Scalar>(
-    glwe_size: GlweSize,
-    polynomial_size: PolynomialSize,
-    fft: Fft128View<'_>,
-) -> Result<StackReq, SizeOverflow> {
-    bootstrap_scratch_f128::<Scalar>(glwe_size, polynomial_size, fft)
+/// Perform a programmable bootstrap on each [`LWE ciphertext`](`LweCiphertext`) of an input
+/// [`LWE ciphertext list`](`LweCiphertextList`), the i-th input being bootstrapped with the i-th
+/// accumulator of a [`GLWE ciphertext list`](`GlweCiphertextList`) and the result being written
+/// in the i-th ciphertext of the output list.
+///
+/// Compared to calling [`programmable_bootstrap_lwe_ciphertext`] on each input, the FFT plan and
+/// the computation buffers are created once for the whole list.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_lwe_ciphertext_list_mem_optimized`]. A parallel version using all the
+/// threads of the current rayon thread pool is available with
+/// [`par_programmable_bootstrap_lwe_ciphertext_list`].
+///
+/// # Panics
+///
+/// Panics if the input list, the output list and the accumulator list do not have the same number
+/// of elements.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message space
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate the secret keys
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // Generate the bootstrapping key and convert it to the Fourier domain
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1usize << 4;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus as u64;
+///
+/// // One look-up table per input
+/// let functions: [fn(u64) -> u64; 3] = [|x| 2 * x, |x| x + 1, |x| x / 2];
+/// let input_messages = [3u64, 7, 5];
+///
+/// let mut accumulators = GlweCiphertextList::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     GlweCiphertextCount(functions.len()),
+///     ciphertext_modulus,
+/// );
+///
+/// let box_size = polynomial_size.0 / message_modulus;
+/// let half_box_size = box_size / 2;
+/// for (mut accumulator, f) in accumulators.iter_mut().zip(functions) {
+///     // Fill each box with the encoded denoised value
+///     let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+///     for i in 0..message_modulus {
+///         let index = i * box_size;
+///         accumulator_u64[index..index + box_size]
+///             .iter_mut()
+///             .for_each(|a| *a = f(i as u64) * delta);
+///     }
+///
+///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+///     for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///         *a_i = (*a_i).wrapping_neg();
+///     }
+///     accumulator_u64.rotate_left(half_box_size);
+///
+///     // Trivially encrypt the look-up table in the body of the accumulator
+///     accumulator
+///         .get_mut_body()
+///         .as_mut()
+///         .copy_from_slice(&accumulator_u64);
+/// }
+///
+/// let plaintext_list = PlaintextList::from_container(
+///     input_messages
+///         .iter()
+///         .map(|&message| message * delta)
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// let mut lwe_ciphertexts_in = LweCiphertextList::new(
+///     0u64,
+///     small_lwe_dimension.to_lwe_size(),
+///     LweCiphertextCount(input_messages.len()),
+///     ciphertext_modulus,
+/// );
+/// encrypt_lwe_ciphertext_list(
+///     &small_lwe_sk,
+///     &mut lwe_ciphertexts_in,
+///     &plaintext_list,
+///     lwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut pbs_outputs = LweCiphertextList::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     LweCiphertextCount(input_messages.len()),
+///     ciphertext_modulus,
+/// );
+///
+/// programmable_bootstrap_lwe_ciphertext_list(
+///     &lwe_ciphertexts_in,
+///     &mut pbs_outputs,
+///     &accumulators,
+///     &fourier_bsk,
+/// );
+///
+/// // Round the 5 MSB, 1 bit of padding plus our 4 bits of message
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// for ((pbs_output, f), input_message) in pbs_outputs.iter().zip(functions).zip(input_messages) {
+///     let plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_output);
+///     let result = signed_decomposer.closest_representable(plaintext.0) / delta;
+///     assert_eq!(result, f(input_message));
+/// }
+/// ```
+pub fn programmable_bootstrap_lwe_ciphertext_list<Scalar, InputCont, OutputCont, AccCont, KeyCont>(
+    input: &LweCiphertextList<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulators: &GlweCiphertextList<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_lwe_ciphertext_list_mem_optimized(
+        input,
+        output,
+        accumulators,
+        fourier_bsk,
+        fft,
+        stack,
+    )
+}
+
+/// Memory optimized version of [`programmable_bootstrap_lwe_ciphertext_list`], the caller must
+/// provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer having
+/// a capacity at least as large as the result of
+/// [`programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement`], the buffer is reused for
+/// each ciphertext of the list.
+pub fn programmable_bootstrap_lwe_ciphertext_list_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertextList<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulators: &GlweCiphertextList<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    mut stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_lwe_ciphertext_list_pbs_is_valid(input, output, accumulators);
+
+    for ((input, mut output), accumulator) in
+        input.iter().zip(output.iter_mut()).zip(accumulators.iter())
+    {
+        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+            &input,
+            &mut output,
+            &accumulator,
+            fourier_bsk,
+            fft,
+            stack.rb_mut(),
+        );
+    }
+}
+
+/// Parallel variant of [`programmable_bootstrap_lwe_ciphertext_list`].
+///
+/// The list is split in as many chunks as there are threads in the current rayon thread pool, each
+/// chunk being bootstrapped sequentially with its own computation buffers, the FFT plan being
+/// shared by all threads.
+///
+/// # Panics
+///
+/// Panics if the input list, the output list and the accumulator list do not have the same number
+/// of elements.
+pub fn par_programmable_bootstrap_lwe_ciphertext_list<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertextList<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulators: &GlweCiphertextList<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize> + Sync + Send,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64> + Sync,
+{
+    assert_lwe_ciphertext_list_pbs_is_valid(input, output, accumulators);
+
+    let ciphertext_count = input.lwe_ciphertext_count().0;
+    if ciphertext_count == 0 {
+        return;
+    }
+
+    let thread_count = rayon::current_num_threads();
+    let chunk_size = (ciphertext_count + thread_count - 1) / thread_count;
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    let buffer_size = programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+        fourier_bsk.glwe_size(),
+        fourier_bsk.polynomial_size(),
+        fft,
+    )
+    .unwrap()
+    .unaligned_bytes_required();
+
+    input
+        .par_chunks(chunk_size)
+        .zip(output.par_chunks_mut(chunk_size))
+        .zip(accumulators.par_chunks(chunk_size))
+        .for_each(|((input, mut output), accumulators)| {
+            let mut buffers = ComputationBuffers::new();
+            buffers.resize(buffer_size);
+
+            programmable_bootstrap_lwe_ciphertext_list_mem_optimized(
+                &input,
+                &mut output,
+                &accumulators,
+                fourier_bsk,
+                fft,
+                buffers.stack(),
+            );
+        });
+}
+
+fn assert_lwe_ciphertext_list_pbs_is_valid<Scalar, InputCont, OutputCont, AccCont>(
+    input: &LweCiphertextList<InputCont>,
+    output: &LweCiphertextList<OutputCont>,
+    accumulators: &GlweCiphertextList<AccCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: Container<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        input.lwe_ciphertext_count(),
+        output.lwe_ciphertext_count(),
+        "Mismatched LweCiphertextCount between input ({:?}) and output ({:?})",
+        input.lwe_ciphertext_count(),
+        output.lwe_ciphertext_count()
+    );
+
+    assert_eq!(
+        input.lwe_ciphertext_count().0,
+        accumulators.glwe_ciphertext_count().0,
+        "Mismatched number of accumulators ({:?}) for the number of inputs ({:?})",
+        accumulators.glwe_ciphertext_count(),
+        input.lwe_ciphertext_count()
+    );
+}
+
+/// Perform a programmable bootstrap evaluating several look-up tables at once, given an input
+/// [`LWE ciphertext`](`LweCiphertext`), an accumulator packing the look-up tables passed as a
+/// [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap key`](`LweBootstrapKey`) in the
+/// fourier domain see [`fourier LWE bootstrap key`](`FourierLweBootstrapKey`).
+///
+/// A single blind rotation is computed, then one [`LWE ciphertext`](`LweCiphertext`) per element
+/// of the output [`LWE ciphertext list`](`LweCiphertextList`) is extracted: the i-th output is
+/// extracted at the monomial degree `i * sample_extraction_stride`, so it holds the value of the
+/// accumulator for the input shifted by `i * sample_extraction_stride` coefficients. The
+/// accumulator is typically filled by splitting the input space in as many chunks as there are
+/// look-up tables, the i-th chunk holding the i-th look-up table, the input message then has to
+/// fit in a single chunk. The output noise is the same as for
+/// [`programmable_bootstrap_lwe_ciphertext`].
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized`].
+///
+/// # Panics
+///
+/// Panics if the output list is empty or if the last output would be extracted at a monomial
+/// degree greater than or equal to the polynomial size of the accumulator.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message space, holding two 3 bits look-up tables
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate the secret keys
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // Generate the bootstrapping key and convert it to the Fourier domain
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space, split in two chunks of 3 bits, one per look-up table
+/// let message_modulus = 1usize << 4;
+/// let lut_count = 2;
+/// let chunk_size = message_modulus / lut_count;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus as u64;
+///
+/// // The look-up tables, evaluated on inputs in [0, 8)
+/// let functions: [fn(u64) -> u64; 2] = [|x| 2 * x, |x| x + 1];
+///
+/// // Fill the accumulator: the i-th chunk of boxes holds the i-th look-up table
+/// let box_size = polynomial_size.0 / message_modulus;
+/// let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+/// for i in 0..message_modulus {
+///     let f = functions[i / chunk_size];
+///     let index = i * box_size;
+///     accumulator_u64[index..index + box_size]
+///         .iter_mut()
+///         .for_each(|a| *a = f((i % chunk_size) as u64) * delta);
+/// }
+///
+/// // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+///
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// // The outputs are extracted one chunk apart
+/// let sample_extraction_stride = MonomialDegree(chunk_size * box_size);
+///
+/// // Our input message
+/// let input_message = 3u64;
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut pbs_outputs = LweCiphertextList::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     LweCiphertextCount(lut_count),
+///     ciphertext_modulus,
+/// );
+///
+/// programmable_bootstrap_lwe_ciphertext_many_lut(
+///     &lwe_ciphertext_in,
+///     &mut pbs_outputs,
+///     &accumulator,
+///     &fourier_bsk,
+///     sample_extraction_stride,
+/// );
+///
+/// // Round the 5 MSB, 1 bit of padding plus our 4 bits of message
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// for (pbs_output, f) in pbs_outputs.iter().zip(functions) {
+///     let plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_output);
+///     let result = signed_decomposer.closest_representable(plaintext.0) / delta;
+///     assert_eq!(result, f(input_message));
+/// }
+/// ```
+pub fn programmable_bootstrap_lwe_ciphertext_many_lut<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    sample_extraction_stride: MonomialDegree,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        sample_extraction_stride,
+        fft,
+        stack,
+    )
+}
+
+/// Memory optimized version of [`programmable_bootstrap_lwe_ciphertext_many_lut`], the caller must
+/// provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer having
+/// a capacity at least as large as the result of
+/// [`programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement`].
+pub fn programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    sample_extraction_stride: MonomialDegree,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        input.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension. LweCiphertext input LweDimension {:?}. \
+        FourierLweBootstrapKey input LweDimension {:?}.",
+        input.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension(),
+        fourier_bsk.output_lwe_dimension(),
+        "Mismatched output LweDimension. LweCiphertextList output LweDimension {:?}. \
+        FourierLweBootstrapKey output LweDimension {:?}.",
+        output.lwe_size().to_lwe_dimension(),
+        fourier_bsk.output_lwe_dimension(),
+    );
+
+    assert_eq!(
+        accumulator.glwe_size(),
+        fourier_bsk.glwe_size(),
+        "Mismatched GlweSize. Accumulator GlweSize {:?}. \
+        FourierLweBootstrapKey GlweSize {:?}.",
+        accumulator.glwe_size(),
+        fourier_bsk.glwe_size(),
+    );
+
+    assert_eq!(
+        accumulator.polynomial_size(),
+        fourier_bsk.polynomial_size(),
+        "Mismatched PolynomialSize. Accumulator PolynomialSize {:?}. \
+        FourierLweBootstrapKey PolynomialSize {:?}.",
+        accumulator.polynomial_size(),
+        fourier_bsk.polynomial_size(),
+    );
+
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_many_lut_extraction_is_valid(
+        output.lwe_ciphertext_count(),
+        accumulator.polynomial_size(),
+        sample_extraction_stride,
+    );
+
+    fourier_bsk.as_view().bootstrap_many_lut(
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        sample_extraction_stride,
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized`].
+pub fn programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
+/// Check that `lut_count` samples extracted every `sample_extraction_stride` coefficients fit in a
+/// polynomial of the given size.
+pub(crate) fn assert_many_lut_extraction_is_valid(
+    lut_count: LweCiphertextCount,
+    polynomial_size: PolynomialSize,
+    sample_extraction_stride: MonomialDegree,
+) {
+    assert!(
+        lut_count.0 > 0,
+        "Got an empty output LweCiphertextList, at least one output is required"
+    );
+
+    let last_extraction_degree = (lut_count.0 - 1) * sample_extraction_stride.0;
+
+    assert!(
+        last_extraction_degree < polynomial_size.0,
+        "The last of the {} outputs would be extracted at the monomial degree {}, \
+        which exceeds the PolynomialSize {:?}",
+        lut_count.0,
+        last_extraction_degree,
+        polynomial_size,
+    );
+}
+
+/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
+/// key`](`LweBootstrapKey`) in the fourier domain using f128 see [`fourier LWE bootstrap
+/// key`](`Fourier128LweBootstrapKey`). The result is written in the provided
+/// output [`LWE ciphertext`](`LweCiphertext`).
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_f128_lwe_ciphertext_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433 * 0.000007069849454709433);
+/// let glwe_modular_std_dev =
+///     StandardDev(0.00000000000000029403601535432533 * 0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// println!("Generating keys...");
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // Generate the seeded bootstrapping key to show how to handle entity decompression,
+/// // we use the parallel variant for performance reason
+/// let std_bootstrapping_key = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     seeder,
+/// );
+///
+/// // We decompress the bootstrapping key
+/// let std_bootstrapping_key: LweBootstrapKeyOwned<u128> =
+///     std_bootstrapping_key.decompress_into_lwe_bootstrap_key();
+///
+/// // Create the empty bootstrapping key in the Fourier domain
+/// let mut fourier_bsk = Fourier128LweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+///
+/// // Use the conversion function (a memory optimized version also exists but is more complicated
+/// // to use) to convert the standard bootstrapping key to the Fourier domain
+/// convert_standard_lwe_bootstrap_key_to_fourier_128(&std_bootstrapping_key, &mut fourier_bsk);
+/// // We don't need the standard bootstrapping key anymore
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u128 << 4;
+///
+/// // Our input message
+/// let input_message = 3u128;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u128
+/// let delta = (1_u128 << 127) / message_modulus;
+///
+/// // Apply our encoding
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// // Allocate a new LweCiphertext and encrypt our plaintext
+/// let lwe_ciphertext_in: LweCiphertextOwned<u128> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Now we will use a PBS to compute a multiplication by 2, it is NOT the recommended way of
+/// // doing this operation in terms of performance as it's much more costly than a multiplication
+/// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
+/// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
+///
+/// // Here we will define a helper function to generate an accumulator for a PBS
+/// fn generate_accumulator<F>(
+///     polynomial_size: PolynomialSize,
+///     glwe_size: GlweSize,
+///     message_modulus: usize,
+///     ciphertext_modulus: CiphertextModulus<u128>,
+///     delta: u128,
+///     f: F,
+/// ) -> GlweCiphertextOwned<u128>
+/// where
+///     F: Fn(u128) -> u128,
+/// {
+///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
+///     // box, which manages redundancy to yield a denoised value for several noisy values around
+///     // a true input value.
+///     let box_size = polynomial_size.0 / message_modulus;
+///
+///     // Create the accumulator
+///     let mut accumulator_u128 = vec![0_u128; polynomial_size.0];
+///
+///     // Fill each box with the encoded denoised value
+///     for i in 0..message_modulus {
+///         let index = i * box_size;
+///         accumulator_u128[index..index + box_size]
+///             .iter_mut()
+///             .for_each(|a| *a = f(i as u128) * delta);
+///     }
+///
+///     let half_box_size = box_size / 2;
+///
+///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+///     for a_i in accumulator_u128[0..half_box_size].iter_mut() {
+///         *a_i = (*a_i).wrapping_neg();
+///     }
+///
+///     // Rotate the accumulator
+///     accumulator_u128.rotate_left(half_box_size);
+///
+///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u128);
+///
+///     let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///         glwe_size,
+///         &accumulator_plaintext,
+///         ciphertext_modulus,
+///     );
+///
+///     accumulator
+/// }
+///
+/// // Generate the accumulator for our multiplication by 2 using a simple closure
+/// let accumulator: GlweCiphertextOwned<u128> = generate_accumulator(
+///     polynomial_size,
+///     glwe_dimension.to_glwe_size(),
+///     message_modulus as usize,
+///     ciphertext_modulus,
+///     delta,
+///     |x: u128| 2 * x,
+/// );
+///
+/// // Allocate the LweCiphertext to store the result of the PBS
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u128,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// println!("Computing PBS...");
+/// programmable_bootstrap_f128_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_multiplication_ct,
+///     &accumulator,
+///     &fourier_bsk,
+/// );
+///
+/// // Decrypt the PBS multiplication result
+/// let pbs_multiplication_plaintext: Plaintext<u128> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// // Create a SignedDecomposer to perform the rounding of the decrypted plaintext
+/// // We pass a DecompositionBaseLog of 5 and a DecompositionLevelCount of 1 indicating we want to
+/// // round the 5 MSB, 1 bit of padding plus our 4 bits of message
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// // Round and remove our encoding
+/// let pbs_multiplication_result: u128 =
+///     signed_decomposer.closest_representable(pbs_multiplication_plaintext.0) / delta;
+///
+/// println!("Checking result...");
+/// assert_eq!(6, pbs_multiplication_result);
+/// println!(
+///     "Multiplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
+/// );
+/// ```
+pub fn programmable_bootstrap_f128_lwe_ciphertext<Scalar, InputCont, OutputCont, AccCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &Fourier128LweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = f64>,
+{
+    assert_eq!(input.ciphertext_modulus(), output.ciphertext_modulus());
+    assert_eq!(
+        output.ciphertext_modulus(),
+        accumulator.ciphertext_modulus()
+    );
+
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft128::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_f128_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        fft,
+        stack,
+    )
+}
+
+/// Memory optimized version of [`programmable_bootstrap_f128_lwe_ciphertext`], the caller must
+/// provide a properly configured [`Fft128View`] object and a `PodStack` used as a memory buffer
+/// having a capacity at least as large as the result of
+/// [`programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement`].
+pub fn programmable_bootstrap_f128_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &Fourier128LweBootstrapKey<KeyCont>,
+    fft: Fft128View<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = f64>,
+{
+    fourier_bsk.bootstrap(output, input, accumulator, fft, stack);
+}
+
+/// Return the required memory for [`programmable_bootstrap_f128_lwe_ciphertext_mem_optimized`].
+pub fn programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_scratch_f128::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
+/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
+/// key`](`LweBootstrapKey`) in the NTT domain see [`NTT LWE bootstrap
+/// key`](`NttLweBootstrapKey`). The result is written in the provided output
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// All ciphertexts must use the same prime [`CiphertextModulus`] $q$ with
+/// $q \equiv 1 \bmod 2N$. As all polynomial products are computed exactly, the result of the
+/// bootstrap is bit-exact and deterministic, independently of the platform it runs on.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// // The Solinas prime 2^64 - 2^32 + 1 is NTT friendly for all the usual polynomial sizes
+/// let custom_modulus = (1u64 << 32).wrapping_neg() + 1;
+/// let ciphertext_modulus = CiphertextModulus::try_new(custom_modulus as u128).unwrap();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// println!("Generating keys...");
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // Generate the bootstrapping key, we use the parallel variant for performance reason
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the empty bootstrapping key in the NTT domain
+/// let mut ntt_bsk = NttLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+///     ciphertext_modulus,
+/// );
+///
+/// // Use the conversion function to convert the standard bootstrapping key to the NTT domain
+/// convert_standard_lwe_bootstrap_key_to_ntt64(&std_bootstrapping_key, &mut ntt_bsk);
+/// // We don't need the standard bootstrapping key anymore
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+///
+/// // Our input message
+/// let input_message = 3u64;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding modulo our prime
+/// let delta = custom_modulus / (2 * message_modulus);
+///
+/// // Apply our encoding
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// // Allocate a new LweCiphertext and encrypt our plaintext
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // N/(p/2) = size of each box used to manage redundancy in the accumulator
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+///
+/// // Fill each box with the encoded denoised value of our multiplication by 2
+/// for i in 0..message_modulus as usize {
+///     let index = i * box_size;
+///     accumulator_u64[index..index + box_size]
+///         .iter_mut()
+///         .for_each(|a| *a = 2 * i as u64 % message_modulus * delta);
+/// }
+///
+/// // Negate the first half_box_size coefficients modulo q to manage negacyclicity and rotate
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (custom_modulus - *a_i) % custom_modulus;
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+///
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// // Allocate the LweCiphertext to store the result of the PBS
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// println!("Computing PBS...");
+/// programmable_bootstrap_ntt64_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_multiplication_ct,
+///     &accumulator,
+///     &ntt_bsk,
+/// );
+///
+/// // Decrypt the PBS multiplication result
+/// let pbs_multiplication_plaintext: Plaintext<u64> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// // Round and remove our encoding
+/// let pbs_multiplication_result = ((pbs_multiplication_plaintext.0 as u128
+///     * (2 * message_modulus) as u128
+///     + custom_modulus as u128 / 2)
+///     / custom_modulus as u128) as u64
+///     % (2 * message_modulus);
+///
+/// println!("Checking result...");
+/// assert_eq!(6, pbs_multiplication_result);
+/// println!(
+///     "Multiplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
+/// );
+/// ```
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext<InputCont, OutputCont, AccCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(input.ciphertext_modulus(), output.ciphertext_modulus());
+    assert_eq!(
+        output.ciphertext_modulus(),
+        accumulator.ciphertext_modulus()
+    );
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        ntt_bsk.ciphertext_modulus()
+    );
+
+    let mut buffers = ComputationBuffers::new();
+
+    let ntt = Ntt64::new(ntt_bsk.polynomial_size(), ntt_bsk.ciphertext_modulus());
+    let ntt = ntt.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+            ntt_bsk.glwe_size(),
+            ntt_bsk.polynomial_size(),
+            ntt_bsk.decomposition_level_count(),
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        ntt_bsk,
+        ntt,
+        stack,
+    )
+}
+
+/// Memory optimized version of [`programmable_bootstrap_ntt64_lwe_ciphertext`], the caller must
+/// provide a properly configured [`Ntt64View`] object and a `PodStack` used as a memory buffer
+/// having a capacity at least as large as the result of
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement`].
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized<
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    ntt_bsk.bootstrap(output, input, accumulator, ntt, stack);
+}
+
+/// Return the required memory for [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized`].
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_scratch_ntt64(glwe_size, polynomial_size, decomposition_level_count)
 }
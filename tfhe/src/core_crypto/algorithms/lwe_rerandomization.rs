@@ -0,0 +1,302 @@
+//! Module containing primitives pertaining to the re-randomization of [`LWE
+//! ciphertexts`](`LweCiphertext`).
+//!
+//! Re-randomizing a ciphertext adds a fresh public encryption of zero to it, so that its mask no
+//! longer depends on the computations that produced it. Flooding the noise of a ciphertext adds a
+//! large amount of fresh noise to its body to hide the noise accumulated during these
+//! computations, given the caller has enough noise margin to absorb it.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Re-randomize an [`LWE ciphertext`](`LweCiphertext`) in place by adding to it a fresh
+/// encryption of zero computed with an [`LWE public key`](`LwePublicKey`).
+///
+/// The public key must encrypt under the same [`LWE secret key`](`LweSecretKey`) as the
+/// ciphertext. The noise of the ciphertext grows by the noise of a public key encryption.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let zero_encryption_count =
+///     LwePublicKeyZeroEncryptionCount(lwe_dimension.to_lwe_size().0 * 64 + 128);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let lwe_public_key = allocate_and_generate_new_lwe_public_key(
+///     &lwe_secret_key,
+///     zero_encryption_count,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// let mut lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+/// let original_lwe = lwe.clone();
+///
+/// rerandomize_lwe_ciphertext_with_public_key(&lwe_public_key, &mut lwe, &mut secret_generator);
+///
+/// // The mask has been refreshed
+/// assert_ne!(lwe, original_lwe);
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &lwe);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 60;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn rerandomize_lwe_ciphertext_with_public_key<Scalar, KeyCont, OutputCont, Gen>(
+    lwe_public_key: &LwePublicKey<KeyCont>,
+    lwe: &mut LweCiphertext<OutputCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut zero_encryption =
+        LweCiphertext::new(Scalar::ZERO, lwe.lwe_size(), lwe.ciphertext_modulus());
+
+    encrypt_lwe_ciphertext_with_public_key(
+        lwe_public_key,
+        &mut zero_encryption,
+        Plaintext(Scalar::ZERO),
+        generator,
+    );
+
+    lwe_ciphertext_add_assign(lwe, &zero_encryption);
+}
+
+/// Re-randomize an [`LWE ciphertext`](`LweCiphertext`) in place by adding to it a fresh
+/// encryption of zero computed with an [`LWE compact public key`](`LweCompactPublicKey`).
+///
+/// The public key must encrypt under the same [`LWE secret key`](`LweSecretKey`) as the
+/// ciphertext. The noise parameters are the ones used for
+/// [`encrypt_lwe_ciphertext_with_compact_public_key`], a larger `body_noise_parameters` can be
+/// used to flood the noise of the ciphertext at the same time.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(2048);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let lwe_compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+///     &lwe_secret_key,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// let mut lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+/// let original_lwe = lwe.clone();
+///
+/// rerandomize_lwe_ciphertext_with_compact_public_key(
+///     &lwe_compact_public_key,
+///     &mut lwe,
+///     glwe_modular_std_dev,
+///     glwe_modular_std_dev,
+///     &mut secret_generator,
+///     &mut encryption_generator,
+/// );
+///
+/// // The mask has been refreshed
+/// assert_ne!(lwe, original_lwe);
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &lwe);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 60;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn rerandomize_lwe_ciphertext_with_compact_public_key<
+    Scalar,
+    KeyCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    lwe: &mut LweCiphertext<OutputCont>,
+    mask_noise_parameters: impl DispersionParameter,
+    body_noise_parameters: impl DispersionParameter,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    let mut zero_encryption =
+        LweCiphertext::new(Scalar::ZERO, lwe.lwe_size(), lwe.ciphertext_modulus());
+
+    encrypt_lwe_ciphertext_with_compact_public_key(
+        lwe_compact_public_key,
+        &mut zero_encryption,
+        Plaintext(Scalar::ZERO),
+        mask_noise_parameters,
+        body_noise_parameters,
+        secret_generator,
+        encryption_generator,
+    );
+
+    lwe_ciphertext_add_assign(lwe, &zero_encryption);
+}
+
+/// Add fresh Gaussian noise with the given `flooding_noise_parameters` to the body of an [`LWE
+/// ciphertext`](`LweCiphertext`).
+///
+/// This hides the noise of the ciphertext, which depends on the computations it went through, at
+/// the cost of a large noise growth: the caller is responsible for choosing parameters leaving
+/// enough room for the flooding noise. Combine it with a re-randomization to also refresh the mask
+/// of the ciphertext.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// // Flood the noise with a standard deviation 2^8 times larger than the encryption one
+/// let flooding_std_dev = StandardDev(lwe_modular_std_dev.0 * 2f64.powi(8));
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // With a 4 bits message there is room for the flooding noise
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// let mut lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// flood_lwe_ciphertext_noise(&mut lwe, flooding_std_dev, &mut encryption_generator);
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &lwe);
+///
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+/// let cleartext = decomposer.closest_representable(decrypted_plaintext.0) >> 60;
+///
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn flood_lwe_ciphertext_noise<Scalar, OutputCont, Gen>(
+    lwe: &mut LweCiphertext<OutputCont>,
+    flooding_noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let ciphertext_modulus = lwe.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+
+    let mut noise: Scalar =
+        generator.random_noise_custom_mod(flooding_noise_parameters, ciphertext_modulus);
+
+    if !ciphertext_modulus.is_native_modulus() {
+        noise = noise.wrapping_mul(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+    }
+
+    let body = lwe.get_mut_body();
+    *body.data = (*body.data).wrapping_add(noise);
+}
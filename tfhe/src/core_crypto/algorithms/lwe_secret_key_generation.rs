@@ -2,8 +2,8 @@
 //! [`LWE secret keys`](`LweSecretKey`).
 
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
-use crate::core_crypto::commons::numeric::Numeric;
+use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary, UniformTernary};
+use crate::core_crypto::commons::numeric::{Numeric, UnsignedInteger};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -64,3 +64,307 @@ pub fn generate_binary_lwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(lwe_secret_key.as_mut())
 }
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with uniformly random ternary
+/// coefficients, i.e. coefficients in $\{-1, 0, 1\}$, negative coefficients being represented
+/// modulo $2^{Scalar::BITS}$.
+///
+/// See [`generate_ternary_lwe_secret_key`] for usage.
+pub fn allocate_and_generate_new_ternary_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformTernary> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_ternary_lwe_secret_key(&mut lwe_secret_key, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with uniformly random ternary coefficients.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_ternary_lwe_secret_key(&mut lwe_secret_key, &mut secret_generator);
+///
+/// // All coefficients are in {-1, 0, 1}
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn generate_ternary_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformTernary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_uniform_ternary(lwe_secret_key.as_mut())
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with coefficients sampled from a
+/// centered discrete gaussian distribution, negative coefficients being represented modulo
+/// $2^{Scalar::BITS}$.
+///
+/// See [`generate_gaussian_lwe_secret_key`] for usage.
+pub fn allocate_and_generate_new_gaussian_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    std_dev: f64,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_gaussian_lwe_secret_key(&mut lwe_secret_key, std_dev, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with coefficients sampled from a centered discrete
+/// gaussian distribution with standard deviation `std_dev`, the standard deviation is expressed on
+/// the integers, i.e. it is not normalized by the ciphertext modulus.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let std_dev = 3.2;
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_gaussian_lwe_secret_key(&mut lwe_secret_key, std_dev, &mut secret_generator);
+///
+/// // Check all coefficients are within 10 standard deviations
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| (elt as i64).unsigned_abs() <= 32));
+/// ```
+pub fn generate_gaussian_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    std_dev: f64,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_gaussian_integers(lwe_secret_key.as_mut(), std_dev)
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with coefficients following the
+/// given [`SecretKeyDistribution`].
+pub fn allocate_and_generate_new_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    secret_key_distribution: SecretKeyDistribution,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary> + RandomGenerable<UniformTernary>,
+    Gen: ByteRandomGenerator,
+{
+    match secret_key_distribution {
+        SecretKeyDistribution::Binary => {
+            allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, generator)
+        }
+        SecretKeyDistribution::Ternary => {
+            allocate_and_generate_new_ternary_lwe_secret_key(lwe_dimension, generator)
+        }
+        SecretKeyDistribution::Gaussian { std_dev } => {
+            allocate_and_generate_new_gaussian_lwe_secret_key(lwe_dimension, std_dev, generator)
+        }
+    }
+}
+
+fn secret_key_coefficient_abs<Scalar: UnsignedInteger>(coefficient: Scalar) -> Scalar {
+    // Negative coefficients are represented modulo 2^Scalar::BITS
+    if coefficient > Scalar::MAX >> 1 {
+        coefficient.wrapping_neg()
+    } else {
+        coefficient
+    }
+}
+
+/// Return the minimum number of bit planes of the binary expansion of an
+/// [`LWE secret key`](`LweSecretKey`), i.e. the number of bits required to represent the absolute
+/// value of its largest coefficient.
+///
+/// The returned value depends on the key, keys are expanded with the number of bit planes of their
+/// distribution given by [`SecretKeyDistribution::bit_plane_count`], which is always at least the
+/// returned value. See [`generate_binary_expanded_lwe_secret_key`] for more details.
+pub fn lwe_secret_key_bit_plane_count<Scalar, InCont>(
+    lwe_secret_key: &LweSecretKey<InCont>,
+) -> SecretKeyBitPlaneCount
+where
+    Scalar: UnsignedInteger,
+    InCont: Container<Element = Scalar>,
+{
+    let max_abs_coefficient = lwe_secret_key
+        .as_ref()
+        .iter()
+        .map(|&coefficient| secret_key_coefficient_abs(coefficient))
+        .max()
+        .unwrap_or(Scalar::ZERO);
+
+    SecretKeyBitPlaneCount((Scalar::BITS - max_abs_coefficient.leading_zeros() as usize).max(1))
+}
+
+/// Fill an output [`LWE secret key`](`LweSecretKey`) with the binary expansion of a non binary
+/// input [`LWE secret key`](`LweSecretKey`).
+///
+/// Each coefficient $s\_i$ of the input key is mapped to $2L$ binary coefficients, where $L$ is
+/// the number of bit planes of the expansion: for each bit plane $j$ the output contains the
+/// $j$-th bit of $s\_i$ if $s\_i \geq 0$ (and 0 otherwise) followed by the $j$-th bit of $-s\_i$ if
+/// $s\_i < 0$ (and 0 otherwise), so that
+/// $s\_i = \sum\_{j=0}^{L-1} 2^j \left(b^+\_{i,j} - b^-\_{i,j}\right)$.
+///
+/// An [`LWE bootstrap key`](`LweBootstrapKey`) generated from the binary expanded key can be used
+/// to bootstrap ciphertexts encrypted under the input key, the blind rotation then computes two
+/// CMuxes per bit plane, rotating the accumulator by $X^{2^j \tilde{a}\_i}$ and
+/// $X^{-2^j \tilde{a}\_i}$. For a ternary key this is one CMux for each of the $+1$ and $-1$ values
+/// of a coefficient.
+///
+/// The output [`LweDimension`] must be $2L$ times the input [`LweDimension`], with $L$ at least
+/// the value returned by [`lwe_secret_key_bit_plane_count`]. To avoid leaking information on the
+/// key through the size of the expansion, $L$ should be the value returned by
+/// [`SecretKeyDistribution::bit_plane_count`] for the distribution of the key.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let lwe_dimension = LweDimension(742);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let lwe_secret_key: LweSecretKeyOwned<u64> =
+///     allocate_and_generate_new_ternary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let bit_plane_count = SecretKeyDistribution::Ternary.bit_plane_count();
+/// assert_eq!(bit_plane_count, SecretKeyBitPlaneCount(1));
+///
+/// let mut expanded_lwe_secret_key =
+///     LweSecretKey::new_empty_key(0u64, LweDimension(2 * bit_plane_count.0 * lwe_dimension.0));
+///
+/// generate_binary_expanded_lwe_secret_key(&lwe_secret_key, &mut expanded_lwe_secret_key);
+///
+/// // Recompose the input key from its binary expansion
+/// for (&coefficient, bits) in lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .zip(expanded_lwe_secret_key.as_ref().chunks_exact(2))
+/// {
+///     assert!(bits.iter().all(|&bit| bit == 0 || bit == 1));
+///     assert_eq!(coefficient, bits[0].wrapping_sub(bits[1]));
+/// }
+/// ```
+pub fn generate_binary_expanded_lwe_secret_key<Scalar, InputCont, OutputCont>(
+    input_lwe_secret_key: &LweSecretKey<InputCont>,
+    output_lwe_secret_key: &mut LweSecretKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let input_lwe_dimension = input_lwe_secret_key.lwe_dimension();
+    let output_lwe_dimension = output_lwe_secret_key.lwe_dimension();
+
+    assert!(
+        output_lwe_dimension.0 % (2 * input_lwe_dimension.0) == 0,
+        "The output LweDimension ({output_lwe_dimension:?}) must be a multiple of twice the \
+        input LweDimension ({input_lwe_dimension:?})."
+    );
+
+    let bit_plane_count = output_lwe_dimension.0 / (2 * input_lwe_dimension.0);
+    let required_bit_plane_count = lwe_secret_key_bit_plane_count(input_lwe_secret_key);
+
+    assert!(
+        bit_plane_count >= required_bit_plane_count.0,
+        "The output LweDimension ({output_lwe_dimension:?}) allows to store {bit_plane_count} \
+        bit planes, {required_bit_plane_count:?} are required to expand the input key."
+    );
+
+    for (&coefficient, expanded_coefficient) in input_lwe_secret_key.as_ref().iter().zip(
+        output_lwe_secret_key
+            .as_mut()
+            .chunks_exact_mut(2 * bit_plane_count),
+    ) {
+        let abs_coefficient = secret_key_coefficient_abs(coefficient);
+        let sign_index = usize::from(abs_coefficient != coefficient);
+
+        for (bit_index, bits) in expanded_coefficient.chunks_exact_mut(2).enumerate() {
+            bits.fill(Scalar::ZERO);
+            bits[sign_index] = (abs_coefficient >> bit_index) & Scalar::ONE;
+        }
+    }
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with the binary expansion of the
+/// input [`LWE secret key`](`LweSecretKey`) sampled from `secret_key_distribution`, using the
+/// number of bit planes of the distribution, see [`SecretKeyDistribution::bit_plane_count`].
+///
+/// See [`generate_binary_expanded_lwe_secret_key`] for more details and
+/// [`programmable_bootstrap_lwe_ciphertext`](`super::programmable_bootstrap_lwe_ciphertext`) for
+/// the bootstrap of ciphertexts encrypted under a non binary key.
+pub fn allocate_and_generate_new_binary_expanded_lwe_secret_key<Scalar, InputCont>(
+    input_lwe_secret_key: &LweSecretKey<InputCont>,
+    secret_key_distribution: SecretKeyDistribution,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let bit_plane_count = secret_key_distribution.bit_plane_count();
+
+    let mut output_lwe_secret_key = LweSecretKeyOwned::new_empty_key(
+        Scalar::ZERO,
+        LweDimension(2 * bit_plane_count.0 * input_lwe_secret_key.lwe_dimension().0),
+    );
+
+    generate_binary_expanded_lwe_secret_key(input_lwe_secret_key, &mut output_lwe_secret_key);
+
+    output_lwe_secret_key
+}
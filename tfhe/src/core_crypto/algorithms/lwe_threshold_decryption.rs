@@ -0,0 +1,556 @@
+//! Module containing primitives pertaining to the threshold decryption of
+//! [`LWE ciphertexts`](`LweCiphertext`), where no single party holds the secret key.
+//!
+//! A dealer splits an [`LweSecretKey`] (or a [`GlweSecretKey`]) in [`shares`](`LweSecretKeyShare`)
+//! following a [`SecretSharingScheme`]. Each party computes a
+//! [`partial decryption`](`LwePartialDecryption`) of a ciphertext with its share, flooding it with
+//! noise so that it does not leak information on the share. Enough partial decryptions are then
+//! combined to recover the plaintext.
+//!
+//! # Formal Definition
+//!
+//! For an LWE ciphertext $(\vec{a}, b)$ with $b = \langle\vec{a}, \vec{s}\rangle + \Delta m + e$:
+//!
+//! - with additive sharing the key is split as $\vec{s} = \sum\_{i=1}^{n} \vec{s}\_i$ and all $n$
+//!   parties are required;
+//! - with Shamir sharing of threshold $t$, the party $i$ receives $\vec{s}\_i = f(i)$ for a random
+//!   polynomial $f$ of degree $t - 1$ with $f(0) = (n!)^{-1} \vec{s}$. For a set $S$ of $t$
+//!   parties the integers $\lambda\_i = n! \prod\_{j \in S, j \neq i} \frac{j}{j - i}$ verify
+//!   $\sum\_{i \in S} \lambda\_i \vec{s}\_i = \vec{s}$. Scaling by $n!$ keeps the $\lambda\_i$
+//!   integral, which avoids amplifying the flooding noise by arbitrary elements of
+//!   $\mathbb{Z}\_q$, but requires $n!$ to be invertible modulo $q$.
+//!
+//! The party $i$ outputs $p\_i = \langle\vec{a}, \vec{s}\_i\rangle + e\_i$ with $e\_i$ the flooding
+//! noise and the plaintext is recovered as $b - \sum\_{i} \lambda\_i p\_i = \Delta m + e -
+//! \sum\_{i} \lambda\_i e\_i$ (with $\lambda\_i = 1$ for additive sharing). The flooding noise must
+//! therefore be chosen large enough to hide the secret shares yet small enough that the combined
+//! noise still allows a correct decoding. With Shamir sharing the $\lambda\_i$ grow quickly with
+//! the number of parties, which limits the number of parties usable in practice.
+//!
+//! # Flooding Noise
+//!
+//! Combining the partial decryptions reveals $\Delta m + e$, and therefore the noise $e$ of the
+//! ciphertext which depends on the secret key, unless it is hidden by the flooding noise. If
+//! $|e| \leq B$ and the $e\_i$ are sampled from a centered gaussian of standard deviation
+//! $\sigma \geq 2^{\lambda} B$, the partial decryptions can be simulated without the shares up to
+//! a statistical distance of $2^{-\lambda}$, with $\lambda = 40$ a common choice of statistical
+//! security parameter. The bound $B$ must hold for the ciphertext being decrypted, it grows with
+//! the computations the ciphertext went through. A flooding noise only a few times larger than
+//! the ciphertext noise does not hide it.
+//!
+//! The decoding is then correct as long as $\sqrt{\sum\_{i} \lambda\_i^2} \cdot \sigma$ is
+//! small compared to $\Delta / 2$, i.e. the parameters must leave about $\lambda$ bits between
+//! the noise of the ciphertext and the encoded message.
+
+use crate::core_crypto::algorithms::slice_algorithms::*;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Split an [`LweSecretKey`] in shares following the given [`SecretSharingScheme`].
+///
+/// The share at index `i` of the returned vector belongs to the party with
+/// [`ThresholdPartyIndex`] `i + 1`.
+///
+/// # Panics
+///
+/// Panics if the [`SecretSharingScheme::Shamir`] scheme is requested for a ciphertext modulus
+/// which is not invertible modulo every integer up to the number of parties, e.g. for the native
+/// or any power of 2 modulus.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let lwe_dimension = LweDimension(2048);
+/// let lwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // The noise of a fresh encryption is bounded by 8 standard deviations except with a
+/// // probability below 2^-49, flood it with a noise 2^40 times larger for the partial
+/// // decryptions to be statistically hiding
+/// let statistical_security = 40;
+/// let input_noise_bound = 8.0 * lwe_modular_std_dev.0;
+/// let flooding_std_dev = StandardDev(2.0f64.powi(statistical_security) * input_noise_bound);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let sharing_scheme = SecretSharingScheme::Additive {
+///     party_count: ThresholdPartyCount(3),
+/// };
+/// let shares = allocate_and_share_lwe_secret_key(
+///     &lwe_secret_key,
+///     sharing_scheme,
+///     ciphertext_modulus,
+///     &mut secret_generator,
+/// );
+///
+/// // A 2 bits message leaves room for the flooding noise
+/// let msg = 3u64;
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     Plaintext(msg << 62),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Each party computes its partial decryption, here with its own generator
+/// let partial_decryptions: Vec<_> = shares
+///     .iter()
+///     .map(|share| {
+///         let mut party_generator =
+///             EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///         partial_decrypt_lwe_ciphertext(share, &lwe, flooding_std_dev, &mut party_generator)
+///     })
+///     .collect();
+///
+/// let plaintext = combine_lwe_partial_decryptions(&lwe, &partial_decryptions);
+///
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(2), DecompositionLevelCount(1));
+/// assert_eq!(decomposer.closest_representable(plaintext.0) >> 62, msg);
+/// ```
+pub fn allocate_and_share_lwe_secret_key<Scalar, KeyCont, Gen>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    sharing_scheme: SecretSharingScheme,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> Vec<LweSecretKeyShareOwned<Scalar>>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    share_secret_key_coefficients(
+        lwe_secret_key.as_ref(),
+        sharing_scheme,
+        ciphertext_modulus,
+        generator,
+    )
+    .into_iter()
+    .enumerate()
+    .map(|(index, share)| {
+        LweSecretKeyShare::from_container(
+            share,
+            ThresholdPartyIndex(index + 1),
+            sharing_scheme,
+            ciphertext_modulus,
+        )
+    })
+    .collect()
+}
+
+/// Split a [`GlweSecretKey`] in shares following the given [`SecretSharingScheme`].
+///
+/// The share at index `i` of the returned vector belongs to the party with
+/// [`ThresholdPartyIndex`] `i + 1`.
+///
+/// See [`allocate_and_share_lwe_secret_key`] for the panics and usage.
+pub fn allocate_and_share_glwe_secret_key<Scalar, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    sharing_scheme: SecretSharingScheme,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> Vec<GlweSecretKeyShareOwned<Scalar>>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    share_secret_key_coefficients(
+        glwe_secret_key.as_ref(),
+        sharing_scheme,
+        ciphertext_modulus,
+        generator,
+    )
+    .into_iter()
+    .enumerate()
+    .map(|(index, share)| {
+        GlweSecretKeyShare::from_container(
+            share,
+            glwe_secret_key.polynomial_size(),
+            ThresholdPartyIndex(index + 1),
+            sharing_scheme,
+            ciphertext_modulus,
+        )
+    })
+    .collect()
+}
+
+/// Compute the [`LwePartialDecryption`] of an [`LweCiphertext`] with an [`LweSecretKeyShare`].
+///
+/// The partial decryption is flooded with a noise sampled from a centered gaussian with the given
+/// dispersion, so that it does not leak the share of the party. All the parties of a threshold
+/// decryption are expected to use the same flooding noise parameters, with a standard deviation
+/// at least $2^{\lambda}$ times larger than a bound on the noise of the ciphertext for a
+/// statistical security of $\lambda$ bits, see the [`module documentation`](`self`).
+///
+/// See [`allocate_and_share_lwe_secret_key`] for usage.
+pub fn partial_decrypt_lwe_ciphertext<Scalar, KeyCont, InputCont, Gen>(
+    lwe_secret_key_share: &LweSecretKeyShare<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    flooding_noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> LwePartialDecryption<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        lwe_ciphertext.lwe_size().to_lwe_dimension() == lwe_secret_key_share.lwe_dimension(),
+        "Mismatch between LweDimension of input ciphertext and input secret key share. \
+        Got {:?} in input, and {:?} in secret key share.",
+        lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        lwe_secret_key_share.lwe_dimension()
+    );
+
+    let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
+
+    assert_eq!(
+        ciphertext_modulus,
+        lwe_secret_key_share.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between input ciphertext and input secret key share"
+    );
+
+    let mask = lwe_ciphertext.get_mask();
+    let noise: Scalar =
+        generator.random_noise_custom_mod(flooding_noise_parameters, ciphertext_modulus);
+
+    let data = if ciphertext_modulus.is_compatible_with_native_modulus() {
+        let noise = if ciphertext_modulus.is_native_modulus() {
+            noise
+        } else {
+            noise.wrapping_mul(ciphertext_modulus.get_power_of_two_scaling_to_native_torus())
+        };
+
+        slice_wrapping_dot_product(mask.as_ref(), lwe_secret_key_share.as_ref()).wrapping_add(noise)
+    } else {
+        let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+
+        slice_wrapping_dot_product_custom_mod(
+            mask.as_ref(),
+            lwe_secret_key_share.as_ref(),
+            custom_modulus,
+        )
+        .wrapping_add_custom_mod(noise, custom_modulus)
+    };
+
+    LwePartialDecryption {
+        data,
+        party_index: lwe_secret_key_share.party_index(),
+        sharing_scheme: lwe_secret_key_share.sharing_scheme(),
+    }
+}
+
+/// Combine [`LwePartialDecryptions`](`LwePartialDecryption`) of an [`LweCiphertext`] to recover
+/// the encrypted [`Plaintext`].
+///
+/// At least as many partial decryptions as the threshold of the [`SecretSharingScheme`] must be
+/// provided. With Shamir sharing only the first `threshold` partial decryptions are used, as every
+/// additional party increases the noise of the recovered plaintext.
+///
+/// # Panics
+///
+/// Panics if the partial decryptions were computed with different sharing schemes, if several
+/// partial decryptions come from the same party or if too few partial decryptions are provided.
+///
+/// See [`allocate_and_share_lwe_secret_key`] for usage.
+pub fn combine_lwe_partial_decryptions<Scalar, InputCont>(
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    partial_decryptions: &[LwePartialDecryption<Scalar>],
+) -> Plaintext<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        !partial_decryptions.is_empty(),
+        "Got no partial decryption to combine"
+    );
+
+    let sharing_scheme = partial_decryptions[0].sharing_scheme();
+
+    assert!(
+        partial_decryptions
+            .iter()
+            .all(|partial| partial.sharing_scheme() == sharing_scheme),
+        "Got partial decryptions computed with different sharing schemes"
+    );
+
+    let threshold = sharing_scheme.threshold();
+
+    assert!(
+        partial_decryptions.len() >= threshold,
+        "Got {} partial decryptions, {threshold} are required by {sharing_scheme:?}",
+        partial_decryptions.len(),
+    );
+
+    let partial_decryptions = &partial_decryptions[..threshold];
+
+    let mut party_indices: Vec<_> = partial_decryptions
+        .iter()
+        .map(LwePartialDecryption::party_index)
+        .collect();
+    party_indices.sort_unstable();
+    party_indices.dedup();
+
+    assert!(
+        party_indices.len() == threshold,
+        "Got several partial decryptions from the same party"
+    );
+
+    let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
+    let body = *lwe_ciphertext.get_body().data;
+
+    match sharing_scheme {
+        SecretSharingScheme::Additive { .. } => {
+            let combined = if ciphertext_modulus.is_compatible_with_native_modulus() {
+                partial_decryptions
+                    .iter()
+                    .fold(body, |acc, partial| acc.wrapping_sub(partial.data))
+            } else {
+                let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+                partial_decryptions.iter().fold(body, |acc, partial| {
+                    acc.wrapping_sub_custom_mod(partial.data, custom_modulus)
+                })
+            };
+
+            if ciphertext_modulus.is_compatible_with_native_modulus()
+                && !ciphertext_modulus.is_native_modulus()
+            {
+                Plaintext(
+                    combined.wrapping_div(
+                        ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+                    ),
+                )
+            } else {
+                Plaintext(combined)
+            }
+        }
+        SecretSharingScheme::Shamir { party_count, .. } => {
+            assert!(
+                !ciphertext_modulus.is_compatible_with_native_modulus(),
+                "Shamir sharing is not supported for power of 2 ciphertext moduli"
+            );
+            let custom_modulus = ciphertext_modulus.get_custom_modulus();
+            let custom_modulus_scalar: Scalar = custom_modulus.cast_into();
+
+            Plaintext(partial_decryptions.iter().fold(body, |acc, partial| {
+                let lagrange_coefficient =
+                    scaled_lagrange_coefficient(partial.party_index, &party_indices, party_count)
+                        .rem_euclid(custom_modulus as i128);
+                let lagrange_coefficient: Scalar = (lagrange_coefficient as u128).cast_into();
+
+                acc.wrapping_sub_custom_mod(
+                    partial
+                        .data
+                        .wrapping_mul_custom_mod(lagrange_coefficient, custom_modulus_scalar),
+                    custom_modulus_scalar,
+                )
+            }))
+        }
+    }
+}
+
+fn share_secret_key_coefficients<Scalar, Gen>(
+    secret_key: &[Scalar],
+    sharing_scheme: SecretSharingScheme,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> Vec<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+    Gen: ByteRandomGenerator,
+{
+    let party_count = sharing_scheme.party_count().0;
+
+    assert!(
+        party_count > 0,
+        "Cannot share a secret key between 0 parties"
+    );
+
+    match sharing_scheme {
+        SecretSharingScheme::Additive { .. } => {
+            let mut shares = vec![vec![Scalar::ZERO; secret_key.len()]; party_count];
+            let (last_share, random_shares) = shares.split_last_mut().unwrap();
+
+            last_share.copy_from_slice(secret_key);
+
+            if ciphertext_modulus.is_compatible_with_native_modulus() {
+                for share in random_shares {
+                    generator.fill_slice_with_random_uniform_custom_mod(
+                        share,
+                        CiphertextModulus::new_native(),
+                    );
+                    slice_wrapping_sub_assign(last_share, share);
+                }
+            } else {
+                let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+                reduce_secret_key_coefficients(last_share, custom_modulus);
+
+                for share in random_shares {
+                    generator.fill_slice_with_random_uniform_custom_mod(share, ciphertext_modulus);
+                    slice_wrapping_sub_assign_custom_mod(last_share, share, custom_modulus);
+                }
+            }
+
+            shares
+        }
+        SecretSharingScheme::Shamir { threshold, .. } => {
+            assert!(
+                threshold >= 1 && threshold <= party_count,
+                "Invalid Shamir threshold {threshold} for {party_count} parties"
+            );
+            assert!(
+                !ciphertext_modulus.is_compatible_with_native_modulus(),
+                "Shamir sharing is not supported for power of 2 ciphertext moduli, \
+                use additive sharing instead"
+            );
+
+            let custom_modulus = ciphertext_modulus.get_custom_modulus();
+            assert!(
+                (2..=party_count as u128).all(|divisor| custom_modulus % divisor != 0),
+                "Shamir sharing between {party_count} parties requires a ciphertext modulus with \
+                no prime factor smaller or equal to {party_count}, got {custom_modulus}"
+            );
+
+            let custom_modulus_scalar: Scalar = custom_modulus.cast_into();
+
+            let party_count_factorial =
+                (2..=party_count as u128).fold(1u128, |acc, value| acc * value % custom_modulus);
+            let inverse_factorial: Scalar =
+                modular_inverse(party_count_factorial, custom_modulus).cast_into();
+
+            // The secret of the sharing is the key scaled by (n!)^-1 so that integral Lagrange
+            // coefficients can be used during the combination
+            let mut scaled_secret_key = secret_key.to_vec();
+            reduce_secret_key_coefficients(&mut scaled_secret_key, custom_modulus_scalar);
+            slice_wrapping_scalar_mul_assign_custom_mod(
+                &mut scaled_secret_key,
+                inverse_factorial,
+                custom_modulus_scalar,
+            );
+
+            let mut polynomial_coefficients =
+                vec![Scalar::ZERO; (threshold - 1) * secret_key.len()];
+            generator.fill_slice_with_random_uniform_custom_mod(
+                &mut polynomial_coefficients,
+                ciphertext_modulus,
+            );
+
+            (1..=party_count)
+                .map(|party_index| {
+                    let party_index: Scalar = (party_index as u128).cast_into();
+                    // Horner evaluation of the sharing polynomials at the party index
+                    let mut share = vec![Scalar::ZERO; secret_key.len()];
+                    for coefficients in polynomial_coefficients.chunks_exact(secret_key.len()).rev()
+                    {
+                        slice_wrapping_add_assign_custom_mod(
+                            &mut share,
+                            coefficients,
+                            custom_modulus_scalar,
+                        );
+                        slice_wrapping_scalar_mul_assign_custom_mod(
+                            &mut share,
+                            party_index,
+                            custom_modulus_scalar,
+                        );
+                    }
+                    slice_wrapping_add_assign_custom_mod(
+                        &mut share,
+                        &scaled_secret_key,
+                        custom_modulus_scalar,
+                    );
+                    share
+                })
+                .collect()
+        }
+    }
+}
+
+/// Map secret key coefficients, which may be negative for non binary keys, to their canonical
+/// representative modulo a custom modulus.
+fn reduce_secret_key_coefficients<Scalar: UnsignedInteger>(
+    coefficients: &mut [Scalar],
+    custom_modulus: Scalar,
+) {
+    for coefficient in coefficients.iter_mut() {
+        let is_negative = (*coefficient >> (Scalar::BITS - 1)) == Scalar::ONE;
+        *coefficient = if is_negative {
+            coefficient
+                .wrapping_neg()
+                .wrapping_rem(custom_modulus)
+                .wrapping_neg_custom_mod(custom_modulus)
+        } else {
+            coefficient.wrapping_rem(custom_modulus)
+        };
+    }
+}
+
+/// Return the Lagrange coefficient of `party_index` for the parties in `party_indices`, evaluated
+/// in 0 and scaled by $n!$.
+///
+/// The scaled coefficient is an integer as the product of the differences between party indices
+/// divides $n!$.
+fn scaled_lagrange_coefficient(
+    party_index: ThresholdPartyIndex,
+    party_indices: &[ThresholdPartyIndex],
+    party_count: ThresholdPartyCount,
+) -> i128 {
+    let overflow_msg = "Overflow while computing Lagrange coefficients, too many parties";
+
+    let party_count_factorial = (2..=party_count.0 as i128)
+        .try_fold(1i128, |acc, value| acc.checked_mul(value))
+        .expect(overflow_msg);
+
+    let i = party_index.0 as i128;
+    let (numerator, denominator) = party_indices
+        .iter()
+        .map(|other| other.0 as i128)
+        .filter(|&j| j != i)
+        .fold((1i128, 1i128), |(numerator, denominator), j| {
+            (
+                numerator.checked_mul(j).expect(overflow_msg),
+                denominator.checked_mul(j - i).expect(overflow_msg),
+            )
+        });
+
+    assert_eq!(party_count_factorial % denominator, 0);
+
+    (party_count_factorial / denominator)
+        .checked_mul(numerator)
+        .expect(overflow_msg)
+}
+
+/// Return the inverse of `value` modulo `modulus`, which must fit in 64 bits.
+fn modular_inverse(value: u128, modulus: u128) -> u128 {
+    let modulus = modulus as i128;
+    let (mut old_r, mut r) = (value as i128, modulus);
+    let (mut old_s, mut s) = (1i128, 0i128);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    assert_eq!(old_r, 1, "{value} is not invertible modulo {modulus}");
+
+    old_s.rem_euclid(modulus) as u128
+}
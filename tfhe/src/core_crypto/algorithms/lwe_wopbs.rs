@@ -1,19 +1,23 @@
 //! Module containing primitives pertaining to the Wopbs (WithOut padding PBS).
 
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
+use crate::core_crypto::fft_impl::fft64::crypto::ggsw::fill_with_forward_fourier_scratch;
 use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::{
+    circuit_bootstrap_boolean, circuit_bootstrap_boolean_scratch,
     circuit_bootstrap_boolean_vertical_packing, circuit_bootstrap_boolean_vertical_packing_scratch,
-    extract_bits, extract_bits_scratch,
+    extract_bits, extract_bits_scratch, vertical_packing, vertical_packing_scratch,
 };
-use crate::core_crypto::fft_impl::fft64::math::fft::FftView;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use aligned_vec::CACHELINE_ALIGN;
 use concrete_fft::c64;
-use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
 use rayon::prelude::*;
 
 /// Allocate a new [`list of LWE private functional packing keyswitch
@@ -730,3 +734,669 @@ pub fn circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_list_mem_optimi
         fft,
     )
 }
+
+/// Perform a boolean circuit bootstrapping of the `lwe_in` [`LWE ciphertext`](`LweCiphertext`)
+/// and write the resulting [`GGSW ciphertext`](`GgswCiphertext`) in `ggsw_out`. The term "boolean"
+/// refers to the fact the input ciphertext encrypts a single bit of message, located at the bit
+/// index `delta_log` (0-indexed) of the plaintext, e.g. `DeltaLog(Scalar::BITS - 1)` for the
+/// outputs of [`extract_bits_from_lwe_ciphertext_mem_optimized`].
+///
+/// The decomposition base log and level count of `ggsw_out` are used as the circuit bootstrapping
+/// parameters. The output GGSW ciphertext encrypts the input bit under the output GLWE secret key
+/// of the `pfpksk_list`, and can be used as a selector for CMux operations.
+///
+/// This function allocates the required [`Fft`] and memory buffers, see
+/// [`circuit_bootstrap_lwe_ciphertext_to_ggsw_mem_optimized`] to manage them yourself.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_dimension = GlweDimension(1);
+/// let lwe_dimension = LweDimension(481);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let var_small = Variance::from_variance(2f64.powf(-80.0));
+/// let var_big = Variance::from_variance(2f64.powf(-70.0));
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let lwe_small_sk =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+/// let lwe_big_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let std_bsk: LweBootstrapKeyOwned<u64> = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &lwe_small_sk,
+///     &glwe_sk,
+///     DecompositionBaseLog(4),
+///     DecompositionLevelCount(9),
+///     var_small,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+///     std_bsk.input_lwe_dimension(),
+///     std_bsk.glwe_size(),
+///     std_bsk.polynomial_size(),
+///     std_bsk.decomposition_base_log(),
+///     std_bsk.decomposition_level_count(),
+/// );
+///
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bsk, &mut fourier_bsk);
+///
+/// let cbs_pfpksk = par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list(
+///     &lwe_big_sk,
+///     &glwe_sk,
+///     DecompositionBaseLog(4),
+///     DecompositionLevelCount(9),
+///     var_small,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // The bit is encrypted in the MSB of the plaintext
+/// let delta_log = DeltaLog(63);
+///
+/// for bit in [0u64, 1] {
+///     let lwe_in = allocate_and_encrypt_new_lwe_ciphertext(
+///         &lwe_small_sk,
+///         Plaintext(bit << delta_log.0),
+///         var_big,
+///         ciphertext_modulus,
+///         &mut encryption_generator,
+///     );
+///
+///     let mut ggsw = GgswCiphertext::new(
+///         0u64,
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         DecompositionBaseLog(6),
+///         DecompositionLevelCount(4),
+///         ciphertext_modulus,
+///     );
+///
+///     circuit_bootstrap_lwe_ciphertext_to_ggsw(
+///         &lwe_in,
+///         &mut ggsw,
+///         &fourier_bsk,
+///         &cbs_pfpksk,
+///         delta_log,
+///     );
+///
+///     let decrypted = decrypt_constant_ggsw_ciphertext(&glwe_sk, &ggsw);
+///     assert_eq!(decrypted.0, bit);
+/// }
+/// ```
+pub fn circuit_bootstrap_lwe_ciphertext_to_ggsw<
+    Scalar,
+    InputCont,
+    OutputCont,
+    BskCont,
+    PFPKSKCont,
+>(
+    lwe_in: &LweCiphertext<InputCont>,
+    ggsw_out: &mut GgswCiphertext<OutputCont>,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    pfpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<PFPKSKCont>,
+    delta_log: DeltaLog,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    BskCont: Container<Element = c64>,
+    PFPKSKCont: Container<Element = Scalar>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(ggsw_out.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        circuit_bootstrap_lwe_ciphertext_to_ggsw_mem_optimized_requirement::<Scalar>(
+            lwe_in.lwe_size(),
+            fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            ggsw_out.glwe_size(),
+            ggsw_out.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    circuit_bootstrap_lwe_ciphertext_to_ggsw_mem_optimized(
+        lwe_in,
+        ggsw_out,
+        fourier_bsk,
+        pfpksk_list,
+        delta_log,
+        fft,
+        buffers.stack(),
+    );
+}
+
+fn check_circuit_bootstrap_inputs<Scalar, InputCont, BskCont, PFPKSKCont>(
+    lwe_in: &LweCiphertext<InputCont>,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    pfpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<PFPKSKCont>,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    BskCont: Container<Element = c64>,
+    PFPKSKCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        lwe_in.ciphertext_modulus(),
+        pfpksk_list.ciphertext_modulus()
+    );
+    assert!(
+        pfpksk_list.ciphertext_modulus().is_native_modulus(),
+        "This operation currently only supports native moduli"
+    );
+    assert_eq!(
+        lwe_in.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched LweDimension between the input LWE ciphertext and the bootstrap key input."
+    );
+    assert_eq!(
+        fourier_bsk.output_lwe_dimension(),
+        pfpksk_list.input_key_lwe_dimension(),
+        "Mismatched LweDimension between the bootstrap key output and the \
+        private functional packing keyswitch keys input."
+    );
+    assert_eq!(
+        output_glwe_size,
+        pfpksk_list.output_glwe_size(),
+        "Mismatched GlweSize between the output GGSW ciphertext and the \
+        private functional packing keyswitch keys output."
+    );
+    assert_eq!(
+        output_polynomial_size,
+        pfpksk_list.output_polynomial_size(),
+        "Mismatched PolynomialSize between the output GGSW ciphertext and the \
+        private functional packing keyswitch keys output."
+    );
+    assert_eq!(
+        pfpksk_list.lwe_pfpksk_count().0,
+        output_glwe_size.0,
+        "The circuit bootstrapping requires one private functional packing keyswitch key per \
+        GLWE secret key polynomial and one for the body, expected {} keys, got {}.",
+        output_glwe_size.0,
+        pfpksk_list.lwe_pfpksk_count().0
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Memory optimized version of [`circuit_bootstrap_lwe_ciphertext_to_ggsw`].
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`circuit_bootstrap_lwe_ciphertext_to_ggsw_mem_optimized_requirement`].
+pub fn circuit_bootstrap_lwe_ciphertext_to_ggsw_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    BskCont,
+    PFPKSKCont,
+>(
+    lwe_in: &LweCiphertext<InputCont>,
+    ggsw_out: &mut GgswCiphertext<OutputCont>,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    pfpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<PFPKSKCont>,
+    delta_log: DeltaLog,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    BskCont: Container<Element = c64>,
+    PFPKSKCont: Container<Element = Scalar>,
+{
+    assert_eq!(lwe_in.ciphertext_modulus(), ggsw_out.ciphertext_modulus());
+    check_circuit_bootstrap_inputs(
+        lwe_in,
+        fourier_bsk,
+        pfpksk_list,
+        ggsw_out.glwe_size(),
+        ggsw_out.polynomial_size(),
+    );
+
+    circuit_bootstrap_boolean(
+        fourier_bsk.as_view(),
+        lwe_in.as_view(),
+        ggsw_out.as_mut_view(),
+        delta_log,
+        pfpksk_list.as_view(),
+        fft,
+        stack,
+    )
+}
+
+/// Return the required memory for [`circuit_bootstrap_lwe_ciphertext_to_ggsw_mem_optimized`].
+pub fn circuit_bootstrap_lwe_ciphertext_to_ggsw_mem_optimized_requirement<Scalar>(
+    lwe_in_size: LweSize,
+    bsk_output_lwe_size: LweSize,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    circuit_bootstrap_boolean_scratch::<Scalar>(
+        lwe_in_size,
+        bsk_output_lwe_size,
+        glwe_size,
+        polynomial_size,
+        fft,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Perform a boolean circuit bootstrapping of the `lwe_in` [`LWE ciphertext`](`LweCiphertext`)
+/// and write the resulting GGSW ciphertext in the Fourier domain in `fourier_ggsw_out`, ready to be
+/// used in external products, CMux operations or as part of a [`FourierGgswCiphertextList`] for
+/// [`vertical_packing_lwe_ciphertext_mem_optimized`].
+///
+/// See [`circuit_bootstrap_lwe_ciphertext_to_ggsw`] for the meaning of the parameters. The
+/// `ciphertext_modulus` is the one of the intermediate GGSW ciphertext in the standard domain and
+/// must match the moduli of `lwe_in` and `pfpksk_list`.
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized_requirement`].
+///
+/// See [`vertical_packing_lwe_ciphertext_mem_optimized`] for usage.
+pub fn circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    BskCont,
+    PFPKSKCont,
+>(
+    lwe_in: &LweCiphertext<InputCont>,
+    fourier_ggsw_out: &mut FourierGgswCiphertext<OutputCont>,
+    fourier_bsk: &FourierLweBootstrapKey<BskCont>,
+    pfpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<PFPKSKCont>,
+    delta_log: DeltaLog,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+    BskCont: Container<Element = c64>,
+    PFPKSKCont: Container<Element = Scalar>,
+{
+    assert_eq!(lwe_in.ciphertext_modulus(), ciphertext_modulus);
+
+    let glwe_size = fourier_ggsw_out.glwe_size();
+    let polynomial_size = fourier_ggsw_out.polynomial_size();
+    let decomposition_level_count = fourier_ggsw_out.decomposition_level_count();
+
+    check_circuit_bootstrap_inputs(lwe_in, fourier_bsk, pfpksk_list, glwe_size, polynomial_size);
+
+    let (mut ggsw_data, mut stack) = stack.make_aligned_with(
+        ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+        CACHELINE_ALIGN,
+        |_| Scalar::ZERO,
+    );
+    let mut ggsw = GgswCiphertext::from_container(
+        &mut *ggsw_data,
+        glwe_size,
+        polynomial_size,
+        fourier_ggsw_out.decomposition_base_log(),
+        ciphertext_modulus,
+    );
+
+    circuit_bootstrap_boolean(
+        fourier_bsk.as_view(),
+        lwe_in.as_view(),
+        ggsw.as_mut_view(),
+        delta_log,
+        pfpksk_list.as_view(),
+        fft,
+        stack.rb_mut(),
+    );
+
+    fourier_ggsw_out
+        .as_mut_view()
+        .fill_with_forward_fourier(ggsw.as_view(), fft, stack);
+}
+
+/// Return the required memory for
+/// [`circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized`].
+pub fn circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized_requirement<Scalar>(
+    lwe_in_size: LweSize,
+    bsk_output_lwe_size: LweSize,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        StackReq::try_new_aligned::<Scalar>(
+            ggsw_ciphertext_size(glwe_size, polynomial_size, decomposition_level_count),
+            CACHELINE_ALIGN,
+        )?,
+        StackReq::try_any_of([
+            circuit_bootstrap_boolean_scratch::<Scalar>(
+                lwe_in_size,
+                bsk_output_lwe_size,
+                glwe_size,
+                polynomial_size,
+                fft,
+            )?,
+            fill_with_forward_fourier_scratch(fft)?,
+        ])?,
+    ])
+}
+
+/// Evaluate the `lut` look-up table on the bits encrypted in the `ggsw_list`
+/// [`list of Fourier GGSW ciphertexts`](`FourierGgswCiphertextList`) with a vertical packing,
+/// writing the result in the `lwe_out` [`LWE ciphertext`](`LweCiphertext`).
+///
+/// The GGSW ciphertexts are expected to encrypt the bits of the look-up table index from the most
+/// significant bit (first GGSW ciphertext of the list) to the least significant bit (last GGSW
+/// ciphertext of the list), as output by
+/// [`circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized`].
+///
+/// The number of polynomials in the `lut` must be a power of two $2^p$. The $p$ most significant
+/// GGSW ciphertexts select a polynomial of the `lut` through a CMux tree and the remaining $r$
+/// ones select a coefficient of that polynomial through a blind rotation, so that the value for
+/// index $i$ is read in the coefficient $i \bmod 2^r$ of the polynomial $\lfloor i / 2^r \rfloor$.
+/// $r$ can therefore not exceed $\log\_2(N)$ where $N$ is the polynomial size. When $r$ is smaller
+/// only the first $2^r$ coefficients of each polynomial are used.
+///
+/// The output LWE ciphertext is encrypted under the LWE secret key equivalent to the GLWE secret
+/// key of the GGSW ciphertexts.
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`vertical_packing_lwe_ciphertext_mem_optimized_requirement`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_dimension = GlweDimension(1);
+/// let lwe_dimension = LweDimension(481);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let var_small = Variance::from_variance(2f64.powf(-80.0));
+/// let var_big = Variance::from_variance(2f64.powf(-70.0));
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let lwe_small_sk =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+/// let lwe_big_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let std_bsk: LweBootstrapKeyOwned<u64> = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &lwe_small_sk,
+///     &glwe_sk,
+///     DecompositionBaseLog(4),
+///     DecompositionLevelCount(9),
+///     var_small,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+///     std_bsk.input_lwe_dimension(),
+///     std_bsk.glwe_size(),
+///     std_bsk.polynomial_size(),
+///     std_bsk.decomposition_base_log(),
+///     std_bsk.decomposition_level_count(),
+/// );
+///
+/// let cbs_pfpksk = par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list(
+///     &lwe_big_sk,
+///     &glwe_sk,
+///     DecompositionBaseLog(4),
+///     DecompositionLevelCount(9),
+///     var_small,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let cbs_base_log = DecompositionBaseLog(6);
+/// let cbs_level_count = DecompositionLevelCount(4);
+///
+/// // The index of the look-up table has 3 bits, each encrypted in the MSB of an LWE ciphertext
+/// let index_bits = 3;
+/// let delta_log = DeltaLog(63);
+///
+/// // The look-up table has 2 polynomials: the MSB of the index goes through the CMux tree and the
+/// // two other bits through the blind rotation
+/// let lut_polynomial_count = PolynomialCount(2);
+/// let delta_log_lut = DeltaLog(60);
+/// let mut lut = PolynomialList::new(0u64, polynomial_size, lut_polynomial_count);
+/// for (poly_idx, mut poly) in lut.iter_mut().enumerate() {
+///     for coeff_idx in 0..4 {
+///         let index = (poly_idx * 4 + coeff_idx) as u64;
+///         // Evaluate x -> 7 - x
+///         poly.as_mut()[coeff_idx] = (7 - index) << delta_log_lut.0;
+///     }
+/// }
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+/// let mut buffers = ComputationBuffers::new();
+///
+/// let buffer_size_req =
+///     convert_standard_lwe_bootstrap_key_to_fourier_mem_optimized_requirement(fft)
+///         .unwrap()
+///         .unaligned_bytes_required();
+/// let buffer_size_req = buffer_size_req.max(
+///     circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized_requirement::<u64>(
+///         lwe_dimension.to_lwe_size(),
+///         fourier_bsk.output_lwe_dimension().to_lwe_size(),
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         cbs_level_count,
+///         fft,
+///     )
+///     .unwrap()
+///     .unaligned_bytes_required(),
+/// );
+/// let buffer_size_req = buffer_size_req.max(
+///     vertical_packing_lwe_ciphertext_mem_optimized_requirement::<u64>(
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         lut_polynomial_count,
+///         index_bits,
+///         fft,
+///     )
+///     .unwrap()
+///     .unaligned_bytes_required(),
+/// );
+///
+/// buffers.resize(buffer_size_req);
+///
+/// convert_standard_lwe_bootstrap_key_to_fourier_mem_optimized(
+///     &std_bsk,
+///     &mut fourier_bsk,
+///     fft,
+///     buffers.stack(),
+/// );
+///
+/// let cleartext = 5u64;
+///
+/// let mut ggsw_list = FourierGgswCiphertextList::new(
+///     vec![
+///         Default::default();
+///         index_bits
+///             * polynomial_size.to_fourier_polynomial_size().0
+///             * glwe_dimension.to_glwe_size().0
+///             * glwe_dimension.to_glwe_size().0
+///             * cbs_level_count.0
+///     ],
+///     index_bits,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     cbs_base_log,
+///     cbs_level_count,
+/// );
+///
+/// // Circuit bootstrap each bit of the index, from the MSB to the LSB
+/// for (bit_idx, mut fourier_ggsw) in ggsw_list.as_mut_view().into_ggsw_iter().enumerate() {
+///     let bit = (cleartext >> (index_bits - 1 - bit_idx)) & 1;
+///     let lwe_bit = allocate_and_encrypt_new_lwe_ciphertext(
+///         &lwe_small_sk,
+///         Plaintext(bit << delta_log.0),
+///         var_big,
+///         ciphertext_modulus,
+///         &mut encryption_generator,
+///     );
+///
+///     circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized(
+///         &lwe_bit,
+///         &mut fourier_ggsw,
+///         &fourier_bsk,
+///         &cbs_pfpksk,
+///         delta_log,
+///         ciphertext_modulus,
+///         fft,
+///         buffers.stack(),
+///     );
+/// }
+///
+/// let mut lwe_out = LweCiphertext::new(
+///     0u64,
+///     lwe_big_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// vertical_packing_lwe_ciphertext_mem_optimized(
+///     &lut,
+///     &ggsw_list,
+///     &mut lwe_out,
+///     fft,
+///     buffers.stack(),
+/// );
+///
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let decrypted = decrypt_lwe_ciphertext(&lwe_big_sk, &lwe_out);
+/// let decoded = decomposer.closest_representable(decrypted.0) >> delta_log_lut.0;
+///
+/// assert_eq!(decoded, 7 - cleartext);
+/// ```
+pub fn vertical_packing_lwe_ciphertext_mem_optimized<Scalar, LutCont, GgswCont, OutputCont>(
+    lut: &PolynomialList<LutCont>,
+    ggsw_list: &FourierGgswCiphertextList<GgswCont>,
+    lwe_out: &mut LweCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    LutCont: Container<Element = Scalar>,
+    GgswCont: Container<Element = c64>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        lwe_out.ciphertext_modulus().is_native_modulus(),
+        "This operation currently only supports native moduli"
+    );
+    assert!(ggsw_list.count() > 0, "Got an empty GGSW ciphertext list.");
+    assert_eq!(
+        lut.polynomial_size(),
+        ggsw_list.polynomial_size(),
+        "Mismatched PolynomialSize between the look-up table and the GGSW ciphertexts."
+    );
+    assert_eq!(
+        lwe_out.lwe_size().to_lwe_dimension(),
+        ggsw_list
+            .glwe_size()
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(ggsw_list.polynomial_size()),
+        "Mismatched LweDimension between the output LWE ciphertext and the GGSW ciphertexts."
+    );
+
+    let lut_polynomial_count = lut.polynomial_count().0;
+    assert!(
+        lut_polynomial_count.is_power_of_two(),
+        "The look-up table must contain a power of two number of polynomials, got {}.",
+        lut_polynomial_count
+    );
+
+    let cmux_tree_ggsw_count = lut_polynomial_count.ilog2() as usize;
+    assert!(
+        cmux_tree_ggsw_count <= ggsw_list.count(),
+        "Not enough GGSW ciphertexts to select one of the {} polynomials of the look-up table, \
+        got {} GGSW ciphertexts.",
+        lut_polynomial_count,
+        ggsw_list.count()
+    );
+
+    let blind_rotation_ggsw_count = ggsw_list.count() - cmux_tree_ggsw_count;
+    assert!(
+        blind_rotation_ggsw_count <= ggsw_list.polynomial_size().log2().0,
+        "Too many GGSW ciphertexts for the look-up table: {} GGSW ciphertexts are left for the \
+        blind rotation, which supports at most {} with a PolynomialSize of {}.",
+        blind_rotation_ggsw_count,
+        ggsw_list.polynomial_size().log2().0,
+        ggsw_list.polynomial_size().0
+    );
+
+    vertical_packing(
+        lut.as_view(),
+        lwe_out.as_mut_view(),
+        ggsw_list.as_view(),
+        fft,
+        stack,
+    )
+}
+
+/// Return the required memory for [`vertical_packing_lwe_ciphertext_mem_optimized`].
+pub fn vertical_packing_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    lut_polynomial_count: PolynomialCount,
+    ggsw_list_count: usize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    vertical_packing_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        lut_polynomial_count,
+        ggsw_list_count,
+        fft,
+    )
+}
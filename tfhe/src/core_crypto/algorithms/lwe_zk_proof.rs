@@ -0,0 +1,987 @@
+//! Module containing primitives pertaining to the zero-knowledge proofs attached to the encryption
+//! of [`LWE compact ciphertext lists`](`LweCompactCiphertextList`), see [`crate::zk`] for the
+//! description of the proof system.
+
+use crate::core_crypto::algorithms::slice_algorithms::*;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator, Seed};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::zk::{
+    CompactPkeCrs, CompactPkeProof, CompactPkeProofResponse, ZkVerificationOutCome,
+    PROOF_REPETITIONS,
+};
+use rayon::prelude::*;
+use sha3::{Digest, Sha3_256};
+
+const CHALLENGE_DOMAIN: &[u8] = b"TFHE-rs-compact-pke-zk-proof-challenge";
+const COMMITMENT_DOMAIN: &[u8] = b"TFHE-rs-compact-pke-zk-proof-commitment";
+
+/// Encrypt an input plaintext list in an output [`LWE compact ciphertext
+/// list`](`LweCompactCiphertextList`) using an [`LWE compact public
+/// key`](`LweCompactPublicKey`) and prove that the list is a well formed encryption of in range
+/// messages.
+///
+/// The encryption follows
+/// [`encrypt_lwe_compact_ciphertext_list_with_compact_public_key`], except that noise values above
+/// the bounds of the [`CompactPkeCrs`] are resampled. The returned [`CompactPkeProof`] can be
+/// checked with [`verify_lwe_compact_ciphertext_list`].
+///
+/// # Panics
+///
+/// Panics if a plaintext is not the encoding `m * delta` of a message `m` in
+/// `[0, message_modulus)`, with `delta` and `message_modulus` the ones of the [`CompactPkeCrs`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+/// use tfhe::zk::CompactPkeCrs;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCompactPublicKey creation
+/// let lwe_dimension = LweDimension(512);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let lwe_compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+///     &lwe_secret_key,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let crs = CompactPkeCrs::new(
+///     lwe_dimension,
+///     LweCiphertextCount(4),
+///     glwe_modular_std_dev,
+///     lwe_modular_std_dev,
+///     16,
+///     1 << 60,
+///     seeder,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext_list = PlaintextList::new(msg << 60, PlaintextCount(4));
+///
+/// // Create a new LweCompactCiphertextList
+/// let mut output_compact_ct_list = LweCompactCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     LweCiphertextCount(plaintext_list.plaintext_count().0),
+///     ciphertext_modulus,
+/// );
+///
+/// let proof = encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+///     &lwe_compact_public_key,
+///     &mut output_compact_ct_list,
+///     &plaintext_list,
+///     glwe_modular_std_dev,
+///     lwe_modular_std_dev,
+///     &crs,
+///     &mut secret_generator,
+///     &mut encryption_generator,
+/// );
+///
+/// let outcome = verify_lwe_compact_ciphertext_list(
+///     &output_compact_ct_list,
+///     &lwe_compact_public_key,
+///     &proof,
+///     &crs,
+/// );
+/// assert!(outcome.is_valid());
+///
+/// let output_ct_list = output_compact_ct_list.expand_into_lwe_ciphertext_list();
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+/// decrypt_lwe_ciphertext_list(&lwe_secret_key, &output_ct_list, &mut output_plaintext_list);
+///
+/// // Round and remove encoding
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0) >> 60);
+///
+/// assert!(output_plaintext_list.iter().all(|elt| *elt.0 == msg));
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key<
+    KeyCont,
+    InputCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    mask_noise_parameters: impl DispersionParameter,
+    body_noise_parameters: impl DispersionParameter,
+    crs: &CompactPkeCrs,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) -> CompactPkeProof
+where
+    KeyCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    assert!(
+        output.lwe_size().to_lwe_dimension() == lwe_compact_public_key.lwe_dimension(),
+        "Mismatch between LweDimension of output ciphertext and input public key. \
+    Got {:?} in output, and {:?} in public key.",
+        output.lwe_size().to_lwe_dimension(),
+        lwe_compact_public_key.lwe_dimension()
+    );
+
+    assert!(
+        lwe_compact_public_key.lwe_dimension() == crs.lwe_dimension(),
+        "Mismatch between LweDimension of input public key and CRS. \
+    Got {:?} in public key, and {:?} in CRS.",
+        lwe_compact_public_key.lwe_dimension(),
+        crs.lwe_dimension()
+    );
+
+    assert!(
+        lwe_compact_public_key.ciphertext_modulus() == output.ciphertext_modulus(),
+        "Mismatch between CiphertextModulus of output ciphertext and input public key. \
+    Got {:?} in output, and {:?} in public key.",
+        output.ciphertext_modulus(),
+        lwe_compact_public_key.ciphertext_modulus()
+    );
+
+    assert!(
+        output.lwe_ciphertext_count().0 == encoded.plaintext_count().0,
+        "Mismatch between LweCiphertextCount of output ciphertext and \
+        PlaintextCount of input list. Got {:?} in output, and {:?} in input plaintext list.",
+        output.lwe_ciphertext_count(),
+        encoded.plaintext_count()
+    );
+
+    assert!(
+        output.lwe_ciphertext_count().0 <= crs.max_num_messages().0,
+        "The output list holds more ciphertexts than allowed by the CRS. \
+        Got {:?} in output, and {:?} in CRS.",
+        output.lwe_ciphertext_count(),
+        crs.max_num_messages()
+    );
+
+    assert!(
+        output.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    let witness = encrypt_lwe_compact_ciphertext_list_with_witness(
+        lwe_compact_public_key,
+        output,
+        encoded,
+        mask_noise_parameters,
+        body_noise_parameters,
+        crs,
+        secret_generator,
+        encryption_generator,
+    );
+
+    prove_lwe_compact_ciphertext_list_encryption(
+        lwe_compact_public_key,
+        output,
+        &witness,
+        crs,
+        secret_generator,
+    )
+}
+
+/// Secret values used to encrypt a compact ciphertext list, see [`crate::zk`] for the relation
+/// they satisfy.
+pub(crate) struct EncryptionWitness {
+    pub(crate) randomness: Vec<u64>,
+    pub(crate) mask_noise: Vec<u64>,
+    pub(crate) body_noise: Vec<u64>,
+    pub(crate) messages: Vec<u64>,
+}
+
+/// Encrypt the plaintexts in the output list, keeping track of the secret values of the
+/// encryption.
+///
+/// The caller is responsible for checking the consistency of the inputs.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn encrypt_lwe_compact_ciphertext_list_with_witness<
+    KeyCont,
+    InputCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    mask_noise_parameters: impl DispersionParameter,
+    body_noise_parameters: impl DispersionParameter,
+    crs: &CompactPkeCrs,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) -> EncryptionWitness
+where
+    KeyCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    let delta = crs.delta();
+    let message_modulus = crs.message_modulus();
+
+    let messages: Vec<u64> = encoded
+        .iter()
+        .map(|plaintext| {
+            let plaintext = *plaintext.0;
+            assert!(
+                plaintext % delta == 0 && plaintext / delta < message_modulus,
+                "The plaintext {plaintext} is not the encoding of a message in the range of the \
+                CRS, messages must be in [0, {message_modulus}) and scaled by {delta}"
+            );
+            plaintext / delta
+        })
+        .collect();
+
+    let mask_noise_bound = crs.mask_noise_bound();
+    let body_noise_bound = crs.body_noise_bound();
+
+    let (mut output_mask_list, mut output_body_list) = output.get_mut_mask_and_body_list();
+    let (pk_mask, pk_body) = lwe_compact_public_key.get_mask_and_body();
+
+    let lwe_mask_count = output_mask_list.lwe_mask_count();
+    let lwe_dimension = output_mask_list.lwe_dimension();
+    let mask_list_size = output_mask_list.lwe_mask_list_size();
+
+    let mut binary_random_vector = vec![0u64; mask_list_size];
+    secret_generator.fill_slice_with_random_uniform_binary(&mut binary_random_vector);
+
+    let mut mask_noise_vector = vec![0u64; mask_list_size];
+    let mut body_noise_vector = vec![0u64; messages.len()];
+
+    let max_ciphertext_per_bin = lwe_dimension.0;
+
+    let gen_iter = encryption_generator
+        .fork_lwe_compact_ciphertext_list_to_bin::<u64>(lwe_mask_count, lwe_dimension)
+        .expect("Failed to split generator into lwe compact ciphertext bins");
+
+    // Loop over the ciphertext "bins", keeping track of the noise as it is part of the witness
+    output_mask_list
+        .iter_mut()
+        .zip(
+            output_body_list
+                .chunks_mut(max_ciphertext_per_bin)
+                .zip(encoded.chunks(max_ciphertext_per_bin))
+                .zip(binary_random_vector.chunks(max_ciphertext_per_bin))
+                .zip(mask_noise_vector.chunks_mut(max_ciphertext_per_bin))
+                .zip(body_noise_vector.chunks_mut(max_ciphertext_per_bin))
+                .zip(gen_iter),
+        )
+        .for_each(
+            |(
+                mut output_mask,
+                (
+                    (
+                        (
+                            ((mut output_body_chunk, input_plaintext_chunk), binary_random_slice),
+                            mask_noise_slice,
+                        ),
+                        body_noise_slice,
+                    ),
+                    mut loop_generator,
+                ),
+            )| {
+                let mut pk_body_convolved = vec![0u64; lwe_dimension.0];
+
+                slice_semi_reverse_negacyclic_convolution(
+                    output_mask.as_mut(),
+                    pk_mask.as_ref(),
+                    binary_random_slice,
+                );
+
+                slice_semi_reverse_negacyclic_convolution(
+                    pk_body_convolved.as_mut_slice(),
+                    pk_body.as_ref(),
+                    binary_random_slice,
+                );
+
+                // Noise from Chi_1 for the mask part of the encryption, the tail of the
+                // distribution is cut so that the noise is covered by the proof
+                for noise in mask_noise_slice.iter_mut() {
+                    *noise = sample_bounded_noise(
+                        &mut loop_generator,
+                        mask_noise_parameters,
+                        mask_noise_bound,
+                    );
+                }
+                slice_wrapping_add_assign(output_mask.as_mut(), mask_noise_slice);
+
+                // Noise from Chi_2 for the body part of the encryption, cut in the same way
+                for noise in body_noise_slice.iter_mut() {
+                    *noise = sample_bounded_noise(
+                        &mut loop_generator,
+                        body_noise_parameters,
+                        body_noise_bound,
+                    );
+                }
+
+                output_body_chunk
+                    .iter_mut()
+                    .zip(pk_body_convolved.iter().zip(input_plaintext_chunk.iter()))
+                    .zip(body_noise_slice.iter())
+                    .for_each(|((dst, (&src, plaintext)), &noise)| {
+                        *dst.data = src.wrapping_add(noise).wrapping_add(*plaintext.0)
+                    });
+            },
+        );
+
+    EncryptionWitness {
+        randomness: binary_random_vector,
+        mask_noise: mask_noise_vector,
+        body_noise: body_noise_vector,
+        messages,
+    }
+}
+
+fn sample_bounded_noise<Gen: ByteRandomGenerator>(
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    noise_parameters: impl DispersionParameter,
+    bound: u64,
+) -> u64 {
+    loop {
+        let candidate: u64 = generator.random_noise(noise_parameters);
+        if (candidate as i64).unsigned_abs() <= bound {
+            break candidate;
+        }
+    }
+}
+
+/// Verify a [`CompactPkeProof`] attached to an [`LWE compact ciphertext
+/// list`](`LweCompactCiphertextList`) encrypted under an [`LWE compact public
+/// key`](`LweCompactPublicKey`).
+///
+/// Malformed inputs, e.g. a list with more ciphertexts than allowed by the [`CompactPkeCrs`], lead
+/// to an [`Invalid`](`ZkVerificationOutCome::Invalid`) outcome. A
+/// [`Valid`](`ZkVerificationOutCome::Valid`) outcome shows that the list encrypts messages in the
+/// range of the [`CompactPkeCrs`] with a noise bounded by its noise bounds, see [`crate::zk`].
+///
+/// See [`encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key`] for usage.
+pub fn verify_lwe_compact_ciphertext_list<ListCont, KeyCont>(
+    lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    proof: &CompactPkeProof,
+    crs: &CompactPkeCrs,
+) -> ZkVerificationOutCome
+where
+    ListCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    let lwe_dimension = lwe_compact_list.lwe_size().to_lwe_dimension();
+    let lwe_ciphertext_count = lwe_compact_list.lwe_ciphertext_count();
+
+    let is_well_formed = lwe_dimension == lwe_compact_public_key.lwe_dimension()
+        && lwe_dimension == crs.lwe_dimension()
+        && lwe_ciphertext_count.0 > 0
+        && lwe_ciphertext_count.0 <= crs.max_num_messages().0
+        && lwe_compact_list.ciphertext_modulus().is_native_modulus()
+        && lwe_compact_public_key
+            .ciphertext_modulus()
+            .is_native_modulus()
+        && proof.responses.len() == PROOF_REPETITIONS;
+
+    if !is_well_formed {
+        return ZkVerificationOutCome::Invalid;
+    }
+
+    let relation = EncryptionRelation::new(lwe_compact_public_key, lwe_compact_list, crs);
+    let witness_size = relation.extended_witness_size();
+    let challenges = challenge_trits(&proof.challenge);
+
+    let commitments: Option<Vec<RepetitionCommitments>> = proof
+        .responses
+        .par_iter()
+        .zip(challenges.par_iter())
+        .map(|(response, &challenge)| match (challenge, response) {
+            (
+                0,
+                CompactPkeProofResponse::PermutedWitness {
+                    permuted_witness,
+                    masking_seed,
+                    salt,
+                    permutation_commitment,
+                },
+            ) => {
+                let permuted_witness = unpack_bits(permuted_witness, witness_size)?;
+                if permuted_witness.iter().sum::<u64>() != (witness_size / 2) as u64 {
+                    return None;
+                }
+
+                let mut permuted_masked_witness =
+                    sample_masking_vector(*masking_seed, witness_size);
+                let masking = hash_commitment(*masking_seed, &permuted_masked_witness);
+                slice_wrapping_add_assign(&mut permuted_masked_witness, &permuted_witness);
+
+                Some(RepetitionCommitments {
+                    permutation: *permutation_commitment,
+                    masking,
+                    masked_witness: hash_commitment(*salt, &permuted_masked_witness),
+                })
+            }
+            (
+                1,
+                CompactPkeProofResponse::MaskedWitness {
+                    permutation_seed,
+                    masked_witness,
+                    salt,
+                    masking_commitment,
+                },
+            ) => {
+                if masked_witness.len() != witness_size {
+                    return None;
+                }
+
+                let permutation = sample_permutation(*permutation_seed, witness_size);
+                let mut image = relation.apply(masked_witness);
+                slice_wrapping_sub_assign(&mut image, &relation.target);
+
+                Some(RepetitionCommitments {
+                    permutation: hash_commitment(*permutation_seed, &image),
+                    masking: *masking_commitment,
+                    masked_witness: hash_commitment(*salt, &permute(&permutation, masked_witness)),
+                })
+            }
+            (
+                2,
+                CompactPkeProofResponse::Seeds {
+                    permutation_seed,
+                    masking_seed,
+                    masked_witness_commitment,
+                },
+            ) => {
+                let permutation = sample_permutation(*permutation_seed, witness_size);
+                let permuted_masking = sample_masking_vector(*masking_seed, witness_size);
+                let masking = unpermute(&permutation, &permuted_masking);
+
+                Some(RepetitionCommitments {
+                    permutation: hash_commitment(*permutation_seed, &relation.apply(&masking)),
+                    masking: hash_commitment(*masking_seed, &permuted_masking),
+                    masked_witness: *masked_witness_commitment,
+                })
+            }
+            _ => None,
+        })
+        .collect();
+
+    let Some(commitments) = commitments else {
+        return ZkVerificationOutCome::Invalid;
+    };
+
+    let challenge = compute_challenge(lwe_compact_public_key, lwe_compact_list, crs, &commitments);
+
+    if challenge == proof.challenge {
+        ZkVerificationOutCome::Valid
+    } else {
+        ZkVerificationOutCome::Invalid
+    }
+}
+
+/// Prove that the list is the encryption described by the witness.
+///
+/// The witness is not checked, a prover with a witness that does not satisfy the relation, e.g.
+/// with out of range messages, produces a proof that is rejected by the verification.
+pub(crate) fn prove_lwe_compact_ciphertext_list_encryption<KeyCont, ListCont, SecretGen>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+    witness: &EncryptionWitness,
+    crs: &CompactPkeCrs,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+) -> CompactPkeProof
+where
+    KeyCont: Container<Element = u64>,
+    ListCont: Container<Element = u64>,
+    SecretGen: ByteRandomGenerator,
+{
+    let relation = EncryptionRelation::new(lwe_compact_public_key, lwe_compact_list, crs);
+    let binary_witness = relation.decompose_witness(witness);
+
+    // Extend the witness with its complement, so that any permutation of a vector with as many
+    // zeros as ones can be revealed without leaking information on the witness
+    let extended_witness: Vec<u64> = binary_witness
+        .iter()
+        .copied()
+        .chain(binary_witness.iter().map(|&bit| 1 - bit))
+        .collect();
+    let witness_size = extended_witness.len();
+
+    let mut seed_halves = vec![0u64; 6 * PROOF_REPETITIONS];
+    secret_generator.fill_slice_with_random_uniform_custom_mod(
+        &mut seed_halves,
+        CiphertextModulus::new_native(),
+    );
+    let seeds: Vec<RepetitionSeeds> = seed_halves
+        .chunks_exact(6)
+        .map(|halves| {
+            let seed = |index: usize| {
+                u128::from(halves[2 * index]) | (u128::from(halves[2 * index + 1]) << 64)
+            };
+            RepetitionSeeds {
+                permutation_seed: seed(0),
+                masking_seed: seed(1),
+                salt: seed(2),
+            }
+        })
+        .collect();
+
+    let commitments: Vec<RepetitionCommitments> = seeds
+        .par_iter()
+        .map(|seeds| {
+            let permutation = sample_permutation(seeds.permutation_seed, witness_size);
+            let permuted_masking = sample_masking_vector(seeds.masking_seed, witness_size);
+            let masking = unpermute(&permutation, &permuted_masking);
+
+            let mut permuted_masked_witness = permute(&permutation, &extended_witness);
+            slice_wrapping_add_assign(&mut permuted_masked_witness, &permuted_masking);
+
+            RepetitionCommitments {
+                permutation: hash_commitment(seeds.permutation_seed, &relation.apply(&masking)),
+                masking: hash_commitment(seeds.masking_seed, &permuted_masking),
+                masked_witness: hash_commitment(seeds.salt, &permuted_masked_witness),
+            }
+        })
+        .collect();
+
+    let challenge = compute_challenge(lwe_compact_public_key, lwe_compact_list, crs, &commitments);
+
+    let responses = seeds
+        .par_iter()
+        .zip(commitments.par_iter())
+        .zip(challenge_trits(&challenge).into_par_iter())
+        .map(|((seeds, commitments), challenge)| match challenge {
+            0 => {
+                let permutation = sample_permutation(seeds.permutation_seed, witness_size);
+                CompactPkeProofResponse::PermutedWitness {
+                    permuted_witness: pack_bits(&permute(&permutation, &extended_witness)),
+                    masking_seed: seeds.masking_seed,
+                    salt: seeds.salt,
+                    permutation_commitment: commitments.permutation,
+                }
+            }
+            1 => {
+                let permutation = sample_permutation(seeds.permutation_seed, witness_size);
+                let permuted_masking = sample_masking_vector(seeds.masking_seed, witness_size);
+                let mut masked_witness = unpermute(&permutation, &permuted_masking);
+                slice_wrapping_add_assign(&mut masked_witness, &extended_witness);
+
+                CompactPkeProofResponse::MaskedWitness {
+                    permutation_seed: seeds.permutation_seed,
+                    masked_witness,
+                    salt: seeds.salt,
+                    masking_commitment: commitments.masking,
+                }
+            }
+            _ => CompactPkeProofResponse::Seeds {
+                permutation_seed: seeds.permutation_seed,
+                masking_seed: seeds.masking_seed,
+                masked_witness_commitment: commitments.masked_witness,
+            },
+        })
+        .collect();
+
+    CompactPkeProof {
+        challenge,
+        responses,
+    }
+}
+
+struct RepetitionSeeds {
+    permutation_seed: u128,
+    masking_seed: u128,
+    salt: u128,
+}
+
+/// Commitments of a repetition of the proof: to the permutation and the image of the masking
+/// vector by the relation, to the permuted masking vector and to the permuted masked witness.
+struct RepetitionCommitments {
+    permutation: [u8; 32],
+    masking: [u8; 32],
+    masked_witness: [u8; 32],
+}
+
+/// Decomposition of the values of a range [0, max_value] on bits, the weights are such that any
+/// combination of the bits is in the range, which gives an exact range proof when the bits are
+/// proven to be binary.
+struct RangeDecomposition {
+    weights: Vec<u64>,
+}
+
+impl RangeDecomposition {
+    fn new(max_value: u64) -> Self {
+        let bit_count = u64::BITS - max_value.leading_zeros();
+        let weights = (0..bit_count)
+            .map(|bit| ((u128::from(max_value) + (1 << bit)) >> (bit + 1)) as u64)
+            .collect();
+        Self { weights }
+    }
+
+    fn bit_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    fn decompose(&self, mut value: u64, output: &mut [u64]) {
+        // The weights are decreasing, taking them greedily decomposes all the values of the range
+        for (bit, &weight) in output.iter_mut().zip(self.weights.iter()) {
+            *bit = u64::from(value >= weight);
+            if value >= weight {
+                value -= weight;
+            }
+        }
+    }
+
+    fn recompose(&self, bits: &[u64]) -> u64 {
+        bits.iter()
+            .zip(self.weights.iter())
+            .fold(0u64, |acc, (&bit, &weight)| {
+                acc.wrapping_add(bit.wrapping_mul(weight))
+            })
+    }
+}
+
+/// Linear relation satisfied by the binary decomposition of an [`EncryptionWitness`].
+///
+/// The binary witness holds the randomness, followed by the decompositions of the mask noise, of
+/// the body noise and of the messages. Its image by the relation is the list of masks followed by
+/// the list of bodies, shifted by the noise bounds as the noise values are decomposed with an
+/// offset to be positive.
+struct EncryptionRelation<'key> {
+    pk_mask: &'key [u64],
+    pk_body: &'key [u64],
+    lwe_dimension: usize,
+    bin_count: usize,
+    ciphertext_count: usize,
+    mask_noise_bound: u64,
+    mask_noise_decomposition: RangeDecomposition,
+    body_noise_bound: u64,
+    body_noise_decomposition: RangeDecomposition,
+    message_decomposition: RangeDecomposition,
+    delta: u64,
+    target: Vec<u64>,
+}
+
+impl<'key> EncryptionRelation<'key> {
+    fn new<KeyCont, ListCont>(
+        lwe_compact_public_key: &'key LweCompactPublicKey<KeyCont>,
+        lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+        crs: &CompactPkeCrs,
+    ) -> Self
+    where
+        KeyCont: Container<Element = u64>,
+        ListCont: Container<Element = u64>,
+    {
+        // The public key is a GLWE ciphertext of size 2, its mask is followed by its body
+        let (pk_mask, pk_body) = lwe_compact_public_key
+            .as_ref()
+            .split_at(lwe_compact_public_key.lwe_dimension().0);
+        let (mask_list, body_list) = lwe_compact_list.get_mask_and_body_list();
+
+        let mask_noise_bound = crs.mask_noise_bound();
+        let body_noise_bound = crs.body_noise_bound();
+
+        let target = mask_list
+            .as_ref()
+            .iter()
+            .map(|mask| mask.wrapping_add(mask_noise_bound))
+            .chain(
+                body_list
+                    .as_ref()
+                    .iter()
+                    .map(|body| body.wrapping_add(body_noise_bound)),
+            )
+            .collect();
+
+        Self {
+            pk_mask,
+            pk_body,
+            lwe_dimension: mask_list.lwe_dimension().0,
+            bin_count: mask_list.lwe_mask_count().0,
+            ciphertext_count: body_list.lwe_body_count().0,
+            mask_noise_bound,
+            mask_noise_decomposition: RangeDecomposition::new(2 * mask_noise_bound),
+            body_noise_bound,
+            body_noise_decomposition: RangeDecomposition::new(2 * body_noise_bound),
+            message_decomposition: RangeDecomposition::new(crs.message_modulus() - 1),
+            delta: crs.delta(),
+            target,
+        }
+    }
+
+    fn randomness_size(&self) -> usize {
+        self.bin_count * self.lwe_dimension
+    }
+
+    fn witness_size(&self) -> usize {
+        self.randomness_size() * (1 + self.mask_noise_decomposition.bit_count())
+            + self.ciphertext_count
+                * (self.body_noise_decomposition.bit_count()
+                    + self.message_decomposition.bit_count())
+    }
+
+    fn extended_witness_size(&self) -> usize {
+        2 * self.witness_size()
+    }
+
+    fn decompose_witness(&self, witness: &EncryptionWitness) -> Vec<u64> {
+        let mut binary_witness = vec![0u64; self.witness_size()];
+
+        let (randomness, rest) = binary_witness.split_at_mut(self.randomness_size());
+        let (mask_noise, rest) =
+            rest.split_at_mut(self.randomness_size() * self.mask_noise_decomposition.bit_count());
+        let (body_noise, messages) =
+            rest.split_at_mut(self.ciphertext_count * self.body_noise_decomposition.bit_count());
+
+        randomness.copy_from_slice(&witness.randomness);
+        decompose_values(
+            &self.mask_noise_decomposition,
+            witness
+                .mask_noise
+                .iter()
+                .map(|noise| noise.wrapping_add(self.mask_noise_bound)),
+            mask_noise,
+        );
+        decompose_values(
+            &self.body_noise_decomposition,
+            witness
+                .body_noise
+                .iter()
+                .map(|noise| noise.wrapping_add(self.body_noise_bound)),
+            body_noise,
+        );
+        decompose_values(
+            &self.message_decomposition,
+            witness.messages.iter().copied(),
+            messages,
+        );
+
+        binary_witness
+    }
+
+    /// Compute the image of a vector by the relation, only the first half of an extended witness
+    /// is used, the complement having a null image.
+    fn apply(&self, input: &[u64]) -> Vec<u64> {
+        let lwe_dimension = self.lwe_dimension;
+        let (randomness, rest) = input.split_at(self.randomness_size());
+        let (mask_noise, rest) =
+            rest.split_at(self.randomness_size() * self.mask_noise_decomposition.bit_count());
+        let (body_noise, rest) =
+            rest.split_at(self.ciphertext_count * self.body_noise_decomposition.bit_count());
+        let messages = &rest[..self.ciphertext_count * self.message_decomposition.bit_count()];
+
+        let mut output = vec![0u64; self.randomness_size() + self.ciphertext_count];
+        let (output_masks, output_bodies) = output.split_at_mut(self.randomness_size());
+        let mut pk_body_convolved = vec![0u64; lwe_dimension];
+
+        for ((output_mask, output_body_chunk), randomness_bin) in output_masks
+            .chunks_exact_mut(lwe_dimension)
+            .zip(output_bodies.chunks_mut(lwe_dimension))
+            .zip(randomness.chunks_exact(lwe_dimension))
+        {
+            slice_semi_reverse_negacyclic_convolution(output_mask, self.pk_mask, randomness_bin);
+            slice_semi_reverse_negacyclic_convolution(
+                &mut pk_body_convolved,
+                self.pk_body,
+                randomness_bin,
+            );
+            output_body_chunk.copy_from_slice(&pk_body_convolved[..output_body_chunk.len()]);
+        }
+
+        for (output_mask, bits) in output_masks
+            .iter_mut()
+            .zip(mask_noise.chunks_exact(self.mask_noise_decomposition.bit_count().max(1)))
+        {
+            *output_mask = output_mask.wrapping_add(self.mask_noise_decomposition.recompose(bits));
+        }
+
+        for (index, output_body) in output_bodies.iter_mut().enumerate() {
+            let noise_bit_count = self.body_noise_decomposition.bit_count();
+            let message_bit_count = self.message_decomposition.bit_count();
+            let noise = self
+                .body_noise_decomposition
+                .recompose(&body_noise[index * noise_bit_count..(index + 1) * noise_bit_count]);
+            let message = self
+                .message_decomposition
+                .recompose(&messages[index * message_bit_count..(index + 1) * message_bit_count]);
+            *output_body = output_body
+                .wrapping_add(noise)
+                .wrapping_add(message.wrapping_mul(self.delta));
+        }
+
+        output
+    }
+}
+
+fn decompose_values(
+    decomposition: &RangeDecomposition,
+    values: impl Iterator<Item = u64>,
+    output: &mut [u64],
+) {
+    if decomposition.bit_count() == 0 {
+        return;
+    }
+    for (value, bits) in values.zip(output.chunks_exact_mut(decomposition.bit_count())) {
+        decomposition.decompose(value, bits);
+    }
+}
+
+fn sample_masking_vector(seed: u128, size: usize) -> Vec<u64> {
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(seed));
+    let mut masking_vector = vec![0u64; size];
+    generator.fill_slice_with_random_uniform(&mut masking_vector);
+    masking_vector
+}
+
+/// Sample a uniform permutation of [0, size) with a Fisher-Yates shuffle
+fn sample_permutation(seed: u128, size: usize) -> Vec<usize> {
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(Seed(seed));
+    let mut permutation: Vec<usize> = (0..size).collect();
+    for index in (1..size).rev() {
+        let other = random_uniform_below(&mut generator, index as u64 + 1) as usize;
+        permutation.swap(index, other);
+    }
+    permutation
+}
+
+/// Sample a value uniformly in [0, bound)
+fn random_uniform_below<G: ByteRandomGenerator>(
+    generator: &mut RandomGenerator<G>,
+    bound: u64,
+) -> u64 {
+    // Reject the last incomplete range to avoid any bias
+    let rejection_threshold = u64::MAX - u64::MAX % bound;
+    loop {
+        let value: u64 = generator.random_uniform();
+        if value < rejection_threshold {
+            return value % bound;
+        }
+    }
+}
+
+fn permute(permutation: &[usize], input: &[u64]) -> Vec<u64> {
+    permutation.iter().map(|&index| input[index]).collect()
+}
+
+fn unpermute(permutation: &[usize], input: &[u64]) -> Vec<u64> {
+    let mut output = vec![0u64; input.len()];
+    for (&index, &value) in permutation.iter().zip(input.iter()) {
+        output[index] = value;
+    }
+    output
+}
+
+fn pack_bits(bits: &[u64]) -> Vec<u64> {
+    let mut words = vec![0u64; (bits.len() + 63) / 64];
+    for (index, &bit) in bits.iter().enumerate() {
+        words[index / 64] |= bit << (index % 64);
+    }
+    words
+}
+
+/// Unpack a vector of bits, checking that the unused bits of the last word are zero
+fn unpack_bits(words: &[u64], size: usize) -> Option<Vec<u64>> {
+    if words.len() != (size + 63) / 64 {
+        return None;
+    }
+    let bits: Vec<u64> = (0..size)
+        .map(|index| (words[index / 64] >> (index % 64)) & 1)
+        .collect();
+    let used_bit_count: u32 = words.iter().map(|word| word.count_ones()).sum();
+    (used_bit_count as u64 == bits.iter().sum::<u64>()).then_some(bits)
+}
+
+/// Derive the challenges of the repetitions, in {0, 1, 2}, from the challenge hash
+fn challenge_trits(challenge: &[u8; 32]) -> Vec<u8> {
+    let mut seed = [0u8; 16];
+    seed.copy_from_slice(&challenge[..16]);
+    let mut generator =
+        RandomGenerator::<ActivatedRandomGenerator>::new(Seed(u128::from_le_bytes(seed)));
+
+    let mut trits = Vec::with_capacity(PROOF_REPETITIONS);
+    while trits.len() < PROOF_REPETITIONS {
+        let value: u8 = generator.random_uniform();
+        // Reject the last value to avoid any bias
+        if value < 255 {
+            trits.push(value % 3);
+        }
+    }
+    trits
+}
+
+fn hash_commitment(salt: u128, values: &[u64]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(COMMITMENT_DOMAIN);
+    hasher.update(salt.to_le_bytes());
+    let mut buffer = Vec::with_capacity(512 * 8);
+    for chunk in values.chunks(512) {
+        buffer.clear();
+        buffer.extend(chunk.iter().flat_map(|value| value.to_le_bytes()));
+        hasher.update(&buffer);
+    }
+    hasher.finalize().into()
+}
+
+fn compute_challenge<KeyCont, ListCont>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+    crs: &CompactPkeCrs,
+    commitments: &[RepetitionCommitments],
+) -> [u8; 32]
+where
+    KeyCont: Container<Element = u64>,
+    ListCont: Container<Element = u64>,
+{
+    let mut hasher = Sha3_256::new();
+    hasher.update(CHALLENGE_DOMAIN);
+    hasher.update(crs.domain_separator());
+    for value in [
+        crs.lwe_dimension().0 as u64,
+        crs.max_num_messages().0 as u64,
+        crs.mask_noise_bound(),
+        crs.body_noise_bound(),
+        crs.message_modulus(),
+        crs.delta(),
+        lwe_compact_list.lwe_ciphertext_count().0 as u64,
+    ] {
+        hasher.update(value.to_le_bytes());
+    }
+    for value in lwe_compact_public_key
+        .as_ref()
+        .iter()
+        .chain(lwe_compact_list.as_ref().iter())
+    {
+        hasher.update(value.to_le_bytes());
+    }
+    for commitments in commitments {
+        hasher.update(commitments.permutation);
+        hasher.update(commitments.masking);
+        hasher.update(commitments.masked_witness);
+    }
+    hasher.finalize().into()
+}
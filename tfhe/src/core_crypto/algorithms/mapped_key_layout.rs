@@ -0,0 +1,656 @@
+//! Module containing primitives pertaining to a stable, aligned binary layout for evaluation keys
+//! which can be loaded from a memory mapped file without copies.
+//!
+//! The serde representation of evaluation keys requires deserializing the whole key into freshly
+//! allocated memory and, for keys stored in the standard domain, converting them to the Fourier
+//! domain afterwards. The layout defined here instead stores the key data in exactly the form used
+//! in memory by the computation primitives. Loading a key is then a matter of validating a header
+//! and reinterpreting a byte slice, typically obtained from a read-only memory map, as the key
+//! container. Several processes mapping the same file share the same page-cached key.
+//!
+//! The layout is made of a fixed size header of [`MAPPED_KEY_HEADER_SIZE`] bytes, followed, for
+//! keys in the Fourier domain, by a probe polynomial and finally by the key data. Every section
+//! starts at an offset which is a multiple of [`MAPPED_KEY_ALIGNMENT`] bytes. All values are
+//! stored with the native endianness of the machine which wrote the key, a marker in the header
+//! allows detecting a mismatch on load.
+//!
+//! The in-memory order of coefficients in the Fourier domain depends on the FFT plan selected at
+//! runtime for a given polynomial size. The probe polynomial is the Fourier transform of the
+//! monomial $X$ computed with the plan of the writing process, it is compared on load with the
+//! transform computed by the plan of the loading process, if they differ the key cannot be used
+//! as is and [`MappedKeyLayoutError::FourierLayoutMismatch`] is returned. The
+//! `experimental-force_fft_algo_dif4` feature makes the plan selection deterministic.
+
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyView;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use concrete_fft::c64;
+use std::io::Write;
+
+/// Size in bytes of the header of the mapped key layout.
+pub const MAPPED_KEY_HEADER_SIZE: usize = 128;
+
+/// Alignment in bytes of every section of the mapped key layout.
+///
+/// Memory maps are page aligned, so sections of a mapped key are aligned on cache lines.
+pub const MAPPED_KEY_ALIGNMENT: usize = 64;
+
+const MAPPED_KEY_MAGIC: [u8; 8] = *b"TFHEMKEY";
+const MAPPED_KEY_VERSION: u64 = 1;
+const ENDIANNESS_MARKER: u64 = 0x0102_0304_0506_0708;
+
+// Header slots, each slot is a u64
+const MAGIC_SLOT: usize = 0;
+const VERSION_SLOT: usize = 1;
+const KIND_SLOT: usize = 2;
+const ENDIANNESS_SLOT: usize = 3;
+const ELEMENT_SIZE_SLOT: usize = 4;
+const DATA_OFFSET_SLOT: usize = 5;
+const DATA_LEN_SLOT: usize = 6;
+const PROBE_OFFSET_SLOT: usize = 7;
+const FIRST_PARAMETER_SLOT: usize = 8;
+const HEADER_SLOT_COUNT: usize = MAPPED_KEY_HEADER_SIZE / std::mem::size_of::<u64>();
+
+/// The kind of entity stored in a mapped key layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MappedKeyKind {
+    FourierLweBootstrapKey,
+    FourierLweMultiBitBootstrapKey,
+    LweKeyswitchKey,
+}
+
+impl MappedKeyKind {
+    fn to_u64(self) -> u64 {
+        match self {
+            Self::FourierLweBootstrapKey => 1,
+            Self::FourierLweMultiBitBootstrapKey => 2,
+            Self::LweKeyswitchKey => 3,
+        }
+    }
+
+    fn from_u64(value: u64) -> Option<Self> {
+        match value {
+            1 => Some(Self::FourierLweBootstrapKey),
+            2 => Some(Self::FourierLweMultiBitBootstrapKey),
+            3 => Some(Self::LweKeyswitchKey),
+            _ => None,
+        }
+    }
+}
+
+/// Errors which can occur when loading a key from the mapped key layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappedKeyLayoutError {
+    /// The bytes are too short to contain the header or the sections it describes.
+    Truncated,
+    /// The bytes do not start with the expected magic value.
+    InvalidMagic,
+    /// The layout was written with an unsupported version of the format.
+    UnsupportedVersion(u64),
+    /// The layout was written on a machine with a different endianness.
+    EndiannessMismatch,
+    /// The layout contains a different kind of key than the one requested.
+    UnexpectedKind {
+        expected: MappedKeyKind,
+        found: Option<MappedKeyKind>,
+    },
+    /// The size of the stored elements does not match the requested scalar type.
+    ElementSizeMismatch { expected: usize, found: usize },
+    /// The key data is not aligned for its element type in memory.
+    Misaligned,
+    /// The stored parameters are inconsistent with the stored data.
+    InvalidParameters(String),
+    /// The Fourier domain order of the writing process differs from the one of the loading
+    /// process.
+    FourierLayoutMismatch,
+}
+
+impl std::fmt::Display for MappedKeyLayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "Mapped key is truncated"),
+            Self::InvalidMagic => write!(f, "Mapped key does not start with the expected magic"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported mapped key layout version {version}")
+            }
+            Self::EndiannessMismatch => {
+                write!(
+                    f,
+                    "Mapped key was written on a machine with another endianness"
+                )
+            }
+            Self::UnexpectedKind { expected, found } => {
+                write!(f, "Expected a mapped {expected:?}, found {found:?}")
+            }
+            Self::ElementSizeMismatch { expected, found } => write!(
+                f,
+                "Expected mapped key elements of {expected} bytes, found {found} bytes"
+            ),
+            Self::Misaligned => write!(f, "Mapped key data is not correctly aligned"),
+            Self::InvalidParameters(msg) => write!(f, "Invalid mapped key parameters: {msg}"),
+            Self::FourierLayoutMismatch => write!(
+                f,
+                "Mapped key Fourier layout does not match the FFT plan of the current process"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MappedKeyLayoutError {}
+
+fn align_offset(offset: usize) -> usize {
+    (offset + MAPPED_KEY_ALIGNMENT - 1) / MAPPED_KEY_ALIGNMENT * MAPPED_KEY_ALIGNMENT
+}
+
+fn as_bytes<T: Copy>(data: &[T]) -> &[u8] {
+    // SAFETY: only used on primitive integers and c64, which do not have padding bytes
+    unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) }
+}
+
+fn write_padding<W: Write>(writer: &mut W, written: usize) -> std::io::Result<usize> {
+    let padding = align_offset(written) - written;
+    writer.write_all(&[0u8; MAPPED_KEY_ALIGNMENT][..padding])?;
+    Ok(written + padding)
+}
+
+/// Fourier transform of the monomial $X$, used to detect FFT plans with different orders.
+fn fourier_probe(polynomial_size: PolynomialSize) -> Vec<c64> {
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(fft.forward_scratch().unwrap().unaligned_bytes_required());
+
+    let mut standard = Polynomial::new(0u64, polynomial_size);
+    standard.as_mut()[1] = 1;
+
+    let mut fourier = FourierPolynomial::new(polynomial_size);
+    fft.forward_as_integer(fourier.as_mut_view(), standard.as_view(), buffers.stack());
+
+    fourier.data.to_vec()
+}
+
+struct MappedKeyHeader {
+    slots: [u64; HEADER_SLOT_COUNT],
+}
+
+impl MappedKeyHeader {
+    fn new(kind: MappedKeyKind, element_size: usize, parameters: &[u64]) -> Self {
+        let mut slots = [0u64; HEADER_SLOT_COUNT];
+        slots[MAGIC_SLOT] = u64::from_ne_bytes(MAPPED_KEY_MAGIC);
+        slots[VERSION_SLOT] = MAPPED_KEY_VERSION;
+        slots[KIND_SLOT] = kind.to_u64();
+        slots[ENDIANNESS_SLOT] = ENDIANNESS_MARKER;
+        slots[ELEMENT_SIZE_SLOT] = element_size as u64;
+        slots[FIRST_PARAMETER_SLOT..FIRST_PARAMETER_SLOT + parameters.len()]
+            .copy_from_slice(parameters);
+        Self { slots }
+    }
+
+    fn parse(bytes: &[u8], expected_kind: MappedKeyKind) -> Result<Self, MappedKeyLayoutError> {
+        if bytes.len() < MAPPED_KEY_HEADER_SIZE {
+            return Err(MappedKeyLayoutError::Truncated);
+        }
+
+        let mut slots = [0u64; HEADER_SLOT_COUNT];
+        for (slot, chunk) in slots
+            .iter_mut()
+            .zip(bytes[..MAPPED_KEY_HEADER_SIZE].chunks_exact(8))
+        {
+            *slot = u64::from_ne_bytes(chunk.try_into().unwrap());
+        }
+
+        if slots[MAGIC_SLOT].to_ne_bytes() != MAPPED_KEY_MAGIC {
+            return Err(MappedKeyLayoutError::InvalidMagic);
+        }
+        if slots[ENDIANNESS_SLOT] != ENDIANNESS_MARKER {
+            return Err(MappedKeyLayoutError::EndiannessMismatch);
+        }
+        if slots[VERSION_SLOT] != MAPPED_KEY_VERSION {
+            return Err(MappedKeyLayoutError::UnsupportedVersion(
+                slots[VERSION_SLOT],
+            ));
+        }
+        let found_kind = MappedKeyKind::from_u64(slots[KIND_SLOT]);
+        if found_kind != Some(expected_kind) {
+            return Err(MappedKeyLayoutError::UnexpectedKind {
+                expected: expected_kind,
+                found: found_kind,
+            });
+        }
+
+        Ok(Self { slots })
+    }
+
+    fn parameter(&self, index: usize) -> u64 {
+        self.slots[FIRST_PARAMETER_SLOT + index]
+    }
+
+    fn parameter_as_usize(&self, index: usize) -> Result<usize, MappedKeyLayoutError> {
+        usize::try_from(self.parameter(index)).map_err(|_| {
+            MappedKeyLayoutError::InvalidParameters(format!(
+                "parameter {} does not fit in usize",
+                self.parameter(index)
+            ))
+        })
+    }
+
+    fn write_into<W: Write>(
+        mut self,
+        writer: &mut W,
+        probe: Option<&[c64]>,
+        data: &[u8],
+        data_len: usize,
+    ) -> std::io::Result<()> {
+        let probe_offset = MAPPED_KEY_HEADER_SIZE;
+        let data_offset = probe.map_or(MAPPED_KEY_HEADER_SIZE, |probe| {
+            align_offset(probe_offset + std::mem::size_of_val(probe))
+        });
+
+        self.slots[DATA_OFFSET_SLOT] = data_offset as u64;
+        self.slots[DATA_LEN_SLOT] = data_len as u64;
+        self.slots[PROBE_OFFSET_SLOT] = if probe.is_some() {
+            probe_offset as u64
+        } else {
+            0
+        };
+
+        writer.write_all(as_bytes(&self.slots))?;
+        let mut written = MAPPED_KEY_HEADER_SIZE;
+
+        if let Some(probe) = probe {
+            writer.write_all(as_bytes(probe))?;
+            written = write_padding(writer, written + std::mem::size_of_val(probe))?;
+        }
+
+        debug_assert_eq!(written, data_offset);
+        writer.write_all(data)?;
+        write_padding(writer, written + data.len())?;
+
+        Ok(())
+    }
+
+    /// Return the `len` elements of type `T` stored at `offset`.
+    fn section<'a, T: Copy>(
+        &self,
+        bytes: &'a [u8],
+        offset: u64,
+        len: usize,
+    ) -> Result<&'a [T], MappedKeyLayoutError> {
+        let element_size = std::mem::size_of::<T>();
+        let offset = usize::try_from(offset).map_err(|_| MappedKeyLayoutError::Truncated)?;
+        let byte_len = len
+            .checked_mul(element_size)
+            .ok_or(MappedKeyLayoutError::Truncated)?;
+        let end = offset
+            .checked_add(byte_len)
+            .ok_or(MappedKeyLayoutError::Truncated)?;
+
+        let section = bytes
+            .get(offset..end)
+            .ok_or(MappedKeyLayoutError::Truncated)?;
+
+        if section.as_ptr().align_offset(std::mem::align_of::<T>()) != 0 {
+            return Err(MappedKeyLayoutError::Misaligned);
+        }
+
+        // SAFETY: the section is in bounds and aligned for T, T is only ever a primitive integer
+        // or c64 for which any bit pattern is valid
+        Ok(unsafe { std::slice::from_raw_parts(section.as_ptr().cast::<T>(), len) })
+    }
+
+    fn data<'a, T: Copy>(&self, bytes: &'a [u8]) -> Result<&'a [T], MappedKeyLayoutError> {
+        let element_size = self.slots[ELEMENT_SIZE_SLOT] as usize;
+        if element_size != std::mem::size_of::<T>() {
+            return Err(MappedKeyLayoutError::ElementSizeMismatch {
+                expected: std::mem::size_of::<T>(),
+                found: element_size,
+            });
+        }
+
+        let len = usize::try_from(self.slots[DATA_LEN_SLOT])
+            .map_err(|_| MappedKeyLayoutError::Truncated)?;
+        self.section(bytes, self.slots[DATA_OFFSET_SLOT], len)
+    }
+
+    fn check_fourier_probe(
+        &self,
+        bytes: &[u8],
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), MappedKeyLayoutError> {
+        let stored_probe: &[c64] = self.section(
+            bytes,
+            self.slots[PROBE_OFFSET_SLOT],
+            polynomial_size.to_fourier_polynomial_size().0,
+        )?;
+
+        let probe = fourier_probe(polynomial_size);
+
+        // Different plans with the same order can produce slightly different roundings
+        let same_order = stored_probe
+            .iter()
+            .zip(probe.iter())
+            .all(|(stored, computed)| {
+                (stored.re - computed.re).abs() < 1e-9 && (stored.im - computed.im).abs() < 1e-9
+            });
+
+        if same_order {
+            Ok(())
+        } else {
+            Err(MappedKeyLayoutError::FourierLayoutMismatch)
+        }
+    }
+}
+
+fn check_polynomial_size(polynomial_size: usize) -> Result<PolynomialSize, MappedKeyLayoutError> {
+    if polynomial_size < 2 || !polynomial_size.is_power_of_two() {
+        return Err(MappedKeyLayoutError::InvalidParameters(format!(
+            "polynomial size {polynomial_size} is not a power of 2 greater than 1"
+        )));
+    }
+    Ok(PolynomialSize(polynomial_size))
+}
+
+fn check_data_len(found: usize, expected: Option<usize>) -> Result<(), MappedKeyLayoutError> {
+    match expected {
+        Some(expected) if expected == found => Ok(()),
+        Some(expected) => Err(MappedKeyLayoutError::InvalidParameters(format!(
+            "expected {expected} data elements, found {found}"
+        ))),
+        None => Err(MappedKeyLayoutError::InvalidParameters(
+            "key size overflows usize".to_owned(),
+        )),
+    }
+}
+
+/// Write a [`FourierLweBootstrapKey`] using the mapped key layout.
+///
+/// The written key can be loaded without copies with
+/// [`view_fourier_lwe_bootstrap_key_from_mapped_layout`].
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let fourier_bsk = FourierLweBootstrapKey::new(
+///     LweDimension(10),
+///     GlweSize(2),
+///     PolynomialSize(256),
+///     DecompositionBaseLog(4),
+///     DecompositionLevelCount(3),
+/// );
+///
+/// // In practice the bytes would be written to a file which is then memory mapped
+/// let mut bytes = vec![];
+/// write_fourier_lwe_bootstrap_key_mapped_layout(&fourier_bsk, &mut bytes).unwrap();
+///
+/// // A Vec<u8> is only guaranteed to be aligned on a byte, memory maps are page aligned
+/// let mut aligned = vec![0u64; bytes.len() / 8];
+/// aligned.iter_mut().zip(bytes.chunks_exact(8)).for_each(|(dst, src)| {
+///     *dst = u64::from_ne_bytes(src.try_into().unwrap());
+/// });
+/// let aligned_bytes: &[u8] =
+///     unsafe { std::slice::from_raw_parts(aligned.as_ptr().cast(), bytes.len()) };
+///
+/// let mapped_bsk = view_fourier_lwe_bootstrap_key_from_mapped_layout(aligned_bytes).unwrap();
+/// assert_eq!(mapped_bsk, fourier_bsk.as_view());
+/// ```
+pub fn write_fourier_lwe_bootstrap_key_mapped_layout<Cont, W>(
+    bsk: &FourierLweBootstrapKey<Cont>,
+    mut writer: W,
+) -> std::io::Result<()>
+where
+    Cont: Container<Element = c64>,
+    W: Write,
+{
+    let header = MappedKeyHeader::new(
+        MappedKeyKind::FourierLweBootstrapKey,
+        std::mem::size_of::<c64>(),
+        &[
+            bsk.input_lwe_dimension().0 as u64,
+            bsk.glwe_size().0 as u64,
+            bsk.polynomial_size().0 as u64,
+            bsk.decomposition_base_log().0 as u64,
+            bsk.decomposition_level_count().0 as u64,
+        ],
+    );
+
+    let data = bsk.as_view().data();
+    header.write_into(
+        &mut writer,
+        Some(&fourier_probe(bsk.polynomial_size())),
+        as_bytes(data),
+        data.len(),
+    )
+}
+
+/// Load a [`FourierLweBootstrapKey`] written with
+/// [`write_fourier_lwe_bootstrap_key_mapped_layout`] from its bytes, without copying the key data.
+///
+/// The bytes are typically a read-only memory map of the file containing the key, they must be
+/// aligned on at least 8 bytes.
+///
+/// See [`write_fourier_lwe_bootstrap_key_mapped_layout`] for usage.
+pub fn view_fourier_lwe_bootstrap_key_from_mapped_layout(
+    bytes: &[u8],
+) -> Result<FourierLweBootstrapKeyView<'_>, MappedKeyLayoutError> {
+    let header = MappedKeyHeader::parse(bytes, MappedKeyKind::FourierLweBootstrapKey)?;
+
+    let input_lwe_dimension = LweDimension(header.parameter_as_usize(0)?);
+    let glwe_size = GlweSize(header.parameter_as_usize(1)?);
+    let polynomial_size = check_polynomial_size(header.parameter_as_usize(2)?)?;
+    let decomposition_base_log = DecompositionBaseLog(header.parameter_as_usize(3)?);
+    let decomposition_level_count = DecompositionLevelCount(header.parameter_as_usize(4)?);
+
+    let data: &[c64] = header.data(bytes)?;
+    check_data_len(
+        data.len(),
+        input_lwe_dimension
+            .0
+            .checked_mul(polynomial_size.to_fourier_polynomial_size().0)
+            .and_then(|len| len.checked_mul(decomposition_level_count.0))
+            .and_then(|len| len.checked_mul(glwe_size.0))
+            .and_then(|len| len.checked_mul(glwe_size.0)),
+    )?;
+
+    header.check_fourier_probe(bytes, polynomial_size)?;
+
+    Ok(FourierLweBootstrapKey::from_container(
+        data,
+        input_lwe_dimension,
+        glwe_size,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count,
+    ))
+}
+
+/// Write a [`FourierLweMultiBitBootstrapKey`] using the mapped key layout.
+///
+/// The written key can be loaded without copies with
+/// [`view_fourier_lwe_multi_bit_bootstrap_key_from_mapped_layout`].
+pub fn write_fourier_lwe_multi_bit_bootstrap_key_mapped_layout<Cont, W>(
+    bsk: &FourierLweMultiBitBootstrapKey<Cont>,
+    mut writer: W,
+) -> std::io::Result<()>
+where
+    Cont: Container<Element = c64>,
+    W: Write,
+{
+    let header = MappedKeyHeader::new(
+        MappedKeyKind::FourierLweMultiBitBootstrapKey,
+        std::mem::size_of::<c64>(),
+        &[
+            bsk.input_lwe_dimension().0 as u64,
+            bsk.glwe_size().0 as u64,
+            bsk.polynomial_size().0 as u64,
+            bsk.decomposition_base_log().0 as u64,
+            bsk.decomposition_level_count().0 as u64,
+            bsk.grouping_factor().0 as u64,
+        ],
+    );
+
+    let data = bsk.as_view().data();
+    header.write_into(
+        &mut writer,
+        Some(&fourier_probe(bsk.polynomial_size())),
+        as_bytes(data),
+        data.len(),
+    )
+}
+
+/// Load a [`FourierLweMultiBitBootstrapKey`] written with
+/// [`write_fourier_lwe_multi_bit_bootstrap_key_mapped_layout`] from its bytes, without copying
+/// the key data.
+///
+/// The bytes are typically a read-only memory map of the file containing the key, they must be
+/// aligned on at least 8 bytes.
+pub fn view_fourier_lwe_multi_bit_bootstrap_key_from_mapped_layout(
+    bytes: &[u8],
+) -> Result<FourierLweMultiBitBootstrapKeyView<'_>, MappedKeyLayoutError> {
+    let header = MappedKeyHeader::parse(bytes, MappedKeyKind::FourierLweMultiBitBootstrapKey)?;
+
+    let input_lwe_dimension = LweDimension(header.parameter_as_usize(0)?);
+    let glwe_size = GlweSize(header.parameter_as_usize(1)?);
+    let polynomial_size = check_polynomial_size(header.parameter_as_usize(2)?)?;
+    let decomposition_base_log = DecompositionBaseLog(header.parameter_as_usize(3)?);
+    let decomposition_level_count = DecompositionLevelCount(header.parameter_as_usize(4)?);
+    let grouping_factor = LweBskGroupingFactor(header.parameter_as_usize(5)?);
+
+    if grouping_factor.0 == 0
+        || grouping_factor.0 >= usize::BITS as usize
+        || input_lwe_dimension.0 % grouping_factor.0 != 0
+    {
+        return Err(MappedKeyLayoutError::InvalidParameters(format!(
+            "invalid grouping factor {grouping_factor:?} for input dimension \
+            {input_lwe_dimension:?}"
+        )));
+    }
+
+    let data: &[c64] = header.data(bytes)?;
+    check_data_len(
+        data.len(),
+        (input_lwe_dimension.0 / grouping_factor.0)
+            .checked_mul(grouping_factor.ggsw_per_multi_bit_element().0)
+            .and_then(|len| len.checked_mul(polynomial_size.to_fourier_polynomial_size().0))
+            .and_then(|len| len.checked_mul(decomposition_level_count.0))
+            .and_then(|len| len.checked_mul(glwe_size.0))
+            .and_then(|len| len.checked_mul(glwe_size.0)),
+    )?;
+
+    header.check_fourier_probe(bytes, polynomial_size)?;
+
+    Ok(FourierLweMultiBitBootstrapKey::from_container(
+        data,
+        input_lwe_dimension,
+        glwe_size,
+        polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count,
+        grouping_factor,
+    ))
+}
+
+/// Write an [`LweKeyswitchKey`] using the mapped key layout.
+///
+/// The written key can be loaded without copies with
+/// [`view_lwe_keyswitch_key_from_mapped_layout`].
+pub fn write_lwe_keyswitch_key_mapped_layout<Scalar, Cont, W>(
+    ksk: &LweKeyswitchKey<Cont>,
+    mut writer: W,
+) -> std::io::Result<()>
+where
+    Scalar: UnsignedInteger,
+    Cont: Container<Element = Scalar>,
+    W: Write,
+{
+    let ciphertext_modulus = ksk.ciphertext_modulus();
+    let raw_modulus = if ciphertext_modulus.is_native_modulus() {
+        0
+    } else {
+        ciphertext_modulus.get_custom_modulus()
+    };
+
+    let header = MappedKeyHeader::new(
+        MappedKeyKind::LweKeyswitchKey,
+        std::mem::size_of::<Scalar>(),
+        &[
+            ksk.decomposition_base_log().0 as u64,
+            ksk.decomposition_level_count().0 as u64,
+            ksk.output_lwe_size().0 as u64,
+            raw_modulus as u64,
+            (raw_modulus >> 64) as u64,
+        ],
+    );
+
+    let data = ksk.as_ref();
+    header.write_into(&mut writer, None, as_bytes(data), data.len())
+}
+
+/// Load an [`LweKeyswitchKey`] written with [`write_lwe_keyswitch_key_mapped_layout`] from its
+/// bytes, without copying the key data.
+///
+/// The bytes are typically a read-only memory map of the file containing the key, they must be
+/// aligned on at least the alignment of `Scalar`.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let ksk = LweKeyswitchKey::new(
+///     0u64,
+///     DecompositionBaseLog(3),
+///     DecompositionLevelCount(5),
+///     LweDimension(16),
+///     LweDimension(8),
+///     CiphertextModulus::new_native(),
+/// );
+///
+/// let mut bytes = vec![];
+/// write_lwe_keyswitch_key_mapped_layout(&ksk, &mut bytes).unwrap();
+///
+/// let mut aligned = vec![0u64; bytes.len() / 8];
+/// aligned.iter_mut().zip(bytes.chunks_exact(8)).for_each(|(dst, src)| {
+///     *dst = u64::from_ne_bytes(src.try_into().unwrap());
+/// });
+/// let aligned_bytes: &[u8] =
+///     unsafe { std::slice::from_raw_parts(aligned.as_ptr().cast(), bytes.len()) };
+///
+/// let mapped_ksk = view_lwe_keyswitch_key_from_mapped_layout::<u64>(aligned_bytes).unwrap();
+/// assert_eq!(mapped_ksk, ksk.as_view());
+///
+/// // The stored scalar type is checked on load
+/// assert!(view_lwe_keyswitch_key_from_mapped_layout::<u32>(aligned_bytes).is_err());
+/// ```
+pub fn view_lwe_keyswitch_key_from_mapped_layout<Scalar: UnsignedInteger>(
+    bytes: &[u8],
+) -> Result<LweKeyswitchKeyView<'_, Scalar>, MappedKeyLayoutError> {
+    let header = MappedKeyHeader::parse(bytes, MappedKeyKind::LweKeyswitchKey)?;
+
+    let decomp_base_log = DecompositionBaseLog(header.parameter_as_usize(0)?);
+    let decomp_level_count = DecompositionLevelCount(header.parameter_as_usize(1)?);
+    let output_lwe_size = LweSize(header.parameter_as_usize(2)?);
+
+    let data: &[Scalar] = header.data(bytes)?;
+
+    let raw_modulus = header.parameter(3) as u128 | ((header.parameter(4) as u128) << 64);
+    let ciphertext_modulus = CiphertextModulus::try_new(raw_modulus)
+        .map_err(|msg| MappedKeyLayoutError::InvalidParameters(msg.to_owned()))?;
+
+    let chunk_size = decomp_level_count.0.checked_mul(output_lwe_size.0);
+    if data.is_empty() || !matches!(chunk_size, Some(size) if size > 0 && data.len() % size == 0) {
+        return Err(MappedKeyLayoutError::InvalidParameters(format!(
+            "{} data elements do not match {decomp_level_count:?} and {output_lwe_size:?}",
+            data.len()
+        )));
+    }
+
+    Ok(LweKeyswitchKey::from_container(
+        data,
+        decomp_base_log,
+        decomp_level_count,
+        output_lwe_size,
+        ciphertext_modulus,
+    ))
+}
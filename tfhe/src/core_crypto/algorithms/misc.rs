@@ -43,6 +43,31 @@ where
     ((numerator_128 + half_denominator) % modulus) / denominator_128
 }
 
+/// Switch a value from the native modulus $2^{Scalar::BITS}$ to the modulus $2^{log\_modulus}$,
+/// rounding to the closest representable value.
+///
+/// The output is in $[0; 2^{log\_modulus}[$, i.e. the result is stored in the LSBs.
+#[inline]
+pub fn modulus_switch<Scalar>(input: Scalar, log_modulus: CiphertextModulusLog) -> Scalar
+where
+    Scalar: UnsignedInteger,
+{
+    assert!(
+        log_modulus.0 > 0 && log_modulus.0 < Scalar::BITS,
+        "Invalid log_modulus {}, expected a value in [1, {}[",
+        log_modulus.0,
+        Scalar::BITS
+    );
+
+    // Keep one more bit than necessary to do the rounding
+    let output = input >> (Scalar::BITS - log_modulus.0 - 1);
+    // Rounding, adding the rounding bit after the shift so that it cannot overflow when
+    // log_modulus == Scalar::BITS - 1
+    let output = (output >> 1) + (output & Scalar::ONE);
+    // Reduce modulo 2^log_modulus as the rounding may have carried up to 2^log_modulus
+    output & ((Scalar::ONE << log_modulus.0) - Scalar::ONE)
+}
+
 pub fn odd_modular_inverse_pow_2<Scalar>(odd_value_to_invert: Scalar, log2_modulo: usize) -> Scalar
 where
     Scalar: UnsignedInteger,
@@ -302,4 +327,39 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_modulus_switch() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+
+        for log_modulus in 1..u64::BITS as usize {
+            let log_modulus = CiphertextModulusLog(log_modulus);
+            let shift = u64::BITS as usize - log_modulus.0;
+
+            // Boundary values first, u64::MAX rounds up to 2^log_modulus which wraps to 0
+            let boundary_values = [0, 1, u64::MAX - 1, u64::MAX];
+            for value in boundary_values
+                .into_iter()
+                .chain((0..10_000).map(|_| rng.gen()))
+            {
+                let switched = modulus_switch(value, log_modulus);
+                assert!(switched < (1 << log_modulus.0));
+
+                // Going back to the native modulus, the error must be at most half a step
+                let back = switched << shift;
+                let error = modular_distance(back, value);
+                assert!(error <= 1 << (shift - 1));
+            }
+        }
+
+        // Largest supported log_modulus, the rounding used to overflow for u64::MAX
+        let log_modulus = CiphertextModulusLog(u64::BITS as usize - 1);
+        assert_eq!(modulus_switch(u64::MAX, log_modulus), 0);
+        assert_eq!(
+            modulus_switch(u64::MAX - 1, log_modulus),
+            (1 << log_modulus.0) - 1
+        );
+    }
 }
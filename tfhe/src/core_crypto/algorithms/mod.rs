@@ -4,10 +4,15 @@
 
 pub mod ggsw_conversion;
 pub mod ggsw_encryption;
+pub mod glwe_automorphism;
+pub mod glwe_automorphism_key_generation;
 pub mod glwe_encryption;
 pub mod glwe_linear_algebra;
+pub mod glwe_public_key_generation;
+pub mod glwe_relinearization_key_generation;
 pub mod glwe_sample_extraction;
 pub mod glwe_secret_key_generation;
+pub mod glwe_tensor_product;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_generation;
 pub mod lwe_compact_ciphertext_list_expansion;
@@ -16,6 +21,7 @@ pub mod lwe_encryption;
 pub mod lwe_keyswitch;
 pub mod lwe_keyswitch_key_generation;
 pub mod lwe_linear_algebra;
+pub mod lwe_modulus_switch;
 pub mod lwe_multi_bit_bootstrap_key_conversion;
 pub mod lwe_multi_bit_bootstrap_key_generation;
 pub mod lwe_multi_bit_programmable_bootstrapping;
@@ -25,14 +31,21 @@ pub mod lwe_private_functional_packing_keyswitch;
 pub mod lwe_private_functional_packing_keyswitch_key_generation;
 pub mod lwe_programmable_bootstrapping;
 pub mod lwe_public_key_generation;
+pub mod lwe_rerandomization;
 pub mod lwe_secret_key_generation;
+pub mod lwe_threshold_decryption;
 pub mod lwe_wopbs;
+#[cfg(feature = "zk-pok-experimental")]
+pub mod lwe_zk_proof;
+pub mod mapped_key_layout;
 pub mod misc;
+pub mod multi_party_key_generation;
 pub mod polynomial_algorithms;
 pub mod seeded_ggsw_ciphertext_decompression;
 pub mod seeded_ggsw_ciphertext_list_decompression;
 pub mod seeded_glwe_ciphertext_decompression;
 pub mod seeded_glwe_ciphertext_list_decompression;
+pub mod seeded_glwe_public_key_decompression;
 pub mod seeded_lwe_bootstrap_key_decompression;
 pub mod seeded_lwe_ciphertext_decompression;
 pub mod seeded_lwe_ciphertext_list_decompression;
@@ -42,6 +55,7 @@ pub mod seeded_lwe_multi_bit_bootstrap_key_decompression;
 pub mod seeded_lwe_packing_keyswitch_key_decompression;
 pub mod seeded_lwe_public_key_decompression;
 pub mod slice_algorithms;
+pub mod streaming_key_generation;
 
 #[cfg(test)]
 mod test;
@@ -50,10 +64,15 @@ mod test;
 // They can still be used via `use crate::core_crypto::algorithms::slice_algorithms::*;`
 pub use ggsw_conversion::*;
 pub use ggsw_encryption::*;
+pub use glwe_automorphism::*;
+pub use glwe_automorphism_key_generation::*;
 pub use glwe_encryption::*;
 pub use glwe_linear_algebra::*;
+pub use glwe_public_key_generation::*;
+pub use glwe_relinearization_key_generation::*;
 pub use glwe_sample_extraction::*;
 pub use glwe_secret_key_generation::*;
+pub use glwe_tensor_product::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
 pub use lwe_compact_ciphertext_list_expansion::*;
@@ -62,6 +81,7 @@ pub use lwe_encryption::*;
 pub use lwe_keyswitch::*;
 pub use lwe_keyswitch_key_generation::*;
 pub use lwe_linear_algebra::*;
+pub use lwe_modulus_switch::*;
 pub use lwe_multi_bit_bootstrap_key_conversion::*;
 pub use lwe_multi_bit_bootstrap_key_generation::*;
 pub use lwe_multi_bit_programmable_bootstrapping::*;
@@ -71,12 +91,19 @@ pub use lwe_private_functional_packing_keyswitch::*;
 pub use lwe_private_functional_packing_keyswitch_key_generation::*;
 pub use lwe_programmable_bootstrapping::*;
 pub use lwe_public_key_generation::*;
+pub use lwe_rerandomization::*;
 pub use lwe_secret_key_generation::*;
+pub use lwe_threshold_decryption::*;
 pub use lwe_wopbs::*;
+#[cfg(feature = "zk-pok-experimental")]
+pub use lwe_zk_proof::*;
+pub use mapped_key_layout::*;
+pub use multi_party_key_generation::*;
 pub use seeded_ggsw_ciphertext_decompression::*;
 pub use seeded_ggsw_ciphertext_list_decompression::*;
 pub use seeded_glwe_ciphertext_decompression::*;
 pub use seeded_glwe_ciphertext_list_decompression::*;
+pub use seeded_glwe_public_key_decompression::*;
 pub use seeded_lwe_bootstrap_key_decompression::*;
 pub use seeded_lwe_ciphertext_decompression::*;
 pub use seeded_lwe_ciphertext_list_decompression::*;
@@ -85,3 +112,4 @@ pub use seeded_lwe_keyswitch_key_decompression::*;
 pub use seeded_lwe_multi_bit_bootstrap_key_decompression::*;
 pub use seeded_lwe_packing_keyswitch_key_decompression::*;
 pub use seeded_lwe_public_key_decompression::*;
+pub use streaming_key_generation::*;
@@ -0,0 +1,453 @@
+//! Module containing primitives pertaining to the distributed generation of public and evaluation
+//! keys by several parties, none of which learns the secret keys the generated keys correspond to.
+//!
+//! # Formal Definition
+//!
+//! Each of the $P$ parties samples its own GLWE secret key share $\vec{S}\_i$ and LWE secret key
+//! share $\vec{s}\_i$. The joint secret keys are:
+//!
+//! - the joint GLWE secret key $\vec{S} = \sum\_{i=1}^{P} \vec{S}\_i$ (and its LWE interpretation
+//!   used as the input of the keyswitch);
+//! - the joint LWE secret key $\vec{s} = \vec{s}\_1 || \ldots || \vec{s}\_P$ of dimension $P \cdot
+//!   n$, the concatenation of the LWE secret key shares. It is binary as required by the blind
+//!   rotation, an LWE ciphertext $(\vec{a}, b)$ under $\sum\_{i=1}^{P} \vec{s}\_i$ being an LWE
+//!   ciphertext $(\vec{a} || \ldots || \vec{a}, b)$ under $\vec{s}$.
+//!
+//! All the parties agree on a common reference seed used as the compression seed of the seeded
+//! entities they generate, so that their contributions share the same masks. The bodies of the
+//! contributions are linear in the secret keys, adding them up yields the keys for the joint secret
+//! keys. Key generation takes two rounds:
+//!
+//! 1. every party generates a [`compact public key`](`SeededLweCompactPublicKey`) with
+//!    [`generate_seeded_lwe_compact_public_key`], an [`LWE keyswitch
+//!    key`](`SeededLweKeyswitchKey`) with
+//!    [`generate_multi_party_seeded_lwe_keyswitch_key_contribution`] and a [`GLWE encryption of
+//!    zero`](`SeededGlweCiphertext`) with
+//!    [`generate_multi_party_seeded_glwe_encryption_of_zero_contribution`]. The contributions are
+//!    aggregated with [`allocate_and_aggregate_seeded_lwe_compact_public_key_contributions`],
+//!    [`allocate_and_aggregate_seeded_lwe_keyswitch_key_contributions`] and
+//!    [`allocate_and_aggregate_seeded_glwe_ciphertext_contributions`];
+//! 2. every party uses the aggregated GLWE encryption of zero under $\vec{S}$ as a public key to
+//!    encrypt the GGSW ciphertexts of its LWE secret key share with
+//!    [`generate_multi_party_lwe_bootstrap_key_contribution`]. The bootstrap key for $\vec{s}$ is
+//!    the concatenation of the contributions computed by
+//!    [`allocate_and_aggregate_lwe_bootstrap_key_contributions`].
+//!
+//! # Noise
+//!
+//! The noise of the joint keys is the sum of the noise of the contributions, and the bootstrap key
+//! encrypted with a public key carries the noise of the GLWE encryption of zero multiplied by a
+//! binary polynomial. The joint LWE secret key is $P$ times larger and the joint GLWE secret key is
+//! not binary anymore, parameters must account for all of these when choosing the number of
+//! parties.
+
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::slice_algorithms::*;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Fill a [`seeded LWE keyswitch key`](`SeededLweKeyswitchKey`) with the contribution of one party
+/// to the distributed generation of a keyswitch key from the joint GLWE secret key to the joint
+/// LWE secret key, see the [`module level documentation`](`self`).
+///
+/// The output key must have been created with the common reference seed as compression seed and
+/// an output [`LweDimension`] equal to the dimension of the LWE secret key shares times the number
+/// of parties.
+pub fn generate_multi_party_seeded_lwe_keyswitch_key_contribution<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    KSKeyCont,
+    NoiseSeeder,
+>(
+    input_lwe_secret_key_share: &LweSecretKey<InputKeyCont>,
+    output_lwe_secret_key_share: &LweSecretKey<OutputKeyCont>,
+    party_index: ThresholdPartyIndex,
+    party_count: ThresholdPartyCount,
+    output: &mut SeededLweKeyswitchKey<KSKeyCont>,
+    noise_parameters: impl DispersionParameter,
+    noise_seeder: &mut NoiseSeeder,
+) where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    KSKeyCont: ContainerMut<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    assert!(
+        party_index.0 >= 1 && party_index.0 <= party_count.0,
+        "Invalid party index {party_index:?} for {party_count:?}, \
+        parties are indexed from 1 to the party count"
+    );
+
+    let share_lwe_dimension = output_lwe_secret_key_share.lwe_dimension();
+    assert!(
+        output.output_key_lwe_dimension().0 == share_lwe_dimension.0 * party_count.0,
+        "The output LweDimension of the SeededLweKeyswitchKey {:?} is not the LweDimension \
+        of the LweSecretKey share {share_lwe_dimension:?} times the party count {party_count:?}",
+        output.output_key_lwe_dimension(),
+    );
+
+    // The share of the joint LWE secret key held by the party, zero outside of its own chunk
+    let mut padded_output_lwe_secret_key_share =
+        LweSecretKey::new_empty_key(Scalar::ZERO, output.output_key_lwe_dimension());
+    padded_output_lwe_secret_key_share
+        .as_mut()
+        .chunks_exact_mut(share_lwe_dimension.0)
+        .nth(party_index.0 - 1)
+        .unwrap()
+        .copy_from_slice(output_lwe_secret_key_share.as_ref());
+
+    generate_seeded_lwe_keyswitch_key(
+        input_lwe_secret_key_share,
+        &padded_output_lwe_secret_key_share,
+        output,
+        noise_parameters,
+        noise_seeder,
+    );
+}
+
+/// Fill a [`seeded GLWE ciphertext`](`SeededGlweCiphertext`) with an encryption of zero under the
+/// GLWE secret key share of one party, its contribution to the encryption of zero under the joint
+/// GLWE secret key used to generate the bootstrap key, see the
+/// [`module level documentation`](`self`).
+///
+/// The output ciphertext must have been created with the common reference seed as compression
+/// seed.
+pub fn generate_multi_party_seeded_glwe_encryption_of_zero_contribution<
+    Scalar,
+    KeyCont,
+    OutputCont,
+    NoiseSeeder,
+>(
+    glwe_secret_key_share: &GlweSecretKey<KeyCont>,
+    output: &mut SeededGlweCiphertext<OutputCont>,
+    noise_parameters: impl DispersionParameter,
+    noise_seeder: &mut NoiseSeeder,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    let zeros = PlaintextList::new(Scalar::ZERO, PlaintextCount(output.polynomial_size().0));
+
+    encrypt_seeded_glwe_ciphertext(
+        glwe_secret_key_share,
+        output,
+        &zeros,
+        noise_parameters,
+        noise_seeder,
+    );
+}
+
+/// Fill an [`LWE bootstrap key`](`LweBootstrapKey`) with the contribution of one party to the
+/// distributed generation of a bootstrap key from the joint LWE secret key to the joint GLWE
+/// secret key, see the [`module level documentation`](`self`).
+///
+/// The contribution holds GGSW encryptions of the elements of the party's LWE secret key share
+/// under the joint GLWE secret key, computed using `glwe_encryption_of_zero` (the aggregation of
+/// the GLWE encryptions of zero of all parties) as a public key.
+pub fn generate_multi_party_lwe_bootstrap_key_contribution<
+    Scalar,
+    InputKeyCont,
+    ZeroCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    input_lwe_secret_key_share: &LweSecretKey<InputKeyCont>,
+    glwe_encryption_of_zero: &GlweCiphertext<ZeroCont>,
+    output: &mut LweBootstrapKey<OutputCont>,
+    noise_parameters: impl DispersionParameter,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    ZeroCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    assert!(
+        output.ciphertext_modulus().is_native_modulus()
+            && glwe_encryption_of_zero
+                .ciphertext_modulus()
+                .is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key_share.lwe_dimension(),
+        "Mismatched LweDimension between input LweSecretKey share {:?} \
+        and output LweBootstrapKey {:?}",
+        input_lwe_secret_key_share.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.glwe_size() == glwe_encryption_of_zero.glwe_size(),
+        "Mismatched GlweSize between the GLWE encryption of zero {:?} \
+        and output LweBootstrapKey {:?}",
+        glwe_encryption_of_zero.glwe_size(),
+        output.glwe_size()
+    );
+
+    assert!(
+        output.polynomial_size() == glwe_encryption_of_zero.polynomial_size(),
+        "Mismatched PolynomialSize between the GLWE encryption of zero {:?} \
+        and output LweBootstrapKey {:?}",
+        glwe_encryption_of_zero.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    let decomp_base_log = output.decomposition_base_log();
+    let zero_polynomials = glwe_encryption_of_zero.as_polynomial_list();
+    let mut ephemeral_polynomial = Polynomial::new(Scalar::ZERO, output.polynomial_size());
+
+    for (&input_key_element, mut ggsw) in input_lwe_secret_key_share
+        .as_ref()
+        .iter()
+        .zip(output.iter_mut())
+    {
+        for (level_index, mut level_matrix) in ggsw.iter_mut().enumerate() {
+            let decomp_level = DecompositionLevel(level_index + 1);
+            let factor = DecompositionTerm::new(decomp_level, decomp_base_log, input_key_element)
+                .to_recomposition_summand();
+
+            for (row_index, mut row_as_glwe) in
+                level_matrix.as_mut_glwe_list().iter_mut().enumerate()
+            {
+                // Fresh public key encryption of zero, the ephemeral binary polynomial times the
+                // encryption of zero plus noise on every polynomial
+                secret_generator
+                    .fill_slice_with_random_uniform_binary(ephemeral_polynomial.as_mut());
+
+                row_as_glwe.as_mut().fill(Scalar::ZERO);
+                for (mut row_polynomial, zero_polynomial) in row_as_glwe
+                    .as_mut_polynomial_list()
+                    .iter_mut()
+                    .zip(zero_polynomials.iter())
+                {
+                    polynomial_wrapping_add_mul_assign(
+                        &mut row_polynomial,
+                        &zero_polynomial,
+                        &ephemeral_polynomial,
+                    );
+                }
+
+                encryption_generator.unsigned_torus_slice_wrapping_add_random_noise_assign(
+                    row_as_glwe.as_mut(),
+                    noise_parameters,
+                );
+
+                // Adding the factor to the mask polynomial of index row_index yields the
+                // encryption of -S_row * factor expected in the GGSW, adding it to the body for
+                // the last row yields the encryption of factor
+                let mut row_polynomials = row_as_glwe.as_mut_polynomial_list();
+                let mut target_polynomial = row_polynomials.get_mut(row_index);
+                let constant_coefficient = &mut target_polynomial.as_mut()[0];
+                *constant_coefficient = constant_coefficient.wrapping_add(factor);
+            }
+        }
+    }
+}
+
+/// Aggregate the contributions of all parties to a distributed
+/// [`compact public key`](`SeededLweCompactPublicKey`) generation into the compact public key for
+/// the joint secret key.
+///
+/// # Panics
+///
+/// Panics if `contributions` is empty or if the contributions do not share the same parameters and
+/// compression seed.
+pub fn allocate_and_aggregate_seeded_lwe_compact_public_key_contributions<Scalar, KeyCont>(
+    contributions: &[SeededLweCompactPublicKey<KeyCont>],
+) -> SeededLweCompactPublicKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+{
+    let first = contributions
+        .first()
+        .expect("Cannot aggregate an empty list of contributions");
+
+    let mut aggregated = SeededLweCompactPublicKeyOwned::new(
+        Scalar::ZERO,
+        first.lwe_dimension(),
+        first.compression_seed(),
+        first.ciphertext_modulus(),
+    );
+
+    for contribution in contributions {
+        assert!(
+            contribution.lwe_dimension() == aggregated.lwe_dimension()
+                && contribution.compression_seed() == aggregated.compression_seed()
+                && contribution.ciphertext_modulus() == aggregated.ciphertext_modulus(),
+            "All SeededLweCompactPublicKey contributions must share the same parameters \
+            and compression seed"
+        );
+
+        slice_wrapping_add_assign(aggregated.as_mut(), contribution.as_ref());
+    }
+
+    aggregated
+}
+
+/// Aggregate the contributions of all parties to a distributed
+/// [`LWE keyswitch key`](`SeededLweKeyswitchKey`) generation into the keyswitch key from the joint
+/// GLWE secret key to the joint LWE secret key.
+///
+/// # Panics
+///
+/// Panics if `contributions` is empty or if the contributions do not share the same parameters and
+/// compression seed.
+pub fn allocate_and_aggregate_seeded_lwe_keyswitch_key_contributions<Scalar, KSKeyCont>(
+    contributions: &[SeededLweKeyswitchKey<KSKeyCont>],
+) -> SeededLweKeyswitchKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KSKeyCont: Container<Element = Scalar>,
+{
+    let first = contributions
+        .first()
+        .expect("Cannot aggregate an empty list of contributions");
+
+    assert!(first
+        .ciphertext_modulus()
+        .is_compatible_with_native_modulus());
+
+    let mut aggregated = SeededLweKeyswitchKeyOwned::new(
+        Scalar::ZERO,
+        first.decomposition_base_log(),
+        first.decomposition_level_count(),
+        first.input_key_lwe_dimension(),
+        first.output_key_lwe_dimension(),
+        first.compression_seed(),
+        first.ciphertext_modulus(),
+    );
+
+    for contribution in contributions {
+        assert!(
+            contribution.decomposition_base_log() == aggregated.decomposition_base_log()
+                && contribution.decomposition_level_count()
+                    == aggregated.decomposition_level_count()
+                && contribution.input_key_lwe_dimension() == aggregated.input_key_lwe_dimension()
+                && contribution.output_key_lwe_dimension() == aggregated.output_key_lwe_dimension()
+                && contribution.compression_seed() == aggregated.compression_seed()
+                && contribution.ciphertext_modulus() == aggregated.ciphertext_modulus(),
+            "All SeededLweKeyswitchKey contributions must share the same parameters \
+            and compression seed"
+        );
+
+        slice_wrapping_add_assign(aggregated.as_mut(), contribution.as_ref());
+    }
+
+    aggregated
+}
+
+/// Aggregate the encryptions of zero of all parties into a
+/// [`seeded GLWE ciphertext`](`SeededGlweCiphertext`) encrypting zero under the joint GLWE secret
+/// key.
+///
+/// # Panics
+///
+/// Panics if `contributions` is empty or if the contributions do not share the same parameters and
+/// compression seed.
+pub fn allocate_and_aggregate_seeded_glwe_ciphertext_contributions<Scalar, InputCont>(
+    contributions: &[SeededGlweCiphertext<InputCont>],
+) -> SeededGlweCiphertextOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+{
+    let first = contributions
+        .first()
+        .expect("Cannot aggregate an empty list of contributions");
+
+    assert!(first
+        .ciphertext_modulus()
+        .is_compatible_with_native_modulus());
+
+    let mut aggregated = SeededGlweCiphertextOwned::new(
+        Scalar::ZERO,
+        first.glwe_size(),
+        first.polynomial_size(),
+        first.compression_seed(),
+        first.ciphertext_modulus(),
+    );
+
+    for contribution in contributions {
+        assert!(
+            contribution.glwe_size() == aggregated.glwe_size()
+                && contribution.polynomial_size() == aggregated.polynomial_size()
+                && contribution.compression_seed() == aggregated.compression_seed()
+                && contribution.ciphertext_modulus() == aggregated.ciphertext_modulus(),
+            "All SeededGlweCiphertext contributions must share the same parameters \
+            and compression seed"
+        );
+
+        slice_wrapping_add_assign(aggregated.as_mut(), contribution.as_ref());
+    }
+
+    aggregated
+}
+
+/// Aggregate the contributions of all parties to a distributed
+/// [`LWE bootstrap key`](`LweBootstrapKey`) generation into the bootstrap key from the joint LWE
+/// secret key to the joint GLWE secret key.
+///
+/// The contributions must be ordered by party index, as the joint LWE secret key is the
+/// concatenation of the LWE secret key shares.
+///
+/// # Panics
+///
+/// Panics if `contributions` is empty or if the contributions do not share the same parameters.
+pub fn allocate_and_aggregate_lwe_bootstrap_key_contributions<Scalar, InputCont>(
+    contributions: &[LweBootstrapKey<InputCont>],
+) -> LweBootstrapKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+{
+    let first = contributions
+        .first()
+        .expect("Cannot aggregate an empty list of contributions");
+
+    let mut container = Vec::with_capacity(
+        contributions
+            .iter()
+            .map(|contribution| contribution.as_ref().len())
+            .sum(),
+    );
+
+    for contribution in contributions {
+        assert!(
+            contribution.glwe_size() == first.glwe_size()
+                && contribution.polynomial_size() == first.polynomial_size()
+                && contribution.decomposition_base_log() == first.decomposition_base_log()
+                && contribution.decomposition_level_count() == first.decomposition_level_count()
+                && contribution.ciphertext_modulus() == first.ciphertext_modulus(),
+            "All LweBootstrapKey contributions must share the same parameters"
+        );
+
+        container.extend_from_slice(contribution.as_ref());
+    }
+
+    LweBootstrapKey::from_container(
+        container,
+        first.glwe_size(),
+        first.polynomial_size(),
+        first.decomposition_base_log(),
+        first.decomposition_level_count(),
+        first.ciphertext_modulus(),
+    )
+}
@@ -413,6 +413,58 @@ pub fn polynomial_wrapping_monic_monomial_mul<Scalar, OutputCont, InputCont>(
     }
 }
 
+/// Evaluate the automorphism $X \mapsto X^{k}$ (mod $(X^{N}+1)$) on the input polynomial and
+/// write the result to the output polynomial, the automorphism index $k$ must be odd.
+///
+/// # Note
+///
+/// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
+/// unsigned integer capacity.
+///
+/// # Examples
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let input = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+/// let mut output = Polynomial::from_container(vec![0, 0, 0, 0]);
+/// polynomial_wrapping_automorphism(&mut output, &input, 3);
+/// assert_eq!(output.as_ref(), &[1, 4, 253, 2]);
+/// ```
+pub fn polynomial_wrapping_automorphism<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    automorphism_index: usize,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == input.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input polynomial size {:?}.",
+        output.polynomial_size(),
+        input.polynomial_size(),
+    );
+    assert!(
+        automorphism_index % 2 == 1,
+        "The automorphism index must be odd, got {automorphism_index}."
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+    // X^{2N} = 1 mod X^N + 1, so the index only matters modulo 2N
+    let automorphism_index = automorphism_index % (2 * polynomial_size);
+
+    for (input_degree, &coefficient) in input.iter().enumerate() {
+        let output_degree = (input_degree * automorphism_index) % (2 * polynomial_size);
+        if output_degree < polynomial_size {
+            output[output_degree] = coefficient;
+        } else {
+            output[output_degree - polynomial_size] = coefficient.wrapping_neg();
+        }
+    }
+}
+
 /// Multiply (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
 /// $X^{degree}$, then subtract the input from the result and assign to the output.
 ///
@@ -738,6 +790,408 @@ pub fn polynomial_karatsuba_wrapping_mul<Scalar, OutputCont, LhsCont, RhsCont>(
     slice_wrapping_sub_assign(&mut output[top], &a1[bottom]);
 }
 
+/// Add a polynomial to the output polynomial, modulo a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let mut first = Polynomial::from_container(vec![1u8, 2, 3, 4, 5, 6]);
+/// let second = Polynomial::from_container(vec![250u8, 250, 250, 1, 2, 3]);
+/// polynomial_wrapping_add_assign_custom_mod(&mut first, &second, 251);
+/// assert_eq!(first.as_ref(), &[0u8, 1, 2, 5, 7, 9]);
+/// ```
+pub fn polynomial_wrapping_add_assign_custom_mod<Scalar, OutputCont, InputCont>(
+    lhs: &mut Polynomial<OutputCont>,
+    rhs: &Polynomial<InputCont>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert_eq!(lhs.polynomial_size(), rhs.polynomial_size());
+    slice_wrapping_add_assign_custom_mod(lhs.as_mut(), rhs.as_ref(), custom_modulus)
+}
+
+/// Subtract a polynomial to the output polynomial, modulo a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let mut first = Polynomial::from_container(vec![1u8, 2, 3, 4, 5, 6]);
+/// let second = Polynomial::from_container(vec![250u8, 250, 250, 1, 2, 3]);
+/// polynomial_wrapping_sub_assign_custom_mod(&mut first, &second, 251);
+/// assert_eq!(first.as_ref(), &[2, 3, 4, 3, 3, 3]);
+/// ```
+pub fn polynomial_wrapping_sub_assign_custom_mod<Scalar, OutputCont, InputCont>(
+    lhs: &mut Polynomial<OutputCont>,
+    rhs: &Polynomial<InputCont>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert_eq!(lhs.polynomial_size(), rhs.polynomial_size());
+    slice_wrapping_sub_assign_custom_mod(lhs.as_mut(), rhs.as_ref(), custom_modulus)
+}
+
+/// Add the sum of the element-wise product between two lists of polynomials to the output
+/// polynomial, modulo a custom modulus.
+///
+/// I.e., if the output polynomial is $C(X)$, for a collection of polynomials $(P\_i(X)))\_i$
+/// and another collection of polynomials $(B\_i(X))\_i$ we perform the operation:
+/// $$
+/// C(X) := C(X) + \sum\_i P\_i(X) \times B\_i(X) mod (X^{N} + 1)
+/// $$
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_list = PolynomialList::from_container(vec![100_u8, 20, 3, 4, 5, 6], PolynomialSize(3));
+/// let bin_poly_list = PolynomialList::from_container(vec![0, 1, 1, 1, 0, 0], PolynomialSize(3));
+/// let mut output = Polynomial::new(250, PolynomialSize(3));
+/// polynomial_wrapping_add_multisum_assign_custom_mod(
+///     &mut output,
+///     &poly_list,
+///     &bin_poly_list,
+///     251,
+/// );
+/// assert_eq!(output.as_ref(), &[231, 101, 125]);
+/// ```
+pub fn polynomial_wrapping_add_multisum_assign_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont1,
+    InputCont2,
+>(
+    output: &mut Polynomial<OutputCont>,
+    poly_list_1: &PolynomialList<InputCont1>,
+    poly_list_2: &PolynomialList<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    for (poly_1, poly_2) in poly_list_1.iter().zip(poly_list_2.iter()) {
+        polynomial_wrapping_add_mul_assign_custom_mod(output, &poly_1, &poly_2, custom_modulus);
+    }
+}
+
+/// Subtract the sum of the element-wise product between two lists of polynomials to the output
+/// polynomial, modulo a custom modulus.
+///
+/// I.e., if the output polynomial is $C(X)$, for two lists of polynomials $(P\_i(X)))\_i$ and
+/// $(B\_i(X))\_i$ we perform the operation:
+/// $$
+/// C(X) := C(X) - \sum\_i P\_i(X) \times B\_i(X) mod (X^{N} + 1)
+/// $$
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_list = PolynomialList::from_container(vec![100_u8, 20, 3, 4, 5, 6], PolynomialSize(3));
+/// let bin_poly_list = PolynomialList::from_container(vec![0, 1, 1, 1, 0, 0], PolynomialSize(3));
+/// let mut output = Polynomial::new(250, PolynomialSize(3));
+/// polynomial_wrapping_sub_multisum_assign_custom_mod(
+///     &mut output,
+///     &poly_list,
+///     &bin_poly_list,
+///     251,
+/// );
+/// assert_eq!(output.as_ref(), &[18, 148, 124]);
+/// ```
+pub fn polynomial_wrapping_sub_multisum_assign_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont1,
+    InputCont2,
+>(
+    output: &mut Polynomial<OutputCont>,
+    poly_list_1: &PolynomialList<InputCont1>,
+    poly_list_2: &PolynomialList<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    for (poly_1, poly_2) in poly_list_1.iter().zip(poly_list_2.iter()) {
+        polynomial_wrapping_sub_mul_assign_custom_mod(output, &poly_1, &poly_2, custom_modulus);
+    }
+}
+
+/// Compute the product of two polynomials reduced modulo $(X^{N}+1)$ and modulo a custom modulus
+/// with the schoolbook algorithm, adding or subtracting it to the output.
+///
+/// Each output coefficient is accumulated on 128 bits and only reduced once at the end, the
+/// overflows of the accumulator being folded back with $2^{128} \bmod q$.
+fn polynomial_wrapping_add_or_sub_mul_assign_custom_mod_schoolbook<Scalar>(
+    mut output: Polynomial<&mut [Scalar]>,
+    lhs: Polynomial<&[Scalar]>,
+    rhs: Polynomial<&[Scalar]>,
+    custom_modulus: Scalar,
+    subtract: bool,
+) where
+    Scalar: UnsignedInteger,
+{
+    assert!(
+        Scalar::BITS <= 64,
+        "custom modulus polynomial multiplication is not yet implemented for types wider than u64"
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+    let modulus: u128 = custom_modulus.cast_into();
+    // 2^128 mod q, used to fold the accumulator back when it overflows
+    let two_pow_128_mod_q = (u128::MAX % modulus + 1) % modulus;
+
+    let accumulate = |acc: u128, lhs_coeff: Scalar, rhs_coeff: Scalar| -> u128 {
+        let lhs_coeff: u128 = lhs_coeff.cast_into();
+        let rhs_coeff: u128 = rhs_coeff.cast_into();
+        let (sum, overflowed) = acc.overflowing_add(lhs_coeff * rhs_coeff);
+        // After an overflow sum < lhs_coeff * rhs_coeff < 2^128 - q so this cannot overflow
+        if overflowed {
+            sum + two_pow_128_mod_q
+        } else {
+            sum
+        }
+    };
+
+    let lhs = lhs.as_ref();
+    let rhs = rhs.as_ref();
+
+    for (degree, output_coefficient) in output.as_mut().iter_mut().enumerate() {
+        // Terms with lhs_degree + rhs_degree == degree
+        let positive = lhs[..=degree]
+            .iter()
+            .zip(rhs[..=degree].iter().rev())
+            .fold(0u128, |acc, (&l, &r)| accumulate(acc, l, r));
+        // Terms with lhs_degree + rhs_degree == degree + N, which are negated by the X^N + 1
+        // reduction
+        let negative = lhs[degree + 1..]
+            .iter()
+            .zip(rhs[degree + 1..polynomial_size].iter().rev())
+            .fold(0u128, |acc, (&l, &r)| accumulate(acc, l, r));
+
+        let product = Scalar::cast_from(positive % modulus)
+            .wrapping_sub_custom_mod(Scalar::cast_from(negative % modulus), custom_modulus);
+
+        *output_coefficient = if subtract {
+            (*output_coefficient).wrapping_sub_custom_mod(product, custom_modulus)
+        } else {
+            (*output_coefficient).wrapping_add_custom_mod(product, custom_modulus)
+        };
+    }
+}
+
+/// Add the result of the product between two polynomials, reduced modulo $(X^{N}+1)$, to the
+/// output polynomial, modulo a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_1 = Polynomial::from_container(vec![1_u8, 2, 3]);
+/// let poly_2 = Polynomial::from_container(vec![0, 1, 1]);
+/// let mut res = Polynomial::from_container(vec![1, 0, 249]);
+/// polynomial_wrapping_add_mul_assign_custom_mod(&mut res, &poly_1, &poly_2, 251);
+/// assert_eq!(res.as_ref(), &[247, 249, 1]);
+/// ```
+pub fn polynomial_wrapping_add_mul_assign_custom_mod<Scalar, OutputCont, InputCont1, InputCont2>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<InputCont1>,
+    rhs: &Polynomial<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == lhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input lhs polynomial {:?}.",
+        output.polynomial_size(),
+        lhs.polynomial_size(),
+    );
+    assert!(
+        output.polynomial_size() == rhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input rhs polynomial {:?}.",
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+
+    polynomial_wrapping_add_or_sub_mul_assign_custom_mod_schoolbook(
+        output.as_mut_view(),
+        lhs.as_view(),
+        rhs.as_view(),
+        custom_modulus,
+        false,
+    );
+}
+
+/// Subtract the result of the product between two polynomials, reduced modulo $(X^{N}+1)$, to the
+/// output polynomial, modulo a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_1 = Polynomial::from_container(vec![1_u8, 2, 3]);
+/// let poly_2 = Polynomial::from_container(vec![0, 1, 1]);
+/// let mut res = Polynomial::from_container(vec![250, 250, 1]);
+/// polynomial_wrapping_sub_mul_assign_custom_mod(&mut res, &poly_1, &poly_2, 251);
+/// assert_eq!(res.as_ref(), &[4, 1, 249]);
+/// ```
+pub fn polynomial_wrapping_sub_mul_assign_custom_mod<Scalar, OutputCont, InputCont1, InputCont2>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<InputCont1>,
+    rhs: &Polynomial<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == lhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input lhs polynomial {:?}.",
+        output.polynomial_size(),
+        lhs.polynomial_size(),
+    );
+    assert!(
+        output.polynomial_size() == rhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input rhs polynomial {:?}.",
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+
+    polynomial_wrapping_add_or_sub_mul_assign_custom_mod_schoolbook(
+        output.as_mut_view(),
+        lhs.as_view(),
+        rhs.as_view(),
+        custom_modulus,
+        true,
+    );
+}
+
+/// Divides (mod $(X^{N}+1)$), the output polynomial with a monic monomial of a given degree i.e.
+/// $X^{degree}$, modulo a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Examples
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let mut poly = Polynomial::from_container(vec![1u8, 2, 3]);
+/// polynomial_wrapping_monic_monomial_div_assign_custom_mod(&mut poly, MonomialDegree(2), 251);
+/// assert_eq!(poly.as_ref(), &[3, 250, 249]);
+/// ```
+pub fn polynomial_wrapping_monic_monomial_div_assign_custom_mod<Scalar, OutputCont>(
+    output: &mut Polynomial<OutputCont>,
+    monomial_degree: MonomialDegree,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let full_cycles_count = monomial_degree.0 / output.as_ref().container_len();
+    if full_cycles_count % 2 != 0 {
+        slice_wrapping_opposite_assign_custom_mod(output.as_mut(), custom_modulus);
+    }
+    let remaining_degree = monomial_degree.0 % output.as_ref().container_len();
+    output.as_mut().rotate_left(remaining_degree);
+    output
+        .as_mut()
+        .iter_mut()
+        .rev()
+        .take(remaining_degree)
+        .for_each(|a| *a = a.wrapping_neg_custom_mod(custom_modulus));
+}
+
+/// Multiply (mod $(X^{N}+1)$), the output polynomial with a monic monomial of a given degree i.e.
+/// $X^{degree}$, modulo a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Examples
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let mut poly = Polynomial::from_container(vec![1u8, 2, 3]);
+/// polynomial_wrapping_monic_monomial_mul_assign_custom_mod(&mut poly, MonomialDegree(2), 251);
+/// assert_eq!(poly.as_ref(), &[249, 248, 1]);
+/// ```
+pub fn polynomial_wrapping_monic_monomial_mul_assign_custom_mod<Scalar, OutputCont>(
+    output: &mut Polynomial<OutputCont>,
+    monomial_degree: MonomialDegree,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let full_cycles_count = monomial_degree.0 / output.as_ref().container_len();
+    if full_cycles_count % 2 != 0 {
+        slice_wrapping_opposite_assign_custom_mod(output.as_mut(), custom_modulus);
+    }
+    let remaining_degree = monomial_degree.0 % output.as_ref().container_len();
+    output.as_mut().rotate_right(remaining_degree);
+    output
+        .as_mut()
+        .iter_mut()
+        .take(remaining_degree)
+        .for_each(|a| *a = a.wrapping_neg_custom_mod(custom_modulus));
+}
+
 const KARATUSBA_STOP: usize = 64;
 /// Compute the induction for the karatsuba algorithm.
 fn induction_karatsuba<Scalar>(res: &mut [Scalar], p: &[Scalar], q: &[Scalar])
@@ -0,0 +1,34 @@
+//! Module with primitives pertaining to [`SeededGlwePublicKey`] decompression.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Decompress a [`SeededGlwePublicKey`], without consuming it, into a standard
+/// [`GlwePublicKey`].
+pub fn decompress_seeded_glwe_public_key<Scalar, InputCont, OutputCont, Gen>(
+    output_pk: &mut GlwePublicKey<OutputCont>,
+    input_pk: &SeededGlwePublicKey<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_pk.ciphertext_modulus(),
+        input_pk.ciphertext_modulus(),
+        "Mismatched CiphertextModulus \
+    between input SeededGlwePublicKey ({:?}) and output GlwePublicKey ({:?})",
+        output_pk.ciphertext_modulus(),
+        input_pk.ciphertext_modulus(),
+    );
+
+    let mut generator = MaskRandomGenerator::<Gen>::new(input_pk.compression_seed().seed);
+    decompress_seeded_glwe_ciphertext_list_with_existing_generator::<_, _, _, Gen>(
+        output_pk,
+        input_pk,
+        &mut generator,
+    );
+}
@@ -38,6 +38,46 @@ where
         })
 }
 
+/// Compute a dot product between two slices containing unsigned integers, modulo a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::slice_algorithms::*;
+/// let first = vec![1u8, 2, 3, 4, 5, 6];
+/// let second = vec![250u8, 250, 250, 1, 2, 3];
+/// let dot_product = slice_wrapping_dot_product_custom_mod(&first, &second, 251);
+/// assert_eq!(dot_product, 26);
+/// ```
+pub fn slice_wrapping_dot_product_custom_mod<Scalar>(
+    lhs: &[Scalar],
+    rhs: &[Scalar],
+    custom_modulus: Scalar,
+) -> Scalar
+where
+    Scalar: UnsignedInteger,
+{
+    assert!(
+        lhs.len() == rhs.len(),
+        "lhs (len: {}) and rhs (len: {}) must have the same length",
+        lhs.len(),
+        rhs.len()
+    );
+
+    lhs.iter()
+        .zip(rhs.iter())
+        .fold(Scalar::ZERO, |acc, (&left, &right)| {
+            acc.wrapping_add_custom_mod(
+                left.wrapping_mul_custom_mod(right, custom_modulus),
+                custom_modulus,
+            )
+        })
+}
+
 /// Add a slice containing unsigned integers to another one element-wise.
 ///
 /// # Note
@@ -110,6 +150,41 @@ where
         .for_each(|(lhs, &rhs)| *lhs = (*lhs).wrapping_add(rhs));
 }
 
+/// Add a slice containing unsigned integers to another one element-wise and in place, modulo a
+/// custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::slice_algorithms::*;
+/// let mut first = vec![1u8, 2, 3, 4, 5, 6];
+/// let second = vec![250u8, 250, 250, 1, 2, 3];
+/// slice_wrapping_add_assign_custom_mod(&mut first, &second, 251);
+/// assert_eq!(&first, &[0u8, 1, 2, 5, 7, 9]);
+/// ```
+pub fn slice_wrapping_add_assign_custom_mod<Scalar>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+{
+    assert!(
+        lhs.len() == rhs.len(),
+        "lhs (len: {}) and rhs (len: {}) must have the same length",
+        lhs.len(),
+        rhs.len()
+    );
+
+    lhs.iter_mut()
+        .zip(rhs.iter())
+        .for_each(|(lhs, &rhs)| *lhs = (*lhs).wrapping_add_custom_mod(rhs, custom_modulus));
+}
+
 /// Add a slice containing unsigned integers to another one mutiplied by a scalar.
 ///
 /// Let *a*,*b* be two slices, let *c* be a scalar, this computes: *a <- a+bc*
@@ -219,6 +294,41 @@ where
         .for_each(|(lhs, &rhs)| *lhs = (*lhs).wrapping_sub(rhs));
 }
 
+/// Subtract a slice containing unsigned integers to another one element-wise and in place, modulo
+/// a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::slice_algorithms::*;
+/// let mut first = vec![1u8, 2, 3, 4, 5, 6];
+/// let second = vec![250u8, 250, 250, 1, 2, 3];
+/// slice_wrapping_sub_assign_custom_mod(&mut first, &second, 251);
+/// assert_eq!(&first, &[2u8, 3, 4, 3, 3, 3]);
+/// ```
+pub fn slice_wrapping_sub_assign_custom_mod<Scalar>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+{
+    assert!(
+        lhs.len() == rhs.len(),
+        "lhs (len: {}) and rhs (len: {}) must have the same length",
+        lhs.len(),
+        rhs.len()
+    );
+
+    lhs.iter_mut()
+        .zip(rhs.iter())
+        .for_each(|(lhs, &rhs)| *lhs = (*lhs).wrapping_sub_custom_mod(rhs, custom_modulus));
+}
+
 /// Subtract a slice containing unsigned integers to another one mutiplied by a scalar,
 /// element-wise and in place.
 ///
@@ -280,6 +390,32 @@ where
         .for_each(|elt| *elt = (*elt).wrapping_neg());
 }
 
+/// Compute the opposite of a slice containing unsigned integers, element-wise and in place, modulo
+/// a custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::slice_algorithms::*;
+/// let mut first = vec![0u8, 1, 2, 3, 4, 5];
+/// slice_wrapping_opposite_assign_custom_mod(&mut first, 251);
+/// assert_eq!(&first, &[0u8, 250, 249, 248, 247, 246]);
+/// ```
+pub fn slice_wrapping_opposite_assign_custom_mod<Scalar>(
+    slice: &mut [Scalar],
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+{
+    slice
+        .iter_mut()
+        .for_each(|elt| *elt = (*elt).wrapping_neg_custom_mod(custom_modulus));
+}
+
 /// Multiply a slice containing unsigned integers by a scalar, element-wise and in place.
 ///
 /// # Note
@@ -304,6 +440,33 @@ where
         .for_each(|lhs| *lhs = (*lhs).wrapping_mul(rhs));
 }
 
+/// Multiply a slice containing unsigned integers by a scalar, element-wise and in place, modulo a
+/// custom modulus.
+///
+/// # Note
+///
+/// Inputs are expected to be in $[0; custom\_modulus[$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::slice_algorithms::*;
+/// let mut first = vec![1u8, 2, 3, 4, 5, 6];
+/// let scalar = 250;
+/// slice_wrapping_scalar_mul_assign_custom_mod(&mut first, scalar, 251);
+/// assert_eq!(&first, &[250, 249, 248, 247, 246, 245]);
+/// ```
+pub fn slice_wrapping_scalar_mul_assign_custom_mod<Scalar>(
+    lhs: &mut [Scalar],
+    rhs: Scalar,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+{
+    lhs.iter_mut()
+        .for_each(|lhs| *lhs = (*lhs).wrapping_mul_custom_mod(rhs, custom_modulus));
+}
+
 pub fn slice_wrapping_scalar_div_assign<Scalar>(lhs: &mut [Scalar], rhs: Scalar)
 where
     Scalar: UnsignedInteger,
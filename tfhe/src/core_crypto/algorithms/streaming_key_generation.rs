@@ -0,0 +1,794 @@
+//! Module containing primitives pertaining to the streaming generation of evaluation keys, i.e.
+//! [`LWE keyswitch keys`](`LweKeyswitchKey`), [`LWE bootstrap keys`](`LweBootstrapKey`) and
+//! [`LWE multi_bit bootstrap keys`](`LweMultiBitBootstrapKey`), as well as their Fourier
+//! counterparts.
+//!
+//! Instead of filling an allocated key, the primitives of this module generate the key one block at
+//! a time (a GGSW ciphertext for bootstrap keys, the encryptions of one input key element for
+//! keyswitch keys) and feed it directly to a [`serde::Serializer`]. The serialized output is the
+//! same as the one obtained by serializing the equivalent in-memory entity, so it can be
+//! deserialized as usual, while the peak memory usage stays bounded by the size of a single block.
+//!
+//! The generation happens during serialization, the serializer must therefore only go through the
+//! data once: serializers computing the serialized size in a first pass (e.g. `bincode` with a size
+//! limit) would generate the key twice.
+
+use crate::core_crypto::algorithms::lwe_multi_bit_bootstrap_key_generation::combine_key_bits;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{
+    par_convert_polynomials_list_to_fourier, Fft, FftView,
+};
+use concrete_fft::c64;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use std::cell::RefCell;
+
+/// Generate an [`LWE keyswitch key`](`LweKeyswitchKey`) and serialize it into the given
+/// serializer as it is being generated.
+///
+/// The serialized output is the same as the one of the key returned by
+/// [`allocate_and_generate_new_lwe_keyswitch_key`] called with the same arguments, only the
+/// encryptions of a single input key element are kept in memory at any given time.
+///
+/// ```
+/// use bincode::Options;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweKeyswitchKey creation
+/// let input_lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let output_lwe_dimension = LweDimension(2048);
+/// let decomp_base_log = DecompositionBaseLog(3);
+/// let decomp_level_count = DecompositionLevelCount(5);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let input_lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+///     output_lwe_dimension,
+///     &mut secret_generator,
+/// );
+///
+/// // Any std::io::Write can be used here, e.g. a File
+/// let mut serialized_ksk: Vec<u8> = vec![];
+/// let mut serializer = bincode::Serializer::new(
+///     &mut serialized_ksk,
+///     bincode::DefaultOptions::new().with_fixint_encoding(),
+/// );
+///
+/// generate_lwe_keyswitch_key_into_serializer(
+///     &input_lwe_secret_key,
+///     &output_lwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+///     &mut serializer,
+/// )
+/// .unwrap();
+///
+/// let ksk: LweKeyswitchKeyOwned<u64> = bincode::deserialize(&serialized_ksk).unwrap();
+///
+/// assert_eq!(ksk.input_key_lwe_dimension(), input_lwe_dimension);
+/// assert_eq!(ksk.output_key_lwe_dimension(), output_lwe_dimension);
+/// assert!(ksk.as_ref().iter().all(|&x| x == 0) == false);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn generate_lwe_keyswitch_key_into_serializer<Scalar, InputKeyCont, OutputKeyCont, Gen, S>(
+    input_lwe_sk: &LweSecretKey<InputKeyCont>,
+    output_lwe_sk: &LweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    Scalar: UnsignedTorus + serde::Serialize,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+    S: serde::Serializer,
+{
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let output_lwe_size = output_lwe_sk.lwe_dimension().to_lwe_size();
+
+    let data = LweKeyswitchKeyDataStream {
+        input_lwe_sk: LweSecretKey::from_container(input_lwe_sk.as_ref()),
+        output_lwe_sk: LweSecretKey::from_container(output_lwe_sk.as_ref()),
+        decomp_base_log,
+        decomp_level_count,
+        noise_parameters,
+        ciphertext_modulus,
+        generator: RefCell::new(generator),
+    };
+
+    // Same layout as the serde derive of LweKeyswitchKey
+    let mut state = serializer.serialize_struct("LweKeyswitchKey", 5)?;
+    state.serialize_field("data", &data)?;
+    state.serialize_field("decomp_base_log", &decomp_base_log)?;
+    state.serialize_field("decomp_level_count", &decomp_level_count)?;
+    state.serialize_field("output_lwe_size", &output_lwe_size)?;
+    state.serialize_field("ciphertext_modulus", &ciphertext_modulus)?;
+    state.end()
+}
+
+/// Generate an [`LWE bootstrap key`](`LweBootstrapKey`) and serialize it into the given serializer
+/// as it is being generated.
+///
+/// The serialized output is the same as the one of the key returned by
+/// [`par_allocate_and_generate_new_lwe_bootstrap_key`] called with the same arguments, only a
+/// single GGSW ciphertext is kept in memory at any given time. Each GGSW ciphertext is encrypted
+/// using [`par_encrypt_constant_ggsw_ciphertext`].
+#[allow(clippy::too_many_arguments)]
+pub fn par_generate_lwe_bootstrap_key_into_serializer<Scalar, InputKeyCont, OutputKeyCont, Gen, S>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter + Sync,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    Scalar: UnsignedTorus + Sync + Send + serde::Serialize,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    S: serde::Serializer,
+{
+    let ggsw_list = bootstrap_key_ggsw_stream(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        noise_parameters,
+        ciphertext_modulus,
+        generator,
+    );
+
+    // Same layout as the serde derive of LweBootstrapKey
+    let mut state = serializer.serialize_struct("LweBootstrapKey", 1)?;
+    state.serialize_field("ggsw_list", &GgswCiphertextListStream(&ggsw_list))?;
+    state.end()
+}
+
+/// Generate an [`LWE bootstrap key`](`LweBootstrapKey`), convert it to the Fourier domain and
+/// serialize it into the given serializer as it is being generated.
+///
+/// The serialized output is the same as the one of the [`FourierLweBootstrapKey`] obtained by
+/// converting the key returned by [`par_allocate_and_generate_new_lwe_bootstrap_key`] with
+/// [`par_convert_standard_lwe_bootstrap_key_to_fourier`]. Only a single GGSW ciphertext is kept in
+/// memory at any given time.
+///
+/// ```
+/// use bincode::Options;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Any std::io::Write can be used here, e.g. a File
+/// let mut serialized_bsk: Vec<u8> = vec![];
+/// let mut serializer = bincode::Serializer::new(
+///     &mut serialized_bsk,
+///     bincode::DefaultOptions::new().with_fixint_encoding(),
+/// );
+///
+/// par_generate_fourier_lwe_bootstrap_key_into_serializer(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+///     &mut serializer,
+/// )
+/// .unwrap();
+///
+/// let fourier_bsk: FourierLweBootstrapKeyOwned = bincode::deserialize(&serialized_bsk).unwrap();
+///
+/// assert_eq!(fourier_bsk.input_lwe_dimension(), small_lwe_dimension);
+/// assert_eq!(fourier_bsk.polynomial_size(), polynomial_size);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn par_generate_fourier_lwe_bootstrap_key_into_serializer<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+    S,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter + Sync,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    S: serde::Serializer,
+{
+    let ggsw_list = bootstrap_key_ggsw_stream(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        noise_parameters,
+        ciphertext_modulus,
+        generator,
+    );
+
+    // Same layout as the serde derive of FourierLweBootstrapKey
+    let mut state = serializer.serialize_struct("FourierLweBootstrapKey", 5)?;
+    state.serialize_field("fourier", &FourierGgswListDataStream(&ggsw_list))?;
+    state.serialize_field("input_lwe_dimension", &input_lwe_secret_key.lwe_dimension())?;
+    state.serialize_field("glwe_size", &ggsw_list.glwe_size)?;
+    state.serialize_field("decomposition_base_log", &decomp_base_log)?;
+    state.serialize_field("decomposition_level_count", &decomp_level_count)?;
+    state.end()
+}
+
+/// Generate an [`LWE multi_bit bootstrap key`](`LweMultiBitBootstrapKey`) and serialize it into
+/// the given serializer as it is being generated.
+///
+/// The serialized output is the same as the one of the key returned by
+/// [`par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key`] called with the same arguments,
+/// only a single GGSW ciphertext is kept in memory at any given time.
+#[allow(clippy::too_many_arguments)]
+pub fn par_generate_lwe_multi_bit_bootstrap_key_into_serializer<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+    S,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+    noise_parameters: impl DispersionParameter + Sync,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    Scalar: UnsignedTorus + CastFrom<usize> + Sync + Send + serde::Serialize,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    S: serde::Serializer,
+{
+    let ggsw_list = multi_bit_bootstrap_key_ggsw_stream(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        grouping_factor,
+        noise_parameters,
+        ciphertext_modulus,
+        generator,
+    );
+
+    // Same layout as the serde derive of LweMultiBitBootstrapKey
+    let mut state = serializer.serialize_struct("LweMultiBitBootstrapKey", 2)?;
+    state.serialize_field("ggsw_list", &GgswCiphertextListStream(&ggsw_list))?;
+    state.serialize_field("grouping_factor", &grouping_factor)?;
+    state.end()
+}
+
+/// Generate an [`LWE multi_bit bootstrap key`](`LweMultiBitBootstrapKey`), convert it to the
+/// Fourier domain and serialize it into the given serializer as it is being generated.
+///
+/// The serialized output is the same as the one of the [`FourierLweMultiBitBootstrapKey`]
+/// obtained by converting the key returned by
+/// [`par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key`] with
+/// [`par_convert_standard_lwe_multi_bit_bootstrap_key_to_fourier`]. Only a single GGSW ciphertext
+/// is kept in memory at any given time.
+#[allow(clippy::too_many_arguments)]
+pub fn par_generate_fourier_lwe_multi_bit_bootstrap_key_into_serializer<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+    S,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+    noise_parameters: impl DispersionParameter + Sync,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    Scalar: UnsignedTorus + CastFrom<usize> + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    S: serde::Serializer,
+{
+    let ggsw_list = multi_bit_bootstrap_key_ggsw_stream(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        grouping_factor,
+        noise_parameters,
+        ciphertext_modulus,
+        generator,
+    );
+
+    // Same layout as the serde derive of FourierLweMultiBitBootstrapKey
+    let mut state = serializer.serialize_struct("FourierLweMultiBitBootstrapKey", 6)?;
+    state.serialize_field("fourier", &FourierGgswListDataStream(&ggsw_list))?;
+    state.serialize_field("input_lwe_dimension", &input_lwe_secret_key.lwe_dimension())?;
+    state.serialize_field("glwe_size", &ggsw_list.glwe_size)?;
+    state.serialize_field("decomposition_base_log", &decomp_base_log)?;
+    state.serialize_field("decomposition_level_count", &decomp_level_count)?;
+    state.serialize_field("grouping_factor", &grouping_factor)?;
+    state.end()
+}
+
+// Data of an LweKeyswitchKey, generated during serialization.
+struct LweKeyswitchKeyDataStream<'a, 'g, Scalar, NoiseParam, Gen>
+where
+    Scalar: UnsignedInteger,
+    Gen: ByteRandomGenerator,
+{
+    input_lwe_sk: LweSecretKey<&'a [Scalar]>,
+    output_lwe_sk: LweSecretKey<&'a [Scalar]>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: NoiseParam,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: RefCell<&'g mut EncryptionRandomGenerator<Gen>>,
+}
+
+impl<'a, 'g, Scalar, NoiseParam, Gen> serde::Serialize
+    for LweKeyswitchKeyDataStream<'a, 'g, Scalar, NoiseParam, Gen>
+where
+    Scalar: UnsignedTorus + serde::Serialize,
+    NoiseParam: DispersionParameter,
+    Gen: ByteRandomGenerator,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut generator = self.generator.borrow_mut();
+
+        let output_lwe_size = self.output_lwe_sk.lwe_dimension().to_lwe_size();
+
+        // The plaintexts used to encrypt a key element will be stored in this buffer
+        let mut decomposition_plaintexts_buffer =
+            PlaintextListOwned::new(Scalar::ZERO, PlaintextCount(self.decomp_level_count.0));
+
+        // The encryptions of a single key element, reused for all key elements
+        let mut keyswitch_key_block = LweCiphertextListOwned::new(
+            Scalar::ZERO,
+            output_lwe_size,
+            LweCiphertextCount(self.decomp_level_count.0),
+            self.ciphertext_modulus,
+        );
+
+        let mut state = serializer.serialize_seq(Some(
+            self.input_lwe_sk.lwe_dimension().0 * keyswitch_key_block.as_ref().len(),
+        ))?;
+
+        // This follows generate_lwe_keyswitch_key so that both produce the same key
+        for input_key_element in self.input_lwe_sk.as_ref().iter() {
+            for (level, message) in (1..=self.decomp_level_count.0)
+                .rev()
+                .map(DecompositionLevel)
+                .zip(decomposition_plaintexts_buffer.iter_mut())
+            {
+                *message.0 =
+                    DecompositionTerm::new(level, self.decomp_base_log, *input_key_element)
+                        .to_recomposition_summand()
+                        .wrapping_div(
+                            self.ciphertext_modulus
+                                .get_power_of_two_scaling_to_native_torus(),
+                        );
+            }
+
+            encrypt_lwe_ciphertext_list(
+                &self.output_lwe_sk,
+                &mut keyswitch_key_block,
+                &decomposition_plaintexts_buffer,
+                self.noise_parameters,
+                &mut **generator,
+            );
+
+            for element in keyswitch_key_block.as_ref().iter() {
+                state.serialize_element(element)?;
+            }
+        }
+
+        state.end()
+    }
+}
+
+// A list of GGSW ciphertexts encrypting constants, generated one at a time during serialization.
+//
+// Each item of `ggsw_sources` holds the generator forked for a GGSW ciphertext and the plaintext
+// it encrypts.
+struct GgswListStream<'a, Scalar, NoiseParam, Iter>
+where
+    Scalar: UnsignedInteger,
+{
+    glwe_secret_key: GlweSecretKey<&'a [Scalar]>,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: NoiseParam,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    ggsw_count: usize,
+    ggsw_sources: RefCell<Iter>,
+}
+
+impl<'a, Scalar, NoiseParam, Iter, Gen> GgswListStream<'a, Scalar, NoiseParam, Iter>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    NoiseParam: DispersionParameter + Sync,
+    Iter: Iterator<Item = (EncryptionRandomGenerator<Gen>, Plaintext<Scalar>)>,
+    Gen: ParallelByteRandomGenerator,
+{
+    fn ggsw_size(&self) -> usize {
+        ggsw_ciphertext_size(
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_level_count,
+        )
+    }
+
+    // Encrypt the GGSW ciphertexts one after the other, reusing the same buffer, and pass each of
+    // them to `f`.
+    fn for_each_ggsw<E>(&self, mut f: impl FnMut(&[Scalar]) -> Result<(), E>) -> Result<(), E> {
+        let mut ggsw = GgswCiphertextOwned::new(
+            Scalar::ZERO,
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.ciphertext_modulus,
+        );
+
+        let mut ggsw_sources = self.ggsw_sources.borrow_mut();
+
+        for (mut generator, plaintext) in &mut *ggsw_sources {
+            par_encrypt_constant_ggsw_ciphertext(
+                &self.glwe_secret_key,
+                &mut ggsw,
+                plaintext,
+                self.noise_parameters,
+                &mut generator,
+            );
+
+            f(ggsw.as_ref())?;
+        }
+
+        Ok(())
+    }
+}
+
+// Same layout as the serde derive of GgswCiphertextList
+struct GgswCiphertextListStream<'s, 'a, Scalar, NoiseParam, Iter>(
+    &'s GgswListStream<'a, Scalar, NoiseParam, Iter>,
+)
+where
+    Scalar: UnsignedInteger;
+
+impl<'s, 'a, Scalar, NoiseParam, Iter, Gen> serde::Serialize
+    for GgswCiphertextListStream<'s, 'a, Scalar, NoiseParam, Iter>
+where
+    Scalar: UnsignedTorus + Sync + Send + serde::Serialize,
+    NoiseParam: DispersionParameter + Sync,
+    Iter: Iterator<Item = (EncryptionRandomGenerator<Gen>, Plaintext<Scalar>)>,
+    Gen: ParallelByteRandomGenerator,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ggsw_list = self.0;
+
+        let mut state = serializer.serialize_struct("GgswCiphertextList", 6)?;
+        state.serialize_field("data", &GgswListDataStream(ggsw_list))?;
+        state.serialize_field("glwe_size", &ggsw_list.glwe_size)?;
+        state.serialize_field("polynomial_size", &ggsw_list.polynomial_size)?;
+        state.serialize_field("decomp_base_log", &ggsw_list.decomp_base_log)?;
+        state.serialize_field("decomp_level_count", &ggsw_list.decomp_level_count)?;
+        state.serialize_field("ciphertext_modulus", &ggsw_list.ciphertext_modulus)?;
+        state.end()
+    }
+}
+
+// The coefficients of the GGSW ciphertexts, as a flat sequence of scalars.
+struct GgswListDataStream<'s, 'a, Scalar, NoiseParam, Iter>(
+    &'s GgswListStream<'a, Scalar, NoiseParam, Iter>,
+)
+where
+    Scalar: UnsignedInteger;
+
+impl<'s, 'a, Scalar, NoiseParam, Iter, Gen> serde::Serialize
+    for GgswListDataStream<'s, 'a, Scalar, NoiseParam, Iter>
+where
+    Scalar: UnsignedTorus + Sync + Send + serde::Serialize,
+    NoiseParam: DispersionParameter + Sync,
+    Iter: Iterator<Item = (EncryptionRandomGenerator<Gen>, Plaintext<Scalar>)>,
+    Gen: ParallelByteRandomGenerator,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ggsw_list = self.0;
+
+        let mut state =
+            serializer.serialize_seq(Some(ggsw_list.ggsw_count * ggsw_list.ggsw_size()))?;
+
+        ggsw_list.for_each_ggsw(|ggsw| {
+            for element in ggsw.iter() {
+                state.serialize_element(element)?;
+            }
+            Ok(())
+        })?;
+
+        state.end()
+    }
+}
+
+// The GGSW ciphertexts converted to the Fourier domain, same layout as the serialization of
+// FourierPolynomialList.
+struct FourierGgswListDataStream<'s, 'a, Scalar, NoiseParam, Iter>(
+    &'s GgswListStream<'a, Scalar, NoiseParam, Iter>,
+)
+where
+    Scalar: UnsignedInteger;
+
+impl<'s, 'a, Scalar, NoiseParam, Iter, Gen> serde::Serialize
+    for FourierGgswListDataStream<'s, 'a, Scalar, NoiseParam, Iter>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    NoiseParam: DispersionParameter + Sync,
+    Iter: Iterator<Item = (EncryptionRandomGenerator<Gen>, Plaintext<Scalar>)>,
+    Gen: ParallelByteRandomGenerator,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct SingleFourierPolynomial<'f> {
+            fft: FftView<'f>,
+            buf: &'f [c64],
+        }
+
+        impl<'f> serde::Serialize for SingleFourierPolynomial<'f> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.fft.serialize_fourier_buffer(serializer, self.buf)
+            }
+        }
+
+        let ggsw_list = self.0;
+        let polynomial_size = ggsw_list.polynomial_size;
+        let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+        let polynomial_count_per_ggsw = ggsw_list.ggsw_size() / polynomial_size.0;
+        let chunk_count = ggsw_list.ggsw_count * polynomial_count_per_ggsw;
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+
+        let mut fourier_ggsw =
+            vec![c64::default(); polynomial_count_per_ggsw * fourier_polynomial_size];
+
+        let mut state = serializer.serialize_seq(Some(2 + chunk_count))?;
+        state.serialize_element(&polynomial_size)?;
+        state.serialize_element(&chunk_count)?;
+
+        ggsw_list.for_each_ggsw(|ggsw| {
+            par_convert_polynomials_list_to_fourier(&mut fourier_ggsw, ggsw, polynomial_size, fft);
+
+            for buf in fourier_ggsw.chunks_exact(fourier_polynomial_size) {
+                state.serialize_element(&SingleFourierPolynomial { fft, buf })?;
+            }
+            Ok(())
+        })?;
+
+        state.end()
+    }
+}
+
+fn bootstrap_key_ggsw_stream<'a, Scalar, InputKeyCont, OutputKeyCont, NoiseParam, Gen>(
+    input_lwe_secret_key: &'a LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &'a GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: NoiseParam,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GgswListStream<
+    'a,
+    Scalar,
+    NoiseParam,
+    impl Iterator<Item = (EncryptionRandomGenerator<Gen>, Plaintext<Scalar>)> + 'a,
+>
+where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator + 'a,
+{
+    let glwe_size = output_glwe_secret_key.glwe_dimension().to_glwe_size();
+    let polynomial_size = output_glwe_secret_key.polynomial_size();
+
+    // The sequential fork yields the same generators as the parallel one used by
+    // par_generate_lwe_bootstrap_key
+    let gen_iter = generator
+        .fork_bsk_to_ggsw::<Scalar>(
+            input_lwe_secret_key.lwe_dimension(),
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+        .unwrap();
+
+    let ggsw_sources = gen_iter.zip(
+        input_lwe_secret_key
+            .as_ref()
+            .iter()
+            .map(|&input_key_element| Plaintext(input_key_element)),
+    );
+
+    GgswListStream {
+        glwe_secret_key: GlweSecretKey::from_container(
+            output_glwe_secret_key.as_ref(),
+            polynomial_size,
+        ),
+        glwe_size,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        noise_parameters,
+        ciphertext_modulus,
+        ggsw_count: input_lwe_secret_key.lwe_dimension().0,
+        ggsw_sources: RefCell::new(ggsw_sources),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn multi_bit_bootstrap_key_ggsw_stream<'a, Scalar, InputKeyCont, OutputKeyCont, NoiseParam, Gen>(
+    input_lwe_secret_key: &'a LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &'a GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+    noise_parameters: NoiseParam,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GgswListStream<
+    'a,
+    Scalar,
+    NoiseParam,
+    impl Iterator<Item = (EncryptionRandomGenerator<Gen>, Plaintext<Scalar>)> + 'a,
+>
+where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator + 'a,
+{
+    let input_lwe_dimension = input_lwe_secret_key.lwe_dimension();
+
+    assert!(
+        input_lwe_dimension.0 % grouping_factor.0 == 0,
+        "Multi Bit BSK requires input LWE dimension ({}) to be a multiple of {}",
+        input_lwe_dimension.0,
+        grouping_factor.0
+    );
+
+    let glwe_size = output_glwe_secret_key.glwe_dimension().to_glwe_size();
+    let polynomial_size = output_glwe_secret_key.polynomial_size();
+    let ggsw_per_multi_bit_element = grouping_factor.ggsw_per_multi_bit_element();
+
+    // The sequential forks yield the same generators as the parallel ones used by
+    // par_generate_lwe_multi_bit_bootstrap_key
+    let gen_iter = generator
+        .fork_multi_bit_bsk_to_ggsw_group::<Scalar>(
+            input_lwe_dimension,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            grouping_factor,
+        )
+        .unwrap();
+
+    let ggsw_sources = gen_iter
+        .zip(
+            input_lwe_secret_key
+                .as_ref()
+                .chunks_exact(grouping_factor.0),
+        )
+        .flat_map(move |(mut loop_generator, input_key_elements)| {
+            loop_generator
+                .fork_multi_bit_bsk_ggsw_group_to_ggsw::<Scalar>(
+                    decomp_level_count,
+                    glwe_size,
+                    polynomial_size,
+                    grouping_factor,
+                )
+                .unwrap()
+                .enumerate()
+                .map(move |(bit_inversion_idx, inner_loop_generator)| {
+                    // Use the index of the ggsw as a way to know which bit to invert
+                    let key_bits_plaintext =
+                        combine_key_bits(bit_inversion_idx, input_key_elements);
+                    (inner_loop_generator, Plaintext(key_bits_plaintext))
+                })
+        });
+
+    GgswListStream {
+        glwe_secret_key: GlweSecretKey::from_container(
+            output_glwe_secret_key.as_ref(),
+            polynomial_size,
+        ),
+        glwe_size,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        noise_parameters,
+        ciphertext_modulus,
+        ggsw_count: input_lwe_dimension.0 / grouping_factor.0 * ggsw_per_multi_bit_element.0,
+        ggsw_sources: RefCell::new(ggsw_sources),
+    }
+}
@@ -0,0 +1,297 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+
+fn glwe_encrypt_automorphism_keyswitch_decrypt_custom_mod<
+    Scalar: UnsignedTorus + CastFrom<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let decomp_base_log = DecompositionBaseLog(8);
+    let decomp_level_count = DecompositionLevelCount(4);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = Scalar::ONE.shl(Scalar::BITS - message_modulus_log.0);
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    for test_index in 0..NB_TESTS {
+        let automorphism_index = 2 * (test_index * 97 % polynomial_size.0) + 1;
+
+        let automorphism_key = allocate_and_generate_new_glwe_automorphism_key(
+            &glwe_sk,
+            automorphism_index,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &automorphism_key.as_glwe_ciphertext_list(),
+            ciphertext_modulus
+        ));
+
+        let msg = Polynomial::from_container(
+            (0..polynomial_size.0)
+                .map(|i| Scalar::cast_from(i + test_index) % msg_modulus)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut expected = Polynomial::new(Scalar::ZERO, polynomial_size);
+        polynomial_wrapping_automorphism(&mut expected, &msg, automorphism_index);
+
+        let mut glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        let plaintext_list =
+            PlaintextList::from_container(msg.iter().map(|&m| m * delta).collect::<Vec<_>>());
+
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut output = glwe.clone();
+
+        glwe_ciphertext_automorphism_with_keyswitch(&glwe, &automorphism_key, &mut output);
+
+        assert!(check_encrypted_content_respects_mod(
+            &output,
+            ciphertext_modulus
+        ));
+
+        let mut output_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &output, &mut output_plaintext_list);
+
+        let decoded = output_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect::<Vec<_>>();
+
+        let expected = expected
+            .iter()
+            .map(|&x| x % msg_modulus)
+            .collect::<Vec<_>>();
+
+        assert_eq!(decoded, expected);
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_automorphism_keyswitch_decrypt_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64
+});
+
+fn glwe_encrypt_trace_decrypt_custom_mod<Scalar: UnsignedTorus + CastFrom<usize>>(
+    params: TestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let decomp_base_log = DecompositionBaseLog(8);
+    let decomp_level_count = DecompositionLevelCount(4);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = Scalar::ONE.shl(Scalar::BITS - message_modulus_log.0);
+    // The trace multiplies the message by the polynomial size
+    let input_delta = delta.shr(polynomial_size.log2().0);
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let automorphism_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    assert_eq!(automorphism_keys.len(), polynomial_size.log2().0);
+
+    for test_index in 0..NB_TESTS {
+        let plaintext_list = PlaintextList::from_container(
+            (0..polynomial_size.0)
+                .map(|i| (Scalar::cast_from(i + test_index) % msg_modulus) * input_delta)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        glwe_ciphertext_trace_assign(&mut glwe, &automorphism_keys);
+
+        assert!(check_encrypted_content_respects_mod(
+            &glwe,
+            ciphertext_modulus
+        ));
+
+        let mut output_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut output_plaintext_list);
+
+        let decoded = output_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect::<Vec<_>>();
+
+        assert_eq!(decoded[0], Scalar::cast_from(test_index) % msg_modulus);
+        assert!(decoded[1..].iter().all(|&x| x == Scalar::ZERO));
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_trace_decrypt_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64
+});
+
+fn lwe_encrypt_pack_in_glwe_decrypt_custom_mod<Scalar: UnsignedTorus + CastFrom<usize>>(
+    params: TestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let decomp_base_log = DecompositionBaseLog(8);
+    let decomp_level_count = DecompositionLevelCount(4);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 5;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = Scalar::ONE.shl(Scalar::BITS - message_modulus_log.0);
+    // The packing multiplies the messages by the polynomial size
+    let input_delta = delta.shr(polynomial_size.log2().0);
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let lwe_sk = glwe_sk.as_lwe_secret_key();
+
+    let automorphism_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    for test_index in 0..NB_TESTS {
+        // Pack 1, 2, 4, 8 and 16 ciphertexts
+        let lwe_count = 1 << test_index;
+
+        let msgs = (0..lwe_count)
+            .map(|i| Scalar::cast_from(3 * i + test_index) % msg_modulus)
+            .collect::<Vec<_>>();
+
+        let mut lwe_list = LweCiphertextList::new(
+            Scalar::ZERO,
+            lwe_sk.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(lwe_count),
+            ciphertext_modulus,
+        );
+
+        let plaintext_list = PlaintextList::from_container(
+            msgs.iter().map(|&m| m * input_delta).collect::<Vec<_>>(),
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &lwe_sk,
+            &mut lwe_list,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut packed_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        pack_lwe_ciphertext_list_into_glwe_ciphertext(
+            &lwe_list,
+            &automorphism_keys,
+            &mut packed_glwe,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &packed_glwe,
+            ciphertext_modulus
+        ));
+
+        let mut output_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &packed_glwe, &mut output_plaintext_list);
+
+        let decoded = output_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect::<Vec<_>>();
+
+        let stride = polynomial_size.0 / lwe_count;
+        let expected = (0..polynomial_size.0)
+            .map(|i| {
+                if i % stride == 0 {
+                    msgs[i / stride]
+                } else {
+                    Scalar::ZERO
+                }
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(decoded, expected);
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_pack_in_glwe_decrypt_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64
+});
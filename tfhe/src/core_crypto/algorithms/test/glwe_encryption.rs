@@ -463,3 +463,174 @@ fn glwe_seeded_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: Te
 }
 
 create_parametrized_test!(glwe_seeded_list_encrypt_decrypt_custom_mod);
+
+fn glwe_public_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: TestParams<Scalar>) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let zero_encryption_count = GlwePublicKeyZeroEncryptionCount(10);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let pk = allocate_and_generate_new_glwe_public_key(
+                &glwe_sk,
+                zero_encryption_count,
+                glwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            encrypt_glwe_ciphertext_with_public_key(
+                &pk,
+                &mut glwe,
+                &plaintext_list,
+                &mut rsc.secret_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe,
+                ciphertext_modulus
+            ));
+
+            let mut plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &glwe, &mut plaintext_list);
+
+            let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+            decoded
+                .iter_mut()
+                .zip(plaintext_list.iter())
+                .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+    }
+}
+
+create_parametrized_test!(glwe_public_encrypt_decrypt_custom_mod);
+
+fn glwe_seeded_public_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: TestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let zero_encryption_count = GlwePublicKeyZeroEncryptionCount(10);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let mut seeded_pk = SeededGlwePublicKey::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                zero_encryption_count,
+                rsc.seeder.seed().into(),
+                ciphertext_modulus,
+            );
+
+            generate_seeded_glwe_public_key(
+                &glwe_sk,
+                &mut seeded_pk,
+                glwe_modular_std_dev,
+                rsc.seeder.as_mut(),
+            );
+
+            let mut glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            encrypt_glwe_ciphertext_with_seeded_public_key(
+                &seeded_pk,
+                &mut glwe,
+                &plaintext_list,
+                &mut rsc.secret_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &glwe,
+                ciphertext_modulus
+            ));
+
+            // Encrypting with the decompressed key must give a ciphertext decrypting to the same
+            // message
+            let pk = seeded_pk.decompress_into_glwe_public_key();
+
+            let mut glwe_from_decompressed = glwe.clone();
+
+            encrypt_glwe_ciphertext_with_public_key(
+                &pk,
+                &mut glwe_from_decompressed,
+                &plaintext_list,
+                &mut rsc.secret_random_generator,
+            );
+
+            for ct in [&glwe, &glwe_from_decompressed] {
+                let mut plaintext_list =
+                    PlaintextList::new(Scalar::ZERO, PlaintextCount(ct.polynomial_size().0));
+
+                decrypt_glwe_ciphertext(&glwe_sk, ct, &mut plaintext_list);
+
+                let mut decoded = vec![Scalar::ZERO; plaintext_list.plaintext_count().0];
+
+                decoded
+                    .iter_mut()
+                    .zip(plaintext_list.iter())
+                    .for_each(|(dst, src)| *dst = round_decode(*src.0, delta) % msg_modulus);
+
+                assert!(decoded.iter().all(|&x| x == msg));
+            }
+        }
+    }
+}
+
+create_parametrized_test!(glwe_seeded_public_encrypt_decrypt_custom_mod);
@@ -0,0 +1,130 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_mul;
+
+fn glwe_encrypt_tensor_product_relinearize_decrypt_custom_mod<
+    Scalar: UnsignedTorus + CastFrom<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let decomp_base_log = DecompositionBaseLog(8);
+    let decomp_level_count = DecompositionLevelCount(4);
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    // No padding bit, the product is computed modulo the message modulus
+    let delta: Scalar = Scalar::ONE.shl(Scalar::BITS - message_modulus_log.0);
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let rlk = allocate_and_generate_new_glwe_relinearization_key(
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    assert!(check_encrypted_content_respects_mod(
+        &rlk.as_glwe_ciphertext_list(),
+        ciphertext_modulus
+    ));
+
+    for test_index in 0..NB_TESTS {
+        // Polynomials covering the whole message space
+        let lhs_msg = Polynomial::from_container(
+            (0..polynomial_size.0)
+                .map(|i| Scalar::cast_from(i + test_index) % msg_modulus)
+                .collect::<Vec<_>>(),
+        );
+        let rhs_msg = Polynomial::from_container(
+            (0..polynomial_size.0)
+                .map(|i| Scalar::cast_from(3 * i + 2 * test_index + 1) % msg_modulus)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut expected = Polynomial::new(Scalar::ZERO, polynomial_size);
+        polynomial_wrapping_mul(&mut expected, &lhs_msg, &rhs_msg);
+
+        let mut lhs = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+        let mut rhs = lhs.clone();
+
+        for (glwe, msg) in [(&mut lhs, &lhs_msg), (&mut rhs, &rhs_msg)] {
+            let plaintext_list =
+                PlaintextList::from_container(msg.iter().map(|&m| m * delta).collect::<Vec<_>>());
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                glwe,
+                &plaintext_list,
+                glwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+        }
+
+        let mut tensor_product = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_tensor_product_output_glwe_size(glwe_dimension.to_glwe_size()),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        glwe_tensor_product(&lhs, &rhs, &mut tensor_product, delta);
+
+        assert!(check_encrypted_content_respects_mod(
+            &tensor_product,
+            ciphertext_modulus
+        ));
+
+        let mut output = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        glwe_relinearization(&tensor_product, &rlk, &mut output);
+
+        assert!(check_encrypted_content_respects_mod(
+            &output,
+            ciphertext_modulus
+        ));
+
+        let mut output_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &output, &mut output_plaintext_list);
+
+        let decoded = output_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect::<Vec<_>>();
+
+        let expected = expected
+            .iter()
+            .map(|&x| x % msg_modulus)
+            .collect::<Vec<_>>();
+
+        assert_eq!(decoded, expected);
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_tensor_product_relinearize_decrypt_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64
+});
@@ -98,3 +98,97 @@ fn lwe_encrypt_ks_decrypt_custom_mod<Scalar: UnsignedTorus + Send + Sync>(
 }
 
 create_parametrized_test!(lwe_encrypt_ks_decrypt_custom_mod);
+
+fn cast_lwe_secret_key_to_u32(lwe_sk: &LweSecretKeyOwned<u64>) -> LweSecretKeyOwned<u32> {
+    LweSecretKey::from_container(lwe_sk.as_ref().iter().map(|&x| x as u32).collect())
+}
+
+#[test]
+fn lwe_encrypt_ks_with_scalar_change_decrypt() {
+    let params = TEST_PARAMS_4_BITS_NATIVE_U64;
+    let input_ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    let output_ciphertext_modulus = CiphertextModulus::<u32>::new_native();
+    let msg_modulus = 1u64 << params.message_modulus_log.0;
+    let delta = (1u64 << 63) / msg_modulus;
+    let output_delta = (1u32 << 31) / msg_modulus as u32;
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+
+    for msg in 0..msg_modulus {
+        for _ in 0..NB_TESTS {
+            let lwe_sk: LweSecretKeyOwned<u64> = allocate_and_generate_new_binary_lwe_secret_key(
+                params.lwe_dimension,
+                &mut rsc.secret_random_generator,
+            );
+            let glwe_sk: GlweSecretKeyOwned<u64> = allocate_and_generate_new_binary_glwe_secret_key(
+                params.glwe_dimension,
+                params.polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+            let big_lwe_sk = glwe_sk.into_lwe_secret_key();
+
+            let lwe_sk_u32 = cast_lwe_secret_key_to_u32(&lwe_sk);
+
+            let ksk_big_to_small = allocate_and_generate_new_lwe_keyswitch_key(
+                &cast_lwe_secret_key_to_u32(&big_lwe_sk),
+                &lwe_sk_u32,
+                params.ks_base_log,
+                params.ks_level,
+                params.lwe_modular_std_dev,
+                output_ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &big_lwe_sk,
+                Plaintext(msg * delta),
+                params.lwe_modular_std_dev,
+                input_ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_ct = LweCiphertext::new(
+                0u32,
+                lwe_sk.lwe_dimension().to_lwe_size(),
+                output_ciphertext_modulus,
+            );
+
+            keyswitch_lwe_ciphertext_with_scalar_change(&ksk_big_to_small, &ct, &mut output_ct);
+
+            let decrypted = decrypt_lwe_ciphertext(&lwe_sk_u32, &output_ct);
+
+            let decoded = round_decode(decrypted.0, output_delta) % msg_modulus as u32;
+
+            assert_eq!(msg as u32, decoded);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "does not fit in the output integer type")]
+fn lwe_ks_with_scalar_change_decomposition_too_large() {
+    let input_lwe_dimension = LweDimension(10);
+    let output_lwe_dimension = LweDimension(10);
+    let ksk = LweKeyswitchKey::new(
+        0u32,
+        DecompositionBaseLog(8),
+        DecompositionLevelCount(5),
+        input_lwe_dimension,
+        output_lwe_dimension,
+        CiphertextModulus::new_native(),
+    );
+    let ct = LweCiphertext::new(
+        0u64,
+        input_lwe_dimension.to_lwe_size(),
+        CiphertextModulus::new_native(),
+    );
+    let mut output_ct = LweCiphertext::new(
+        0u32,
+        output_lwe_dimension.to_lwe_size(),
+        CiphertextModulus::new_native(),
+    );
+
+    keyswitch_lwe_ciphertext_with_scalar_change(&ksk, &ct, &mut output_ct);
+}
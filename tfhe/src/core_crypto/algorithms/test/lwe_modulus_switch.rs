@@ -0,0 +1,85 @@
+use super::*;
+
+// DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+// computations
+const LWE_DIMENSION: LweDimension = LweDimension(742);
+const LWE_MODULAR_STD_DEV: StandardDev = StandardDev(0.000007069849454709433);
+const MESSAGE_BITS: usize = 4;
+
+fn lwe_modulus_switch_to<OutputScalar>()
+where
+    OutputScalar: UnsignedInteger,
+    u64: CastInto<OutputScalar>,
+{
+    let mut rsc = TestResources::new();
+
+    let lwe_secret_key: LweSecretKeyOwned<u64> = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = LweSecretKey::from_container(
+        lwe_secret_key
+            .as_ref()
+            .iter()
+            .map(|&coeff| coeff.cast_into())
+            .collect::<Vec<OutputScalar>>(),
+    );
+
+    // Keep one bit of padding
+    let input_delta_log = 64 - MESSAGE_BITS - 1;
+    let output_delta_log = OutputScalar::BITS - MESSAGE_BITS - 1;
+    let decomposer = SignedDecomposer::<OutputScalar>::new(
+        DecompositionBaseLog(MESSAGE_BITS + 1),
+        DecompositionLevelCount(1),
+    );
+
+    for msg in 0..1u64 << MESSAGE_BITS {
+        let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+            &lwe_secret_key,
+            Plaintext(msg << input_delta_log),
+            LWE_MODULAR_STD_DEV,
+            CiphertextModulus::new_native(),
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut switched_lwe = LweCiphertext::new(
+            OutputScalar::ZERO,
+            LWE_DIMENSION.to_lwe_size(),
+            CiphertextModulus::new_native(),
+        );
+
+        modulus_switch_lwe_ciphertext(&lwe, &mut switched_lwe);
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &switched_lwe);
+        let decoded = decomposer.closest_representable(decrypted.0) >> output_delta_log;
+
+        assert_eq!(decoded, msg.cast_into());
+    }
+}
+
+#[test]
+fn lwe_modulus_switch_to_u32() {
+    lwe_modulus_switch_to::<u32>();
+}
+
+#[test]
+fn lwe_modulus_switch_to_u16() {
+    lwe_modulus_switch_to::<u16>();
+}
+
+#[test]
+#[should_panic(expected = "Mismatched LweSize")]
+fn lwe_modulus_switch_mismatched_dimension() {
+    let lwe = LweCiphertext::new(
+        0u64,
+        LWE_DIMENSION.to_lwe_size(),
+        CiphertextModulus::new_native(),
+    );
+    let mut switched_lwe = LweCiphertext::new(
+        0u32,
+        LweSize(LWE_DIMENSION.0),
+        CiphertextModulus::new_native(),
+    );
+
+    modulus_switch_lwe_ciphertext(&lwe, &mut switched_lwe);
+}
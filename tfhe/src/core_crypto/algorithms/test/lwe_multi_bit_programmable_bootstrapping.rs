@@ -602,6 +602,158 @@ fn std_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_custom_mod<
     }
 }
 
+fn lwe_encrypt_multi_bit_many_lut_pbs_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: MultiBitParams<Scalar>,
+) {
+    let input_lwe_dimension = params.input_lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.decomp_base_log;
+    let decomp_level_count = params.decomp_level_count;
+    let grouping_factor = params.grouping_factor;
+    let thread_count = params.thread_count;
+
+    let mut rsc = TestResources::new();
+
+    // Two look-up tables, each one defined on half of the message space
+    const LUT_COUNT: usize = 2;
+    let chunk_size = msg_modulus / Scalar::TWO;
+    let functions: [&dyn Fn(Scalar) -> Scalar; LUT_COUNT] = [
+        &|x| x.wrapping_mul(Scalar::TWO).wrapping_rem(msg_modulus),
+        &|x| x.wrapping_add(Scalar::ONE),
+    ];
+    let packed_f = |x: Scalar| {
+        let lut_index: usize = (x / chunk_size).cast_into();
+        functions[lut_index](x % chunk_size)
+    };
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = chunk_size;
+    const NB_TESTS: usize = 10;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        packed_f,
+    );
+
+    // The look-up tables are one chunk of boxes apart
+    let box_size = polynomial_size.0 / <Scalar as CastInto<usize>>::cast_into(msg_modulus);
+    let sample_extraction_stride =
+        MonomialDegree(<Scalar as CastInto<usize>>::cast_into(chunk_size) * box_size);
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let mut bsk = LweMultiBitBootstrapKey::new(
+        Scalar::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        input_lwe_dimension,
+        grouping_factor,
+        ciphertext_modulus,
+    );
+
+    par_generate_lwe_multi_bit_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        &mut bsk,
+        glwe_modular_std_dev,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweMultiBitBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        grouping_factor,
+    );
+
+    par_convert_standard_lwe_multi_bit_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut out_pbs_cts = LweCiphertextList::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                LweCiphertextCount(LUT_COUNT),
+                ciphertext_modulus,
+            );
+
+            multi_bit_programmable_bootstrap_lwe_ciphertext_many_lut(
+                &lwe_ciphertext_in,
+                &mut out_pbs_cts,
+                &accumulator,
+                &fbsk,
+                thread_count,
+                sample_extraction_stride,
+            );
+
+            let mut deterministic_out_pbs_cts = out_pbs_cts.clone();
+
+            multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext_many_lut(
+                &lwe_ciphertext_in,
+                &mut deterministic_out_pbs_cts,
+                &accumulator,
+                &fbsk,
+                thread_count,
+                sample_extraction_stride,
+            );
+
+            for out_pbs_cts in [&out_pbs_cts, &deterministic_out_pbs_cts] {
+                assert!(check_encrypted_content_respects_mod(
+                    out_pbs_cts,
+                    ciphertext_modulus
+                ));
+
+                for (out_pbs_ct, f) in out_pbs_cts.iter().zip(functions.iter()) {
+                    let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+                    let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+                    assert_eq!(decoded, f(msg));
+                }
+            }
+        }
+    }
+}
+
 // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield
 // correct computations
 const MULTI_BIT_2_2_2_PARAMS: MultiBitParams<u64> = MultiBitParams {
@@ -775,3 +927,21 @@ pub fn std_test_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_factor_3_thread_
         ..MULTI_BIT_2_2_3_PARAMS
     });
 }
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_many_lut_pbs_decrypt_factor_2_thread_5_native_mod() {
+    lwe_encrypt_multi_bit_many_lut_pbs_decrypt_custom_mod::<u64>(MultiBitParams {
+        thread_count: ThreadCount(5),
+        ..MULTI_BIT_2_2_2_PARAMS
+    });
+}
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_many_lut_pbs_decrypt_factor_3_thread_12_custom_mod() {
+    lwe_encrypt_multi_bit_many_lut_pbs_decrypt_custom_mod::<u64>(MultiBitParams {
+        thread_count: ThreadCount(12),
+        message_modulus_log: CiphertextModulusLog(3),
+        ciphertext_modulus: CiphertextModulus::try_new_power_of_2(63).unwrap(),
+        ..MULTI_BIT_2_2_3_PARAMS
+    });
+}
@@ -137,6 +137,613 @@ fn lwe_encrypt_pbs_decrypt_custom_mod<
 
 create_parametrized_test!(lwe_encrypt_pbs_decrypt_custom_mod);
 
+fn lwe_encrypt_pbs_non_binary_secret_key_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| {
+        x.wrapping_mul(Scalar::TWO)
+            .wrapping_sub(Scalar::ONE)
+            .wrapping_rem(msg_modulus)
+    };
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    const NB_TESTS: usize = 10;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    for secret_key_distribution in [
+        SecretKeyDistribution::Ternary,
+        SecretKeyDistribution::Gaussian { std_dev: 1.0 },
+    ] {
+        let input_lwe_secret_key: LweSecretKeyOwned<Scalar> =
+            allocate_and_generate_new_lwe_secret_key(
+                input_lwe_dimension,
+                secret_key_distribution,
+                &mut rsc.secret_random_generator,
+            );
+        let output_glwe_secret_key = allocate_and_generate_new_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            secret_key_distribution,
+            &mut rsc.secret_random_generator,
+        );
+        let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+        let expanded_lwe_secret_key = allocate_and_generate_new_binary_expanded_lwe_secret_key(
+            &input_lwe_secret_key,
+            secret_key_distribution,
+        );
+
+        // The size of the expansion only depends on the distribution of the key
+        let bit_plane_count = secret_key_distribution.bit_plane_count();
+        assert!(lwe_secret_key_bit_plane_count(&input_lwe_secret_key).0 <= bit_plane_count.0);
+        assert_eq!(
+            expanded_lwe_secret_key.lwe_dimension().0,
+            2 * bit_plane_count.0 * input_lwe_dimension.0
+        );
+
+        // Check the binary expansion recomposes to the input key
+        for (&coefficient, expanded_coefficient) in input_lwe_secret_key.as_ref().iter().zip(
+            expanded_lwe_secret_key
+                .as_ref()
+                .chunks_exact(2 * bit_plane_count.0),
+        ) {
+            let recomposed = expanded_coefficient.chunks_exact(2).enumerate().fold(
+                Scalar::ZERO,
+                |acc, (bit_index, bits)| {
+                    assert!(bits
+                        .iter()
+                        .all(|&bit| bit == Scalar::ZERO || bit == Scalar::ONE));
+                    acc.wrapping_add(bits[0].wrapping_sub(bits[1]) << bit_index)
+                },
+            );
+            assert_eq!(recomposed, coefficient);
+        }
+
+        let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+            &expanded_lwe_secret_key,
+            &output_glwe_secret_key,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut fbsk = FourierLweBootstrapKey::new(
+            bsk.input_lwe_dimension(),
+            bsk.glwe_size(),
+            bsk.polynomial_size(),
+            bsk.decomposition_base_log(),
+            bsk.decomposition_level_count(),
+        );
+
+        par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+        drop(bsk);
+
+        for test_index in 0..NB_TESTS {
+            let msg = Scalar::cast_from(test_index) % msg_modulus;
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &fbsk,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_pbs_non_binary_secret_key_decrypt_custom_mod);
+
+fn lwe_encrypt_many_lut_pbs_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    // Two look-up tables, each one defined on half of the message space
+    const LUT_COUNT: usize = 2;
+    let chunk_size = msg_modulus / Scalar::TWO;
+    let functions: [&dyn Fn(Scalar) -> Scalar; LUT_COUNT] = [
+        &|x| x.wrapping_mul(Scalar::TWO).wrapping_rem(msg_modulus),
+        &|x| x.wrapping_add(Scalar::ONE),
+    ];
+    let packed_f = |x: Scalar| {
+        let lut_index: usize = (x / chunk_size).cast_into();
+        functions[lut_index](x % chunk_size)
+    };
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = chunk_size;
+    const NB_TESTS: usize = 10;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        packed_f,
+    );
+
+    // The look-up tables are one chunk of boxes apart
+    let box_size = polynomial_size.0 / <Scalar as CastInto<usize>>::cast_into(msg_modulus);
+    let sample_extraction_stride =
+        MonomialDegree(<Scalar as CastInto<usize>>::cast_into(chunk_size) * box_size);
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    );
+
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut out_pbs_cts = LweCiphertextList::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                LweCiphertextCount(LUT_COUNT),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_lwe_ciphertext_many_lut(
+                &lwe_ciphertext_in,
+                &mut out_pbs_cts,
+                &accumulator,
+                &fbsk,
+                sample_extraction_stride,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_cts,
+                ciphertext_modulus
+            ));
+
+            for (out_pbs_ct, f) in out_pbs_cts.iter().zip(functions.iter()) {
+                let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+                let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+                assert_eq!(decoded, f(msg));
+            }
+        }
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_many_lut_pbs_decrypt_custom_mod);
+
+#[test]
+#[should_panic(expected = "Mismatched input LweDimension")]
+fn lwe_many_lut_pbs_mismatched_input_lwe_dimension() {
+    let polynomial_size = PolynomialSize(256);
+    let glwe_size = GlweSize(2);
+    let lwe_dimension = LweDimension(10);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let fbsk = FourierLweBootstrapKey::new(
+        lwe_dimension,
+        glwe_size,
+        polynomial_size,
+        DecompositionBaseLog(10),
+        DecompositionLevelCount(1),
+    );
+    let input = LweCiphertext::new(
+        0u64,
+        LweDimension(lwe_dimension.0 + 1).to_lwe_size(),
+        ciphertext_modulus,
+    );
+    let mut output = LweCiphertextList::new(
+        0u64,
+        glwe_size
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(polynomial_size)
+            .to_lwe_size(),
+        LweCiphertextCount(2),
+        ciphertext_modulus,
+    );
+    let accumulator = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+
+    programmable_bootstrap_lwe_ciphertext_many_lut(
+        &input,
+        &mut output,
+        &accumulator,
+        &fbsk,
+        MonomialDegree(polynomial_size.0 / 2),
+    );
+}
+
+fn lwe_encrypt_pbs_list_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    // Each ciphertext of the list is bootstrapped with its own look-up table
+    let functions: [&dyn Fn(Scalar) -> Scalar; 2] = [
+        &|x| {
+            x.wrapping_mul(Scalar::TWO)
+                .wrapping_sub(Scalar::ONE)
+                .wrapping_rem(msg_modulus)
+        },
+        &|x| x.wrapping_add(Scalar::ONE).wrapping_rem(msg_modulus),
+    ];
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let msg_count: usize = msg_modulus.cast_into();
+    let ciphertext_count = 2 * msg_count;
+    let msg_and_function = |i: usize| (Scalar::cast_from(i % msg_count), functions[i % 2]);
+
+    let mut accumulators = GlweCiphertextList::new(
+        Scalar::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        GlweCiphertextCount(ciphertext_count),
+        ciphertext_modulus,
+    );
+    for (i, mut accumulator) in accumulators.iter_mut().enumerate() {
+        let (_, f) = msg_and_function(i);
+        let generated = generate_accumulator(
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            msg_count,
+            ciphertext_modulus,
+            delta,
+            f,
+        );
+        accumulator.as_mut().copy_from_slice(generated.as_ref());
+    }
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    );
+
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    let mut lwe_ciphertexts_in = LweCiphertextList::new(
+        Scalar::ZERO,
+        input_lwe_dimension.to_lwe_size(),
+        LweCiphertextCount(ciphertext_count),
+        ciphertext_modulus,
+    );
+    for (i, mut lwe_ciphertext_in) in lwe_ciphertexts_in.iter_mut().enumerate() {
+        let (msg, _) = msg_and_function(i);
+        encrypt_lwe_ciphertext(
+            &input_lwe_secret_key,
+            &mut lwe_ciphertext_in,
+            Plaintext(msg * delta),
+            lwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+    }
+
+    let mut out_pbs_cts = LweCiphertextList::new(
+        Scalar::ZERO,
+        output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+        LweCiphertextCount(ciphertext_count),
+        ciphertext_modulus,
+    );
+    let mut par_out_pbs_cts = out_pbs_cts.clone();
+
+    programmable_bootstrap_lwe_ciphertext_list(
+        &lwe_ciphertexts_in,
+        &mut out_pbs_cts,
+        &accumulators,
+        &fbsk,
+    );
+    par_programmable_bootstrap_lwe_ciphertext_list(
+        &lwe_ciphertexts_in,
+        &mut par_out_pbs_cts,
+        &accumulators,
+        &fbsk,
+    );
+
+    // The bootstrap is deterministic, both versions must compute the same ciphertexts
+    assert_eq!(out_pbs_cts, par_out_pbs_cts);
+
+    assert!(check_encrypted_content_respects_mod(
+        &out_pbs_cts,
+        ciphertext_modulus
+    ));
+
+    for (i, out_pbs_ct) in out_pbs_cts.iter().enumerate() {
+        let (msg, f) = msg_and_function(i);
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+        let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+        assert_eq!(decoded, f(msg));
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_pbs_list_decrypt_custom_mod);
+
+#[test]
+#[should_panic(expected = "Mismatched number of accumulators")]
+fn lwe_pbs_list_mismatched_accumulator_count() {
+    let polynomial_size = PolynomialSize(256);
+    let glwe_size = GlweSize(2);
+    let lwe_dimension = LweDimension(10);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let fbsk = FourierLweBootstrapKey::new(
+        lwe_dimension,
+        glwe_size,
+        polynomial_size,
+        DecompositionBaseLog(10),
+        DecompositionLevelCount(1),
+    );
+    let input = LweCiphertextList::new(
+        0u64,
+        lwe_dimension.to_lwe_size(),
+        LweCiphertextCount(2),
+        ciphertext_modulus,
+    );
+    let mut output = LweCiphertextList::new(
+        0u64,
+        glwe_size
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(polynomial_size)
+            .to_lwe_size(),
+        LweCiphertextCount(2),
+        ciphertext_modulus,
+    );
+    let accumulators = GlweCiphertextList::new(
+        0u64,
+        glwe_size,
+        polynomial_size,
+        GlweCiphertextCount(1),
+        ciphertext_modulus,
+    );
+
+    programmable_bootstrap_lwe_ciphertext_list(&input, &mut output, &accumulators, &fbsk);
+}
+
+#[test]
+fn lwe_encrypt_ks_with_scalar_change_pbs_decrypt() {
+    let params = TEST_PARAMS_4_BITS_NATIVE_U64;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let msg_modulus = 1u64 << params.message_modulus_log.0;
+    let delta = (1u64 << 63) / msg_modulus;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: u64| x.wrapping_mul(2).wrapping_sub(1) % msg_modulus;
+
+    let accumulator = generate_accumulator(
+        params.polynomial_size,
+        params.glwe_dimension.to_glwe_size(),
+        msg_modulus as usize,
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let small_lwe_sk: LweSecretKeyOwned<u64> = allocate_and_generate_new_binary_lwe_secret_key(
+        params.lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let glwe_sk: GlweSecretKeyOwned<u64> = allocate_and_generate_new_binary_glwe_secret_key(
+        params.glwe_dimension,
+        params.polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+
+    // The keyswitch key is generated on u32 from both keys cast to u32
+    let to_u32 = |sk: &LweSecretKeyOwned<u64>| {
+        LweSecretKey::from_container(sk.as_ref().iter().map(|&x| x as u32).collect::<Vec<_>>())
+    };
+    let ksk_big_to_small = allocate_and_generate_new_lwe_keyswitch_key(
+        &to_u32(&big_lwe_sk),
+        &to_u32(&small_lwe_sk),
+        params.ks_base_log,
+        params.ks_level,
+        params.lwe_modular_std_dev,
+        CiphertextModulus::<u32>::new_native(),
+        &mut rsc.encryption_random_generator,
+    );
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &small_lwe_sk,
+        &glwe_sk,
+        params.pbs_base_log,
+        params.pbs_level,
+        params.glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+    let mut fbsk = FourierLweBootstrapKey::new(
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+    drop(bsk);
+
+    const NB_TESTS: usize = 10;
+
+    for msg in 0..msg_modulus {
+        for _ in 0..NB_TESTS {
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &big_lwe_sk,
+                Plaintext(msg * delta),
+                params.lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut ks_ct = LweCiphertext::new(
+                0u32,
+                small_lwe_sk.lwe_dimension().to_lwe_size(),
+                CiphertextModulus::new_native(),
+            );
+            keyswitch_lwe_ciphertext_with_scalar_change(&ksk_big_to_small, &ct, &mut ks_ct);
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                0u64,
+                big_lwe_sk.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+            programmable_bootstrap_lwe_ciphertext_with_scalar_change(
+                &ks_ct,
+                &mut out_pbs_ct,
+                &accumulator,
+                &fbsk,
+            );
+
+            let decrypted = decrypt_lwe_ciphertext(&big_lwe_sk, &out_pbs_ct);
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+    }
+}
+
 // DISCLAIMER: all parameters here are not guaranteed to be secure or yield correct computations
 pub const TEST_PARAMS_4_BITS_NATIVE_U128: TestParams<u128> = TestParams {
     lwe_dimension: LweDimension(742),
@@ -319,3 +926,146 @@ fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_4_bits_native_u128() {
 fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_3_bits_127_u128() {
     lwe_encrypt_pbs_f128_decrypt_custom_mod(TEST_PARAMS_3_BITS_127_U128)
 }
+
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod(params: TestParams<u64>) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = 1u64 << message_modulus_log.0;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: u64| x.wrapping_mul(2).wrapping_sub(1).wrapping_rem(msg_modulus);
+
+    let delta = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+    const NB_TESTS: usize = 10;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    assert!(check_encrypted_content_respects_mod(
+        &accumulator,
+        ciphertext_modulus
+    ));
+
+    while msg != 0 {
+        msg -= 1;
+        // Create the LweSecretKey
+        let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            input_lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+        let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+        let mut bsk = LweBootstrapKey::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            input_lwe_dimension,
+            ciphertext_modulus,
+        );
+
+        par_generate_lwe_bootstrap_key(
+            &input_lwe_secret_key,
+            &output_glwe_secret_key,
+            &mut bsk,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &*bsk,
+            ciphertext_modulus
+        ));
+
+        let mut nbsk = NttLweBootstrapKey::new(
+            input_lwe_dimension,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        );
+
+        convert_standard_lwe_bootstrap_key_to_ntt64(&bsk, &mut nbsk);
+
+        drop(bsk);
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &lwe_ciphertext_in,
+                ciphertext_modulus
+            ));
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                0u64,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_ntt64_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &nbsk,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            // The NTT computes the PBS exactly, running it again must give the same ciphertext
+            let mut out_pbs_ct_2 = out_pbs_ct.clone();
+            programmable_bootstrap_ntt64_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct_2,
+                &accumulator,
+                &nbsk,
+            );
+            assert_eq!(out_pbs_ct, out_pbs_ct_2);
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+    }
+}
+
+#[test]
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod_test_params_3_bits_solinas_u64() {
+    lwe_encrypt_pbs_ntt64_decrypt_custom_mod(TEST_PARAMS_3_BITS_SOLINAS_U64)
+}
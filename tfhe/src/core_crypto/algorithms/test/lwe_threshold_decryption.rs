@@ -0,0 +1,247 @@
+use super::*;
+
+// DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+// computations
+const LWE_DIMENSION: LweDimension = LweDimension(742);
+const LWE_MODULAR_STD_DEV: StandardDev = StandardDev(0.000007069849454709433);
+const FLOODING_STD_DEV: StandardDev = StandardDev(0.000007069849454709433 * 64.0);
+// Shamir Lagrange coefficients scaled by n! amplify the flooding noise of each party
+const SHAMIR_FLOODING_STD_DEV: StandardDev = StandardDev(0.000000029802322387695312);
+const MESSAGE_MODULUS: u128 = 16;
+
+fn modulus_as_u128(ciphertext_modulus: CiphertextModulus<u64>) -> u128 {
+    if ciphertext_modulus.is_native_modulus() {
+        1u128 << 64
+    } else {
+        ciphertext_modulus.get_custom_modulus()
+    }
+}
+
+fn encode(msg: u64, ciphertext_modulus: CiphertextModulus<u64>) -> Plaintext<u64> {
+    let delta = modulus_as_u128(ciphertext_modulus) / MESSAGE_MODULUS;
+    Plaintext((msg as u128 * delta) as u64)
+}
+
+fn decode(plaintext: Plaintext<u64>, ciphertext_modulus: CiphertextModulus<u64>) -> u64 {
+    let modulus = modulus_as_u128(ciphertext_modulus);
+    (((plaintext.0 as u128 * MESSAGE_MODULUS + modulus / 2) / modulus) % MESSAGE_MODULUS) as u64
+}
+
+fn threshold_decrypt_all_subsets(
+    lwe_secret_key: &LweSecretKeyOwned<u64>,
+    sharing_scheme: SecretSharingScheme,
+    ciphertext_modulus: CiphertextModulus<u64>,
+    flooding_std_dev: StandardDev,
+    rsc: &mut TestResources,
+) {
+    let shares = allocate_and_share_lwe_secret_key(
+        lwe_secret_key,
+        sharing_scheme,
+        ciphertext_modulus,
+        &mut rsc.secret_random_generator,
+    );
+    assert_eq!(shares.len(), sharing_scheme.party_count().0);
+
+    for msg in 0..MESSAGE_MODULUS as u64 {
+        let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+            lwe_secret_key,
+            encode(msg, ciphertext_modulus),
+            LWE_MODULAR_STD_DEV,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let partial_decryptions: Vec<_> = shares
+            .iter()
+            .map(|share| {
+                partial_decrypt_lwe_ciphertext(
+                    share,
+                    &lwe,
+                    flooding_std_dev,
+                    &mut rsc.encryption_random_generator,
+                )
+            })
+            .collect();
+
+        let party_count = sharing_scheme.party_count().0;
+        let threshold = sharing_scheme.threshold();
+
+        // Try every window of threshold consecutive parties (wrapping around)
+        for first_party in 0..party_count {
+            let subset: Vec<_> = (0..threshold)
+                .map(|offset| partial_decryptions[(first_party + offset) % party_count])
+                .collect();
+
+            let plaintext = combine_lwe_partial_decryptions(&lwe, &subset);
+            assert_eq!(decode(plaintext, ciphertext_modulus), msg);
+        }
+    }
+}
+
+#[test]
+fn lwe_threshold_decryption_additive_native_mod() {
+    let mut rsc = TestResources::new();
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+
+    for party_count in [1, 2, 5] {
+        threshold_decrypt_all_subsets(
+            &lwe_secret_key,
+            SecretSharingScheme::Additive {
+                party_count: ThresholdPartyCount(party_count),
+            },
+            CiphertextModulus::new_native(),
+            FLOODING_STD_DEV,
+            &mut rsc,
+        );
+    }
+}
+
+#[test]
+fn lwe_threshold_decryption_additive_power_of_2_mod() {
+    let mut rsc = TestResources::new();
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+
+    threshold_decrypt_all_subsets(
+        &lwe_secret_key,
+        SecretSharingScheme::Additive {
+            party_count: ThresholdPartyCount(3),
+        },
+        CiphertextModulus::try_new_power_of_2(63).unwrap(),
+        FLOODING_STD_DEV,
+        &mut rsc,
+    );
+}
+
+#[test]
+fn lwe_threshold_decryption_custom_mod() {
+    let mut rsc = TestResources::new();
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+
+    // Solinas prime
+    let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+
+    threshold_decrypt_all_subsets(
+        &lwe_secret_key,
+        SecretSharingScheme::Additive {
+            party_count: ThresholdPartyCount(3),
+        },
+        ciphertext_modulus,
+        FLOODING_STD_DEV,
+        &mut rsc,
+    );
+
+    for (threshold, party_count) in [(1, 1), (1, 3), (2, 3), (3, 5), (5, 5)] {
+        threshold_decrypt_all_subsets(
+            &lwe_secret_key,
+            SecretSharingScheme::Shamir {
+                threshold,
+                party_count: ThresholdPartyCount(party_count),
+            },
+            ciphertext_modulus,
+            SHAMIR_FLOODING_STD_DEV,
+            &mut rsc,
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "partial decryptions, 3 are required")]
+fn lwe_threshold_decryption_not_enough_parties() {
+    let mut rsc = TestResources::new();
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+    let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+
+    let shares = allocate_and_share_lwe_secret_key(
+        &lwe_secret_key,
+        SecretSharingScheme::Shamir {
+            threshold: 3,
+            party_count: ThresholdPartyCount(5),
+        },
+        ciphertext_modulus,
+        &mut rsc.secret_random_generator,
+    );
+
+    let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+        &lwe_secret_key,
+        encode(1, ciphertext_modulus),
+        LWE_MODULAR_STD_DEV,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let partial_decryptions: Vec<_> = shares[..2]
+        .iter()
+        .map(|share| {
+            partial_decrypt_lwe_ciphertext(
+                share,
+                &lwe,
+                FLOODING_STD_DEV,
+                &mut rsc.encryption_random_generator,
+            )
+        })
+        .collect();
+
+    combine_lwe_partial_decryptions(&lwe, &partial_decryptions);
+}
+
+#[test]
+fn glwe_threshold_decryption_of_extracted_lwe() {
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(1024);
+    let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+    let sharing_scheme = SecretSharingScheme::Additive {
+        party_count: ThresholdPartyCount(4),
+    };
+
+    let mut rsc = TestResources::new();
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let shares = allocate_and_share_glwe_secret_key(
+        &glwe_secret_key,
+        sharing_scheme,
+        ciphertext_modulus,
+        &mut rsc.secret_random_generator,
+    );
+
+    for msg in 0..MESSAGE_MODULUS as u64 {
+        let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+            &glwe_secret_key.as_lwe_secret_key(),
+            encode(msg, ciphertext_modulus),
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let partial_decryptions: Vec<_> = shares
+            .iter()
+            .map(|share| {
+                partial_decrypt_lwe_ciphertext(
+                    &share.as_lwe_secret_key_share(),
+                    &lwe,
+                    FLOODING_STD_DEV,
+                    &mut rsc.encryption_random_generator,
+                )
+            })
+            .collect();
+
+        let plaintext = combine_lwe_partial_decryptions(&lwe, &partial_decryptions);
+        assert_eq!(decode(plaintext, ciphertext_modulus), msg);
+    }
+}
@@ -0,0 +1,282 @@
+use super::*;
+
+// DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+// computations
+const POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(512);
+const GLWE_DIMENSION: GlweDimension = GlweDimension(2);
+const LWE_DIMENSION: LweDimension = LweDimension(10);
+const BSK_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(15);
+const BSK_LEVEL: DecompositionLevelCount = DecompositionLevelCount(2);
+const PFPKSK_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(15);
+const PFPKSK_LEVEL: DecompositionLevelCount = DecompositionLevelCount(2);
+const CBS_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(10);
+const CBS_LEVEL: DecompositionLevelCount = DecompositionLevelCount(1);
+// The bits given to the circuit bootstrapping are encoded in the MSB
+const BIT_DELTA_LOG: DeltaLog = DeltaLog(63);
+
+struct CbsKeys {
+    lwe_sk: LweSecretKeyOwned<u64>,
+    glwe_sk: GlweSecretKeyOwned<u64>,
+    fourier_bsk: FourierLweBootstrapKeyOwned,
+    pfpksk_list: LwePrivateFunctionalPackingKeyswitchKeyListOwned<u64>,
+}
+
+fn generate_cbs_keys(rsc: &mut TestResources) -> CbsKeys {
+    let std = LogStandardDev::from_log_standard_dev(-60.);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        GLWE_DIMENSION,
+        POLYNOMIAL_SIZE,
+        &mut rsc.secret_random_generator,
+    );
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+
+    let std_bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        BSK_BASE_LOG,
+        BSK_LEVEL,
+        std,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        std_bsk.input_lwe_dimension(),
+        std_bsk.glwe_size(),
+        std_bsk.polynomial_size(),
+        std_bsk.decomposition_base_log(),
+        std_bsk.decomposition_level_count(),
+    );
+    convert_standard_lwe_bootstrap_key_to_fourier(&std_bsk, &mut fourier_bsk);
+
+    let pfpksk_list = par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list(
+        &glwe_sk.as_lwe_secret_key(),
+        &glwe_sk,
+        PFPKSK_BASE_LOG,
+        PFPKSK_LEVEL,
+        std,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    CbsKeys {
+        lwe_sk,
+        glwe_sk,
+        fourier_bsk,
+        pfpksk_list,
+    }
+}
+
+fn encrypt_bit(keys: &CbsKeys, bit: u64, rsc: &mut TestResources) -> LweCiphertextOwned<u64> {
+    allocate_and_encrypt_new_lwe_ciphertext(
+        &keys.lwe_sk,
+        Plaintext(bit << BIT_DELTA_LOG.0),
+        LogStandardDev::from_log_standard_dev(-60.),
+        CiphertextModulus::new_native(),
+        &mut rsc.encryption_random_generator,
+    )
+}
+
+#[test]
+fn lwe_circuit_bootstrap_to_ggsw() {
+    let mut rsc = TestResources::new();
+    let keys = generate_cbs_keys(&mut rsc);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    for bit in [0u64, 1, 1, 0] {
+        let lwe_in = encrypt_bit(&keys, bit, &mut rsc);
+
+        let mut ggsw = GgswCiphertext::new(
+            0u64,
+            GLWE_DIMENSION.to_glwe_size(),
+            POLYNOMIAL_SIZE,
+            CBS_BASE_LOG,
+            CBS_LEVEL,
+            ciphertext_modulus,
+        );
+
+        circuit_bootstrap_lwe_ciphertext_to_ggsw(
+            &lwe_in,
+            &mut ggsw,
+            &keys.fourier_bsk,
+            &keys.pfpksk_list,
+            BIT_DELTA_LOG,
+        );
+
+        let decrypted = decrypt_constant_ggsw_ciphertext(&keys.glwe_sk, &ggsw);
+        assert_eq!(decrypted.0, bit);
+    }
+}
+
+fn test_vertical_packing(index_bits: usize, lut_polynomial_count: PolynomialCount) {
+    let mut rsc = TestResources::new();
+    let keys = generate_cbs_keys(&mut rsc);
+    let ciphertext_modulus = CiphertextModulus::new_native();
+    let glwe_size = GLWE_DIMENSION.to_glwe_size();
+
+    let fft = Fft::new(POLYNOMIAL_SIZE);
+    let fft = fft.as_view();
+    let mut buffers = ComputationBuffers::new();
+    let cbs_buffer_size =
+        circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized_requirement::<u64>(
+            LWE_DIMENSION.to_lwe_size(),
+            keys.fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            glwe_size,
+            POLYNOMIAL_SIZE,
+            CBS_LEVEL,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required();
+    let vp_buffer_size = vertical_packing_lwe_ciphertext_mem_optimized_requirement::<u64>(
+        glwe_size,
+        POLYNOMIAL_SIZE,
+        lut_polynomial_count,
+        index_bits,
+        fft,
+    )
+    .unwrap()
+    .unaligned_bytes_required();
+    buffers.resize(cbs_buffer_size.max(vp_buffer_size));
+
+    // Bits of the index left for the blind rotation, selecting a coefficient in a polynomial
+    let blind_rotation_bits = index_bits - lut_polynomial_count.0.ilog2() as usize;
+    let index_count = 1 << index_bits;
+    let delta_log_lut = DeltaLog(64 - index_bits - 1);
+
+    // Reverse the index, so that each entry of the table is different
+    let lut_value = |index: usize| (index_count - 1 - index) as u64;
+
+    let mut lut = PolynomialList::new(0u64, POLYNOMIAL_SIZE, lut_polynomial_count);
+    for (poly_idx, mut poly) in lut.iter_mut().enumerate() {
+        for coeff_idx in 0..1 << blind_rotation_bits {
+            let index = (poly_idx << blind_rotation_bits) + coeff_idx;
+            poly.as_mut()[coeff_idx] = lut_value(index) << delta_log_lut.0;
+        }
+    }
+
+    let decomposer = SignedDecomposer::new(
+        DecompositionBaseLog(index_bits + 1),
+        DecompositionLevelCount(1),
+    );
+
+    let output_lwe_sk = keys.glwe_sk.as_lwe_secret_key();
+
+    for index in 0..index_count {
+        let mut ggsw_list = FourierGgswCiphertextList::new(
+            vec![
+                Default::default();
+                index_bits
+                    * POLYNOMIAL_SIZE.to_fourier_polynomial_size().0
+                    * glwe_size.0
+                    * glwe_size.0
+                    * CBS_LEVEL.0
+            ],
+            index_bits,
+            glwe_size,
+            POLYNOMIAL_SIZE,
+            CBS_BASE_LOG,
+            CBS_LEVEL,
+        );
+
+        for (bit_idx, mut fourier_ggsw) in ggsw_list.as_mut_view().into_ggsw_iter().enumerate() {
+            let bit = ((index >> (index_bits - 1 - bit_idx)) & 1) as u64;
+            let lwe_in = encrypt_bit(&keys, bit, &mut rsc);
+
+            circuit_bootstrap_lwe_ciphertext_to_fourier_ggsw_mem_optimized(
+                &lwe_in,
+                &mut fourier_ggsw,
+                &keys.fourier_bsk,
+                &keys.pfpksk_list,
+                BIT_DELTA_LOG,
+                ciphertext_modulus,
+                fft,
+                buffers.stack(),
+            );
+        }
+
+        let mut lwe_out = LweCiphertext::new(
+            0u64,
+            output_lwe_sk.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        vertical_packing_lwe_ciphertext_mem_optimized(
+            &lut,
+            &ggsw_list,
+            &mut lwe_out,
+            fft,
+            buffers.stack(),
+        );
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_sk, &lwe_out);
+        let decoded = decomposer.closest_representable(decrypted.0) >> delta_log_lut.0;
+
+        assert_eq!(decoded, lut_value(index));
+    }
+}
+
+#[test]
+fn lwe_vertical_packing_blind_rotation_only() {
+    test_vertical_packing(3, PolynomialCount(1));
+}
+
+#[test]
+fn lwe_vertical_packing_with_cmux_tree() {
+    test_vertical_packing(3, PolynomialCount(4));
+}
+
+#[test]
+#[should_panic(expected = "Not enough GGSW ciphertexts")]
+fn lwe_vertical_packing_lut_too_big() {
+    let glwe_size = GLWE_DIMENSION.to_glwe_size();
+    let ggsw_list = FourierGgswCiphertextList::new(
+        vec![
+            Default::default();
+            POLYNOMIAL_SIZE.to_fourier_polynomial_size().0
+                * glwe_size.0
+                * glwe_size.0
+                * CBS_LEVEL.0
+        ],
+        1,
+        glwe_size,
+        POLYNOMIAL_SIZE,
+        CBS_BASE_LOG,
+        CBS_LEVEL,
+    );
+    let lut = PolynomialList::new(0u64, POLYNOMIAL_SIZE, PolynomialCount(4));
+    let mut lwe_out = LweCiphertext::new(
+        0u64,
+        GLWE_DIMENSION
+            .to_equivalent_lwe_dimension(POLYNOMIAL_SIZE)
+            .to_lwe_size(),
+        CiphertextModulus::new_native(),
+    );
+
+    let fft = Fft::new(POLYNOMIAL_SIZE);
+    let fft = fft.as_view();
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        vertical_packing_lwe_ciphertext_mem_optimized_requirement::<u64>(
+            glwe_size,
+            POLYNOMIAL_SIZE,
+            PolynomialCount(4),
+            1,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    vertical_packing_lwe_ciphertext_mem_optimized(
+        &lut,
+        &ggsw_list,
+        &mut lwe_out,
+        fft,
+        buffers.stack(),
+    );
+}
@@ -0,0 +1,269 @@
+use super::*;
+use crate::core_crypto::algorithms::lwe_zk_proof::{
+    encrypt_lwe_compact_ciphertext_list_with_witness, prove_lwe_compact_ciphertext_list_encryption,
+};
+use crate::zk::{CompactPkeCrs, CompactPkeProof, CompactPkeProofResponse};
+
+// DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+// computations
+const LWE_DIMENSION: LweDimension = LweDimension(512);
+const MASK_MODULAR_STD_DEV: StandardDev = StandardDev(0.00000000000000029403601535432533);
+const BODY_MODULAR_STD_DEV: StandardDev = StandardDev(0.000007069849454709433);
+const MESSAGE_MODULUS: u64 = 16;
+// Spans two bins of the compact list
+const CIPHERTEXT_COUNT: LweCiphertextCount = LweCiphertextCount(600);
+
+struct ProvenListSetup {
+    lwe_secret_key: LweSecretKeyOwned<u64>,
+    compact_public_key: LweCompactPublicKeyOwned<u64>,
+    crs: CompactPkeCrs,
+    messages: Vec<u64>,
+    list: LweCompactCiphertextListOwned<u64>,
+    proof: CompactPkeProof,
+}
+
+fn encrypt_and_prove(rsc: &mut TestResources, delta: u64) -> ProvenListSetup {
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+    let compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+        &lwe_secret_key,
+        MASK_MODULAR_STD_DEV,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+    let crs = CompactPkeCrs::new(
+        LWE_DIMENSION,
+        LweCiphertextCount(2 * LWE_DIMENSION.0),
+        MASK_MODULAR_STD_DEV,
+        BODY_MODULAR_STD_DEV,
+        MESSAGE_MODULUS,
+        delta,
+        rsc.seeder.as_mut(),
+    );
+
+    let messages: Vec<u64> = (0..CIPHERTEXT_COUNT.0 as u64)
+        .map(|index| index % MESSAGE_MODULUS)
+        .collect();
+    let plaintext_list =
+        PlaintextList::from_container(messages.iter().map(|&msg| msg * delta).collect::<Vec<_>>());
+
+    let mut list = LweCompactCiphertextList::new(
+        0u64,
+        LWE_DIMENSION.to_lwe_size(),
+        CIPHERTEXT_COUNT,
+        ciphertext_modulus,
+    );
+    let proof = encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+        &compact_public_key,
+        &mut list,
+        &plaintext_list,
+        MASK_MODULAR_STD_DEV,
+        BODY_MODULAR_STD_DEV,
+        &crs,
+        &mut rsc.secret_random_generator,
+        &mut rsc.encryption_random_generator,
+    );
+
+    ProvenListSetup {
+        lwe_secret_key,
+        compact_public_key,
+        crs,
+        messages,
+        list,
+        proof,
+    }
+}
+
+#[test]
+fn lwe_compact_list_zk_proof_valid() {
+    let mut rsc = TestResources::new();
+    let delta = get_encoding_with_padding(CiphertextModulus::<u64>::new_native()) / MESSAGE_MODULUS;
+
+    let setup = encrypt_and_prove(&mut rsc, delta);
+
+    assert!(verify_lwe_compact_ciphertext_list(
+        &setup.list,
+        &setup.compact_public_key,
+        &setup.proof,
+        &setup.crs,
+    )
+    .is_valid());
+
+    let expanded = setup.list.expand_into_lwe_ciphertext_list();
+    for (ct, &msg) in expanded.iter().zip(setup.messages.iter()) {
+        let decrypted = decrypt_lwe_ciphertext(&setup.lwe_secret_key, &ct);
+        assert_eq!(round_decode(decrypted.0, delta) % MESSAGE_MODULUS, msg);
+    }
+}
+
+#[test]
+fn lwe_compact_list_zk_proof_tampered() {
+    let mut rsc = TestResources::new();
+    let delta = get_encoding_with_padding(CiphertextModulus::<u64>::new_native()) / MESSAGE_MODULUS;
+
+    let setup = encrypt_and_prove(&mut rsc, delta);
+    let verify = |list: &LweCompactCiphertextListOwned<u64>,
+                  proof: &CompactPkeProof,
+                  crs: &CompactPkeCrs| {
+        verify_lwe_compact_ciphertext_list(list, &setup.compact_public_key, proof, crs)
+    };
+
+    // Modified body
+    let mut list = setup.list.clone();
+    *list.as_mut().last_mut().unwrap() = list.as_ref().last().unwrap().wrapping_add(delta);
+    assert!(verify(&list, &setup.proof, &setup.crs).is_invalid());
+
+    // Modified mask
+    let mut list = setup.list.clone();
+    list.as_mut()[0] = list.as_ref()[0].wrapping_add(1);
+    assert!(verify(&list, &setup.proof, &setup.crs).is_invalid());
+
+    // Modified response
+    let mut proof = setup.proof.clone();
+    let response = proof
+        .responses
+        .iter_mut()
+        .find_map(|response| match response {
+            CompactPkeProofResponse::MaskedWitness { masked_witness, .. } => Some(masked_witness),
+            CompactPkeProofResponse::PermutedWitness { .. }
+            | CompactPkeProofResponse::Seeds { .. } => None,
+        })
+        .unwrap();
+    response[0] += 1;
+    assert!(verify(&setup.list, &proof, &setup.crs).is_invalid());
+
+    // Missing response
+    let mut proof = setup.proof.clone();
+    proof.responses.pop();
+    assert!(verify(&setup.list, &proof, &setup.crs).is_invalid());
+
+    // Other CRS
+    let other_crs = CompactPkeCrs::new(
+        LWE_DIMENSION,
+        LweCiphertextCount(2 * LWE_DIMENSION.0),
+        MASK_MODULAR_STD_DEV,
+        BODY_MODULAR_STD_DEV,
+        MESSAGE_MODULUS,
+        delta,
+        rsc.seeder.as_mut(),
+    );
+    assert!(verify(&setup.list, &setup.proof, &other_crs).is_invalid());
+
+    // More ciphertexts than allowed by the CRS
+    let small_crs = CompactPkeCrs::new(
+        LWE_DIMENSION,
+        LweCiphertextCount(LWE_DIMENSION.0),
+        MASK_MODULAR_STD_DEV,
+        BODY_MODULAR_STD_DEV,
+        MESSAGE_MODULUS,
+        delta,
+        rsc.seeder.as_mut(),
+    );
+    assert!(verify(&setup.list, &setup.proof, &small_crs).is_invalid());
+}
+
+#[test]
+fn lwe_compact_list_zk_proof_out_of_range_message() {
+    let mut rsc = TestResources::new();
+    let delta = get_encoding_with_padding(CiphertextModulus::<u64>::new_native()) / MESSAGE_MODULUS;
+
+    let setup = encrypt_and_prove(&mut rsc, delta);
+    let plaintext_list = PlaintextList::from_container(
+        setup
+            .messages
+            .iter()
+            .map(|&msg| msg * delta)
+            .collect::<Vec<_>>(),
+    );
+
+    // A malicious client encrypts the last message plus the message modulus, which sets the
+    // padding bit, and proves it with the best witness it can build
+    let mut list = setup.list.clone();
+    let witness = encrypt_lwe_compact_ciphertext_list_with_witness(
+        &setup.compact_public_key,
+        &mut list,
+        &plaintext_list,
+        MASK_MODULAR_STD_DEV,
+        BODY_MODULAR_STD_DEV,
+        &setup.crs,
+        &mut rsc.secret_random_generator,
+        &mut rsc.encryption_random_generator,
+    );
+    let mut out_of_range_list = list.clone();
+    let last_body = out_of_range_list.as_mut().last_mut().unwrap();
+    *last_body = last_body.wrapping_add(MESSAGE_MODULUS * delta);
+
+    let verify = |list: &LweCompactCiphertextListOwned<u64>, proof: &CompactPkeProof| {
+        verify_lwe_compact_ciphertext_list(list, &setup.compact_public_key, proof, &setup.crs)
+    };
+
+    let proof = prove_lwe_compact_ciphertext_list_encryption(
+        &setup.compact_public_key,
+        &out_of_range_list,
+        &witness,
+        &setup.crs,
+        &mut rsc.secret_random_generator,
+    );
+    assert!(verify(&out_of_range_list, &proof).is_invalid());
+
+    // The same witness gives a valid proof for the in range list
+    let proof = prove_lwe_compact_ciphertext_list_encryption(
+        &setup.compact_public_key,
+        &list,
+        &witness,
+        &setup.crs,
+        &mut rsc.secret_random_generator,
+    );
+    assert!(verify(&list, &proof).is_valid());
+}
+
+#[test]
+#[should_panic(expected = "is not the encoding of a message in the range of the CRS")]
+fn lwe_compact_list_zk_proof_out_of_range_plaintext() {
+    let mut rsc = TestResources::new();
+    let ciphertext_modulus = CiphertextModulus::new_native();
+    let delta = get_encoding_with_padding(ciphertext_modulus) / MESSAGE_MODULUS;
+
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        LWE_DIMENSION,
+        &mut rsc.secret_random_generator,
+    );
+    let compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+        &lwe_secret_key,
+        MASK_MODULAR_STD_DEV,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+    let crs = CompactPkeCrs::new(
+        LWE_DIMENSION,
+        LweCiphertextCount(1),
+        MASK_MODULAR_STD_DEV,
+        BODY_MODULAR_STD_DEV,
+        MESSAGE_MODULUS,
+        delta,
+        rsc.seeder.as_mut(),
+    );
+
+    let plaintext_list = PlaintextList::new(MESSAGE_MODULUS * delta, PlaintextCount(1));
+    let mut list = LweCompactCiphertextList::new(
+        0u64,
+        LWE_DIMENSION.to_lwe_size(),
+        LweCiphertextCount(1),
+        ciphertext_modulus,
+    );
+
+    let _ = encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+        &compact_public_key,
+        &mut list,
+        &plaintext_list,
+        MASK_MODULAR_STD_DEV,
+        BODY_MODULAR_STD_DEV,
+        &crs,
+        &mut rsc.secret_random_generator,
+        &mut rsc.encryption_random_generator,
+    );
+}
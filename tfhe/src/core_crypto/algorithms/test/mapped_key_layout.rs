@@ -0,0 +1,214 @@
+use super::*;
+
+// Memory maps are page aligned, emulate that by copying the bytes to a u64 buffer
+fn aligned_copy(bytes: &[u8]) -> Vec<u64> {
+    assert_eq!(bytes.len() % std::mem::size_of::<u64>(), 0);
+    bytes
+        .chunks_exact(std::mem::size_of::<u64>())
+        .map(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+fn as_bytes(words: &[u64]) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(words.as_ptr().cast(), std::mem::size_of_val(words)) }
+}
+
+#[test]
+fn test_mapped_fourier_lwe_bootstrap_key_layout() {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let lwe_dimension = LweDimension(10);
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(256);
+    let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    let decomp_base_log = DecompositionBaseLog(4);
+    let decomp_level_count = DecompositionLevelCount(3);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut rsc = TestResources::new();
+
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fourier_bsk);
+
+    let mut bytes = vec![];
+    write_fourier_lwe_bootstrap_key_mapped_layout(&fourier_bsk, &mut bytes).unwrap();
+    assert_eq!(bytes.len() % MAPPED_KEY_ALIGNMENT, 0);
+
+    let mut aligned = aligned_copy(&bytes);
+
+    let mapped_bsk = view_fourier_lwe_bootstrap_key_from_mapped_layout(as_bytes(&aligned)).unwrap();
+    assert_eq!(mapped_bsk, fourier_bsk.as_view());
+    // No copy is made, the key data lives in the mapped bytes
+    assert_eq!(
+        mapped_bsk.data().as_ptr_range().end.cast::<u8>(),
+        as_bytes(&aligned).as_ptr_range().end
+    );
+
+    assert_eq!(
+        view_lwe_keyswitch_key_from_mapped_layout::<u64>(as_bytes(&aligned)),
+        Err(MappedKeyLayoutError::UnexpectedKind {
+            expected: MappedKeyKind::LweKeyswitchKey,
+            found: Some(MappedKeyKind::FourierLweBootstrapKey),
+        })
+    );
+    assert_eq!(
+        view_fourier_lwe_bootstrap_key_from_mapped_layout(
+            &as_bytes(&aligned)[..as_bytes(&aligned).len() - MAPPED_KEY_ALIGNMENT]
+        ),
+        Err(MappedKeyLayoutError::Truncated)
+    );
+    assert_eq!(
+        view_fourier_lwe_bootstrap_key_from_mapped_layout(&as_bytes(&aligned)[1..]),
+        Err(MappedKeyLayoutError::InvalidMagic)
+    );
+
+    // Corrupt the probe, as if the key had been written by a process using another FFT order
+    let probe_start = MAPPED_KEY_HEADER_SIZE / std::mem::size_of::<u64>();
+    aligned.swap(probe_start, probe_start + 2);
+    assert_eq!(
+        view_fourier_lwe_bootstrap_key_from_mapped_layout(as_bytes(&aligned)),
+        Err(MappedKeyLayoutError::FourierLayoutMismatch)
+    );
+}
+
+#[test]
+fn test_mapped_fourier_lwe_multi_bit_bootstrap_key_layout() {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let lwe_dimension = LweDimension(12);
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(256);
+    let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    let decomp_base_log = DecompositionBaseLog(4);
+    let decomp_level_count = DecompositionLevelCount(3);
+    let grouping_factor = LweBskGroupingFactor(3);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut rsc = TestResources::new();
+
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let bsk = par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        grouping_factor,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fourier_bsk = FourierLweMultiBitBootstrapKey::new(
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+        bsk.grouping_factor(),
+    );
+    par_convert_standard_lwe_multi_bit_bootstrap_key_to_fourier(&bsk, &mut fourier_bsk);
+
+    let mut bytes = vec![];
+    write_fourier_lwe_multi_bit_bootstrap_key_mapped_layout(&fourier_bsk, &mut bytes).unwrap();
+
+    let aligned = aligned_copy(&bytes);
+
+    let mapped_bsk =
+        view_fourier_lwe_multi_bit_bootstrap_key_from_mapped_layout(as_bytes(&aligned)).unwrap();
+    assert_eq!(mapped_bsk, fourier_bsk.as_view());
+
+    assert!(matches!(
+        view_fourier_lwe_bootstrap_key_from_mapped_layout(as_bytes(&aligned)),
+        Err(MappedKeyLayoutError::UnexpectedKind { .. })
+    ));
+}
+
+#[test]
+fn test_mapped_lwe_keyswitch_key_layout() {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let input_lwe_dimension = LweDimension(742);
+    let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+    let output_lwe_dimension = LweDimension(2048);
+    let decomp_base_log = DecompositionBaseLog(3);
+    let decomp_level_count = DecompositionLevelCount(5);
+
+    let mut rsc = TestResources::new();
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        output_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+
+    for ciphertext_modulus in [
+        CiphertextModulus::<u64>::new_native(),
+        CiphertextModulus::<u64>::try_new_power_of_2(63).unwrap(),
+    ] {
+        let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+            &input_lwe_secret_key,
+            &output_lwe_secret_key,
+            decomp_base_log,
+            decomp_level_count,
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut bytes = vec![];
+        write_lwe_keyswitch_key_mapped_layout(&ksk, &mut bytes).unwrap();
+
+        let aligned = aligned_copy(&bytes);
+
+        let mapped_ksk =
+            view_lwe_keyswitch_key_from_mapped_layout::<u64>(as_bytes(&aligned)).unwrap();
+        assert_eq!(mapped_ksk, ksk.as_view());
+        assert_eq!(mapped_ksk.ciphertext_modulus(), ciphertext_modulus);
+
+        assert_eq!(
+            view_lwe_keyswitch_key_from_mapped_layout::<u32>(as_bytes(&aligned)),
+            Err(MappedKeyLayoutError::ElementSizeMismatch {
+                expected: 4,
+                found: 8,
+            })
+        );
+    }
+}
@@ -3,22 +3,32 @@ use crate::core_crypto::prelude::*;
 use paste::paste;
 
 mod ggsw_encryption;
+mod glwe_automorphism;
 mod glwe_encryption;
 mod glwe_linear_algebra;
 mod glwe_sample_extraction;
+mod glwe_tensor_product;
 mod lwe_bootstrap_key_generation;
 mod lwe_compact_public_key_generation;
 mod lwe_encryption;
 mod lwe_keyswitch;
 mod lwe_keyswitch_key_generation;
 mod lwe_linear_algebra;
+mod lwe_modulus_switch;
 mod lwe_multi_bit_bootstrap_key_generation;
 mod lwe_multi_bit_programmable_bootstrapping;
 mod lwe_packing_keyswitch;
 mod lwe_packing_keyswitch_key_generation;
 mod lwe_private_functional_packing_keyswitch;
 mod lwe_programmable_bootstrapping;
+mod lwe_threshold_decryption;
+mod lwe_wopbs;
+#[cfg(feature = "zk-pok-experimental")]
+mod lwe_zk_proof;
+mod mapped_key_layout;
+mod multi_party_key_generation;
 mod noise_distribution;
+mod streaming_key_generation;
 
 pub struct TestResources {
     pub seeder: Box<dyn Seeder>,
@@ -207,8 +217,15 @@ where
     let half_box_size = box_size / 2;
 
     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-    for a_i in accumulator_scalar[0..half_box_size].iter_mut() {
-        *a_i = (*a_i).wrapping_neg();
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        for a_i in accumulator_scalar[0..half_box_size].iter_mut() {
+            *a_i = (*a_i).wrapping_neg();
+        }
+    } else {
+        let custom_modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+        for a_i in accumulator_scalar[0..half_box_size].iter_mut() {
+            *a_i = (*a_i).wrapping_neg_custom_mod(custom_modulus);
+        }
     }
 
     // Rotate the accumulator
@@ -0,0 +1,263 @@
+use super::*;
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+use crate::core_crypto::commons::math::random::CompressionSeed;
+
+// DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+// computations
+const PARTY_COUNT: ThresholdPartyCount = ThresholdPartyCount(2);
+// The joint LWE secret key is the concatenation of the shares
+const LWE_SHARE_DIMENSION: LweDimension = LweDimension(371);
+const GLWE_DIMENSION: GlweDimension = GlweDimension(1);
+const POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(1024);
+const LWE_MODULAR_STD_DEV: StandardDev = StandardDev(0.000007069849454709433);
+const GLWE_MODULAR_STD_DEV: StandardDev = StandardDev(0.00000000000000029403601535432533);
+// The bootstrap key is encrypted with the aggregated GLWE encryption of zero multiplied by binary
+// polynomials, its noise is amplified accordingly
+const BSK_MODULAR_STD_DEV: StandardDev = StandardDev(0.0000000000000000008673617379884035);
+const PBS_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(23);
+const PBS_LEVEL: DecompositionLevelCount = DecompositionLevelCount(1);
+const KS_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(3);
+const KS_LEVEL: DecompositionLevelCount = DecompositionLevelCount(5);
+const MESSAGE_MODULUS: u64 = 16;
+const NB_TESTS: usize = 10;
+
+struct PartyKeys {
+    glwe_secret_key_share: GlweSecretKeyOwned<u64>,
+    lwe_secret_key_share: LweSecretKeyOwned<u64>,
+}
+
+fn generate_party_keys(rsc: &mut TestResources) -> Vec<PartyKeys> {
+    (0..PARTY_COUNT.0)
+        .map(|_| PartyKeys {
+            glwe_secret_key_share: allocate_and_generate_new_binary_glwe_secret_key(
+                GLWE_DIMENSION,
+                POLYNOMIAL_SIZE,
+                &mut rsc.secret_random_generator,
+            ),
+            lwe_secret_key_share: allocate_and_generate_new_binary_lwe_secret_key(
+                LWE_SHARE_DIMENSION,
+                &mut rsc.secret_random_generator,
+            ),
+        })
+        .collect()
+}
+
+fn joint_glwe_secret_key(parties: &[PartyKeys]) -> GlweSecretKeyOwned<u64> {
+    let mut joint_key = GlweSecretKey::new_empty_key(0u64, GLWE_DIMENSION, POLYNOMIAL_SIZE);
+    for party in parties {
+        slice_wrapping_add_assign(joint_key.as_mut(), party.glwe_secret_key_share.as_ref());
+    }
+    joint_key
+}
+
+fn joint_lwe_secret_key(parties: &[PartyKeys]) -> LweSecretKeyOwned<u64> {
+    LweSecretKey::from_container(
+        parties
+            .iter()
+            .flat_map(|party| party.lwe_secret_key_share.as_ref().iter().copied())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn decrypt_and_decode<C: Container<Element = u64>>(
+    lwe_secret_key: &LweSecretKey<C>,
+    ct: &LweCiphertextOwned<u64>,
+    delta: u64,
+) -> u64 {
+    let decrypted = decrypt_lwe_ciphertext(lwe_secret_key, ct);
+    round_decode(decrypted.0, delta) % MESSAGE_MODULUS
+}
+
+#[test]
+fn multi_party_compact_public_key_encrypt_decrypt() {
+    let mut rsc = TestResources::new();
+    let ciphertext_modulus = CiphertextModulus::new_native();
+    let delta = get_encoding_with_padding(ciphertext_modulus) / MESSAGE_MODULUS;
+
+    let parties = generate_party_keys(&mut rsc);
+    let crs_seed: CompressionSeed = rsc.seeder.seed().into();
+    let large_lwe_dimension = GLWE_DIMENSION.to_equivalent_lwe_dimension(POLYNOMIAL_SIZE);
+
+    let contributions: Vec<_> = parties
+        .iter()
+        .map(|party| {
+            let mut contribution = SeededLweCompactPublicKey::new(
+                0u64,
+                large_lwe_dimension,
+                crs_seed,
+                ciphertext_modulus,
+            );
+            generate_seeded_lwe_compact_public_key(
+                &party.glwe_secret_key_share.as_lwe_secret_key(),
+                &mut contribution,
+                GLWE_MODULAR_STD_DEV,
+                rsc.seeder.as_mut(),
+            );
+            contribution
+        })
+        .collect();
+
+    let compact_public_key =
+        allocate_and_aggregate_seeded_lwe_compact_public_key_contributions(&contributions)
+            .decompress_into_lwe_compact_public_key();
+
+    let joint_key = joint_glwe_secret_key(&parties);
+
+    for msg in 0..MESSAGE_MODULUS {
+        let mut ct =
+            LweCiphertext::new(0u64, large_lwe_dimension.to_lwe_size(), ciphertext_modulus);
+        encrypt_lwe_ciphertext_with_compact_public_key(
+            &compact_public_key,
+            &mut ct,
+            Plaintext(msg * delta),
+            GLWE_MODULAR_STD_DEV,
+            GLWE_MODULAR_STD_DEV,
+            &mut rsc.secret_random_generator,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let decoded = decrypt_and_decode(&joint_key.as_lwe_secret_key(), &ct, delta);
+        assert_eq!(decoded, msg);
+    }
+}
+
+#[test]
+fn multi_party_keyswitch_and_bootstrap_keys_pbs() {
+    let mut rsc = TestResources::new();
+    let ciphertext_modulus = CiphertextModulus::new_native();
+    let delta = get_encoding_with_padding(ciphertext_modulus) / MESSAGE_MODULUS;
+
+    let parties = generate_party_keys(&mut rsc);
+    let ksk_crs_seed: CompressionSeed = rsc.seeder.seed().into();
+    let glwe_crs_seed: CompressionSeed = rsc.seeder.seed().into();
+    let large_lwe_dimension = GLWE_DIMENSION.to_equivalent_lwe_dimension(POLYNOMIAL_SIZE);
+    let joint_lwe_dimension = LweDimension(LWE_SHARE_DIMENSION.0 * PARTY_COUNT.0);
+
+    // Round one, keyswitch key and GLWE encryption of zero contributions
+    let mut ksk_contributions = Vec::with_capacity(PARTY_COUNT.0);
+    let mut glwe_zero_contributions = Vec::with_capacity(PARTY_COUNT.0);
+    for (party_offset, party) in parties.iter().enumerate() {
+        let mut ksk_contribution = SeededLweKeyswitchKey::new(
+            0u64,
+            KS_BASE_LOG,
+            KS_LEVEL,
+            large_lwe_dimension,
+            joint_lwe_dimension,
+            ksk_crs_seed,
+            ciphertext_modulus,
+        );
+        generate_multi_party_seeded_lwe_keyswitch_key_contribution(
+            &party.glwe_secret_key_share.as_lwe_secret_key(),
+            &party.lwe_secret_key_share,
+            ThresholdPartyIndex(party_offset + 1),
+            PARTY_COUNT,
+            &mut ksk_contribution,
+            LWE_MODULAR_STD_DEV,
+            rsc.seeder.as_mut(),
+        );
+        ksk_contributions.push(ksk_contribution);
+
+        let mut glwe_zero_contribution = SeededGlweCiphertext::new(
+            0u64,
+            GLWE_DIMENSION.to_glwe_size(),
+            POLYNOMIAL_SIZE,
+            glwe_crs_seed,
+            ciphertext_modulus,
+        );
+        generate_multi_party_seeded_glwe_encryption_of_zero_contribution(
+            &party.glwe_secret_key_share,
+            &mut glwe_zero_contribution,
+            BSK_MODULAR_STD_DEV,
+            rsc.seeder.as_mut(),
+        );
+        glwe_zero_contributions.push(glwe_zero_contribution);
+    }
+
+    let ksk = allocate_and_aggregate_seeded_lwe_keyswitch_key_contributions(&ksk_contributions)
+        .decompress_into_lwe_keyswitch_key();
+    let glwe_encryption_of_zero =
+        allocate_and_aggregate_seeded_glwe_ciphertext_contributions(&glwe_zero_contributions)
+            .decompress_into_glwe_ciphertext();
+
+    // Round two, bootstrap key contributions
+    let bsk_contributions: Vec<_> = parties
+        .iter()
+        .map(|party| {
+            let mut bsk_contribution = LweBootstrapKey::new(
+                0u64,
+                GLWE_DIMENSION.to_glwe_size(),
+                POLYNOMIAL_SIZE,
+                PBS_BASE_LOG,
+                PBS_LEVEL,
+                LWE_SHARE_DIMENSION,
+                ciphertext_modulus,
+            );
+            generate_multi_party_lwe_bootstrap_key_contribution(
+                &party.lwe_secret_key_share,
+                &glwe_encryption_of_zero,
+                &mut bsk_contribution,
+                BSK_MODULAR_STD_DEV,
+                &mut rsc.secret_random_generator,
+                &mut rsc.encryption_random_generator,
+            );
+            bsk_contribution
+        })
+        .collect();
+
+    let bsk = allocate_and_aggregate_lwe_bootstrap_key_contributions(&bsk_contributions);
+    assert_eq!(bsk.input_lwe_dimension(), joint_lwe_dimension);
+
+    let joint_glwe_key = joint_glwe_secret_key(&parties);
+    let joint_lwe_key = joint_lwe_secret_key(&parties);
+
+    // The GGSW ciphertexts encrypt the bits of the joint LWE secret key
+    for (ggsw, &key_bit) in bsk.iter().zip(joint_lwe_key.as_ref()) {
+        let decrypted = decrypt_constant_ggsw_ciphertext(&joint_glwe_key, &ggsw);
+        assert_eq!(decrypted.0, key_bit);
+    }
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        joint_lwe_dimension,
+        GLWE_DIMENSION.to_glwe_size(),
+        POLYNOMIAL_SIZE,
+        PBS_BASE_LOG,
+        PBS_LEVEL,
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    let f = |x: u64| x.wrapping_mul(3) % MESSAGE_MODULUS;
+    let accumulator = generate_accumulator(
+        POLYNOMIAL_SIZE,
+        GLWE_DIMENSION.to_glwe_size(),
+        MESSAGE_MODULUS as usize,
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let joint_large_lwe_key = joint_glwe_key.as_lwe_secret_key();
+    for _ in 0..NB_TESTS {
+        for msg in 0..MESSAGE_MODULUS {
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &joint_large_lwe_key,
+                Plaintext(msg * delta),
+                GLWE_MODULAR_STD_DEV,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut ks_ct =
+                LweCiphertext::new(0u64, joint_lwe_dimension.to_lwe_size(), ciphertext_modulus);
+            keyswitch_lwe_ciphertext(&ksk, &ct, &mut ks_ct);
+            assert_eq!(decrypt_and_decode(&joint_lwe_key, &ks_ct, delta), msg);
+
+            let mut pbs_ct =
+                LweCiphertext::new(0u64, large_lwe_dimension.to_lwe_size(), ciphertext_modulus);
+            programmable_bootstrap_lwe_ciphertext(&ks_ct, &mut pbs_ct, &accumulator, &fbsk);
+            assert_eq!(
+                decrypt_and_decode(&joint_large_lwe_key, &pbs_ct, delta),
+                f(msg)
+            );
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use super::*;
+use crate::core_crypto::commons::noise_formulas::packing_keyswitch_additive_variance;
+use crate::core_crypto::commons::test_tools::{torus_modular_diff, variance};
+
+// The measured variance is estimated on fewer samples than for the encryption, keep some margin
+const RELATIVE_TOLERANCE: f64 = 0.125;
+
+fn lwe_encrypt_pack_ks_decrypt_noise_distribution_custom_mod<
+    Scalar: UnsignedTorus + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    // Use the decomposition of the PBS so that the noise of the key dominates the rounding error
+    let pks_decomp_base_log = params.pbs_base_log;
+    let pks_decomp_level_count = params.pbs_level;
+    // Pack several ciphertexts so that a missing dependency on their count is detected
+    let packed_lwe_count = LweCiphertextCount(polynomial_size.0 / 2);
+
+    // The inputs are encrypted with a small noise so that the keyswitch noise dominates
+    let expected_variance = Variance(
+        glwe_modular_std_dev.get_variance()
+            + packing_keyswitch_additive_variance(
+                lwe_dimension,
+                SecretKeyDistribution::Binary,
+                packed_lwe_count,
+                pks_decomp_base_log,
+                pks_decomp_level_count,
+                glwe_modular_std_dev,
+                ciphertext_modulus.raw_modulus_float(),
+            )
+            .0,
+    );
+
+    let mut rsc = TestResources::new();
+
+    const NB_TESTS: usize = 10;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let mut noise_samples = Vec::with_capacity(NB_TESTS * packed_lwe_count.0);
+
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let pksk = allocate_and_generate_new_lwe_packing_keyswitch_key(
+        &lwe_sk,
+        &glwe_sk,
+        pks_decomp_base_log,
+        pks_decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    for _ in 0..NB_TESTS {
+        let plaintexts: Vec<Scalar> = (0..packed_lwe_count.0)
+            .map(|i| Scalar::cast_from(i) % msg_modulus * delta)
+            .collect();
+
+        let mut input_lwe_list = LweCiphertextList::new(
+            Scalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            packed_lwe_count,
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &lwe_sk,
+            &mut input_lwe_list,
+            &PlaintextList::from_container(plaintexts.as_slice()),
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut output_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(
+            &pksk,
+            &input_lwe_list,
+            &mut output_glwe,
+        );
+
+        let mut decrypted = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut decrypted);
+
+        for (&plaintext, &decrypted) in plaintexts.iter().zip(decrypted.as_ref()) {
+            let decoded = round_decode(decrypted, delta) % msg_modulus;
+
+            assert_eq!(plaintext / delta, decoded);
+
+            let torus_distance = torus_modular_diff(plaintext, decrypted, ciphertext_modulus);
+            noise_samples.push(torus_distance);
+        }
+    }
+
+    let measured_variance = variance(&noise_samples);
+
+    let var_abs_diff = (expected_variance.0 - measured_variance.0).abs();
+    let tolerance_threshold = RELATIVE_TOLERANCE * expected_variance.0;
+    assert!(
+        var_abs_diff < tolerance_threshold,
+        "Absolute difference for variance: {var_abs_diff}, \
+        tolerance threshold: {tolerance_threshold}, \
+        got variance: {measured_variance:?}, \
+        expected variance: {expected_variance:?}"
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_pack_ks_decrypt_noise_distribution_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64
+});
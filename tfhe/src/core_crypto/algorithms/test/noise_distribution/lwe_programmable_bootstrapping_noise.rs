@@ -0,0 +1,153 @@
+use super::*;
+use crate::core_crypto::commons::noise_formulas::programmable_bootstrap_output_variance;
+use crate::core_crypto::commons::test_tools::{torus_modular_diff, variance};
+
+// Fewer samples are used than for the encryption, keep some margin
+const RELATIVE_TOLERANCE: f64 = 0.125;
+// The error of the floating point FFT is not part of the formula, the measured variance can be
+// higher than the expected one by this factor
+const FFT_ERROR_FACTOR: f64 = 2.0;
+
+fn lwe_encrypt_pbs_decrypt_noise_distribution_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let expected_variance = programmable_bootstrap_output_variance(
+        input_lwe_dimension,
+        SecretKeyDistribution::Binary,
+        glwe_dimension,
+        SecretKeyDistribution::Binary,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus.raw_modulus_float(),
+    );
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+    const NB_TESTS: usize = 100;
+
+    let num_samples = NB_TESTS * <Scalar as CastInto<usize>>::cast_into(msg);
+    let mut noise_samples = Vec::with_capacity(num_samples);
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let mut bsk = LweBootstrapKey::new(
+        Scalar::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        input_lwe_dimension,
+        ciphertext_modulus,
+    );
+
+    par_generate_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        &mut bsk,
+        glwe_modular_std_dev,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    );
+
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &fbsk,
+            );
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+
+            let torus_distance =
+                torus_modular_diff(f(msg).wrapping_mul(delta), decrypted.0, ciphertext_modulus);
+            noise_samples.push(torus_distance);
+        }
+    }
+
+    let measured_variance = variance(&noise_samples);
+
+    let lower_bound = (1.0 - RELATIVE_TOLERANCE) * expected_variance.0;
+    let upper_bound = FFT_ERROR_FACTOR * expected_variance.0;
+    assert!(
+        measured_variance.0 > lower_bound && measured_variance.0 < upper_bound,
+        "Measured variance out of bounds [{lower_bound}, {upper_bound}], \
+        got variance: {measured_variance:?}, \
+        expected variance: {expected_variance:?}"
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_pbs_decrypt_noise_distribution_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64
+});
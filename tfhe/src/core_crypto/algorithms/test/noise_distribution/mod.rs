@@ -1,3 +1,6 @@
 use super::*;
 
 mod lwe_encryption_noise;
+mod lwe_keyswitch_noise;
+mod lwe_packing_keyswitch_noise;
+mod lwe_programmable_bootstrapping_noise;
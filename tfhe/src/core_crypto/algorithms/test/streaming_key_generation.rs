@@ -0,0 +1,238 @@
+use super::*;
+
+use crate::core_crypto::commons::generators::{
+    DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
+use bincode::Options;
+
+fn deterministic_encryption_generator(
+    mask_seed: Seed,
+    deterministic_seeder_seed: Seed,
+) -> EncryptionRandomGenerator<ActivatedRandomGenerator> {
+    EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+        mask_seed,
+        &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(deterministic_seeder_seed),
+    )
+}
+
+fn bincode_options() -> impl Options {
+    bincode::DefaultOptions::new().with_fixint_encoding()
+}
+
+#[test]
+fn test_streamed_lwe_ksk_gen_equivalence() {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let input_lwe_dimension = LweDimension(742);
+    let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+    let output_lwe_dimension = LweDimension(2048);
+    let decomp_base_log = DecompositionBaseLog(3);
+    let decomp_level_count = DecompositionLevelCount(5);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mask_seed = seeder.seed();
+    let deterministic_seeder_seed = seeder.seed();
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+
+    let input_lwe_secret_key =
+        allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+    let output_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        output_lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+        &input_lwe_secret_key,
+        &output_lwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        lwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+    );
+
+    let mut streamed_ksk = vec![];
+    generate_lwe_keyswitch_key_into_serializer(
+        &input_lwe_secret_key,
+        &output_lwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        lwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+        &mut bincode::Serializer::new(&mut streamed_ksk, bincode_options()),
+    )
+    .unwrap();
+
+    assert_eq!(streamed_ksk, bincode::serialize(&ksk).unwrap());
+}
+
+#[test]
+fn test_streamed_lwe_bsk_gen_equivalence() {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let lwe_dimension = LweDimension(10);
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(256);
+    let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    let decomp_base_log = DecompositionBaseLog(4);
+    let decomp_level_count = DecompositionLevelCount(3);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mask_seed = seeder.seed();
+    let deterministic_seeder_seed = seeder.seed();
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+
+    let lwe_sk =
+        allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &lwe_sk,
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+    );
+
+    let mut streamed_bsk = vec![];
+    par_generate_lwe_bootstrap_key_into_serializer(
+        &lwe_sk,
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+        &mut bincode::Serializer::new(&mut streamed_bsk, bincode_options()),
+    )
+    .unwrap();
+
+    assert_eq!(streamed_bsk, bincode::serialize(&bsk).unwrap());
+
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fourier_bsk);
+
+    let mut streamed_fourier_bsk = vec![];
+    par_generate_fourier_lwe_bootstrap_key_into_serializer(
+        &lwe_sk,
+        &glwe_sk,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+        &mut bincode::Serializer::new(&mut streamed_fourier_bsk, bincode_options()),
+    )
+    .unwrap();
+
+    assert_eq!(
+        streamed_fourier_bsk,
+        bincode::serialize(&fourier_bsk).unwrap()
+    );
+}
+
+#[test]
+fn test_streamed_lwe_multi_bit_bsk_gen_equivalence() {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    let lwe_dimension = LweDimension(12);
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(256);
+    let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    let decomp_base_log = DecompositionBaseLog(4);
+    let decomp_level_count = DecompositionLevelCount(3);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mask_seed = seeder.seed();
+    let deterministic_seeder_seed = seeder.seed();
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+
+    let lwe_sk =
+        allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    for grouping_factor in [LweBskGroupingFactor(2), LweBskGroupingFactor(3)] {
+        let bsk = par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
+            &lwe_sk,
+            &glwe_sk,
+            decomp_base_log,
+            decomp_level_count,
+            grouping_factor,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+        );
+
+        let mut streamed_bsk = vec![];
+        par_generate_lwe_multi_bit_bootstrap_key_into_serializer(
+            &lwe_sk,
+            &glwe_sk,
+            decomp_base_log,
+            decomp_level_count,
+            grouping_factor,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+            &mut bincode::Serializer::new(&mut streamed_bsk, bincode_options()),
+        )
+        .unwrap();
+
+        assert_eq!(streamed_bsk, bincode::serialize(&bsk).unwrap());
+
+        let mut fourier_bsk = FourierLweMultiBitBootstrapKey::new(
+            bsk.input_lwe_dimension(),
+            bsk.glwe_size(),
+            bsk.polynomial_size(),
+            bsk.decomposition_base_log(),
+            bsk.decomposition_level_count(),
+            bsk.grouping_factor(),
+        );
+        par_convert_standard_lwe_multi_bit_bootstrap_key_to_fourier(&bsk, &mut fourier_bsk);
+
+        let mut streamed_fourier_bsk = vec![];
+        par_generate_fourier_lwe_multi_bit_bootstrap_key_into_serializer(
+            &lwe_sk,
+            &glwe_sk,
+            decomp_base_log,
+            decomp_level_count,
+            grouping_factor,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut deterministic_encryption_generator(mask_seed, deterministic_seeder_seed),
+            &mut bincode::Serializer::new(&mut streamed_fourier_bsk, bincode_options()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            streamed_fourier_bsk,
+            bincode::serialize(&fourier_bsk).unwrap()
+        );
+    }
+}
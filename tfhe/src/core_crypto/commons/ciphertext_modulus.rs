@@ -202,6 +202,17 @@ impl<Scalar: UnsignedInteger> CiphertextModulus<Scalar> {
         }
     }
 
+    /// Return the modulus as a floating point value, the native modulus being $2^{Scalar::BITS}$.
+    ///
+    /// This is mostly useful to evaluate noise formulas, the returned value may not be exact for
+    /// large custom moduli.
+    pub fn raw_modulus_float(&self) -> f64 {
+        match self.inner {
+            CiphertextModulusInner::Native => 2.0f64.powi(Scalar::BITS as i32),
+            CiphertextModulusInner::Custom(modulus) => modulus.get() as f64,
+        }
+    }
+
     /// Depending on the Scalar type used in the call, this function will determine whether the
     /// current modulus is the native modulus of the given Scalar type allowing for more efficient
     /// implementations than can rely on wrapping arithmetic operations behavior to compute the
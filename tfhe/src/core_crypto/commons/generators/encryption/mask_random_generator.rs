@@ -1,8 +1,8 @@
 //! Module containing primitives pertaining to random mask generation in the context of encryption.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator, Seed,
-    Uniform,
+    ByteRandomGenerator, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator,
+    RandomGeneratorState, Seed, Uniform,
 };
 use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{
@@ -40,6 +40,16 @@ impl<G: ByteRandomGenerator> MaskRandomGenerator<G> {
         self.gen.remaining_bytes()
     }
 
+    pub fn state(&self) -> RandomGeneratorState {
+        self.gen.state()
+    }
+
+    pub fn from_state(state: RandomGeneratorState) -> Self {
+        Self {
+            gen: RandomGenerator::from_state(state),
+        }
+    }
+
     // Fills the slice with random uniform values, using the mask generator.
     pub(crate) fn fill_slice_with_random_mask<Scalar>(&mut self, output: &mut [Scalar])
     where
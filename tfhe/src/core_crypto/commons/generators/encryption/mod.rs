@@ -6,8 +6,8 @@ pub(crate) mod noise_random_generator;
 mod test;
 use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, Seed, Seeder,
-    Uniform,
+    ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable,
+    RandomGeneratorState, Seed, Seeder, Uniform,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::UnsignedInteger;
@@ -19,6 +19,17 @@ use concrete_csprng::generators::ForkError;
 use mask_random_generator::MaskRandomGenerator;
 use noise_random_generator::NoiseRandomGenerator;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A serializable snapshot of the state of an [`EncryptionRandomGenerator`].
+///
+/// The snapshot contains the private seed of the noise generator and must be kept as secret as the
+/// secret keys used alongside the generator.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct EncryptionRandomGeneratorState {
+    pub mask: RandomGeneratorState,
+    pub noise: RandomGeneratorState,
+}
 
 /// A random number generator which can be used to encrypt messages.
 pub struct EncryptionRandomGenerator<G: ByteRandomGenerator> {
@@ -51,6 +62,28 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.mask.remaining_bytes()
     }
 
+    /// Return a snapshot of the state of both the mask and noise generators, from which the
+    /// generator can be restored with [`EncryptionRandomGenerator::from_state`], e.g. to resume
+    /// an interrupted key generation.
+    pub fn state(&self) -> EncryptionRandomGeneratorState {
+        EncryptionRandomGeneratorState {
+            mask: self.mask.state(),
+            noise: self.noise.state(),
+        }
+    }
+
+    /// Create a generator from a snapshot of its state.
+    ///
+    /// The noise generator is restored from the snapshot instead of being seeded by a
+    /// [`Seeder`], the snapshot must therefore come from a generator built with
+    /// [`EncryptionRandomGenerator::new`].
+    pub fn from_state(state: EncryptionRandomGeneratorState) -> Self {
+        Self {
+            mask: MaskRandomGenerator::from_state(state.mask),
+            noise: NoiseRandomGenerator::from_state(state.noise),
+        }
+    }
+
     // Forks the generator, when splitting a bootstrap key into ggsw ct.
     pub(crate) fn fork_bsk_to_ggsw<T: UnsignedInteger>(
         &mut self,
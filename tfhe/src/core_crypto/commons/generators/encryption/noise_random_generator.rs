@@ -3,7 +3,7 @@
 use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator,
-    Seed,
+    RandomGeneratorState, Seed,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
@@ -42,6 +42,16 @@ impl<G: ByteRandomGenerator> NoiseRandomGenerator<G> {
         self.gen.remaining_bytes()
     }
 
+    pub fn state(&self) -> RandomGeneratorState {
+        self.gen.state()
+    }
+
+    pub fn from_state(state: RandomGeneratorState) -> Self {
+        Self {
+            gen: RandomGenerator::from_state(state),
+        }
+    }
+
     // Sample a noise value, using the random generator.
     pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
     where
@@ -1,14 +1,23 @@
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::dispersion::{StandardDev, Variance};
+use crate::core_crypto::commons::generators::{
+    EncryptionRandomGenerator, EncryptionRandomGeneratorState, MaskRandomGenerator,
+    SecretRandomGenerator,
+};
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGeneratorState};
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
-    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension,
-    PolynomialSize,
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweCiphertextCount,
+    LweDimension, PlaintextCount, PolynomialSize,
 };
 use crate::core_crypto::commons::test_tools::{
-    new_encryption_random_generator, new_secret_random_generator, normality_test_f64,
+    new_encryption_random_generator, new_secret_random_generator, normality_test_f64, random_seed,
+    UnsafeRandSeeder,
+};
+use crate::core_crypto::commons::traits::{ContiguousEntityContainer, UnsignedTorus};
+use crate::core_crypto::entities::{
+    LweSecretKeyOwned, Plaintext, PlaintextList, SeededLweCiphertextList,
 };
-use crate::core_crypto::commons::traits::UnsignedTorus;
 
 #[test]
 fn test_gaussian_sampling_margin_factor_does_not_panic() {
@@ -627,3 +636,96 @@ fn mask_gen_slice_native_custom_mod_u64() {
 fn mask_gen_slice_native_custom_mod_u128() {
     mask_gen_slice_custom_mod::<u128>(CiphertextModulus::new_native());
 }
+
+#[test]
+fn encryption_generator_state_round_trip() {
+    let lwe_dimension = LweDimension(742);
+    let noise = StandardDev(0.000007069849454709433);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut sec_generator = new_secret_random_generator();
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut sec_generator);
+
+    let mut enc_generator = new_encryption_random_generator();
+    // Consume part of the streams before taking the snapshot
+    let _ = allocate_and_encrypt_new_lwe_ciphertext(
+        &lwe_sk,
+        Plaintext(0u64),
+        noise,
+        ciphertext_modulus,
+        &mut enc_generator,
+    );
+
+    let serialized_state = bincode::serialize(&enc_generator.state()).unwrap();
+    let state: EncryptionRandomGeneratorState = bincode::deserialize(&serialized_state).unwrap();
+    let mut restored_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::from_state(state);
+    assert_eq!(restored_generator.state(), enc_generator.state());
+
+    let plaintext = Plaintext(3u64 << 60);
+    let ct = allocate_and_encrypt_new_lwe_ciphertext(
+        &lwe_sk,
+        plaintext,
+        noise,
+        ciphertext_modulus,
+        &mut enc_generator,
+    );
+    let restored_ct = allocate_and_encrypt_new_lwe_ciphertext(
+        &lwe_sk,
+        plaintext,
+        noise,
+        ciphertext_modulus,
+        &mut restored_generator,
+    );
+    assert_eq!(ct, restored_ct);
+
+    let mut restored_sec_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::from_state(sec_generator.state());
+    let lwe_sk: LweSecretKeyOwned<u64> =
+        allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut sec_generator);
+    let restored_lwe_sk =
+        allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut restored_sec_generator);
+    assert_eq!(lwe_sk, restored_lwe_sk);
+}
+
+#[test]
+fn seeded_lwe_list_decompression_at_known_positions() {
+    let lwe_dimension = LweDimension(742);
+    let ciphertext_count = LweCiphertextCount(10);
+    let noise = StandardDev(0.000007069849454709433);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let mut sec_generator = new_secret_random_generator();
+    let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut sec_generator);
+
+    let seed = random_seed();
+    let mut seeded_list = SeededLweCiphertextList::new(
+        0u64,
+        lwe_dimension.to_lwe_size(),
+        ciphertext_count,
+        seed.into(),
+        ciphertext_modulus,
+    );
+    encrypt_seeded_lwe_ciphertext_list(
+        &lwe_sk,
+        &mut seeded_list,
+        &PlaintextList::new(0u64, PlaintextCount(ciphertext_count.0)),
+        noise,
+        &mut UnsafeRandSeeder,
+    );
+    let list = seeded_list.decompress_into_lwe_ciphertext_list();
+
+    // The mask of each ciphertext is drawn from its own chunk of the stream of the seed
+    let mask_bytes_per_lwe = (lwe_dimension.0 * std::mem::size_of::<u64>()) as u128;
+    for (index, lwe) in list.iter().enumerate() {
+        let mut mask_generator =
+            MaskRandomGenerator::<ActivatedRandomGenerator>::from_state(RandomGeneratorState {
+                seed,
+                position: index as u128 * mask_bytes_per_lwe,
+                bound: None,
+            });
+        let mut expected_mask = vec![0u64; lwe_dimension.0];
+        mask_generator.fill_slice_with_random_mask(&mut expected_mask);
+        assert_eq!(lwe.get_mask().as_ref(), expected_mask.as_slice());
+    }
+}
@@ -1,9 +1,13 @@
 //! Module containing primitives pertaining to random generation in the context of secret key
 //! generation.
 
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, RandomGeneratorState, Seed, Uniform,
+    UniformBinary, UniformTernary,
 };
+use crate::core_crypto::commons::numeric::{CastFrom, UnsignedInteger};
+use crate::core_crypto::commons::parameters::SecretKeyDistribution;
 
 /// A random number generator which can be used to generate secret keys.
 pub struct SecretRandomGenerator<G: ByteRandomGenerator>(RandomGenerator<G>);
@@ -19,10 +23,72 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
         self.0.remaining_bytes()
     }
 
+    /// Return a snapshot of the state of the generator, from which it can be restored with
+    /// [`SecretRandomGenerator::from_state`], e.g. to resume an interrupted key generation.
+    ///
+    /// The snapshot allows to regenerate the secret keys and must be kept as secret as them.
+    pub fn state(&self) -> RandomGeneratorState {
+        self.0.state()
+    }
+
+    /// Create a generator from a snapshot of its state.
+    pub fn from_state(state: RandomGeneratorState) -> Self {
+        Self(RandomGenerator::from_state(state))
+    }
+
     pub(crate) fn fill_slice_with_random_uniform_binary<Scalar>(&mut self, slice: &mut [Scalar])
     where
         Scalar: RandomGenerable<UniformBinary>,
     {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
+
+    pub(crate) fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        slice
+            .iter_mut()
+            .for_each(|elt| *elt = self.0.random_uniform_ternary());
+    }
+
+    /// Fill a slice with integers sampled from a centered discrete gaussian distribution of
+    /// standard deviation `std_dev`, negative values are represented modulo $2^{Scalar::BITS}$.
+    pub(crate) fn fill_slice_with_random_gaussian_integers<Scalar>(
+        &mut self,
+        slice: &mut [Scalar],
+        std_dev: f64,
+    ) where
+        Scalar: UnsignedInteger,
+    {
+        let max_abs_coefficient =
+            SecretKeyDistribution::Gaussian { std_dev }.max_abs_coefficient() as f64;
+
+        // Samples above the bound of the distribution are rejected so that the coefficients of
+        // the key are bounded independently of the sampled values
+        let mut samples = std::iter::repeat_with(|| {
+            let samples: (f64, f64) = self.0.random_gaussian(0.0, std_dev);
+            <[f64; 2]>::from(samples)
+        })
+        .flatten()
+        .map(f64::round)
+        .filter(|sample| sample.abs() <= max_abs_coefficient);
+
+        for elt in slice.iter_mut() {
+            let sample = samples.next().unwrap();
+            let signed_sample = <Scalar::Signed as CastFrom<f64>>::cast_from(sample);
+            *elt = <Scalar as CastFrom<Scalar::Signed>>::cast_from(signed_sample);
+        }
+    }
+
+    pub(crate) fn fill_slice_with_random_uniform_custom_mod<Scalar>(
+        &mut self,
+        slice: &mut [Scalar],
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) where
+        Scalar: UnsignedInteger + RandomGenerable<Uniform, CustomModulus = Scalar>,
+    {
+        self.0
+            .fill_slice_with_random_uniform_custom_mod(slice, ciphertext_modulus);
+    }
 }
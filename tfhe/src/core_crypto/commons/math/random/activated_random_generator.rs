@@ -1,19 +1,40 @@
-#[cfg(feature = "generator_x86_64_aesni")]
+#[cfg(all(
+    feature = "generator_x86_64_aesni",
+    not(feature = "generator_x86_64_vaes"),
+    not(feature = "generator_chacha20")
+))]
 use concrete_csprng::generators::AesniRandomGenerator;
-#[cfg(feature = "generator_aarch64_aes")]
+#[cfg(feature = "generator_chacha20")]
+use concrete_csprng::generators::ChaCha20RandomGenerator;
+#[cfg(all(feature = "generator_aarch64_aes", not(feature = "generator_chacha20")))]
 use concrete_csprng::generators::NeonAesRandomGenerator;
 #[cfg(all(
     not(feature = "generator_x86_64_aesni"),
-    not(feature = "generator_aarch64_aes")
+    not(feature = "generator_aarch64_aes"),
+    not(feature = "generator_chacha20")
 ))]
 use concrete_csprng::generators::SoftwareRandomGenerator;
+#[cfg(all(feature = "generator_x86_64_vaes", not(feature = "generator_chacha20")))]
+use concrete_csprng::generators::VaesRandomGenerator;
 
-#[cfg(feature = "generator_x86_64_aesni")]
+// The ChaCha20 generator is only used when explicitly requested, it takes precedence over the aes
+// based generators enabled by the target_arch features.
+#[cfg(feature = "generator_chacha20")]
+pub type ActivatedRandomGenerator = ChaCha20RandomGenerator;
+// The vaes generator outputs the same streams as the aesni one, and falls back to it at runtime.
+#[cfg(all(feature = "generator_x86_64_vaes", not(feature = "generator_chacha20")))]
+pub type ActivatedRandomGenerator = VaesRandomGenerator;
+#[cfg(all(
+    feature = "generator_x86_64_aesni",
+    not(feature = "generator_x86_64_vaes"),
+    not(feature = "generator_chacha20")
+))]
 pub type ActivatedRandomGenerator = AesniRandomGenerator;
-#[cfg(feature = "generator_aarch64_aes")]
+#[cfg(all(feature = "generator_aarch64_aes", not(feature = "generator_chacha20")))]
 pub type ActivatedRandomGenerator = NeonAesRandomGenerator;
 #[cfg(all(
     not(feature = "generator_x86_64_aesni"),
-    not(feature = "generator_aarch64_aes")
+    not(feature = "generator_aarch64_aes"),
+    not(feature = "generator_chacha20")
 ))]
 pub type ActivatedRandomGenerator = SoftwareRandomGenerator;
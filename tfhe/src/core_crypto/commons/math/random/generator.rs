@@ -5,7 +5,9 @@ use crate::core_crypto::commons::math::random::{
 use crate::core_crypto::commons::math::torus::{UnsignedInteger, UnsignedTorus};
 use crate::core_crypto::commons::numeric::{CastInto, FloatingPoint};
 use crate::core_crypto::commons::parameters::CiphertextModulus;
-use concrete_csprng::generators::{BytesPerChild, ChildrenCount, ForkError};
+use concrete_csprng::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState,
+};
 use rayon::prelude::*;
 use std::convert::TryInto;
 
@@ -44,6 +46,38 @@ impl From<Seed> for CompressionSeed {
     }
 }
 
+/// A serializable snapshot of the state of a [`RandomGenerator`].
+///
+/// Positions are counted in bytes from the first byte outputted by a generator freshly created from
+/// `seed`, see [`RandomGenerator::state`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RandomGeneratorState {
+    #[serde(with = "SeedSerdeDef")]
+    pub seed: Seed,
+    pub position: u128,
+    pub bound: Option<u128>,
+}
+
+impl From<GeneratorState> for RandomGeneratorState {
+    fn from(state: GeneratorState) -> Self {
+        Self {
+            seed: state.seed,
+            position: state.position.0,
+            bound: state.bound.map(|bound| bound.0),
+        }
+    }
+}
+
+impl From<RandomGeneratorState> for GeneratorState {
+    fn from(state: RandomGeneratorState) -> Self {
+        Self {
+            seed: state.seed,
+            position: ByteCount(state.position),
+            bound: state.bound.map(ByteCount),
+        }
+    }
+}
+
 /// A cryptographically secure random number generator.
 ///
 /// This csprng is used by every objects that needs sampling in the library. If the proper
@@ -100,6 +134,62 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         Self(G::new(seed))
     }
 
+    /// Generate a new generator from the given seed, starting `offset` bytes into its stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let _skipped = generator.random_uniform::<u64>();
+    /// let mut offset_generator =
+    ///     RandomGenerator::<SoftwareRandomGenerator>::new_at_offset(Seed(0), 8);
+    /// assert_eq!(
+    ///     generator.random_uniform::<u64>(),
+    ///     offset_generator.random_uniform::<u64>()
+    /// );
+    /// ```
+    pub fn new_at_offset(seed: Seed, offset: u128) -> Self {
+        Self(G::new_at_offset(seed, ByteCount(offset)))
+    }
+
+    /// Return a snapshot of the state of the generator, from which it can be restored with
+    /// [`RandomGenerator::from_state`].
+    ///
+    /// The snapshot contains the seed of the generator and must be handled with the same care as
+    /// the seed itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let _skipped = generator.random_uniform::<u64>();
+    /// let state = generator.state();
+    /// assert_eq!(state.position, 8);
+    /// let mut restored = RandomGenerator::<SoftwareRandomGenerator>::from_state(state);
+    /// assert_eq!(
+    ///     generator.random_uniform::<u64>(),
+    ///     restored.random_uniform::<u64>()
+    /// );
+    /// ```
+    pub fn state(&self) -> RandomGeneratorState {
+        self.0.state().into()
+    }
+
+    /// Create a generator from a snapshot of its state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position of the state is greater than its bound.
+    pub fn from_state(state: RandomGeneratorState) -> Self {
+        Self(G::from_state(state.into()))
+    }
+
     /// Return the number of bytes that can still be generated, if the generator is bounded.
     ///
     /// # Example
@@ -574,10 +664,10 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
                 custom_modulus_as_scalar,
             );
             if let Some(elem) = s.get_mut(0) {
-                *elem = (*elem).wrapping_add(g1);
+                *elem = (*elem).wrapping_add_custom_mod(g1, custom_modulus_as_scalar);
             }
             if let Some(elem) = s.get_mut(1) {
-                *elem = (*elem).wrapping_add(g2);
+                *elem = (*elem).wrapping_add_custom_mod(g2, custom_modulus_as_scalar);
             }
         });
     }
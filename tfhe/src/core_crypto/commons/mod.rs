@@ -6,6 +6,10 @@
 //! This module contains the functions used to compute the variance, standard
 //! deviation, etc.
 //!
+//! # Noise formulas
+//! This module contains the functions used to estimate the variance of the noise of ciphertexts
+//! after the various operations of the library.
+//!
 //! # Parameters
 //! This module contains structures that wrap unsigned integer parameters like the ciphertext
 //! dimension or the polynomial degree.
@@ -44,8 +48,11 @@ pub mod computation_buffers;
 pub mod dispersion;
 pub mod generators;
 pub mod math;
+pub mod noise_formulas;
 pub mod numeric;
 pub mod parameters;
+pub mod secret_memory;
+pub mod security;
 pub mod utils;
 
 // Refactor modules
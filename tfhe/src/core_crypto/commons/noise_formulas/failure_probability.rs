@@ -0,0 +1,52 @@
+//! Tools to turn a noise variance into a probability of incorrect decoding.
+
+use crate::core_crypto::commons::dispersion::Variance;
+
+/// Return an approximation of the complementary error function
+/// $\text{erfc}(x) = \frac{2}{\sqrt{\pi}}\int_x^{+\infty}e^{-t^2}dt$.
+///
+/// The approximation is based on a Chebyshev fit, its relative error is smaller than $1.2 \cdot
+/// 10^{-7}$ for all $x$.
+pub fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+
+    let polynomial = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+
+    let result = t * polynomial.exp();
+
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+/// Return the probability that a centered gaussian noise of the given variance is greater than
+/// `decoding_bound` in absolute value.
+///
+/// For a message encoded with a scaling factor $\Delta$ on the torus, the decoding (and the
+/// programmable bootstrap) fails when the noise is greater than $\frac{\Delta}{2}$.
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::Variance;
+/// use tfhe::core_crypto::commons::noise_formulas::failure_probability;
+///
+/// // A standard deviation of 1 has around 31.7% of its mass outside of [-1, 1]
+/// let probability = failure_probability(Variance(1.0), 1.0);
+/// assert!((probability - 0.3173).abs() < 1e-4);
+/// ```
+pub fn failure_probability(variance: Variance, decoding_bound: f64) -> f64 {
+    if variance.0 <= 0.0 {
+        return 0.0;
+    }
+
+    erfc(decoding_bound / (2.0 * variance.0).sqrt())
+}
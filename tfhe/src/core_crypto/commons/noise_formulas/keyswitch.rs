@@ -0,0 +1,109 @@
+//! Noise formulas for the LWE keyswitch and the packing keyswitches.
+
+use super::{decomposition_digit_square_mean, decomposition_rounding_variance};
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    SecretKeyDistribution,
+};
+
+/// Return the variance added by
+/// [`keyswitch_lwe_ciphertext`](`crate::core_crypto::algorithms::keyswitch_lwe_ciphertext`).
+///
+/// The output variance is the sum of the input variance and of the returned value, made of:
+///
+/// + the noise of the keyswitch key, multiplied by the digits of the decomposed input mask;
+/// + the rounding error of the decomposition of the input mask, multiplied by the input key, whose
+///   coefficients are distributed according to `input_secret_key_distribution`.
+pub fn keyswitch_additive_variance(
+    input_lwe_dimension: LweDimension,
+    input_secret_key_distribution: SecretKeyDistribution,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    keyswitch_key_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let input_lwe_dimension = input_lwe_dimension.0 as f64;
+
+    let key_noise_term = input_lwe_dimension
+        * decomp_level_count.0 as f64
+        * decomposition_digit_square_mean(decomp_base_log)
+        * keyswitch_key_noise.get_variance();
+
+    let rounding_term = input_lwe_dimension
+        * input_secret_key_distribution.square_mean()
+        * decomposition_rounding_variance(decomp_base_log, decomp_level_count, ciphertext_modulus)
+            .0;
+
+    Variance(key_noise_term + rounding_term)
+}
+
+/// Return the variance added to the coefficients of the output GLWE ciphertext holding the
+/// packed bodies, when `packed_lwe_count` LWE ciphertexts are packed by a packing keyswitch.
+///
+/// This applies to [`keyswitch_lwe_ciphertext_into_glwe_ciphertext`], for which
+/// `packed_lwe_count` is 1, and to
+/// [`keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`].
+///
+/// Each input LWE ciphertext is keyswitched to a GLWE ciphertext whose coefficients all carry the
+/// noise of the packing keyswitch key, and these GLWE ciphertexts are summed, so the key noise term
+/// of [`keyswitch_additive_variance`] is multiplied by `packed_lwe_count`. The rounding error of
+/// the decomposition only lands on the coefficient of the body of each input ciphertext, so this
+/// term is not.
+///
+/// [`keyswitch_lwe_ciphertext_into_glwe_ciphertext`]:
+/// crate::core_crypto::algorithms::keyswitch_lwe_ciphertext_into_glwe_ciphertext
+/// [`keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`]:
+/// crate::core_crypto::algorithms::keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext
+pub fn packing_keyswitch_additive_variance(
+    input_lwe_dimension: LweDimension,
+    input_secret_key_distribution: SecretKeyDistribution,
+    packed_lwe_count: LweCiphertextCount,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    packing_keyswitch_key_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let input_lwe_dimension = input_lwe_dimension.0 as f64;
+
+    let key_noise_term = packed_lwe_count.0 as f64
+        * input_lwe_dimension
+        * decomp_level_count.0 as f64
+        * decomposition_digit_square_mean(decomp_base_log)
+        * packing_keyswitch_key_noise.get_variance();
+
+    let rounding_term = input_lwe_dimension
+        * input_secret_key_distribution.square_mean()
+        * decomposition_rounding_variance(decomp_base_log, decomp_level_count, ciphertext_modulus)
+            .0;
+
+    Variance(key_noise_term + rounding_term)
+}
+
+/// Return the variance added by a private functional packing keyswitch evaluating the identity
+/// or the multiplication by a key coefficient, as done by the circuit bootstrap.
+///
+/// Compared to [`packing_keyswitch_additive_variance`], the body of the input ciphertext is also
+/// decomposed and multiplied by the key.
+pub fn private_functional_packing_keyswitch_additive_variance(
+    input_lwe_dimension: LweDimension,
+    input_secret_key_distribution: SecretKeyDistribution,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    pfpksk_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let input_lwe_dimension = input_lwe_dimension.0 as f64;
+
+    let key_noise_term = (input_lwe_dimension + 1.0)
+        * decomp_level_count.0 as f64
+        * decomposition_digit_square_mean(decomp_base_log)
+        * pfpksk_noise.get_variance();
+
+    let rounding_term = input_lwe_dimension
+        * input_secret_key_distribution.square_mean()
+        * decomposition_rounding_variance(decomp_base_log, decomp_level_count, ciphertext_modulus)
+            .0;
+
+    Variance(key_noise_term + rounding_term)
+}
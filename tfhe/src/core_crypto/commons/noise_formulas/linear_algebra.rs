@@ -0,0 +1,64 @@
+//! Noise formulas for the encryption and the leveled linear operations on LWE and GLWE
+//! ciphertexts.
+
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+
+/// Return the variance of a freshly encrypted ciphertext, for a secret key encryption with the
+/// given noise parameter.
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
+/// use tfhe::core_crypto::commons::noise_formulas::encryption_variance;
+///
+/// let noise = StandardDev(2.0f64.powi(-20));
+/// assert_eq!(encryption_variance(noise).get_variance(), 2.0f64.powi(-40));
+/// ```
+pub fn encryption_variance(noise_parameter: impl DispersionParameter) -> Variance {
+    Variance(noise_parameter.get_variance())
+}
+
+/// Return the variance of the sum (or of the difference) of two ciphertexts with independent
+/// noises.
+pub fn addition_variance(lhs: Variance, rhs: Variance) -> Variance {
+    Variance(lhs.0 + rhs.0)
+}
+
+/// Return the variance of a ciphertext multiplied by a cleartext.
+pub fn cleartext_multiplication_variance(input: Variance, cleartext: i64) -> Variance {
+    let cleartext = cleartext as f64;
+    Variance(input.0 * cleartext * cleartext)
+}
+
+/// Return the variance of the dot product between a list of ciphertexts with independent noises
+/// and a list of cleartexts.
+///
+/// # Panics
+///
+/// Panics if the two lists do not have the same length.
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::Variance;
+/// use tfhe::core_crypto::commons::noise_formulas::dot_product_variance;
+///
+/// let variances = [Variance(1.0), Variance(2.0)];
+/// let cleartexts = [3, -1];
+///
+/// assert_eq!(dot_product_variance(&variances, &cleartexts).0, 11.0);
+/// ```
+pub fn dot_product_variance(variances: &[Variance], cleartexts: &[i64]) -> Variance {
+    assert_eq!(
+        variances.len(),
+        cleartexts.len(),
+        "Mismatched number of variances ({}) and cleartexts ({})",
+        variances.len(),
+        cleartexts.len()
+    );
+
+    Variance(
+        variances
+            .iter()
+            .zip(cleartexts.iter())
+            .map(|(&variance, &cleartext)| cleartext_multiplication_variance(variance, cleartext).0)
+            .sum(),
+    )
+}
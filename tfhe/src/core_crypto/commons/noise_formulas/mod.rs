@@ -0,0 +1,70 @@
+//! Module containing analytical formulas estimating the variance of the noise of ciphertexts.
+//!
+//! These formulas can be used to check that a set of custom parameters yields correct
+//! computations before using it, by comparing the variance of the noise at the input of a
+//! decryption or of a bootstrap with the decoding bound of the encoding, see
+//! [`failure_probability`](`failure_probability::failure_probability`).
+//!
+//! # Conventions
+//!
+//! + All variances are expressed on the torus, i.e. for a ciphertext modulus $q$, an integer
+//!   variance $v$ is represented by $\frac{v}{q^2}$. This is the convention used by the
+//!   [`Variance`] type and by the noise parameters of the library.
+//! + The ciphertext modulus is given as a floating point value, see
+//!   [`CiphertextModulus::raw_modulus_float`](`super::ciphertext_modulus::CiphertextModulus::raw_modulus_float`).
+//! + The distribution of the secret keys is given by a
+//!   [`SecretKeyDistribution`](`super::parameters::SecretKeyDistribution`), the formulas
+//!   use the mean of the square of their coefficients, and the noise terms are assumed to be
+//!   independent.
+//!
+//! The formulas only keep the dominant terms of the variance. In particular the error introduced
+//! by the floating point FFT used by the Fourier bootstraps is not modeled: for usual parameters
+//! the measured output variance of a bootstrap can be up to twice the estimated one, more for
+//! large polynomial sizes or large decomposition bases. Some margin should therefore be kept when
+//! validating parameters.
+
+use super::dispersion::Variance;
+use super::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+pub mod failure_probability;
+pub mod keyswitch;
+pub mod linear_algebra;
+pub mod modulus_switch;
+pub mod programmable_bootstrap;
+pub mod wopbs;
+
+pub use failure_probability::*;
+pub use keyswitch::*;
+pub use linear_algebra::*;
+pub use modulus_switch::*;
+pub use programmable_bootstrap::*;
+pub use wopbs::*;
+
+/// Return the mean of the square of a digit of a signed decomposition in base $B=2^{base\_log}$.
+///
+/// The digits are assumed to be uniformly distributed in $[-\frac{B}{2}, \frac{B}{2}[$.
+pub(crate) fn decomposition_digit_square_mean(base_log: DecompositionBaseLog) -> f64 {
+    let base = 2.0f64.powi(base_log.0 as i32);
+    (base * base + 2.0) / 12.0
+}
+
+/// Return the variance of the error made when rounding a torus element to the closest value
+/// representable with `level` digits in base $B=2^{base\_log}$, for a ciphertext modulus $q$.
+///
+/// This is the variance of a uniform error on $[-\frac{1}{2B^\ell}, \frac{1}{2B^\ell}[$, which is
+/// discretized with a step of $\frac{1}{q}$.
+pub fn decomposition_rounding_variance(
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let precision = 2.0f64.powi((base_log.0 * level.0) as i32);
+
+    if precision >= ciphertext_modulus {
+        return Variance(0.0);
+    }
+
+    Variance(
+        (1.0 / (precision * precision) - 1.0 / (ciphertext_modulus * ciphertext_modulus)) / 12.0,
+    )
+}
@@ -0,0 +1,52 @@
+//! Noise formulas for the modulus switch of LWE and GLWE ciphertexts.
+
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulusLog, LweDimension, PolynomialSize, SecretKeyDistribution,
+};
+
+/// Return the variance added by switching the modulus of an LWE ciphertext from $q$ to
+/// $2^{output\_log\_modulus}$.
+///
+/// Each coefficient is rounded independently, the rounding errors of the mask are multiplied by
+/// the secret key during the decryption, its coefficients are distributed according to
+/// `secret_key_distribution`.
+///
+/// To estimate the noise added by the modulus switch of a GLWE ciphertext, use the
+/// [`LweDimension`] of the equivalent LWE ciphertext.
+pub fn modulus_switch_additive_variance(
+    lwe_dimension: LweDimension,
+    secret_key_distribution: SecretKeyDistribution,
+    output_log_modulus: CiphertextModulusLog,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let output_modulus = 2.0f64.powi(output_log_modulus.0 as i32);
+
+    if output_modulus >= ciphertext_modulus {
+        return Variance(0.0);
+    }
+
+    let rounding_variance = (1.0 / (output_modulus * output_modulus)
+        - 1.0 / (ciphertext_modulus * ciphertext_modulus))
+        / 12.0;
+
+    Variance(
+        rounding_variance * (1.0 + lwe_dimension.0 as f64 * secret_key_distribution.square_mean()),
+    )
+}
+
+/// Return the variance added by the modulus switch to $2N$ done at the beginning of a
+/// programmable bootstrap using polynomials of size $N$.
+pub fn pbs_modulus_switch_additive_variance(
+    lwe_dimension: LweDimension,
+    secret_key_distribution: SecretKeyDistribution,
+    polynomial_size: PolynomialSize,
+    ciphertext_modulus: f64,
+) -> Variance {
+    modulus_switch_additive_variance(
+        lwe_dimension,
+        secret_key_distribution,
+        CiphertextModulusLog(polynomial_size.log2().0 + 1),
+        ciphertext_modulus,
+    )
+}
@@ -0,0 +1,237 @@
+//! Noise formulas for the external product, the CMux and the classic and multi-bit programmable
+//! bootstraps.
+
+use super::{decomposition_digit_square_mean, decomposition_rounding_variance};
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    LweDimension, PolynomialSize, SecretKeyDistribution,
+};
+
+fn ggsw_noise_term(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ggsw_noise: impl DispersionParameter,
+) -> f64 {
+    glwe_dimension.to_glwe_size().0 as f64
+        * decomp_level_count.0 as f64
+        * polynomial_size.0 as f64
+        * decomposition_digit_square_mean(decomp_base_log)
+        * ggsw_noise.get_variance()
+}
+
+fn rounding_term(
+    glwe_dimension: GlweDimension,
+    glwe_secret_key_distribution: SecretKeyDistribution,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ciphertext_modulus: f64,
+) -> f64 {
+    // The rounding error of the body is not multiplied by the key, the ones of the mask are, and
+    // the result is multiplied by the binary message of the GGSW ciphertext
+    (1.0 + (glwe_dimension.0 * polynomial_size.0) as f64
+        * glwe_secret_key_distribution.square_mean())
+        * decomposition_rounding_variance(decomp_base_log, decomp_level_count, ciphertext_modulus).0
+        * SecretKeyDistribution::Binary.square_mean()
+}
+
+/// Return the number of CMuxes computed by the blind rotation of a PBS.
+///
+/// This is the blind rotation of
+/// [`programmable_bootstrap_lwe_ciphertext`](`crate::core_crypto::algorithms::programmable_bootstrap_lwe_ciphertext`)
+/// for an input LWE secret key of the given dimension and distribution.
+/// A binary key requires one CMux per coefficient. Other keys are bootstrapped through their
+/// binary expansion, see
+/// [`generate_binary_expanded_lwe_secret_key`](`crate::core_crypto::algorithms::generate_binary_expanded_lwe_secret_key`),
+/// which requires two CMuxes per bit plane of each coefficient, see
+/// [`SecretKeyDistribution::bit_plane_count`].
+pub fn blind_rotation_cmux_count(
+    input_lwe_dimension: LweDimension,
+    input_secret_key_distribution: SecretKeyDistribution,
+) -> usize {
+    match input_secret_key_distribution {
+        SecretKeyDistribution::Binary => input_lwe_dimension.0,
+        SecretKeyDistribution::Ternary | SecretKeyDistribution::Gaussian { .. } => {
+            2 * input_secret_key_distribution.bit_plane_count().0 * input_lwe_dimension.0
+        }
+    }
+}
+
+/// Return the variance added by the external product between a GGSW ciphertext encrypting a
+/// binary message and a GLWE ciphertext.
+///
+/// See
+/// [`add_external_product_assign`](`crate::core_crypto::algorithms::add_external_product_assign`).
+/// When the GGSW ciphertext encrypts $m$, the output variance is $m^2$ times the input variance
+/// plus the returned value.
+///
+/// `ggsw_noise` is the noise of the GGSW ciphertext, which is the encryption noise for a freshly
+/// encrypted GGSW ciphertext, or the output variance of a circuit bootstrap. The GLWE secret key
+/// of both ciphertexts is distributed according to `glwe_secret_key_distribution`.
+pub fn external_product_additive_variance(
+    glwe_dimension: GlweDimension,
+    glwe_secret_key_distribution: SecretKeyDistribution,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ggsw_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    Variance(
+        ggsw_noise_term(
+            glwe_dimension,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ggsw_noise,
+        ) + rounding_term(
+            glwe_dimension,
+            glwe_secret_key_distribution,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        ),
+    )
+}
+
+/// Return the variance added by a CMux, see
+/// [`cmux_assign`](`crate::core_crypto::algorithms::cmux_assign`).
+///
+/// The output variance is the variance of the selected GLWE ciphertext plus the returned value,
+/// which is the variance added by the underlying external product.
+pub fn cmux_additive_variance(
+    glwe_dimension: GlweDimension,
+    glwe_secret_key_distribution: SecretKeyDistribution,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ggsw_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    external_product_additive_variance(
+        glwe_dimension,
+        glwe_secret_key_distribution,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        ggsw_noise,
+        ciphertext_modulus,
+    )
+}
+
+/// Return the variance of the output of
+/// [`programmable_bootstrap_lwe_ciphertext`](`crate::core_crypto::algorithms::programmable_bootstrap_lwe_ciphertext`)
+/// for a noiseless accumulator.
+///
+/// The blind rotation computes one CMux per coefficient of the input LWE secret key, or more for
+/// non binary keys, see [`blind_rotation_cmux_count`]. The output noise does not depend on the
+/// noise of the input ciphertext, as long as the bootstrap is correct. The error of the FFT is not included, see the [`module documentation`](`super`). The
+/// noise added by the modulus switch of the input ciphertext is given by
+/// [`pbs_modulus_switch_additive_variance`](`super::pbs_modulus_switch_additive_variance`).
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::programmable_bootstrap_output_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let variance = programmable_bootstrap_output_variance(
+///     LweDimension(742),
+///     SecretKeyDistribution::Binary,
+///     GlweDimension(1),
+///     SecretKeyDistribution::Binary,
+///     PolynomialSize(2048),
+///     DecompositionBaseLog(23),
+///     DecompositionLevelCount(1),
+///     StandardDev(0.0000000000000003152931493498455),
+///     CiphertextModulus::<u64>::new_native().raw_modulus_float(),
+/// );
+///
+/// // The standard deviation of the output is around 2^-15.5
+/// assert!(variance.get_log_standard_dev() < -15.0);
+/// assert!(variance.get_log_standard_dev() > -16.0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn programmable_bootstrap_output_variance(
+    input_lwe_dimension: LweDimension,
+    input_secret_key_distribution: SecretKeyDistribution,
+    glwe_dimension: GlweDimension,
+    glwe_secret_key_distribution: SecretKeyDistribution,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    bootstrap_key_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let cmux_variance = cmux_additive_variance(
+        glwe_dimension,
+        glwe_secret_key_distribution,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        bootstrap_key_noise,
+        ciphertext_modulus,
+    );
+
+    let cmux_count = blind_rotation_cmux_count(input_lwe_dimension, input_secret_key_distribution);
+
+    Variance(cmux_count as f64 * cmux_variance.0)
+}
+
+/// Return the variance of the output of a multi-bit programmable bootstrap for a noiseless
+/// accumulator.
+///
+/// See
+/// [`multi_bit_programmable_bootstrap_lwe_ciphertext`](`crate::core_crypto::algorithms::multi_bit_programmable_bootstrap_lwe_ciphertext`).
+/// Each of the $\frac{n}{g}$ steps of the blind rotation computes a single external product with
+/// a GGSW ciphertext obtained as a sum of the $2^g - 1$ GGSW ciphertexts of a bootstrap key
+/// element, so the noise of the key is counted $2^g - 1$ times per step. The input LWE secret key
+/// must be binary.
+///
+/// # Panics
+///
+/// Panics if the input [`LweDimension`] is not a multiple of the grouping factor.
+#[allow(clippy::too_many_arguments)]
+pub fn multi_bit_programmable_bootstrap_output_variance(
+    input_lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    glwe_secret_key_distribution: SecretKeyDistribution,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+    bootstrap_key_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    assert_eq!(
+        input_lwe_dimension.0 % grouping_factor.0,
+        0,
+        "Input LweDimension ({}) is not a multiple of the grouping factor ({})",
+        input_lwe_dimension.0,
+        grouping_factor.0
+    );
+
+    let step_count = (input_lwe_dimension.0 / grouping_factor.0) as f64;
+    let ggsw_per_step = (grouping_factor.ggsw_per_multi_bit_element().0 - 1) as f64;
+
+    let step_variance = ggsw_per_step
+        * ggsw_noise_term(
+            glwe_dimension,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            bootstrap_key_noise,
+        )
+        + rounding_term(
+            glwe_dimension,
+            glwe_secret_key_distribution,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        );
+
+    Variance(step_count * step_variance)
+}
@@ -0,0 +1,109 @@
+//! Noise formulas for the circuit bootstrap and the vertical packing composing the
+//! without-padding programmable bootstrap (WoP-PBS).
+//!
+//! The circuit bootstrap requires binary secret keys, the formulas of this module are given for
+//! binary LWE and GLWE secret keys.
+
+use super::{
+    external_product_additive_variance, private_functional_packing_keyswitch_additive_variance,
+};
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    SecretKeyDistribution,
+};
+
+/// Return the variance of the GLWE ciphertexts composing the GGSW ciphertext output by a circuit
+/// bootstrap.
+///
+/// Each level of the output GGSW ciphertext is obtained with a programmable bootstrap, whose
+/// output variance is given by `pbs_output_variance`, followed by a private functional packing
+/// keyswitch from the LWE ciphertext of dimension `pfpksk_input_lwe_dimension` output by the
+/// bootstrap.
+pub fn circuit_bootstrap_output_variance(
+    pbs_output_variance: Variance,
+    pfpksk_input_lwe_dimension: LweDimension,
+    pfpksk_base_log: DecompositionBaseLog,
+    pfpksk_level_count: DecompositionLevelCount,
+    pfpksk_noise: impl DispersionParameter,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let pfpks_variance = private_functional_packing_keyswitch_additive_variance(
+        pfpksk_input_lwe_dimension,
+        SecretKeyDistribution::Binary,
+        pfpksk_base_log,
+        pfpksk_level_count,
+        pfpksk_noise,
+        ciphertext_modulus,
+    );
+
+    Variance(pbs_output_variance.0 + pfpks_variance.0)
+}
+
+/// Return the variance of the LWE ciphertext output by a vertical packing of a noiseless lookup
+/// table, controlled by `ggsw_count` GGSW ciphertexts of variance `ggsw_variance`.
+///
+/// The vertical packing selects the output with a CMux tree followed by a blind rotation, in both
+/// cases a single external product is computed per GGSW ciphertext on the path of the selected
+/// value.
+pub fn vertical_packing_output_variance(
+    ggsw_count: usize,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    ggsw_base_log: DecompositionBaseLog,
+    ggsw_level_count: DecompositionLevelCount,
+    ggsw_variance: Variance,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let external_product_variance = external_product_additive_variance(
+        glwe_dimension,
+        SecretKeyDistribution::Binary,
+        polynomial_size,
+        ggsw_base_log,
+        ggsw_level_count,
+        ggsw_variance,
+        ciphertext_modulus,
+    );
+
+    Variance(ggsw_count as f64 * external_product_variance.0)
+}
+
+/// Return the variance of the output of a WoP-PBS computing a lookup table on `extracted_bit_count`
+/// bits.
+///
+/// Each extracted bit is turned into a GGSW ciphertext by a circuit bootstrap, see
+/// [`circuit_bootstrap_output_variance`], the lookup table is then evaluated by a vertical
+/// packing, see [`vertical_packing_output_variance`]. The bit extraction does not impact the
+/// output noise as long as it is correct.
+#[allow(clippy::too_many_arguments)]
+pub fn wopbs_output_variance(
+    extracted_bit_count: usize,
+    pbs_output_variance: Variance,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    pfpksk_base_log: DecompositionBaseLog,
+    pfpksk_level_count: DecompositionLevelCount,
+    pfpksk_noise: impl DispersionParameter,
+    cbs_base_log: DecompositionBaseLog,
+    cbs_level_count: DecompositionLevelCount,
+    ciphertext_modulus: f64,
+) -> Variance {
+    let ggsw_variance = circuit_bootstrap_output_variance(
+        pbs_output_variance,
+        glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+        pfpksk_base_log,
+        pfpksk_level_count,
+        pfpksk_noise,
+        ciphertext_modulus,
+    );
+
+    vertical_packing_output_variance(
+        extracted_bit_count,
+        glwe_dimension,
+        polynomial_size,
+        cbs_base_log,
+        cbs_level_count,
+        ggsw_variance,
+        ciphertext_modulus,
+    )
+}
@@ -79,6 +79,10 @@ impl LweDimension {
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct LwePublicKeyZeroEncryptionCount(pub usize);
 
+/// The number of GLWE encryptions of 0 in a GLWE public key.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GlwePublicKeyZeroEncryptionCount(pub usize);
+
 /// The number of masks in a collection of LWE masks.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct LweMaskCount(pub usize);
@@ -237,12 +241,128 @@ impl LweBskGroupingFactor {
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct GgswPerLweMultiBitBskElement(pub usize);
 
+/// The number of parties holding a share of a secret key.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct ThresholdPartyCount(pub usize);
+
+/// The index of a party holding a share of a secret key, parties are indexed starting from 1.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ThresholdPartyIndex(pub usize);
+
+/// The scheme used to split a secret key in shares held by several parties.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub enum SecretSharingScheme {
+    /// The key is the sum of the shares, all the parties are required to decrypt.
+    Additive { party_count: ThresholdPartyCount },
+    /// The key is shared using Shamir's scheme, any `threshold` parties can decrypt.
+    ///
+    /// Shamir sharing requires the ciphertext modulus to be invertible modulo every integer up to
+    /// the number of parties, it is therefore not available for power of 2 moduli.
+    Shamir {
+        threshold: usize,
+        party_count: ThresholdPartyCount,
+    },
+}
+
+impl SecretSharingScheme {
+    /// Return the number of parties holding a share.
+    pub fn party_count(&self) -> ThresholdPartyCount {
+        match self {
+            Self::Additive { party_count } | Self::Shamir { party_count, .. } => *party_count,
+        }
+    }
+
+    /// Return the number of parties required to decrypt.
+    pub fn threshold(&self) -> usize {
+        match self {
+            Self::Additive { party_count } => party_count.0,
+            Self::Shamir { threshold, .. } => *threshold,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub enum EncryptionKeyChoice {
     Big,
     Small,
 }
 
+/// The distribution of the coefficients of a secret key.
+#[derive(Debug, Default, PartialEq, Copy, Clone, Serialize, Deserialize)]
+pub enum SecretKeyDistribution {
+    /// Coefficients uniformly sampled in `{0, 1}`.
+    #[default]
+    Binary,
+    /// Coefficients uniformly sampled in `{-1, 0, 1}`.
+    Ternary,
+    /// Coefficients sampled from a centered discrete gaussian distribution, the standard deviation
+    /// is expressed on the integers, i.e. it is not normalized by the ciphertext modulus.
+    Gaussian { std_dev: f64 },
+}
+
+impl SecretKeyDistribution {
+    /// Return the standard deviation of a coefficient of the secret key.
+    pub fn standard_dev(&self) -> f64 {
+        match self {
+            Self::Binary => 0.5,
+            Self::Ternary => (2.0f64 / 3.0).sqrt(),
+            Self::Gaussian { std_dev } => *std_dev,
+        }
+    }
+
+    /// Return the mean of the square of a coefficient of the secret key.
+    pub fn square_mean(&self) -> f64 {
+        match self {
+            Self::Binary => 0.5,
+            Self::Ternary => 2.0 / 3.0,
+            Self::Gaussian { std_dev } => std_dev * std_dev,
+        }
+    }
+
+    /// Return the entropy in bits of a coefficient of the secret key.
+    pub fn entropy(&self) -> f64 {
+        match self {
+            Self::Binary => 1.0,
+            Self::Ternary => 3.0f64.log2(),
+            // Entropy of a continuous gaussian, which is a good approximation for the discrete
+            // one as long as the standard deviation is not too small
+            Self::Gaussian { std_dev } => (0.5
+                * (2.0 * std::f64::consts::PI * std::f64::consts::E * std_dev * std_dev).log2())
+            .max(0.0),
+        }
+    }
+
+    /// Return the bound on the absolute value of a coefficient of the secret key.
+    ///
+    /// Gaussian coefficients are sampled from the distribution cut at this bound, which is
+    /// exceeded by a sample of the uncut distribution with a probability below $2^{-64}$.
+    pub fn max_abs_coefficient(&self) -> u64 {
+        match self {
+            Self::Binary | Self::Ternary => 1,
+            Self::Gaussian { std_dev } => {
+                // P(|X| > t) <= 2 exp(-t^2 / (2 std_dev^2)) <= 2^-64
+                let tail_factor = (2.0 * 65.0 * std::f64::consts::LN_2).sqrt();
+                (std_dev * tail_factor).ceil() as u64
+            }
+        }
+    }
+
+    /// Return the number of bit planes of the binary expansion of a secret key following this
+    /// distribution.
+    ///
+    /// The count only depends on the distribution and not on the sampled key, so that the size of
+    /// the expanded key and of the keys derived from it does not leak information on the key.
+    pub fn bit_plane_count(&self) -> SecretKeyBitPlaneCount {
+        let max_abs_coefficient = self.max_abs_coefficient();
+        SecretKeyBitPlaneCount(((u64::BITS - max_abs_coefficient.leading_zeros()) as usize).max(1))
+    }
+}
+
+/// The number of bit planes of the binary expansion of a non binary secret key, i.e. the number
+/// of bits required to represent the absolute value of its largest coefficient.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SecretKeyBitPlaneCount(pub usize);
+
 impl From<EncryptionKeyChoice> for PBSOrder {
     fn from(value: EncryptionKeyChoice) -> Self {
         match value {
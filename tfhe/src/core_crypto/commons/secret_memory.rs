@@ -0,0 +1,116 @@
+//! Module with helpers to handle the memory holding secret material.
+//!
+//! Entities holding secret material, like
+//! [`LweSecretKey`](crate::core_crypto::entities::LweSecretKey) or
+//! [`GlweSecretKey`](crate::core_crypto::entities::GlweSecretKey), wipe the memory they own when
+//! they are dropped, see
+//! [`Container::wipe_owned_memory`](crate::core_crypto::commons::traits::Container::wipe_owned_memory).
+//!
+//! When the `secret-key-memory-lock` feature is enabled on unix platforms, the memory holding
+//! secret material can additionally be locked in RAM with [`lock_memory`], preventing it from being
+//! written to the swap.
+
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// Overwrite the memory of the slice with zeros, in a way the compiler cannot optimize out, and
+/// unlock it if it was locked with [`lock_memory`].
+///
+/// Elements with drop glue are not plain data and are left untouched.
+///
+/// # Safety
+///
+/// The elements of the slice are overwritten byte by byte, they must not be read afterwards unless
+/// the all-zero bit pattern is a valid value for `T`.
+pub(crate) unsafe fn wipe_memory<T>(data: &mut [T]) {
+    if std::mem::needs_drop::<T>() {
+        return;
+    }
+
+    let ptr = data.as_mut_ptr().cast::<u8>();
+    for offset in 0..std::mem::size_of_val(data) {
+        // SAFETY: the pointer stays in the memory of the slice, which is valid for writes
+        std::ptr::write_volatile(ptr.add(offset), 0);
+    }
+    // Prevent the writes from being reordered after the deallocation of the memory
+    compiler_fence(Ordering::SeqCst);
+
+    unlock_memory(data);
+}
+
+/// Lock the memory pages holding `data` in RAM, preventing them from being written to the swap.
+///
+/// Return `false` if the memory could not be locked, either because the platform does not support
+/// it, because the `secret-key-memory-lock` feature is disabled or because the lock was refused by
+/// the OS, e.g. when going over the `RLIMIT_MEMLOCK` limit of the process.
+///
+/// The pages are unlocked when the secret material is dropped. As locks are not counted, this also
+/// unlocks other data sharing the first or last page with it.
+pub fn lock_memory<T>(data: &[T]) -> bool {
+    #[cfg(all(unix, feature = "secret-key-memory-lock"))]
+    {
+        if data.is_empty() {
+            return true;
+        }
+        // SAFETY: the pointer and length describe the memory of the slice
+        unsafe { libc::mlock(data.as_ptr().cast(), std::mem::size_of_val(data)) == 0 }
+    }
+    #[cfg(not(all(unix, feature = "secret-key-memory-lock")))]
+    {
+        let _ = data;
+        false
+    }
+}
+
+fn unlock_memory<T>(data: &[T]) {
+    #[cfg(all(unix, feature = "secret-key-memory-lock"))]
+    if !data.is_empty() {
+        // SAFETY: the pointer and length describe the memory of the slice, unlocking pages that
+        // were not locked is a no-op
+        unsafe {
+            libc::munlock(data.as_ptr().cast(), std::mem::size_of_val(data));
+        }
+    }
+    #[cfg(not(all(unix, feature = "secret-key-memory-lock")))]
+    let _ = data;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core_crypto::commons::traits::Container;
+    use crate::core_crypto::entities::LweSecretKey;
+
+    #[test]
+    fn wipe_owned_memory_zeroes_owned_containers() {
+        let mut data = vec![0xFFu64; 17];
+        // SAFETY: the all-zero pattern is a valid u64
+        unsafe { data.wipe_owned_memory() };
+        assert!(data.iter().all(|&x| x == 0));
+
+        // The spare capacity of the vector is wiped as well
+        let mut data = vec![0xFFu64; 32];
+        data.truncate(17);
+        // SAFETY: the all-zero pattern is a valid u64
+        unsafe { data.wipe_owned_memory() };
+        // SAFETY: the whole allocation was initialized, first with 0xFF then with zeros
+        unsafe { data.set_len(32) };
+        assert!(data.iter().all(|&x| x == 0));
+
+        let mut data = vec![0xFFu64; 17];
+        let mut view = data.as_mut_slice();
+        // SAFETY: the all-zero pattern is a valid u64
+        unsafe { Container::wipe_owned_memory(&mut view) };
+        assert!(data.iter().all(|&x| x == 0xFF));
+    }
+
+    #[test]
+    fn secret_key_debug_is_redacted() {
+        let secret = 0x1234_5678u64;
+        let key = LweSecretKey::from_container(vec![secret; 4]);
+
+        let debug = format!("{key:?}");
+        assert!(!debug.contains(&secret.to_string()));
+
+        // The container handed over by the key is not wiped
+        assert_eq!(key.into_container(), vec![secret; 4]);
+    }
+}
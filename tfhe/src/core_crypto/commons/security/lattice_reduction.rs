@@ -0,0 +1,31 @@
+//! Cost model of the BKZ lattice reduction algorithm.
+
+use std::f64::consts::{E, PI};
+
+/// The smallest block size handled by the cost model, the asymptotic formula of the root Hermite
+/// factor is not accurate for smaller block sizes.
+pub(crate) const MIN_BLOCK_SIZE: usize = 40;
+
+/// Return the base 2 logarithm of the root Hermite factor $\delta$ reached by BKZ with block size
+/// $\beta$, following the asymptotic formula
+/// $\delta = \left(\frac{\beta}{2\pi e}(\pi\beta)^{\frac{1}{\beta}}\right)^{\frac{1}{2(\beta-1)}}$.
+pub(crate) fn log2_root_hermite_factor(block_size: usize) -> f64 {
+    let beta = block_size as f64;
+
+    ((PI * beta).log2() / beta + (beta / (2.0 * PI * E)).log2()) / (2.0 * (beta - 1.0))
+}
+
+/// Return the base 2 logarithm of the number of operations needed to run BKZ with block size
+/// $\beta$ on a lattice of dimension $d$.
+///
+/// The SVP oracle is a classical sieve costing $2^{0.292\beta + 16.4}$ operations, called
+/// $8d$ times.
+pub(crate) fn log2_bkz_cost(block_size: usize, lattice_dimension: usize) -> f64 {
+    0.292 * block_size as f64 + 16.4 + (8.0 * lattice_dimension as f64).log2()
+}
+
+/// Return the base 2 logarithm of the number of short vectors output by a sieve in dimension
+/// $\beta$, which can be used by the dual attack without running the reduction again.
+pub(crate) fn log2_sieve_output_count(block_size: usize) -> f64 {
+    0.2075 * block_size as f64
+}
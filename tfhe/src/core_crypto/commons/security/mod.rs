@@ -0,0 +1,319 @@
+//! Module containing an estimator of the security of LWE and GLWE parameters.
+//!
+//! The security is estimated against the main lattice attacks on LWE, and is expressed as the
+//! base 2 logarithm of the number of operations needed by the cheapest one:
+//!
+//! + the primal attack, solving the unique-SVP instance obtained by embedding the LWE samples into
+//!   a lattice, see [`primal_usvp_cost`];
+//! + the dual attack, distinguishing the LWE samples from uniform ones by finding short vectors of
+//!   the dual lattice, see [`dual_cost`];
+//! + the hybrid attack, guessing some coefficients of the secret key with a meet-in-the-middle
+//!   search and running the primal attack on the remaining ones, see [`hybrid_cost`].
+//!
+//! The cost of the lattice reduction is estimated with the classical sieving cost model
+//! $2^{0.292\beta + 16.4}$ for the SVP oracle in dimension $\beta$, and the root Hermite factor
+//! reached by BKZ follows the geometric series assumption. These are the usual models of the
+//! literature, but the resulting estimates are simplified versions of the ones of the reference
+//! estimators: they are meant to reject clearly insecure custom parameters, not to replace a
+//! careful security analysis. GLWE parameters are estimated as LWE parameters of dimension
+//! $k \cdot N$, the ring structure is not exploited by any known attack.
+//!
+//! ```
+//! use tfhe::core_crypto::commons::security::estimate_lwe_security;
+//! use tfhe::core_crypto::prelude::*;
+//!
+//! let estimate = estimate_lwe_security(
+//!     LweDimension(742),
+//!     StandardDev(0.000007069849454709433),
+//!     SecretKeyDistribution::Binary,
+//!     CiphertextModulus::<u64>::new_native(),
+//! );
+//!
+//! assert!(estimate.security_bits() > 120.0);
+//!
+//! // Removing the noise breaks the security
+//! let estimate = estimate_lwe_security(
+//!     LweDimension(742),
+//!     StandardDev(2.0f64.powi(-60)),
+//!     SecretKeyDistribution::Binary,
+//!     CiphertextModulus::<u64>::new_native(),
+//! );
+//!
+//! assert!(estimate.security_bits() < 80.0);
+//! ```
+
+use super::dispersion::DispersionParameter;
+use super::parameters::{GlweDimension, LweDimension, PolynomialSize, SecretKeyDistribution};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+
+mod lattice_reduction;
+
+use lattice_reduction::{
+    log2_bkz_cost, log2_root_hermite_factor, log2_sieve_output_count, MIN_BLOCK_SIZE,
+};
+
+/// The LWE problem whose security is estimated.
+///
+/// The noise standard deviation is normalized on the torus, as for the noise parameters of the
+/// library.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LweSecurityParameters {
+    pub lwe_dimension: LweDimension,
+    pub noise_std_dev: f64,
+    pub secret_key_distribution: SecretKeyDistribution,
+    pub log2_modulus: f64,
+}
+
+impl LweSecurityParameters {
+    /// Return the base 2 logarithm of the standard deviation of the noise on the integers.
+    fn log2_integer_noise_std_dev(&self) -> f64 {
+        self.noise_std_dev.log2() + self.log2_modulus
+    }
+
+    /// Return the base 2 logarithm of the factor used to scale the secret key coordinates of the
+    /// attacked lattices so that they have the same standard deviation as the noise.
+    fn log2_secret_scaling(&self) -> f64 {
+        (self.log2_integer_noise_std_dev() - self.secret_key_distribution.standard_dev().log2())
+            .max(0.0)
+    }
+
+    fn max_block_size(&self) -> usize {
+        (2 * self.lwe_dimension.0 + 1).max(MIN_BLOCK_SIZE)
+    }
+}
+
+/// The estimated security of a set of parameters against each attack, in bits.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SecurityEstimate {
+    pub primal_usvp: f64,
+    pub dual: f64,
+    pub hybrid: f64,
+}
+
+impl SecurityEstimate {
+    /// Return the estimated security in bits, i.e. the cost of the cheapest attack.
+    pub fn security_bits(&self) -> f64 {
+        self.primal_usvp.min(self.dual).min(self.hybrid)
+    }
+}
+
+/// Estimate the security of the LWE problem with the given parameters.
+///
+/// `noise_parameter` is the noise of the encryption, normalized on the torus.
+pub fn estimate_lwe_security<Scalar: UnsignedInteger>(
+    lwe_dimension: LweDimension,
+    noise_parameter: impl DispersionParameter,
+    secret_key_distribution: SecretKeyDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> SecurityEstimate {
+    estimate_security(LweSecurityParameters {
+        lwe_dimension,
+        noise_std_dev: noise_parameter.get_standard_dev(),
+        secret_key_distribution,
+        log2_modulus: ciphertext_modulus.raw_modulus_float().log2(),
+    })
+}
+
+/// Estimate the security of the GLWE problem with the given parameters, as the security of the
+/// LWE problem of dimension $k \cdot N$.
+///
+/// `noise_parameter` is the noise of the encryption, normalized on the torus.
+pub fn estimate_glwe_security<Scalar: UnsignedInteger>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    noise_parameter: impl DispersionParameter,
+    secret_key_distribution: SecretKeyDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> SecurityEstimate {
+    estimate_lwe_security(
+        glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+        noise_parameter,
+        secret_key_distribution,
+        ciphertext_modulus,
+    )
+}
+
+/// Estimate the security of the given LWE problem against all the supported attacks.
+pub fn estimate_security(parameters: LweSecurityParameters) -> SecurityEstimate {
+    SecurityEstimate {
+        primal_usvp: primal_usvp_cost(parameters),
+        dual: dual_cost(parameters),
+        hybrid: hybrid_cost(parameters),
+    }
+}
+
+/// Return the base 2 logarithm of the cost of the primal attack.
+///
+/// The attack succeeds for the smallest BKZ block size $\beta$ such that the projection of the
+/// embedded short vector on the last $\beta$ Gram-Schmidt vectors is shorter than the
+/// corresponding Gram-Schmidt norm, for the best number of samples. Returns [`f64::INFINITY`] if
+/// no block size works.
+pub fn primal_usvp_cost(parameters: LweSecurityParameters) -> f64 {
+    let n = parameters.lwe_dimension.0;
+
+    if n == 0 {
+        return 0.0;
+    }
+
+    let n_f64 = n as f64;
+    let log2_q = parameters.log2_modulus;
+    let log2_sigma = parameters.log2_integer_noise_std_dev();
+    let log2_nu = parameters.log2_secret_scaling();
+
+    for block_size in MIN_BLOCK_SIZE..=parameters.max_block_size() {
+        let log2_delta = log2_root_hermite_factor(block_size);
+
+        // The lattice of dimension d = m + n + 1 has a volume of q^m * nu^n, the dimension
+        // maximizing the Gram-Schmidt norm at index d - beta is sqrt(c / log(delta))
+        let c = (n_f64 + 1.0) * log2_q - n_f64 * log2_nu;
+        let optimal_dimension = (c / log2_delta).sqrt() as usize;
+        let dimension = optimal_dimension.max(n + 1).max(block_size);
+
+        let sample_count = (dimension - n - 1) as f64;
+        let log2_volume = sample_count * log2_q + n_f64 * log2_nu;
+
+        let lhs = log2_sigma + 0.5 * (block_size as f64).log2();
+        let rhs = (2.0 * block_size as f64 - dimension as f64 - 1.0) * log2_delta
+            + log2_volume / dimension as f64;
+
+        if lhs <= rhs {
+            return log2_bkz_cost(block_size, dimension);
+        }
+    }
+
+    f64::INFINITY
+}
+
+/// Return the base 2 logarithm of the cost of the dual attack.
+///
+/// A short vector of the (scaled) dual lattice found by BKZ gives a sample whose noise has a
+/// standard deviation $\tau$ on the torus, the distinguishing advantage of a sample is
+/// $\varepsilon = e^{-2\pi^2\tau^2}$ and $\frac{1}{\varepsilon^2}$ samples are needed. A sieve
+/// outputs many short vectors, so the reduction only needs to be repeated if more samples are
+/// needed than the sieve outputs.
+pub fn dual_cost(parameters: LweSecurityParameters) -> f64 {
+    let n = parameters.lwe_dimension.0;
+
+    if n == 0 {
+        return 0.0;
+    }
+
+    let n_f64 = n as f64;
+    let log2_q = parameters.log2_modulus;
+    let log2_sigma = parameters.log2_integer_noise_std_dev();
+    let log2_nu = parameters.log2_secret_scaling();
+
+    let mut best_cost = f64::INFINITY;
+
+    for block_size in MIN_BLOCK_SIZE..=parameters.max_block_size() {
+        let log2_delta = log2_root_hermite_factor(block_size);
+
+        // The scaled dual lattice of dimension d = m + n has a volume of (q / nu)^n, the
+        // dimension minimizing the length of the shortest vector found is sqrt(c / log(delta))
+        let c = n_f64 * (log2_q - log2_nu);
+        let optimal_dimension = (c / log2_delta).sqrt() as usize;
+        let dimension = optimal_dimension.max(n).max(block_size);
+
+        let log2_reduction_cost = log2_bkz_cost(block_size, dimension);
+        if log2_reduction_cost >= best_cost {
+            break;
+        }
+
+        let log2_vector_length = (dimension as f64 - 1.0) * log2_delta + c / dimension as f64;
+        let log2_tau = log2_vector_length + log2_sigma - log2_q;
+        let tau = 2.0f64.powf(log2_tau);
+
+        let log2_sample_count =
+            4.0 * std::f64::consts::PI.powi(2) * tau * tau * std::f64::consts::LOG2_E;
+        let log2_repetitions = (log2_sample_count - log2_sieve_output_count(block_size)).max(0.0);
+
+        best_cost = best_cost.min(log2_reduction_cost + log2_repetitions);
+    }
+
+    best_cost
+}
+
+/// Return the base 2 logarithm of the cost of the hybrid attack.
+///
+/// The attacker reduces the lattice built from $n - \zeta$ coefficients of the secret key, then
+/// guesses the $\zeta$ remaining coefficients with a meet-in-the-middle search, whose cost is
+/// taken as the square root of the size of the search space. Each guess is checked with Babai's
+/// nearest plane algorithm, which must be reliable enough for the two halves of the search to
+/// match, see [`nearest_plane_reduction_cost`]. The costs of the two steps are added, and the best
+/// $\zeta$ is kept.
+pub fn hybrid_cost(parameters: LweSecurityParameters) -> f64 {
+    let n = parameters.lwe_dimension.0;
+    let entropy = parameters.secret_key_distribution.entropy();
+
+    let step = (n / 128).max(1);
+
+    (0..n)
+        .step_by(step)
+        .map(|guessed_coefficients| {
+            let reduced_parameters = LweSecurityParameters {
+                lwe_dimension: LweDimension(n - guessed_coefficients),
+                ..parameters
+            };
+
+            let log2_lattice_cost = nearest_plane_reduction_cost(reduced_parameters);
+            // Each step of the search runs the nearest plane algorithm, in quadratic time
+            let log2_nearest_plane_cost = 2.0 * (2.0 * n as f64).log2();
+            let log2_search_cost =
+                0.5 * guessed_coefficients as f64 * entropy + log2_nearest_plane_cost;
+
+            log2_sum(log2_lattice_cost, log2_search_cost)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Return the base 2 logarithm of the cost of reducing the lattice of the given LWE problem
+/// until Babai's nearest plane algorithm recovers the noise with a high probability.
+///
+/// The smallest Gram-Schmidt norm of the reduced basis must be larger than twice the standard
+/// deviation of the noise scaled by $\sqrt{d}$, so that the rounding is correct on all the $d$
+/// coordinates at once.
+fn nearest_plane_reduction_cost(parameters: LweSecurityParameters) -> f64 {
+    let n = parameters.lwe_dimension.0;
+
+    if n == 0 {
+        return 0.0;
+    }
+
+    let n_f64 = n as f64;
+    let log2_q = parameters.log2_modulus;
+    let log2_sigma = parameters.log2_integer_noise_std_dev();
+    let log2_nu = parameters.log2_secret_scaling();
+
+    for block_size in MIN_BLOCK_SIZE..=parameters.max_block_size() {
+        let log2_delta = log2_root_hermite_factor(block_size);
+
+        // The lattice of dimension d = m + n has a volume of q^m * nu^n, the dimension
+        // maximizing the smallest Gram-Schmidt norm is sqrt(c / log(delta))
+        let c = n_f64 * (log2_q - log2_nu);
+        let optimal_dimension = (c / log2_delta).sqrt() as usize;
+        let dimension = optimal_dimension.max(n).max(block_size);
+
+        let sample_count = (dimension - n) as f64;
+        let log2_volume = sample_count * log2_q + n_f64 * log2_nu;
+
+        let log2_smallest_norm = -(dimension as f64) * log2_delta + log2_volume / dimension as f64;
+
+        if log2_smallest_norm >= log2_sigma + 0.5 * (dimension as f64).log2() + 1.0 {
+            return log2_bkz_cost(block_size, dimension);
+        }
+    }
+
+    f64::INFINITY
+}
+
+/// Return $\log_2(2^a + 2^b)$.
+fn log2_sum(a: f64, b: f64) -> f64 {
+    let (max, min) = if a > b { (a, b) } else { (b, a) };
+
+    if max == f64::INFINITY {
+        return max;
+    }
+
+    max + (1.0 + 2.0f64.powf(min - max)).log2()
+}
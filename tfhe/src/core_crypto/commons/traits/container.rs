@@ -1,5 +1,8 @@
 //! Module with traits pertaining to container manipulation.
 
+use crate::core_crypto::commons::secret_memory::wipe_memory;
+use std::mem::MaybeUninit;
+
 /// A trait to manipulate various immutable container types transparently.
 pub trait Container: AsRef<[Self::Element]> {
     type Element;
@@ -7,6 +10,17 @@ pub trait Container: AsRef<[Self::Element]> {
     fn container_len(&self) -> usize {
         self.as_ref().len()
     }
+
+    /// Overwrite the memory owned by the container with zeros, containers borrowing their data
+    /// leave it untouched.
+    ///
+    /// This is called when entities holding secret material are dropped.
+    ///
+    /// # Safety
+    ///
+    /// The elements of the container must not be read afterwards unless the all-zero bit pattern is
+    /// a valid value for them.
+    unsafe fn wipe_owned_memory(&mut self) {}
 }
 
 /// A trait to manipulate various mutable container types transparently.
@@ -20,6 +34,18 @@ impl<T> ContainerMut for [T] {}
 
 impl<T> Container for Vec<T> {
     type Element = T;
+
+    unsafe fn wipe_owned_memory(&mut self) {
+        if std::mem::needs_drop::<T>() {
+            return;
+        }
+        // Wipe the whole allocation, the spare capacity may still hold values that were written
+        // before the vector got truncated
+        wipe_memory(std::slice::from_raw_parts_mut(
+            self.as_mut_ptr().cast::<MaybeUninit<T>>(),
+            self.capacity(),
+        ));
+    }
 }
 
 impl<T> ContainerMut for Vec<T> {}
@@ -36,18 +62,37 @@ impl<T> ContainerMut for &mut [T] {}
 
 impl<T> Container for aligned_vec::ABox<[T]> {
     type Element = T;
+
+    unsafe fn wipe_owned_memory(&mut self) {
+        wipe_memory(self.as_mut());
+    }
 }
 
 impl<T> ContainerMut for aligned_vec::ABox<[T]> {}
 
 impl<T> Container for Box<[T]> {
     type Element = T;
+
+    unsafe fn wipe_owned_memory(&mut self) {
+        wipe_memory(self.as_mut());
+    }
 }
 
 impl<T> ContainerMut for Box<[T]> {}
 
 impl<T> Container for aligned_vec::AVec<T> {
     type Element = T;
+
+    unsafe fn wipe_owned_memory(&mut self) {
+        if std::mem::needs_drop::<T>() {
+            return;
+        }
+        // Same as for Vec, wipe the spare capacity as well
+        wipe_memory(std::slice::from_raw_parts_mut(
+            self.as_mut_ptr().cast::<MaybeUninit<T>>(),
+            self.capacity(),
+        ));
+    }
 }
 
 impl<T> ContainerMut for aligned_vec::AVec<T> {}
@@ -0,0 +1,194 @@
+//! Module containing the definition of the CompressedModulusSwitchedGlweCiphertext.
+
+use crate::core_crypto::algorithms::misc::modulus_switch;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+use serde::{Deserialize, Serialize};
+
+/// A [`GLWE ciphertext`](`GlweCiphertext`) whose coefficients have been modulus switched to a
+/// smaller power of 2 modulus and packed to save storage space.
+///
+/// Only the first `bodies_count` coefficients of the body are kept, which is useful when the
+/// ciphertext is the output of a packing keyswitch of less than `polynomial_size` LWE
+/// ciphertexts: the other coefficients of the body carry no information.
+///
+/// # Note
+///
+/// The modulus switch adds noise to the ciphertext, the storage modulus must be chosen
+/// accordingly. Only the native modulus is supported for the uncompressed ciphertext.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedModulusSwitchedGlweCiphertext<Scalar: UnsignedInteger> {
+    packed_integers: PackedIntegers<Scalar>,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    bodies_count: LweCiphertextCount,
+    uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedTorus> CompressedModulusSwitchedGlweCiphertext<Scalar> {
+    /// Compress a [`GLWE ciphertext`](`GlweCiphertext`) by switching its modulus to
+    /// $2^{log\_modulus}$ and packing the result.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    /// let log_modulus = CiphertextModulusLog(12);
+    ///
+    /// // Create the PRNG
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+    /// let mut secret_generator =
+    ///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    ///
+    /// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// // Encode 4 bits messages in the MSBs
+    /// let msgs: Vec<u64> = (0..polynomial_size.0 as u64).map(|i| i % 16).collect();
+    /// let plaintext_list =
+    ///     PlaintextList::from_container(msgs.iter().map(|msg| msg << 60).collect::<Vec<_>>());
+    ///
+    /// let mut glwe = GlweCiphertext::new(
+    ///     0u64,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// encrypt_glwe_ciphertext(
+    ///     &glwe_secret_key,
+    ///     &mut glwe,
+    ///     &plaintext_list,
+    ///     glwe_modular_std_dev,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// // Only keep the first 100 coefficients of the body
+    /// let compressed = CompressedModulusSwitchedGlweCiphertext::compress(
+    ///     &glwe,
+    ///     log_modulus,
+    ///     LweCiphertextCount(100),
+    /// );
+    ///
+    /// let extracted = compressed.extract();
+    ///
+    /// let mut decrypted = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+    ///
+    /// decrypt_glwe_ciphertext(&glwe_secret_key, &extracted, &mut decrypted);
+    ///
+    /// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+    ///
+    /// for (decrypted, msg) in decrypted.iter().zip(msgs.iter()).take(100) {
+    ///     assert_eq!(decomposer.closest_representable(*decrypted.0) >> 60, *msg);
+    /// }
+    /// ```
+    pub fn compress<Cont: Container<Element = Scalar>>(
+        ct: &GlweCiphertext<Cont>,
+        log_modulus: CiphertextModulusLog,
+        bodies_count: LweCiphertextCount,
+    ) -> Self {
+        let uncompressed_ciphertext_modulus = ct.ciphertext_modulus();
+
+        assert!(
+            uncompressed_ciphertext_modulus.is_native_modulus(),
+            "CompressedModulusSwitchedGlweCiphertext only supports the native modulus"
+        );
+        assert!(
+            log_modulus.0 < Scalar::BITS,
+            "The storage modulus must be smaller than the ciphertext modulus"
+        );
+
+        let polynomial_size = ct.polynomial_size();
+
+        assert!(
+            bodies_count.0 <= polynomial_size.0,
+            "Cannot keep more bodies ({}) than the polynomial size ({})",
+            bodies_count.0,
+            polynomial_size.0,
+        );
+
+        let glwe_dimension = ct.glwe_size().to_glwe_dimension();
+
+        let modulus_switched: Vec<Scalar> = ct
+            .as_ref()
+            .iter()
+            .take(glwe_dimension.0 * polynomial_size.0 + bodies_count.0)
+            .map(|&coeff| modulus_switch(coeff, log_modulus))
+            .collect();
+
+        let packed_integers = PackedIntegers::pack(&modulus_switched, log_modulus);
+
+        Self {
+            packed_integers,
+            glwe_dimension,
+            polynomial_size,
+            bodies_count,
+            uncompressed_ciphertext_modulus,
+        }
+    }
+
+    /// Convert a [`CompressedModulusSwitchedGlweCiphertext`] back to a
+    /// [`GLWE ciphertext`](`GlweCiphertext`) under the native modulus.
+    ///
+    /// The body coefficients which were not kept during the compression are set to 0.
+    ///
+    /// See [`CompressedModulusSwitchedGlweCiphertext::compress`] for usage.
+    pub fn extract(&self) -> GlweCiphertextOwned<Scalar> {
+        let log_modulus = self.packed_integers.log_modulus().0;
+
+        let glwe_size = self.glwe_dimension.to_glwe_size();
+
+        let container: Vec<Scalar> = self
+            .packed_integers
+            .unpack()
+            // Put the information back in the MSBs
+            .map(|coeff| coeff << (Scalar::BITS - log_modulus))
+            .chain(std::iter::repeat(Scalar::ZERO))
+            .take(glwe_size.0 * self.polynomial_size.0)
+            .collect();
+
+        GlweCiphertextOwned::from_container(
+            container,
+            self.polynomial_size,
+            self.uncompressed_ciphertext_modulus,
+        )
+    }
+
+    /// Return the [`GlweDimension`] of the compressed ciphertext.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    /// Return the [`PolynomialSize`] of the compressed ciphertext.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the number of body coefficients kept in the compressed ciphertext.
+    pub fn bodies_count(&self) -> LweCiphertextCount {
+        self.bodies_count
+    }
+
+    /// Return the log2 of the modulus used to store the compressed ciphertext.
+    pub fn log_modulus(&self) -> CiphertextModulusLog {
+        self.packed_integers.log_modulus()
+    }
+
+    /// Return the [`CiphertextModulus`] of the ciphertext before compression.
+    pub fn uncompressed_ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.uncompressed_ciphertext_modulus
+    }
+}
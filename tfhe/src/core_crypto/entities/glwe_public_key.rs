@@ -0,0 +1,198 @@
+//! Module containing the definition of the [`GlwePublicKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+// A GlwePublicKey is literally a GlweCiphertextList, so we wrap a GlweCiphertextList and use
+// Deref to have access to all the primitives of the GlweCiphertextList easily
+
+/// A [`public GLWE encryption key`](`GlwePublicKey`).
+///
+/// This is a wrapper type of [`GlweCiphertextList`], [`std::ops::Deref`] and
+/// [`std::ops::DerefMut`] are implemented to dereference to the underlying [`GlweCiphertextList`]
+/// for ease of use. See [`GlweCiphertextList`] for additional methods.
+///
+/// # Formal Definition
+///
+/// ## GLWE Public Key
+///
+/// A GLWE public key contains $m$ GLWE encryptions of the zero polynomial under a secret key
+/// $\vec{S} \in \mathcal{R}\_q^k$ where $k$ is the GLWE dimension of the ciphertexts contained in
+/// the public key.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlwePublicKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    glwe_list: GlweCiphertextList<C>,
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref for GlwePublicKey<C> {
+    type Target = GlweCiphertextList<C>;
+
+    fn deref(&self) -> &GlweCiphertextList<C> {
+        &self.glwe_list
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> std::ops::DerefMut
+    for GlwePublicKey<C>
+{
+    fn deref_mut(&mut self) -> &mut GlweCiphertextList<C> {
+        &mut self.glwe_list
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlwePublicKey<C> {
+    /// Create a [`GlwePublicKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlwePublicKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_public_key`] using this key as output.
+    ///
+    /// This docstring exhibits [`GlwePublicKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlwePublicKey creation
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let zero_encryption_count = GlwePublicKeyZeroEncryptionCount(3);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlwePublicKey
+    /// let glwe_public_key = GlwePublicKey::new(
+    ///     0u64,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     zero_encryption_count,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// // These are methods from GlweCiphertextList
+    /// assert_eq!(glwe_public_key.glwe_size(), glwe_size);
+    /// assert_eq!(glwe_public_key.polynomial_size(), polynomial_size);
+    /// // This is a method from GlwePublicKey
+    /// assert_eq!(
+    ///     glwe_public_key.zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    /// assert_eq!(glwe_public_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = glwe_public_key.into_container();
+    ///
+    /// // Recreate a public key using from_container
+    /// let glwe_public_key = GlwePublicKey::from_container(
+    ///     underlying_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(glwe_public_key.glwe_size(), glwe_size);
+    /// assert_eq!(glwe_public_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     glwe_public_key.zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    /// assert_eq!(glwe_public_key.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a GlwePublicKey"
+        );
+        Self {
+            glwe_list: GlweCiphertextList::from_container(
+                container,
+                glwe_size,
+                polynomial_size,
+                ciphertext_modulus,
+            ),
+        }
+    }
+
+    /// Return the [`GlwePublicKeyZeroEncryptionCount`] of the [`GlwePublicKey`].
+    ///
+    /// See [`GlwePublicKey::from_container`] for usage.
+    pub fn zero_encryption_count(&self) -> GlwePublicKeyZeroEncryptionCount {
+        GlwePublicKeyZeroEncryptionCount(self.glwe_ciphertext_count().0)
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlwePublicKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.glwe_list.into_container()
+    }
+
+    /// Return a view of the [`GlwePublicKey`]. This is useful if an algorithm takes a view by
+    /// value.
+    pub fn as_view(&self) -> GlwePublicKey<&'_ [Scalar]> {
+        GlwePublicKey::from_container(
+            self.as_ref(),
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.ciphertext_modulus(),
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlwePublicKey<C> {
+    /// Mutable variant of [`GlwePublicKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlwePublicKey<&'_ mut [Scalar]> {
+        let glwe_size = self.glwe_size();
+        let polynomial_size = self.polynomial_size();
+        let ciphertext_modulus = self.ciphertext_modulus();
+        GlwePublicKey::from_container(
+            self.as_mut(),
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`GlwePublicKey`] owning the memory for its own storage.
+pub type GlwePublicKeyOwned<Scalar> = GlwePublicKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger> GlwePublicKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlwePublicKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlwePublicKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_public_key`] using this key as output.
+    ///
+    /// See [`GlwePublicKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_ciphertext_size(glwe_size, polynomial_size) * zero_encryption_count.0
+            ],
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
@@ -0,0 +1,386 @@
+//! Module containing the definition of the [`GlweRelinearizationKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::glwe_ciphertext::glwe_ciphertext_size;
+use crate::core_crypto::entities::glwe_ciphertext_list::{
+    GlweCiphertextListCreationMetadata, GlweCiphertextListMutView, GlweCiphertextListView,
+};
+
+/// A relinearization key allowing to turn the output of a
+/// [`GLWE tensor product`](`crate::core_crypto::algorithms::glwe_tensor_product`), encrypted under
+/// the tensor product of a [`GLWE secret key`](`super::GlweSecretKey`) with itself, back into a
+/// [`GLWE ciphertext`](`super::GlweCiphertext`) encrypted under the original
+/// [`GLWE secret key`](`super::GlweSecretKey`).
+///
+/// For a secret key $(S\_1, \ldots, S\_k)$ the key contains, for each pair $i \leq j$ in
+/// lexicographic order, a list of GLWE encryptions of $S\_i S\_j$ scaled by the powers of the
+/// decomposition base.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlweRelinearizationKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweRelinearizationKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweRelinearizationKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the number of products $S\_i S\_j$ with $i \leq j$ encrypted in a
+/// [`GlweRelinearizationKey`] for a secret key of the given [`GlweDimension`].
+pub fn glwe_relinearization_key_secret_product_count(glwe_dimension: GlweDimension) -> usize {
+    glwe_dimension.0 * (glwe_dimension.0 + 1) / 2
+}
+
+/// Return the number of elements in an encryption of a product of two [`super::GlweSecretKey`]
+/// polynomials for a [`GlweRelinearizationKey`] given a [`DecompositionLevelCount`], [`GlweSize`]
+/// and [`PolynomialSize`].
+pub fn glwe_relinearization_key_secret_product_encrypted_size(
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> usize {
+    // One ciphertext per level encrypted under the key
+    decomp_level_count.0 * glwe_ciphertext_size(glwe_size, polynomial_size)
+}
+
+/// Return the number of elements in a [`GlweRelinearizationKey`] given a
+/// [`DecompositionLevelCount`], [`GlweSize`] and [`PolynomialSize`].
+pub fn glwe_relinearization_key_size(
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> usize {
+    glwe_relinearization_key_secret_product_count(glwe_size.to_glwe_dimension())
+        * glwe_relinearization_key_secret_product_encrypted_size(
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweRelinearizationKey<C> {
+    /// Create a [`GlweRelinearizationKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweRelinearizationKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_relinearization_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`GlweRelinearizationKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweRelinearizationKey creation
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(8);
+    /// let decomp_level_count = DecompositionLevelCount(4);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweRelinearizationKey
+    /// let rlk = GlweRelinearizationKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(rlk.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(rlk.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(rlk.glwe_size(), glwe_dimension.to_glwe_size());
+    /// assert_eq!(rlk.polynomial_size(), polynomial_size);
+    /// assert_eq!(rlk.secret_product_count(), 3);
+    /// assert_eq!(rlk.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = rlk.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let rlk = GlweRelinearizationKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(rlk.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(rlk.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(rlk.glwe_size(), glwe_dimension.to_glwe_size());
+    /// assert_eq!(rlk.polynomial_size(), polynomial_size);
+    /// assert_eq!(rlk.secret_product_count(), 3);
+    /// assert_eq!(rlk.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a GlweRelinearizationKey"
+        );
+        assert_eq!(
+            container.container_len(),
+            glwe_relinearization_key_size(decomp_level_count, glwe_size, polynomial_size),
+            "The provided container length is not valid. \
+        It needs to be equal to: {}. Got container length: {} and decomp_level_count: \
+        {decomp_level_count:?}, glwe_size: {glwe_size:?}, polynomial_size: \
+        {polynomial_size:?}.",
+            glwe_relinearization_key_size(decomp_level_count, glwe_size, polynomial_size),
+            container.container_len()
+        );
+
+        Self {
+            data: container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Return the [`GlweSize`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Return the [`PolynomialSize`] of the [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the number of products of secret key polynomials encrypted in the
+    /// [`GlweRelinearizationKey`].
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn secret_product_count(&self) -> usize {
+        glwe_relinearization_key_secret_product_count(self.glwe_dimension())
+    }
+
+    /// Return the number of elements in an encryption of a product of two secret key polynomials
+    /// of the current [`GlweRelinearizationKey`].
+    pub fn secret_product_encrypted_size(&self) -> usize {
+        glwe_relinearization_key_secret_product_encrypted_size(
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+        )
+    }
+
+    /// Return a view of the [`GlweRelinearizationKey`]. This is useful if an algorithm takes a view
+    /// by value.
+    pub fn as_view(&self) -> GlweRelinearizationKeyView<'_, Scalar> {
+        GlweRelinearizationKey::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.glwe_size,
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    pub fn as_glwe_ciphertext_list(&self) -> GlweCiphertextListView<'_, Scalar> {
+        GlweCiphertextListView::from_container(
+            self.as_ref(),
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.ciphertext_modulus(),
+        )
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweRelinearizationKey<C> {
+    /// Mutable variant of [`GlweRelinearizationKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweRelinearizationKeyMutView<'_, Scalar> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        GlweRelinearizationKey::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_glwe_ciphertext_list(&mut self) -> GlweCiphertextListMutView<'_, Scalar> {
+        let glwe_size = self.glwe_size();
+        let polynomial_size = self.polynomial_size();
+        let ciphertext_modulus = self.ciphertext_modulus();
+        GlweCiphertextListMutView::from_container(
+            self.as_mut(),
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`GlweRelinearizationKey`] owning the memory for its own storage.
+pub type GlweRelinearizationKeyOwned<Scalar> = GlweRelinearizationKey<Vec<Scalar>>;
+/// A [`GlweRelinearizationKey`] immutably borrowing memory for its own storage.
+pub type GlweRelinearizationKeyView<'data, Scalar> = GlweRelinearizationKey<&'data [Scalar]>;
+/// A [`GlweRelinearizationKey`] mutably borrowing memory for its own storage.
+pub type GlweRelinearizationKeyMutView<'data, Scalar> = GlweRelinearizationKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweRelinearizationKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweRelinearizationKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweRelinearizationKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_relinearization_key`] using this key as
+    /// output.
+    ///
+    /// See [`GlweRelinearizationKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_relinearization_key_size(decomp_level_count, glwe_size, polynomial_size)
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ContiguousEntityContainer
+    for GlweRelinearizationKey<C>
+{
+    type Element = C::Element;
+
+    type EntityViewMetadata = GlweCiphertextListCreationMetadata<Self::Element>;
+
+    type EntityView<'this>
+        = GlweCiphertextListView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    type SelfViewMetadata = ();
+
+    // At the moment it does not make sense to return "sub" relinearization keys. So we use a dummy
+    // placeholder type here.
+    type SelfView<'this>
+        = DummyCreateFrom
+    where
+        Self: 'this;
+
+    fn get_entity_view_creation_metadata(&self) -> Self::EntityViewMetadata {
+        GlweCiphertextListCreationMetadata(
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.ciphertext_modulus(),
+        )
+    }
+
+    fn get_entity_view_pod_size(&self) -> usize {
+        self.secret_product_encrypted_size()
+    }
+
+    /// Unimplemented for [`GlweRelinearizationKey`]. At the moment it does not make sense to
+    /// return "sub" relinearization keys.
+    fn get_self_view_creation_metadata(&self) -> Self::SelfViewMetadata {
+        unimplemented!(
+            "This function is not supported for GlweRelinearizationKey. \
+        At the moment it does not make sense to return 'sub' relinearization keys."
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntityContainerMut
+    for GlweRelinearizationKey<C>
+{
+    type EntityMutView<'this>
+        = GlweCiphertextListMutView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    // At the moment it does not make sense to return "sub" relinearization keys. So we use a dummy
+    // placeholder type here.
+    type SelfMutView<'this>
+        = DummyCreateFrom
+    where
+        Self: 'this;
+}
@@ -18,7 +18,7 @@ use crate::core_crypto::entities::*;
 /// The $k$ polynomials composing $\vec{S}$ contain each $N$ integers coefficients that have been
 /// sampled from some distribution which is either uniformly binary, uniformly ternary, gaussian or
 /// even uniform.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct GlweSecretKey<C: Container> {
     data: C,
     polynomial_size: PolynomialSize,
@@ -36,6 +36,23 @@ impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for GlweSecretKey<C> {
     }
 }
 
+impl<C: Container> std::fmt::Debug for GlweSecretKey<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The key material is redacted to avoid leaking it in logs
+        f.debug_struct("GlweSecretKey")
+            .field("glwe_dimension", &self.glwe_dimension())
+            .field("polynomial_size", &self.polynomial_size())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Container> Drop for GlweSecretKey<C> {
+    fn drop(&mut self) {
+        // SAFETY: the secret material is never read after being wiped
+        unsafe { self.data.wipe_owned_memory() };
+    }
+}
+
 impl<Scalar, C: Container<Element = Scalar>> GlweSecretKey<C> {
     /// Create a [`GlweSecretKey`] from an existing container.
     ///
@@ -105,9 +122,19 @@ impl<Scalar, C: Container<Element = Scalar>> GlweSecretKey<C> {
         self.polynomial_size
     }
 
+    /// Lock the memory of the [`GlweSecretKey`] in RAM, preventing it from being written to the
+    /// swap.
+    ///
+    /// Return `false` if the memory could not be locked, see
+    /// [`lock_memory`](`crate::core_crypto::commons::secret_memory::lock_memory`) for details. The
+    /// memory is unlocked when the key is dropped, clones of the key are not locked.
+    pub fn lock_in_memory(&self) -> bool {
+        crate::core_crypto::commons::secret_memory::lock_memory(self.data.as_ref())
+    }
+
     /// Consume the [`GlweSecretKey`] and return it interpreted as an [`LweSecretKey`].
     pub fn into_lwe_secret_key(self) -> LweSecretKey<C> {
-        LweSecretKey::from_container(self.data)
+        LweSecretKey::from_container(self.into_container())
     }
 
     /// Borrowes and returns the [`GlweSecretKey`] views as an [`LweSecretKey`].
@@ -124,7 +151,10 @@ impl<Scalar, C: Container<Element = Scalar>> GlweSecretKey<C> {
     ///
     /// See [`GlweSecretKey::from_container`] for usage.
     pub fn into_container(self) -> C {
-        self.data
+        // The secret memory is handed over to the caller, the entity must not wipe it on drop
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: the container is read only once and the entity is never dropped
+        unsafe { std::ptr::read(&this.data) }
     }
 }
 
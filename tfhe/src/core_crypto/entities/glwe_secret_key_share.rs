@@ -0,0 +1,166 @@
+//! Module containing the definition of the GlweSecretKeyShare.
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// A share of a [`GLWE secret key`](`GlweSecretKey`) held by one party of a threshold decryption.
+///
+/// Shares are generated with
+/// [`allocate_and_share_glwe_secret_key`](`crate::core_crypto::algorithms::allocate_and_share_glwe_secret_key`).
+/// As the [`GlweSecretKey`] can be interpreted as an [`LweSecretKey`], a [`GlweSecretKeyShare`]
+/// can be interpreted as an [`LweSecretKeyShare`] to partially decrypt LWE ciphertexts under the
+/// GLWE secret key, e.g. ciphertexts output by a programmable bootstrap.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlweSecretKeyShare<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    polynomial_size: PolynomialSize,
+    party_index: ThresholdPartyIndex,
+    sharing_scheme: SecretSharingScheme,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweSecretKeyShare<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweSecretKeyShare<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+impl<C: Container> std::fmt::Debug for GlweSecretKeyShare<C>
+where
+    C::Element: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The key material is redacted to avoid leaking it in logs
+        f.debug_struct("GlweSecretKeyShare")
+            .field("glwe_dimension", &self.glwe_dimension())
+            .field("polynomial_size", &self.polynomial_size())
+            .field("party_index", &self.party_index())
+            .field("sharing_scheme", &self.sharing_scheme())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Container> Drop for GlweSecretKeyShare<C>
+where
+    C::Element: UnsignedInteger,
+{
+    fn drop(&mut self) {
+        // SAFETY: the secret material is never read after being wiped
+        unsafe { self.data.wipe_owned_memory() };
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweSecretKeyShare<C> {
+    /// Create a [`GlweSecretKeyShare`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to share a
+    /// [`GlweSecretKey`] you need to call
+    /// [`crate::core_crypto::algorithms::allocate_and_share_glwe_secret_key`].
+    pub fn from_container(
+        container: C,
+        polynomial_size: PolynomialSize,
+        party_index: ThresholdPartyIndex,
+        sharing_scheme: SecretSharingScheme,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a GlweSecretKeyShare"
+        );
+        assert!(
+            container.container_len() % polynomial_size.0 == 0,
+            "The provided container length is not valid. \
+        It needs to be dividable by polynomial_size. \
+        Got container length: {} and polynomial_size: {polynomial_size:?}",
+            container.container_len()
+        );
+        assert!(
+            party_index.0 >= 1 && party_index.0 <= sharing_scheme.party_count().0,
+            "Invalid party index {party_index:?} for {sharing_scheme:?}, \
+            parties are indexed from 1 to the party count"
+        );
+        Self {
+            data: container,
+            polynomial_size,
+            party_index,
+            sharing_scheme,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweSecretKeyShare`].
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(self.data.container_len() / self.polynomial_size.0)
+    }
+
+    /// Return the [`PolynomialSize`] of the [`GlweSecretKeyShare`].
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Return the [`ThresholdPartyIndex`] of the party holding the [`GlweSecretKeyShare`].
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.party_index
+    }
+
+    /// Return the [`SecretSharingScheme`] used to generate the [`GlweSecretKeyShare`].
+    pub fn sharing_scheme(&self) -> SecretSharingScheme {
+        self.sharing_scheme
+    }
+
+    /// Return the [`CiphertextModulus`] the [`GlweSecretKeyShare`] was generated for.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ciphertext_modulus
+    }
+
+    /// Consume the [`GlweSecretKeyShare`] and return it interpreted as an [`LweSecretKeyShare`].
+    pub fn into_lwe_secret_key_share(self) -> LweSecretKeyShare<C> {
+        let party_index = self.party_index;
+        let sharing_scheme = self.sharing_scheme;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        LweSecretKeyShare::from_container(
+            self.into_container(),
+            party_index,
+            sharing_scheme,
+            ciphertext_modulus,
+        )
+    }
+
+    /// Borrow the [`GlweSecretKeyShare`] and return it viewed as an [`LweSecretKeyShare`].
+    pub fn as_lwe_secret_key_share(&self) -> LweSecretKeyShareView<'_, Scalar> {
+        LweSecretKeyShare::from_container(
+            self.data.as_ref(),
+            self.party_index,
+            self.sharing_scheme,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Interpret the [`GlweSecretKeyShare`] as a [`PolynomialList`].
+    pub fn as_polynomial_list(&self) -> PolynomialListView<'_, Scalar> {
+        PolynomialListView::from_container(self.as_ref(), self.polynomial_size)
+    }
+
+    /// Consume the entity and return its underlying container.
+    pub fn into_container(self) -> C {
+        // The secret memory is handed over to the caller, the entity must not wipe it on drop
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: the container is read only once and the entity is never dropped
+        unsafe { std::ptr::read(&this.data) }
+    }
+}
+
+/// A [`GlweSecretKeyShare`] owning the memory for its own storage.
+pub type GlweSecretKeyShareOwned<Scalar> = GlweSecretKeyShare<Vec<Scalar>>;
@@ -16,7 +16,7 @@ use crate::core_crypto::commons::traits::*;
 /// $$\vec{s} \in \mathbb{Z}^n$$
 /// This vector contains $n$ integers that have been sampled for some distribution which is either
 /// uniformly binary, uniformly ternary, gaussian or even uniform.
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct LweSecretKey<C: Container> {
     data: C,
 }
@@ -33,6 +33,22 @@ impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for LweSecretKey<C> {
     }
 }
 
+impl<C: Container> std::fmt::Debug for LweSecretKey<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The key material is redacted to avoid leaking it in logs
+        f.debug_struct("LweSecretKey")
+            .field("lwe_dimension", &self.lwe_dimension())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Container> Drop for LweSecretKey<C> {
+    fn drop(&mut self) {
+        // SAFETY: the secret material is never read after being wiped
+        unsafe { self.data.wipe_owned_memory() };
+    }
+}
+
 impl<Scalar, C: Container<Element = Scalar>> LweSecretKey<C> {
     /// Create an [`LweSecretKey`] from an existing container.
     ///
@@ -82,11 +98,24 @@ impl<Scalar, C: Container<Element = Scalar>> LweSecretKey<C> {
         LweDimension(self.data.container_len())
     }
 
+    /// Lock the memory of the [`LweSecretKey`] in RAM, preventing it from being written to the
+    /// swap.
+    ///
+    /// Return `false` if the memory could not be locked, see
+    /// [`lock_memory`](`crate::core_crypto::commons::secret_memory::lock_memory`) for details. The
+    /// memory is unlocked when the key is dropped, clones of the key are not locked.
+    pub fn lock_in_memory(&self) -> bool {
+        crate::core_crypto::commons::secret_memory::lock_memory(self.data.as_ref())
+    }
+
     /// Consume the entity and return its underlying container.
     ///
     /// See [`LweSecretKey::from_container`] for usage.
     pub fn into_container(self) -> C {
-        self.data
+        // The secret memory is handed over to the caller, the entity must not wipe it on drop
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: the container is read only once and the entity is never dropped
+        unsafe { std::ptr::read(&this.data) }
     }
 }
 
@@ -0,0 +1,163 @@
+//! Module containing the definition of the LweSecretKeyShare and of the LwePartialDecryption.
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+
+/// A share of an [`LWE secret key`](`crate::core_crypto::entities::LweSecretKey`) held by one
+/// party of a threshold decryption.
+///
+/// Shares are generated with
+/// [`allocate_and_share_lwe_secret_key`](`crate::core_crypto::algorithms::allocate_and_share_lwe_secret_key`).
+/// Unlike the secret key they are derived from, their coefficients are uniformly distributed
+/// modulo the ciphertext modulus they were generated for, which is why that modulus is stored
+/// with the share.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LweSecretKeyShare<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    party_index: ThresholdPartyIndex,
+    sharing_scheme: SecretSharingScheme,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for LweSecretKeyShare<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for LweSecretKeyShare<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+impl<C: Container> std::fmt::Debug for LweSecretKeyShare<C>
+where
+    C::Element: UnsignedInteger,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The key material is redacted to avoid leaking it in logs
+        f.debug_struct("LweSecretKeyShare")
+            .field("lwe_dimension", &self.lwe_dimension())
+            .field("party_index", &self.party_index())
+            .field("sharing_scheme", &self.sharing_scheme())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Container> Drop for LweSecretKeyShare<C>
+where
+    C::Element: UnsignedInteger,
+{
+    fn drop(&mut self) {
+        // SAFETY: the secret material is never read after being wiped
+        unsafe { self.data.wipe_owned_memory() };
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweSecretKeyShare<C> {
+    /// Create an [`LweSecretKeyShare`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to share an
+    /// [`LweSecretKey`](`crate::core_crypto::entities::LweSecretKey`) you need to call
+    /// [`crate::core_crypto::algorithms::allocate_and_share_lwe_secret_key`].
+    pub fn from_container(
+        container: C,
+        party_index: ThresholdPartyIndex,
+        sharing_scheme: SecretSharingScheme,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create an LweSecretKeyShare"
+        );
+        assert!(
+            party_index.0 >= 1 && party_index.0 <= sharing_scheme.party_count().0,
+            "Invalid party index {party_index:?} for {sharing_scheme:?}, \
+            parties are indexed from 1 to the party count"
+        );
+        Self {
+            data: container,
+            party_index,
+            sharing_scheme,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`LweDimension`] of the [`LweSecretKeyShare`].
+    pub fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.data.container_len())
+    }
+
+    /// Return the [`ThresholdPartyIndex`] of the party holding the [`LweSecretKeyShare`].
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.party_index
+    }
+
+    /// Return the [`SecretSharingScheme`] used to generate the [`LweSecretKeyShare`].
+    pub fn sharing_scheme(&self) -> SecretSharingScheme {
+        self.sharing_scheme
+    }
+
+    /// Return the [`CiphertextModulus`] the [`LweSecretKeyShare`] was generated for.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ciphertext_modulus
+    }
+
+    /// Return a view of the [`LweSecretKeyShare`]. This is useful if an algorithm takes a view by
+    /// value.
+    pub fn as_view(&self) -> LweSecretKeyShareView<'_, Scalar> {
+        LweSecretKeyShare {
+            data: self.data.as_ref(),
+            party_index: self.party_index,
+            sharing_scheme: self.sharing_scheme,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    /// Consume the entity and return its underlying container.
+    pub fn into_container(self) -> C {
+        // The secret memory is handed over to the caller, the entity must not wipe it on drop
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: the container is read only once and the entity is never dropped
+        unsafe { std::ptr::read(&this.data) }
+    }
+}
+
+/// An [`LweSecretKeyShare`] owning the memory for its own storage.
+pub type LweSecretKeyShareOwned<Scalar> = LweSecretKeyShare<Vec<Scalar>>;
+/// An [`LweSecretKeyShare`] immutably borrowing memory for its own storage.
+pub type LweSecretKeyShareView<'data, Scalar> = LweSecretKeyShare<&'data [Scalar]>;
+
+/// The contribution of one party to the threshold decryption of an
+/// [`LWE ciphertext`](`crate::core_crypto::entities::LweCiphertext`).
+///
+/// Partial decryptions are computed with
+/// [`partial_decrypt_lwe_ciphertext`](`crate::core_crypto::algorithms::partial_decrypt_lwe_ciphertext`)
+/// and combined with
+/// [`combine_lwe_partial_decryptions`](`crate::core_crypto::algorithms::combine_lwe_partial_decryptions`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LwePartialDecryption<Scalar: UnsignedInteger> {
+    pub(crate) data: Scalar,
+    pub(crate) party_index: ThresholdPartyIndex,
+    pub(crate) sharing_scheme: SecretSharingScheme,
+}
+
+impl<Scalar: UnsignedInteger> LwePartialDecryption<Scalar> {
+    /// Return the [`ThresholdPartyIndex`] of the party which computed the
+    /// [`LwePartialDecryption`].
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.party_index
+    }
+
+    /// Return the [`SecretSharingScheme`] of the key share used to compute the
+    /// [`LwePartialDecryption`].
+    pub fn sharing_scheme(&self) -> SecretSharingScheme {
+        self.sharing_scheme
+    }
+}
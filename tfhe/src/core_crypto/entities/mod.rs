@@ -4,11 +4,16 @@
 //! associated to the object, e.g., `get_mask` for the entity `LweCiphertext`.
 
 pub mod cleartext;
+pub mod compressed_modulus_switched_glwe_ciphertext;
 pub mod ggsw_ciphertext;
 pub mod ggsw_ciphertext_list;
+pub mod glwe_automorphism_key;
 pub mod glwe_ciphertext;
 pub mod glwe_ciphertext_list;
+pub mod glwe_public_key;
+pub mod glwe_relinearization_key;
 pub mod glwe_secret_key;
+pub mod glwe_secret_key_share;
 pub mod gsw_ciphertext;
 pub mod lwe_bootstrap_key;
 pub mod lwe_ciphertext;
@@ -22,6 +27,8 @@ pub mod lwe_private_functional_packing_keyswitch_key;
 pub mod lwe_private_functional_packing_keyswitch_key_list;
 pub mod lwe_public_key;
 pub mod lwe_secret_key;
+pub mod lwe_secret_key_share;
+pub mod packed_integers;
 pub mod plaintext;
 pub mod plaintext_list;
 pub mod polynomial;
@@ -30,6 +37,7 @@ pub mod seeded_ggsw_ciphertext;
 pub mod seeded_ggsw_ciphertext_list;
 pub mod seeded_glwe_ciphertext;
 pub mod seeded_glwe_ciphertext_list;
+pub mod seeded_glwe_public_key;
 pub mod seeded_lwe_bootstrap_key;
 pub mod seeded_lwe_ciphertext;
 pub mod seeded_lwe_ciphertext_list;
@@ -52,12 +60,23 @@ pub use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     FourierGgswCiphertext, FourierGgswCiphertextList, FourierGgswLevelMatrix, FourierGgswLevelRow,
 };
 pub use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomial;
+pub use crate::core_crypto::ntt_impl::ntt64::crypto::bootstrap::{
+    NttLweBootstrapKey, NttLweBootstrapKeyOwned,
+};
+pub use crate::core_crypto::ntt_impl::ntt64::crypto::ggsw::{
+    NttGgswCiphertext, NttGgswLevelMatrix, NttGgswLevelRow,
+};
 pub use cleartext::*;
+pub use compressed_modulus_switched_glwe_ciphertext::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_list::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_list::*;
+pub use glwe_public_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
+pub use glwe_secret_key_share::*;
 pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
@@ -71,6 +90,8 @@ pub use lwe_private_functional_packing_keyswitch_key::*;
 pub use lwe_private_functional_packing_keyswitch_key_list::*;
 pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
+pub use lwe_secret_key_share::*;
+pub use packed_integers::*;
 pub use plaintext::*;
 pub use plaintext_list::*;
 pub use polynomial::*;
@@ -79,6 +100,7 @@ pub use seeded_ggsw_ciphertext::*;
 pub use seeded_ggsw_ciphertext_list::*;
 pub use seeded_glwe_ciphertext::*;
 pub use seeded_glwe_ciphertext_list::*;
+pub use seeded_glwe_public_key::*;
 pub use seeded_lwe_bootstrap_key::*;
 pub use seeded_lwe_ciphertext::*;
 pub use seeded_lwe_ciphertext_list::*;
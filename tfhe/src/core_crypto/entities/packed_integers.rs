@@ -0,0 +1,121 @@
+//! Module containing the definition of the PackedIntegers.
+
+use crate::core_crypto::algorithms::misc::divide_ceil;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::CiphertextModulusLog;
+
+use serde::{Deserialize, Serialize};
+
+/// A list of integers stored using exactly `log_modulus` bits each.
+///
+/// All the integers are concatenated in a bit stream which is then split in `Scalar` words, this
+/// allows to store values smaller than `Scalar::BITS` without wasting the unused most significant
+/// bits.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackedIntegers<Scalar: UnsignedInteger> {
+    packed_coeffs: Vec<Scalar>,
+    log_modulus: CiphertextModulusLog,
+    initial_len: usize,
+}
+
+impl<Scalar: UnsignedInteger> PackedIntegers<Scalar> {
+    /// Pack a slice of integers, each being strictly smaller than $2^{log\_modulus}$.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let log_modulus = CiphertextModulusLog(12);
+    /// let values: Vec<u64> = (0..100).map(|x| (x * 37) % (1 << 12)).collect();
+    ///
+    /// let packed = PackedIntegers::pack(&values, log_modulus);
+    ///
+    /// // 100 values of 12 bits fit in 19 u64 words
+    /// assert_eq!(packed.packed_coeffs().len(), 19);
+    ///
+    /// let unpacked: Vec<u64> = packed.unpack().collect();
+    /// assert_eq!(unpacked, values);
+    /// ```
+    pub fn pack(slice: &[Scalar], log_modulus: CiphertextModulusLog) -> Self {
+        assert!(
+            log_modulus.0 > 0 && log_modulus.0 <= Scalar::BITS,
+            "Invalid log_modulus {}, expected a value in [1, {}]",
+            log_modulus.0,
+            Scalar::BITS
+        );
+
+        let log_modulus_usize = log_modulus.0;
+
+        let in_len = slice.len();
+        let number_bits_to_pack = in_len * log_modulus_usize;
+        let len = divide_ceil(number_bits_to_pack, Scalar::BITS);
+
+        let mut packed_coeffs = vec![Scalar::ZERO; len];
+
+        for (i, &value) in slice.iter().enumerate() {
+            debug_assert!(
+                log_modulus_usize == Scalar::BITS || value < (Scalar::ONE << log_modulus_usize),
+                "Value {value:?} does not fit on {log_modulus_usize} bits"
+            );
+
+            let start_bit = i * log_modulus_usize;
+            let word_index = start_bit / Scalar::BITS;
+            let bit_offset = start_bit % Scalar::BITS;
+
+            packed_coeffs[word_index] |= value << bit_offset;
+
+            // The value overlaps two words, store its remaining most significant bits in the next
+            // word
+            if bit_offset + log_modulus_usize > Scalar::BITS {
+                packed_coeffs[word_index + 1] |= value >> (Scalar::BITS - bit_offset);
+            }
+        }
+
+        Self {
+            packed_coeffs,
+            log_modulus,
+            initial_len: in_len,
+        }
+    }
+
+    /// Return an iterator over the unpacked integers.
+    ///
+    /// See [`PackedIntegers::pack`] for usage.
+    pub fn unpack(&self) -> impl Iterator<Item = Scalar> + '_ {
+        let log_modulus = self.log_modulus.0;
+
+        let mask = if log_modulus == Scalar::BITS {
+            Scalar::MAX
+        } else {
+            (Scalar::ONE << log_modulus) - Scalar::ONE
+        };
+
+        (0..self.initial_len).map(move |i| {
+            let start_bit = i * log_modulus;
+            let word_index = start_bit / Scalar::BITS;
+            let bit_offset = start_bit % Scalar::BITS;
+
+            let mut value = self.packed_coeffs[word_index] >> bit_offset;
+
+            if bit_offset + log_modulus > Scalar::BITS {
+                value |= self.packed_coeffs[word_index + 1] << (Scalar::BITS - bit_offset);
+            }
+
+            value & mask
+        })
+    }
+
+    /// Return the underlying packed words.
+    pub fn packed_coeffs(&self) -> &[Scalar] {
+        &self.packed_coeffs
+    }
+
+    /// Return the number of bits used to store each integer.
+    pub fn log_modulus(&self) -> CiphertextModulusLog {
+        self.log_modulus
+    }
+
+    /// Return the number of integers stored.
+    pub fn initial_len(&self) -> usize {
+        self.initial_len
+    }
+}
@@ -0,0 +1,257 @@
+//! Module containing the definition of the [`SeededGlwePublicKey`].
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, CompressionSeed};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+// A SeededGlwePublicKey is literally a SeededGlweCiphertextList, so we wrap a
+// SeededGlweCiphertextList and use Deref to have access to all the primitives of the
+// SeededGlweCiphertextList easily
+
+/// A [`seeded public GLWE encryption key`](`SeededGlwePublicKey`).
+///
+/// This is a wrapper type of [`SeededGlweCiphertextList`], [`std::ops::Deref`] and
+/// [`std::ops::DerefMut`] are implemented to dereference to the underlying
+/// [`SeededGlweCiphertextList`] for ease of use. See [`SeededGlweCiphertextList`] for additional
+/// methods.
+///
+/// # Formal Definition
+///
+/// ## GLWE Public Key
+///
+/// A GLWE public key contains $m$ GLWE encryptions of the zero polynomial under a secret key
+/// $\vec{S} \in \mathcal{R}\_q^k$ where $k$ is the GLWE dimension of the ciphertexts contained in
+/// the public key.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SeededGlwePublicKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    glwe_list: SeededGlweCiphertextList<C>,
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
+    for SeededGlwePublicKey<C>
+{
+    type Target = SeededGlweCiphertextList<C>;
+
+    fn deref(&self) -> &SeededGlweCiphertextList<C> {
+        &self.glwe_list
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> std::ops::DerefMut
+    for SeededGlwePublicKey<C>
+{
+    fn deref_mut(&mut self) -> &mut SeededGlweCiphertextList<C> {
+        &mut self.glwe_list
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> SeededGlwePublicKey<C> {
+    /// Create a [`SeededGlwePublicKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`SeededGlwePublicKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_seeded_glwe_public_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`SeededGlwePublicKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for SeededGlwePublicKey creation
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let zero_encryption_count = GlwePublicKeyZeroEncryptionCount(3);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Get a seeder
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    ///
+    /// // Create a new SeededGlwePublicKey
+    /// let seeded_glwe_public_key = SeededGlwePublicKey::new(
+    ///     0u64,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     zero_encryption_count,
+    ///     seeder.seed().into(),
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// // These are methods from SeededGlweCiphertextList
+    /// assert_eq!(seeded_glwe_public_key.glwe_size(), glwe_size);
+    /// assert_eq!(seeded_glwe_public_key.polynomial_size(), polynomial_size);
+    /// // This is a method from SeededGlwePublicKey
+    /// assert_eq!(
+    ///     seeded_glwe_public_key.zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    /// assert_eq!(
+    ///     seeded_glwe_public_key.ciphertext_modulus(),
+    ///     ciphertext_modulus
+    /// );
+    ///
+    /// let compression_seed = seeded_glwe_public_key.compression_seed();
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = seeded_glwe_public_key.into_container();
+    ///
+    /// // Recreate a public key using from_container
+    /// let seeded_glwe_public_key = SeededGlwePublicKey::from_container(
+    ///     underlying_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     compression_seed,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(seeded_glwe_public_key.glwe_size(), glwe_size);
+    /// assert_eq!(seeded_glwe_public_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     seeded_glwe_public_key.zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    /// assert_eq!(
+    ///     seeded_glwe_public_key.ciphertext_modulus(),
+    ///     ciphertext_modulus
+    /// );
+    ///
+    /// // Decompress the key
+    /// let glwe_public_key = seeded_glwe_public_key.decompress_into_glwe_public_key();
+    ///
+    /// assert_eq!(glwe_public_key.glwe_size(), glwe_size);
+    /// assert_eq!(glwe_public_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     glwe_public_key.zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    /// assert_eq!(glwe_public_key.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a SeededGlwePublicKey"
+        );
+        Self {
+            glwe_list: SeededGlweCiphertextList::from_container(
+                container,
+                glwe_size,
+                polynomial_size,
+                compression_seed,
+                ciphertext_modulus,
+            ),
+        }
+    }
+
+    /// Return the [`GlwePublicKeyZeroEncryptionCount`] of the [`SeededGlwePublicKey`].
+    ///
+    /// See [`SeededGlwePublicKey::from_container`] for usage.
+    pub fn zero_encryption_count(&self) -> GlwePublicKeyZeroEncryptionCount {
+        GlwePublicKeyZeroEncryptionCount(self.glwe_ciphertext_count().0)
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`SeededGlwePublicKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.glwe_list.into_container()
+    }
+
+    /// Consume the [`SeededGlwePublicKey`] and decompress it into a standard
+    /// [`GlwePublicKey`].
+    ///
+    /// See [`SeededGlwePublicKey::from_container`] for usage.
+    pub fn decompress_into_glwe_public_key(self) -> GlwePublicKeyOwned<Scalar>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let mut decompressed_public_key = GlwePublicKey::new(
+            Scalar::ZERO,
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.zero_encryption_count(),
+            self.ciphertext_modulus(),
+        );
+        decompress_seeded_glwe_public_key::<_, _, _, ActivatedRandomGenerator>(
+            &mut decompressed_public_key,
+            &self,
+        );
+        decompressed_public_key
+    }
+
+    /// Return a view of the [`SeededGlwePublicKey`]. This is useful if an algorithm takes a view
+    /// by value.
+    pub fn as_view(&self) -> SeededGlwePublicKey<&'_ [Scalar]> {
+        SeededGlwePublicKey::from_container(
+            self.as_ref(),
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.compression_seed(),
+            self.ciphertext_modulus(),
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> SeededGlwePublicKey<C> {
+    /// Mutable variant of [`SeededGlwePublicKey::as_view`].
+    pub fn as_mut_view(&mut self) -> SeededGlwePublicKey<&'_ mut [Scalar]> {
+        let glwe_size = self.glwe_size();
+        let polynomial_size = self.polynomial_size();
+        let compression_seed = self.compression_seed();
+        let ciphertext_modulus = self.ciphertext_modulus();
+        SeededGlwePublicKey::from_container(
+            self.as_mut(),
+            glwe_size,
+            polynomial_size,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`SeededGlwePublicKey`] owning the memory for its own storage.
+pub type SeededGlwePublicKeyOwned<Scalar> = SeededGlwePublicKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger> SeededGlwePublicKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`SeededGlwePublicKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`SeededGlwePublicKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_seeded_glwe_public_key`] using this key as
+    /// output.
+    ///
+    /// See [`SeededGlwePublicKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![fill_with; zero_encryption_count.0 * polynomial_size.0],
+            glwe_size,
+            polynomial_size,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
@@ -237,15 +237,50 @@ pub fn bootstrap_scratch<Scalar>(
     )
 }
 
+/// Replace `ct0` by `ct0 * X^{monomial_degree}` if `ggsw` encrypts 1, leave it unchanged if `ggsw`
+/// encrypts 0, `ct1` is used as a temporary buffer.
+fn rotation_cmux_assign<Scalar: UnsignedTorus>(
+    ct0: GlweCiphertextMutView<'_, Scalar>,
+    mut ct1: GlweCiphertextMutView<'_, Scalar>,
+    ggsw: FourierGgswCiphertextView<'_>,
+    monomial_degree: MonomialDegree,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) {
+    // we effectively inline the body of cmux here, merging the initial subtraction
+    // operation with the monic polynomial multiplication, then performing the external
+    // product manually
+
+    // We rotate ct_1 and subtract ct_0 (first step of cmux) by performing
+    // ct_1 <- (ct_0 * X^{a_hat}) - ct_0
+    for (mut ct1_poly, ct0_poly) in izip!(
+        ct1.as_mut_polynomial_list().iter_mut(),
+        ct0.as_polynomial_list().iter(),
+    ) {
+        polynomial_wrapping_monic_monomial_mul_and_subtract(
+            &mut ct1_poly,
+            &ct0_poly,
+            monomial_degree,
+        );
+    }
+
+    // second step of cmux
+    add_external_product_assign(ct0, ggsw, ct1, fft, stack);
+}
+
 impl<'a> FourierLweBootstrapKeyView<'a> {
-    // CastInto required for PBS modulus switch which returns a usize
-    pub fn blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
+    // CastInto required for PBS modulus switch which returns a usize, the input can use a different
+    // integer type than the lookup table as it is only used through the modulus switch
+    pub fn blind_rotate_assign<InputScalar, OutputScalar>(
         self,
-        mut lut: GlweCiphertextMutView<'_, Scalar>,
-        lwe: &[Scalar],
+        mut lut: GlweCiphertextMutView<'_, OutputScalar>,
+        lwe: &[InputScalar],
         fft: FftView<'_>,
         mut stack: PodStack<'_>,
-    ) {
+    ) where
+        InputScalar: UnsignedTorus + CastInto<usize>,
+        OutputScalar: UnsignedTorus,
+    {
         let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
 
         let lut_poly_size = lut.polynomial_size();
@@ -276,42 +311,82 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
         let mut ct1 =
             GlweCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
 
-        for (lwe_mask_element, bootstrap_key_ggsw) in izip!(lwe_mask.iter(), self.into_ggsw_iter())
-        {
-            if *lwe_mask_element != Scalar::ZERO {
-                let monomial_degree = MonomialDegree(fast_pbs_modulus_switch(
+        let input_lwe_dimension = lwe_mask.len();
+        let key_lwe_dimension = self.input_lwe_dimension().0;
+
+        if key_lwe_dimension == input_lwe_dimension {
+            for (lwe_mask_element, bootstrap_key_ggsw) in
+                izip!(lwe_mask.iter(), self.into_ggsw_iter())
+            {
+                if *lwe_mask_element != InputScalar::ZERO {
+                    let monomial_degree = MonomialDegree(fast_pbs_modulus_switch(
+                        *lwe_mask_element,
+                        lut_poly_size,
+                        ModulusSwitchOffset(0),
+                        LutCountLog(0),
+                    ));
+
+                    rotation_cmux_assign(
+                        ct0.as_mut_view(),
+                        ct1.as_mut_view(),
+                        bootstrap_key_ggsw,
+                        monomial_degree,
+                        fft,
+                        stack.rb_mut(),
+                    );
+                }
+            }
+        } else {
+            // The key was generated from the binary expansion of a non binary secret key, see
+            // generate_binary_expanded_lwe_secret_key: each coefficient s_i of the input key is
+            // split in bit planes, the GGSW ciphertexts encrypt in turn the j-th bit of s_i if it is
+            // positive and the j-th bit of -s_i if it is negative, so the rotation by X^{a_i * s_i}
+            // is computed with two CMuxes per bit plane, rotating by X^{2^j * a_i} and
+            // X^{-2^j * a_i}
+            assert!(
+                input_lwe_dimension != 0 && key_lwe_dimension % (2 * input_lwe_dimension) == 0,
+                "The input LweDimension ({input_lwe_dimension}) is not compatible with the input \
+                LweDimension of the bootstrapping key ({key_lwe_dimension}), \
+                expected it to be equal or to divide half of it."
+            );
+            let bit_plane_count = key_lwe_dimension / (2 * input_lwe_dimension);
+            let monomial_degree_modulus = 2 * lut_poly_size.0;
+
+            let mut bootstrap_key_ggsw_iter = self.into_ggsw_iter();
+
+            for lwe_mask_element in lwe_mask.iter() {
+                let mut plane_monomial_degree = fast_pbs_modulus_switch(
                     *lwe_mask_element,
                     lut_poly_size,
                     ModulusSwitchOffset(0),
                     LutCountLog(0),
-                ));
-
-                // we effectively inline the body of cmux here, merging the initial subtraction
-                // operation with the monic polynomial multiplication, then performing the external
-                // product manually
-
-                // We rotate ct_1 and subtract ct_0 (first step of cmux) by performing
-                // ct_1 <- (ct_0 * X^{a_hat}) - ct_0
-                for (mut ct1_poly, ct0_poly) in izip!(
-                    ct1.as_mut_polynomial_list().iter_mut(),
-                    ct0.as_polynomial_list().iter(),
-                ) {
-                    polynomial_wrapping_monic_monomial_mul_and_subtract(
-                        &mut ct1_poly,
-                        &ct0_poly,
-                        monomial_degree,
-                    );
+                ) % monomial_degree_modulus;
+
+                for _ in 0..bit_plane_count {
+                    let positive_bit_ggsw = bootstrap_key_ggsw_iter.next().unwrap();
+                    let negative_bit_ggsw = bootstrap_key_ggsw_iter.next().unwrap();
+
+                    if plane_monomial_degree != 0 {
+                        rotation_cmux_assign(
+                            ct0.as_mut_view(),
+                            ct1.as_mut_view(),
+                            positive_bit_ggsw,
+                            MonomialDegree(plane_monomial_degree),
+                            fft,
+                            stack.rb_mut(),
+                        );
+                        rotation_cmux_assign(
+                            ct0.as_mut_view(),
+                            ct1.as_mut_view(),
+                            negative_bit_ggsw,
+                            MonomialDegree(monomial_degree_modulus - plane_monomial_degree),
+                            fft,
+                            stack.rb_mut(),
+                        );
+                    }
+
+                    plane_monomial_degree = (2 * plane_monomial_degree) % monomial_degree_modulus;
                 }
-
-                // as_mut_view is required to keep borrow rules consistent
-                // second step of cmux
-                add_external_product_assign(
-                    ct0.as_mut_view(),
-                    bootstrap_key_ggsw,
-                    ct1.as_mut_view(),
-                    fft,
-                    stack.rb_mut(),
-                );
             }
         }
 
@@ -330,20 +405,22 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
         }
     }
 
-    pub fn bootstrap<Scalar>(
+    /// Bootstrap `lwe_in` into `lwe_out`, the input ciphertext can use a different integer type
+    /// than the accumulator and the output ciphertext.
+    pub fn bootstrap<InputScalar, OutputScalar>(
         self,
-        mut lwe_out: LweCiphertextMutView<'_, Scalar>,
-        lwe_in: LweCiphertextView<'_, Scalar>,
-        accumulator: GlweCiphertextView<'_, Scalar>,
+        mut lwe_out: LweCiphertextMutView<'_, OutputScalar>,
+        lwe_in: LweCiphertextView<'_, InputScalar>,
+        accumulator: GlweCiphertextView<'_, OutputScalar>,
         fft: FftView<'_>,
         stack: PodStack<'_>,
     ) where
         // CastInto required for PBS modulus switch which returns a usize
-        Scalar: UnsignedTorus + CastInto<usize>,
+        InputScalar: UnsignedTorus + CastInto<usize>,
+        OutputScalar: UnsignedTorus,
     {
-        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
         debug_assert_eq!(
-            lwe_in.ciphertext_modulus(),
+            lwe_out.ciphertext_modulus(),
             accumulator.ciphertext_modulus()
         );
 
@@ -362,6 +439,45 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             MonomialDegree(0),
         );
     }
+
+    /// Bootstrap `lwe_in` with an accumulator packing several lookup tables, the i-th output
+    /// ciphertext is extracted from the rotated accumulator at the monomial degree
+    /// `i * sample_extraction_stride`.
+    pub fn bootstrap_many_lut<Scalar>(
+        self,
+        mut lwe_out: LweCiphertextListMutView<'_, Scalar>,
+        lwe_in: LweCiphertextView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        sample_extraction_stride: MonomialDegree,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+        debug_assert_eq!(
+            lwe_in.ciphertext_modulus(),
+            accumulator.ciphertext_modulus()
+        );
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.blind_rotate_assign(local_accumulator.as_mut_view(), lwe_in.as_ref(), fft, stack);
+
+        for (lut_index, mut lwe_out) in lwe_out.iter_mut().enumerate() {
+            extract_lwe_sample_from_glwe_ciphertext(
+                &local_accumulator,
+                &mut lwe_out,
+                MonomialDegree(lut_index * sample_extraction_stride.0),
+            );
+        }
+    }
 }
 
 impl<Scalar> FourierBootstrapKey<Scalar> for FourierLweBootstrapKeyOwned
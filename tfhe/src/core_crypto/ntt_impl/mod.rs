@@ -0,0 +1,8 @@
+//! Number theoretic transform (NTT) backends, allowing exact polynomial multiplications modulo
+//! NTT-friendly prime ciphertext moduli.
+//!
+//! Contrary to the floating point FFT backends in
+//! [`fft_impl`](`crate::core_crypto::fft_impl`), computations performed here are exact and
+//! therefore deterministic, the only noise growth being the one inherent to the FHE operations.
+
+pub mod ntt64;
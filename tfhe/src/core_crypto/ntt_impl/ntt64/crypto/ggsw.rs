@@ -0,0 +1,427 @@
+use super::super::math::ntt::{sub_mod, Ntt64View};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::math::decomposition::{
+    DecompositionLevel, SignedDecomposerNonNative,
+};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContainerMut, ContiguousEntityContainer, ContiguousEntityContainerMut, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// A GGSW ciphertext in the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttGgswCiphertext<C: Container<Element = u64>> {
+    data: C,
+
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<u64>,
+}
+
+/// A matrix containing a single level of gadget decomposition, in the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttGgswLevelMatrix<C: Container<Element = u64>> {
+    data: C,
+
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    row_count: usize,
+    decomposition_level: DecompositionLevel,
+}
+
+/// A row of a GGSW level matrix, in the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttGgswLevelRow<C: Container<Element = u64>> {
+    data: C,
+
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_level: DecompositionLevel,
+}
+
+impl<C: Container<Element = u64>> NttGgswCiphertext<C> {
+    pub fn from_container(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<u64>,
+    ) -> Self {
+        let container_len =
+            polynomial_size.0 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0;
+        assert_eq!(data.container_len(), container_len);
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<u64> {
+        self.ciphertext_modulus
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttGgswCiphertext<&[C::Element]> {
+        NttGgswCiphertext {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> NttGgswCiphertext<&mut [C::Element]>
+    where
+        C: AsMut<[C::Element]>,
+    {
+        NttGgswCiphertext {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    /// Return an iterator over the level matrices.
+    pub fn into_levels(self) -> impl DoubleEndedIterator<Item = NttGgswLevelMatrix<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.decomposition_level_count.0)
+            .enumerate()
+            .map(move |(i, data)| {
+                NttGgswLevelMatrix::from_container(
+                    data,
+                    self.polynomial_size,
+                    self.glwe_size,
+                    self.glwe_size.0,
+                    DecompositionLevel(i + 1),
+                )
+            })
+    }
+}
+
+impl<C: Container<Element = u64>> NttGgswLevelMatrix<C> {
+    pub fn from_container(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        row_count: usize,
+        decomposition_level: DecompositionLevel,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            polynomial_size.0 * glwe_size.0 * row_count
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            row_count,
+            decomposition_level,
+        }
+    }
+
+    /// Return an iterator over the rows of the level matrices.
+    pub fn into_rows(self) -> impl DoubleEndedIterator<Item = NttGgswLevelRow<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.row_count)
+            .map(move |data| NttGgswLevelRow {
+                data,
+                polynomial_size: self.polynomial_size,
+                glwe_size: self.glwe_size,
+                decomposition_level: self.decomposition_level,
+            })
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn decomposition_level(&self) -> DecompositionLevel {
+        self.decomposition_level
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+}
+
+impl<C: Container<Element = u64>> NttGgswLevelRow<C> {
+    pub fn from_container(
+        data: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level: DecompositionLevel,
+    ) -> Self {
+        assert_eq!(data.container_len(), polynomial_size.0 * glwe_size.0);
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_level,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_level(&self) -> DecompositionLevel {
+        self.decomposition_level
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+}
+
+impl<Cont> NttGgswCiphertext<Cont>
+where
+    Cont: ContainerMut<Element = u64>,
+{
+    /// Fill a GGSW ciphertext with the NTT of a GGSW ciphertext in the standard domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext moduli of the two GGSW ciphertexts or of the NTT differ.
+    pub fn fill_with_forward_ntt<ContGgsw>(
+        &mut self,
+        coef_ggsw: &GgswCiphertext<ContGgsw>,
+        ntt: Ntt64View<'_>,
+    ) where
+        ContGgsw: Container<Element = u64>,
+    {
+        fn implementation(
+            this: NttGgswCiphertext<&mut [u64]>,
+            coef_ggsw: GgswCiphertext<&[u64]>,
+            ntt: Ntt64View<'_>,
+        ) {
+            assert_eq!(coef_ggsw.polynomial_size(), this.polynomial_size());
+            assert_eq!(coef_ggsw.ciphertext_modulus(), this.ciphertext_modulus());
+            assert_eq!(ntt.ciphertext_modulus(), this.ciphertext_modulus());
+            let poly_size = coef_ggsw.polynomial_size().0;
+
+            for (ntt_poly, coef_poly) in izip!(
+                this.data().into_chunks(poly_size),
+                coef_ggsw.as_polynomial_list().iter()
+            ) {
+                ntt.forward(ntt_poly, coef_poly.as_ref());
+            }
+        }
+        implementation(self.as_mut_view(), coef_ggsw.as_view(), ntt)
+    }
+}
+
+/// Return the required memory for [`add_external_product_assign`].
+pub fn add_external_product_assign_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let glwe_scratch = StackReq::try_new_aligned::<u64>(glwe_size.0 * polynomial_size.0, align)?;
+    let decomposition_scratch = StackReq::try_new_aligned::<u64>(
+        decomposition_level_count.0 * glwe_size.0 * polynomial_size.0,
+        align,
+    )?;
+    let ntt_scratch_single = StackReq::try_new_aligned::<u64>(polynomial_size.0, align)?;
+
+    StackReq::try_all_of([glwe_scratch, decomposition_scratch, ntt_scratch_single])
+}
+
+/// Compute the external product of `ggsw` and `glwe`, and add the result to `out`.
+///
+/// All computations are exact modulo the prime ciphertext modulus of the NTT.
+#[cfg_attr(__profiling, inline(never))]
+pub fn add_external_product_assign<ContOut, ContGgsw, ContGlwe>(
+    out: &mut GlweCiphertext<ContOut>,
+    ggsw: &NttGgswCiphertext<ContGgsw>,
+    glwe: &GlweCiphertext<ContGlwe>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    ContOut: ContainerMut<Element = u64>,
+    ContGgsw: Container<Element = u64>,
+    ContGlwe: Container<Element = u64>,
+{
+    fn implementation(
+        mut out: GlweCiphertext<&mut [u64]>,
+        ggsw: NttGgswCiphertext<&[u64]>,
+        glwe: GlweCiphertext<&[u64]>,
+        ntt: Ntt64View<'_>,
+        stack: PodStack<'_>,
+    ) {
+        // we check that the polynomial sizes match
+        debug_assert_eq!(ggsw.polynomial_size(), glwe.polynomial_size());
+        debug_assert_eq!(ggsw.polynomial_size(), out.polynomial_size());
+        // we check that the glwe sizes match
+        debug_assert_eq!(ggsw.glwe_size(), glwe.glwe_size());
+        debug_assert_eq!(ggsw.glwe_size(), out.glwe_size());
+
+        debug_assert_eq!(glwe.ciphertext_modulus(), out.ciphertext_modulus());
+        debug_assert_eq!(glwe.ciphertext_modulus(), ggsw.ciphertext_modulus());
+
+        let align = CACHELINE_ALIGN;
+        let poly_size = ggsw.polynomial_size().0;
+        let glwe_len = poly_size * ggsw.glwe_size().0;
+
+        let decomposer = SignedDecomposerNonNative::new(
+            ggsw.decomposition_base_log(),
+            ggsw.decomposition_level_count(),
+            ggsw.ciphertext_modulus(),
+        );
+
+        let (mut output_ntt_buffer, stack) = stack.make_aligned_raw::<u64>(glwe_len, align);
+        let (mut glwe_decomposition, stack) =
+            stack.make_aligned_raw::<u64>(glwe_len * decomposer.level_count, align);
+        let (mut ntt_poly, _) = stack.make_aligned_raw::<u64>(poly_size, align);
+
+        output_ntt_buffer.fill(0);
+
+        // --------------------------------------------------------- GADGET DECOMPOSITION OF THE GLWE
+        // The decomposition of the i-th coefficient at level j is stored at index
+        // (j - 1) * glwe_len + i, matching the level order of the GGSW.
+        for (i, coef) in glwe.as_ref().iter().enumerate() {
+            for term in decomposer.decompose(*coef) {
+                glwe_decomposition[(term.level().0 - 1) * glwe_len + i] = term.value();
+            }
+        }
+
+        // ------------------------------------------------------- EXTERNAL PRODUCT IN NTT DOMAIN
+        // For each level we add the result of the vector-matrix product between the decomposition
+        // of the glwe, and the ggsw level matrix to the output.
+        for (ggsw_decomp_matrix, glwe_decomp_term) in izip!(
+            ggsw.into_levels(),
+            glwe_decomposition.chunks_exact(glwe_len)
+        ) {
+            for (ggsw_row, glwe_poly) in izip!(
+                ggsw_decomp_matrix.into_rows(),
+                glwe_decomp_term.chunks_exact(poly_size)
+            ) {
+                ntt.forward(&mut ntt_poly, glwe_poly);
+                for (output_poly, ggsw_poly) in izip!(
+                    output_ntt_buffer.chunks_exact_mut(poly_size),
+                    ggsw_row.data().chunks_exact(poly_size)
+                ) {
+                    ntt.mul_accumulate(output_poly, ggsw_poly, &ntt_poly);
+                }
+            }
+        }
+
+        // ------------------------------------------------ TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+        for (mut out, ntt_out) in izip!(
+            out.as_mut_polynomial_list().iter_mut(),
+            output_ntt_buffer.chunks_exact_mut(poly_size)
+        ) {
+            ntt.add_backward(out.as_mut(), ntt_out);
+        }
+    }
+
+    implementation(
+        out.as_mut_view(),
+        ggsw.as_view(),
+        glwe.as_view(),
+        ntt,
+        stack,
+    )
+}
+
+/// Return the required memory for [`cmux`].
+pub fn cmux_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+) -> Result<StackReq, SizeOverflow> {
+    add_external_product_assign_scratch(glwe_size, polynomial_size, decomposition_level_count)
+}
+
+/// This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
+pub fn cmux<ContCt0, ContCt1, ContGgsw>(
+    ct0: &mut GlweCiphertext<ContCt0>,
+    ct1: &mut GlweCiphertext<ContCt1>,
+    ggsw: &NttGgswCiphertext<ContGgsw>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    ContCt0: ContainerMut<Element = u64>,
+    ContCt1: ContainerMut<Element = u64>,
+    ContGgsw: Container<Element = u64>,
+{
+    fn implementation(
+        mut ct0: GlweCiphertext<&mut [u64]>,
+        mut ct1: GlweCiphertext<&mut [u64]>,
+        ggsw: NttGgswCiphertext<&[u64]>,
+        ntt: Ntt64View<'_>,
+        stack: PodStack<'_>,
+    ) {
+        let modulus = ntt.custom_modulus();
+        for (c1, c0) in izip!(ct1.as_mut(), ct0.as_ref(),) {
+            *c1 = sub_mod(*c1, *c0, modulus);
+        }
+        add_external_product_assign(&mut ct0, &ggsw, &ct1, ntt, stack);
+    }
+
+    implementation(
+        ct0.as_mut_view(),
+        ct1.as_mut_view(),
+        ggsw.as_view(),
+        ntt,
+        stack,
+    )
+}
@@ -0,0 +1,107 @@
+use dyn_stack::{GlobalPodBuffer, PodStack};
+
+use super::ggsw::{add_external_product_assign, add_external_product_assign_scratch};
+use crate::core_crypto::prelude::*;
+
+#[test]
+fn test_external_product_solinas() {
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(2048);
+    let decomp_base_log = DecompositionBaseLog(23);
+    let decomp_level_count = DecompositionLevelCount(1);
+    let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+    let custom_modulus = (1u64 << 32).wrapping_neg() + 1;
+    let ciphertext_modulus = CiphertextModulus::try_new(custom_modulus as u128).unwrap();
+
+    let message_modulus = 1u64 << 4;
+    let delta = custom_modulus / (2 * message_modulus);
+
+    let mut boxed_seeder = new_seeder();
+    let seeder = boxed_seeder.as_mut();
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+    let glwe_sk =
+        GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+
+    let ntt = Ntt64::new(polynomial_size, ciphertext_modulus);
+    let ntt = ntt.as_view();
+
+    let mut mem = GlobalPodBuffer::new(
+        add_external_product_assign_scratch(
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_level_count,
+        )
+        .unwrap(),
+    );
+
+    for bit in [0u64, 1] {
+        let mut ggsw = GgswCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        );
+        encrypt_constant_ggsw_ciphertext(
+            &glwe_sk,
+            &mut ggsw,
+            Plaintext(bit),
+            glwe_modular_std_dev,
+            &mut encryption_generator,
+        );
+
+        let mut ntt_ggsw = NttGgswCiphertext::from_container(
+            vec![0u64; ggsw.as_ref().len()],
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        );
+        ntt_ggsw.fill_with_forward_ntt(&ggsw, ntt);
+
+        let messages: Vec<u64> = (0..polynomial_size.0 as u64)
+            .map(|i| i % message_modulus)
+            .collect();
+        let plaintext_list =
+            PlaintextList::from_container(messages.iter().map(|m| m * delta).collect::<Vec<_>>());
+
+        let mut glwe = GlweCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+        encrypt_glwe_ciphertext(
+            &glwe_sk,
+            &mut glwe,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            &mut encryption_generator,
+        );
+
+        let mut out = GlweCiphertext::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+        add_external_product_assign(&mut out, &ntt_ggsw, &glwe, ntt, PodStack::new(&mut mem));
+
+        let mut decrypted = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+        decrypt_glwe_ciphertext(&glwe_sk, &out, &mut decrypted);
+
+        for (decrypted, message) in decrypted.iter().zip(messages.iter()) {
+            let decoded = ((*decrypted.0 as u128 * (2 * message_modulus) as u128
+                + custom_modulus as u128 / 2)
+                / custom_modulus as u128) as u64
+                % (2 * message_modulus);
+            assert_eq!(decoded, message * bit);
+        }
+    }
+}
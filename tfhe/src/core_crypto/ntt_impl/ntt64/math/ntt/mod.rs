@@ -0,0 +1,399 @@
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::PolynomialSize;
+use crate::core_crypto::commons::utils::izip;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+#[cfg(test)]
+mod tests;
+
+/// Precomputed data needed to perform negacyclic NTTs for a given polynomial size and prime
+/// modulus.
+///
+/// Twiddle factors are stored alongside their Shoup representation $\lfloor w 2^{64} / q \rfloor$,
+/// which allows to compute products by a constant modulo $q$ without any division.
+#[derive(Clone)]
+pub(crate) struct Plan {
+    polynomial_size: usize,
+    modulus: u64,
+    // Powers of a primitive 2N-th root of unity psi, in bit reversed order
+    psi_powers_bit_reversed: Box<[u64]>,
+    psi_powers_bit_reversed_shoup: Box<[u64]>,
+    // Powers of psi^-1, in bit reversed order
+    inv_psi_powers_bit_reversed: Box<[u64]>,
+    inv_psi_powers_bit_reversed_shoup: Box<[u64]>,
+    // N^-1 mod q
+    inv_polynomial_size: u64,
+    inv_polynomial_size_shoup: u64,
+}
+
+impl core::fmt::Debug for Plan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Plan")
+            .field("polynomial_size", &self.polynomial_size)
+            .field("modulus", &self.modulus)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Negacyclic Number Theoretic Transform modulo a 64 bits prime. See [`Ntt64View`] for transform
+/// functions.
+///
+/// This structure contains the twiddle factors needed to compute the negacyclic convolution of
+/// polynomials with coefficients in $\mathbb{Z}\_q$, where $q$ is a prime such that
+/// $q \equiv 1 \bmod 2N$.
+#[derive(Clone, Debug)]
+pub struct Ntt64 {
+    plan: Arc<Plan>,
+}
+
+/// View type for [`Ntt64`].
+#[derive(Clone, Copy, Debug)]
+pub struct Ntt64View<'a> {
+    pub(crate) plan: &'a Plan,
+}
+
+impl Ntt64 {
+    pub fn as_view(&self) -> Ntt64View<'_> {
+        Ntt64View { plan: &self.plan }
+    }
+}
+
+type PlanMap = RwLock<HashMap<(usize, u64), Arc<OnceLock<Arc<Plan>>>>>;
+pub(crate) static PLANS: OnceLock<PlanMap> = OnceLock::new();
+fn plans() -> &'static PlanMap {
+    PLANS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+impl Ntt64 {
+    /// Polynomials of size `size` with coefficients modulo `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a power of two, or if `modulus` is not a prime congruent to 1
+    /// modulo `2 * size`, see [`Ntt64::is_supported`].
+    pub fn new(size: PolynomialSize, modulus: CiphertextModulus<u64>) -> Self {
+        assert!(
+            Self::is_supported(size, modulus),
+            "Ntt64 requires a power of two PolynomialSize and a prime modulus q such that \
+            q = 1 mod 2N, got {size:?} and {modulus:?}"
+        );
+
+        let global_plans = plans();
+
+        let n = size.0;
+        let q = modulus.get_custom_modulus() as u64;
+        let get_plan = || {
+            let plans = global_plans.read().unwrap();
+            let plan = plans.get(&(n, q)).cloned();
+            drop(plans);
+
+            plan.map(|p| p.get_or_init(|| Arc::new(Plan::new(n, q))).clone())
+        };
+
+        // could not find a plan of the given size, we lock the map again and try to insert it
+        let mut plans = global_plans.write().unwrap();
+        if let Entry::Vacant(v) = plans.entry((n, q)) {
+            v.insert(Arc::new(OnceLock::new()));
+        }
+
+        drop(plans);
+
+        Self {
+            plan: get_plan().unwrap(),
+        }
+    }
+
+    /// Return whether an [`Ntt64`] can be created for the given polynomial size and ciphertext
+    /// modulus, i.e. whether the polynomial size is a power of two and the modulus is a prime
+    /// congruent to 1 modulo $2N$.
+    pub fn is_supported(size: PolynomialSize, modulus: CiphertextModulus<u64>) -> bool {
+        if modulus.is_native_modulus() || !size.0.is_power_of_two() || size.0 < 2 {
+            return false;
+        }
+
+        let q = modulus.get_custom_modulus();
+        q % (2 * size.0 as u128) == 1 && is_prime(q as u64)
+    }
+}
+
+impl Plan {
+    fn new(polynomial_size: usize, modulus: u64) -> Self {
+        let n = polynomial_size;
+        let q = modulus;
+        let log_n = n.ilog2();
+
+        let psi = primitive_root_of_unity(2 * n as u64, q);
+        let inv_psi = pow_mod(psi, q - 2, q);
+
+        let bit_reversed_powers = |root: u64| -> Box<[u64]> {
+            let mut powers = vec![0u64; n];
+            let mut current = 1u64;
+            for i in 0..n {
+                powers[i.reverse_bits() >> (usize::BITS - log_n)] = current;
+                current = mul_mod(current, root, q);
+            }
+            powers.into_boxed_slice()
+        };
+        let shoup_all =
+            |powers: &[u64]| -> Box<[u64]> { powers.iter().map(|&w| shoup(w, q)).collect() };
+
+        let psi_powers_bit_reversed = bit_reversed_powers(psi);
+        let inv_psi_powers_bit_reversed = bit_reversed_powers(inv_psi);
+        let inv_polynomial_size = pow_mod(n as u64, q - 2, q);
+
+        Self {
+            polynomial_size: n,
+            modulus: q,
+            psi_powers_bit_reversed_shoup: shoup_all(&psi_powers_bit_reversed),
+            psi_powers_bit_reversed,
+            inv_psi_powers_bit_reversed_shoup: shoup_all(&inv_psi_powers_bit_reversed),
+            inv_psi_powers_bit_reversed,
+            inv_polynomial_size_shoup: shoup(inv_polynomial_size, q),
+            inv_polynomial_size,
+        }
+    }
+
+    /// In place forward negacyclic NTT, the input is in natural order and the output in bit
+    /// reversed order.
+    fn forward(&self, data: &mut [u64]) {
+        let n = self.polynomial_size;
+        let q = self.modulus;
+        debug_assert_eq!(data.len(), n);
+
+        let mut t = n;
+        let mut m = 1;
+        while m < n {
+            t /= 2;
+            for (i, chunk) in data.chunks_exact_mut(2 * t).enumerate() {
+                let w = self.psi_powers_bit_reversed[m + i];
+                let w_shoup = self.psi_powers_bit_reversed_shoup[m + i];
+                let (lo, hi) = chunk.split_at_mut(t);
+                for (x, y) in izip!(lo.iter_mut(), hi.iter_mut()) {
+                    let u = *x;
+                    let v = mul_mod_shoup(*y, w, w_shoup, q);
+                    *x = add_mod(u, v, q);
+                    *y = sub_mod(u, v, q);
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    /// In place backward negacyclic NTT, the input is in bit reversed order and the output in
+    /// natural order.
+    fn backward(&self, data: &mut [u64]) {
+        let n = self.polynomial_size;
+        let q = self.modulus;
+        debug_assert_eq!(data.len(), n);
+
+        let mut t = 1;
+        let mut m = n;
+        while m > 1 {
+            let h = m / 2;
+            for (i, chunk) in data.chunks_exact_mut(2 * t).enumerate() {
+                let w = self.inv_psi_powers_bit_reversed[h + i];
+                let w_shoup = self.inv_psi_powers_bit_reversed_shoup[h + i];
+                let (lo, hi) = chunk.split_at_mut(t);
+                for (x, y) in izip!(lo.iter_mut(), hi.iter_mut()) {
+                    let u = *x;
+                    let v = *y;
+                    *x = add_mod(u, v, q);
+                    *y = mul_mod_shoup(sub_mod(u, v, q), w, w_shoup, q);
+                }
+            }
+            t *= 2;
+            m = h;
+        }
+
+        for x in data.iter_mut() {
+            *x = mul_mod_shoup(
+                *x,
+                self.inv_polynomial_size,
+                self.inv_polynomial_size_shoup,
+                q,
+            );
+        }
+    }
+}
+
+impl<'a> Ntt64View<'a> {
+    /// Return the polynomial size that this NTT was made for.
+    pub fn polynomial_size(self) -> PolynomialSize {
+        PolynomialSize(self.plan.polynomial_size)
+    }
+
+    /// Return the prime modulus that this NTT was made for.
+    pub fn custom_modulus(self) -> u64 {
+        self.plan.modulus
+    }
+
+    /// Return the [`CiphertextModulus`] that this NTT was made for.
+    pub fn ciphertext_modulus(self) -> CiphertextModulus<u64> {
+        CiphertextModulus::try_new(self.plan.modulus as u128).unwrap()
+    }
+
+    /// Perform a negacyclic NTT of a polynomial with coefficients in $[0; q[$.
+    ///
+    /// The output is in bit reversed order, which is irrelevant for pointwise products.
+    ///
+    /// # Panics
+    ///
+    /// See [`Self::forward_assign`].
+    pub fn forward(self, ntt: &mut [u64], standard: &[u64]) {
+        ntt.copy_from_slice(standard);
+        self.forward_assign(ntt);
+    }
+
+    /// In place variant of [`Self::forward`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input length is not equal to the polynomial size of this NTT.
+    pub fn forward_assign(self, data: &mut [u64]) {
+        assert_eq!(data.len(), self.plan.polynomial_size);
+        self.plan.forward(data);
+    }
+
+    /// Perform an inverse negacyclic NTT, writing the result in `standard`.
+    ///
+    /// `ntt` is used as a scratch buffer and is left in an unspecified state.
+    pub fn backward(self, standard: &mut [u64], ntt: &mut [u64]) {
+        self.backward_assign(ntt);
+        standard.copy_from_slice(ntt);
+    }
+
+    /// Perform an inverse negacyclic NTT, adding the result to `standard` modulo $q$.
+    ///
+    /// `ntt` is used as a scratch buffer and is left in an unspecified state.
+    pub fn add_backward(self, standard: &mut [u64], ntt: &mut [u64]) {
+        self.backward_assign(ntt);
+        let q = self.plan.modulus;
+        for (out, &value) in izip!(standard.iter_mut(), ntt.iter()) {
+            *out = add_mod(*out, value, q);
+        }
+    }
+
+    /// In place inverse negacyclic NTT.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input length is not equal to the polynomial size of this NTT.
+    pub fn backward_assign(self, data: &mut [u64]) {
+        assert_eq!(data.len(), self.plan.polynomial_size);
+        self.plan.backward(data);
+    }
+
+    /// Compute the pointwise product of `lhs` and `rhs` in the NTT domain and add it to `output`
+    /// modulo $q$.
+    pub fn mul_accumulate(self, output: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+        let q = self.plan.modulus;
+        for (out, &l, &r) in izip!(output.iter_mut(), lhs.iter(), rhs.iter()) {
+            *out = add_mod(*out, mul_mod(l, r, q), q);
+        }
+    }
+}
+
+#[inline(always)]
+pub(crate) fn add_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    let (sum, overflowed) = lhs.overflowing_add(rhs);
+    if overflowed || sum >= modulus {
+        sum.wrapping_sub(modulus)
+    } else {
+        sum
+    }
+}
+
+#[inline(always)]
+pub(crate) fn sub_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    if lhs >= rhs {
+        lhs - rhs
+    } else {
+        lhs.wrapping_sub(rhs).wrapping_add(modulus)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn mul_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    ((lhs as u128 * rhs as u128) % modulus as u128) as u64
+}
+
+/// Shoup representation of `w`, i.e. $\lfloor w 2^{64} / q \rfloor$.
+#[inline(always)]
+fn shoup(w: u64, modulus: u64) -> u64 {
+    (((w as u128) << 64) / modulus as u128) as u64
+}
+
+/// Compute `lhs * w mod q` given the Shoup representation of `w`.
+#[inline(always)]
+fn mul_mod_shoup(lhs: u64, w: u64, w_shoup: u64, modulus: u64) -> u64 {
+    let quotient = ((lhs as u128 * w_shoup as u128) >> 64) as u64;
+    // The approximate quotient is off by at most one, so the result is in [0, 2q[ which may not
+    // fit on 64 bits for q > 2^63, hence the 128 bits arithmetic
+    let result = (lhs as u128 * w as u128).wrapping_sub(quotient as u128 * modulus as u128);
+    if result >= modulus as u128 {
+        (result - modulus as u128) as u64
+    } else {
+        result as u64
+    }
+}
+
+fn pow_mod(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1u64 % modulus;
+    let mut base = base % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Deterministic Miller-Rabin primality test, the set of bases used is sufficient for all 64 bits
+/// integers.
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &p in BASES.iter() {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for &a in BASES.iter() {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+/// Find a primitive `order`-th root of unity modulo the prime `modulus`, `order` being a power of
+/// two dividing `modulus - 1`.
+fn primitive_root_of_unity(order: u64, modulus: u64) -> u64 {
+    let cofactor = (modulus - 1) / order;
+    (2..modulus)
+        .map(|generator| pow_mod(generator, cofactor, modulus))
+        // As order is a power of two, the candidate has the right order iff
+        // candidate^(order / 2) = -1
+        .find(|&candidate| pow_mod(candidate, order / 2, modulus) == modulus - 1)
+        .unwrap()
+}
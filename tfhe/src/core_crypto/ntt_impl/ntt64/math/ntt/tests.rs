@@ -0,0 +1,120 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_add_mul_assign_custom_mod;
+use crate::core_crypto::commons::test_tools::new_random_generator;
+use crate::core_crypto::entities::Polynomial;
+
+// 2^64 - 2^32 + 1, NTT friendly for all polynomial sizes up to 2^31
+const SOLINAS_PRIME: u64 = 0xFFFF_FFFF_0000_0001;
+// A prime smaller than 2^62 with a 2^19 root of unity
+const SMALL_PRIME: u64 = 0x3FFF_FFFF_FFE8_0001;
+
+fn test_roundtrip(modulus: u64) {
+    let mut generator = new_random_generator();
+    let ciphertext_modulus = CiphertextModulus::try_new(modulus as u128).unwrap();
+    for size_log in 1..=14 {
+        let size = 1_usize << size_log;
+
+        let ntt = Ntt64::new(PolynomialSize(size), ciphertext_modulus);
+        let ntt = ntt.as_view();
+
+        let mut poly = vec![0u64; size];
+        let mut ntt_poly = vec![0u64; size];
+        let mut roundtrip = vec![0u64; size];
+
+        for x in poly.iter_mut() {
+            *x = generator.random_uniform_custom_mod(ciphertext_modulus);
+        }
+
+        ntt.forward(&mut ntt_poly, &poly);
+        ntt.backward(&mut roundtrip, &mut ntt_poly);
+
+        assert_eq!(poly, roundtrip);
+    }
+}
+
+fn test_product(modulus: u64) {
+    let mut generator = new_random_generator();
+    let ciphertext_modulus = CiphertextModulus::try_new(modulus as u128).unwrap();
+    for size_log in 1..=10 {
+        for _ in 0..10 {
+            let size = 1_usize << size_log;
+
+            let ntt = Ntt64::new(PolynomialSize(size), ciphertext_modulus);
+            let ntt = ntt.as_view();
+
+            let mut poly0 = Polynomial::new(0u64, PolynomialSize(size));
+            let mut poly1 = Polynomial::new(0u64, PolynomialSize(size));
+            let mut accumulator = Polynomial::new(0u64, PolynomialSize(size));
+            for (x, y, z) in izip!(
+                poly0.as_mut().iter_mut(),
+                poly1.as_mut().iter_mut(),
+                accumulator.as_mut().iter_mut()
+            ) {
+                *x = generator.random_uniform_custom_mod(ciphertext_modulus);
+                *y = generator.random_uniform_custom_mod(ciphertext_modulus);
+                *z = generator.random_uniform_custom_mod(ciphertext_modulus);
+            }
+
+            let mut ntt0 = vec![0u64; size];
+            let mut ntt1 = vec![0u64; size];
+            let mut ntt_prod = vec![0u64; size];
+            ntt.forward(&mut ntt0, poly0.as_ref());
+            ntt.forward(&mut ntt1, poly1.as_ref());
+            ntt.mul_accumulate(&mut ntt_prod, &ntt0, &ntt1);
+
+            let mut product_from_ntt = accumulator.clone();
+            ntt.add_backward(product_from_ntt.as_mut(), &mut ntt_prod);
+
+            let mut product_from_schoolbook = accumulator;
+            polynomial_wrapping_add_mul_assign_custom_mod(
+                &mut product_from_schoolbook,
+                &poly0,
+                &poly1,
+                modulus,
+            );
+
+            assert_eq!(product_from_ntt, product_from_schoolbook);
+        }
+    }
+}
+
+#[test]
+fn test_supported_parameters() {
+    let solinas = CiphertextModulus::try_new(SOLINAS_PRIME as u128).unwrap();
+    assert!(Ntt64::is_supported(PolynomialSize(2048), solinas));
+    assert!(!Ntt64::is_supported(PolynomialSize(2047), solinas));
+    assert!(!Ntt64::is_supported(
+        PolynomialSize(2048),
+        CiphertextModulus::new_native()
+    ));
+    // Not a prime
+    assert!(!Ntt64::is_supported(
+        PolynomialSize(2048),
+        CiphertextModulus::try_new(SOLINAS_PRIME as u128 + 2 * 2048).unwrap()
+    ));
+    // Prime but not congruent to 1 mod 2N
+    assert!(!Ntt64::is_supported(
+        PolynomialSize(2048),
+        CiphertextModulus::try_new((1 << 61) - 1).unwrap()
+    ));
+}
+
+#[test]
+fn test_roundtrip_solinas() {
+    test_roundtrip(SOLINAS_PRIME);
+}
+
+#[test]
+fn test_roundtrip_small_prime() {
+    test_roundtrip(SMALL_PRIME);
+}
+
+#[test]
+fn test_product_solinas() {
+    test_product(SOLINAS_PRIME);
+}
+
+#[test]
+fn test_product_small_prime() {
+    test_product(SMALL_PRIME);
+}
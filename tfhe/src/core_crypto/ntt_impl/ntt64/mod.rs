@@ -0,0 +1,4 @@
+#![allow(clippy::too_many_arguments)]
+
+pub mod crypto;
+pub mod math;
@@ -14,4 +14,5 @@ pub use super::commons::traits::*;
 pub use super::entities::*;
 pub use super::fft_impl::fft128::math::fft::Fft128;
 pub use super::fft_impl::fft64::math::fft::Fft;
+pub use super::ntt_impl::ntt64::math::ntt::Ntt64;
 pub use super::seeders::*;
@@ -7,10 +7,15 @@
 pub use crate::core_crypto::commons::math::random::Seeder;
 #[cfg(all(target_os = "macos", not(feature = "__wasm_api")))]
 pub use concrete_csprng::seeders::AppleSecureEnclaveSeeder;
+#[cfg(feature = "seeder_conditioned")]
+pub use concrete_csprng::seeders::ConditionedSeeder;
+#[cfg(all(feature = "seeder_unix", target_os = "linux"))]
+pub use concrete_csprng::seeders::GetrandomSeeder;
 #[cfg(feature = "seeder_x86_64_rdseed")]
 pub use concrete_csprng::seeders::RdseedSeeder;
 #[cfg(feature = "seeder_unix")]
 pub use concrete_csprng::seeders::UnixSeeder;
+pub use concrete_csprng::seeders::{HealthTestError, HealthTestedSeeder};
 
 #[cfg(feature = "__wasm_api")]
 mod wasm_seeder {
@@ -0,0 +1,196 @@
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::integers::{GenericInteger, IntegerId};
+use crate::integer::ciphertext::{
+    CompressedCiphertextList as IntegerCompressedCiphertextList,
+    CompressedCiphertextListBuilder as IntegerCompressedCiphertextListBuilder, Compressible,
+    DataKind, Expandable,
+};
+use crate::integer::compression_keys::DecompressionKey;
+use crate::integer::BooleanBlock;
+use crate::shortint::Ciphertext;
+use crate::{Error, FheBool};
+use serde::{Deserialize, Serialize};
+
+impl<Id> Compressible for GenericInteger<Id>
+where
+    Id: IntegerId,
+    Id::InnerCiphertext: Compressible,
+{
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        self.ciphertext.compress_into(messages)
+    }
+}
+
+impl Compressible for FheBool {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        self.ciphertext.compress_into(messages)
+    }
+}
+
+impl<Id> Expandable for GenericInteger<Id>
+where
+    Id: IntegerId,
+    Id::InnerCiphertext: Expandable,
+{
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> Self {
+        assert_eq!(
+            kind.num_blocks(),
+            Id::num_blocks(),
+            "Tried to expand an integer of {} blocks while a {kind:?} is stored in this slot",
+            Id::num_blocks(),
+        );
+
+        Self::new(
+            Id::InnerCiphertext::from_expanded_blocks(blocks, kind),
+            Id::default(),
+        )
+    }
+}
+
+impl Expandable for FheBool {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> Self {
+        Self::new(BooleanBlock::from_expanded_blocks(blocks, kind))
+    }
+}
+
+/// A builder used to pack many encrypted values in a [`CompressedCiphertextList`].
+///
+/// The compression must have been enabled in the [`Config`](`crate::Config`) used to generate the
+/// keys, see [`ConfigBuilder::enable_compression`](`crate::ConfigBuilder::enable_compression`).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::COMP_PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::{
+///     generate_keys, set_server_key, CompressedCiphertextListBuilder, ConfigBuilder, FheBool,
+///     FheInt64, FheUint16, FheUint32,
+/// };
+///
+/// let config = ConfigBuilder::default()
+///     .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+///     .build();
+///
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint32::encrypt(17u32, &client_key);
+/// let b = FheInt64::encrypt(-1i64, &client_key);
+/// let c = FheBool::encrypt(true, &client_key);
+///
+/// let compressed = CompressedCiphertextListBuilder::new()
+///     .push(a)
+///     .push(b)
+///     .push(c)
+///     .build();
+///
+/// let a: FheUint32 = compressed.get(0).unwrap().unwrap();
+/// let decrypted: u32 = a.decrypt(&client_key);
+/// assert_eq!(decrypted, 17);
+///
+/// let b: FheInt64 = compressed.get(1).unwrap().unwrap();
+/// let decrypted: i64 = b.decrypt(&client_key);
+/// assert_eq!(decrypted, -1);
+///
+/// let c: FheBool = compressed.get(2).unwrap().unwrap();
+/// assert!(c.decrypt(&client_key));
+///
+/// assert!(compressed.get::<FheUint16>(3).unwrap().is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct CompressedCiphertextListBuilder {
+    inner: IntegerCompressedCiphertextListBuilder,
+}
+
+impl CompressedCiphertextListBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an encrypted value at the end of the list.
+    pub fn push<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Compressible,
+    {
+        self.inner.push(value);
+        self
+    }
+
+    /// Add the encrypted values of an iterator at the end of the list.
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compressible,
+    {
+        self.inner.extend(values);
+        self
+    }
+
+    /// Pack the values added so far in a [`CompressedCiphertextList`], using the compression key
+    /// of the server key set in the current thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the compression was not enabled in the server key, or if no value was added.
+    pub fn build(&self) -> CompressedCiphertextList {
+        let inner = with_internal_keys(|keys| {
+            let compression_key = keys.integer_key.compression_key.as_ref().expect(
+                "Compression key not set in the server key, \
+                 did you forget to call `enable_compression` on the ConfigBuilder?",
+            );
+
+            self.inner.build(compression_key)
+        });
+
+        CompressedCiphertextList { inner }
+    }
+}
+
+/// A list of encrypted values, possibly of different types, packed to take less space.
+///
+/// See [`CompressedCiphertextListBuilder`] for usage.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    inner: IntegerCompressedCiphertextList,
+}
+
+impl crate::named::Named for CompressedCiphertextList {
+    const NAME: &'static str = "high_level_api::CompressedCiphertextList";
+}
+
+impl CompressedCiphertextList {
+    /// Return the number of values stored in the list.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Return `true` if the list does not contain any value.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Decompress the value stored at the given index, using the decompression key of the server
+    /// key set in the current thread.
+    ///
+    /// Returns `Ok(None)` if the index is out of bounds, and an error if the list is inconsistent,
+    /// e.g. because it was deserialized from corrupted data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the compression was not enabled in the server key, or if the value stored at the
+    /// given index is not of type `T`.
+    pub fn get<T>(&self, index: usize) -> Result<Option<T>, Error>
+    where
+        T: Expandable,
+    {
+        with_internal_keys(|keys| {
+            let decompression_key: &DecompressionKey =
+                keys.integer_key.decompression_key.as_ref().expect(
+                    "Decompression key not set in the server key, \
+                     did you forget to call `enable_compression` on the ConfigBuilder?",
+                );
+
+            Ok(self.inner.get(index, decompression_key)?)
+        })
+    }
+}
@@ -1,4 +1,6 @@
+use crate::high_level_api::errors::UnwrapResultExt;
 use crate::high_level_api::integers::IntegerConfig;
+use crate::shortint::parameters::DecryptionCompressionParameters;
 
 /// The config type
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -28,6 +30,30 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enable the compression of ciphertext lists, see
+    /// [`CompressedCiphertextListBuilder`](`crate::CompressedCiphertextListBuilder`).
+    ///
+    /// The compression requires parameters using the big encryption key.
+    pub fn enable_compression(
+        mut self,
+        compression_parameters: crate::shortint::parameters::CompressionParameters,
+    ) -> Self {
+        self.config.inner.enable_compression(compression_parameters);
+        self
+    }
+
+    /// Enable the compression of ciphertexts before their decryption, see
+    /// [`FheUint64::compress_for_decryption`](`crate::FheUint64::compress_for_decryption`).
+    pub fn enable_decryption_compression(
+        mut self,
+        decryption_compression_parameters: DecryptionCompressionParameters,
+    ) -> Self {
+        self.config
+            .inner
+            .enable_decryption_compression(decryption_compression_parameters);
+        self
+    }
+
     pub fn default_with_big_encryption() -> Self {
         Self {
             config: Config {
@@ -44,6 +70,13 @@ impl ConfigBuilder {
         }
     }
 
+    /// Create a builder using the given custom parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the estimated security of the block parameters does not reach
+    /// [`DEFAULT_SECURITY_BITS`](`crate::shortint::parameters::DEFAULT_SECURITY_BITS`), see
+    /// [`Self::try_use_custom_parameters`] for a non panicking version.
     pub fn with_custom_parameters<P>(
         block_parameters: P,
         wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
@@ -51,23 +84,63 @@ impl ConfigBuilder {
     where
         P: Into<crate::shortint::PBSParameters>,
     {
-        Self {
-            config: Config {
-                inner: IntegerConfig::new(block_parameters.into(), wopbs_block_parameters),
-            },
-        }
+        Self::default().use_custom_parameters(block_parameters, wopbs_block_parameters)
     }
 
+    /// Use the given custom parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the estimated security of the block parameters does not reach
+    /// [`DEFAULT_SECURITY_BITS`](`crate::shortint::parameters::DEFAULT_SECURITY_BITS`), see
+    /// [`Self::try_use_custom_parameters`] for a non panicking version.
     pub fn use_custom_parameters<P>(
-        mut self,
+        self,
         block_parameters: P,
         wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
     ) -> Self
     where
         P: Into<crate::shortint::PBSParameters>,
     {
-        self.config.inner = IntegerConfig::new(block_parameters.into(), wopbs_block_parameters);
-        self
+        self.try_use_custom_parameters(block_parameters, wopbs_block_parameters)
+            .unwrap_display()
+    }
+
+    /// Same as [`Self::use_custom_parameters`], but returns an error instead of panicking if the
+    /// estimated security of the block parameters does not reach [`DEFAULT_SECURITY_BITS`], see
+    /// [`PBSParameters::check_security`](`crate::shortint::PBSParameters::check_security`).
+    ///
+    /// [`DEFAULT_SECURITY_BITS`]: crate::shortint::parameters::DEFAULT_SECURITY_BITS
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{StandardDev, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+    /// use tfhe::ConfigBuilder;
+    ///
+    /// let builder = ConfigBuilder::default();
+    /// assert!(builder
+    ///     .clone()
+    ///     .try_use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS, None)
+    ///     .is_ok());
+    ///
+    /// let mut insecure_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// insecure_params.glwe_modular_std_dev = StandardDev(2.0f64.powi(-62));
+    /// assert!(builder
+    ///     .try_use_custom_parameters(insecure_params, None)
+    ///     .is_err());
+    /// ```
+    pub fn try_use_custom_parameters<P>(
+        mut self,
+        block_parameters: P,
+        wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    ) -> Result<Self, crate::shortint::InsecureParametersError>
+    where
+        P: Into<crate::shortint::PBSParameters>,
+    {
+        let block_parameters = block_parameters.into();
+        block_parameters.check_security(crate::shortint::parameters::DEFAULT_SECURITY_BITS)?;
+
+        self.config.inner = IntegerConfig::new(block_parameters, wopbs_block_parameters);
+        Ok(self)
     }
 
     pub fn build(self) -> Config {
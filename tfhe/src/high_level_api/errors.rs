@@ -83,6 +83,9 @@ impl std::error::Error for OutOfRangeError {}
 pub enum Error {
     OutOfRange,
     UninitializedServerKey,
+    InconsistentCompressedList,
+    #[cfg(feature = "zk-pok-experimental")]
+    InvalidProof,
 }
 
 impl From<OutOfRangeError> for Error {
@@ -91,6 +94,19 @@ impl From<OutOfRangeError> for Error {
     }
 }
 
+#[cfg(feature = "zk-pok-experimental")]
+impl From<crate::zk::InvalidProofError> for Error {
+    fn from(_: crate::zk::InvalidProofError) -> Self {
+        Self::InvalidProof
+    }
+}
+
+impl From<crate::integer::ciphertext::InconsistentCompressedListError> for Error {
+    fn from(_: crate::integer::ciphertext::InconsistentCompressedListError) -> Self {
+        Self::InconsistentCompressedList
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -100,6 +116,17 @@ impl Display for Error {
             Self::UninitializedServerKey => {
                 write!(f, "{}", UninitializedServerKey)
             }
+            Self::InconsistentCompressedList => {
+                write!(
+                    f,
+                    "{}",
+                    crate::integer::ciphertext::InconsistentCompressedListError
+                )
+            }
+            #[cfg(feature = "zk-pok-experimental")]
+            Self::InvalidProof => {
+                write!(f, "{}", crate::zk::InvalidProofError)
+            }
         }
     }
 }
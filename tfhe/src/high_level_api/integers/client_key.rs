@@ -1,10 +1,19 @@
 use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
-use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey};
+use crate::high_level_api::internal_traits::{
+    CompressedForDecryptionDecrypter, DecryptionKey, EncryptionKey, PartialDecryptionCombiner,
+};
 use crate::integer::ciphertext::{
     CompressedRadixCiphertext, CompressedSignedRadixCiphertext, RadixCiphertext,
 };
 use crate::integer::client_key::RecomposableSignedInteger;
+use crate::integer::decryption_compression::{
+    DecryptionCompressedRadixCiphertext, DecryptionCompressionPrivateKey,
+};
 use crate::integer::public_key::CompactPublicKey;
+use crate::integer::threshold_decryption::{
+    combine_radix_partial_decryptions, combine_signed_radix_partial_decryptions,
+    RadixPartialDecryption,
+};
 use crate::integer::SignedRadixCiphertext;
 
 impl<ClearType> DecryptionKey<RadixCiphertext, ClearType> for crate::integer::ClientKey
@@ -16,6 +25,35 @@ where
     }
 }
 
+impl<ClearType> PartialDecryptionCombiner<RadixPartialDecryption, ClearType> for RadixCiphertext
+where
+    ClearType: crate::integer::block_decomposition::RecomposableFrom<u64> + UnsignedNumeric,
+{
+    fn combine_partial_decryptions(
+        &self,
+        partial_decryptions: &[RadixPartialDecryption],
+    ) -> ClearType {
+        combine_radix_partial_decryptions(self, partial_decryptions)
+    }
+}
+
+impl<ClearType>
+    CompressedForDecryptionDecrypter<
+        DecryptionCompressionPrivateKey,
+        DecryptionCompressedRadixCiphertext,
+        ClearType,
+    > for RadixCiphertext
+where
+    ClearType: crate::integer::block_decomposition::RecomposableFrom<u64> + UnsignedNumeric,
+{
+    fn decrypt_compressed(
+        key: &DecryptionCompressionPrivateKey,
+        compressed: &DecryptionCompressedRadixCiphertext,
+    ) -> ClearType {
+        key.decrypt_radix(compressed)
+    }
+}
+
 impl<T> EncryptionKey<(T, usize), RadixCiphertext> for crate::integer::ClientKey
 where
     T: crate::integer::block_decomposition::DecomposableInto<u64> + UnsignedNumeric,
@@ -71,6 +109,36 @@ where
     }
 }
 
+impl<ClearType> PartialDecryptionCombiner<RadixPartialDecryption, ClearType>
+    for SignedRadixCiphertext
+where
+    ClearType: RecomposableSignedInteger,
+{
+    fn combine_partial_decryptions(
+        &self,
+        partial_decryptions: &[RadixPartialDecryption],
+    ) -> ClearType {
+        combine_signed_radix_partial_decryptions(self, partial_decryptions)
+    }
+}
+
+impl<ClearType>
+    CompressedForDecryptionDecrypter<
+        DecryptionCompressionPrivateKey,
+        DecryptionCompressedRadixCiphertext,
+        ClearType,
+    > for SignedRadixCiphertext
+where
+    ClearType: RecomposableSignedInteger,
+{
+    fn decrypt_compressed(
+        key: &DecryptionCompressionPrivateKey,
+        compressed: &DecryptionCompressedRadixCiphertext,
+    ) -> ClearType {
+        key.decrypt_signed_radix(compressed)
+    }
+}
+
 impl<T> EncryptionKey<(T, usize), SignedRadixCiphertext> for crate::integer::ClientKey
 where
     T: crate::integer::block_decomposition::DecomposableInto<u64> + SignedNumeric,
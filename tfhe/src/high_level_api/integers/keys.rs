@@ -4,14 +4,21 @@ use serde::{Deserialize, Serialize};
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
 use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::compression_keys::{CompressionKey, CompressionPrivateKeys, DecompressionKey};
+use crate::integer::decryption_compression::{
+    DecryptionCompressionKey, DecryptionCompressionPrivateKey,
+};
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::CompressedCompactPublicKey;
+use crate::shortint::parameters::{CompressionParameters, DecryptionCompressionParameters};
 use crate::shortint::EncryptionKeyChoice;
 
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerConfig {
     pub(crate) block_parameters: crate::shortint::PBSParameters,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) compression_params: Option<CompressionParameters>,
+    pub(crate) decryption_compression_params: Option<DecryptionCompressionParameters>,
 }
 
 impl IntegerConfig {
@@ -22,6 +29,8 @@ impl IntegerConfig {
         Self {
             block_parameters,
             wopbs_block_parameters,
+            compression_params: None,
+            decryption_compression_params: None,
         }
     }
 
@@ -29,6 +38,8 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
             wopbs_block_parameters: None,
+            compression_params: None,
+            decryption_compression_params: None,
         }
     }
 
@@ -36,6 +47,8 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS.into(),
             wopbs_block_parameters: None,
+            compression_params: None,
+            decryption_compression_params: None,
         }
     }
 
@@ -47,23 +60,46 @@ impl IntegerConfig {
 
         self.wopbs_block_parameters = Some(wopbs_block_parameters);
     }
+
+    pub fn enable_compression(&mut self, compression_parameters: CompressionParameters) {
+        self.compression_params = Some(compression_parameters);
+    }
+
+    pub fn enable_decryption_compression(
+        &mut self,
+        decryption_compression_parameters: DecryptionCompressionParameters,
+    ) {
+        self.decryption_compression_params = Some(decryption_compression_parameters);
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerClientKey {
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) compression_key: Option<CompressionPrivateKeys>,
+    pub(crate) decryption_compression_key: Option<DecryptionCompressionPrivateKey>,
 }
 
 impl IntegerClientKey {
     pub(crate) fn with_seed(config: IntegerConfig, seed: Seed) -> Self {
         let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
-        let cks = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder)
-            .new_client_key(config.block_parameters.into());
+        let mut engine = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder);
+        let cks = engine.new_client_key(config.block_parameters.into());
+        let compression_key = config.compression_params.map(|params| {
+            CompressionPrivateKeys::from(engine.new_compression_private_key(&cks, params))
+        });
+        let decryption_compression_key = config.decryption_compression_params.map(|params| {
+            DecryptionCompressionPrivateKey::from(
+                engine.new_decryption_compression_private_key(params),
+            )
+        });
         let key = crate::integer::ClientKey::from(cks);
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            compression_key,
+            decryption_compression_key,
         }
     }
 
@@ -76,9 +112,17 @@ impl IntegerClientKey {
 impl From<IntegerConfig> for IntegerClientKey {
     fn from(config: IntegerConfig) -> Self {
         let key = crate::integer::ClientKey::new(config.block_parameters);
+        let compression_key = config
+            .compression_params
+            .map(|params| key.new_compression_private_key(params));
+        let decryption_compression_key = config
+            .decryption_compression_params
+            .map(|params| key.new_decryption_compression_private_key(params));
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            compression_key,
+            decryption_compression_key,
         }
     }
 }
@@ -87,6 +131,9 @@ impl From<IntegerConfig> for IntegerClientKey {
 pub struct IntegerServerKey {
     pub(crate) key: crate::integer::ServerKey,
     pub(crate) wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+    pub(crate) compression_key: Option<CompressionKey>,
+    pub(crate) decompression_key: Option<DecompressionKey>,
+    pub(crate) decryption_compression_key: Option<DecryptionCompressionKey>,
 }
 
 impl IntegerServerKey {
@@ -104,9 +151,25 @@ impl IntegerServerKey {
             .map(|wopbs_params| {
                 crate::integer::wopbs::WopbsKey::new_wopbs_key(cks, &base_integer_key, wopbs_params)
             });
+        let (compression_key, decompression_key) =
+            client_key
+                .compression_key
+                .as_ref()
+                .map_or((None, None), |private_compression_key| {
+                    let (compression_key, decompression_key) =
+                        cks.new_compression_decompression_keys(private_compression_key);
+                    (Some(compression_key), Some(decompression_key))
+                });
+        let decryption_compression_key = client_key
+            .decryption_compression_key
+            .as_ref()
+            .map(|private_key| cks.new_decryption_compression_key(private_key));
         Self {
             key: base_integer_key,
             wopbs_key,
+            compression_key,
+            decompression_key,
+            decryption_compression_key,
         }
     }
 
@@ -115,9 +178,74 @@ impl IntegerServerKey {
     }
 }
 
+/// An [`IntegerServerKey`] which is generated while it is serialized.
+pub(in crate::high_level_api) struct IntegerServerKeyStream<'a> {
+    client_key: &'a IntegerClientKey,
+}
+
+impl<'a> IntegerServerKeyStream<'a> {
+    pub(in crate::high_level_api) fn new(client_key: &'a IntegerClientKey) -> Self {
+        assert_eq!(
+            client_key.key.parameters().message_modulus().0,
+            4,
+            "This API only supports integers with 2 bits per block (MessageModulus(4))",
+        );
+        assert!(
+            client_key.wopbs_block_parameters.is_none(),
+            "The configuration used to create the ClientKey \
+                   had function evaluation on integers enabled.
+                   This feature requires an additional key that can only be
+                   generated from a server key held in memory. Thus, It is not possible
+                   to generate the ServerKey while serializing it.
+                   "
+        );
+        Self { client_key }
+    }
+}
+
+impl<'a> Serialize for IntegerServerKeyStream<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let cks = &self.client_key.key;
+
+        // Same layout as the serde derive of IntegerServerKey, the compression keys are small
+        // compared to the server key and are generated in memory once it has been serialized
+        let mut state = serializer.serialize_struct("IntegerServerKey", 5)?;
+        state.serialize_field(
+            "key",
+            &crate::integer::server_key::ServerKeyStream::new_radix(cks),
+        )?;
+        state.serialize_field("wopbs_key", &None::<crate::integer::wopbs::WopbsKey>)?;
+        let (compression_key, decompression_key) = self.client_key.compression_key.as_ref().map_or(
+            (None, None),
+            |private_compression_key| {
+                let (compression_key, decompression_key) =
+                    cks.new_compression_decompression_keys(private_compression_key);
+                (Some(compression_key), Some(decompression_key))
+            },
+        );
+        state.serialize_field("compression_key", &compression_key)?;
+        state.serialize_field("decompression_key", &decompression_key)?;
+        let decryption_compression_key = self
+            .client_key
+            .decryption_compression_key
+            .as_ref()
+            .map(|private_key| cks.new_decryption_compression_key(private_key));
+        state.serialize_field("decryption_compression_key", &decryption_compression_key)?;
+        state.end()
+    }
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct IntegerCompressedServerKey {
     pub(crate) key: crate::integer::CompressedServerKey,
+    pub(crate) compression_key: Option<CompressionKey>,
+    pub(crate) decompression_key: Option<DecompressionKey>,
+    pub(crate) decryption_compression_key: Option<DecryptionCompressionKey>,
 }
 
 impl IntegerCompressedServerKey {
@@ -133,13 +261,34 @@ impl IntegerCompressedServerKey {
                    "
         );
         let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(integer_key);
-        Self { key }
+        let (compression_key, decompression_key) =
+            client_key
+                .compression_key
+                .as_ref()
+                .map_or((None, None), |private_compression_key| {
+                    let (compression_key, decompression_key) =
+                        integer_key.new_compression_decompression_keys(private_compression_key);
+                    (Some(compression_key), Some(decompression_key))
+                });
+        let decryption_compression_key = client_key
+            .decryption_compression_key
+            .as_ref()
+            .map(|private_key| integer_key.new_decryption_compression_key(private_key));
+        Self {
+            key,
+            compression_key,
+            decompression_key,
+            decryption_compression_key,
+        }
     }
 
     pub(in crate::high_level_api) fn decompress(self) -> IntegerServerKey {
         IntegerServerKey {
             key: crate::integer::ServerKey::from(self.key),
             wopbs_key: None,
+            compression_key: self.compression_key,
+            decompression_key: self.decompression_key,
+            decryption_compression_key: self.decryption_compression_key,
         }
     }
 }
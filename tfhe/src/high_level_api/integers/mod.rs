@@ -7,7 +7,7 @@ expand_pub_use_fhe_type!(
 
 pub(in crate::high_level_api) use keys::{
     IntegerClientKey, IntegerCompactPublicKey, IntegerCompressedCompactPublicKey,
-    IntegerCompressedServerKey, IntegerConfig, IntegerServerKey,
+    IntegerCompressedServerKey, IntegerConfig, IntegerServerKey, IntegerServerKeyStream,
 };
 
 pub(in crate::high_level_api) use parameters::IntegerId;
@@ -2,8 +2,8 @@ use crate::integer::I256;
 use crate::prelude::*;
 use crate::{
     generate_keys, set_server_key, CompactFheInt32, CompactFheInt32List, CompactPublicKey,
-    CompressedFheInt16, Config, ConfigBuilder, FheInt16, FheInt256, FheInt32, FheInt64, FheInt8,
-    FheUint64, FheUint8,
+    CompressedFheInt16, Config, ConfigBuilder, DecryptionCompressedFheInt64, FheInt16, FheInt256,
+    FheInt32, FheInt64, FheInt8, FheUint64, FheUint8,
 };
 use rand::prelude::*;
 
@@ -626,3 +626,36 @@ fn test_compact_public_key_list(config: Config) {
     let decrypted: i32 = a.decrypt(&client_key);
     assert_eq!(clear_xs[0], decrypted);
 }
+
+#[test]
+fn test_compress_for_decryption() {
+    use crate::shortint::parameters::DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2;
+
+    let config = ConfigBuilder::default()
+        .enable_decryption_compression(DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2)
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    let clear_a = rng.gen::<i64>();
+    let clear_b = rng.gen::<i64>();
+
+    let a = FheInt64::encrypt(clear_a, &client_key);
+    let b = FheInt64::encrypt(clear_b, &client_key);
+    let c = &a - &b;
+
+    let compressed = c.compress_for_decryption();
+
+    let serialized = bincode::serialize(&compressed).unwrap();
+    assert!(4 * serialized.len() < bincode::serialize(&c).unwrap().len());
+
+    let compressed: DecryptionCompressedFheInt64 = bincode::deserialize(&serialized).unwrap();
+    let decrypted: i64 = compressed.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_sub(clear_b));
+
+    let d = FheInt8::encrypt(-3i8, &client_key);
+    let decrypted: i8 = d.compress_for_decryption().decrypt(&client_key);
+    assert_eq!(decrypted, -3);
+}
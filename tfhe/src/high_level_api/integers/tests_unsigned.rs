@@ -823,3 +823,37 @@ fn test_if_then_else() {
         if clear_a <= clear_b { clear_b } else { clear_a }
     );
 }
+
+#[test]
+fn test_uint32_threshold_decryption() {
+    use crate::shortint::parameters::{SecretSharingScheme, StandardDev, ThresholdPartyCount};
+
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let shares = client_key.share(SecretSharingScheme::Additive {
+        party_count: ThresholdPartyCount(3),
+    });
+    let flooding_noise = StandardDev(0.0000000009313225746154785);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u32>();
+    let clear_b = rng.gen::<u32>();
+
+    let a = FheUint32::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+    let c = &a * &b;
+
+    let partial_decryptions: Vec<_> = shares
+        .iter()
+        .map(|share| c.partial_decrypt(share, flooding_noise))
+        .collect();
+
+    for (partial_decryption, share) in partial_decryptions.iter().zip(shares.iter()) {
+        assert_eq!(partial_decryption.party_index(), share.party_index());
+    }
+
+    let decrypted: u32 = c.combine_partial_decryptions(&partial_decryptions);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_b));
+}
@@ -8,15 +8,18 @@ use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::CastFrom;
 use crate::high_level_api::global_state::WithGlobalKey;
 use crate::high_level_api::integers::parameters::IntegerId;
+use crate::high_level_api::integers::types::decryption_compressed::GenericDecryptionCompressedInteger;
 use crate::high_level_api::integers::IntegerServerKey;
-use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey};
+use crate::high_level_api::internal_traits::{
+    DecryptionKey, EncryptionKey, PartialDecryptionCombiner,
+};
 use crate::high_level_api::keys::CompressedPublicKey;
 use crate::high_level_api::traits::{
     DivRem, FheBootstrap, FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTrivialEncrypt,
     FheTryEncrypt, FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
-use crate::high_level_api::{ClientKey, PublicKey};
+use crate::high_level_api::{ClientKey, ClientKeyShare, PartialDecryption, PublicKey};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{IntegerRadixCiphertext, RadixCiphertext};
 use crate::integer::parameters::RadixCiphertextConformanceParams;
@@ -115,6 +118,178 @@ where
 
         Self::new(ciphertext, self.id)
     }
+
+    /// Returns a re-randomized copy of the value, by adding a fresh encryption of zero
+    /// under the given [CompactPublicKey].
+    ///
+    /// The result decrypts to the same value, but its mask is independent from the one of `self`.
+    /// See [Self::sanitize] to also refresh the noise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// let public_key = CompactPublicKey::new(&client_key);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint32::encrypt(1234u32, &client_key);
+    /// let b = FheUint32::encrypt(4321u32, &client_key);
+    /// let c = &a + &b;
+    ///
+    /// let rerandomized = c.rerandomize(&public_key);
+    /// let decrypted: u32 = rerandomized.decrypt(&client_key);
+    /// assert_eq!(decrypted, 5555);
+    ///
+    /// let sanitized = c.sanitize(&public_key);
+    /// let decrypted: u32 = sanitized.decrypt(&client_key);
+    /// assert_eq!(decrypted, 5555);
+    /// ```
+    pub fn rerandomize(&self, key: &CompactPublicKey) -> Self {
+        let mut ciphertext = self.ciphertext.clone();
+        key.key.key.rerandomize_assign(&mut ciphertext);
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Returns a sanitized copy of the value, with a fresh mask and a nominal noise level.
+    ///
+    /// The value is re-randomized with the given [CompactPublicKey] then bootstrapped with the
+    /// server key. No noise flooding is applied: the noise of the result still depends on the one
+    /// of `self`, so the result is not statistically unlinkable to `self`, see
+    /// [crate::shortint::ServerKey::sanitizing_bootstrap_assign].
+    ///
+    /// See [Self::rerandomize] for an example.
+    pub fn sanitize(&self, key: &CompactPublicKey) -> Self {
+        let mut ciphertext = self.ciphertext.clone();
+        key.key.key.rerandomize_assign(&mut ciphertext);
+
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .sanitizing_bootstrap_assign_parallelized(&mut ciphertext);
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Compresses the value so that it can be sent to the owner of the [ClientKey] and decrypted.
+    ///
+    /// The value is keyswitched to a small dedicated key and switched to a 32 bits modulus, using
+    /// the decryption compression key of the server key set in the current thread. The result is
+    /// several times smaller than the value, but it cannot be used in further computations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decryption compression was not enabled in the server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint64};
+    ///
+    /// let config = ConfigBuilder::default()
+    ///     .enable_decryption_compression(DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2)
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint64::encrypt(1234u64, &client_key);
+    /// let b = FheUint64::encrypt(4321u64, &client_key);
+    /// let c = &a + &b;
+    ///
+    /// let compressed = c.compress_for_decryption();
+    /// let decrypted: u64 = compressed.decrypt(&client_key);
+    /// assert_eq!(decrypted, 5555);
+    /// ```
+    pub fn compress_for_decryption(&self) -> GenericDecryptionCompressedInteger<Id> {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let decryption_compression_key =
+                keys.integer_key.decryption_compression_key.as_ref().expect(
+                    "Decryption compression key not set in the server key, \
+                     did you forget to call `enable_decryption_compression` on the ConfigBuilder?",
+                );
+
+            decryption_compression_key.compress_radix_for_decryption(&self.ciphertext)
+        });
+
+        GenericDecryptionCompressedInteger::new(ciphertext, self.id)
+    }
+
+    /// Computes the partial decryption of the value with a share of the [ClientKey].
+    ///
+    /// The partial decryption is flooded with a Gaussian noise of standard deviation
+    /// `flooding_noise` so that it does not leak information about the share. It must be at least
+    /// $2^{\lambda}$ times larger than a bound on the noise of the value for a statistical security
+    /// of $\lambda$ bits, which requires parameters leaving room for it, see
+    /// [`crate::shortint::threshold_decryption`]. The default parameters used in the example do
+    /// not leave such room, the example only illustrates the API.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::{SecretSharingScheme, StandardDev, ThresholdPartyCount};
+    /// use tfhe::{generate_keys, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, _server_key) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let shares = client_key.share(SecretSharingScheme::Additive {
+    ///     party_count: ThresholdPartyCount(3),
+    /// });
+    ///
+    /// let a = FheInt16::encrypt(-1234i16, &client_key);
+    ///
+    /// let flooding_noise = StandardDev(2.0f64.powi(-30));
+    /// let partial_decryptions: Vec<_> = shares
+    ///     .iter()
+    ///     .map(|share| a.partial_decrypt(share, flooding_noise))
+    ///     .collect();
+    ///
+    /// let decrypted: i16 = a.combine_partial_decryptions(&partial_decryptions);
+    /// assert_eq!(decrypted, -1234i16);
+    /// ```
+    pub fn partial_decrypt(
+        &self,
+        share: &ClientKeyShare,
+        flooding_noise: crate::shortint::parameters::StandardDev,
+    ) -> PartialDecryption {
+        PartialDecryption {
+            inner: share
+                .key
+                .partial_decrypt_radix(&self.ciphertext, flooding_noise),
+        }
+    }
+
+    /// Combines partial decryptions of the value computed by enough distinct parties and returns
+    /// the decrypted value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if less partial decryptions than the threshold of the sharing scheme are provided,
+    /// or if they do not come from distinct parties.
+    pub fn combine_partial_decryptions<ClearType>(
+        &self,
+        partial_decryptions: &[PartialDecryption],
+    ) -> ClearType
+    where
+        Id::InnerCiphertext: PartialDecryptionCombiner<
+            crate::integer::threshold_decryption::RadixPartialDecryption,
+            ClearType,
+        >,
+    {
+        let partial_decryptions: Vec<_> = partial_decryptions
+            .iter()
+            .map(|partial_decryption| partial_decryption.inner.clone())
+            .collect();
+
+        self.ciphertext
+            .combine_partial_decryptions(&partial_decryptions)
+    }
 }
 
 impl<FromId, IntoId> CastFrom<GenericInteger<FromId>> for GenericInteger<IntoId>
@@ -0,0 +1,59 @@
+use crate::high_level_api::integers::parameters::IntegerId;
+use crate::high_level_api::internal_traits::CompressedForDecryptionDecrypter;
+use crate::high_level_api::traits::FheDecrypt;
+use crate::high_level_api::ClientKey;
+use crate::integer::decryption_compression::{
+    DecryptionCompressedRadixCiphertext, DecryptionCompressionPrivateKey,
+};
+use crate::named::Named;
+
+/// An integer compressed to be sent to the owner of the [ClientKey], it can only be decrypted.
+///
+/// See [`FheUint64::compress_for_decryption`](`crate::FheUint64::compress_for_decryption`) for
+/// usage.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct GenericDecryptionCompressedInteger<Id>
+where
+    Id: IntegerId,
+{
+    pub(in crate::high_level_api::integers) ciphertext: DecryptionCompressedRadixCiphertext,
+    pub(in crate::high_level_api::integers) id: Id,
+}
+
+impl<Id: IntegerId> Named for GenericDecryptionCompressedInteger<Id> {
+    const NAME: &'static str = "high_level_api::GenericDecryptionCompressedInteger";
+}
+
+impl<Id> GenericDecryptionCompressedInteger<Id>
+where
+    Id: IntegerId,
+{
+    pub(in crate::high_level_api::integers) fn new(
+        ciphertext: DecryptionCompressedRadixCiphertext,
+        id: Id,
+    ) -> Self {
+        Self { ciphertext, id }
+    }
+}
+
+impl<Id, ClearType> FheDecrypt<ClearType> for GenericDecryptionCompressedInteger<Id>
+where
+    Id: IntegerId,
+    Id::InnerCiphertext: CompressedForDecryptionDecrypter<
+        DecryptionCompressionPrivateKey,
+        DecryptionCompressedRadixCiphertext,
+        ClearType,
+    >,
+{
+    /// # Panics
+    ///
+    /// Panics if the decryption compression was not enabled in the [ClientKey].
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        let private_key = key.key.decryption_compression_key.as_ref().expect(
+            "Decryption compression key not set in the client key, \
+             did you forget to call `enable_decryption_compression` on the ConfigBuilder?",
+        );
+
+        Id::InnerCiphertext::decrypt_compressed(private_key, &self.ciphertext)
+    }
+}
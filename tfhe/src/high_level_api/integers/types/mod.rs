@@ -10,4 +10,7 @@ expand_pub_use_fhe_type!(
 pub(super) mod base;
 pub(super) mod compact;
 pub(super) mod compressed;
+pub(super) mod decryption_compressed;
+#[cfg(feature = "zk-pok-experimental")]
+pub(super) mod proven_compact;
 pub(super) mod static_;
@@ -0,0 +1,117 @@
+use crate::high_level_api::errors::Error;
+use crate::high_level_api::integers::parameters::IntegerId;
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::integer::zk::ProvenCompactCiphertextList;
+use crate::named::Named;
+use crate::zk::{CompactPkeCrs, ZkVerificationOutCome};
+use crate::{CompactPublicKey, Config};
+
+impl CompactPkeCrs {
+    /// Create the public parameters to prove the encryption of lists holding up to
+    /// `max_num_bits` bits of messages, e.g. 64 bits for a list of 8 FheUint8, with the
+    /// [`CompactPublicKey`] of the given config.
+    pub fn from_config(config: Config, max_num_bits: usize) -> Self {
+        let block_parameters = config.inner.block_parameters;
+        let bits_per_block = block_parameters.message_modulus().0.ilog2() as usize;
+        let max_num_blocks = (max_num_bits + bits_per_block - 1) / bits_per_block;
+
+        Self::from_shortint_params(block_parameters, max_num_blocks)
+    }
+}
+
+/// A list of compact integers encrypted along with the zero-knowledge proofs of their encryption.
+///
+/// The proofs must be verified by calling [`Self::verify_and_expand`] to use the values.
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "zk-pok-experimental")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct GenericProvenCompactIntegerList<Id: IntegerId> {
+    pub(in crate::high_level_api::integers) list: ProvenCompactCiphertextList,
+    pub(in crate::high_level_api::integers) id: Id,
+}
+
+impl<Id> GenericProvenCompactIntegerList<Id>
+where
+    Id: IntegerId,
+{
+    /// Encrypt the values and prove their encryption.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::zk::CompactPkeCrs;
+    /// use tfhe::{generate_keys, CompactPublicKey, ConfigBuilder, ProvenCompactFheUint8List};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, _) = generate_keys(config.clone());
+    /// let public_key = CompactPublicKey::new(&client_key);
+    /// let crs = CompactPkeCrs::from_config(config, 32);
+    ///
+    /// let clears = [1u8, 2, 3, 4];
+    /// let list = ProvenCompactFheUint8List::try_encrypt(&clears, &crs, &public_key).unwrap();
+    /// assert_eq!(list.len(), clears.len());
+    ///
+    /// let values = list.verify_and_expand(&crs, &public_key).unwrap();
+    /// for (value, &clear) in values.iter().zip(clears.iter()) {
+    ///     let decrypted: u8 = value.decrypt(&client_key);
+    ///     assert_eq!(decrypted, clear);
+    /// }
+    /// ```
+    pub fn try_encrypt<T>(
+        values: &[T],
+        crs: &CompactPkeCrs,
+        key: &CompactPublicKey,
+    ) -> Result<Self, Error>
+    where
+        T: crate::integer::block_decomposition::DecomposableInto<u64>,
+    {
+        let id = Id::default();
+        let list = key
+            .key
+            .key
+            .encrypt_and_prove_slice_radix_compact(values, Id::num_blocks(), crs);
+        Ok(Self { list, id })
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.ciphertext_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Verify the proofs of the list and expand it.
+    ///
+    /// The proofs show that the blocks of the values were encrypted with a bounded noise and hold
+    /// in range messages, see [`crate::zk`].
+    pub fn verify_and_expand(
+        &self,
+        crs: &CompactPkeCrs,
+        key: &CompactPublicKey,
+    ) -> Result<Vec<GenericInteger<Id>>, Error> {
+        if self.list.num_blocks_per_integer != Id::num_blocks() {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(self
+            .list
+            .verify_and_expand(crs, &key.key.key)?
+            .into_iter()
+            .map(|ct| GenericInteger::new(ct, self.id))
+            .collect())
+    }
+
+    /// Verify the proofs of the list without expanding it.
+    pub fn verify(&self, crs: &CompactPkeCrs, key: &CompactPublicKey) -> ZkVerificationOutCome {
+        if self.list.num_blocks_per_integer != Id::num_blocks() {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        self.list.verify(crs, &key.key.key)
+    }
+}
+
+impl<Id: IntegerId> Named for GenericProvenCompactIntegerList<Id> {
+    const NAME: &'static str = "high_level_api::GenericProvenCompactIntegerList";
+}
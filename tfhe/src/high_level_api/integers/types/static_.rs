@@ -6,6 +6,9 @@ use crate::high_level_api::integers::types::compact::{
     GenericCompactInteger, GenericCompactIntegerList,
 };
 use crate::high_level_api::integers::types::compressed::CompressedGenericInteger;
+use crate::high_level_api::integers::types::decryption_compressed::GenericDecryptionCompressedInteger;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::high_level_api::integers::types::proven_compact::GenericProvenCompactIntegerList;
 use crate::high_level_api::internal_traits::TypeIdentifier;
 #[cfg(feature = "internal-keycache")]
 use crate::integer::keycache::{KEY_CACHE, KEY_CACHE_WOPBS};
@@ -91,12 +94,18 @@ macro_rules! static_int_type {
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
             pub type [<Compressed $name>] = CompressedGenericInteger<[<$name Id>]>;
 
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type [<DecryptionCompressed $name>] = GenericDecryptionCompressedInteger<[<$name Id>]>;
+
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
             pub type [<Compact $name>] = GenericCompactInteger<[<$name Id>]>;
 
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
             pub type [<Compact $name List>] = GenericCompactIntegerList<[<$name Id>]>;
 
+            #[cfg(feature = "zk-pok-experimental")]
+            pub type [<ProvenCompact $name List>] = GenericProvenCompactIntegerList<[<$name Id>]>;
+
             impl $crate::high_level_api::global_state::WithGlobalKey for [<$name Id>] {
                 type Key = crate::high_level_api::integers::IntegerServerKey;
 
@@ -10,6 +10,23 @@ pub(crate) trait DecryptionKey<CiphertextType, ClearType> {
     fn decrypt(&self, ciphertext: &CiphertextType) -> ClearType;
 }
 
+/// Trait to be implemented on ciphertexts whose partial decryptions can be combined into clear
+/// values
+pub trait PartialDecryptionCombiner<PartialDecryptionType, ClearType> {
+    /// The combination process
+    fn combine_partial_decryptions(
+        &self,
+        partial_decryptions: &[PartialDecryptionType],
+    ) -> ClearType;
+}
+
+/// Trait to be implemented on ciphertexts whose values can be decrypted once compressed for
+/// decryption
+pub(crate) trait CompressedForDecryptionDecrypter<KeyType, CompressedType, ClearType> {
+    /// The decryption process
+    fn decrypt_compressed(key: &KeyType, compressed: &CompressedType) -> ClearType;
+}
+
 pub trait TypeIdentifier {
     fn type_variant(&self) -> crate::high_level_api::errors::Type;
 }
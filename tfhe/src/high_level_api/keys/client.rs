@@ -48,6 +48,27 @@ impl ClientKey {
     pub fn generate_compressed_server_key(&self) -> CompressedServerKey {
         CompressedServerKey::new(self)
     }
+
+    /// Locks the memory holding the secret keys in RAM, preventing it from being written to the
+    /// swap.
+    ///
+    /// Returns `false` if the memory could not be locked, which is always the case when the
+    /// `secret-key-memory-lock` feature is disabled. Whether they are locked or not, the secret
+    /// keys are wiped from memory when the [ClientKey] is dropped.
+    pub fn lock_in_memory(&self) -> bool {
+        let compression_key_locked = self
+            .key
+            .compression_key
+            .as_ref()
+            .map_or(true, |key| key.key.post_packing_ks_key.lock_in_memory());
+        let decryption_compression_key_locked = self
+            .key
+            .decryption_compression_key
+            .as_ref()
+            .map_or(true, |key| key.key.lwe_secret_key.lock_in_memory());
+
+        self.key.key.lock_in_memory() && compression_key_locked && decryption_compression_key_locked
+    }
 }
 
 impl AsRef<crate::integer::ClientKey> for ClientKey {
@@ -1,4 +1,6 @@
-use crate::high_level_api::integers::{IntegerCompressedServerKey, IntegerServerKey};
+use crate::high_level_api::integers::{
+    IntegerCompressedServerKey, IntegerServerKey, IntegerServerKeyStream,
+};
 
 use std::sync::Arc;
 
@@ -25,6 +27,71 @@ impl ServerKey {
             integer_key: Arc::new(IntegerServerKey::new(&keys.key)),
         }
     }
+
+    /// Generates a server key and serializes it into `serializer` while it is being generated.
+    ///
+    /// The keyswitching and bootstrapping keys are produced one ciphertext at a time, so the
+    /// server key is never entirely held in memory. Deserializing the output gives a
+    /// [`ServerKey`] generated in the same way as [`ServerKey::new`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the configuration used to create the [`ClientKey`] had function evaluation
+    /// on integers enabled, as the corresponding key can only be generated from a server key
+    /// held in memory.
+    pub fn new_into_serializer<S>(keys: &ClientKey, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let integer_key = IntegerServerKeyStream::new(&keys.key);
+
+        // Same layout as the serialization of ServerKey
+        let mut state = serializer.serialize_struct("SerializableServerKey", 1)?;
+        state.serialize_field("integer_key", &integer_key)?;
+        state.end()
+    }
+
+    /// Generates a server key and writes it to `writer` while it is being generated.
+    ///
+    /// The output is the same as the one of `bincode::serialize_into` called on a
+    /// [`ServerKey`], see [`ServerKey::new_into_serializer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, ServerKey};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, _) = generate_keys(config);
+    ///
+    /// // Any std::io::Write can be used here, e.g. a File
+    /// let mut serialized_server_key = vec![];
+    /// ServerKey::new_into_writer(&client_key, &mut serialized_server_key).unwrap();
+    ///
+    /// let server_key: ServerKey = bincode::deserialize(&serialized_server_key).unwrap();
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    ///
+    /// let c = a + b;
+    /// let decrypted: u8 = c.decrypt(&client_key);
+    /// assert_eq!(decrypted, 127);
+    /// ```
+    #[cfg(feature = "safe-deserialization")]
+    pub fn new_into_writer(keys: &ClientKey, writer: impl std::io::Write) -> bincode::Result<()> {
+        use bincode::Options;
+
+        let mut serializer = bincode::Serializer::new(
+            writer,
+            bincode::DefaultOptions::new().with_fixint_encoding(),
+        );
+
+        Self::new_into_serializer(keys, &mut serializer)
+    }
 }
 
 impl AsRef<crate::integer::ServerKey> for ServerKey {
@@ -13,16 +13,25 @@ macro_rules! expand_pub_use_fhe_type(
                 $(
                     $fhe_type_name,
                     [<Compressed $fhe_type_name>],
+                    [<DecryptionCompressed $fhe_type_name>],
                     [<Compact $fhe_type_name>],
                     [<Compact $fhe_type_name List>],
                 )*
             };
 
+            #[cfg(feature = "zk-pok-experimental")]
+            pub use $module_path::{
+                $(
+                    [<ProvenCompact $fhe_type_name List>],
+                )*
+            };
+
         }
     }
 );
 
 pub use crate::core_crypto::commons::math::random::Seed;
+pub use compressed_ciphertext_list::{CompressedCiphertextList, CompressedCiphertextListBuilder};
 pub use config::{Config, ConfigBuilder};
 pub use errors::{Error, OutOfRangeError};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
@@ -30,6 +39,7 @@ pub use keys::{
     generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
     CompressedServerKey, PublicKey, ServerKey,
 };
+pub use threshold_decryption::{ClientKeyShare, PartialDecryption};
 
 #[cfg(test)]
 mod tests;
@@ -57,8 +67,10 @@ mod internal_traits;
 mod traits;
 
 mod booleans;
+mod compressed_ciphertext_list;
 pub mod errors;
 mod integers;
+mod threshold_decryption;
 
 /// The tfhe prelude.
 pub mod prelude;
@@ -93,6 +93,43 @@ fn test_server_key_decompression() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_server_key_into_serializer() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::shortint::parameters::COMP_PARAM_MESSAGE_2_CARRY_2;
+    use crate::{set_server_key, ServerKey};
+    use bincode::Options;
+
+    let config = ConfigBuilder::default()
+        .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+        .build();
+
+    let cks = ClientKey::generate(config);
+
+    let mut serialized_sks = vec![];
+    ServerKey::new_into_serializer(
+        &cks,
+        &mut bincode::Serializer::new(
+            &mut serialized_sks,
+            bincode::DefaultOptions::new().with_fixint_encoding(),
+        ),
+    )?;
+
+    let sks: ServerKey = bincode::deserialize(&serialized_sks)?;
+    assert!(sks.integer_key.compression_key.is_some());
+    assert!(sks.integer_key.decompression_key.is_some());
+
+    set_server_key(sks);
+
+    let clear_a = 12u8;
+    let a = FheUint8::try_encrypt(clear_a, &cks)?;
+
+    let c = a + 234u8;
+    let decrypted: u8 = c.decrypt(&cks);
+    assert_eq!(decrypted, clear_a.wrapping_add(234));
+
+    Ok(())
+}
+
 #[test]
 fn test_with_seed() -> Result<(), Box<dyn std::error::Error>> {
     use crate::Seed;
@@ -133,6 +170,37 @@ fn test_compressed_server_key_creation_panic_if_function_eval() {
     let _ = CompressedServerKey::new(&cks);
 }
 
+#[test]
+#[should_panic(
+    expected = "The configuration used to create the ClientKey had function evaluation on integers enabled.
+                   This feature requires an additional key that can only be
+                   generated from a server key held in memory. Thus, It is not possible
+                   to generate the ServerKey while serializing it.
+                   "
+)]
+fn test_server_key_into_serializer_panic_if_function_eval() {
+    let config = ConfigBuilder::default()
+        .enable_function_evaluation()
+        .build();
+
+    let cks = ClientKey::generate(config);
+    let _ = crate::ServerKey::new_into_serializer(
+        &cks,
+        &mut bincode::Serializer::new(std::io::sink(), bincode::DefaultOptions::new()),
+    );
+}
+
+#[test]
+#[should_panic(expected = "The parameters of the GLWE secret key have an estimated security of")]
+fn test_use_custom_parameters_panic_if_insecure() {
+    use crate::shortint::parameters::{StandardDev, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+
+    let mut insecure_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    insecure_params.glwe_modular_std_dev = StandardDev(2.0f64.powi(-62));
+
+    let _ = ConfigBuilder::default().use_custom_parameters(insecure_params, None);
+}
+
 #[test]
 fn test_with_context() {
     let config = ConfigBuilder::default().build();
@@ -171,3 +239,48 @@ fn test_serialize_deserialize_are_implemented() {
     can_be_deserialized(&csks);
     can_be_deserialized(&pksz);
 }
+
+#[test]
+fn test_compressed_ciphertext_list() {
+    use crate::shortint::parameters::COMP_PARAM_MESSAGE_2_CARRY_2;
+    use crate::{
+        set_server_key, CompressedCiphertextList, CompressedCiphertextListBuilder, FheInt16,
+    };
+
+    let config = ConfigBuilder::default()
+        .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+        .build();
+
+    let cks = ClientKey::generate(config);
+    let sks = CompressedServerKey::new(&cks).decompress();
+
+    set_server_key(sks);
+
+    let a = FheUint8::encrypt(213u8, &cks);
+    let b = FheInt16::encrypt(-1234i16, &cks);
+    let c = FheBool::encrypt(true, &cks);
+
+    let compressed = CompressedCiphertextListBuilder::new()
+        .push(a)
+        .push(b)
+        .push(c)
+        .build();
+
+    let serialized = bincode::serialize(&compressed).unwrap();
+    let compressed: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+    assert_eq!(compressed.len(), 3);
+
+    let a: FheUint8 = compressed.get(0).unwrap().unwrap();
+    let decrypted: u8 = a.decrypt(&cks);
+    assert_eq!(decrypted, 213);
+
+    let b: FheInt16 = compressed.get(1).unwrap().unwrap();
+    let decrypted: i16 = b.decrypt(&cks);
+    assert_eq!(decrypted, -1234);
+
+    let c: FheBool = compressed.get(2).unwrap().unwrap();
+    assert!(c.decrypt(&cks));
+
+    assert!(compressed.get::<FheBool>(3).unwrap().is_none());
+}
@@ -0,0 +1,61 @@
+//! This module defines the keys and values used in the threshold decryption of FheUint and FheInt
+//! values.
+//!
+//! - [ClientKeyShare] is the share of a [ClientKey] held by one party.
+//! - [PartialDecryption] is the contribution of one party to the decryption of a value.
+
+use crate::high_level_api::keys::ClientKey;
+use crate::integer::threshold_decryption::{
+    ClientKeyShare as IntegerClientKeyShare, RadixPartialDecryption,
+};
+use crate::shortint::parameters::{SecretSharingScheme, ThresholdPartyIndex};
+
+/// Share of a [ClientKey] held by one party of a threshold decryption
+///
+/// It does not allow to encrypt or decrypt, only to compute [PartialDecryption]s of values
+/// encrypted under the [ClientKey] it was generated from.
+///
+/// This key **MUST NOT** be sent to the server.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ClientKeyShare {
+    pub(crate) key: IntegerClientKeyShare,
+}
+
+impl ClientKeyShare {
+    /// Returns the index of the party holding the share.
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.key.party_index()
+    }
+
+    /// Returns the [SecretSharingScheme] used to generate the share.
+    pub fn sharing_scheme(&self) -> SecretSharingScheme {
+        self.key.sharing_scheme()
+    }
+}
+
+/// Contribution of one party to the threshold decryption of a value
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PartialDecryption {
+    pub(crate) inner: RadixPartialDecryption,
+}
+
+impl PartialDecryption {
+    /// Returns the index of the party which computed the partial decryption.
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.inner.party_index()
+    }
+}
+
+impl ClientKey {
+    /// Splits the key in shares according to the given [SecretSharingScheme].
+    ///
+    /// The i-th returned share belongs to the party with index i + 1.
+    pub fn share(&self, sharing_scheme: SecretSharingScheme) -> Vec<ClientKeyShare> {
+        self.key
+            .key
+            .share(sharing_scheme)
+            .into_iter()
+            .map(|key| ClientKeyShare { key })
+            .collect()
+    }
+}
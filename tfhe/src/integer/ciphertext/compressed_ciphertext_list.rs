@@ -0,0 +1,379 @@
+use super::boolean_value::BooleanBlock;
+use super::{RadixCiphertext, SignedRadixCiphertext};
+use crate::core_crypto::prelude::Fft;
+use crate::integer::compression_keys::{CompressionKey, DecompressionKey};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::list_compression::CompressedCiphertextList as ShortintCompressedCiphertextList;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Error returned when the description of the data stored in a [`CompressedCiphertextList`]
+/// refers to more blocks than the list actually packs, e.g. after deserializing corrupted data.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct InconsistentCompressedListError;
+
+impl Display for InconsistentCompressedListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The data description of the compressed list does not match its packed blocks"
+        )
+    }
+}
+
+impl std::error::Error for InconsistentCompressedListError {}
+
+/// The kind of data stored in a [`CompressedCiphertextList`], along with its number of blocks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataKind {
+    Unsigned(usize),
+    Signed(usize),
+    Boolean,
+}
+
+impl DataKind {
+    pub fn num_blocks(self) -> usize {
+        match self {
+            Self::Unsigned(n) | Self::Signed(n) => n,
+            Self::Boolean => 1,
+        }
+    }
+}
+
+/// Trait implemented by the types which can be pushed in a [`CompressedCiphertextListBuilder`].
+pub trait Compressible {
+    /// Move the blocks of `self` at the end of `messages` and return the kind of data they
+    /// represent.
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind;
+}
+
+impl Compressible for RadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        let num_blocks = self.blocks.len();
+
+        messages.extend(self.blocks);
+
+        DataKind::Unsigned(num_blocks)
+    }
+}
+
+impl Compressible for SignedRadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        let num_blocks = self.blocks.len();
+
+        messages.extend(self.blocks);
+
+        DataKind::Signed(num_blocks)
+    }
+}
+
+impl Compressible for BooleanBlock {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        messages.push(self.0);
+
+        DataKind::Boolean
+    }
+}
+
+/// Trait implemented by the types which can be retrieved from a [`CompressedCiphertextList`].
+pub trait Expandable: Sized {
+    /// Build `Self` from its decompressed blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kind` does not describe data of type `Self`.
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> Self;
+}
+
+impl Expandable for RadixCiphertext {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> Self {
+        assert!(
+            matches!(kind, DataKind::Unsigned(_)),
+            "Tried to expand a RadixCiphertext while a {kind:?} is stored in this slot"
+        );
+
+        Self::from(blocks)
+    }
+}
+
+impl Expandable for SignedRadixCiphertext {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> Self {
+        assert!(
+            matches!(kind, DataKind::Signed(_)),
+            "Tried to expand a SignedRadixCiphertext while a {kind:?} is stored in this slot"
+        );
+
+        Self::from(blocks)
+    }
+}
+
+impl Expandable for BooleanBlock {
+    fn from_expanded_blocks(mut blocks: Vec<Ciphertext>, kind: DataKind) -> Self {
+        assert_eq!(
+            kind,
+            DataKind::Boolean,
+            "Tried to expand a BooleanBlock while a {kind:?} is stored in this slot"
+        );
+
+        let mut block = blocks.pop().unwrap();
+        // The decompression bootstrap resets the degree to its maximum value while the block
+        // still encrypts a boolean
+        block.degree = Degree(1);
+
+        Self::new_unchecked(block)
+    }
+}
+
+/// A builder used to pack many integer ciphertexts in a [`CompressedCiphertextList`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::ciphertext::CompressedCiphertextListBuilder;
+/// use tfhe::integer::{ClientKey, RadixCiphertext, SignedRadixCiphertext};
+/// use tfhe::shortint::parameters::{
+///     COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let private_compression_key =
+///     cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let (compression_key, decompression_key) =
+///     cks.new_compression_decompression_keys(&private_compression_key);
+///
+/// let num_blocks = 4;
+///
+/// let ct1 = cks.encrypt_radix(200u8, num_blocks);
+/// let ct2 = cks.encrypt_signed_radix(-3i8, num_blocks);
+///
+/// let compressed = CompressedCiphertextListBuilder::new()
+///     .push(ct1)
+///     .push(ct2)
+///     .build(&compression_key);
+///
+/// let decompressed: RadixCiphertext = compressed.get(0, &decompression_key).unwrap().unwrap();
+/// let clear: u8 = cks.decrypt_radix(&decompressed);
+/// assert_eq!(clear, 200);
+///
+/// let decompressed: SignedRadixCiphertext = compressed.get(1, &decompression_key).unwrap().unwrap();
+/// let clear: i8 = cks.decrypt_signed_radix(&decompressed);
+/// assert_eq!(clear, -3);
+/// ```
+#[derive(Clone, Default)]
+pub struct CompressedCiphertextListBuilder {
+    pub(crate) ciphertexts: Vec<Ciphertext>,
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl CompressedCiphertextListBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a ciphertext at the end of the list.
+    pub fn push<T>(&mut self, data: T) -> &mut Self
+    where
+        T: Compressible,
+    {
+        let kind = data.compress_into(&mut self.ciphertexts);
+
+        self.info.push(kind);
+
+        self
+    }
+
+    /// Add the ciphertexts of an iterator at the end of the list.
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compressible,
+    {
+        for value in values {
+            self.push(value);
+        }
+
+        self
+    }
+
+    /// Pack the ciphertexts added so far in a [`CompressedCiphertextList`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no ciphertext was added, or if the blocks of a ciphertext have non-empty
+    /// carries.
+    pub fn build(&self, comp_key: &CompressionKey) -> CompressedCiphertextList {
+        let packed_list = comp_key
+            .key
+            .compress_ciphertexts_into_list(&self.ciphertexts);
+
+        CompressedCiphertextList {
+            packed_list,
+            info: self.info.clone(),
+        }
+    }
+}
+
+/// A list of integer ciphertexts, possibly of different types and sizes, whose blocks are packed
+/// in a few GLWE ciphertexts.
+///
+/// See [`CompressedCiphertextListBuilder`] for usage.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    pub(crate) packed_list: ShortintCompressedCiphertextList,
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl CompressedCiphertextList {
+    /// Return the number of ciphertexts stored in the list.
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    /// Return `true` if the list does not contain any ciphertext.
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    /// Return the kind of data stored at the given index, or [`None`] if the index is out of
+    /// bounds.
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
+    }
+
+    /// Decompress the blocks of the ciphertext stored at the given index.
+    ///
+    /// Returns `Ok(None)` if the index is out of bounds, and an error if the data description of
+    /// the list refers to blocks which are not packed in it.
+    pub fn blocks_of(
+        &self,
+        index: usize,
+        decomp_key: &DecompressionKey,
+    ) -> Result<Option<(Vec<Ciphertext>, DataKind)>, InconsistentCompressedListError> {
+        let Some(kind) = self.get_kind_of(index) else {
+            return Ok(None);
+        };
+
+        let start_block_index: usize = self
+            .info
+            .iter()
+            .take(index)
+            .copied()
+            .map(DataKind::num_blocks)
+            .sum();
+
+        let end_block_index = start_block_index + kind.num_blocks();
+
+        if end_block_index > self.packed_list.count {
+            return Err(InconsistentCompressedListError);
+        }
+
+        let fft = Fft::new(decomp_key.key.blind_rotate_key.polynomial_size());
+        let fft = fft.as_view();
+
+        let blocks = (start_block_index..end_block_index)
+            .into_par_iter()
+            .map(|i| decomp_key.key.unpack_with_fft(&self.packed_list, i, fft))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(InconsistentCompressedListError)?;
+
+        Ok(Some((blocks, kind)))
+    }
+
+    /// Decompress the ciphertext stored at the given index.
+    ///
+    /// Returns `Ok(None)` if the index is out of bounds, and an error if the data description of
+    /// the list refers to blocks which are not packed in it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data stored at the given index is not of type `T`.
+    pub fn get<T>(
+        &self,
+        index: usize,
+        decomp_key: &DecompressionKey,
+    ) -> Result<Option<T>, InconsistentCompressedListError>
+    where
+        T: Expandable,
+    {
+        Ok(self
+            .blocks_of(index, decomp_key)?
+            .map(|(blocks, kind)| T::from_expanded_blocks(blocks, kind)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::IntegerKeyKind;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use rand::Rng;
+
+    const NUM_BLOCKS: usize = 32;
+
+    #[test]
+    fn test_ciphertext_compression() {
+        let (cks, sks) =
+            KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+
+        let private_compression_key =
+            cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let (compression_key, decompression_key) =
+            cks.new_compression_decompression_keys(&private_compression_key);
+
+        let mut rng = rand::thread_rng();
+
+        let message1 = rng.gen::<u64>();
+        let ct1 = cks.encrypt_radix(message1, NUM_BLOCKS);
+
+        let message2 = rng.gen::<i64>();
+        let ct2 = cks.encrypt_signed_radix(message2, NUM_BLOCKS);
+
+        let ct3 = cks.encrypt_bool(false);
+
+        let compressed = CompressedCiphertextListBuilder::new()
+            .push(ct1)
+            .push(ct2)
+            .push(ct3)
+            .build(&compression_key);
+
+        assert_eq!(compressed.len(), 3);
+
+        let decompressed1: RadixCiphertext =
+            compressed.get(0, &decompression_key).unwrap().unwrap();
+        let decrypted: u64 = cks.decrypt_radix(&decompressed1);
+        assert_eq!(decrypted, message1);
+
+        let decompressed2: SignedRadixCiphertext =
+            compressed.get(1, &decompression_key).unwrap().unwrap();
+        let decrypted: i64 = cks.decrypt_signed_radix(&decompressed2);
+        assert_eq!(decrypted, message2);
+
+        let decompressed3: BooleanBlock = compressed.get(2, &decompression_key).unwrap().unwrap();
+        assert_eq!(decompressed3.0.degree, Degree(1));
+        assert!(!cks.decrypt_bool(&decompressed3));
+
+        // The decompressed ciphertexts can be used in further computations
+        let sum = sks.add_parallelized(&decompressed1, &decompressed1);
+        let decrypted: u64 = cks.decrypt_radix(&sum);
+        assert_eq!(decrypted, message1.wrapping_mul(2));
+
+        assert!(compressed
+            .get::<RadixCiphertext>(3, &decompression_key)
+            .unwrap()
+            .is_none());
+
+        // A data description referring to more blocks than packed is rejected
+        let mut corrupted = compressed;
+        corrupted.info[2] = DataKind::Unsigned(2);
+        assert_eq!(
+            corrupted.get::<RadixCiphertext>(2, &decompression_key),
+            Err(InconsistentCompressedListError)
+        );
+    }
+}
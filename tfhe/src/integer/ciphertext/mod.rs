@@ -1,5 +1,8 @@
 //! This module implements the ciphertext structures.
 pub mod boolean_value;
+mod compressed_ciphertext_list;
+
+pub use compressed_ciphertext_list::*;
 
 use super::parameters::{
     RadixCiphertextConformanceParams, RadixCompactCiphertextListConformanceParams,
@@ -50,6 +50,27 @@ impl RecomposableSignedInteger for i128 {}
 
 impl<const N: usize> RecomposableSignedInteger for StaticSignedBigInt<N> {}
 
+/// Sign extend a value decrypted from a signed radix ciphertext encrypting `num_bits_in_ctxt`
+/// bits to the full width of `T`.
+pub(crate) fn sign_extend_decrypted_value<T>(unpadded_value: T, num_bits_in_ctxt: u32) -> T
+where
+    T: RecomposableSignedInteger,
+{
+    if num_bits_in_ctxt >= T::BITS as u32 {
+        return unpadded_value;
+    }
+
+    let sign_bit_pos = num_bits_in_ctxt - 1;
+    let sign_bit_mask = T::cast_from(1u32 << sign_bit_pos);
+    let sign_bit = (unpadded_value & sign_bit_mask) >> sign_bit_pos;
+
+    // Creates a padding mask
+    // where bits above num_bits_in_ctxt
+    // are 1s if sign bit is one else 0
+    let padding = (T::MAX * sign_bit) << num_bits_in_ctxt;
+    padding | unpadded_value
+}
+
 /// A structure containing the client key, which must be kept secret.
 ///
 /// This key can be used to encrypt both in Radix and CRT
@@ -114,6 +135,12 @@ impl ClientKey {
         self.key.parameters.pbs_parameters().unwrap()
     }
 
+    /// Lock the memory holding the secret keys in RAM, see
+    /// [`crate::shortint::ClientKey::lock_in_memory`].
+    pub fn lock_in_memory(&self) -> bool {
+        self.key.lock_in_memory()
+    }
+
     /// Encrypts an integer in radix decomposition
     ///
     /// # Example
@@ -298,11 +325,9 @@ impl ClientKey {
 
         for encrypted_block in blocks {
             let decrypted_block = decrypt_block(&self.key, encrypted_block);
-            if !recomposer.add_unmasked(decrypted_block) {
-                // End of T::BITS reached no need to try more
-                // recomposition
-                break;
-            };
+            // Once T::BITS are reached the recomposer ignores the remaining blocks, they are still
+            // decrypted so that the decryption does not exit early
+            recomposer.add_unmasked(decrypted_block);
         }
 
         recomposer.value()
@@ -391,19 +416,7 @@ impl ClientKey {
 
         let num_bits_in_message = message_modulus.ilog2();
         let num_bits_in_ctxt = num_bits_in_message * ctxt.blocks.len() as u32;
-        if num_bits_in_ctxt >= T::BITS as u32 {
-            return unpadded_value;
-        }
-
-        let sign_bit_pos = num_bits_in_ctxt - 1;
-        let sign_bit_mask = T::cast_from(1u32 << sign_bit_pos);
-        let sign_bit = (unpadded_value & sign_bit_mask) >> sign_bit_pos;
-
-        // Creates a padding mask
-        // where bits above num_bits_in_ctxt
-        // are 1s if sign bit is one else 0
-        let padding = (T::MAX * sign_bit) << num_bits_in_ctxt;
-        padding | unpadded_value
+        sign_extend_decrypted_value(unpadded_value, num_bits_in_ctxt)
     }
 
     /// Encrypts one block.
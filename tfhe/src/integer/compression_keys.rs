@@ -0,0 +1,83 @@
+//! Module with the definition of the keys used to compress lists of integer ciphertexts.
+
+use crate::integer::ClientKey;
+use crate::shortint::list_compression::{
+    CompressionKey as ShortintCompressionKey,
+    CompressionPrivateKeys as ShortintCompressionPrivateKeys,
+    DecompressionKey as ShortintDecompressionKey,
+};
+use crate::shortint::parameters::CompressionParameters;
+use serde::{Deserialize, Serialize};
+
+/// The private key used by the compression, which must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    pub(crate) key: ShortintCompressionPrivateKeys,
+}
+
+/// The server key used to build a
+/// [`CompressedCiphertextList`](`crate::integer::ciphertext::CompressedCiphertextList`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    pub(crate) key: ShortintCompressionKey,
+}
+
+/// The server key used to get ciphertexts out of a
+/// [`CompressedCiphertextList`](`crate::integer::ciphertext::CompressedCiphertextList`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    pub(crate) key: ShortintDecompressionKey,
+}
+
+impl From<ShortintCompressionPrivateKeys> for CompressionPrivateKeys {
+    fn from(key: ShortintCompressionPrivateKeys) -> Self {
+        Self { key }
+    }
+}
+
+impl From<ShortintCompressionKey> for CompressionKey {
+    fn from(key: ShortintCompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+impl From<ShortintDecompressionKey> for DecompressionKey {
+    fn from(key: ShortintDecompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+impl ClientKey {
+    /// Generate the private key used by the compression of ciphertext lists.
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        CompressionPrivateKeys {
+            key: self.key.new_compression_private_key(params),
+        }
+    }
+
+    /// Generate the [`CompressionKey`] and the [`DecompressionKey`] associated to the given
+    /// [`CompressionPrivateKeys`].
+    ///
+    /// See [`CompressedCiphertextListBuilder`](`crate::integer::ciphertext::CompressedCiphertextListBuilder`)
+    /// for usage.
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let (compression_key, decompression_key) = self
+            .key
+            .new_compression_decompression_keys(&private_compression_key.key);
+
+        (
+            CompressionKey {
+                key: compression_key,
+            },
+            DecompressionKey {
+                key: decompression_key,
+            },
+        )
+    }
+}
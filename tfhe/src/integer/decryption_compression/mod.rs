@@ -0,0 +1,169 @@
+//! Module with the compression of integer radix ciphertexts for their decryption.
+//!
+//! Every block of a radix ciphertext is compressed with the
+//! [`shortint` decryption compression](`crate::shortint::decryption_compression`), the decrypted
+//! blocks are then recomposed into the cleartext as in a regular decryption.
+
+#[cfg(test)]
+mod test;
+
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::client_key::{sign_extend_decrypted_value, RecomposableSignedInteger};
+use crate::integer::ClientKey;
+use crate::shortint::decryption_compression::{
+    DecryptionCompressedCiphertext as ShortintDecryptionCompressedCiphertext,
+    DecryptionCompressionKey as ShortintDecryptionCompressionKey,
+    DecryptionCompressionPrivateKey as ShortintDecryptionCompressionPrivateKey,
+};
+use crate::shortint::parameters::DecryptionCompressionParameters;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The private key used to decrypt a [`DecryptionCompressedRadixCiphertext`], which must be kept
+/// secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecryptionCompressionPrivateKey {
+    pub(crate) key: ShortintDecryptionCompressionPrivateKey,
+}
+
+/// The server key used to build a [`DecryptionCompressedRadixCiphertext`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptionCompressionKey {
+    pub(crate) key: ShortintDecryptionCompressionKey,
+}
+
+/// A signed or unsigned radix ciphertext compressed to be sent to the owner of the
+/// [`DecryptionCompressionPrivateKey`], it can only be decrypted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptionCompressedRadixCiphertext {
+    pub(crate) blocks: Vec<ShortintDecryptionCompressedCiphertext>,
+}
+
+impl From<ShortintDecryptionCompressionPrivateKey> for DecryptionCompressionPrivateKey {
+    fn from(key: ShortintDecryptionCompressionPrivateKey) -> Self {
+        Self { key }
+    }
+}
+
+impl From<ShortintDecryptionCompressionKey> for DecryptionCompressionKey {
+    fn from(key: ShortintDecryptionCompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+impl DecryptionCompressedRadixCiphertext {
+    /// Return the compressed blocks.
+    pub fn blocks(&self) -> &[ShortintDecryptionCompressedCiphertext] {
+        &self.blocks
+    }
+}
+
+impl ClientKey {
+    /// Generate the private key used to decrypt ciphertexts compressed for decryption.
+    pub fn new_decryption_compression_private_key(
+        &self,
+        params: DecryptionCompressionParameters,
+    ) -> DecryptionCompressionPrivateKey {
+        DecryptionCompressionPrivateKey {
+            key: self.key.new_decryption_compression_private_key(params),
+        }
+    }
+
+    /// Generate the [`DecryptionCompressionKey`] associated to the given
+    /// [`DecryptionCompressionPrivateKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::{
+    ///     DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let num_block = 4;
+    ///
+    /// let private_key =
+    ///     cks.new_decryption_compression_private_key(DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2);
+    /// let compression_key = cks.new_decryption_compression_key(&private_key);
+    ///
+    /// let msg = 191_u64;
+    /// let ct = cks.encrypt_radix(msg, num_block);
+    ///
+    /// let compressed = compression_key.compress_radix_for_decryption(&ct);
+    ///
+    /// let dec: u64 = private_key.decrypt_radix(&compressed);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn new_decryption_compression_key(
+        &self,
+        private_key: &DecryptionCompressionPrivateKey,
+    ) -> DecryptionCompressionKey {
+        DecryptionCompressionKey {
+            key: self.key.new_decryption_compression_key(&private_key.key),
+        }
+    }
+}
+
+impl DecryptionCompressionKey {
+    /// Compress a signed or unsigned radix ciphertext so that it can be sent to the owner of the
+    /// [`DecryptionCompressionPrivateKey`] and decrypted.
+    pub fn compress_radix_for_decryption<T>(&self, ctxt: &T) -> DecryptionCompressedRadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        DecryptionCompressedRadixCiphertext {
+            blocks: ctxt
+                .blocks()
+                .par_iter()
+                .map(|block| self.key.compress_for_decryption(block))
+                .collect(),
+        }
+    }
+}
+
+impl DecryptionCompressionPrivateKey {
+    fn decrypt_radix_impl<T>(&self, ctxt: &DecryptionCompressedRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64>,
+    {
+        let Some(first_block) = ctxt.blocks.first() else {
+            return T::ZERO;
+        };
+
+        let bits_in_block = first_block.message_modulus.0.ilog2();
+        let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+        for block in &ctxt.blocks {
+            recomposer.add_unmasked(self.key.decrypt_message_and_carry(block));
+        }
+
+        recomposer.value()
+    }
+
+    /// Decrypt a compressed ciphertext encrypting an unsigned radix integer.
+    pub fn decrypt_radix<T>(&self, ctxt: &DecryptionCompressedRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        self.decrypt_radix_impl(ctxt)
+    }
+
+    /// Decrypt a compressed ciphertext encrypting a signed radix integer.
+    pub fn decrypt_signed_radix<T>(&self, ctxt: &DecryptionCompressedRadixCiphertext) -> T
+    where
+        T: RecomposableSignedInteger,
+    {
+        let Some(first_block) = ctxt.blocks.first() else {
+            return T::ZERO;
+        };
+
+        let unpadded_value = self.decrypt_radix_impl(ctxt);
+
+        let num_bits_in_message = first_block.message_modulus.0.ilog2();
+        let num_bits_in_ctxt = num_bits_in_message * ctxt.blocks.len() as u32;
+        sign_extend_decrypted_value(unpadded_value, num_bits_in_ctxt)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::{
+    DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+};
+use rand::Rng;
+
+const NB_TESTS: usize = 10;
+const NUM_BLOCKS: usize = 32;
+
+#[test]
+fn integer_compress_for_decryption() {
+    let (cks, sks) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    let private_key =
+        cks.new_decryption_compression_private_key(DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2);
+    let compression_key = cks.new_decryption_compression_key(&private_key);
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>();
+        let clear_1 = rng.gen::<u64>();
+
+        let ct_0 = cks.encrypt_radix(clear_0, NUM_BLOCKS);
+        let ct_1 = cks.encrypt_radix(clear_1, NUM_BLOCKS);
+        let ct_res = sks.add_parallelized(&ct_0, &ct_1);
+
+        let compressed = compression_key.compress_radix_for_decryption(&ct_res);
+        assert_eq!(compressed.blocks().len(), NUM_BLOCKS);
+
+        let dec: u64 = private_key.decrypt_radix(&compressed);
+        assert_eq!(dec, clear_0.wrapping_add(clear_1));
+
+        let clear_0 = rng.gen::<i64>();
+        let clear_1 = rng.gen::<i64>();
+
+        let ct_0 = cks.encrypt_signed_radix(clear_0, NUM_BLOCKS);
+        let ct_1 = cks.encrypt_signed_radix(clear_1, NUM_BLOCKS);
+        let ct_res = sks.add_parallelized(&ct_0, &ct_1);
+
+        let compressed = compression_key.compress_radix_for_decryption(&ct_res);
+
+        let dec: i64 = private_key.decrypt_signed_radix(&compressed);
+        assert_eq!(dec, clear_0.wrapping_add(clear_1));
+    }
+
+    // Signed values encrypted on fewer bits than the clear type are sign extended
+    let ct = cks.encrypt_signed_radix(-3i64, NUM_BLOCKS / 4);
+    let compressed = compression_key.compress_radix_for_decryption(&ct);
+    let dec: i64 = private_key.decrypt_signed_radix(&compressed);
+    assert_eq!(dec, -3);
+}
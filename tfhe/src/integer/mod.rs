@@ -55,13 +55,19 @@ pub(crate) mod encryption;
 pub mod bigint;
 pub mod ciphertext;
 pub mod client_key;
+pub mod compression_keys;
+pub mod decryption_compression;
 pub mod key_switching_key;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
 pub mod parameters;
+pub mod pbs128;
 pub mod public_key;
 pub mod server_key;
+pub mod threshold_decryption;
 pub mod wopbs;
+#[cfg(feature = "zk-pok-experimental")]
+pub mod zk;
 
 pub use bigint::i256::I256;
 pub use bigint::i512::I512;
@@ -120,6 +126,7 @@ where
             carry_modulus: wopbs_params.carry_modulus,
             ciphertext_modulus: wopbs_params.ciphertext_modulus,
             encryption_key_choice: wopbs_params.encryption_key_choice,
+            secret_key_distribution: crate::shortint::parameters::SecretKeyDistribution::Binary,
         };
 
         crate::shortint::parameters::ShortintParameterSet::try_new_pbs_and_wopbs_param_set((
@@ -0,0 +1,207 @@
+//! Module with the 128 bits mode of integer.
+//!
+//! Radix ciphertexts of this mode are made of blocks of the
+//! [`shortint` 128 bits mode](`crate::shortint::pbs128`). As its parameter sets allow larger
+//! message moduli per block, wide integers need fewer blocks, e.g. 16 blocks for 64 bits with
+//! [`PARAM_PBS128_MESSAGE_4_CARRY_4`](`crate::shortint::parameters::PARAM_PBS128_MESSAGE_4_CARRY_4`).
+
+#[cfg(test)]
+mod test;
+
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::block_decomposition::{BlockRecomposer, DecomposableInto, RecomposableFrom};
+use crate::integer::encryption::{encrypt_words_radix_impl, KnowsMessageModulus};
+use crate::shortint::parameters::{MessageModulus, PBS128Parameters};
+use crate::shortint::pbs128::{
+    Ciphertext128, ClientKey128 as ShortintClientKey128, ServerKey128 as ShortintServerKey128,
+};
+use serde::{Deserialize, Serialize};
+
+/// An unsigned radix ciphertext of the 128 bits mode.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct RadixCiphertext128 {
+    pub(crate) blocks: Vec<Ciphertext128>,
+}
+
+impl From<Vec<Ciphertext128>> for RadixCiphertext128 {
+    fn from(blocks: Vec<Ciphertext128>) -> Self {
+        Self { blocks }
+    }
+}
+
+impl RadixCiphertext128 {
+    /// Return the blocks of the ciphertext, least significant first.
+    pub fn blocks(&self) -> &[Ciphertext128] {
+        &self.blocks
+    }
+}
+
+/// The client key of the 128 bits mode, which must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientKey128 {
+    pub(crate) key: ShortintClientKey128,
+}
+
+impl From<ShortintClientKey128> for ClientKey128 {
+    fn from(key: ShortintClientKey128) -> Self {
+        Self { key }
+    }
+}
+
+impl KnowsMessageModulus for ShortintClientKey128 {
+    fn message_modulus(&self) -> MessageModulus {
+        self.parameters.message_modulus
+    }
+}
+
+impl ClientKey128 {
+    /// Generate a client key for the 128 bits mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::pbs128::{ClientKey128, ServerKey128};
+    /// use tfhe::shortint::parameters::PARAM_PBS128_MESSAGE_2_CARRY_2;
+    ///
+    /// let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+    /// let sks = ServerKey128::new(&cks);
+    /// let num_block = 4;
+    ///
+    /// let ct_1 = cks.encrypt_radix(191u64, num_block);
+    /// let ct_2 = cks.encrypt_radix(100u64, num_block);
+    ///
+    /// let ct_res = sks.add(&ct_1, &ct_2);
+    ///
+    /// let dec: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(dec, (191 + 100) % 256);
+    /// ```
+    pub fn new(parameters: PBS128Parameters) -> Self {
+        Self {
+            key: ShortintClientKey128::new(parameters),
+        }
+    }
+
+    /// Return the parameters of the key.
+    pub fn parameters(&self) -> PBS128Parameters {
+        self.key.parameters
+    }
+
+    /// Encrypt an unsigned integer in `num_blocks` blocks.
+    pub fn encrypt_radix<T>(&self, message: T, num_blocks: usize) -> RadixCiphertext128
+    where
+        T: DecomposableInto<u64> + UnsignedNumeric,
+    {
+        encrypt_words_radix_impl(
+            &self.key,
+            message,
+            num_blocks,
+            ShortintClientKey128::encrypt,
+        )
+    }
+
+    /// Decrypt a ciphertext encrypting an unsigned integer.
+    pub fn decrypt_radix<T>(&self, ctxt: &RadixCiphertext128) -> T
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        let bits_in_block = self.key.parameters.message_modulus.0.ilog2();
+        let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+        for block in &ctxt.blocks {
+            recomposer.add_unmasked(self.key.decrypt_message_and_carry(block));
+        }
+
+        recomposer.value()
+    }
+}
+
+/// The server key of the 128 bits mode.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerKey128 {
+    pub(crate) key: ShortintServerKey128,
+}
+
+impl From<ShortintServerKey128> for ServerKey128 {
+    fn from(key: ShortintServerKey128) -> Self {
+        Self { key }
+    }
+}
+
+impl ServerKey128 {
+    /// Generate the server key associated to the given [`ClientKey128`].
+    pub fn new(cks: &ClientKey128) -> Self {
+        Self {
+            key: ShortintServerKey128::new(&cks.key),
+        }
+    }
+
+    /// Add `ct_right` to `ct_left` block-wise, without propagating the carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks.
+    pub fn unchecked_add_assign(
+        &self,
+        ct_left: &mut RadixCiphertext128,
+        ct_right: &RadixCiphertext128,
+    ) {
+        assert_eq!(
+            ct_left.blocks.len(),
+            ct_right.blocks.len(),
+            "Mismatched number of blocks between the left ({}) and right ({}) operands",
+            ct_left.blocks.len(),
+            ct_right.blocks.len()
+        );
+
+        for (left, right) in ct_left.blocks.iter_mut().zip(ct_right.blocks.iter()) {
+            self.key.unchecked_add_assign(left, right);
+        }
+    }
+
+    /// Propagate the carries of every block to the next one, emptying all carries.
+    ///
+    /// The carry of the last block is dropped, the result is computed modulo the capacity of the
+    /// ciphertext.
+    pub fn full_propagate_assign(&self, ctxt: &mut RadixCiphertext128) {
+        let num_blocks = ctxt.blocks.len();
+
+        for i in 0..num_blocks {
+            if i + 1 < num_blocks {
+                let carry = self.key.carry_extract(&ctxt.blocks[i]);
+                self.key
+                    .unchecked_add_assign(&mut ctxt.blocks[i + 1], &carry);
+            }
+            self.key.message_extract_assign(&mut ctxt.blocks[i]);
+        }
+    }
+
+    /// Add two ciphertexts and propagate the carries of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks.
+    pub fn add_assign(&self, ct_left: &mut RadixCiphertext128, ct_right: &RadixCiphertext128) {
+        let mut ct_right = ct_right.clone();
+        if !ct_left.blocks.iter().all(Ciphertext128::carry_is_empty) {
+            self.full_propagate_assign(ct_left);
+        }
+        if !ct_right.blocks.iter().all(Ciphertext128::carry_is_empty) {
+            self.full_propagate_assign(&mut ct_right);
+        }
+
+        self.unchecked_add_assign(ct_left, &ct_right);
+        self.full_propagate_assign(ct_left);
+    }
+
+    /// Add two ciphertexts, see [`Self::add_assign`].
+    pub fn add(
+        &self,
+        ct_left: &RadixCiphertext128,
+        ct_right: &RadixCiphertext128,
+    ) -> RadixCiphertext128 {
+        let mut result = ct_left.clone();
+        self.add_assign(&mut result, ct_right);
+        result
+    }
+}
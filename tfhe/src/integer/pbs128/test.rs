@@ -0,0 +1,55 @@
+use super::*;
+use crate::shortint::parameters::PARAM_PBS128_MESSAGE_2_CARRY_2;
+use rand::Rng;
+
+const NB_TESTS: usize = 5;
+const NUM_BLOCKS: usize = 8;
+
+#[test]
+fn integer_pbs128_add() {
+    let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+    let sks = ServerKey128::new(&cks);
+    let mut rng = rand::thread_rng();
+
+    let modulus = 1u64 << (2 * NUM_BLOCKS);
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt_radix(clear_0, NUM_BLOCKS);
+        let ct_1 = cks.encrypt_radix(clear_1, NUM_BLOCKS);
+
+        let dec: u64 = cks.decrypt_radix(&ct_0);
+        assert_eq!(dec, clear_0);
+
+        let ct_res = sks.add(&ct_0, &ct_1);
+        assert_eq!(ct_res.blocks().len(), NUM_BLOCKS);
+        assert!(ct_res.blocks().iter().all(Ciphertext128::carry_is_empty));
+
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec, (clear_0 + clear_1) % modulus);
+
+        // Operands with carries are propagated first
+        let mut ct_acc = ct_0.clone();
+        sks.unchecked_add_assign(&mut ct_acc, &ct_1);
+        let ct_res = sks.add(&ct_acc, &ct_acc);
+
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec, (2 * (clear_0 + clear_1)) % modulus);
+    }
+}
+
+#[test]
+fn integer_pbs128_serialization() {
+    let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+    let sks = ServerKey128::new(&cks);
+
+    let sks: ServerKey128 = bincode::deserialize(&bincode::serialize(&sks).unwrap()).unwrap();
+
+    let ct = cks.encrypt_radix(1234u64, NUM_BLOCKS);
+    let ct: RadixCiphertext128 = bincode::deserialize(&bincode::serialize(&ct).unwrap()).unwrap();
+
+    let ct_res = sks.add(&ct, &ct);
+    let dec: u64 = cks.decrypt_radix(&ct_res);
+    assert_eq!(dec, 2468);
+}
@@ -2,7 +2,7 @@ use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
 use serde::{Deserialize, Serialize};
 
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::{CompactCiphertextList, RadixCiphertext};
+use crate::integer::ciphertext::{CompactCiphertextList, IntegerCiphertext, RadixCiphertext};
 use crate::integer::encryption::{create_clear_radix_block_iterator, encrypt_words_radix_impl};
 use crate::integer::{ClientKey, SignedRadixCiphertext};
 use crate::shortint::{
@@ -123,6 +123,37 @@ impl CompactPublicKey {
         }
     }
 
+    /// Re-randomize each block of the ciphertext, see
+    /// [`ShortintCompactPublicKey::rerandomize_assign`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, num_blocks);
+    /// let pk = CompactPublicKey::new(cks.as_ref());
+    ///
+    /// let ct1 = cks.encrypt(17u64);
+    /// let ct2 = cks.encrypt(25u64);
+    /// let mut ct_res: RadixCiphertext = sks.add_parallelized(&ct1, &ct2);
+    ///
+    /// // Refresh the mask and the noise before sending the result back
+    /// pk.rerandomize_assign(&mut ct_res);
+    /// sks.sanitizing_bootstrap_assign_parallelized(&mut ct_res);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn rerandomize_assign<T: IntegerCiphertext>(&self, ct: &mut T) {
+        for block in ct.blocks_mut() {
+            self.key.rerandomize_assign(block);
+        }
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
@@ -1,6 +1,6 @@
 use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertext};
+use crate::integer::ciphertext::{CrtCiphertext, IntegerCiphertext, RadixCiphertext};
 use crate::integer::client_key::ClientKey;
 use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl};
 use crate::integer::public_key::compressed::CompressedPublicKey;
@@ -84,6 +84,13 @@ impl PublicKey {
             ShortintPublicKey::encrypt_without_padding,
         )
     }
+
+    /// Re-randomize each block of the ciphertext, see [`ShortintPublicKey::rerandomize_assign`].
+    pub fn rerandomize_assign<T: IntegerCiphertext>(&self, ct: &mut T) {
+        for block in ct.blocks_mut() {
+            self.key.rerandomize_assign(block);
+        }
+    }
 }
 
 impl From<CompressedPublicKey> for PublicKey {
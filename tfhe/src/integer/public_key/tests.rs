@@ -1,6 +1,6 @@
 use rand::Rng;
 
-use crate::integer::{gen_keys, CompressedPublicKey, IntegerKeyKind, PublicKey};
+use crate::integer::{gen_keys, CompressedPublicKey, IntegerKeyKind, PublicKey, RadixCiphertext};
 use crate::shortint::parameters::*;
 use crate::shortint::ClassicPBSParameters;
 
@@ -27,6 +27,11 @@ create_parametrized_test!(small_radix_encrypt_decrypt_compact_128_bits_list {
     PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
 });
 
+create_parametrized_test!(radix_compact_public_key_rerandomize_and_sanitize {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
 /// Test that the public key can encrypt a 128 bit number
 /// in radix decomposition, and that the client key can decrypt it
 fn big_radix_encrypt_decrypt_128_bits(param: ClassicPBSParameters) {
@@ -105,3 +110,27 @@ fn radix_encrypt_decrypt_compact_128_bits_list(params: ClassicPBSParameters) {
         }
     }
 }
+
+fn radix_compact_public_key_rerandomize_and_sanitize(params: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(params, IntegerKeyKind::Radix);
+    let pk = crate::integer::public_key::CompactPublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+    let num_block = (64f64 / (params.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+    for _ in 0..5 {
+        let clear = rng.gen::<u64>();
+        let ct: RadixCiphertext = cks.encrypt_radix(clear, num_block);
+
+        let mut rerandomized = ct.clone();
+        pk.rerandomize_assign(&mut rerandomized);
+        assert_ne!(rerandomized, ct);
+
+        let decrypted: u64 = cks.decrypt_radix(&rerandomized);
+        assert_eq!(decrypted, clear);
+
+        sks.sanitizing_bootstrap_assign_parallelized(&mut rerandomized);
+        let decrypted: u64 = cks.decrypt_radix(&rerandomized);
+        assert_eq!(decrypted, clear);
+    }
+}
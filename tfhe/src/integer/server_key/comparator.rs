@@ -216,6 +216,36 @@ impl<'a> Comparator<'a> {
         self.server_key.key.unchecked_scalar_add_assign(lhs, 1);
     }
 
+    /// Batched version of [`Self::compare_block_assign`], all the bootstraps are computed in a
+    /// single batch.
+    fn compare_blocks_batch(
+        &self,
+        mut lhs: Vec<crate::shortint::Ciphertext>,
+        rhs: &[crate::shortint::Ciphertext],
+    ) -> Vec<crate::shortint::Ciphertext> {
+        assert_eq!(lhs.len(), rhs.len());
+
+        // Here we need the true lwe sub, not the one that comes from shortint.
+        for (lhs_block, rhs_block) in lhs.iter_mut().zip(rhs.iter()) {
+            crate::core_crypto::algorithms::lwe_ciphertext_sub_assign(
+                &mut lhs_block.ct,
+                &rhs_block.ct,
+            );
+        }
+
+        let mut comparisons = self.server_key.key.apply_lookup_table_batch(
+            &lhs.iter().collect::<Vec<_>>(),
+            &vec![&self.sign_lut; lhs.len()],
+        );
+        for comparison in &mut comparisons {
+            self.server_key
+                .key
+                .unchecked_scalar_add_assign(comparison, 1);
+        }
+
+        comparisons
+    }
+
     // lhs will be assigned
     // - 0 if lhs < rhs
     // - 1 if lhs == rhs
@@ -402,15 +432,8 @@ impl<'a> Comparator<'a> {
                     rhs_blocks: &[crate::shortint::Ciphertext],
                     out_comparisons: &mut Vec<crate::shortint::Ciphertext>,
                 ) {
-                    lhs_blocks
-                        .par_iter()
-                        .zip(rhs_blocks.par_iter())
-                        .map(|(lhs, rhs)| {
-                            let mut lhs = lhs.clone();
-                            comparator.compare_block_assign(&mut lhs, rhs);
-                            lhs
-                        })
-                        .collect_into_vec(out_comparisons);
+                    let lhs_blocks = lhs_blocks.to_vec();
+                    *out_comparisons = comparator.compare_blocks_batch(lhs_blocks, rhs_blocks);
                 }
 
                 compare_blocks
@@ -422,19 +445,18 @@ impl<'a> Comparator<'a> {
                     rhs_blocks: &[crate::shortint::Ciphertext],
                     out_comparisons: &mut Vec<crate::shortint::Ciphertext>,
                 ) {
-                    lhs_blocks
-                        .par_chunks(2)
-                        .zip(rhs_blocks.par_chunks(2))
+                    let (packed_lhs, packed_rhs): (Vec<_>, Vec<_>) = lhs_blocks
+                        .chunks(2)
+                        .zip(rhs_blocks.chunks(2))
                         .map(|(lhs_chunk, rhs_chunk)| {
-                            let (mut packed_lhs, packed_rhs) = rayon::join(
-                                || comparator.pack_block_chunk(lhs_chunk),
-                                || comparator.pack_block_chunk(rhs_chunk),
-                            );
-
-                            comparator.compare_block_assign(&mut packed_lhs, &packed_rhs);
-                            packed_lhs
+                            (
+                                comparator.pack_block_chunk(lhs_chunk),
+                                comparator.pack_block_chunk(rhs_chunk),
+                            )
                         })
-                        .collect_into_vec(out_comparisons);
+                        .unzip();
+
+                    *out_comparisons = comparator.compare_blocks_batch(packed_lhs, &packed_rhs);
                 }
                 compare_blocks
             };
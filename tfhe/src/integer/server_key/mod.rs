@@ -34,6 +34,46 @@ impl From<ServerKey> for crate::shortint::ServerKey {
     }
 }
 
+/// A [`ServerKey`] which is generated while it is serialized, see
+/// [`ServerKey::new_radix_server_key_into_serializer`].
+pub(crate) struct ServerKeyStream<'a> {
+    pub(crate) key: crate::shortint::server_key::ServerKeyStream<'a>,
+}
+
+impl<'a> ServerKeyStream<'a> {
+    pub(crate) fn new_radix(cks: &'a ClientKey) -> Self {
+        Self {
+            key: crate::shortint::server_key::ServerKeyStream {
+                cks: &cks.key,
+                max_degree: integer_radix_server_key_max_degree(cks.key.parameters),
+            },
+        }
+    }
+
+    pub(crate) fn new_crt(cks: &'a ClientKey) -> Self {
+        Self {
+            key: crate::shortint::server_key::ServerKeyStream {
+                cks: &cks.key,
+                max_degree: integer_crt_server_key_max_degree(cks.key.parameters),
+            },
+        }
+    }
+}
+
+impl<'a> Serialize for ServerKeyStream<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        // Same layout as the serde derive of ServerKey
+        let mut state = serializer.serialize_struct("ServerKey", 1)?;
+        state.serialize_field("key", &self.key)?;
+        state.end()
+    }
+}
+
 /// Compute the [`MaxDegree`] for an integer server key (compressed or uncompressed).
 /// To allow carry propagation between shortint blocks in a
 /// [`RadixCiphertext`](`crate::integer::RadixCiphertext`) (which includes adding the extracted
@@ -106,6 +146,104 @@ impl ServerKey {
         Self { key: sks }
     }
 
+    /// Generates a server key destined to be used with
+    /// [`RadixCiphertext`](`crate::integer::RadixCiphertext`) and serializes it into `serializer`
+    /// while it is being generated.
+    ///
+    /// Deserializing the output gives a [`ServerKey`] generated in the same way as
+    /// [`ServerKey::new_radix_server_key`], see
+    /// [`new_into_serializer`](`crate::shortint::ServerKey::new_into_serializer`).
+    pub fn new_radix_server_key_into_serializer<C, S>(
+        cks: C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C: AsRef<ClientKey>,
+        S: serde::Serializer,
+    {
+        ServerKeyStream::new_radix(cks.as_ref()).serialize(serializer)
+    }
+
+    /// Generates a server key destined to be used with
+    /// [`CrtCiphertext`](`crate::integer::CrtCiphertext`) and serializes it into `serializer`
+    /// while it is being generated.
+    ///
+    /// Deserializing the output gives a [`ServerKey`] generated in the same way as
+    /// [`ServerKey::new_crt_server_key`], see
+    /// [`new_into_serializer`](`crate::shortint::ServerKey::new_into_serializer`).
+    pub fn new_crt_server_key_into_serializer<C, S>(
+        cks: C,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        C: AsRef<ClientKey>,
+        S: serde::Serializer,
+    {
+        ServerKeyStream::new_crt(cks.as_ref()).serialize(serializer)
+    }
+
+    /// Generates a server key destined to be used with
+    /// [`RadixCiphertext`](`crate::integer::RadixCiphertext`) and writes it to `writer` while it
+    /// is being generated.
+    ///
+    /// The output is the same as the one of `bincode::serialize_into` called on a [`ServerKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key, any std::io::Write can be used, e.g. a File:
+    /// let mut serialized_sks = vec![];
+    /// ServerKey::new_radix_server_key_into_writer(&cks, &mut serialized_sks).unwrap();
+    ///
+    /// let sks: ServerKey = bincode::deserialize(&serialized_sks).unwrap();
+    /// ```
+    #[cfg(feature = "safe-deserialization")]
+    pub fn new_radix_server_key_into_writer<C>(
+        cks: C,
+        writer: impl std::io::Write,
+    ) -> bincode::Result<()>
+    where
+        C: AsRef<ClientKey>,
+    {
+        use bincode::Options;
+
+        let mut serializer = bincode::Serializer::new(
+            writer,
+            bincode::DefaultOptions::new().with_fixint_encoding(),
+        );
+
+        Self::new_radix_server_key_into_serializer(cks, &mut serializer)
+    }
+
+    /// Generates a server key destined to be used with
+    /// [`CrtCiphertext`](`crate::integer::CrtCiphertext`) and writes it to `writer` while it is
+    /// being generated.
+    ///
+    /// The output is the same as the one of `bincode::serialize_into` called on a [`ServerKey`].
+    #[cfg(feature = "safe-deserialization")]
+    pub fn new_crt_server_key_into_writer<C>(
+        cks: C,
+        writer: impl std::io::Write,
+    ) -> bincode::Result<()>
+    where
+        C: AsRef<ClientKey>,
+    {
+        use bincode::Options;
+
+        let mut serializer = bincode::Serializer::new(
+            writer,
+            bincode::DefaultOptions::new().with_fixint_encoding(),
+        );
+
+        Self::new_crt_server_key_into_serializer(cks, &mut serializer)
+    }
+
     /// Creates a ServerKey destined to be used with
     /// [`RadixCiphertext`](`crate::integer::RadixCiphertext`) from an already generated
     /// shortint::ServerKey.
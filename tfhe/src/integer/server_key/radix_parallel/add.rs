@@ -668,24 +668,19 @@ impl ServerKey {
             }
         });
 
-        let mut generates_or_propagates = Vec::with_capacity(sum_ct.blocks().len());
-        sum_ct
-            .blocks()
-            .par_iter()
-            .enumerate()
-            .map(|(i, block)| {
+        let blocks = sum_ct.blocks().iter().collect::<Vec<_>>();
+        let luts = (0..blocks.len())
+            .map(|i| {
                 if i == 0 {
                     // The first block can only output a carry
-                    self.key
-                        .apply_lookup_table(block, &lut_does_block_generate_carry)
+                    &lut_does_block_generate_carry
                 } else {
-                    self.key
-                        .apply_lookup_table(block, &lut_does_block_generate_or_propagate)
+                    &lut_does_block_generate_or_propagate
                 }
             })
-            .collect_into_vec(&mut generates_or_propagates);
+            .collect::<Vec<_>>();
 
-        generates_or_propagates
+        self.key.apply_lookup_table_batch(&blocks, &luts)
     }
 
     /// op must be associative and commutative
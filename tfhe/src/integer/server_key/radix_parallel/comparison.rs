@@ -4,6 +4,8 @@ use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::server_key::comparator::Comparator;
 
 use crate::integer::ciphertext::boolean_value::BooleanBlock;
+use crate::shortint::server_key::BivariateLookupTableOwned;
+use crate::shortint::Ciphertext;
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -18,14 +20,8 @@ impl ServerKey {
         let lut = self
             .key
             .generate_lookup_table_bivariate(|x, y| u64::from(x == y));
-        let mut block_comparisons = lhs.blocks().to_vec();
-        block_comparisons
-            .par_iter_mut()
-            .zip(rhs.blocks().par_iter())
-            .for_each(|(lhs_block, rhs_block)| {
-                self.key
-                    .unchecked_apply_lookup_table_bivariate_assign(lhs_block, rhs_block, &lut);
-            });
+        let block_comparisons =
+            self.unchecked_apply_lookup_table_bivariate_blockwise(lhs.blocks(), rhs.blocks(), &lut);
 
         let is_equal_result = self.are_all_comparisons_block_true(block_comparisons);
 
@@ -43,35 +39,31 @@ impl ServerKey {
         let lut = self
             .key
             .generate_lookup_table_bivariate(|x, y| u64::from(x != y));
-        let mut block_comparisons = lhs.blocks().to_vec();
-        block_comparisons
-            .par_iter_mut()
-            .zip(rhs.blocks().par_iter())
-            .for_each(|(lhs_block, rhs_block)| {
-                self.key
-                    .unchecked_apply_lookup_table_bivariate_assign(lhs_block, rhs_block, &lut);
-            });
+        let mut block_comparisons =
+            self.unchecked_apply_lookup_table_bivariate_blockwise(lhs.blocks(), rhs.blocks(), &lut);
 
         let message_modulus = self.key.message_modulus.0;
         let carry_modulus = self.key.carry_modulus.0;
         let total_modulus = message_modulus * carry_modulus;
         let max_value = total_modulus - 1;
 
-        let mut block_comparisons_2 = Vec::with_capacity(block_comparisons.len() / 2);
         let is_non_zero = self.key.generate_lookup_table(|x| u64::from(x != 0));
 
         while block_comparisons.len() > 1 {
-            block_comparisons
+            let sums = block_comparisons
                 .par_chunks(max_value)
                 .map(|blocks| {
                     let mut sum = blocks[0].clone();
                     for other_block in &blocks[1..] {
                         self.key.unchecked_add_assign(&mut sum, other_block);
                     }
-                    self.key.apply_lookup_table(&sum, &is_non_zero)
+                    sum
                 })
-                .collect_into_vec(&mut block_comparisons_2);
-            std::mem::swap(&mut block_comparisons_2, &mut block_comparisons);
+                .collect::<Vec<_>>();
+            block_comparisons = self.key.apply_lookup_table_batch(
+                &sums.iter().collect::<Vec<_>>(),
+                &vec![&is_non_zero; sums.len()],
+            );
         }
 
         BooleanBlock::new_unchecked(
@@ -309,4 +301,35 @@ impl ServerKey {
     {
         Comparator::new(self).min_parallelized(lhs, rhs)
     }
+
+    /// Apply the bivariate lookup table to each pair of blocks of `lhs` and `rhs`, all the
+    /// bootstraps being computed in a single batch.
+    fn unchecked_apply_lookup_table_bivariate_blockwise(
+        &self,
+        lhs: &[Ciphertext],
+        rhs: &[Ciphertext],
+        lut: &BivariateLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        assert_eq!(lhs.len(), rhs.len());
+
+        let packed_blocks = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(lhs_block, rhs_block)| {
+                let mut packed = lhs_block.clone();
+                self.key
+                    .unchecked_pack_bivariate_lookup_table_inputs_assign(
+                        &mut packed,
+                        rhs_block,
+                        lut,
+                    );
+                packed
+            })
+            .collect::<Vec<_>>();
+
+        self.key.apply_lookup_table_batch(
+            &packed_blocks.iter().collect::<Vec<_>>(),
+            &vec![&lut.acc; packed_blocks.len()],
+        )
+    }
 }
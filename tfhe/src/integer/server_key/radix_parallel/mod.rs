@@ -85,31 +85,39 @@ impl ServerKey {
         carry
     }
 
+    /// Bootstrap each block of the ciphertext with the identity function, see
+    /// [`crate::shortint::ServerKey::sanitizing_bootstrap_assign`].
+    ///
+    /// See [`crate::integer::public_key::CompactPublicKey::rerandomize_assign`] for an example.
+    pub fn sanitizing_bootstrap_assign_parallelized<T>(&self, ctxt: &mut T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        ctxt.blocks_mut()
+            .par_iter_mut()
+            .for_each(|block| self.key.sanitizing_bootstrap_assign(block));
+    }
+
     pub fn partial_propagate_parallelized<T>(&self, ctxt: &mut T, start_index: usize)
     where
         T: IntegerRadixCiphertext,
     {
         if self.is_eligible_for_parallel_single_carry_propagation(ctxt) {
-            let num_blocks = ctxt.blocks().len();
+            let message_modulus = self.key.message_modulus.0 as u64;
+            let message_lut = self.key.generate_lookup_table(|x| x % message_modulus);
+            let carry_lut = self.key.generate_lookup_table(|x| x / message_modulus);
+
+            // Extract the messages and the carries with a single batch of bootstraps
+            let blocks = &ctxt.blocks()[start_index..];
+            let mut cts = blocks.iter().collect::<Vec<_>>();
+            // No need to compute the carry of the last block, we would just throw it away
+            cts.extend(&blocks[..blocks.len() - 1]);
+            let mut luts = vec![&message_lut; blocks.len()];
+            luts.resize(cts.len(), &carry_lut);
 
-            let (mut message_blocks, carry_blocks) = rayon::join(
-                || {
-                    ctxt.blocks()[start_index..]
-                        .par_iter()
-                        .map(|block| self.key.message_extract(block))
-                        .collect::<Vec<_>>()
-                },
-                || {
-                    let mut carry_blocks = Vec::with_capacity(num_blocks);
-                    // No need to compute the carry of the last block, we would just throw it away
-                    ctxt.blocks()[start_index..num_blocks - 1]
-                        .par_iter()
-                        .map(|block| self.key.carry_extract(block))
-                        .collect_into_vec(&mut carry_blocks);
-                    carry_blocks.insert(0, self.key.create_trivial(0));
-                    carry_blocks
-                },
-            );
+            let mut message_blocks = self.key.apply_lookup_table_batch(&cts, &luts);
+            let mut carry_blocks = message_blocks.split_off(blocks.len());
+            carry_blocks.insert(0, self.key.create_trivial(0));
 
             ctxt.blocks_mut()[start_index..].swap_with_slice(&mut message_blocks);
             let carries = T::from_blocks(carry_blocks);
@@ -164,24 +164,28 @@ impl ServerKey {
         while block_comparisons.len() > 1 {
             // Since all blocks encrypt either 0 or 1, we can sum max_value of them
             // as in the worst case we will be adding `max_value` ones
-            block_comparisons = block_comparisons
+            let sums = block_comparisons
                 .par_chunks(max_value)
                 .map(|blocks| {
                     let mut sum = blocks[0].clone();
                     for other_block in &blocks[1..] {
                         self.key.unchecked_add_assign(&mut sum, other_block);
                     }
-
-                    if blocks.len() == max_value {
-                        self.key.apply_lookup_table(&sum, &is_max_value)
-                    } else {
-                        let is_equal_to_num_blocks = self
-                            .key
-                            .generate_lookup_table(|x| u64::from(x == blocks.len() as u64));
-                        self.key.apply_lookup_table(&sum, &is_equal_to_num_blocks)
-                    }
+                    sum
                 })
                 .collect::<Vec<_>>();
+
+            // Only the last chunk may hold less than max_value blocks
+            let last_chunk_len = block_comparisons.len() - (sums.len() - 1) * max_value;
+            let is_equal_to_last_chunk_len = self
+                .key
+                .generate_lookup_table(|x| u64::from(x == last_chunk_len as u64));
+            let mut luts = vec![&is_max_value; sums.len() - 1];
+            luts.push(&is_equal_to_last_chunk_len);
+
+            block_comparisons = self
+                .key
+                .apply_lookup_table_batch(&sums.iter().collect::<Vec<_>>(), &luts);
         }
 
         block_comparisons
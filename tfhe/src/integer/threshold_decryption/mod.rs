@@ -0,0 +1,225 @@
+//! Module with the threshold decryption of integer radix ciphertexts.
+//!
+//! Every block of a radix ciphertext is partially decrypted with the
+//! [`shortint` threshold decryption](`crate::shortint::threshold_decryption`), the combined blocks
+//! are then recomposed into the cleartext as in a regular decryption.
+
+#[cfg(test)]
+mod test;
+
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::ciphertext::{IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::client_key::{sign_extend_decrypted_value, RecomposableSignedInteger};
+use crate::integer::ClientKey;
+use crate::shortint::parameters::{SecretSharingScheme, StandardDev, ThresholdPartyIndex};
+use crate::shortint::threshold_decryption::{
+    combine_partial_decryptions_message_and_carry, ClientKeyShare as ShortintClientKeyShare,
+    PartialDecryption as ShortintPartialDecryption,
+};
+use serde::{Deserialize, Serialize};
+
+/// The share of a [`ClientKey`] held by one party of a threshold decryption.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientKeyShare {
+    pub(crate) key: ShortintClientKeyShare,
+}
+
+impl From<ShortintClientKeyShare> for ClientKeyShare {
+    fn from(key: ShortintClientKeyShare) -> Self {
+        Self { key }
+    }
+}
+
+impl From<ClientKeyShare> for ShortintClientKeyShare {
+    fn from(key: ClientKeyShare) -> Self {
+        key.key
+    }
+}
+
+/// The contribution of one party to the threshold decryption of a radix ciphertext, it holds
+/// one partial decryption per block of the ciphertext.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RadixPartialDecryption {
+    pub(crate) blocks: Vec<ShortintPartialDecryption>,
+}
+
+impl RadixPartialDecryption {
+    /// Return the index of the party which computed the [`RadixPartialDecryption`].
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.blocks[0].party_index()
+    }
+
+    /// Return the partial decryptions of the blocks.
+    pub fn blocks(&self) -> &[ShortintPartialDecryption] {
+        &self.blocks
+    }
+}
+
+impl ClientKey {
+    /// Split the client key in shares according to the given [`SecretSharingScheme`].
+    ///
+    /// The i-th returned share belongs to the party with index i + 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::threshold_decryption::combine_radix_partial_decryptions;
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::{
+    ///     SecretSharingScheme, StandardDev, ThresholdPartyCount, PARAM_MESSAGE_2_CARRY_3_KS_PBS,
+    /// };
+    ///
+    /// let params = PARAM_MESSAGE_2_CARRY_3_KS_PBS;
+    /// let cks = ClientKey::new(params);
+    /// let num_block = 4;
+    ///
+    /// let shares = cks.share(SecretSharingScheme::Additive {
+    ///     party_count: ThresholdPartyCount(3),
+    /// });
+    ///
+    /// let msg = 191_u64;
+    /// let ct = cks.encrypt_radix(msg, num_block);
+    ///
+    /// // Statistically hiding flooding of fresh ciphertexts, see
+    /// // tfhe::shortint::threshold_decryption for the choice of the flooding noise
+    /// let statistical_security = 40;
+    /// let input_noise_bound = 8.0 * params.glwe_modular_std_dev.0;
+    /// let flooding_noise = StandardDev(2.0f64.powi(statistical_security) * input_noise_bound);
+    /// let partial_decryptions: Vec<_> = shares
+    ///     .iter()
+    ///     .map(|share| share.partial_decrypt_radix(&ct, flooding_noise))
+    ///     .collect();
+    ///
+    /// let dec: u64 = combine_radix_partial_decryptions(&ct, &partial_decryptions);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn share(&self, sharing_scheme: SecretSharingScheme) -> Vec<ClientKeyShare> {
+        self.key
+            .share(sharing_scheme)
+            .into_iter()
+            .map(ClientKeyShare::from)
+            .collect()
+    }
+}
+
+impl ClientKeyShare {
+    /// Return the index of the party holding the share.
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.key.party_index()
+    }
+
+    /// Return the [`SecretSharingScheme`] used to generate the share.
+    pub fn sharing_scheme(&self) -> SecretSharingScheme {
+        self.key.sharing_scheme()
+    }
+
+    /// Compute the partial decryption of a signed or unsigned radix ciphertext with the share.
+    ///
+    /// See [`ShortintClientKeyShare::partial_decrypt`] for the requirements on the flooding
+    /// noise.
+    pub fn partial_decrypt_radix<T>(
+        &self,
+        ctxt: &T,
+        flooding_noise: StandardDev,
+    ) -> RadixPartialDecryption
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            !ctxt.blocks().is_empty(),
+            "Cannot partially decrypt a ciphertext without blocks"
+        );
+
+        RadixPartialDecryption {
+            blocks: ctxt
+                .blocks()
+                .iter()
+                .map(|block| self.key.partial_decrypt(block, flooding_noise))
+                .collect(),
+        }
+    }
+}
+
+fn combine_radix_partial_decryptions_impl<T>(
+    blocks: &[crate::shortint::Ciphertext],
+    partial_decryptions: &[RadixPartialDecryption],
+) -> T
+where
+    T: RecomposableFrom<u64>,
+{
+    assert!(
+        partial_decryptions
+            .iter()
+            .all(|partial_decryption| partial_decryption.blocks.len() == blocks.len()),
+        "Mismatch between the number of blocks of the ciphertext ({}) and of the partial \
+        decryptions",
+        blocks.len()
+    );
+
+    if blocks.is_empty() {
+        return T::ZERO;
+    }
+
+    let bits_in_block = blocks[0].message_modulus.0.ilog2();
+    let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+    let mut block_partial_decryptions = Vec::with_capacity(partial_decryptions.len());
+    for (block_index, encrypted_block) in blocks.iter().enumerate() {
+        block_partial_decryptions.clear();
+        block_partial_decryptions.extend(
+            partial_decryptions
+                .iter()
+                .map(|partial_decryption| partial_decryption.blocks[block_index]),
+        );
+
+        let decrypted_block = combine_partial_decryptions_message_and_carry(
+            encrypted_block,
+            &block_partial_decryptions,
+        );
+        // As in ClientKey::decrypt_radix, all blocks are combined even once T::BITS are reached
+        recomposer.add_unmasked(decrypted_block);
+    }
+
+    recomposer.value()
+}
+
+/// Combine partial decryptions of a [`RadixCiphertext`] and return the decrypted value.
+///
+/// # Panics
+///
+/// Panics if less partial decryptions than the threshold of the [`SecretSharingScheme`] are
+/// provided, if they do not come from distinct parties or if they were not computed on `ctxt`.
+pub fn combine_radix_partial_decryptions<T>(
+    ctxt: &RadixCiphertext,
+    partial_decryptions: &[RadixPartialDecryption],
+) -> T
+where
+    T: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    combine_radix_partial_decryptions_impl(&ctxt.blocks, partial_decryptions)
+}
+
+/// Combine partial decryptions of a [`SignedRadixCiphertext`] and return the decrypted value.
+///
+/// See [`combine_radix_partial_decryptions`] for the requirements on the partial decryptions.
+pub fn combine_signed_radix_partial_decryptions<T>(
+    ctxt: &SignedRadixCiphertext,
+    partial_decryptions: &[RadixPartialDecryption],
+) -> T
+where
+    T: RecomposableSignedInteger,
+{
+    let unpadded_value: T =
+        combine_radix_partial_decryptions_impl(&ctxt.blocks, partial_decryptions);
+
+    if ctxt.blocks.is_empty() {
+        return unpadded_value;
+    }
+
+    let message_modulus = ctxt.blocks[0].message_modulus.0;
+    assert!(message_modulus.is_power_of_two());
+
+    let num_bits_in_ctxt = message_modulus.ilog2() * ctxt.blocks.len() as u32;
+    sign_extend_decrypted_value(unpadded_value, num_bits_in_ctxt)
+}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::{ThresholdPartyCount, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+use rand::Rng;
+
+const NB_TESTS: usize = 10;
+const NUM_BLOCKS: usize = 8;
+const FLOODING_NOISE: StandardDev = StandardDev(0.0000000009313225746154785);
+
+#[test]
+fn integer_threshold_decryption_unsigned() {
+    let (cks, sks) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    let shares = cks.share(SecretSharingScheme::Additive {
+        party_count: ThresholdPartyCount(3),
+    });
+
+    let modulus = 1u64 << (2 * NUM_BLOCKS);
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt_radix(clear_0, NUM_BLOCKS);
+        let ct_1 = cks.encrypt_radix(clear_1, NUM_BLOCKS);
+        let ct_res = sks.add_parallelized(&ct_0, &ct_1);
+
+        let partial_decryptions: Vec<_> = shares
+            .iter()
+            .map(|share| share.partial_decrypt_radix(&ct_res, FLOODING_NOISE))
+            .collect();
+
+        for (partial_decryption, share) in partial_decryptions.iter().zip(shares.iter()) {
+            assert_eq!(partial_decryption.party_index(), share.party_index());
+            assert_eq!(partial_decryption.blocks().len(), NUM_BLOCKS);
+        }
+
+        let dec: u64 = combine_radix_partial_decryptions(&ct_res, &partial_decryptions);
+        assert_eq!(dec, (clear_0 + clear_1) % modulus);
+    }
+}
+
+#[test]
+fn integer_threshold_decryption_signed() {
+    let (cks, sks) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    let shares = cks.share(SecretSharingScheme::Additive {
+        party_count: ThresholdPartyCount(2),
+    });
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<i16>();
+        let clear_1 = rng.gen::<i16>();
+
+        let ct_0 = cks.encrypt_signed_radix(clear_0, NUM_BLOCKS);
+        let ct_1 = cks.encrypt_signed_radix(clear_1, NUM_BLOCKS);
+        let ct_res = sks.add_parallelized(&ct_0, &ct_1);
+
+        let partial_decryptions: Vec<_> = shares
+            .iter()
+            .map(|share| share.partial_decrypt_radix(&ct_res, FLOODING_NOISE))
+            .collect();
+
+        // Decrypting in a wider type checks the sign extension
+        let dec: i64 = combine_signed_radix_partial_decryptions(&ct_res, &partial_decryptions);
+        assert_eq!(dec, clear_0.wrapping_add(clear_1) as i64);
+    }
+}
@@ -104,6 +104,7 @@ pub fn wopbs_native_crt_bivariate() {
         carry_modulus: wopbs_params.carry_modulus,
         ciphertext_modulus: wopbs_params.ciphertext_modulus,
         encryption_key_choice: wopbs_params.encryption_key_choice,
+        secret_key_distribution: SecretKeyDistribution::Binary,
     };
 
     let params = (pbs_params, wopbs_params);
@@ -0,0 +1,132 @@
+//! Module with the proven encryption of radix integers in compact lists.
+//!
+//! See [`crate::shortint::zk`] for the underlying shortint lists and [`crate::zk`] for the
+//! guarantees given by the proofs.
+
+#[cfg(test)]
+mod test;
+
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::encryption::create_clear_radix_block_iterator;
+use crate::integer::public_key::CompactPublicKey;
+use crate::integer::IntegerRadixCiphertext;
+use crate::shortint::zk::ProvenCompactCiphertextList as ShortintProvenCompactCiphertextList;
+use crate::zk::{CompactPkeCrs, InvalidProofError, ZkVerificationOutCome};
+use serde::{Deserialize, Serialize};
+
+/// A list of radix integers encrypted in compact lists along with the proofs of their encryption.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvenCompactCiphertextList {
+    pub(crate) ct_list: ShortintProvenCompactCiphertextList,
+    // Keep track of the num_blocks, as we allow
+    // storing many integer that have the same num_blocks
+    // into ct_list
+    pub(crate) num_blocks_per_integer: usize,
+}
+
+impl ProvenCompactCiphertextList {
+    /// Return the number of radix integers in the list.
+    pub fn ciphertext_count(&self) -> usize {
+        self.ct_list.ciphertext_count() / self.num_blocks_per_integer
+    }
+
+    /// Verify the proofs of the list, and that it holds a whole number of radix integers.
+    ///
+    /// The proofs show that the blocks hold in range messages with a bounded noise, see
+    /// [`crate::zk`].
+    pub fn verify(
+        &self,
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+    ) -> ZkVerificationOutCome {
+        if self.num_blocks_per_integer == 0
+            || self.ct_list.ciphertext_count() % self.num_blocks_per_integer != 0
+        {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        self.ct_list.verify(crs, &public_key.key)
+    }
+
+    /// Verify the list and expand it into radix ciphertexts, see [`Self::verify`].
+    pub fn verify_and_expand<T: IntegerRadixCiphertext>(
+        &self,
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+    ) -> Result<Vec<T>, InvalidProofError> {
+        if self.verify(crs, public_key).is_invalid() {
+            return Err(InvalidProofError);
+        }
+
+        let mut all_block_iter = self
+            .ct_list
+            .proved_lists
+            .iter()
+            .flat_map(|(list, _)| list.expand());
+
+        Ok((0..self.ciphertext_count())
+            .map(|_| {
+                T::from(
+                    all_block_iter
+                        .by_ref()
+                        .take(self.num_blocks_per_integer)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect())
+    }
+}
+
+impl CompactPublicKey {
+    /// Encrypt the messages as radix integers in a [`ProvenCompactCiphertextList`].
+    ///
+    /// The [`CompactPkeCrs`] bounds the number of blocks, not integers, held by each proven
+    /// compact list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{ClientKey, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::zk::CompactPkeCrs;
+    ///
+    /// let num_blocks = 4;
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    /// let crs = CompactPkeCrs::from_shortint_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 8);
+    ///
+    /// let messages = [17u8, 255, 3];
+    /// let proven_list = pk.encrypt_and_prove_slice_radix_compact(&messages, num_blocks, &crs);
+    ///
+    /// let cts: Vec<RadixCiphertext> = proven_list.verify_and_expand(&crs, &pk).unwrap();
+    /// for (ct, &msg) in cts.iter().zip(messages.iter()) {
+    ///     let dec: u8 = cks.decrypt_radix(ct);
+    ///     assert_eq!(dec, msg);
+    /// }
+    /// ```
+    pub fn encrypt_and_prove_slice_radix_compact<T: DecomposableInto<u64>>(
+        &self,
+        messages: &[T],
+        num_blocks_per_integer: usize,
+        crs: &CompactPkeCrs,
+    ) -> ProvenCompactCiphertextList {
+        assert!(
+            num_blocks_per_integer > 0,
+            "At least one block per integer is required"
+        );
+
+        let message_modulus = self.key.parameters.message_modulus();
+        let clear_blocks: Vec<u64> = messages
+            .iter()
+            .flat_map(|&message| {
+                create_clear_radix_block_iterator(message, message_modulus, num_blocks_per_integer)
+            })
+            .collect();
+
+        ProvenCompactCiphertextList {
+            ct_list: self.key.encrypt_and_prove_slice(&clear_blocks, crs),
+            num_blocks_per_integer,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, SignedRadixCiphertext};
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+#[test]
+fn integer_proven_compact_list() {
+    let num_blocks = 4;
+    let (cks, _) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let pk = CompactPublicKey::new(&cks);
+    let crs = CompactPkeCrs::from_shortint_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 6);
+
+    let messages = [0u8, 17, 128, 255];
+    let proven_list = pk.encrypt_and_prove_slice_radix_compact(&messages, num_blocks, &crs);
+    assert_eq!(proven_list.ciphertext_count(), messages.len());
+
+    let cts: Vec<RadixCiphertext> = proven_list.verify_and_expand(&crs, &pk).unwrap();
+    assert_eq!(cts.len(), messages.len());
+    for (ct, &msg) in cts.iter().zip(messages.iter()) {
+        let dec: u8 = cks.decrypt_radix(ct);
+        assert_eq!(dec, msg);
+    }
+
+    let signed_messages = [-128i8, -1, 0, 127];
+    let proven_list = pk.encrypt_and_prove_slice_radix_compact(&signed_messages, num_blocks, &crs);
+    let cts: Vec<SignedRadixCiphertext> = proven_list.verify_and_expand(&crs, &pk).unwrap();
+    for (ct, &msg) in cts.iter().zip(signed_messages.iter()) {
+        let dec: i8 = cks.decrypt_signed_radix(ct);
+        assert_eq!(dec, msg);
+    }
+
+    // A list whose blocks don't form whole integers is rejected
+    let mut forged_list = proven_list;
+    forged_list.num_blocks_per_integer = 3;
+    assert!(forged_list.verify(&crs, &pk).is_invalid());
+}
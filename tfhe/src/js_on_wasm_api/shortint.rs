@@ -332,6 +332,7 @@ impl Shortint {
             )
             .unwrap(),
             encryption_key_choice: encryption_key_choice.into(),
+            secret_key_distribution: crate::shortint::parameters::SecretKeyDistribution::Binary,
         })
     }
 
@@ -125,4 +125,7 @@ pub mod safe_deserialization;
 
 pub mod conformance;
 
+#[cfg(feature = "zk-pok-experimental")]
+pub mod zk;
+
 pub mod named;
@@ -25,6 +25,10 @@ impl NoiseLevel {
     pub const MAX: Self = Self(usize::MAX);
     // As a safety measure the unknwon noise level is set to the max value
     pub const UNKOWN: Self = Self::MAX;
+
+    pub const fn get(&self) -> usize {
+        self.0
+    }
 }
 
 impl std::ops::AddAssign for NoiseLevel {
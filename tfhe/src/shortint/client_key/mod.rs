@@ -1,11 +1,14 @@
 //! Module with the definition of the ClientKey.
 
+use crate::core_crypto::algorithms::allocate_and_generate_new_binary_expanded_lwe_secret_key;
+use crate::core_crypto::commons::parameters::SecretKeyDistribution;
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{MessageModulus, ShortintParameterSet};
 use crate::shortint::CarryModulus;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 /// A structure containing the client key, which must be kept secret.
@@ -48,6 +51,51 @@ impl ClientKey {
         })
     }
 
+    /// Lock the memory holding the secret keys in RAM, preventing it from being written to the
+    /// swap.
+    ///
+    /// Return `false` if any of the secret keys could not be locked, see
+    /// [`LweSecretKey::lock_in_memory`]. Whether they are locked or not, the secret keys are wiped
+    /// from memory when the [`ClientKey`] is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Locking requires the secret-key-memory-lock feature and can be refused by the OS
+    /// let is_locked = cks.lock_in_memory();
+    /// if !cfg!(feature = "secret-key-memory-lock") {
+    ///     assert!(!is_locked);
+    /// }
+    /// ```
+    pub fn lock_in_memory(&self) -> bool {
+        // Lock all the keys even if one of them fails
+        let large_lwe_locked = self.large_lwe_secret_key.lock_in_memory();
+        let glwe_locked = self.glwe_secret_key.lock_in_memory();
+        let small_lwe_locked = self.small_lwe_secret_key.lock_in_memory();
+
+        large_lwe_locked && glwe_locked && small_lwe_locked
+    }
+
+    /// Return the LWE secret key encrypted in the bootstrapping key, i.e. the small LWE secret key
+    /// for binary keys and its binary expansion otherwise.
+    pub(crate) fn bootstrapping_input_lwe_secret_key(&self) -> Cow<'_, LweSecretKeyOwned<u64>> {
+        let secret_key_distribution = self.parameters.secret_key_distribution();
+        match secret_key_distribution {
+            SecretKeyDistribution::Binary => Cow::Borrowed(&self.small_lwe_secret_key),
+            SecretKeyDistribution::Ternary | SecretKeyDistribution::Gaussian { .. } => {
+                Cow::Owned(allocate_and_generate_new_binary_expanded_lwe_secret_key(
+                    &self.small_lwe_secret_key,
+                    secret_key_distribution,
+                ))
+            }
+        }
+    }
+
     /// Encrypt a small integer message using the client key.
     ///
     /// The input message is reduced to the encrypted message space modulus
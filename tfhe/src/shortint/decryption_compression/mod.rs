@@ -0,0 +1,31 @@
+//! Module with the definition of the compression of shortint ciphertexts for their decryption.
+//!
+//! Before being sent to the owner of the secret key, a
+//! [`Ciphertext`](`crate::shortint::Ciphertext`) is keyswitched to a small dedicated LWE secret
+//! key, then modulus switched from $2^{64}$ to $2^{32}$. The resulting
+//! [`DecryptionCompressedCiphertext`] is several times smaller than the original ciphertext, but
+//! can only be decrypted, it cannot be used in further computations.
+
+mod private_key;
+mod server_keys;
+
+#[cfg(test)]
+mod test;
+
+pub use private_key::DecryptionCompressionPrivateKey;
+pub use server_keys::DecryptionCompressionKey;
+
+use crate::core_crypto::entities::LweCiphertextOwned;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use serde::{Deserialize, Serialize};
+
+/// A shortint ciphertext keyswitched and modulus switched to 32 bits, ready to be sent to the
+/// owner of the [`DecryptionCompressionPrivateKey`].
+///
+/// It is created using a [`DecryptionCompressionKey`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptionCompressedCiphertext {
+    pub ct: LweCiphertextOwned<u32>,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
@@ -0,0 +1,86 @@
+use super::DecryptionCompressedCiphertext;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_binary_lwe_secret_key, decrypt_lwe_ciphertext, LweSecretKey,
+    LweSecretKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::DecryptionCompressionParameters;
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Serialize};
+
+/// The secret key used to decrypt
+/// [`DecryptionCompressedCiphertext`](`super::DecryptionCompressedCiphertext`)s, which must be
+/// kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecryptionCompressionPrivateKey {
+    pub lwe_secret_key: LweSecretKeyOwned<u64>,
+    pub params: DecryptionCompressionParameters,
+}
+
+impl ClientKey {
+    /// Generate the private key used to decrypt ciphertexts compressed for decryption.
+    pub fn new_decryption_compression_private_key(
+        &self,
+        params: DecryptionCompressionParameters,
+    ) -> DecryptionCompressionPrivateKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_decryption_compression_private_key(params)
+        })
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_decryption_compression_private_key(
+        &mut self,
+        params: DecryptionCompressionParameters,
+    ) -> DecryptionCompressionPrivateKey {
+        let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            params.lwe_dimension,
+            &mut self.secret_generator,
+        );
+
+        DecryptionCompressionPrivateKey {
+            lwe_secret_key,
+            params,
+        }
+    }
+}
+
+impl DecryptionCompressionPrivateKey {
+    /// Decrypt a compressed ciphertext, returning the message and the carry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of the ciphertext does not match the one of the key.
+    pub fn decrypt_message_and_carry(&self, ct: &DecryptionCompressedCiphertext) -> u64 {
+        // The key is binary, it decrypts the 32 bits ciphertext once its coefficients are cast
+        let lwe_secret_key = LweSecretKey::from_container(
+            self.lwe_secret_key
+                .as_ref()
+                .iter()
+                .map(|&coeff| coeff as u32)
+                .collect::<Vec<_>>(),
+        );
+
+        let decrypted_u32 = decrypt_lwe_ciphertext(&lwe_secret_key, &ct.ct).0;
+
+        let delta = (1_u32 << 31) / (ct.message_modulus.0 * ct.carry_modulus.0) as u32;
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        //compute the rounding bit
+        let rounding = (decrypted_u32 & rounding_bit) << 1;
+
+        (decrypted_u32.wrapping_add(rounding) / delta) as u64
+    }
+
+    /// Decrypt a compressed ciphertext, returning only the message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of the ciphertext does not match the one of the key.
+    pub fn decrypt(&self, ct: &DecryptionCompressedCiphertext) -> u64 {
+        self.decrypt_message_and_carry(ct) % ct.message_modulus.0 as u64
+    }
+}
@@ -0,0 +1,111 @@
+use super::{DecryptionCompressedCiphertext, DecryptionCompressionPrivateKey};
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_lwe_keyswitch_key, keyswitch_lwe_ciphertext,
+    modulus_switch_lwe_ciphertext, CiphertextModulus, LweCiphertext, LweKeyswitchKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{Ciphertext, ClientKey, PBSOrder};
+use serde::{Deserialize, Serialize};
+
+/// The server key used to compress shortint ciphertexts into
+/// [`DecryptionCompressedCiphertext`](`super::DecryptionCompressedCiphertext`)s.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptionCompressionKey {
+    pub keyswitch_key: LweKeyswitchKeyOwned<u64>,
+    pub pbs_order: PBSOrder,
+}
+
+impl ClientKey {
+    /// Generate the [`DecryptionCompressionKey`] associated to the given
+    /// [`DecryptionCompressionPrivateKey`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_key =
+    ///     cks.new_decryption_compression_private_key(DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2);
+    /// let compression_key = cks.new_decryption_compression_key(&private_key);
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let compressed = compression_key.compress_for_decryption(&ct);
+    ///
+    /// assert_eq!(private_key.decrypt(&compressed), 3);
+    /// ```
+    pub fn new_decryption_compression_key(
+        &self,
+        private_key: &DecryptionCompressionPrivateKey,
+    ) -> DecryptionCompressionKey {
+        let params = &private_key.params;
+        let pbs_order = PBSOrder::from(self.parameters.encryption_key_choice());
+
+        // Ciphertexts returned by the server are encrypted under the key used at the end of a PBS
+        let input_lwe_secret_key = match pbs_order {
+            PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key,
+            PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key,
+        };
+
+        let keyswitch_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_lwe_keyswitch_key(
+                input_lwe_secret_key,
+                &private_key.lwe_secret_key,
+                params.ks_base_log,
+                params.ks_level,
+                params.lwe_noise_distribution,
+                self.parameters.ciphertext_modulus(),
+                &mut engine.encryption_generator,
+            )
+        });
+
+        DecryptionCompressionKey {
+            keyswitch_key,
+            pbs_order,
+        }
+    }
+}
+
+impl DecryptionCompressionKey {
+    /// Compress a ciphertext so that it can be sent to the owner of the
+    /// [`DecryptionCompressionPrivateKey`] and decrypted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the PBS order of the ciphertext does not match the one of the key.
+    pub fn compress_for_decryption(&self, ct: &Ciphertext) -> DecryptionCompressedCiphertext {
+        assert_eq!(
+            ct.pbs_order, self.pbs_order,
+            "Mismatched PBSOrder between the ciphertext ({:?}) and the decryption compression \
+            key ({:?})",
+            ct.pbs_order, self.pbs_order
+        );
+
+        let mut keyswitched = LweCiphertext::new(
+            0u64,
+            self.keyswitch_key.output_lwe_size(),
+            self.keyswitch_key.ciphertext_modulus(),
+        );
+
+        keyswitch_lwe_ciphertext(&self.keyswitch_key, &ct.ct, &mut keyswitched);
+
+        let mut switched = LweCiphertext::new(
+            0u32,
+            self.keyswitch_key.output_lwe_size(),
+            CiphertextModulus::new_native(),
+        );
+
+        modulus_switch_lwe_ciphertext(&keyswitched, &mut switched);
+
+        DecryptionCompressedCiphertext {
+            ct: switched,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::{
+    ClassicPBSParameters, DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+};
+
+fn test_compress_for_decryption(param: ClassicPBSParameters) {
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_key =
+        cks.new_decryption_compression_private_key(DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2);
+    let compression_key = cks.new_decryption_compression_key(&private_key);
+
+    let modulus = param.message_modulus.0 as u64;
+
+    for msg in 0..modulus {
+        let ct = cks.encrypt(msg);
+        let compressed = compression_key.compress_for_decryption(&ct);
+        assert_eq!(private_key.decrypt(&compressed), msg);
+
+        // Ciphertexts with a carry and the output of a PBS are supported as well
+        let sum = sks.unchecked_add(&ct, &ct);
+        let compressed = compression_key.compress_for_decryption(&sum);
+        assert_eq!(private_key.decrypt_message_and_carry(&compressed), 2 * msg);
+        assert_eq!(private_key.decrypt(&compressed), (2 * msg) % modulus);
+
+        let squared = sks.mul_lsb(&ct, &ct);
+        let compressed = compression_key.compress_for_decryption(&squared);
+        assert_eq!(private_key.decrypt(&compressed), (msg * msg) % modulus);
+    }
+
+    let ct = cks.encrypt(1);
+    let compressed = compression_key.compress_for_decryption(&ct);
+
+    let original_size = bincode::serialize(&ct).unwrap().len();
+    let compressed_size = bincode::serialize(&compressed).unwrap().len();
+    assert!(
+        2 * compressed_size < original_size,
+        "compressed size {compressed_size} is not small enough, original size {original_size}"
+    );
+}
+
+#[test]
+fn test_compress_for_decryption_ci_run_filter() {
+    test_compress_for_decryption(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+}
+
+#[test]
+fn test_compress_for_decryption_pbs_ks() {
+    test_compress_for_decryption(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+}
@@ -11,16 +11,20 @@ use crate::shortint::{
 
 impl ShortintEngine {
     pub fn new_client_key(&mut self, parameters: ShortintParameterSet) -> ClientKey {
+        let secret_key_distribution = parameters.secret_key_distribution();
+
         // generate the lwe secret key
-        let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        let small_lwe_secret_key = allocate_and_generate_new_lwe_secret_key(
             parameters.lwe_dimension(),
+            secret_key_distribution,
             &mut self.secret_generator,
         );
 
         // generate the rlwe secret key
-        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        let glwe_secret_key = allocate_and_generate_new_glwe_secret_key(
             parameters.glwe_dimension(),
             parameters.polynomial_size(),
+            secret_key_distribution,
             &mut self.secret_generator,
         );
 
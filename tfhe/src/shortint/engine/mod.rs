@@ -9,9 +9,9 @@ use crate::core_crypto::commons::generators::{
 };
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
 use crate::core_crypto::entities::*;
-use crate::core_crypto::prelude::ContainerMut;
+use crate::core_crypto::prelude::{CastFrom, ContainerMut, UnsignedInteger};
 use crate::core_crypto::seeders::new_seeder;
-use crate::shortint::ServerKey;
+use crate::shortint::{CarryModulus, MessageModulus, ServerKey};
 use std::cell::RefCell;
 use std::fmt::Debug;
 
@@ -87,21 +87,47 @@ where
         server_key.bootstrapping_key.glwe_size()
     );
 
+    fill_accumulator_with_moduli(
+        accumulator,
+        server_key.message_modulus,
+        server_key.carry_modulus,
+        f,
+    )
+}
+
+/// Fill the accumulator for a lookup table evaluating `f`, for a ciphertext with the given message
+/// and carry moduli.
+///
+/// Returns the maximum value taken by `f` which can be used as the degree of the output.
+pub(crate) fn fill_accumulator_with_moduli<Scalar, F, C>(
+    accumulator: &mut GlweCiphertext<C>,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    f: F,
+) -> u64
+where
+    Scalar: UnsignedInteger + CastFrom<u64>,
+    C: ContainerMut<Element = Scalar>,
+    F: Fn(u64) -> u64,
+{
+    let polynomial_size = accumulator.polynomial_size();
+
     let mut accumulator_view = accumulator.as_mut_view();
 
-    accumulator_view.get_mut_mask().as_mut().fill(0);
+    accumulator_view.get_mut_mask().as_mut().fill(Scalar::ZERO);
 
     // Modulus of the msg contained in the msg bits and operations buffer
-    let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+    let modulus_sup = message_modulus.0 * carry_modulus.0;
 
     // N/(p/2) = size of each block
-    let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+    let box_size = polynomial_size.0 / modulus_sup;
 
     // Value of the shift we multiply our messages by
-    let delta = (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+    let delta = (Scalar::ONE << (Scalar::BITS - 1))
+        / Scalar::cast_from((message_modulus.0 * carry_modulus.0) as u64);
 
     let mut body = accumulator_view.get_mut_body();
-    let accumulator_u64 = body.as_mut();
+    let accumulator_body = body.as_mut();
 
     // Tracking the max value of the function to define the degree later
     let mut max_value = 0;
@@ -109,11 +135,11 @@ where
     // This accumulator extracts the carry bits
     for i in 0..modulus_sup {
         let index = i * box_size;
-        accumulator_u64[index..index + box_size]
+        accumulator_body[index..index + box_size]
             .iter_mut()
             .for_each(|a| {
                 let f_eval = f(i as u64);
-                *a = f_eval * delta;
+                *a = Scalar::cast_from(f_eval) * delta;
                 max_value = max_value.max(f_eval);
             });
     }
@@ -121,16 +147,61 @@ where
     let half_box_size = box_size / 2;
 
     // Negate the first half_box_size coefficients
-    for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+    for a_i in accumulator_body[0..half_box_size].iter_mut() {
         *a_i = (*a_i).wrapping_neg();
     }
 
     // Rotate the accumulator
-    accumulator_u64.rotate_left(half_box_size);
+    accumulator_body.rotate_left(half_box_size);
 
     max_value
 }
 
+/// Fill the accumulator for a many look-up table evaluating each function of `functions`, for a
+/// ciphertext with the given message and carry moduli.
+///
+/// The input space is split in as many chunks as there are functions, the i-th chunk of boxes
+/// holding the i-th function evaluated on the inputs of the first chunk. The outputs of a bootstrap
+/// using this accumulator are thus extracted one chunk of boxes apart.
+///
+/// Returns the maximum value taken by each function, which can be used as the degree of the
+/// corresponding output.
+pub(crate) fn fill_many_lut_accumulator<C>(
+    accumulator: &mut GlweCiphertext<C>,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    functions: &[&dyn Fn(u64) -> u64],
+) -> Vec<u64>
+where
+    C: ContainerMut<Element = u64>,
+{
+    let modulus_sup = message_modulus.0 * carry_modulus.0;
+    let function_count = functions.len();
+
+    assert!(
+        function_count > 0 && function_count <= modulus_sup,
+        "Got {function_count} functions, at least one and at most {modulus_sup} functions can be \
+        packed in a single accumulator"
+    );
+
+    // Number of inputs each function is evaluated on
+    let chunk_size = modulus_sup / function_count;
+
+    fill_accumulator_with_moduli(accumulator, message_modulus, carry_modulus, |x| {
+        let function_index = x as usize / chunk_size;
+        // Boxes after the last chunk are left empty when the number of functions does not divide
+        // the input space
+        functions
+            .get(function_index)
+            .map_or(0, |f| f(x % chunk_size as u64))
+    });
+
+    functions
+        .iter()
+        .map(|f| (0..chunk_size as u64).map(f).max().unwrap_or(0))
+        .collect()
+}
+
 /// Simple wrapper around [`std::error::Error`] to be able to
 /// forward all the possible `EngineError` type from [`core_crypto`](crate::core_crypto)
 #[allow(dead_code)]
@@ -236,4 +307,10 @@ impl ShortintEngine {
             &mut self.computation_buffers,
         )
     }
+
+    /// Return the [`ComputationBuffers`] of the engine, for computations which do not depend on a
+    /// [`ServerKey`]
+    pub(crate) fn get_computation_buffers(&mut self) -> &mut ComputationBuffers {
+        &mut self.computation_buffers
+    }
 }
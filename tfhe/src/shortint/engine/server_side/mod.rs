@@ -1,20 +1,24 @@
 use super::ShortintEngine;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
 use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
-    LweDimension, PolynomialSize, ThreadCount,
+    LweCiphertextCount, LweDimension, PolynomialSize, ThreadCount,
 };
+use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
-use crate::shortint::parameters::{MessageModulus, ShortintKeySwitchingParameters};
+use crate::shortint::parameters::{MessageModulus, PBSParameters, ShortintKeySwitchingParameters};
 use crate::shortint::server_key::{
-    BivariateLookupTableOwned, LookupTableOwned, MaxDegree, ShortintBootstrappingKey,
-    ShortintCompressedBootstrappingKey,
+    compressed, key_switch_32_placeholder, BivariateLookupTableOwned, LookupTableOwned,
+    ManyLookupTableOwned, MaxDegree, ShortintBootstrappingKey, ShortintCompressedBootstrappingKey,
 };
 use crate::shortint::{Ciphertext, ClientKey, CompressedServerKey, PBSOrder, ServerKey};
+use std::cell::RefCell;
 
 mod add;
 mod bitwise_op;
@@ -76,16 +80,18 @@ impl ShortintEngine {
     ) -> ServerKey {
         let params = &cks.parameters;
         let pbs_params_base = params.pbs_parameters().unwrap();
+        let bootstrapping_input_lwe_secret_key = cks.bootstrapping_input_lwe_secret_key();
         let bootstrapping_key_base = match pbs_params_base {
-            crate::shortint::PBSParameters::PBS(pbs_params) => {
+            crate::shortint::PBSParameters::PBS(_)
+            | crate::shortint::PBSParameters::KeySwitch32PBS(_) => {
                 let bootstrap_key: LweBootstrapKeyOwned<u64> =
                     par_allocate_and_generate_new_lwe_bootstrap_key(
-                        &cks.small_lwe_secret_key,
+                        &*bootstrapping_input_lwe_secret_key,
                         &cks.glwe_secret_key,
-                        pbs_params.pbs_base_log,
-                        pbs_params.pbs_level,
-                        pbs_params.glwe_modular_std_dev,
-                        pbs_params.ciphertext_modulus,
+                        pbs_params_base.pbs_base_log(),
+                        pbs_params_base.pbs_level(),
+                        pbs_params_base.glwe_modular_std_dev(),
+                        pbs_params_base.ciphertext_modulus(),
                         &mut self.encryption_generator,
                     );
 
@@ -106,7 +112,7 @@ impl ShortintEngine {
             crate::shortint::PBSParameters::MultiBitPBS(pbs_params) => {
                 let bootstrap_key: LweMultiBitBootstrapKeyOwned<u64> =
                     par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
-                        &cks.small_lwe_secret_key,
+                        &*bootstrapping_input_lwe_secret_key,
                         &cks.glwe_secret_key,
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
@@ -149,19 +155,51 @@ impl ShortintEngine {
         };
 
         // Creation of the key switching key
-        let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
-            &cks.large_lwe_secret_key,
-            &cks.small_lwe_secret_key,
-            cks.parameters.ks_base_log(),
-            cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
-            cks.parameters.ciphertext_modulus(),
-            &mut self.encryption_generator,
-        );
+        let (key_switching_key, key_switching_key_32) = match pbs_params_base {
+            crate::shortint::PBSParameters::KeySwitch32PBS(_) => {
+                assert_key_switch_32_order(cks);
+
+                let (large_lwe_secret_key, small_lwe_secret_key) = key_switch_32_secret_keys(cks);
+
+                let key_switching_key_32 = allocate_and_generate_new_lwe_keyswitch_key(
+                    &large_lwe_secret_key,
+                    &small_lwe_secret_key,
+                    cks.parameters.ks_base_log(),
+                    cks.parameters.ks_level(),
+                    cks.parameters.lwe_modular_std_dev(),
+                    CiphertextModulus::new_native(),
+                    &mut self.encryption_generator,
+                );
+
+                (
+                    key_switch_32_placeholder(
+                        cks.large_lwe_secret_key.lwe_dimension(),
+                        cks.parameters.ks_base_log(),
+                        cks.parameters.ciphertext_modulus(),
+                    ),
+                    Some(key_switching_key_32),
+                )
+            }
+            crate::shortint::PBSParameters::PBS(_)
+            | crate::shortint::PBSParameters::MultiBitPBS(_) => {
+                let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+                    &cks.large_lwe_secret_key,
+                    &cks.small_lwe_secret_key,
+                    cks.parameters.ks_base_log(),
+                    cks.parameters.ks_level(),
+                    cks.parameters.lwe_modular_std_dev(),
+                    cks.parameters.ciphertext_modulus(),
+                    &mut self.encryption_generator,
+                );
+
+                (key_switching_key, None)
+            }
+        };
 
         // Pack the keys in the server key set:
         ServerKey {
             key_switching_key,
+            key_switching_key_32,
             bootstrapping_key: bootstrapping_key_base,
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
@@ -171,6 +209,46 @@ impl ShortintEngine {
         }
     }
 
+    pub(crate) fn new_server_key_with_max_degree_into_serializer<S: serde::Serializer>(
+        &mut self,
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let params = &cks.parameters;
+        let pbs_order: PBSOrder = params.encryption_key_choice().into();
+        let encryption_generator = RefCell::new(&mut self.encryption_generator);
+
+        // Same layout as the serde derive of ServerKey, the keys are generated in the order in
+        // which they are serialized
+        let key_switching_key = KeyswitchKeyStream {
+            cks,
+            encryption_generator: &encryption_generator,
+        };
+
+        let mut state = serializer.serialize_struct("ServerKey", 8)?;
+        state.serialize_field("key_switching_key", &key_switching_key)?;
+        state.serialize_field(
+            "key_switching_key_32",
+            &KeyswitchKey32Stream(&key_switching_key),
+        )?;
+        state.serialize_field(
+            "bootstrapping_key",
+            &BootstrappingKeyStream {
+                cks,
+                encryption_generator: &encryption_generator,
+            },
+        )?;
+        state.serialize_field("message_modulus", &params.message_modulus())?;
+        state.serialize_field("carry_modulus", &params.carry_modulus())?;
+        state.serialize_field("max_degree", &max_degree)?;
+        state.serialize_field("ciphertext_modulus", &params.ciphertext_modulus())?;
+        state.serialize_field("pbs_order", &pbs_order)?;
+        state.end()
+    }
+
     pub(crate) fn new_key_switching_key(
         &mut self,
         cks1: &ClientKey,
@@ -203,27 +281,30 @@ impl ShortintEngine {
         cks: &ClientKey,
         max_degree: MaxDegree,
     ) -> CompressedServerKey {
-        let bootstrapping_key = match cks.parameters.pbs_parameters().unwrap() {
-            crate::shortint::PBSParameters::PBS(pbs_params) => {
+        let pbs_params_base = cks.parameters.pbs_parameters().unwrap();
+        let bootstrapping_input_lwe_secret_key = cks.bootstrapping_input_lwe_secret_key();
+        let bootstrapping_key = match pbs_params_base {
+            crate::shortint::PBSParameters::PBS(_)
+            | crate::shortint::PBSParameters::KeySwitch32PBS(_) => {
                 #[cfg(not(feature = "__wasm_api"))]
                 let bootstrapping_key = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
-                    &cks.small_lwe_secret_key,
+                    &*bootstrapping_input_lwe_secret_key,
                     &cks.glwe_secret_key,
-                    pbs_params.pbs_base_log,
-                    pbs_params.pbs_level,
-                    pbs_params.glwe_modular_std_dev,
-                    pbs_params.ciphertext_modulus,
+                    pbs_params_base.pbs_base_log(),
+                    pbs_params_base.pbs_level(),
+                    pbs_params_base.glwe_modular_std_dev(),
+                    pbs_params_base.ciphertext_modulus(),
                     &mut self.seeder,
                 );
 
                 #[cfg(feature = "__wasm_api")]
                 let bootstrapping_key = allocate_and_generate_new_seeded_lwe_bootstrap_key(
-                    &cks.small_lwe_secret_key,
+                    &*bootstrapping_input_lwe_secret_key,
                     &cks.glwe_secret_key,
-                    pbs_params.pbs_base_log,
-                    pbs_params.pbs_level,
-                    pbs_params.glwe_modular_std_dev,
-                    pbs_params.ciphertext_modulus,
+                    pbs_params_base.pbs_base_log(),
+                    pbs_params_base.pbs_level(),
+                    pbs_params_base.glwe_modular_std_dev(),
+                    pbs_params_base.ciphertext_modulus(),
                     &mut self.seeder,
                 );
 
@@ -233,7 +314,7 @@ impl ShortintEngine {
                 #[cfg(not(feature = "__wasm_api"))]
                 let bootstrapping_key =
                     par_allocate_and_generate_new_seeded_lwe_multi_bit_bootstrap_key(
-                        &cks.small_lwe_secret_key,
+                        &*bootstrapping_input_lwe_secret_key,
                         &cks.glwe_secret_key,
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
@@ -246,7 +327,7 @@ impl ShortintEngine {
                 #[cfg(feature = "__wasm_api")]
                 let bootstrapping_key =
                     allocate_and_generate_new_seeded_lwe_multi_bit_bootstrap_key(
-                        &cks.small_lwe_secret_key,
+                        &*bootstrapping_input_lwe_secret_key,
                         &cks.glwe_secret_key,
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
@@ -264,19 +345,51 @@ impl ShortintEngine {
         };
 
         // Creation of the key switching key
-        let key_switching_key = allocate_and_generate_new_seeded_lwe_keyswitch_key(
-            &cks.large_lwe_secret_key,
-            &cks.small_lwe_secret_key,
-            cks.parameters.ks_base_log(),
-            cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
-            cks.parameters.ciphertext_modulus(),
-            &mut self.seeder,
-        );
+        let (key_switching_key, key_switching_key_32) = match pbs_params_base {
+            crate::shortint::PBSParameters::KeySwitch32PBS(_) => {
+                assert_key_switch_32_order(cks);
+
+                let (large_lwe_secret_key, small_lwe_secret_key) = key_switch_32_secret_keys(cks);
+
+                let key_switching_key_32 = allocate_and_generate_new_seeded_lwe_keyswitch_key(
+                    &large_lwe_secret_key,
+                    &small_lwe_secret_key,
+                    cks.parameters.ks_base_log(),
+                    cks.parameters.ks_level(),
+                    cks.parameters.lwe_modular_std_dev(),
+                    CiphertextModulus::new_native(),
+                    &mut self.seeder,
+                );
+
+                (
+                    compressed::seeded_key_switch_32_placeholder(
+                        cks.large_lwe_secret_key.lwe_dimension(),
+                        cks.parameters.ks_base_log(),
+                        cks.parameters.ciphertext_modulus(),
+                    ),
+                    Some(key_switching_key_32),
+                )
+            }
+            crate::shortint::PBSParameters::PBS(_)
+            | crate::shortint::PBSParameters::MultiBitPBS(_) => {
+                let key_switching_key = allocate_and_generate_new_seeded_lwe_keyswitch_key(
+                    &cks.large_lwe_secret_key,
+                    &cks.small_lwe_secret_key,
+                    cks.parameters.ks_base_log(),
+                    cks.parameters.ks_level(),
+                    cks.parameters.lwe_modular_std_dev(),
+                    cks.parameters.ciphertext_modulus(),
+                    &mut self.seeder,
+                );
+
+                (key_switching_key, None)
+            }
+        };
 
         // Pack the keys in the server key set:
         CompressedServerKey {
             key_switching_key,
+            key_switching_key_32,
             bootstrapping_key,
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
@@ -292,6 +405,16 @@ impl ShortintEngine {
         ct: &mut Ciphertext,
         acc: &LookupTableOwned,
     ) {
+        if let Some(key_switching_key_32) = &server_key.key_switching_key_32 {
+            self.keyswitch_32_programmable_bootstrap_assign(
+                server_key,
+                key_switching_key_32,
+                ct,
+                acc,
+            );
+            return;
+        }
+
         // Compute the programmable bootstrapping with fixed test polynomial
         let (mut ciphertext_buffers, buffers) = self.get_buffers(server_key);
 
@@ -356,6 +479,60 @@ impl ShortintEngine {
         ct.set_noise_level(NoiseLevel::NOMINAL);
     }
 
+    /// Keyswitch `ct` to a `u32` ciphertext and bootstrap it back to `u64`.
+    fn keyswitch_32_programmable_bootstrap_assign(
+        &mut self,
+        server_key: &ServerKey,
+        key_switching_key: &LweKeyswitchKeyOwned<u32>,
+        ct: &mut Ciphertext,
+        acc: &LookupTableOwned,
+    ) {
+        let ShortintBootstrappingKey::Classic(fourier_bsk) = &server_key.bootstrapping_key else {
+            panic!("The keyswitch to u32 ciphertexts is only supported with the classic PBS")
+        };
+
+        let mut buffer_lwe_after_ks = LweCiphertext::new(
+            0u32,
+            key_switching_key.output_lwe_size(),
+            key_switching_key.ciphertext_modulus(),
+        );
+
+        // Compute a key switch to u32
+        keyswitch_lwe_ciphertext_with_scalar_change(
+            key_switching_key,
+            &ct.ct,
+            &mut buffer_lwe_after_ks,
+        );
+
+        let buffers = self.get_computation_buffers();
+
+        let fft = Fft::new(fourier_bsk.polynomial_size());
+        let fft = fft.as_view();
+        buffers.resize(
+            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                fourier_bsk.glwe_size(),
+                fourier_bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let stack = buffers.stack();
+
+        // Compute a bootstrap of the u32 ciphertext
+        programmable_bootstrap_lwe_ciphertext_with_scalar_change_mem_optimized(
+            &buffer_lwe_after_ks,
+            &mut ct.ct,
+            &acc.acc,
+            fourier_bsk,
+            fft,
+            stack,
+        );
+
+        ct.degree = acc.degree;
+        ct.set_noise_level(NoiseLevel::NOMINAL);
+    }
+
     pub(crate) fn unchecked_apply_lookup_table_bivariate(
         &mut self,
         server_key: &ServerKey,
@@ -374,6 +551,20 @@ impl ShortintEngine {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
         acc: &BivariateLookupTableOwned,
+    ) {
+        self.pack_bivariate_lookup_table_inputs_assign(ct_left, ct_right, acc);
+
+        // Compute the PBS
+        self.apply_lookup_table_assign(server_key, ct_left, &acc.acc);
+    }
+
+    /// Pack `ct_right` in the lower part of the message space of `ct_left`, producing the input
+    /// expected by the univariate lookup table of `acc`.
+    pub(crate) fn pack_bivariate_lookup_table_inputs_assign(
+        &mut self,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+        acc: &BivariateLookupTableOwned,
     ) {
         let modulus = (ct_right.degree.0 + 1) as u64;
         assert!(modulus <= acc.ct_right_modulus.0 as u64);
@@ -382,9 +573,6 @@ impl ShortintEngine {
         self.unchecked_scalar_mul_assign(ct_left, acc.ct_right_modulus.0 as u8);
 
         self.unchecked_add_assign(ct_left, ct_right);
-
-        // Compute the PBS
-        self.apply_lookup_table_assign(server_key, ct_left, &acc.acc);
     }
 
     pub(crate) fn unchecked_evaluate_bivariate_function<F>(
@@ -617,6 +805,154 @@ impl ShortintEngine {
         ct_res
     }
 
+    /// Compute a programmable bootstrap evaluating all the functions of a [`ManyLookupTableOwned`]
+    /// on `input`, the outputs are encrypted under the key of the output of the bootstrap.
+    fn many_lut_programmable_bootstrap(
+        &mut self,
+        server_key: &ServerKey,
+        input: &LweCiphertextOwned<u64>,
+        acc: &ManyLookupTableOwned,
+    ) -> LweCiphertextListOwned<u64> {
+        let mut outputs = LweCiphertextList::new(
+            0,
+            server_key
+                .bootstrapping_key
+                .output_lwe_dimension()
+                .to_lwe_size(),
+            LweCiphertextCount(acc.function_count()),
+            server_key.ciphertext_modulus,
+        );
+
+        match &server_key.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                let buffers = self.get_computation_buffers();
+
+                let fft = Fft::new(fourier_bsk.polynomial_size());
+                let fft = fft.as_view();
+                buffers.resize(
+                    programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement::<
+                        u64,
+                    >(fourier_bsk.glwe_size(), fourier_bsk.polynomial_size(), fft)
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized(
+                    input,
+                    &mut outputs,
+                    &acc.acc,
+                    fourier_bsk,
+                    acc.sample_extraction_stride,
+                    fft,
+                    stack,
+                );
+            }
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk,
+                thread_count,
+                deterministic_execution,
+            } => {
+                if *deterministic_execution {
+                    multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext_many_lut(
+                        input,
+                        &mut outputs,
+                        &acc.acc,
+                        fourier_bsk,
+                        *thread_count,
+                        acc.sample_extraction_stride,
+                    );
+                } else {
+                    multi_bit_programmable_bootstrap_lwe_ciphertext_many_lut(
+                        input,
+                        &mut outputs,
+                        &acc.acc,
+                        fourier_bsk,
+                        *thread_count,
+                        acc.sample_extraction_stride,
+                    );
+                }
+            }
+        };
+
+        outputs
+    }
+
+    pub(crate) fn apply_many_lookup_tables(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        acc: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        let outputs = match server_key.pbs_order {
+            PBSOrder::KeyswitchBootstrap => {
+                let mut buffer_lwe_after_ks = LweCiphertext::new(
+                    0,
+                    server_key
+                        .key_switching_key
+                        .output_key_lwe_dimension()
+                        .to_lwe_size(),
+                    server_key.ciphertext_modulus,
+                );
+
+                // Compute a key switch
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &ct.ct,
+                    &mut buffer_lwe_after_ks,
+                );
+
+                self.many_lut_programmable_bootstrap(server_key, &buffer_lwe_after_ks, acc)
+                    .iter()
+                    .map(|output| {
+                        LweCiphertext::from_container(
+                            output.as_ref().to_vec(),
+                            output.ciphertext_modulus(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }
+            PBSOrder::BootstrapKeyswitch => self
+                .many_lut_programmable_bootstrap(server_key, &ct.ct, acc)
+                .iter()
+                .map(|output| {
+                    let mut output_after_ks = LweCiphertext::new(
+                        0,
+                        server_key
+                            .key_switching_key
+                            .output_key_lwe_dimension()
+                            .to_lwe_size(),
+                        server_key.ciphertext_modulus,
+                    );
+
+                    // Compute a key switch
+                    keyswitch_lwe_ciphertext(
+                        &server_key.key_switching_key,
+                        &output,
+                        &mut output_after_ks,
+                    );
+
+                    output_after_ks
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        outputs
+            .into_iter()
+            .zip(acc.per_function_output_degree.iter())
+            .map(|(output, degree)| {
+                Ciphertext::new(
+                    output,
+                    *degree,
+                    NoiseLevel::NOMINAL,
+                    ct.message_modulus,
+                    ct.carry_modulus,
+                    ct.pbs_order,
+                )
+            })
+            .collect()
+    }
+
     pub(crate) fn carry_extract_assign(&mut self, server_key: &ServerKey, ct: &mut Ciphertext) {
         let modulus = ct.message_modulus.0 as u64;
 
@@ -659,10 +995,7 @@ impl ShortintEngine {
                 .bootstrapping_key
                 .output_lwe_dimension()
                 .to_lwe_size(),
-            PBSOrder::BootstrapKeyswitch => server_key
-                .bootstrapping_key
-                .input_lwe_dimension()
-                .to_lwe_size(),
+            PBSOrder::BootstrapKeyswitch => server_key.key_switching_key.output_lwe_size(),
         };
 
         let modular_value = value as usize % server_key.message_modulus.0;
@@ -713,3 +1046,193 @@ impl ShortintEngine {
         ct.set_noise_level(NoiseLevel::ZERO);
     }
 }
+
+// Return the large and small LWE secret keys of `cks` cast to u32, used to generate the
+// keyswitching key of KeySwitch32PBSParameters.
+fn key_switch_32_secret_keys(cks: &ClientKey) -> (LweSecretKeyOwned<u32>, LweSecretKeyOwned<u32>) {
+    let cast = |lwe_secret_key: &LweSecretKeyOwned<u64>| {
+        LweSecretKey::from_container(
+            lwe_secret_key
+                .as_ref()
+                .iter()
+                .map(|&coeff| coeff as u32)
+                .collect::<Vec<_>>(),
+        )
+    };
+
+    (
+        cast(&cks.large_lwe_secret_key),
+        cast(&cks.small_lwe_secret_key),
+    )
+}
+
+// The keyswitch to u32 ciphertexts is followed by a bootstrap back to u64 ciphertexts: it is only
+// supported in the KeyswitchBootstrap order.
+fn assert_key_switch_32_order(cks: &ClientKey) {
+    assert_eq!(
+        PBSOrder::from(cks.parameters.encryption_key_choice()),
+        PBSOrder::KeyswitchBootstrap,
+        "The keyswitch to u32 ciphertexts is only supported with the KeyswitchBootstrap order"
+    );
+}
+
+// Keyswitching keys of a ServerKey, generated while they are serialized.
+//
+// With KeySwitch32PBSParameters the u64 key is the placeholder and the u32 key is serialized in
+// the key_switching_key_32 field.
+struct KeyswitchKeyStream<'a, 'g> {
+    cks: &'a ClientKey,
+    encryption_generator: &'a RefCell<&'g mut EncryptionRandomGenerator<ActivatedRandomGenerator>>,
+}
+
+impl<'a, 'g> KeyswitchKeyStream<'a, 'g> {
+    fn is_key_switch_32(&self) -> bool {
+        matches!(
+            self.cks.parameters.pbs_parameters(),
+            Some(PBSParameters::KeySwitch32PBS(_))
+        )
+    }
+}
+
+impl<'a, 'g> serde::Serialize for KeyswitchKeyStream<'a, 'g> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let cks = self.cks;
+        let params = &cks.parameters;
+
+        if self.is_key_switch_32() {
+            assert_key_switch_32_order(cks);
+
+            return key_switch_32_placeholder(
+                cks.large_lwe_secret_key.lwe_dimension(),
+                params.ks_base_log(),
+                params.ciphertext_modulus(),
+            )
+            .serialize(serializer);
+        }
+
+        let mut encryption_generator = self.encryption_generator.borrow_mut();
+
+        generate_lwe_keyswitch_key_into_serializer(
+            &cks.large_lwe_secret_key,
+            &cks.small_lwe_secret_key,
+            params.ks_base_log(),
+            params.ks_level(),
+            params.lwe_modular_std_dev(),
+            params.ciphertext_modulus(),
+            &mut **encryption_generator,
+            serializer,
+        )
+    }
+}
+
+// Serialized as an Option<LweKeyswitchKeyOwned<u32>>.
+struct KeyswitchKey32Stream<'s, 'a, 'g>(&'s KeyswitchKeyStream<'a, 'g>);
+
+impl<'s, 'a, 'g> serde::Serialize for KeyswitchKey32Stream<'s, 'a, 'g> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_key_switch_32() {
+            serializer.serialize_some(&LweKeyswitchKey32Stream(self.0))
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+struct LweKeyswitchKey32Stream<'s, 'a, 'g>(&'s KeyswitchKeyStream<'a, 'g>);
+
+impl<'s, 'a, 'g> serde::Serialize for LweKeyswitchKey32Stream<'s, 'a, 'g> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let cks = self.0.cks;
+        let params = &cks.parameters;
+        let mut encryption_generator = self.0.encryption_generator.borrow_mut();
+
+        let (large_lwe_secret_key, small_lwe_secret_key) = key_switch_32_secret_keys(cks);
+
+        generate_lwe_keyswitch_key_into_serializer(
+            &large_lwe_secret_key,
+            &small_lwe_secret_key,
+            params.ks_base_log(),
+            params.ks_level(),
+            params.lwe_modular_std_dev(),
+            CiphertextModulus::<u32>::new_native(),
+            &mut **encryption_generator,
+            serializer,
+        )
+    }
+}
+
+// Bootstrapping key of a ServerKey, generated in the Fourier domain while it is serialized.
+//
+// This has the same layout as the serialization of ShortintBootstrappingKey.
+struct BootstrappingKeyStream<'a, 'g> {
+    cks: &'a ClientKey,
+    encryption_generator: &'a RefCell<&'g mut EncryptionRandomGenerator<ActivatedRandomGenerator>>,
+}
+
+impl<'a, 'g> serde::Serialize for BootstrappingKeyStream<'a, 'g> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStructVariant;
+
+        const NAME: &str = "SerializableShortintBootstrappingKey";
+
+        match self.cks.parameters.pbs_parameters().unwrap() {
+            crate::shortint::PBSParameters::PBS(_)
+            | crate::shortint::PBSParameters::KeySwitch32PBS(_) => serializer
+                .serialize_newtype_variant(
+                    NAME,
+                    0,
+                    "Classic",
+                    &FourierBootstrappingKeyStream(self),
+                ),
+            crate::shortint::PBSParameters::MultiBitPBS(pbs_params) => {
+                let mut state = serializer.serialize_struct_variant(NAME, 1, "MultiBit", 2)?;
+                state.serialize_field("fourier_bsk", &FourierBootstrappingKeyStream(self))?;
+                state.serialize_field(
+                    "deterministic_execution",
+                    &pbs_params.deterministic_execution,
+                )?;
+                state.end()
+            }
+        }
+    }
+}
+
+struct FourierBootstrappingKeyStream<'s, 'a, 'g>(&'s BootstrappingKeyStream<'a, 'g>);
+
+impl<'s, 'a, 'g> serde::Serialize for FourierBootstrappingKeyStream<'s, 'a, 'g> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let cks = self.0.cks;
+        let mut encryption_generator = self.0.encryption_generator.borrow_mut();
+
+        let pbs_params_base = cks.parameters.pbs_parameters().unwrap();
+        let bootstrapping_input_lwe_secret_key = cks.bootstrapping_input_lwe_secret_key();
+        match pbs_params_base {
+            crate::shortint::PBSParameters::PBS(_)
+            | crate::shortint::PBSParameters::KeySwitch32PBS(_) => {
+                par_generate_fourier_lwe_bootstrap_key_into_serializer(
+                    &*bootstrapping_input_lwe_secret_key,
+                    &cks.glwe_secret_key,
+                    pbs_params_base.pbs_base_log(),
+                    pbs_params_base.pbs_level(),
+                    pbs_params_base.glwe_modular_std_dev(),
+                    pbs_params_base.ciphertext_modulus(),
+                    &mut **encryption_generator,
+                    serializer,
+                )
+            }
+            crate::shortint::PBSParameters::MultiBitPBS(pbs_params) => {
+                par_generate_fourier_lwe_multi_bit_bootstrap_key_into_serializer(
+                    &*bootstrapping_input_lwe_secret_key,
+                    &cks.glwe_secret_key,
+                    pbs_params.pbs_base_log,
+                    pbs_params.pbs_level,
+                    pbs_params.grouping_factor,
+                    pbs_params.glwe_modular_std_dev,
+                    pbs_params.ciphertext_modulus,
+                    &mut **encryption_generator,
+                    serializer,
+                )
+            }
+        }
+    }
+}
@@ -14,6 +14,8 @@ use crate::shortint::{Ciphertext, ClientKey, ServerKey, WopbsParameters};
 #[derive(Debug)]
 pub enum WopbsKeyCreationError {
     UnsupportedMultiBit,
+    UnsupportedKeySwitch32,
+    UnsupportedSecretKeyDistribution,
 }
 
 impl std::error::Error for WopbsKeyCreationError {}
@@ -24,6 +26,18 @@ impl std::fmt::Display for WopbsKeyCreationError {
             Self::UnsupportedMultiBit => {
                 write!(f, "WopbsKey does not yet support using multi bit PBS")
             }
+            Self::UnsupportedKeySwitch32 => {
+                write!(
+                    f,
+                    "WopbsKey does not support using a keyswitch to u32 ciphertexts"
+                )
+            }
+            Self::UnsupportedSecretKeyDistribution => {
+                write!(
+                    f,
+                    "WopbsKey created only for WoPBS does not support non binary secret keys"
+                )
+            }
         }
     }
 }
@@ -41,6 +55,12 @@ impl ShortintEngine {
         ) {
             return Err(WopbsKeyCreationError::UnsupportedMultiBit.into());
         }
+        if sks.key_switching_key_32.is_some() {
+            return Err(WopbsKeyCreationError::UnsupportedKeySwitch32.into());
+        }
+        if cks.parameters.secret_key_distribution() != SecretKeyDistribution::Binary {
+            return Err(WopbsKeyCreationError::UnsupportedSecretKeyDistribution.into());
+        }
 
         let wop_params = cks.parameters.wopbs_parameters().unwrap();
 
@@ -157,6 +177,7 @@ impl ShortintEngine {
 
         let wopbs_server_key = ServerKey {
             key_switching_key: ksk_wopbs_large_to_wopbs_small,
+            key_switching_key_32: None,
             bootstrapping_key: ShortintBootstrappingKey::Classic(small_bsk),
             message_modulus: parameters.message_modulus,
             carry_modulus: parameters.carry_modulus,
@@ -167,6 +188,7 @@ impl ShortintEngine {
 
         let pbs_server_key = ServerKey {
             key_switching_key: ksk_wopbs_large_to_pbs_small,
+            key_switching_key_32: None,
             bootstrapping_key: sks.bootstrapping_key.clone(),
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
@@ -239,6 +239,8 @@ named_params_impl!( ShortintParameterSet =>
     WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_7_KS_PBS,
     WOPBS_PRIME_PARAM_MESSAGE_8_NORM2_8_KS_PBS,
     PARAM_4_BITS_5_BLOCKS,
+    // KS32
+    PARAM_MESSAGE_2_CARRY_2_KS32_PBS,
 );
 
 impl NamedParam for ClassicPBSParameters {
@@ -253,6 +255,12 @@ impl NamedParam for MultiBitPBSParameters {
     }
 }
 
+impl NamedParam for KeySwitch32PBSParameters {
+    fn name(&self) -> String {
+        PBSParameters::from(*self).name()
+    }
+}
+
 impl NamedParam for PBSParameters {
     fn name(&self) -> String {
         ShortintParameterSet::from(*self).name()
@@ -0,0 +1,243 @@
+use super::{CompressedCiphertextList, CompressionKey, DecompressionKey};
+use crate::core_crypto::fft_impl::fft64::math::fft::FftView;
+use crate::core_crypto::prelude::{
+    extract_lwe_sample_from_glwe_ciphertext,
+    keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext,
+    programmable_bootstrap_lwe_ciphertext_mem_optimized,
+    programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement,
+    CompressedModulusSwitchedGlweCiphertext, Fft, GlweCiphertext, LweCiphertext,
+    LweCiphertextCount, LweCiphertextList, MonomialDegree,
+};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::{fill_accumulator_with_moduli, ShortintEngine};
+use crate::shortint::{Ciphertext, PBSOrder};
+use rayon::prelude::*;
+
+impl CompressionKey {
+    /// Pack the given ciphertexts in a [`CompressedCiphertextList`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is empty, if the ciphertexts do not share the same parameters, if their
+    /// carries are not empty, if their noise level is not nominal or if they do not use the
+    /// [`KeyswitchBootstrap`](`PBSOrder::KeyswitchBootstrap`) order.
+    ///
+    /// See [`ClientKey::new_compression_decompression_keys`](`crate::shortint::ClientKey`) for
+    /// usage.
+    pub fn compress_ciphertexts_into_list(
+        &self,
+        ciphertexts: &[Ciphertext],
+    ) -> CompressedCiphertextList {
+        let count = ciphertexts.len();
+
+        assert!(count > 0, "Cannot compress an empty list of ciphertexts");
+
+        let lwe_pksk = &self.packing_key_switching_key;
+
+        let polynomial_size = lwe_pksk.output_polynomial_size();
+        let ciphertext_modulus = lwe_pksk.ciphertext_modulus();
+        let lwe_size = lwe_pksk.input_key_lwe_dimension().to_lwe_size();
+
+        let first_ct = &ciphertexts[0];
+
+        let message_modulus = first_ct.message_modulus;
+        let carry_modulus = first_ct.carry_modulus;
+        let pbs_order = first_ct.pbs_order;
+
+        assert_eq!(
+            pbs_order,
+            PBSOrder::KeyswitchBootstrap,
+            "Compression is only compatible with the KeyswitchBootstrap PBS order"
+        );
+
+        for ct in ciphertexts {
+            assert_eq!(
+                ct.ct.lwe_size(),
+                lwe_size,
+                "All ciphertexts must have the input lwe size of the packing keyswitch key"
+            );
+            assert_eq!(ct.ct.ciphertext_modulus(), ciphertext_modulus);
+            assert_eq!(ct.message_modulus, message_modulus);
+            assert_eq!(ct.carry_modulus, carry_modulus);
+            assert_eq!(ct.pbs_order, pbs_order);
+            assert!(
+                ct.carry_is_empty(),
+                "Ciphertexts must have empty carries to be compressed"
+            );
+            assert!(
+                ct.noise_level() == NoiseLevel::NOMINAL || ct.noise_level() == NoiseLevel::ZERO,
+                "Ciphertexts must have a nominal noise level to be compressed"
+            );
+        }
+
+        let modulus_switched_glwe_ciphertext_list = ciphertexts
+            .par_chunks(self.lwe_per_glwe.0)
+            .map(|chunk| {
+                let mut buffer = Vec::with_capacity(chunk.len() * lwe_size.0);
+
+                for ct in chunk {
+                    buffer.extend(ct.ct.as_ref());
+                }
+
+                let input_lwe_list =
+                    LweCiphertextList::from_container(buffer, lwe_size, ciphertext_modulus);
+
+                let mut packed_glwe = GlweCiphertext::new(
+                    0,
+                    lwe_pksk.output_glwe_size(),
+                    polynomial_size,
+                    ciphertext_modulus,
+                );
+
+                keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(
+                    lwe_pksk,
+                    &input_lwe_list,
+                    &mut packed_glwe,
+                );
+
+                CompressedModulusSwitchedGlweCiphertext::compress(
+                    &packed_glwe,
+                    self.storage_log_modulus,
+                    LweCiphertextCount(chunk.len()),
+                )
+            })
+            .collect();
+
+        CompressedCiphertextList {
+            modulus_switched_glwe_ciphertext_list,
+            ciphertext_modulus,
+            message_modulus,
+            carry_modulus,
+            pbs_order,
+            lwe_per_glwe: self.lwe_per_glwe,
+            count,
+        }
+    }
+}
+
+impl DecompressionKey {
+    /// Recover the ciphertext stored at the given index of a [`CompressedCiphertextList`].
+    ///
+    /// Returns [`None`] if the index is out of bounds.
+    ///
+    /// The output ciphertext is bootstrapped, so it has a nominal noise level.
+    ///
+    /// This builds the FFT plan of the blind rotation key on each call, prefer
+    /// [`Self::unpack_with_fft`] to recover several ciphertexts.
+    ///
+    /// See [`ClientKey::new_compression_decompression_keys`](`crate::shortint::ClientKey`) for
+    /// usage.
+    pub fn unpack(&self, packed: &CompressedCiphertextList, index: usize) -> Option<Ciphertext> {
+        let fft = Fft::new(self.blind_rotate_key.polynomial_size());
+
+        self.unpack_with_fft(packed, index, fft.as_view())
+    }
+
+    /// Recover the ciphertext stored at the given index of a [`CompressedCiphertextList`], using
+    /// an FFT plan built beforehand for the polynomial size of the blind rotation key.
+    ///
+    /// Returns [`None`] if the index is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the FFT plan does not match the polynomial size of the blind rotation key.
+    pub fn unpack_with_fft(
+        &self,
+        packed: &CompressedCiphertextList,
+        index: usize,
+        fft: FftView<'_>,
+    ) -> Option<Ciphertext> {
+        assert_eq!(
+            fft.polynomial_size(),
+            self.blind_rotate_key.polynomial_size(),
+            "The FFT plan does not match the polynomial size of the blind rotation key"
+        );
+
+        if index >= packed.count {
+            return None;
+        }
+
+        assert_eq!(
+            packed.lwe_per_glwe, self.lwe_per_glwe,
+            "The list was not packed with the parameters of this decompression key"
+        );
+
+        let fourier_bsk = &self.blind_rotate_key;
+
+        let glwe_index = index / self.lwe_per_glwe.0;
+        let index_in_glwe = index % self.lwe_per_glwe.0;
+
+        let glwe_ciphertext = packed.modulus_switched_glwe_ciphertext_list[glwe_index].extract();
+
+        let mut intermediate_lwe_ciphertext = LweCiphertext::new(
+            0,
+            glwe_ciphertext
+                .glwe_size()
+                .to_glwe_dimension()
+                .to_equivalent_lwe_dimension(glwe_ciphertext.polynomial_size())
+                .to_lwe_size(),
+            packed.ciphertext_modulus,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &glwe_ciphertext,
+            &mut intermediate_lwe_ciphertext,
+            MonomialDegree(index_in_glwe),
+        );
+
+        let message_modulus = packed.message_modulus;
+
+        let mut accumulator = GlweCiphertext::new(
+            0,
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            packed.ciphertext_modulus,
+        );
+
+        fill_accumulator_with_moduli(
+            &mut accumulator,
+            message_modulus,
+            packed.carry_modulus,
+            |x| x % message_modulus.0 as u64,
+        );
+
+        let mut output_lwe = LweCiphertext::new(
+            0,
+            fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            packed.ciphertext_modulus,
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let buffers = engine.get_computation_buffers();
+
+            buffers.resize(
+                programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                    fourier_bsk.glwe_size(),
+                    fourier_bsk.polynomial_size(),
+                    fft,
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            );
+            let stack = buffers.stack();
+
+            programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                &intermediate_lwe_ciphertext,
+                &mut output_lwe,
+                &accumulator,
+                fourier_bsk,
+                fft,
+                stack,
+            );
+        });
+
+        Some(Ciphertext::new(
+            output_lwe,
+            Degree(message_modulus.0 - 1),
+            NoiseLevel::NOMINAL,
+            message_modulus,
+            packed.carry_modulus,
+            packed.pbs_order,
+        ))
+    }
+}
@@ -0,0 +1,49 @@
+//! Module with the definition of the compression of lists of shortint ciphertexts.
+//!
+//! Many [`Ciphertext`](`crate::shortint::Ciphertext`)s are packed in a few GLWE ciphertexts using a
+//! packing keyswitch, the GLWE ciphertexts are then modulus switched to a small storage modulus to
+//! further reduce their size. Decompressing a ciphertext is done on the server side by extracting
+//! the corresponding LWE sample and bootstrapping it back to the usual shortint parameters.
+
+mod compression;
+mod private_key;
+mod server_keys;
+
+#[cfg(test)]
+mod test;
+
+pub use private_key::CompressionPrivateKeys;
+pub use server_keys::{CompressionKey, DecompressionKey};
+
+use crate::core_crypto::entities::CompressedModulusSwitchedGlweCiphertext;
+use crate::core_crypto::prelude::LweCiphertextCount;
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::PBSOrder;
+use serde::{Deserialize, Serialize};
+
+/// A list of shortint ciphertexts packed in modulus switched GLWE ciphertexts.
+///
+/// It is created using a [`CompressionKey`] and the ciphertexts it contains are recovered using a
+/// [`DecompressionKey`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    pub modulus_switched_glwe_ciphertext_list: Vec<CompressedModulusSwitchedGlweCiphertext<u64>>,
+    pub ciphertext_modulus: CiphertextModulus,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub pbs_order: PBSOrder,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub count: usize,
+}
+
+impl CompressedCiphertextList {
+    /// Return the number of ciphertexts stored in the list.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Return `true` if the list does not contain any ciphertext.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
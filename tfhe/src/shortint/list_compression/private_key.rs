@@ -0,0 +1,65 @@
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_binary_glwe_secret_key, GlweSecretKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::CompressionParameters;
+use crate::shortint::{ClientKey, PBSOrder};
+use serde::{Deserialize, Serialize};
+
+/// The secret key used to encrypt the GLWE ciphertexts of a
+/// [`CompressedCiphertextList`](`super::CompressedCiphertextList`), which must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    pub post_packing_ks_key: GlweSecretKeyOwned<u64>,
+    pub params: CompressionParameters,
+}
+
+impl ClientKey {
+    /// Generate the private key used by the compression of ciphertext lists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of the client key do not use the
+    /// [`KeyswitchBootstrap`](`PBSOrder::KeyswitchBootstrap`) order, or if more LWE ciphertexts
+    /// per GLWE are requested than the GLWE polynomial size allows.
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compression_private_key(self, params)
+        })
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compression_private_key(
+        &mut self,
+        cks: &ClientKey,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        assert_eq!(
+            PBSOrder::from(cks.parameters.encryption_key_choice()),
+            PBSOrder::KeyswitchBootstrap,
+            "Compression is only compatible with the KeyswitchBootstrap PBS order"
+        );
+
+        assert!(
+            params.lwe_per_glwe.0 <= params.packing_ks_polynomial_size.0,
+            "Cannot pack more than polynomial_size ({}) LWE ciphertexts per GLWE, got {}",
+            params.packing_ks_polynomial_size.0,
+            params.lwe_per_glwe.0
+        );
+
+        let post_packing_ks_key = allocate_and_generate_new_binary_glwe_secret_key(
+            params.packing_ks_glwe_dimension,
+            params.packing_ks_polynomial_size,
+            &mut self.secret_generator,
+        );
+
+        CompressionPrivateKeys {
+            post_packing_ks_key,
+            params,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use super::CompressionPrivateKeys;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_lwe_packing_keyswitch_key,
+    par_allocate_and_generate_new_lwe_bootstrap_key,
+    par_convert_standard_lwe_bootstrap_key_to_fourier, CiphertextModulusLog,
+    FourierLweBootstrapKey, FourierLweBootstrapKeyOwned, LweCiphertextCount,
+    LwePackingKeyswitchKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Serialize};
+
+/// The server key used to pack shortint ciphertexts in a
+/// [`CompressedCiphertextList`](`super::CompressedCiphertextList`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    pub packing_key_switching_key: LwePackingKeyswitchKeyOwned<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+}
+
+/// The server key used to recover shortint ciphertexts from a
+/// [`CompressedCiphertextList`](`super::CompressedCiphertextList`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    pub blind_rotate_key: FourierLweBootstrapKeyOwned,
+    pub lwe_per_glwe: LweCiphertextCount,
+}
+
+impl ClientKey {
+    /// Generate the [`CompressionKey`] and the [`DecompressionKey`] associated to the given
+    /// [`CompressionPrivateKeys`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_compression_key =
+    ///     cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let (compression_key, decompression_key) =
+    ///     cks.new_compression_decompression_keys(&private_compression_key);
+    ///
+    /// let ct1 = cks.encrypt(3);
+    /// let ct2 = cks.encrypt(1);
+    ///
+    /// let compressed = compression_key.compress_ciphertexts_into_list(&[ct1, ct2]);
+    ///
+    /// let decompressed = decompression_key.unpack(&compressed, 1).unwrap();
+    /// assert_eq!(cks.decrypt(&decompressed), 1);
+    /// ```
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let params = &private_compression_key.params;
+
+        let (packing_key_switching_key, bootstrap_key) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let packing_key_switching_key = allocate_and_generate_new_lwe_packing_keyswitch_key(
+                    &self.large_lwe_secret_key,
+                    &private_compression_key.post_packing_ks_key,
+                    params.packing_ks_base_log,
+                    params.packing_ks_level,
+                    params.packing_ks_key_noise_distribution,
+                    self.parameters.ciphertext_modulus(),
+                    &mut engine.encryption_generator,
+                );
+
+                let bootstrap_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+                    &private_compression_key
+                        .post_packing_ks_key
+                        .as_lwe_secret_key(),
+                    &self.glwe_secret_key,
+                    params.br_base_log,
+                    params.br_level,
+                    self.parameters.glwe_modular_std_dev(),
+                    self.parameters.ciphertext_modulus(),
+                    &mut engine.encryption_generator,
+                );
+
+                (packing_key_switching_key, bootstrap_key)
+            });
+
+        // Creation of the bootstrapping key in the Fourier domain
+        let mut blind_rotate_key = FourierLweBootstrapKey::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+
+        // Conversion to fourier domain
+        par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut blind_rotate_key);
+
+        (
+            CompressionKey {
+                packing_key_switching_key,
+                lwe_per_glwe: params.lwe_per_glwe,
+                storage_log_modulus: params.storage_log_modulus,
+            },
+            DecompressionKey {
+                blind_rotate_key,
+                lwe_per_glwe: params.lwe_per_glwe,
+            },
+        )
+    }
+}
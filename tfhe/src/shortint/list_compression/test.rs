@@ -0,0 +1,40 @@
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+#[test]
+fn test_packing_and_unpacking_ci_run_filter() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_compression_key =
+        cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let (compression_key, decompression_key) =
+        cks.new_compression_decompression_keys(&private_compression_key);
+
+    let lwe_per_glwe = COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS.lwe_per_glwe.0;
+
+    // Test a single ciphertext, a full GLWE and a last partially filled GLWE
+    for number_to_pack in [1, lwe_per_glwe, lwe_per_glwe + 3] {
+        let cleartexts: Vec<u64> = (0..number_to_pack as u64).map(|i| i % 4).collect();
+
+        let cts: Vec<_> = cleartexts.iter().map(|&msg| cks.encrypt(msg)).collect();
+
+        let packed = compression_key.compress_ciphertexts_into_list(&cts);
+
+        assert_eq!(packed.len(), number_to_pack);
+
+        for (i, cleartext) in cleartexts.iter().enumerate() {
+            let unpacked = decompression_key.unpack(&packed, i).unwrap();
+
+            assert_eq!(cks.decrypt_message_and_carry(&unpacked), *cleartext);
+
+            // The output can be used in further computations
+            let sum = sks.unchecked_add(&unpacked, &unpacked);
+            assert_eq!(cks.decrypt(&sum), (2 * cleartext) % 4);
+        }
+
+        assert!(decompression_key.unpack(&packed, number_to_pack).is_none());
+    }
+}
@@ -49,22 +49,30 @@
 //! ```
 pub mod ciphertext;
 pub mod client_key;
+pub mod decryption_compression;
 pub mod engine;
 pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod list_compression;
+pub mod multi_party_key_generation;
 pub mod parameters;
+pub mod pbs128;
 pub mod prelude;
 pub mod public_key;
 pub mod server_key;
+pub mod threshold_decryption;
 pub mod wopbs;
+#[cfg(feature = "zk-pok-experimental")]
+pub mod zk;
 
 pub use ciphertext::{Ciphertext, CompressedCiphertext, PBSOrder};
 pub use client_key::ClientKey;
 pub use key_switching_key::KeySwitchingKey;
 pub use parameters::{
-    CarryModulus, CiphertextModulus, ClassicPBSParameters, EncryptionKeyChoice, MessageModulus,
-    MultiBitPBSParameters, PBSParameters, ShortintParameterSet, WopbsParameters,
+    CarryModulus, CiphertextModulus, ClassicPBSParameters, EncryptionKeyChoice,
+    InsecureParametersError, MessageModulus, MultiBitPBSParameters, PBSParameters,
+    ShortintParameterSet, WopbsParameters,
 };
 pub use public_key::{
     CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey,
@@ -111,6 +119,7 @@ where
             carry_modulus: wopbs_params.carry_modulus,
             ciphertext_modulus: wopbs_params.ciphertext_modulus,
             encryption_key_choice: wopbs_params.encryption_key_choice,
+            secret_key_distribution: parameters::SecretKeyDistribution::Binary,
         };
 
         ShortintParameterSet::try_new_pbs_and_wopbs_param_set((pbs_params, wopbs_params)).unwrap()
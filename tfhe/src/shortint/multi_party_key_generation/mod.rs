@@ -0,0 +1,551 @@
+//! Module with the distributed generation of shortint public and evaluation keys.
+//!
+//! Several parties, each holding a [`MultiPartyKeyGenerator`], jointly generate a
+//! [`CompactPublicKey`] and a [`ServerKey`] without any of them learning the corresponding secret
+//! keys. Each party keeps a [`ClientKeyShare`] allowing to take part in the threshold decryption
+//! of the ciphertexts, see [`threshold_decryption`](`crate::shortint::threshold_decryption`).
+//!
+//! The generation takes two rounds, all the messages exchanged by the parties are serializable:
+//!
+//! 1. each party sends a [`RoundOneMessage`] computed with [`MultiPartyKeyGenerator::round_one`],
+//!    the messages are combined with [`aggregate_round_one_messages`] into a [`RoundOneOutput`]
+//!    holding the [`CompactPublicKey`];
+//! 2. each party sends a [`RoundTwoMessage`] computed from the [`RoundOneOutput`] with
+//!    [`MultiPartyKeyGenerator::round_two`], the messages are combined with
+//!    [`aggregate_round_two_messages`] into the [`ServerKey`].
+//!
+//! The aggregations do not require any secret and can be run by any party or by the server.
+//!
+//! See [`multi_party_key_generation`](`crate::core_crypto::algorithms::multi_party_key_generation`)
+//! for the underlying primitives.
+//!
+//! # Security
+//!
+//! Each party samples full size secret key shares: a GLWE secret key and an LWE secret key of the
+//! dimensions given by the parameters. The joint GLWE secret key is the sum of the GLWE shares and
+//! the joint LWE secret key, used after the keyswitch, is the concatenation of the $P$ LWE shares,
+//! which amounts to summing them and repeating the ciphertext mask once per party.
+//!
+//! The keys are secure as long as at least one party is honest: a coalition of up to $P - 1$
+//! parties knows its own shares and noise contributions, but the share of the honest party is a
+//! full dimension secret key encrypted with at least the noise of the parameters, so recovering
+//! the joint keys is as hard as breaking a key generated by a single party with these parameters.
+//!
+//! The joint LWE secret key has dimension $P \cdot n$ and the noise of the joint keys is the sum of
+//! the noise of the contributions, so the keys have more noise amplification than the keys of a
+//! single [`ClientKey`](`crate::shortint::ClientKey`) and the PBS is $P$ times longer, the
+//! parameters must account for it when choosing the number of parties.
+
+#[cfg(test)]
+mod test;
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{
+    ActivatedRandomGenerator, CompressionSeed, Seed, Seeder,
+};
+use crate::core_crypto::entities::*;
+use crate::core_crypto::prelude::LweDimension;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{
+    EncryptionKeyChoice, PBSParameters, SecretSharingScheme, ShortintParameterSet,
+    ThresholdPartyCount, ThresholdPartyIndex,
+};
+use crate::shortint::server_key::{MaxDegree, ShortintBootstrappingKey};
+use crate::shortint::threshold_decryption::ClientKeyShare;
+use crate::shortint::{CompactPublicKey, PBSOrder, ServerKey};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// The state of one party of a distributed key generation.
+///
+/// It holds the secret key shares of the party and **MUST NOT** be sent to other parties or to
+/// the server.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MultiPartyKeyGenerator {
+    party_index: ThresholdPartyIndex,
+    party_count: ThresholdPartyCount,
+    glwe_secret_key_share: GlweSecretKeyOwned<u64>,
+    lwe_secret_key_share: LweSecretKeyOwned<u64>,
+    parameters: ShortintParameterSet,
+    common_reference_seed: CompressionSeed,
+}
+
+/// The message sent by one party at the end of the first round of the key generation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoundOneMessage {
+    party_index: ThresholdPartyIndex,
+    party_count: ThresholdPartyCount,
+    compact_public_key: SeededLweCompactPublicKeyOwned<u64>,
+    key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    glwe_encryption_of_zero: SeededGlweCiphertextOwned<u64>,
+    parameters: ShortintParameterSet,
+}
+
+impl RoundOneMessage {
+    /// Return the index of the party which sent the message.
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.party_index
+    }
+}
+
+/// The public result of the first round of the key generation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoundOneOutput {
+    compact_public_key: CompactPublicKey,
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    glwe_encryption_of_zero: GlweCiphertextOwned<u64>,
+    party_count: ThresholdPartyCount,
+}
+
+impl RoundOneOutput {
+    /// Return the [`CompactPublicKey`] of the joint secret key.
+    pub fn compact_public_key(&self) -> &CompactPublicKey {
+        &self.compact_public_key
+    }
+}
+
+/// The message sent by one party at the end of the second round of the key generation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundTwoMessage {
+    party_index: ThresholdPartyIndex,
+    bootstrapping_key: LweBootstrapKeyOwned<u64>,
+}
+
+impl RoundTwoMessage {
+    /// Return the index of the party which sent the message.
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.party_index
+    }
+}
+
+impl MultiPartyKeyGenerator {
+    /// Create the state of the party with index `party_index` and sample its secret key shares.
+    ///
+    /// All the parties must use the same `parameters` and `common_reference_seed`, the seed must
+    /// be sampled uniformly at random, for example by a coin tossing protocol between the parties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are not classic PBS parameters with the
+    /// [`EncryptionKeyChoice::Big`] key choice and a native modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::shortint::multi_party_key_generation::{
+    ///     aggregate_round_one_messages, aggregate_round_two_messages, MultiPartyKeyGenerator,
+    /// };
+    /// use tfhe::shortint::parameters::{
+    ///     StandardDev, ThresholdPartyCount, ThresholdPartyIndex, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::threshold_decryption::combine_partial_decryptions;
+    ///
+    /// let party_count = ThresholdPartyCount(2);
+    /// let common_reference_seed = Seed(0x5eed);
+    ///
+    /// let parties: Vec<_> = (1..=party_count.0)
+    ///     .map(|party_index| {
+    ///         MultiPartyKeyGenerator::new(
+    ///             ThresholdPartyIndex(party_index),
+    ///             party_count,
+    ///             PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    ///             common_reference_seed,
+    ///         )
+    ///     })
+    ///     .collect();
+    ///
+    /// let round_one_messages: Vec<_> = parties.iter().map(|party| party.round_one()).collect();
+    /// let round_one_output = aggregate_round_one_messages(&round_one_messages);
+    ///
+    /// let round_two_messages: Vec<_> = parties
+    ///     .iter()
+    ///     .map(|party| party.round_two(&round_one_output))
+    ///     .collect();
+    /// let sks = aggregate_round_two_messages(&round_one_output, &round_two_messages);
+    ///
+    /// let ct = round_one_output.compact_public_key().encrypt(1);
+    /// let lookup_table = sks.generate_lookup_table(|x| (x * 3) % 4);
+    /// let ct = sks.apply_lookup_table(&ct, &lookup_table);
+    ///
+    /// // The noise of a PBS output is too large for a statistically hiding flooding with these
+    /// // parameters, see tfhe::shortint::threshold_decryption for the choice of the flooding noise
+    /// let flooding_noise = StandardDev(2.0f64.powi(-30));
+    /// let partial_decryptions: Vec<_> = parties
+    ///     .iter()
+    ///     .map(|party| party.client_key_share().partial_decrypt(&ct, flooding_noise))
+    ///     .collect();
+    ///
+    /// let dec = combine_partial_decryptions(&ct, &partial_decryptions);
+    /// assert_eq!(dec, 3);
+    /// ```
+    pub fn new<P>(
+        party_index: ThresholdPartyIndex,
+        party_count: ThresholdPartyCount,
+        parameters: P,
+        common_reference_seed: Seed,
+    ) -> Self
+    where
+        P: TryInto<ShortintParameterSet>,
+        <P as TryInto<ShortintParameterSet>>::Error: Debug,
+    {
+        let parameters: ShortintParameterSet = parameters.try_into().unwrap();
+
+        assert!(
+            party_index.0 >= 1 && party_index.0 <= party_count.0,
+            "Invalid party index {party_index:?} for {party_count:?}, \
+            parties are indexed from 1 to the party count"
+        );
+        assert!(
+            matches!(parameters.pbs_parameters(), Some(PBSParameters::PBS(_))),
+            "Multi party key generation only supports classic PBS parameters"
+        );
+        assert!(
+            parameters.encryption_key_choice() == EncryptionKeyChoice::Big,
+            "Multi party key generation only supports the EncryptionKeyChoice::Big key choice"
+        );
+        assert!(
+            parameters.ciphertext_modulus().is_native_modulus(),
+            "Multi party key generation only supports native moduli"
+        );
+
+        let (glwe_secret_key_share, lwe_secret_key_share) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let glwe_secret_key_share = allocate_and_generate_new_binary_glwe_secret_key(
+                    parameters.glwe_dimension(),
+                    parameters.polynomial_size(),
+                    &mut engine.secret_generator,
+                );
+                let lwe_secret_key_share = allocate_and_generate_new_binary_lwe_secret_key(
+                    parameters.lwe_dimension(),
+                    &mut engine.secret_generator,
+                );
+                (glwe_secret_key_share, lwe_secret_key_share)
+            });
+
+        Self {
+            party_index,
+            party_count,
+            glwe_secret_key_share,
+            lwe_secret_key_share,
+            parameters,
+            common_reference_seed: common_reference_seed.into(),
+        }
+    }
+
+    /// Return the index of the party.
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.party_index
+    }
+
+    /// Return the dimension of the joint LWE secret key, the concatenation of the LWE secret key
+    /// shares of all the parties.
+    pub fn joint_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.parameters.lwe_dimension().0 * self.party_count.0)
+    }
+
+    /// Return the share of the joint client key held by the party, used to take part in the
+    /// threshold decryption of ciphertexts encrypted under the generated keys.
+    ///
+    /// The joint secret keys are the sum of the shares of all parties, the returned share uses
+    /// the [`SecretSharingScheme::Additive`] scheme.
+    pub fn client_key_share(&self) -> ClientKeyShare {
+        let sharing_scheme = SecretSharingScheme::Additive {
+            party_count: self.party_count,
+        };
+        let ciphertext_modulus = self.parameters.ciphertext_modulus();
+
+        // The joint LWE secret key is the concatenation of the shares, the share of the party is
+        // its own chunk padded with zeros
+        let lwe_share_dimension = self.lwe_secret_key_share.lwe_dimension().0;
+        let mut small_lwe_secret_key_share = vec![0u64; self.joint_lwe_dimension().0];
+        small_lwe_secret_key_share
+            .chunks_exact_mut(lwe_share_dimension)
+            .nth(self.party_index.0 - 1)
+            .unwrap()
+            .copy_from_slice(self.lwe_secret_key_share.as_ref());
+
+        ClientKeyShare {
+            large_lwe_secret_key_share: LweSecretKeyShare::from_container(
+                self.glwe_secret_key_share.as_ref().to_vec(),
+                self.party_index,
+                sharing_scheme,
+                ciphertext_modulus,
+            ),
+            small_lwe_secret_key_share: LweSecretKeyShare::from_container(
+                small_lwe_secret_key_share,
+                self.party_index,
+                sharing_scheme,
+                ciphertext_modulus,
+            ),
+            parameters: self.parameters,
+        }
+    }
+
+    /// Derive the compression seeds of the compact public key, the keyswitch key and the GLWE
+    /// encryption of zero from the common reference seed, so that they use distinct masks.
+    fn compression_seeds(&self) -> (CompressionSeed, CompressionSeed, CompressionSeed) {
+        let mut seeder =
+            DeterministicSeeder::<ActivatedRandomGenerator>::new(self.common_reference_seed.seed);
+        let compact_public_key_seed = seeder.seed().into();
+        let key_switching_key_seed = seeder.seed().into();
+        let glwe_encryption_of_zero_seed = seeder.seed().into();
+        (
+            compact_public_key_seed,
+            key_switching_key_seed,
+            glwe_encryption_of_zero_seed,
+        )
+    }
+
+    /// Compute the message of the party for the first round of the key generation.
+    pub fn round_one(&self) -> RoundOneMessage {
+        let params = &self.parameters;
+        let (compact_public_key_seed, key_switching_key_seed, glwe_encryption_of_zero_seed) =
+            self.compression_seeds();
+        let large_lwe_secret_key_share = self.glwe_secret_key_share.as_lwe_secret_key();
+
+        let mut compact_public_key = SeededLweCompactPublicKeyOwned::new(
+            0u64,
+            large_lwe_secret_key_share.lwe_dimension(),
+            compact_public_key_seed,
+            params.ciphertext_modulus(),
+        );
+
+        let mut key_switching_key = SeededLweKeyswitchKeyOwned::new(
+            0u64,
+            params.ks_base_log(),
+            params.ks_level(),
+            large_lwe_secret_key_share.lwe_dimension(),
+            self.joint_lwe_dimension(),
+            key_switching_key_seed,
+            params.ciphertext_modulus(),
+        );
+
+        let mut glwe_encryption_of_zero = SeededGlweCiphertextOwned::new(
+            0u64,
+            params.glwe_dimension().to_glwe_size(),
+            params.polynomial_size(),
+            glwe_encryption_of_zero_seed,
+            params.ciphertext_modulus(),
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            generate_seeded_lwe_compact_public_key(
+                &large_lwe_secret_key_share,
+                &mut compact_public_key,
+                params.glwe_modular_std_dev(),
+                &mut engine.seeder,
+            );
+
+            generate_multi_party_seeded_lwe_keyswitch_key_contribution(
+                &large_lwe_secret_key_share,
+                &self.lwe_secret_key_share,
+                self.party_index,
+                self.party_count,
+                &mut key_switching_key,
+                params.lwe_modular_std_dev(),
+                &mut engine.seeder,
+            );
+
+            generate_multi_party_seeded_glwe_encryption_of_zero_contribution(
+                &self.glwe_secret_key_share,
+                &mut glwe_encryption_of_zero,
+                params.glwe_modular_std_dev(),
+                &mut engine.seeder,
+            );
+        });
+
+        RoundOneMessage {
+            party_index: self.party_index,
+            party_count: self.party_count,
+            compact_public_key,
+            key_switching_key,
+            glwe_encryption_of_zero,
+            parameters: self.parameters,
+        }
+    }
+
+    /// Compute the message of the party for the second round of the key generation.
+    pub fn round_two(&self, round_one_output: &RoundOneOutput) -> RoundTwoMessage {
+        let params = &self.parameters;
+        assert_eq!(
+            round_one_output.compact_public_key.parameters, self.parameters,
+            "The RoundOneOutput was not generated with the parameters of the party"
+        );
+        assert_eq!(
+            round_one_output.party_count, self.party_count,
+            "The RoundOneOutput was not generated with the party count of the party"
+        );
+
+        let mut bootstrapping_key = LweBootstrapKeyOwned::new(
+            0u64,
+            params.glwe_dimension().to_glwe_size(),
+            params.polynomial_size(),
+            params.pbs_base_log(),
+            params.pbs_level(),
+            self.lwe_secret_key_share.lwe_dimension(),
+            params.ciphertext_modulus(),
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            generate_multi_party_lwe_bootstrap_key_contribution(
+                &self.lwe_secret_key_share,
+                &round_one_output.glwe_encryption_of_zero,
+                &mut bootstrapping_key,
+                params.glwe_modular_std_dev(),
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            );
+        });
+
+        RoundTwoMessage {
+            party_index: self.party_index,
+            bootstrapping_key,
+        }
+    }
+}
+
+/// Check that the messages come from all the parties exactly once and return them ordered by party
+/// index.
+fn sort_messages_by_party_index<T>(
+    messages: &[T],
+    party_index: impl Fn(&T) -> ThresholdPartyIndex,
+) -> Vec<&T> {
+    let mut sorted_messages: Vec<_> = messages.iter().collect();
+    sorted_messages.sort_by_key(|message| party_index(message).0);
+
+    assert!(
+        sorted_messages
+            .iter()
+            .enumerate()
+            .all(|(offset, message)| party_index(message).0 == offset + 1),
+        "Expected exactly one message from each party"
+    );
+
+    sorted_messages
+}
+
+/// Aggregate the [`RoundOneMessage`]s of all the parties.
+///
+/// # Panics
+///
+/// Panics if the messages do not come from all the parties exactly once or were not generated with
+/// the same parameters and common reference seed.
+pub fn aggregate_round_one_messages(messages: &[RoundOneMessage]) -> RoundOneOutput {
+    let messages = sort_messages_by_party_index(messages, RoundOneMessage::party_index);
+    let parameters = messages[0].parameters;
+    let party_count = messages[0].party_count;
+    assert!(
+        messages
+            .iter()
+            .all(|message| message.parameters == parameters && message.party_count == party_count),
+        "All RoundOneMessages must be generated with the same parameters and party count"
+    );
+    assert_eq!(
+        messages.len(),
+        party_count.0,
+        "Expected exactly one message from each party"
+    );
+
+    let compact_public_keys: Vec<_> = messages
+        .iter()
+        .map(|message| {
+            SeededLweCompactPublicKey::from_container(
+                message.compact_public_key.as_ref(),
+                message.compact_public_key.compression_seed(),
+                message.compact_public_key.ciphertext_modulus(),
+            )
+        })
+        .collect();
+    let key_switching_keys: Vec<_> = messages
+        .iter()
+        .map(|message| message.key_switching_key.as_view())
+        .collect();
+    let glwe_encryptions_of_zero: Vec<_> = messages
+        .iter()
+        .map(|message| message.glwe_encryption_of_zero.as_view())
+        .collect();
+
+    let compact_public_key =
+        allocate_and_aggregate_seeded_lwe_compact_public_key_contributions(&compact_public_keys)
+            .decompress_into_lwe_compact_public_key();
+    let key_switching_key =
+        allocate_and_aggregate_seeded_lwe_keyswitch_key_contributions(&key_switching_keys)
+            .decompress_into_lwe_keyswitch_key();
+    let glwe_encryption_of_zero =
+        allocate_and_aggregate_seeded_glwe_ciphertext_contributions(&glwe_encryptions_of_zero)
+            .decompress_into_glwe_ciphertext();
+
+    RoundOneOutput {
+        compact_public_key: CompactPublicKey {
+            key: compact_public_key,
+            parameters,
+            pbs_order: PBSOrder::KeyswitchBootstrap,
+        },
+        key_switching_key,
+        glwe_encryption_of_zero,
+        party_count,
+    }
+}
+
+/// Aggregate the [`RoundTwoMessage`]s of all the parties into the [`ServerKey`] of the joint
+/// secret key.
+///
+/// # Panics
+///
+/// Panics if the messages do not come from all the parties exactly once or were not generated with
+/// the same parameters.
+pub fn aggregate_round_two_messages(
+    round_one_output: &RoundOneOutput,
+    messages: &[RoundTwoMessage],
+) -> ServerKey {
+    let messages = sort_messages_by_party_index(messages, RoundTwoMessage::party_index);
+    assert_eq!(
+        messages.len(),
+        round_one_output.party_count.0,
+        "Expected exactly one message from each party"
+    );
+
+    let parameters = round_one_output.compact_public_key.parameters;
+
+    let bootstrapping_keys: Vec<_> = messages
+        .iter()
+        .map(|message| message.bootstrapping_key.as_view())
+        .collect();
+    let bootstrap_key = allocate_and_aggregate_lwe_bootstrap_key_contributions(&bootstrapping_keys);
+
+    assert_eq!(
+        bootstrap_key.input_lwe_dimension(),
+        round_one_output
+            .key_switching_key
+            .output_key_lwe_dimension(),
+        "The RoundTwoMessages were not generated with the parameters of the RoundOneOutput"
+    );
+
+    // Creation of the bootstrapping key in the Fourier domain
+    let mut fourier_bsk = FourierLweBootstrapKey::new(
+        bootstrap_key.input_lwe_dimension(),
+        bootstrap_key.glwe_size(),
+        bootstrap_key.polynomial_size(),
+        bootstrap_key.decomposition_base_log(),
+        bootstrap_key.decomposition_level_count(),
+    );
+
+    // Conversion to fourier domain
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut fourier_bsk);
+
+    // Plaintext Max Value
+    let max_value = parameters.message_modulus().0 * parameters.carry_modulus().0 - 1;
+
+    ServerKey {
+        key_switching_key: round_one_output.key_switching_key.clone(),
+        key_switching_key_32: None,
+        bootstrapping_key: ShortintBootstrappingKey::Classic(fourier_bsk),
+        message_modulus: parameters.message_modulus(),
+        carry_modulus: parameters.carry_modulus(),
+        max_degree: MaxDegree(max_value),
+        ciphertext_modulus: parameters.ciphertext_modulus(),
+        pbs_order: PBSOrder::KeyswitchBootstrap,
+    }
+}
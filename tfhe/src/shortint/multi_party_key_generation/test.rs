@@ -0,0 +1,164 @@
+use super::*;
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+use crate::shortint::parameters::{StandardDev, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+use crate::shortint::threshold_decryption::combine_partial_decryptions;
+use crate::shortint::ClientKey;
+
+const FLOODING_NOISE: StandardDev = StandardDev(0.0000000009313225746154785);
+
+fn generate_parties(party_count: ThresholdPartyCount) -> Vec<MultiPartyKeyGenerator> {
+    let common_reference_seed =
+        ShortintEngine::with_thread_local_mut(|engine| engine.seeder.seed());
+
+    (1..=party_count.0)
+        .map(|party_index| {
+            MultiPartyKeyGenerator::new(
+                ThresholdPartyIndex(party_index),
+                party_count,
+                PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+                common_reference_seed,
+            )
+        })
+        .collect()
+}
+
+// Rebuild the client key no party knows, for testing purposes only
+fn joint_client_key(parties: &[MultiPartyKeyGenerator]) -> ClientKey {
+    let parameters = parties[0].parameters;
+    let mut glwe_secret_key = GlweSecretKey::new_empty_key(
+        0u64,
+        parameters.glwe_dimension(),
+        parameters.polynomial_size(),
+    );
+    for party in parties {
+        slice_wrapping_add_assign(
+            glwe_secret_key.as_mut(),
+            party.glwe_secret_key_share.as_ref(),
+        );
+    }
+
+    let small_lwe_secret_key = LweSecretKey::from_container(
+        parties
+            .iter()
+            .flat_map(|party| party.lwe_secret_key_share.as_ref().iter().copied())
+            .collect::<Vec<_>>(),
+    );
+
+    ClientKey {
+        large_lwe_secret_key: glwe_secret_key.clone().into_lwe_secret_key(),
+        glwe_secret_key,
+        small_lwe_secret_key,
+        parameters,
+    }
+}
+
+#[test]
+fn shortint_multi_party_key_generation() {
+    let party_count = ThresholdPartyCount(2);
+    let parties = generate_parties(party_count);
+
+    // Messages go through serialization as they would be sent over the network
+    let round_one_messages: Vec<RoundOneMessage> = parties
+        .iter()
+        .rev()
+        .map(|party| {
+            let serialized = bincode::serialize(&party.round_one()).unwrap();
+            bincode::deserialize(&serialized).unwrap()
+        })
+        .collect();
+    let round_one_output = aggregate_round_one_messages(&round_one_messages);
+
+    let round_two_messages: Vec<RoundTwoMessage> = parties
+        .iter()
+        .map(|party| {
+            let serialized = bincode::serialize(&party.round_two(&round_one_output)).unwrap();
+            bincode::deserialize(&serialized).unwrap()
+        })
+        .collect();
+    let sks = aggregate_round_two_messages(&round_one_output, &round_two_messages);
+
+    let cpk = round_one_output.compact_public_key();
+    let shares: Vec<_> = parties
+        .iter()
+        .map(MultiPartyKeyGenerator::client_key_share)
+        .collect();
+    let cks = joint_client_key(&parties);
+
+    let message_modulus = cks.parameters.message_modulus().0 as u64;
+    let lut = sks.generate_lookup_table(|x| (x * 3 + 1) % message_modulus);
+
+    for msg in 0..message_modulus {
+        let ct = cpk.encrypt(msg);
+        assert_eq!(cks.decrypt(&ct), msg);
+
+        let ct_pbs = sks.apply_lookup_table(&ct, &lut);
+        let ct_add = sks.add(&ct, &ct_pbs);
+
+        for (ct, expected) in [
+            (ct_pbs, (msg * 3 + 1) % message_modulus),
+            (ct_add, (msg * 4 + 1) % message_modulus),
+        ] {
+            assert_eq!(cks.decrypt(&ct), expected);
+
+            let partial_decryptions: Vec<_> = shares
+                .iter()
+                .map(|share| share.partial_decrypt(&ct, FLOODING_NOISE))
+                .collect();
+            assert_eq!(
+                combine_partial_decryptions(&ct, &partial_decryptions),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn shortint_multi_party_key_generation_shares_keep_the_key_dimension() {
+    let party_count = ThresholdPartyCount(3);
+    let parties = generate_parties(party_count);
+    let lwe_dimension = parties[0].parameters.lwe_dimension();
+
+    let round_one_messages: Vec<_> = parties.iter().map(|party| party.round_one()).collect();
+    let round_one_output = aggregate_round_one_messages(&round_one_messages);
+    let joint_lwe_dimension = round_one_output
+        .key_switching_key
+        .output_key_lwe_dimension();
+    assert_eq!(joint_lwe_dimension.0, lwe_dimension.0 * party_count.0);
+
+    for party in &parties {
+        // Each share is a full size key, only covering the chunk of the party in the joint key
+        assert_eq!(party.lwe_secret_key_share.lwe_dimension(), lwe_dimension);
+
+        let share = party.client_key_share();
+        let coordinates_of_other_parties: Vec<_> = share
+            .small_lwe_secret_key_share
+            .as_ref()
+            .chunks_exact(lwe_dimension.0)
+            .enumerate()
+            .filter(|(chunk_index, _)| *chunk_index != party.party_index().0 - 1)
+            .flat_map(|(_, chunk)| chunk.iter().copied())
+            .collect();
+        assert!(coordinates_of_other_parties
+            .iter()
+            .all(|&coordinate| coordinate == 0));
+    }
+
+    // A coalition of all the parties but one still misses a full size share of the joint key
+    let coalition_known_dimension: usize = parties[1..]
+        .iter()
+        .map(|party| party.lwe_secret_key_share.lwe_dimension().0)
+        .sum();
+    assert_eq!(
+        joint_lwe_dimension.0 - coalition_known_dimension,
+        lwe_dimension.0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Expected exactly one message from each party")]
+fn shortint_multi_party_key_generation_missing_party() {
+    let parties = generate_parties(ThresholdPartyCount(2));
+
+    let round_one_messages: Vec<_> = parties[..1].iter().map(|party| party.round_one()).collect();
+    let _ = aggregate_round_one_messages(&round_one_messages);
+}
@@ -0,0 +1,37 @@
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, StandardDev,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters used to shrink shortint ciphertexts into
+/// [`DecryptionCompressedCiphertext`](`crate::shortint::decryption_compression::DecryptionCompressedCiphertext`)s
+/// before sending them to the owner of the secret key.
+///
+/// * `lwe_dimension` - the dimension of the LWE secret key under which the compressed ciphertexts
+///   are decrypted.
+/// * `lwe_noise_distribution` - the standard deviation of the noise used when generating the
+///   keyswitch key to the decryption key.
+/// * `ks_level`/`ks_base_log` - decomposition parameters of the keyswitch to the decryption key.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecryptionCompressionParameters {
+    pub lwe_dimension: LweDimension,
+    pub lwe_noise_distribution: StandardDev,
+    pub ks_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+}
+
+/// Decryption compression parameters compatible with
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](`super::PARAM_MESSAGE_2_CARRY_2_KS_PBS`) and
+/// [`PARAM_MESSAGE_2_CARRY_2_PBS_KS`](`super::PARAM_MESSAGE_2_CARRY_2_PBS_KS`).
+///
+/// The decryption key has the dimension and noise of the small key of
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](`super::PARAM_MESSAGE_2_CARRY_2_KS_PBS`), the switch to
+/// 32 bits then adds a rounding noise far below the keyswitch one.
+pub const DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2: DecryptionCompressionParameters =
+    DecryptionCompressionParameters {
+        lwe_dimension: LweDimension(742),
+        lwe_noise_distribution: StandardDev(0.000007069849454709433),
+        ks_level: DecompositionLevelCount(5),
+        ks_base_log: DecompositionBaseLog(3),
+    };
@@ -0,0 +1,74 @@
+use crate::core_crypto::prelude::LweCiphertextParameters;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextConformanceParams, CiphertextModulus, DecompositionBaseLog,
+    DecompositionLevelCount, GlweDimension, LweDimension, MessageModulus, PolynomialSize,
+    StandardDev,
+};
+use crate::shortint::PBSOrder;
+
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters where the keyswitch outputs ciphertexts on `u32`.
+///
+/// Ciphertexts are encrypted under the big LWE key derived from the GLWE key, lookup tables are
+/// evaluated with a keyswitch to the small LWE key followed by a PBS back to the big key, as with
+/// the [`Big`](`super::EncryptionKeyChoice::Big`) encryption key choice of
+/// [`ClassicPBSParameters`](`super::ClassicPBSParameters`).
+///
+/// The keyswitch key and the keyswitched ciphertexts use the native $2^{32}$ modulus, which halves
+/// the size of the keyswitch key and speeds up the keyswitch. The ciphertext modulus must be the
+/// native one and the keyswitch decomposition must fit in 32 bits.
+#[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
+pub struct KeySwitch32PBSParameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub lwe_modular_std_dev: StandardDev,
+    pub glwe_modular_std_dev: StandardDev,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub ciphertext_modulus: CiphertextModulus,
+}
+
+impl KeySwitch32PBSParameters {
+    pub fn to_shortint_conformance_param(&self) -> CiphertextConformanceParams {
+        CiphertextConformanceParams {
+            ct_params: LweCiphertextParameters {
+                lwe_dim: self
+                    .glwe_dimension
+                    .to_equivalent_lwe_dimension(self.polynomial_size),
+                ct_modulus: self.ciphertext_modulus,
+            },
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            pbs_order: PBSOrder::KeyswitchBootstrap,
+            degree: Degree(self.message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
+        }
+    }
+}
+
+/// Parameters with 2 bits of message and 2 bits of carry per block, with a keyswitch to `u32`.
+///
+/// The dimensions and noise levels are the ones of
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](`super::PARAM_MESSAGE_2_CARRY_2_KS_PBS`), the small LWE
+/// noise being far above the $2^{-32}$ precision of the keyswitched ciphertexts.
+pub const PARAM_MESSAGE_2_CARRY_2_KS32_PBS: KeySwitch32PBSParameters = KeySwitch32PBSParameters {
+    lwe_dimension: LweDimension(742),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
+    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(3),
+    ks_level: DecompositionLevelCount(5),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+};
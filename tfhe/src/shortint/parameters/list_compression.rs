@@ -0,0 +1,53 @@
+use crate::core_crypto::commons::parameters::{CiphertextModulusLog, LweCiphertextCount};
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, StandardDev,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters used to pack many shortint ciphertexts into a
+/// [`CompressedCiphertextList`](`crate::shortint::list_compression::CompressedCiphertextList`).
+///
+/// * `br_level`/`br_base_log` - decomposition parameters of the blind rotation used during the
+///   decompression.
+/// * `packing_ks_level`/`packing_ks_base_log` - decomposition parameters of the packing keyswitch
+///   used during the compression.
+/// * `packing_ks_polynomial_size`/`packing_ks_glwe_dimension` - dimensions of the GLWE ciphertexts
+///   storing the packed LWE ciphertexts.
+/// * `lwe_per_glwe` - the number of LWE ciphertexts packed in a single GLWE ciphertext, cannot be
+///   greater than `packing_ks_polynomial_size`.
+/// * `storage_log_modulus` - the log2 of the modulus used to store the packed GLWE ciphertexts.
+/// * `packing_ks_key_noise_distribution` - the standard deviation of the noise used when generating
+///   the packing keyswitch key.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionParameters {
+    pub br_level: DecompositionLevelCount,
+    pub br_base_log: DecompositionBaseLog,
+    pub packing_ks_level: DecompositionLevelCount,
+    pub packing_ks_base_log: DecompositionBaseLog,
+    pub packing_ks_polynomial_size: PolynomialSize,
+    pub packing_ks_glwe_dimension: GlweDimension,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+    pub packing_ks_key_noise_distribution: StandardDev,
+}
+
+/// Compression parameters compatible with
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](`super::PARAM_MESSAGE_2_CARRY_2_KS_PBS`).
+///
+/// The storage modulus is chosen to match the modulus switch to $2N$ done by the blind rotation of
+/// the decompression, so that no additional noise is introduced by the latter.
+pub const COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS: CompressionParameters = CompressionParameters {
+    br_level: DecompositionLevelCount(1),
+    br_base_log: DecompositionBaseLog(23),
+    packing_ks_level: DecompositionLevelCount(4),
+    packing_ks_base_log: DecompositionBaseLog(4),
+    packing_ks_polynomial_size: PolynomialSize(256),
+    packing_ks_glwe_dimension: GlweDimension(4),
+    lwe_per_glwe: LweCiphertextCount(256),
+    storage_log_modulus: CiphertextModulusLog(12),
+    packing_ks_key_noise_distribution: StandardDev(0.00000004816736),
+};
+
+/// Alias of [`COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS`].
+pub const COMP_PARAM_MESSAGE_2_CARRY_2: CompressionParameters = COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
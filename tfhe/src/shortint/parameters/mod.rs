@@ -9,23 +9,42 @@ use crate::conformance::ListSizeConstraint;
 pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
 pub use crate::core_crypto::commons::parameters::{
     CiphertextModulus as CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
-    GlweDimension, LweBskGroupingFactor, LweDimension, PolynomialSize,
+    GlweDimension, LweBskGroupingFactor, LweDimension, PolynomialSize, SecretKeyDistribution,
+    SecretSharingScheme, ThresholdPartyCount, ThresholdPartyIndex,
 };
 use crate::core_crypto::prelude::{LweCiphertextListParameters, LweCiphertextParameters};
 use serde::{Deserialize, Serialize};
 
+pub mod decryption_compression;
+pub mod key_switch32;
 pub mod key_switching;
+pub mod list_compression;
 pub mod multi_bit;
+pub mod noise_estimation;
 pub mod parameters_compact_pk;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
+pub mod pbs128;
+pub mod security;
 
 pub use crate::core_crypto::commons::parameters::EncryptionKeyChoice;
+pub use decryption_compression::{
+    DecryptionCompressionParameters, DECRYPTION_COMP_PARAM_MESSAGE_2_CARRY_2,
+};
+pub use key_switch32::{KeySwitch32PBSParameters, PARAM_MESSAGE_2_CARRY_2_KS32_PBS};
 pub use key_switching::ShortintKeySwitchingParameters;
+pub use list_compression::{
+    CompressionParameters, COMP_PARAM_MESSAGE_2_CARRY_2, COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+};
 pub use multi_bit::*;
 pub use parameters_compact_pk::*;
 pub use parameters_wopbs::WopbsParameters;
+pub use pbs128::{
+    CiphertextModulus128, PBS128Parameters, PARAM_PBS128_MESSAGE_2_CARRY_2,
+    PARAM_PBS128_MESSAGE_3_CARRY_3, PARAM_PBS128_MESSAGE_4_CARRY_4,
+};
+pub use security::{InsecureParametersError, DEFAULT_SECURITY_BITS};
 
 use super::ciphertext::{Degree, NoiseLevel};
 use super::PBSOrder;
@@ -73,6 +92,15 @@ pub struct ClassicPBSParameters {
     pub carry_modulus: CarryModulus,
     pub ciphertext_modulus: CiphertextModulus,
     pub encryption_key_choice: EncryptionKeyChoice,
+    /// The distribution of the coefficients of the LWE and GLWE secret keys. Non binary
+    /// distributions are supported by the bootstrap through the binary expansion of the small
+    /// LWE secret key, see
+    /// [`crate::core_crypto::algorithms::generate_binary_expanded_lwe_secret_key`].
+    ///
+    /// Parameters serialized before the distribution was configurable deserialize to a binary
+    /// distribution.
+    #[serde(default)]
+    pub secret_key_distribution: SecretKeyDistribution,
 }
 
 impl ClassicPBSParameters {
@@ -98,6 +126,7 @@ impl ClassicPBSParameters {
         carry_modulus: CarryModulus,
         ciphertext_modulus: CiphertextModulus,
         encryption_key_choice: EncryptionKeyChoice,
+        secret_key_distribution: SecretKeyDistribution,
     ) -> Self {
         Self {
             lwe_dimension,
@@ -113,6 +142,7 @@ impl ClassicPBSParameters {
             carry_modulus,
             ciphertext_modulus,
             encryption_key_choice,
+            secret_key_distribution,
         }
     }
 
@@ -152,6 +182,7 @@ impl ClassicPBSParameters {
 pub enum PBSParameters {
     PBS(ClassicPBSParameters),
     MultiBitPBS(MultiBitPBSParameters),
+    KeySwitch32PBS(KeySwitch32PBSParameters),
 }
 
 /// Structure to store the expected properties of a ciphertext
@@ -211,83 +242,108 @@ impl From<MultiBitPBSParameters> for PBSParameters {
     }
 }
 
+impl From<KeySwitch32PBSParameters> for PBSParameters {
+    fn from(value: KeySwitch32PBSParameters) -> Self {
+        Self::KeySwitch32PBS(value)
+    }
+}
+
 impl PBSParameters {
     pub const fn lwe_dimension(&self) -> LweDimension {
         match self {
             Self::PBS(params) => params.lwe_dimension,
             Self::MultiBitPBS(params) => params.lwe_dimension,
+            Self::KeySwitch32PBS(params) => params.lwe_dimension,
         }
     }
     pub const fn glwe_dimension(&self) -> GlweDimension {
         match self {
             Self::PBS(params) => params.glwe_dimension,
             Self::MultiBitPBS(params) => params.glwe_dimension,
+            Self::KeySwitch32PBS(params) => params.glwe_dimension,
         }
     }
     pub const fn polynomial_size(&self) -> PolynomialSize {
         match self {
             Self::PBS(params) => params.polynomial_size,
             Self::MultiBitPBS(params) => params.polynomial_size,
+            Self::KeySwitch32PBS(params) => params.polynomial_size,
         }
     }
     pub const fn lwe_modular_std_dev(&self) -> StandardDev {
         match self {
             Self::PBS(params) => params.lwe_modular_std_dev,
             Self::MultiBitPBS(params) => params.lwe_modular_std_dev,
+            Self::KeySwitch32PBS(params) => params.lwe_modular_std_dev,
         }
     }
     pub const fn glwe_modular_std_dev(&self) -> StandardDev {
         match self {
             Self::PBS(params) => params.glwe_modular_std_dev,
             Self::MultiBitPBS(params) => params.glwe_modular_std_dev,
+            Self::KeySwitch32PBS(params) => params.glwe_modular_std_dev,
         }
     }
     pub const fn pbs_base_log(&self) -> DecompositionBaseLog {
         match self {
             Self::PBS(params) => params.pbs_base_log,
             Self::MultiBitPBS(params) => params.pbs_base_log,
+            Self::KeySwitch32PBS(params) => params.pbs_base_log,
         }
     }
     pub const fn pbs_level(&self) -> DecompositionLevelCount {
         match self {
             Self::PBS(params) => params.pbs_level,
             Self::MultiBitPBS(params) => params.pbs_level,
+            Self::KeySwitch32PBS(params) => params.pbs_level,
         }
     }
     pub const fn ks_base_log(&self) -> DecompositionBaseLog {
         match self {
             Self::PBS(params) => params.ks_base_log,
             Self::MultiBitPBS(params) => params.ks_base_log,
+            Self::KeySwitch32PBS(params) => params.ks_base_log,
         }
     }
     pub const fn ks_level(&self) -> DecompositionLevelCount {
         match self {
             Self::PBS(params) => params.ks_level,
             Self::MultiBitPBS(params) => params.ks_level,
+            Self::KeySwitch32PBS(params) => params.ks_level,
         }
     }
     pub const fn message_modulus(&self) -> MessageModulus {
         match self {
             Self::PBS(params) => params.message_modulus,
             Self::MultiBitPBS(params) => params.message_modulus,
+            Self::KeySwitch32PBS(params) => params.message_modulus,
         }
     }
     pub const fn carry_modulus(&self) -> CarryModulus {
         match self {
             Self::PBS(params) => params.carry_modulus,
             Self::MultiBitPBS(params) => params.carry_modulus,
+            Self::KeySwitch32PBS(params) => params.carry_modulus,
         }
     }
     pub const fn ciphertext_modulus(&self) -> CiphertextModulus {
         match self {
             Self::PBS(params) => params.ciphertext_modulus,
             Self::MultiBitPBS(params) => params.ciphertext_modulus,
+            Self::KeySwitch32PBS(params) => params.ciphertext_modulus,
         }
     }
     pub const fn encryption_key_choice(&self) -> EncryptionKeyChoice {
         match self {
             Self::PBS(params) => params.encryption_key_choice,
             Self::MultiBitPBS(params) => params.encryption_key_choice,
+            Self::KeySwitch32PBS(_) => EncryptionKeyChoice::Big,
+        }
+    }
+    pub const fn secret_key_distribution(&self) -> SecretKeyDistribution {
+        match self {
+            Self::PBS(params) => params.secret_key_distribution,
+            Self::MultiBitPBS(_) | Self::KeySwitch32PBS(_) => SecretKeyDistribution::Binary,
         }
     }
     pub const fn grouping_factor(&self) -> LweBskGroupingFactor {
@@ -296,6 +352,9 @@ impl PBSParameters {
                 panic!("PBSParameters::PBS does not have an LweBskGroupingFactor")
             }
             Self::MultiBitPBS(params) => params.grouping_factor,
+            Self::KeySwitch32PBS(_) => {
+                panic!("PBSParameters::KeySwitch32PBS does not have an LweBskGroupingFactor")
+            }
         }
     }
 
@@ -307,10 +366,15 @@ impl PBSParameters {
         matches!(self, Self::MultiBitPBS(_))
     }
 
+    pub const fn is_key_switch_32_pbs(&self) -> bool {
+        matches!(self, Self::KeySwitch32PBS(_))
+    }
+
     pub fn to_shortint_conformance_param(&self) -> CiphertextConformanceParams {
         match self {
             Self::PBS(param) => param.to_shortint_conformance_param(),
             Self::MultiBitPBS(param) => param.to_shortint_conformance_param(),
+            Self::KeySwitch32PBS(param) => param.to_shortint_conformance_param(),
         }
     }
 }
@@ -497,6 +561,14 @@ impl ShortintParameterSet {
         }
     }
 
+    pub const fn secret_key_distribution(&self) -> SecretKeyDistribution {
+        match self.inner {
+            ShortintParameterSetInner::PBSOnly(params)
+            | ShortintParameterSetInner::PBSAndWopbs(params, _) => params.secret_key_distribution(),
+            ShortintParameterSetInner::WopbsOnly(_) => SecretKeyDistribution::Binary,
+        }
+    }
+
     pub const fn pbs_only(&self) -> bool {
         self.inner.is_pbs_only()
     }
@@ -609,6 +681,7 @@ pub const PARAM_MESSAGE_1_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(684),
@@ -624,6 +697,7 @@ pub const PARAM_MESSAGE_1_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(656),
@@ -639,6 +713,7 @@ pub const PARAM_MESSAGE_2_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(742),
@@ -654,6 +729,7 @@ pub const PARAM_MESSAGE_1_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(742),
@@ -669,6 +745,7 @@ pub const PARAM_MESSAGE_2_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(742),
@@ -684,6 +761,7 @@ pub const PARAM_MESSAGE_3_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(745),
@@ -699,6 +777,7 @@ pub const PARAM_MESSAGE_1_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(742),
@@ -714,6 +793,7 @@ pub const PARAM_MESSAGE_2_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(742),
@@ -729,6 +809,7 @@ pub const PARAM_MESSAGE_3_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(742),
@@ -744,6 +825,7 @@ pub const PARAM_MESSAGE_4_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(807),
@@ -759,6 +841,7 @@ pub const PARAM_MESSAGE_1_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(856),
@@ -774,6 +857,7 @@ pub const PARAM_MESSAGE_2_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(812),
@@ -789,6 +873,7 @@ pub const PARAM_MESSAGE_3_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(808),
@@ -804,6 +889,7 @@ pub const PARAM_MESSAGE_4_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(807),
@@ -819,6 +905,7 @@ pub const PARAM_MESSAGE_5_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(864),
@@ -834,6 +921,7 @@ pub const PARAM_MESSAGE_1_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(32),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(864),
@@ -849,6 +937,7 @@ pub const PARAM_MESSAGE_2_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(864),
@@ -864,6 +953,7 @@ pub const PARAM_MESSAGE_3_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(864),
@@ -879,6 +969,7 @@ pub const PARAM_MESSAGE_4_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(875),
@@ -894,6 +985,7 @@ pub const PARAM_MESSAGE_5_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_6_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(915),
@@ -909,6 +1001,7 @@ pub const PARAM_MESSAGE_6_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_6_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(930),
@@ -924,6 +1017,7 @@ pub const PARAM_MESSAGE_1_CARRY_6_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(64),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(934),
@@ -939,6 +1033,7 @@ pub const PARAM_MESSAGE_2_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(32),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(930),
@@ -954,6 +1049,7 @@ pub const PARAM_MESSAGE_3_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(930),
@@ -969,6 +1065,7 @@ pub const PARAM_MESSAGE_4_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(930),
@@ -984,6 +1081,7 @@ pub const PARAM_MESSAGE_5_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_6_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(930),
@@ -999,6 +1097,7 @@ pub const PARAM_MESSAGE_6_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_7_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(930),
@@ -1014,6 +1113,7 @@ pub const PARAM_MESSAGE_7_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_1_CARRY_7_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(1004),
@@ -1029,6 +1129,7 @@ pub const PARAM_MESSAGE_1_CARRY_7_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(128),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_2_CARRY_6_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(987),
@@ -1044,6 +1145,7 @@ pub const PARAM_MESSAGE_2_CARRY_6_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(64),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_3_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(985),
@@ -1059,6 +1161,7 @@ pub const PARAM_MESSAGE_3_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(32),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_4_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(996),
@@ -1074,6 +1177,7 @@ pub const PARAM_MESSAGE_4_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_5_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(1020),
@@ -1089,6 +1193,7 @@ pub const PARAM_MESSAGE_5_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_6_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(1018),
@@ -1104,6 +1209,7 @@ pub const PARAM_MESSAGE_6_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_7_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(1017),
@@ -1119,6 +1225,7 @@ pub const PARAM_MESSAGE_7_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 pub const PARAM_MESSAGE_8_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
     lwe_dimension: LweDimension(1017),
@@ -1134,6 +1241,7 @@ pub const PARAM_MESSAGE_8_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(1),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Big,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAM_MESSAGE_1_CARRY_1_PBS_KS: ClassicPBSParameters = ClassicPBSParameters {
@@ -1150,6 +1258,7 @@ pub const PARAM_MESSAGE_1_CARRY_1_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(2),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAM_MESSAGE_2_CARRY_2_PBS_KS: ClassicPBSParameters = ClassicPBSParameters {
@@ -1166,6 +1275,7 @@ pub const PARAM_MESSAGE_2_CARRY_2_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(4),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAM_MESSAGE_3_CARRY_3_PBS_KS: ClassicPBSParameters = ClassicPBSParameters {
@@ -1182,6 +1292,7 @@ pub const PARAM_MESSAGE_3_CARRY_3_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(8),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 pub const PARAM_MESSAGE_4_CARRY_4_PBS_KS: ClassicPBSParameters = ClassicPBSParameters {
@@ -1198,6 +1309,7 @@ pub const PARAM_MESSAGE_4_CARRY_4_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     carry_modulus: CarryModulus(16),
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
+    secret_key_distribution: SecretKeyDistribution::Binary,
 };
 
 /// Return a parameter set from a message and carry moduli.
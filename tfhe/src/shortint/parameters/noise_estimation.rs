@@ -0,0 +1,242 @@
+//! Estimation of the noise of shortint ciphertexts, based on the
+//! [`noise formulas`](`crate::core_crypto::commons::noise_formulas`) of the core crypto module.
+//!
+//! The [`NoiseLevel`] of a ciphertext tracks how many ciphertexts with a nominal noise have been
+//! combined to obtain it, it is turned into a variance by the functions of this module. This makes
+//! it possible to check that a custom set of parameters yields correct computations before using
+//! it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::ciphertext::NoiseLevel;
+//! use tfhe::shortint::parameters::{PBSParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+//!
+//! let params = PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+//!
+//! // A bootstrap applied on a ciphertext with a nominal noise level is correct with an
+//! // overwhelming probability
+//! assert!(params.pbs_failure_probability(NoiseLevel::NOMINAL) < 2.0f64.powi(-40));
+//!
+//! // Adding many ciphertexts together makes the failure probability grow
+//! assert!(params.pbs_failure_probability(NoiseLevel::NOMINAL * 100) > 2.0f64.powi(-30));
+//! ```
+
+use super::{ClassicPBSParameters, EncryptionKeyChoice, KeySwitch32PBSParameters, PBSParameters};
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::noise_formulas::{
+    encryption_variance, failure_probability, keyswitch_additive_variance,
+    multi_bit_programmable_bootstrap_output_variance, pbs_modulus_switch_additive_variance,
+    programmable_bootstrap_output_variance,
+};
+use crate::shortint::ciphertext::NoiseLevel;
+
+impl PBSParameters {
+    fn raw_ciphertext_modulus(&self) -> f64 {
+        self.ciphertext_modulus().raw_modulus_float()
+    }
+
+    /// Return the variance of a ciphertext freshly encrypted with the client key.
+    pub fn encryption_variance(&self) -> Variance {
+        match self.encryption_key_choice() {
+            EncryptionKeyChoice::Big => encryption_variance(self.glwe_modular_std_dev()),
+            EncryptionKeyChoice::Small => encryption_variance(self.lwe_modular_std_dev()),
+        }
+    }
+
+    /// Return the variance added by the keyswitch from the big LWE key to the small LWE key.
+    pub fn keyswitch_additive_variance(&self) -> Variance {
+        keyswitch_additive_variance(
+            self.glwe_dimension()
+                .to_equivalent_lwe_dimension(self.polynomial_size()),
+            self.secret_key_distribution(),
+            self.ks_base_log(),
+            self.ks_level(),
+            self.lwe_modular_std_dev(),
+            self.raw_ciphertext_modulus(),
+        )
+    }
+
+    /// Return the variance added by the modulus switch done at the beginning of a bootstrap.
+    pub fn pbs_modulus_switch_additive_variance(&self) -> Variance {
+        pbs_modulus_switch_additive_variance(
+            self.lwe_dimension(),
+            self.secret_key_distribution(),
+            self.polynomial_size(),
+            self.raw_ciphertext_modulus(),
+        )
+    }
+
+    /// Return the variance of the output of a bootstrap, before any keyswitch.
+    ///
+    /// The error of the FFT is not taken into account, see the
+    /// [`noise formulas`](`crate::core_crypto::commons::noise_formulas`) documentation.
+    pub fn pbs_output_variance(&self) -> Variance {
+        match self {
+            Self::PBS(ClassicPBSParameters {
+                lwe_dimension,
+                glwe_dimension,
+                polynomial_size,
+                pbs_base_log,
+                pbs_level,
+                glwe_modular_std_dev,
+                ..
+            })
+            | Self::KeySwitch32PBS(KeySwitch32PBSParameters {
+                lwe_dimension,
+                glwe_dimension,
+                polynomial_size,
+                pbs_base_log,
+                pbs_level,
+                glwe_modular_std_dev,
+                ..
+            }) => programmable_bootstrap_output_variance(
+                *lwe_dimension,
+                self.secret_key_distribution(),
+                *glwe_dimension,
+                self.secret_key_distribution(),
+                *polynomial_size,
+                *pbs_base_log,
+                *pbs_level,
+                *glwe_modular_std_dev,
+                self.raw_ciphertext_modulus(),
+            ),
+            Self::MultiBitPBS(params) => multi_bit_programmable_bootstrap_output_variance(
+                params.lwe_dimension,
+                params.glwe_dimension,
+                self.secret_key_distribution(),
+                params.polynomial_size,
+                params.pbs_base_log,
+                params.pbs_level,
+                params.grouping_factor,
+                params.glwe_modular_std_dev,
+                self.raw_ciphertext_modulus(),
+            ),
+        }
+    }
+
+    /// Return the maximum variance of a ciphertext with a [`NoiseLevel::NOMINAL`] noise level,
+    /// i.e. a fresh ciphertext or the output of a bootstrap.
+    pub fn nominal_variance(&self) -> Variance {
+        let pbs_output_variance = match self.encryption_key_choice() {
+            EncryptionKeyChoice::Big => self.pbs_output_variance(),
+            // The bootstrap is followed by a keyswitch
+            EncryptionKeyChoice::Small => {
+                Variance(self.pbs_output_variance().0 + self.keyswitch_additive_variance().0)
+            }
+        };
+
+        Variance(pbs_output_variance.0.max(self.encryption_variance().0))
+    }
+
+    /// Return an upper bound of the variance of a ciphertext with the given [`NoiseLevel`].
+    ///
+    /// The noise level is an upper bound of the sum of the absolute values of the coefficients of
+    /// the linear combination of nominal ciphertexts used to obtain the ciphertext, so its square
+    /// bounds the sum of the squares of these coefficients.
+    pub fn variance_upper_bound(&self, noise_level: NoiseLevel) -> Variance {
+        let multiplier = noise_level.get() as f64;
+
+        Variance(multiplier * multiplier * self.nominal_variance().0)
+    }
+
+    /// Return an upper bound of the variance of a ciphertext with the given [`NoiseLevel`] after
+    /// the keyswitch (for the [`EncryptionKeyChoice::Big`] order) and the modulus switch done
+    /// before the blind rotation of a bootstrap.
+    pub fn pbs_input_variance_upper_bound(&self, noise_level: NoiseLevel) -> Variance {
+        let keyswitch_variance = match self.encryption_key_choice() {
+            EncryptionKeyChoice::Big => self.keyswitch_additive_variance().0,
+            EncryptionKeyChoice::Small => 0.0,
+        };
+
+        Variance(
+            self.variance_upper_bound(noise_level).0
+                + keyswitch_variance
+                + self.pbs_modulus_switch_additive_variance().0,
+        )
+    }
+
+    /// Return an estimation of the probability that a bootstrap applied to a ciphertext with the
+    /// given [`NoiseLevel`] outputs an incorrect result.
+    ///
+    /// The bootstrap fails when the noise exceeds half of the scaling factor of the message,
+    /// taking into account the padding bit.
+    pub fn pbs_failure_probability(&self, noise_level: NoiseLevel) -> f64 {
+        let message_space_size = (self.message_modulus().0 * self.carry_modulus().0) as f64;
+        // Half of the scaling factor 1 / (2 * message_space_size) on the torus
+        let decoding_bound = 1.0 / (4.0 * message_space_size);
+
+        failure_probability(
+            self.pbs_input_variance_upper_bound(noise_level),
+            decoding_bound,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::commons::parameters::SecretKeyDistribution;
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+        PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    };
+
+    #[test]
+    fn test_pbs_failure_probability_ci_run_filter() {
+        for params in [
+            PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+            PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_PBS_KS),
+            PBSParameters::MultiBitPBS(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS),
+        ] {
+            assert!(params.nominal_variance().0 >= params.encryption_variance().0);
+            assert_eq!(params.variance_upper_bound(NoiseLevel::ZERO).0, 0.0);
+
+            let nominal_probability = params.pbs_failure_probability(NoiseLevel::NOMINAL);
+            assert!(
+                nominal_probability < 2.0f64.powi(-40),
+                "{params:?}: {nominal_probability}"
+            );
+
+            let mut previous_probability = nominal_probability;
+            for level in 2..20 {
+                let probability = params.pbs_failure_probability(NoiseLevel::NOMINAL * level);
+                assert!(probability >= previous_probability);
+                previous_probability = probability;
+            }
+
+            assert!(params.pbs_failure_probability(NoiseLevel::MAX) > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_non_binary_secret_key_noise_estimation_ci_run_filter() {
+        let binary_params = PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        for secret_key_distribution in [
+            SecretKeyDistribution::Ternary,
+            SecretKeyDistribution::Gaussian { std_dev: 1.0 },
+        ] {
+            let params = PBSParameters::PBS(ClassicPBSParameters {
+                secret_key_distribution,
+                ..PARAM_MESSAGE_2_CARRY_2_KS_PBS
+            });
+
+            // Non binary keys have a larger square mean and are bootstrapped through their
+            // binary expansion, all the noise terms depending on the keys must grow
+            assert!(
+                params.keyswitch_additive_variance().0
+                    > binary_params.keyswitch_additive_variance().0
+            );
+            assert!(
+                params.pbs_modulus_switch_additive_variance().0
+                    > binary_params.pbs_modulus_switch_additive_variance().0
+            );
+            assert!(params.pbs_output_variance().0 > binary_params.pbs_output_variance().0);
+            assert!(
+                params.pbs_failure_probability(NoiseLevel::NOMINAL)
+                    > binary_params.pbs_failure_probability(NoiseLevel::NOMINAL)
+            );
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::shortint::parameters::{
+    CarryModulus, CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
+    GlweDimension, LweDimension, MessageModulus, PolynomialSize, StandardDev,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Determines in what ring the computations of the 128 bits mode are made.
+pub type CiphertextModulus128 = CoreCiphertextModulus<u128>;
+
+/// A set of cryptographic parameters for the 128 bits mode of shortint, see
+/// [`pbs128`](`crate::shortint::pbs128`).
+///
+/// Ciphertexts are encrypted under the big LWE key derived from the GLWE key, lookup tables are
+/// evaluated with a keyswitch to the small LWE key followed by a PBS back to the big key, as with
+/// the [`Big`](`super::EncryptionKeyChoice::Big`) encryption key choice of
+/// [`ClassicPBSParameters`](`super::ClassicPBSParameters`).
+///
+/// The standard deviations are given relative to the $2^{128}$ modulus.
+#[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
+pub struct PBS128Parameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub lwe_modular_std_dev: StandardDev,
+    pub glwe_modular_std_dev: StandardDev,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub ciphertext_modulus: CiphertextModulus128,
+}
+
+/// 128 bits parameters with 2 bits of message and 2 bits of carry per block.
+///
+/// The dimensions and relative noise levels are the ones of
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](`super::PARAM_MESSAGE_2_CARRY_2_KS_PBS`), these parameters do
+/// not make use of the additional precision and are mostly meant to compare both modes.
+pub const PARAM_PBS128_MESSAGE_2_CARRY_2: PBS128Parameters = PBS128Parameters {
+    lwe_dimension: LweDimension(742),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
+    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(3),
+    ks_level: DecompositionLevelCount(5),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus128::new_native(),
+};
+
+/// 128 bits parameters with 3 bits of message and 3 bits of carry per block.
+///
+/// The GLWE noise has a standard deviation of $2^{-110}$, far below what 64 bits integers can
+/// represent, which lets the PBS use a single decomposition level where
+/// [`PARAM_MESSAGE_3_CARRY_3_KS_PBS`](`super::PARAM_MESSAGE_3_CARRY_3_KS_PBS`) needs two, and makes
+/// the noise of the PBS output negligible compared to the one of the keyswitch and modulus switch.
+pub const PARAM_PBS128_MESSAGE_3_CARRY_3: PBS128Parameters = PBS128Parameters {
+    lwe_dimension: LweDimension(864),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(8192),
+    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
+    glwe_modular_std_dev: StandardDev(0.0000000000000000000000000000000007703719777548943),
+    pbs_base_log: DecompositionBaseLog(35),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(3),
+    ks_level: DecompositionLevelCount(6),
+    message_modulus: MessageModulus(8),
+    carry_modulus: CarryModulus(8),
+    ciphertext_modulus: CiphertextModulus128::new_native(),
+};
+
+/// 128 bits parameters with 4 bits of message and 4 bits of carry per block, halving the number
+/// of blocks of radix integers compared to [`PARAM_PBS128_MESSAGE_2_CARRY_2`].
+///
+/// As for [`PARAM_PBS128_MESSAGE_3_CARRY_3`], the GLWE noise has a standard deviation of
+/// $2^{-110}$ and the PBS uses a single decomposition level, where
+/// [`PARAM_MESSAGE_4_CARRY_4_KS_PBS`](`super::PARAM_MESSAGE_4_CARRY_4_KS_PBS`) needs two.
+pub const PARAM_PBS128_MESSAGE_4_CARRY_4: PBS128Parameters = PBS128Parameters {
+    lwe_dimension: LweDimension(996),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(32768),
+    lwe_modular_std_dev: StandardDev(0.00000006767666038309478),
+    glwe_modular_std_dev: StandardDev(0.0000000000000000000000000000000007703719777548943),
+    pbs_base_log: DecompositionBaseLog(40),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(3),
+    ks_level: DecompositionLevelCount(7),
+    message_modulus: MessageModulus(16),
+    carry_modulus: CarryModulus(16),
+    ciphertext_modulus: CiphertextModulus128::new_native(),
+};
@@ -0,0 +1,279 @@
+//! Estimation of the security of shortint parameters, based on the
+//! [`security estimator`](`crate::core_crypto::commons::security`) of the core crypto module.
+//!
+//! The keys of a set of [`PBSParameters`] are protected by two independent problems: the LWE
+//! problem of the small LWE secret key, used by the keyswitching key, and the GLWE problem of the
+//! GLWE secret key, used by the bootstrapping key. The security of the parameters is the smallest
+//! of the two. Both secret keys follow the [`SecretKeyDistribution`] of the parameters.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::parameters::{
+//!     PBSParameters, StandardDev, DEFAULT_SECURITY_BITS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+//! };
+//!
+//! let params = PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+//! assert!(params.check_security(DEFAULT_SECURITY_BITS).is_ok());
+//!
+//! // Parameters with a much smaller noise are rejected
+//! let mut insecure_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//! insecure_params.lwe_modular_std_dev = StandardDev(2.0f64.powi(-40));
+//!
+//! let error = PBSParameters::PBS(insecure_params)
+//!     .check_security(DEFAULT_SECURITY_BITS)
+//!     .unwrap_err();
+//! println!("{error}");
+//! ```
+
+use super::{PBS128Parameters, PBSParameters};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::SecretKeyDistribution;
+use crate::core_crypto::commons::security::{
+    estimate_glwe_security, estimate_lwe_security, SecurityEstimate,
+};
+use std::fmt::{Display, Formatter};
+
+/// The security level, in bits, targeted by the parameters shipped with the library.
+///
+/// The estimator only gives an approximation of the cost of the attacks, parameters estimated
+/// slightly below this value by [`PBSParameters::check_security`] are accepted, see
+/// [`SECURITY_ESTIMATION_MARGIN`].
+pub const DEFAULT_SECURITY_BITS: f64 = 128.0;
+
+/// The number of bits by which the estimated security of a set of parameters may fall below the
+/// required security level, to account for the simplifications of the estimator.
+pub const SECURITY_ESTIMATION_MARGIN: f64 = 2.0;
+
+/// The secret key whose security is estimated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SecretKeyKind {
+    /// The small LWE secret key, encrypted in the keyswitching key.
+    Lwe,
+    /// The GLWE secret key, encrypted in the bootstrapping key.
+    Glwe,
+}
+
+impl Display for SecretKeyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lwe => write!(f, "LWE"),
+            Self::Glwe => write!(f, "GLWE"),
+        }
+    }
+}
+
+/// Error returned when the estimated security of a set of parameters is below the required level.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InsecureParametersError {
+    pub secret_key_kind: SecretKeyKind,
+    pub estimated_security_bits: f64,
+    pub required_security_bits: f64,
+}
+
+impl Display for InsecureParametersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The parameters of the {} secret key have an estimated security of {:.1} bits, \
+            {} bits are required",
+            self.secret_key_kind, self.estimated_security_bits, self.required_security_bits
+        )
+    }
+}
+
+impl std::error::Error for InsecureParametersError {}
+
+impl PBSParameters {
+    /// Return the estimated security of the small LWE secret key.
+    pub fn lwe_security_estimate(&self) -> SecurityEstimate {
+        match self {
+            // The keyswitching key is encrypted on u32
+            Self::KeySwitch32PBS(params) => estimate_lwe_security(
+                params.lwe_dimension,
+                params.lwe_modular_std_dev,
+                SecretKeyDistribution::Binary,
+                CiphertextModulus::<u32>::new_native(),
+            ),
+            Self::PBS(_) | Self::MultiBitPBS(_) => estimate_lwe_security(
+                self.lwe_dimension(),
+                self.lwe_modular_std_dev(),
+                self.secret_key_distribution(),
+                self.ciphertext_modulus(),
+            ),
+        }
+    }
+
+    /// Return the estimated security of the GLWE secret key.
+    pub fn glwe_security_estimate(&self) -> SecurityEstimate {
+        estimate_glwe_security(
+            self.glwe_dimension(),
+            self.polynomial_size(),
+            self.glwe_modular_std_dev(),
+            self.secret_key_distribution(),
+            self.ciphertext_modulus(),
+        )
+    }
+
+    /// Return the estimated security of the parameters in bits, i.e. the security of the weakest
+    /// of the two secret keys.
+    pub fn security_bits(&self) -> f64 {
+        self.lwe_security_estimate()
+            .security_bits()
+            .min(self.glwe_security_estimate().security_bits())
+    }
+
+    /// Check that the estimated security of both secret keys reaches `required_security_bits`,
+    /// up to [`SECURITY_ESTIMATION_MARGIN`].
+    pub fn check_security(
+        &self,
+        required_security_bits: f64,
+    ) -> Result<(), InsecureParametersError> {
+        check_estimates(
+            self.lwe_security_estimate(),
+            self.glwe_security_estimate(),
+            required_security_bits,
+        )
+    }
+}
+
+impl PBS128Parameters {
+    /// Return the estimated security of the small LWE secret key.
+    pub fn lwe_security_estimate(&self) -> SecurityEstimate {
+        estimate_lwe_security(
+            self.lwe_dimension,
+            self.lwe_modular_std_dev,
+            SecretKeyDistribution::Binary,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Return the estimated security of the GLWE secret key.
+    pub fn glwe_security_estimate(&self) -> SecurityEstimate {
+        estimate_glwe_security(
+            self.glwe_dimension,
+            self.polynomial_size,
+            self.glwe_modular_std_dev,
+            SecretKeyDistribution::Binary,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Return the estimated security of the parameters in bits, i.e. the security of the weakest
+    /// of the two secret keys.
+    pub fn security_bits(&self) -> f64 {
+        self.lwe_security_estimate()
+            .security_bits()
+            .min(self.glwe_security_estimate().security_bits())
+    }
+
+    /// Check that the estimated security of both secret keys reaches `required_security_bits`,
+    /// up to [`SECURITY_ESTIMATION_MARGIN`].
+    pub fn check_security(
+        &self,
+        required_security_bits: f64,
+    ) -> Result<(), InsecureParametersError> {
+        check_estimates(
+            self.lwe_security_estimate(),
+            self.glwe_security_estimate(),
+            required_security_bits,
+        )
+    }
+}
+
+fn check_estimates(
+    lwe_estimate: SecurityEstimate,
+    glwe_estimate: SecurityEstimate,
+    required_security_bits: f64,
+) -> Result<(), InsecureParametersError> {
+    for (secret_key_kind, estimate) in [
+        (SecretKeyKind::Lwe, lwe_estimate),
+        (SecretKeyKind::Glwe, glwe_estimate),
+    ] {
+        let estimated_security_bits = estimate.security_bits();
+
+        if estimated_security_bits + SECURITY_ESTIMATION_MARGIN < required_security_bits {
+            return Err(InsecureParametersError {
+                secret_key_kind,
+                estimated_security_bits,
+                required_security_bits,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::{
+        LweDimension, StandardDev, ALL_MULTI_BIT_PARAMETER_VEC, ALL_PARAMETER_VEC,
+        PARAM_MESSAGE_2_CARRY_2_KS32_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_PBS128_MESSAGE_2_CARRY_2, PARAM_PBS128_MESSAGE_3_CARRY_3,
+        PARAM_PBS128_MESSAGE_4_CARRY_4,
+    };
+
+    #[test]
+    fn test_shipped_parameters_security_ci_run_filter() {
+        let all_params = ALL_PARAMETER_VEC
+            .into_iter()
+            .map(PBSParameters::PBS)
+            .chain(
+                ALL_MULTI_BIT_PARAMETER_VEC
+                    .into_iter()
+                    .map(PBSParameters::MultiBitPBS),
+            )
+            .chain([PBSParameters::KeySwitch32PBS(
+                PARAM_MESSAGE_2_CARRY_2_KS32_PBS,
+            )]);
+
+        for params in all_params {
+            assert!(
+                params.check_security(DEFAULT_SECURITY_BITS).is_ok(),
+                "{params:?}: {}",
+                params.security_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn test_shipped_pbs128_parameters_security_ci_run_filter() {
+        for params in [
+            PARAM_PBS128_MESSAGE_2_CARRY_2,
+            PARAM_PBS128_MESSAGE_3_CARRY_3,
+            PARAM_PBS128_MESSAGE_4_CARRY_4,
+        ] {
+            assert!(
+                params.check_security(DEFAULT_SECURITY_BITS).is_ok(),
+                "{params:?}: {}",
+                params.security_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn test_insecure_parameters_are_rejected_ci_run_filter() {
+        let mut params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+        params.lwe_modular_std_dev = StandardDev(2.0f64.powi(-40));
+        let error = PBSParameters::PBS(params)
+            .check_security(DEFAULT_SECURITY_BITS)
+            .unwrap_err();
+        assert_eq!(error.secret_key_kind, SecretKeyKind::Lwe);
+        assert!(error.estimated_security_bits < 100.0);
+
+        let mut params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+        params.glwe_modular_std_dev = StandardDev(2.0f64.powi(-62));
+        let error = PBSParameters::PBS(params)
+            .check_security(DEFAULT_SECURITY_BITS)
+            .unwrap_err();
+        assert_eq!(error.secret_key_kind, SecretKeyKind::Glwe);
+
+        // Lowering the requirement accepts the parameters
+        let mut params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+        params.lwe_dimension = LweDimension(600);
+        let params = PBSParameters::PBS(params);
+        assert!(params.check_security(DEFAULT_SECURITY_BITS).is_err());
+        assert!(params.check_security(100.0).is_ok());
+    }
+}
@@ -0,0 +1,139 @@
+use super::Ciphertext128;
+use crate::core_crypto::prelude::{
+    allocate_and_encrypt_new_lwe_ciphertext, allocate_and_generate_new_binary_glwe_secret_key,
+    allocate_and_generate_new_binary_lwe_secret_key, decrypt_lwe_ciphertext, GlweSecretKeyOwned,
+    LweSecretKeyOwned, Plaintext,
+};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::PBS128Parameters;
+use serde::{Deserialize, Serialize};
+
+/// The client key of the 128 bits mode, which must be kept secret.
+///
+/// * `large_lwe_secret_key` - the LWE key derived from the GLWE key, used to encrypt the inputs
+///   and decrypt the outputs.
+/// * `glwe_secret_key` - the GLWE key, used as the output key of the PBS.
+/// * `small_lwe_secret_key` - the LWE key used as the output key of the keyswitch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientKey128 {
+    pub(crate) large_lwe_secret_key: LweSecretKeyOwned<u128>,
+    pub(crate) glwe_secret_key: GlweSecretKeyOwned<u128>,
+    pub(crate) small_lwe_secret_key: LweSecretKeyOwned<u128>,
+    pub parameters: PBS128Parameters,
+}
+
+impl ClientKey128 {
+    /// Generate a client key for the 128 bits mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_PBS128_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::pbs128::ClientKey128;
+    ///
+    /// let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn new(parameters: PBS128Parameters) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| engine.new_client_key_128(parameters))
+    }
+
+    /// Lock the memory holding the secret keys in RAM, preventing it from being written to the
+    /// swap, see [`ClientKey::lock_in_memory`](`crate::shortint::ClientKey::lock_in_memory`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_PBS128_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::pbs128::ClientKey128;
+    ///
+    /// let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+    ///
+    /// // Locking requires the secret-key-memory-lock feature and can be refused by the OS
+    /// let is_locked = cks.lock_in_memory();
+    /// if !cfg!(feature = "secret-key-memory-lock") {
+    ///     assert!(!is_locked);
+    /// }
+    /// ```
+    pub fn lock_in_memory(&self) -> bool {
+        // Lock all the keys even if one of them fails
+        let large_lwe_locked = self.large_lwe_secret_key.lock_in_memory();
+        let glwe_locked = self.glwe_secret_key.lock_in_memory();
+        let small_lwe_locked = self.small_lwe_secret_key.lock_in_memory();
+
+        large_lwe_locked && glwe_locked && small_lwe_locked
+    }
+
+    /// Encrypt a message, reduced modulo the message modulus of the parameters.
+    pub fn encrypt(&self, message: u64) -> Ciphertext128 {
+        let message_modulus = self.parameters.message_modulus;
+        let carry_modulus = self.parameters.carry_modulus;
+
+        let delta = (1_u128 << 127) / (message_modulus.0 * carry_modulus.0) as u128;
+        let plaintext = Plaintext((message % message_modulus.0 as u64) as u128 * delta);
+
+        let ct = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_encrypt_new_lwe_ciphertext(
+                &self.large_lwe_secret_key,
+                plaintext,
+                self.parameters.glwe_modular_std_dev,
+                self.parameters.ciphertext_modulus,
+                &mut engine.encryption_generator,
+            )
+        });
+
+        Ciphertext128 {
+            ct,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus,
+        }
+    }
+
+    /// Decrypt a ciphertext, returning the message and the carry.
+    pub fn decrypt_message_and_carry(&self, ct: &Ciphertext128) -> u64 {
+        let decrypted_u128 = decrypt_lwe_ciphertext(&self.large_lwe_secret_key, &ct.ct).0;
+
+        let delta = (1_u128 << 127) / (ct.message_modulus.0 * ct.carry_modulus.0) as u128;
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        //compute the rounding bit
+        let rounding = (decrypted_u128 & rounding_bit) << 1;
+
+        (decrypted_u128.wrapping_add(rounding) / delta) as u64
+    }
+
+    /// Decrypt a ciphertext, returning only the message.
+    pub fn decrypt(&self, ct: &Ciphertext128) -> u64 {
+        self.decrypt_message_and_carry(ct) % ct.message_modulus.0 as u64
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_client_key_128(&mut self, parameters: PBS128Parameters) -> ClientKey128 {
+        let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            parameters.lwe_dimension,
+            &mut self.secret_generator,
+        );
+
+        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+            &mut self.secret_generator,
+        );
+
+        let large_lwe_secret_key = glwe_secret_key.clone().into_lwe_secret_key();
+
+        ClientKey128 {
+            large_lwe_secret_key,
+            glwe_secret_key,
+            small_lwe_secret_key,
+            parameters,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Module with the 128 bits mode of shortint.
+//!
+//! Ciphertexts of this mode are LWE ciphertexts over $2^{128}$ and lookup tables are evaluated with
+//! a keyswitch followed by a PBS using a [`Fourier128LweBootstrapKey`], whose FFT has enough
+//! precision for 128 bits computations. The noise of the GLWE encryptions is then no longer bounded
+//! from below by the precision of 64 bits integers, which makes parameter sets with larger message
+//! and carry moduli per block feasible, see
+//! [`PBS128Parameters`](`crate::shortint::parameters::PBS128Parameters`).
+//!
+//! [`Fourier128LweBootstrapKey`]: `crate::core_crypto::prelude::Fourier128LweBootstrapKey`
+
+mod client_key;
+mod server_key;
+
+#[cfg(test)]
+mod test;
+
+pub use client_key::ClientKey128;
+pub use server_key::{LookupTable128, ServerKey128};
+
+use crate::core_crypto::entities::LweCiphertextOwned;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use serde::{Deserialize, Serialize};
+
+/// A shortint ciphertext of the 128 bits mode, encrypted under the big LWE key of a
+/// [`ClientKey128`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct Ciphertext128 {
+    pub ct: LweCiphertextOwned<u128>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+impl Ciphertext128 {
+    /// Return `true` if the carry part of the ciphertext is known to be empty.
+    pub fn carry_is_empty(&self) -> bool {
+        self.degree.0 < self.message_modulus.0
+    }
+}
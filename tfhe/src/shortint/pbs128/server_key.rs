@@ -0,0 +1,210 @@
+use super::{Ciphertext128, ClientKey128};
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_lwe_keyswitch_key, convert_standard_lwe_bootstrap_key_to_fourier_128,
+    keyswitch_lwe_ciphertext, lwe_ciphertext_add_assign,
+    par_allocate_and_generate_new_lwe_bootstrap_key, programmable_bootstrap_f128_lwe_ciphertext,
+    Fourier128LweBootstrapKey, Fourier128LweBootstrapKeyOwned, GlweCiphertext, GlweCiphertextOwned,
+    LweCiphertext, LweKeyswitchKeyOwned,
+};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::{fill_accumulator_with_moduli, ShortintEngine};
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus128, MessageModulus};
+use crate::shortint::server_key::MaxDegree;
+use serde::{Deserialize, Serialize};
+
+/// A lookup table of the 128 bits mode, see [`ServerKey128::generate_lookup_table`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct LookupTable128 {
+    pub acc: GlweCiphertextOwned<u128>,
+    pub degree: Degree,
+}
+
+/// The server key of the 128 bits mode, used to evaluate lookup tables on [`Ciphertext128`]s.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerKey128 {
+    pub key_switching_key: LweKeyswitchKeyOwned<u128>,
+    pub bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    // Size of the message buffer
+    pub message_modulus: MessageModulus,
+    // Size of the carry buffer
+    pub carry_modulus: CarryModulus,
+    // Maximum number of operations that can be done before emptying the operation buffer
+    pub max_degree: MaxDegree,
+    // Modulus use for computations on the ciphertext
+    pub ciphertext_modulus: CiphertextModulus128,
+}
+
+impl ServerKey128 {
+    /// Generate the server key associated to the given [`ClientKey128`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_PBS128_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::pbs128::{ClientKey128, ServerKey128};
+    ///
+    /// let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+    /// let sks = ServerKey128::new(&cks);
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let lut = sks.generate_lookup_table(|x| (x * x) % 4);
+    /// let ct_res = sks.apply_lookup_table(&ct, &lut);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    /// ```
+    pub fn new(cks: &ClientKey128) -> Self {
+        let params = &cks.parameters;
+
+        let (key_switching_key, standard_bootstrapping_key) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+                    &cks.large_lwe_secret_key,
+                    &cks.small_lwe_secret_key,
+                    params.ks_base_log,
+                    params.ks_level,
+                    params.lwe_modular_std_dev,
+                    params.ciphertext_modulus,
+                    &mut engine.encryption_generator,
+                );
+
+                let bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+                    &cks.small_lwe_secret_key,
+                    &cks.glwe_secret_key,
+                    params.pbs_base_log,
+                    params.pbs_level,
+                    params.glwe_modular_std_dev,
+                    params.ciphertext_modulus,
+                    &mut engine.encryption_generator,
+                );
+
+                (key_switching_key, bootstrapping_key)
+            });
+
+        let mut bootstrapping_key = Fourier128LweBootstrapKey::new(
+            standard_bootstrapping_key.input_lwe_dimension(),
+            standard_bootstrapping_key.glwe_size(),
+            standard_bootstrapping_key.polynomial_size(),
+            standard_bootstrapping_key.decomposition_base_log(),
+            standard_bootstrapping_key.decomposition_level_count(),
+        );
+
+        convert_standard_lwe_bootstrap_key_to_fourier_128(
+            &standard_bootstrapping_key,
+            &mut bootstrapping_key,
+        );
+
+        Self {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus: params.message_modulus,
+            carry_modulus: params.carry_modulus,
+            max_degree: MaxDegree(params.message_modulus.0 * params.carry_modulus.0 - 1),
+            ciphertext_modulus: params.ciphertext_modulus,
+        }
+    }
+
+    /// Construct the lookup table evaluating `f` on the message and carry bits of a ciphertext.
+    pub fn generate_lookup_table<F>(&self, f: F) -> LookupTable128
+    where
+        F: Fn(u64) -> u64,
+    {
+        let mut acc = GlweCiphertext::new(
+            0u128,
+            self.bootstrapping_key.glwe_size(),
+            self.bootstrapping_key.polynomial_size(),
+            self.ciphertext_modulus,
+        );
+        let max_value =
+            fill_accumulator_with_moduli(&mut acc, self.message_modulus, self.carry_modulus, f);
+
+        LookupTable128 {
+            acc,
+            degree: Degree(max_value as usize),
+        }
+    }
+
+    /// Evaluate a lookup table on a ciphertext, the keyswitch and the PBS reset its noise.
+    pub fn apply_lookup_table_assign(&self, ct: &mut Ciphertext128, lut: &LookupTable128) {
+        let mut ciphertext_after_ks = LweCiphertext::new(
+            0u128,
+            self.key_switching_key.output_lwe_size(),
+            self.ciphertext_modulus,
+        );
+
+        keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ciphertext_after_ks);
+
+        programmable_bootstrap_f128_lwe_ciphertext(
+            &ciphertext_after_ks,
+            &mut ct.ct,
+            &lut.acc,
+            &self.bootstrapping_key,
+        );
+
+        ct.degree = lut.degree;
+    }
+
+    /// Evaluate a lookup table on a ciphertext, see [`Self::apply_lookup_table_assign`].
+    pub fn apply_lookup_table(&self, ct: &Ciphertext128, lut: &LookupTable128) -> Ciphertext128 {
+        let mut result = ct.clone();
+        self.apply_lookup_table_assign(&mut result, lut);
+        result
+    }
+
+    /// Empty the carry part of a ciphertext, keeping only its message.
+    pub fn message_extract_assign(&self, ct: &mut Ciphertext128) {
+        let modulus = self.message_modulus.0 as u64;
+        let lut = self.generate_lookup_table(|x| x % modulus);
+        self.apply_lookup_table_assign(ct, &lut);
+    }
+
+    /// Return a ciphertext encrypting the carry of `ct` in its message part.
+    pub fn carry_extract(&self, ct: &Ciphertext128) -> Ciphertext128 {
+        let modulus = self.message_modulus.0 as u64;
+        let lut = self.generate_lookup_table(|x| x / modulus);
+        self.apply_lookup_table(ct, &lut)
+    }
+
+    /// Add `ct_right` to `ct_left` without checking that the result fits in the carry buffer.
+    pub fn unchecked_add_assign(&self, ct_left: &mut Ciphertext128, ct_right: &Ciphertext128) {
+        lwe_ciphertext_add_assign(&mut ct_left.ct, &ct_right.ct);
+        ct_left.degree = Degree(ct_left.degree.0 + ct_right.degree.0);
+    }
+
+    /// Add two ciphertexts without checking that the result fits in the carry buffer.
+    pub fn unchecked_add(
+        &self,
+        ct_left: &Ciphertext128,
+        ct_right: &Ciphertext128,
+    ) -> Ciphertext128 {
+        let mut result = ct_left.clone();
+        self.unchecked_add_assign(&mut result, ct_right);
+        result
+    }
+
+    /// Add `ct_right` to `ct_left`, the carries of the operands are first emptied if the result
+    /// would not fit in the carry buffer otherwise.
+    pub fn add_assign(&self, ct_left: &mut Ciphertext128, ct_right: &Ciphertext128) {
+        if ct_left.degree.0 + ct_right.degree.0 <= self.max_degree.0 {
+            self.unchecked_add_assign(ct_left, ct_right);
+            return;
+        }
+
+        let mut ct_right = ct_right.clone();
+        if !ct_left.carry_is_empty() {
+            self.message_extract_assign(ct_left);
+        }
+        if !ct_right.carry_is_empty() {
+            self.message_extract_assign(&mut ct_right);
+        }
+        self.unchecked_add_assign(ct_left, &ct_right);
+    }
+
+    /// Add two ciphertexts, see [`Self::add_assign`].
+    pub fn add(&self, ct_left: &Ciphertext128, ct_right: &Ciphertext128) -> Ciphertext128 {
+        let mut result = ct_left.clone();
+        self.add_assign(&mut result, ct_right);
+        result
+    }
+}
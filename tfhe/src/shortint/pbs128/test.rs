@@ -0,0 +1,82 @@
+use super::{Ciphertext128, ClientKey128, ServerKey128};
+use crate::shortint::parameters::{
+    PBS128Parameters, PARAM_PBS128_MESSAGE_2_CARRY_2, PARAM_PBS128_MESSAGE_3_CARRY_3,
+    PARAM_PBS128_MESSAGE_4_CARRY_4,
+};
+
+fn pbs128_lookup_table_and_add(params: PBS128Parameters) {
+    let cks = ClientKey128::new(params);
+    let sks = ServerKey128::new(&cks);
+
+    let modulus = params.message_modulus.0 as u64;
+
+    for msg in 0..modulus {
+        let ct = cks.encrypt(msg);
+        assert_eq!(cks.decrypt(&ct), msg);
+
+        let lut = sks.generate_lookup_table(|x| (x + 1) % modulus);
+        let ct_res = sks.apply_lookup_table(&ct, &lut);
+        assert_eq!(cks.decrypt(&ct_res), (msg + 1) % modulus);
+
+        let sum = sks.unchecked_add(&ct, &ct);
+        assert_eq!(cks.decrypt_message_and_carry(&sum), 2 * msg);
+
+        let carry = sks.carry_extract(&sum);
+        assert_eq!(cks.decrypt(&carry), (2 * msg) / modulus);
+
+        // The carries are emptied once the degree would overflow
+        let mut acc = sum;
+        for _ in 0..4 {
+            sks.add_assign(&mut acc, &ct);
+        }
+        assert!(acc.degree.0 <= sks.max_degree.0);
+        assert_eq!(cks.decrypt(&acc), (6 * msg) % modulus);
+    }
+}
+
+#[test]
+fn test_pbs128_lookup_table_and_add() {
+    pbs128_lookup_table_and_add(PARAM_PBS128_MESSAGE_2_CARRY_2);
+}
+
+#[test]
+fn test_pbs128_lookup_table_and_add_message_3_carry_3() {
+    pbs128_lookup_table_and_add(PARAM_PBS128_MESSAGE_3_CARRY_3);
+}
+
+#[test]
+fn test_pbs128_lookup_table_and_add_message_4_carry_4() {
+    pbs128_lookup_table_and_add(PARAM_PBS128_MESSAGE_4_CARRY_4);
+}
+
+#[test]
+fn test_pbs128_lock_in_memory() {
+    let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+
+    // The lock can be refused by the OS, e.g. because of RLIMIT_MEMLOCK
+    let is_locked = cks.lock_in_memory();
+    if !cfg!(feature = "secret-key-memory-lock") {
+        assert!(!is_locked);
+    }
+
+    let ct = cks.encrypt(3);
+    assert_eq!(cks.decrypt(&ct), 3);
+}
+
+#[test]
+fn test_pbs128_serialization() {
+    let cks = ClientKey128::new(PARAM_PBS128_MESSAGE_2_CARRY_2);
+    let sks = ServerKey128::new(&cks);
+
+    let serialized_sks = bincode::serialize(&sks).unwrap();
+    let deserialized_sks: ServerKey128 = bincode::deserialize(&serialized_sks).unwrap();
+    assert_eq!(deserialized_sks, sks);
+
+    let ct = cks.encrypt(2);
+    let deserialized_ct: Ciphertext128 =
+        bincode::deserialize(&bincode::serialize(&ct).unwrap()).unwrap();
+    assert_eq!(deserialized_ct, ct);
+
+    let ct_res = deserialized_sks.carry_extract(&deserialized_sks.unchecked_add(&ct, &ct));
+    assert_eq!(cks.decrypt(&ct_res), 1);
+}
@@ -8,7 +8,10 @@ use crate::core_crypto::prelude::{
     LweCompactPublicKeyOwned, Plaintext, PlaintextList, SeededLweCompactPublicKeyOwned,
 };
 
-use crate::core_crypto::prelude::encrypt_lwe_ciphertext_with_compact_public_key;
+use crate::core_crypto::prelude::{
+    encrypt_lwe_ciphertext_with_compact_public_key,
+    rerandomize_lwe_ciphertext_with_compact_public_key,
+};
 
 use crate::shortint::ciphertext::{CompactCiphertextList, Degree, NoiseLevel};
 use crate::shortint::{Ciphertext, ClientKey, PBSOrder, ShortintParameterSet};
@@ -22,7 +25,7 @@ pub struct CompactPublicKey {
     pub pbs_order: PBSOrder,
 }
 
-fn to_plaintext_iterator(
+pub(crate) fn to_plaintext_iterator(
     message_iter: impl Iterator<Item = u64>,
     parameters: &ShortintParameterSet,
 ) -> impl Iterator<Item = Plaintext<u64>> {
@@ -189,6 +192,54 @@ impl CompactPublicKey {
         }
     }
 
+    /// Re-randomize a ciphertext by adding a fresh encryption of zero to it.
+    ///
+    /// The mask of the re-randomized ciphertext no longer depends on the computations that
+    /// produced the input ciphertext, its noise level grows by [`NoiseLevel::NOMINAL`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::CompactPublicKey;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let ct1 = cks.encrypt(1);
+    /// let ct2 = cks.encrypt(2);
+    /// let mut ct_res = sks.add(&ct1, &ct2);
+    ///
+    /// pk.rerandomize_assign(&mut ct_res);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn rerandomize_assign(&self, ct: &mut Ciphertext) {
+        assert_eq!(
+            ct.pbs_order, self.pbs_order,
+            "The ciphertext and the public key must be encrypted under the same key"
+        );
+
+        let encryption_noise = match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => self.parameters.glwe_modular_std_dev(),
+            PBSOrder::BootstrapKeyswitch => self.parameters.lwe_modular_std_dev(),
+        };
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            rerandomize_lwe_ciphertext_with_compact_public_key(
+                &self.key,
+                &mut ct.ct,
+                encryption_noise,
+                encryption_noise,
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            );
+        });
+
+        ct.set_noise_level(ct.noise_level() + NoiseLevel::NOMINAL);
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
@@ -1,6 +1,7 @@
 //! Module with the definition of the PublicKey.
+use crate::core_crypto::algorithms::*;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::Ciphertext;
+use crate::shortint::ciphertext::{Ciphertext, NoiseLevel};
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{MessageModulus, ShortintParameterSet};
 use crate::shortint::{ClientKey, CompressedPublicKey, PBSOrder};
@@ -189,6 +190,46 @@ impl PublicKey {
             engine.encrypt_native_crt_with_public_key(self, message, message_modulus)
         })
     }
+
+    /// Re-randomize a ciphertext by adding a fresh encryption of zero to it.
+    ///
+    /// The mask of the re-randomized ciphertext no longer depends on the computations that
+    /// produced the input ciphertext, its noise level grows by [`NoiseLevel::NOMINAL`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::PublicKey;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// let ct1 = cks.encrypt(1);
+    /// let ct2 = cks.encrypt(2);
+    /// let mut ct_res = sks.add(&ct1, &ct2);
+    ///
+    /// pk.rerandomize_assign(&mut ct_res);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn rerandomize_assign(&self, ct: &mut Ciphertext) {
+        assert_eq!(
+            ct.pbs_order, self.pbs_order,
+            "The ciphertext and the public key must be encrypted under the same key"
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            rerandomize_lwe_ciphertext_with_public_key(
+                &self.lwe_public_key,
+                &mut ct.ct,
+                &mut engine.secret_generator,
+            );
+        });
+
+        ct.set_noise_level(ct.noise_level() + NoiseLevel::NOMINAL);
+    }
 }
 
 impl From<CompressedPublicKey> for PublicKey {
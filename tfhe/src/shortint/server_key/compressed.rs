@@ -1,6 +1,7 @@
 //! Module with the definition of the CompressedServerKey.
 
 use super::MaxDegree;
+use crate::core_crypto::commons::math::random::Seed;
 use crate::core_crypto::prelude::*;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
@@ -42,7 +43,11 @@ impl ShortintCompressedBootstrappingKey {
 /// sends it to the server so it can compute homomorphic circuits.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompressedServerKey {
-    pub key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    // Only an empty placeholder when key_switching_key_32 is present, use the
+    // key_switching_key accessor to read it
+    pub(crate) key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    // Keyswitching key to u32 ciphertexts, see ServerKey::key_switching_key_32
+    pub key_switching_key_32: Option<SeededLweKeyswitchKeyOwned<u32>>,
     pub bootstrapping_key: ShortintCompressedBootstrappingKey,
     // Size of the message buffer
     pub message_modulus: MessageModulus,
@@ -79,4 +84,38 @@ impl CompressedServerKey {
             engine.new_compressed_server_key_with_max_degree(cks, max_degree)
         })
     }
+
+    /// Return the keyswitching key of the compressed server key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key was generated from
+    /// [`KeySwitch32PBSParameters`](`crate::shortint::parameters::KeySwitch32PBSParameters`),
+    /// see [`ServerKey::key_switching_key`](`super::ServerKey::key_switching_key`).
+    pub fn key_switching_key(&self) -> &SeededLweKeyswitchKeyOwned<u64> {
+        assert!(
+            self.key_switching_key_32.is_none(),
+            "The keyswitching key of this server key outputs u32 ciphertexts, \
+            use the key_switching_key_32 field instead"
+        );
+
+        &self.key_switching_key
+    }
+}
+
+/// The compressed counterpart of [`key_switch_32_placeholder`](`super::key_switch_32_placeholder`).
+pub(crate) fn seeded_key_switch_32_placeholder(
+    input_key_lwe_dimension: LweDimension,
+    decomp_base_log: DecompositionBaseLog,
+    ciphertext_modulus: CiphertextModulus,
+) -> SeededLweKeyswitchKeyOwned<u64> {
+    SeededLweKeyswitchKey::new(
+        0,
+        decomp_base_log,
+        DecompositionLevelCount(1),
+        input_key_lwe_dimension,
+        LweDimension(0),
+        Seed(0).into(),
+        ciphertext_modulus,
+    )
 }
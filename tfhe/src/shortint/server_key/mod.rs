@@ -22,13 +22,13 @@ pub use compressed::{CompressedServerKey, ShortintCompressedBootstrappingKey};
 mod tests;
 
 use super::ciphertext::NoiseLevel;
-use super::engine::fill_accumulator;
+use super::engine::{fill_accumulator, fill_many_lut_accumulator};
 use super::parameters::CiphertextConformanceParams;
 use super::PBSOrder;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
-    ThreadCount,
+    DecompositionBaseLog, DecompositionLevelCount, GlweCiphertextCount, GlweSize,
+    LweCiphertextCount, LweDimension, MonomialDegree, PolynomialSize, ThreadCount,
 };
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -36,6 +36,7 @@ use crate::shortint::ciphertext::{Ciphertext, Degree};
 use crate::shortint::client_key::ClientKey;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display, Formatter};
 
@@ -243,7 +244,12 @@ impl ShortintBootstrappingKey {
 /// sends it to the server so it can compute homomorphic circuits.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServerKey {
-    pub key_switching_key: LweKeyswitchKeyOwned<u64>,
+    // Only an empty placeholder when key_switching_key_32 is present, use the
+    // key_switching_key accessor to read it
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+    // Keyswitching key to u32 ciphertexts, only present for keys generated from
+    // KeySwitch32PBSParameters
+    pub key_switching_key_32: Option<LweKeyswitchKeyOwned<u32>>,
     pub bootstrapping_key: ShortintBootstrappingKey,
     // Size of the message buffer
     pub message_modulus: MessageModulus,
@@ -256,7 +262,47 @@ pub struct ServerKey {
     pub pbs_order: PBSOrder,
 }
 
+/// A [`ServerKey`] which is generated while it is serialized, see
+/// [`ServerKey::new_into_serializer`].
+pub(crate) struct ServerKeyStream<'a> {
+    pub(crate) cks: &'a ClientKey,
+    pub(crate) max_degree: MaxDegree,
+}
+
+impl<'a> Serialize for ServerKeyStream<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_server_key_with_max_degree_into_serializer(
+                self.cks,
+                self.max_degree,
+                serializer,
+            )
+        })
+    }
+}
+
 impl ServerKey {
+    /// Return the keyswitching key of the server key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key was generated from
+    /// [`KeySwitch32PBSParameters`](`crate::shortint::parameters::KeySwitch32PBSParameters`),
+    /// its keyswitching key outputs `u32` ciphertexts and is stored in
+    /// [`ServerKey::key_switching_key_32`].
+    pub fn key_switching_key(&self) -> &LweKeyswitchKeyOwned<u64> {
+        assert!(
+            self.key_switching_key_32.is_none(),
+            "The keyswitching key of this server key outputs u32 ciphertexts, \
+            use the key_switching_key_32 field instead"
+        );
+
+        &self.key_switching_key
+    }
+
     pub fn conformance_params(&self) -> CiphertextConformanceParams {
         let lwe_dim = match self.pbs_order {
             PBSOrder::KeyswitchBootstrap => self.key_switching_key.input_key_lwe_dimension(),
@@ -279,6 +325,24 @@ impl ServerKey {
     }
 }
 
+/// Return the keyswitching key stored in the `key_switching_key` field of a [`ServerKey`] when the
+/// keyswitch outputs `u32` ciphertexts: it keeps the input dimension of the keyswitch but has an
+/// output dimension of 0, it is never exposed outside of the crate.
+pub(crate) fn key_switch_32_placeholder(
+    input_key_lwe_dimension: LweDimension,
+    decomp_base_log: DecompositionBaseLog,
+    ciphertext_modulus: CiphertextModulus,
+) -> LweKeyswitchKeyOwned<u64> {
+    LweKeyswitchKey::new(
+        0,
+        decomp_base_log,
+        DecompositionLevelCount(1),
+        input_key_lwe_dimension,
+        LweDimension(0),
+        ciphertext_modulus,
+    )
+}
+
 /// Returns whether it is possible to pack lhs and rhs into a unique
 /// ciphertext without exceeding the max storable value using the formula:
 /// `unique_ciphertext = (lhs * factor) + rhs`
@@ -302,6 +366,32 @@ pub type LookupTableOwned = LookupTable<Vec<u64>>;
 pub type LookupTableMutView<'a> = LookupTable<&'a mut [u64]>;
 pub type LookupTableView<'a> = LookupTable<&'a [u64]>;
 
+/// A lookup table packing several functions in a single accumulator, so that they are all evaluated
+/// by a single programmable bootstrap, see [`ServerKey::apply_many_lookup_tables`].
+///
+/// The functions can only be applied to ciphertexts with a degree of at most `input_max_degree`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct ManyLookupTable<C: Container<Element = u64>> {
+    pub acc: GlweCiphertext<C>,
+    // Degree of the output of each function
+    pub per_function_output_degree: Vec<Degree>,
+    pub input_max_degree: Degree,
+    // Distance between two consecutive functions in the accumulator
+    pub sample_extraction_stride: MonomialDegree,
+}
+
+pub type ManyLookupTableOwned = ManyLookupTable<Vec<u64>>;
+pub type ManyLookupTableMutView<'a> = ManyLookupTable<&'a mut [u64]>;
+pub type ManyLookupTableView<'a> = ManyLookupTable<&'a [u64]>;
+
+impl<C: Container<Element = u64>> ManyLookupTable<C> {
+    /// Return the number of functions packed in the lookup table.
+    pub fn function_count(&self) -> usize {
+        self.per_function_output_degree.len()
+    }
+}
+
 #[must_use]
 pub struct BivariateLookupTable<C: Container<Element = u64>> {
     // A bivariate lookup table is an univariate loolookup table
@@ -348,6 +438,77 @@ impl ServerKey {
         })
     }
 
+    /// Generate a server key and serialize it into `serializer` while it is being generated.
+    ///
+    /// The keyswitching and bootstrapping keys are produced one ciphertext at a time and
+    /// serialized as soon as they are ready, so that the key is never entirely held in memory.
+    /// Deserializing the output gives a [`ServerKey`] generated in the same way as
+    /// [`ServerKey::new`].
+    ///
+    /// The serializer must go through the data only once, see
+    /// [`streaming_key_generation`](`crate::core_crypto::algorithms::streaming_key_generation`).
+    pub fn new_into_serializer<S: serde::Serializer>(
+        cks: &ClientKey,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // Plaintext Max Value
+        let max_value = cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0 - 1;
+
+        Self::new_with_max_degree_into_serializer(cks, MaxDegree(max_value), serializer)
+    }
+
+    /// Generate a server key with a chosen maximum degree and serialize it into `serializer` while
+    /// it is being generated, see [`ServerKey::new_into_serializer`].
+    pub fn new_with_max_degree_into_serializer<S: serde::Serializer>(
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ServerKeyStream { cks, max_degree }.serialize(serializer)
+    }
+
+    /// Generate a server key and write it to `writer` while it is being generated.
+    ///
+    /// The output is the same as the one of `bincode::serialize_into` called on a [`ServerKey`],
+    /// see [`ServerKey::new_into_serializer`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, ServerKey};
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // Generate the server key, any std::io::Write can be used, e.g. a File:
+    /// let mut serialized_sks = vec![];
+    /// ServerKey::new_into_writer(&cks, &mut serialized_sks).unwrap();
+    ///
+    /// let sks: ServerKey = bincode::deserialize(&serialized_sks).unwrap();
+    ///
+    /// let msg1 = 1;
+    /// let msg2 = 2;
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.add(&ct1, &ct2);
+    ///
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    /// assert_eq!(cks.decrypt(&ct_res), (msg1 + msg2) % modulus);
+    /// ```
+    #[cfg(feature = "safe-deserialization")]
+    pub fn new_into_writer(cks: &ClientKey, writer: impl std::io::Write) -> bincode::Result<()> {
+        use bincode::Options;
+
+        let mut serializer = bincode::Serializer::new(
+            writer,
+            bincode::DefaultOptions::new().with_fixint_encoding(),
+        );
+
+        Self::new_into_serializer(cks, &mut serializer)
+    }
+
     /// Constructs the lookup table given a function as input.
     ///
     /// # Example
@@ -530,6 +691,19 @@ impl ServerKey {
         })
     }
 
+    /// Pack the inputs of a bivariate lookup table in `ct_left`, so that `acc.acc` can then be
+    /// applied to it like a univariate lookup table, e.g. in a batch of bootstraps.
+    pub(crate) fn unchecked_pack_bivariate_lookup_table_inputs_assign(
+        &self,
+        ct_left: &mut Ciphertext,
+        ct_right: &Ciphertext,
+        acc: &BivariateLookupTableOwned,
+    ) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.pack_bivariate_lookup_table_inputs_assign(ct_left, ct_right, acc);
+        });
+    }
+
     /// Compute a keyswitch and programmable bootstrap.
     ///
     /// # Example
@@ -609,6 +783,265 @@ impl ServerKey {
         })
     }
 
+    /// Compute a programmable bootstrap on each ciphertext of `cts`, the i-th ciphertext being
+    /// bootstrapped with the i-th lookup table of `accs`.
+    ///
+    /// This computes the same ciphertexts as calling [`Self::apply_lookup_table`] on each pair,
+    /// but the bootstraps are split among the threads of the current rayon thread pool, each
+    /// thread reusing its computation buffers for all its ciphertexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` and `accs` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    /// let msgs = [1, 2, 3];
+    /// let cts = msgs.map(|msg| cks.encrypt(msg));
+    ///
+    /// let double = sks.generate_lookup_table(|x| (2 * x) % modulus);
+    /// let square = sks.generate_lookup_table(|x| (x * x) % modulus);
+    ///
+    /// let cts_res = sks.apply_lookup_table_batch(
+    ///     &[&cts[0], &cts[1], &cts[2]],
+    ///     &[&double, &square, &double],
+    /// );
+    ///
+    /// assert_eq!(cks.decrypt(&cts_res[0]), 2);
+    /// assert_eq!(cks.decrypt(&cts_res[1]), 0);
+    /// assert_eq!(cks.decrypt(&cts_res[2]), 2);
+    /// ```
+    pub fn apply_lookup_table_batch(
+        &self,
+        cts: &[&Ciphertext],
+        accs: &[&LookupTableOwned],
+    ) -> Vec<Ciphertext> {
+        assert_eq!(
+            cts.len(),
+            accs.len(),
+            "Mismatched number of ciphertexts ({}) and lookup tables ({})",
+            cts.len(),
+            accs.len()
+        );
+
+        let fourier_bsk = match (&self.bootstrapping_key, &self.key_switching_key_32) {
+            (ShortintBootstrappingKey::Classic(fourier_bsk), None) => fourier_bsk,
+            // The multi bit bootstrap already splits each bootstrap among several threads, the
+            // bootstraps of u32 ciphertexts are not batched
+            (ShortintBootstrappingKey::MultiBit { .. }, _) | (_, Some(_)) => {
+                return cts
+                    .par_iter()
+                    .zip(accs.par_iter())
+                    .map(|(ct, acc)| self.apply_lookup_table(ct, acc))
+                    .collect();
+            }
+        };
+
+        if cts.is_empty() {
+            return Vec::new();
+        }
+
+        let ciphertext_count = LweCiphertextCount(cts.len());
+
+        let mut accumulators = GlweCiphertextList::new(
+            0,
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            GlweCiphertextCount(cts.len()),
+            self.ciphertext_modulus,
+        );
+        for (mut accumulator, acc) in accumulators.iter_mut().zip(accs.iter()) {
+            accumulator.as_mut().copy_from_slice(acc.acc.as_ref());
+        }
+
+        // For non binary secret keys the bootstrapping key encrypts the binary expansion of the
+        // small LWE secret key, its input dimension is larger than the one of the PBS inputs
+        let mut pbs_inputs = LweCiphertextList::new(
+            0,
+            self.key_switching_key.output_lwe_size(),
+            ciphertext_count,
+            self.ciphertext_modulus,
+        );
+        match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => {
+                pbs_inputs
+                    .par_iter_mut()
+                    .zip(cts.par_iter())
+                    .for_each(|(mut pbs_input, ct)| {
+                        keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut pbs_input);
+                    })
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                for (mut pbs_input, ct) in pbs_inputs.iter_mut().zip(cts.iter()) {
+                    pbs_input.as_mut().copy_from_slice(ct.ct.as_ref());
+                }
+            }
+        }
+
+        let mut pbs_outputs = LweCiphertextList::new(
+            0,
+            fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            ciphertext_count,
+            self.ciphertext_modulus,
+        );
+        par_programmable_bootstrap_lwe_ciphertext_list(
+            &pbs_inputs,
+            &mut pbs_outputs,
+            &accumulators,
+            fourier_bsk,
+        );
+
+        pbs_outputs
+            .par_iter()
+            .zip(cts.par_iter())
+            .zip(accs.par_iter())
+            .map(|((pbs_output, ct), acc)| {
+                let output = match self.pbs_order {
+                    PBSOrder::KeyswitchBootstrap => LweCiphertext::from_container(
+                        pbs_output.as_ref().to_vec(),
+                        pbs_output.ciphertext_modulus(),
+                    ),
+                    PBSOrder::BootstrapKeyswitch => {
+                        let mut output = LweCiphertext::new(
+                            0,
+                            self.key_switching_key
+                                .output_key_lwe_dimension()
+                                .to_lwe_size(),
+                            self.ciphertext_modulus,
+                        );
+                        keyswitch_lwe_ciphertext(&self.key_switching_key, &pbs_output, &mut output);
+                        output
+                    }
+                };
+
+                Ciphertext::new(
+                    output,
+                    acc.degree,
+                    NoiseLevel::NOMINAL,
+                    ct.message_modulus,
+                    ct.carry_modulus,
+                    ct.pbs_order,
+                )
+            })
+            .collect()
+    }
+
+    /// Constructs a lookup table packing several functions, which are evaluated at once by
+    /// [`Self::apply_many_lookup_tables`].
+    ///
+    /// The input space of the ciphertexts, including the carry bits, is split in as many parts as
+    /// there are functions: the functions can only be evaluated on inputs smaller than
+    /// `message_modulus * carry_modulus / functions.len()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `functions` is empty or contains more functions than there are possible inputs.
+    ///
+    /// Panics if the server key keyswitches to `u32` ciphertexts, see
+    /// [`KeySwitch32PBSParameters`](`super::parameters::KeySwitch32PBSParameters`), as evaluating
+    /// several lookup tables is not supported in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Generate a lookup table for both the square and the cube of the message modulo 4
+    /// let square = |x: u64| x.pow(2) % 4;
+    /// let cube = |x: u64| x.pow(3) % 4;
+    /// let acc = sks.generate_many_lookup_table(&[&square, &cube]);
+    /// let cts_res = sks.apply_many_lookup_tables(&ct, &acc);
+    ///
+    /// assert_eq!(cts_res.len(), 2);
+    /// assert_eq!(cks.decrypt(&cts_res[0]), square(msg));
+    /// assert_eq!(cks.decrypt(&cts_res[1]), cube(msg));
+    /// ```
+    pub fn generate_many_lookup_table(
+        &self,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> ManyLookupTableOwned {
+        assert!(
+            self.key_switching_key_32.is_none(),
+            "Many lookup tables are not supported with a keyswitch to u32 ciphertexts"
+        );
+
+        let mut acc = GlweCiphertext::new(
+            0,
+            self.bootstrapping_key.glwe_size(),
+            self.bootstrapping_key.polynomial_size(),
+            self.ciphertext_modulus,
+        );
+        let max_values = fill_many_lut_accumulator(
+            &mut acc,
+            self.message_modulus,
+            self.carry_modulus,
+            functions,
+        );
+
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let chunk_size = modulus_sup / functions.len();
+        let box_size = self.bootstrapping_key.polynomial_size().0 / modulus_sup;
+
+        ManyLookupTableOwned {
+            acc,
+            per_function_output_degree: max_values
+                .into_iter()
+                .map(|max_value| Degree(max_value as usize))
+                .collect(),
+            input_max_degree: Degree(chunk_size - 1),
+            sample_extraction_stride: MonomialDegree(chunk_size * box_size),
+        }
+    }
+
+    /// Apply all the functions packed in a [`ManyLookupTable`] to a ciphertext, using a single
+    /// programmable bootstrap.
+    ///
+    /// The i-th returned ciphertext holds the result of the i-th function, see
+    /// [`Self::generate_many_lookup_table`] for an example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree of `ct_in` exceeds the `input_max_degree` of the lookup table.
+    ///
+    /// Panics if the server key keyswitches to `u32` ciphertexts.
+    pub fn apply_many_lookup_tables(
+        &self,
+        ct_in: &Ciphertext,
+        acc: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        assert!(
+            self.key_switching_key_32.is_none(),
+            "Many lookup tables are not supported with a keyswitch to u32 ciphertexts"
+        );
+        assert!(
+            ct_in.degree.0 <= acc.input_max_degree.0,
+            "The degree of the input ciphertext ({}) exceeds the maximum degree supported by the \
+            many lookup table ({})",
+            ct_in.degree.0,
+            acc.input_max_degree.0
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_many_lookup_tables(self, ct_in, acc)
+        })
+    }
+
     /// Generic programmable bootstrap where messages are concatenated into one ciphertext to
     /// evaluate a bivariate function. This is used to apply many binary operations (comparisons,
     /// multiplications, division).
@@ -818,6 +1251,55 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.message_extract(self, ct))
     }
 
+    /// Bootstrap the ciphertext with the identity function, replacing its noise with the one of a
+    /// freshly bootstrapped ciphertext.
+    ///
+    /// Combined with a re-randomization of the input, e.g. with the `rerandomize_assign` method of
+    /// a [`CompactPublicKey`](`crate::shortint::CompactPublicKey`), the output has a fresh mask and
+    /// a nominal noise level whatever the computations that produced the input. Both the message
+    /// and carry bits are kept.
+    ///
+    /// This does not provide circuit privacy: no noise flooding is applied, so the noise of the
+    /// output still depends on the noise of the input, through the rotation of the bootstrap, and
+    /// on the server key. The output is not statistically unlinkable to the input.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::CompactPublicKey;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let ct1 = cks.encrypt(3);
+    /// let ct2 = cks.encrypt(2);
+    /// let mut ct_res = sks.unchecked_add(&ct1, &ct2);
+    ///
+    /// pk.rerandomize_assign(&mut ct_res);
+    /// sks.sanitizing_bootstrap_assign(&mut ct_res);
+    ///
+    /// // The carry is kept
+    /// let res = cks.decrypt_message_and_carry(&ct_res);
+    /// assert_eq!(5, res);
+    /// ```
+    pub fn sanitizing_bootstrap_assign(&self, ct: &mut Ciphertext) {
+        let degree = ct.degree;
+        let identity = self.generate_lookup_table(|x| x);
+        self.apply_lookup_table_assign(ct, &identity);
+        // The identity does not change the encrypted value
+        ct.degree = degree;
+    }
+
+    /// Return a sanitized copy of the ciphertext, see [`Self::sanitizing_bootstrap_assign`].
+    pub fn sanitizing_bootstrap(&self, ct: &Ciphertext) -> Ciphertext {
+        let mut result = ct.clone();
+        self.sanitizing_bootstrap_assign(&mut result);
+        result
+    }
+
     /// Compute a trivial shortint ciphertext with the dimension of the big LWE secret key from a
     /// given value.
     ///
@@ -859,11 +1341,17 @@ impl ServerKey {
     }
 
     pub fn key_switching_key_size_elements(&self) -> usize {
-        self.key_switching_key.as_ref().len()
+        self.key_switching_key_32.as_ref().map_or_else(
+            || self.key_switching_key.as_ref().len(),
+            |ksk| ksk.as_ref().len(),
+        )
     }
 
     pub fn key_switching_key_size_bytes(&self) -> usize {
-        std::mem::size_of_val(self.key_switching_key.as_ref())
+        self.key_switching_key_32.as_ref().map_or_else(
+            || std::mem::size_of_val(self.key_switching_key.as_ref()),
+            |ksk| std::mem::size_of_val(ksk.as_ref()),
+        )
     }
 
     pub fn deterministic_pbs_execution(&self) -> bool {
@@ -880,6 +1368,7 @@ impl From<CompressedServerKey> for ServerKey {
     fn from(compressed_server_key: CompressedServerKey) -> Self {
         let CompressedServerKey {
             key_switching_key,
+            key_switching_key_32,
             bootstrapping_key,
             message_modulus,
             carry_modulus,
@@ -888,8 +1377,22 @@ impl From<CompressedServerKey> for ServerKey {
             pbs_order,
         } = compressed_server_key;
 
-        let (key_switching_key, bootstrapping_key) = rayon::join(
-            || key_switching_key.par_decompress_into_lwe_keyswitch_key(),
+        let ((key_switching_key, key_switching_key_32), bootstrapping_key) = rayon::join(
+            || match key_switching_key_32 {
+                // The placeholder has no mask to decompress
+                Some(key_switching_key_32) => (
+                    key_switch_32_placeholder(
+                        key_switching_key.input_key_lwe_dimension(),
+                        key_switching_key.decomposition_base_log(),
+                        key_switching_key.ciphertext_modulus(),
+                    ),
+                    Some(key_switching_key_32.par_decompress_into_lwe_keyswitch_key()),
+                ),
+                None => (
+                    key_switching_key.par_decompress_into_lwe_keyswitch_key(),
+                    None,
+                ),
+            },
             || match bootstrapping_key {
                 ShortintCompressedBootstrappingKey::Classic(bootstrapping_key) => {
                     let standard_bootstrapping_key =
@@ -953,6 +1456,7 @@ impl From<CompressedServerKey> for ServerKey {
 
         Self {
             key_switching_key,
+            key_switching_key_32,
             bootstrapping_key,
             message_modulus,
             carry_modulus,
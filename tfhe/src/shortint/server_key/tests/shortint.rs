@@ -1,5 +1,6 @@
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
+use crate::shortint::ServerKey;
 use paste::paste;
 use rand::Rng;
 
@@ -176,6 +177,8 @@ create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
 create_parametrized_test!(shortint_generate_lookup_table);
+create_parametrized_test!(shortint_apply_many_lookup_tables);
+create_parametrized_test!(shortint_apply_lookup_table_batch);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
 create_parametrized_test!(shortint_default_add);
@@ -233,6 +236,184 @@ fn test_shortint_public_key_smart_add_param_message_2_carry_2_ks_pbs() {
     shortint_public_key_smart_add(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
 }
 
+#[test]
+fn test_shortint_server_key_into_serializer_param_message_2_carry_2_ks_pbs() {
+    shortint_server_key_into_serializer(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+}
+
+#[test]
+fn test_shortint_server_key_into_serializer_param_multi_bit_message_2_carry_2_group_2_ks_pbs() {
+    shortint_server_key_into_serializer(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS)
+}
+
+#[test]
+fn test_shortint_keyswitch_programmable_bootstrap_param_message_2_carry_2_ks32_pbs() {
+    shortint_keyswitch_programmable_bootstrap(PARAM_MESSAGE_2_CARRY_2_KS32_PBS)
+}
+
+#[test]
+fn test_shortint_server_key_into_serializer_param_message_2_carry_2_ks32_pbs() {
+    shortint_server_key_into_serializer(PARAM_MESSAGE_2_CARRY_2_KS32_PBS)
+}
+
+#[test]
+fn test_shortint_apply_lookup_table_batch_param_message_2_carry_2_ks32_pbs() {
+    shortint_apply_lookup_table_batch(PARAM_MESSAGE_2_CARRY_2_KS32_PBS)
+}
+
+#[test]
+fn test_shortint_compressed_server_key_param_message_2_carry_2_ks32_pbs() {
+    use crate::shortint::CompressedServerKey;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS32_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let compressed_sks = CompressedServerKey::new(cks);
+    let decompressed_sks = ServerKey::from(compressed_sks);
+
+    // The keyswitching key stores u32 elements, halving its size compared to the u64 one
+    assert_eq!(
+        sks.key_switching_key_size_bytes(),
+        sks.key_switching_key_size_elements() * std::mem::size_of::<u32>()
+    );
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = decompressed_sks.add(&ctxt_0, &ctxt_1);
+
+        assert_eq!((clear_0 + clear_1) % modulus, cks.decrypt(&ct_res));
+    }
+}
+
+#[test]
+#[should_panic(expected = "use the key_switching_key_32 field instead")]
+fn test_shortint_key_switching_key_accessor_param_message_2_carry_2_ks32_pbs() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS32_PBS);
+    let sks = keys.server_key();
+
+    assert!(sks.key_switching_key_32.is_some());
+    let _ = sks.key_switching_key();
+}
+
+#[test]
+#[should_panic(expected = "Many lookup tables are not supported with a keyswitch to u32")]
+fn test_shortint_many_lookup_table_param_message_2_carry_2_ks32_pbs() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS32_PBS);
+    let sks = keys.server_key();
+
+    let _ = sks.generate_many_lookup_table(&[&|x| x, &|x| x + 1]);
+}
+
+fn shortint_non_binary_secret_key_apply_lookup_table(
+    param: ClassicPBSParameters,
+    secret_key_distribution: SecretKeyDistribution,
+) {
+    use crate::shortint::{gen_keys, CompressedServerKey};
+    use bincode::Options;
+
+    let param = ClassicPBSParameters {
+        secret_key_distribution,
+        ..param
+    };
+    let (cks, sks) = gen_keys(param);
+    let decompressed_sks = ServerKey::from(CompressedServerKey::new(&cks));
+    let streamed_sks: ServerKey = {
+        let mut serialized_sks = vec![];
+        ServerKey::new_into_serializer(
+            &cks,
+            &mut bincode::Serializer::new(
+                &mut serialized_sks,
+                bincode::DefaultOptions::new().with_fixint_encoding(),
+            ),
+        )
+        .unwrap();
+        bincode::deserialize(&serialized_sks).unwrap()
+    };
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let f = |x: u64| (3 * x + 1) % modulus;
+    let lut = sks.generate_lookup_table(f);
+
+    for _ in 0..NB_TEST_SMART {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.apply_lookup_table(&ctxt, &lut);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+
+        let ct_res = decompressed_sks.apply_lookup_table(&ctxt, &lut);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+
+        let ct_res = streamed_sks.apply_lookup_table(&ctxt, &lut);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+
+        let trivial = sks.create_trivial(clear);
+        let ct_res = sks.apply_lookup_table(&trivial, &lut);
+        assert_eq!(cks.decrypt(&ct_res), f(clear));
+    }
+
+    let clears = (0..NB_TEST_SMART)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let cts = clears
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+    let ct_refs = cts.iter().collect::<Vec<_>>();
+    let lut_refs = vec![&lut; cts.len()];
+
+    let cts_res = sks.apply_lookup_table_batch(&ct_refs, &lut_refs);
+    for (ct_res, clear) in cts_res.iter().zip(clears.iter()) {
+        assert_eq!(cks.decrypt(ct_res), f(*clear));
+    }
+}
+
+#[test]
+fn test_shortint_ternary_secret_key_apply_lookup_table_param_message_2_carry_2_ks_pbs() {
+    shortint_non_binary_secret_key_apply_lookup_table(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        SecretKeyDistribution::Ternary,
+    );
+}
+
+#[test]
+fn test_shortint_ternary_secret_key_apply_lookup_table_param_message_2_carry_2_pbs_ks() {
+    shortint_non_binary_secret_key_apply_lookup_table(
+        PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+        SecretKeyDistribution::Ternary,
+    );
+}
+
+#[test]
+fn test_shortint_gaussian_secret_key_apply_lookup_table_param_message_2_carry_2_ks_pbs() {
+    shortint_non_binary_secret_key_apply_lookup_table(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        SecretKeyDistribution::Gaussian { std_dev: 1.0 },
+    );
+}
+
+#[test]
+fn test_shortint_parameters_without_secret_key_distribution_deserialize_to_binary() {
+    let mut serialized = serde_json::to_value(PARAM_MESSAGE_2_CARRY_2_KS_PBS).unwrap();
+    serialized
+        .as_object_mut()
+        .unwrap()
+        .remove("secret_key_distribution")
+        .unwrap();
+
+    let deserialized: ClassicPBSParameters = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+}
+
 //These functions are compatible with some parameter sets where the carry modulus is larger than
 // the message modulus.
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_bitand);
@@ -426,6 +607,57 @@ where
     }
 }
 
+fn shortint_server_key_into_serializer<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use bincode::Options;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let mut serialized_sks = vec![];
+    ServerKey::new_into_serializer(
+        cks,
+        &mut bincode::Serializer::new(
+            &mut serialized_sks,
+            bincode::DefaultOptions::new().with_fixint_encoding(),
+        ),
+    )
+    .unwrap();
+
+    let streamed_sks: ServerKey = bincode::deserialize(&serialized_sks).unwrap();
+
+    // The streamed key only differs from the cached one by its randomness
+    assert_eq!(
+        serialized_sks.len(),
+        bincode::serialized_size(sks).unwrap() as usize
+    );
+    assert_eq!(streamed_sks.message_modulus, sks.message_modulus);
+    assert_eq!(streamed_sks.carry_modulus, sks.carry_modulus);
+    assert_eq!(streamed_sks.max_degree, sks.max_degree);
+    assert_eq!(streamed_sks.pbs_order, sks.pbs_order);
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // The bivariate lookup table goes through both the keyswitch and the bootstrap
+        let ct_res = streamed_sks.unchecked_bitxor(&ctxt_0, &ctxt_1);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(clear_0 ^ clear_1, dec_res);
+    }
+}
+
 fn shortint_keyswitch_bivariate_programmable_bootstrap<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -556,6 +788,101 @@ where
     }
 }
 
+/// test the evaluation of several functions with a single bootstrap
+fn shortint_apply_many_lookup_tables<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let modulus_sup = modulus * cks.parameters.carry_modulus().0 as u64;
+
+    let message = |x: u64| x % modulus;
+    let carry = |x: u64| x / modulus;
+    let is_zero = |x: u64| u64::from(x == 0);
+    let all_functions: [&dyn Fn(u64) -> u64; 3] = [&message, &carry, &is_zero];
+
+    // There may be less than 3 possible inputs
+    let functions = &all_functions[..all_functions.len().min(modulus_sup as usize)];
+    let acc = sks.generate_many_lookup_table(functions);
+
+    let input_modulus = modulus_sup / functions.len() as u64;
+    assert_eq!(acc.input_max_degree.0 as u64, input_modulus - 1);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % input_modulus;
+
+        // unchecked encryption to be able to use the carry bits
+        let mut ct = cks.unchecked_encrypt(clear);
+        ct.degree = acc.input_max_degree;
+
+        let cts_res = sks.apply_many_lookup_tables(&ct, &acc);
+        assert_eq!(cts_res.len(), functions.len());
+
+        for ((ct_res, f), degree) in cts_res
+            .iter()
+            .zip(functions.iter())
+            .zip(acc.per_function_output_degree.iter())
+        {
+            assert_eq!(ct_res.degree, *degree);
+            assert_eq!(cks.decrypt_message_and_carry(ct_res), f(clear));
+        }
+    }
+}
+
+#[test]
+fn test_shortint_apply_many_lookup_tables_param_message_2_carry_2_pbs_ks() {
+    shortint_apply_many_lookup_tables(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+}
+
+/// test the batched evaluation of lookup tables on several ciphertexts
+fn shortint_apply_lookup_table_batch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let functions: [&dyn Fn(u64) -> u64; 2] = [&|x| (2 * x) % modulus, &|x| (x * x) % modulus];
+    let luts = functions.map(|f| sks.generate_lookup_table(f));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let clears = (0..NB_TEST)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let cts = clears
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    let ct_refs = cts.iter().collect::<Vec<_>>();
+    let lut_refs = (0..cts.len()).map(|i| &luts[i % 2]).collect::<Vec<_>>();
+
+    let cts_res = sks.apply_lookup_table_batch(&ct_refs, &lut_refs);
+    assert_eq!(cts_res.len(), cts.len());
+
+    for (i, (ct_res, clear)) in cts_res.iter().zip(clears.iter()).enumerate() {
+        assert_eq!(ct_res.degree, luts[i % 2].degree);
+        assert_eq!(cks.decrypt(ct_res), functions[i % 2](*clear));
+    }
+
+    assert!(sks.apply_lookup_table_batch(&[], &[]).is_empty());
+}
+
+#[test]
+fn test_shortint_apply_lookup_table_batch_param_message_2_carry_2_pbs_ks() {
+    shortint_apply_lookup_table_batch(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+}
+
 /// test addition with the LWE server key
 fn shortint_unchecked_add<P>(param: P)
 where
@@ -1,3 +1,4 @@
+use crate::shortint::ciphertext::NoiseLevel;
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::parameters_compact_pk::*;
 use crate::shortint::parameters::*;
@@ -219,3 +220,41 @@ fn shortint_compact_public_key_base_list_smart_sub(params: ClassicPBSParameters)
         }
     }
 }
+
+create_parametrized_test!(shortint_compact_public_key_rerandomize_and_sanitize);
+
+fn shortint_compact_public_key_rerandomize_and_sanitize(params: ClassicPBSParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let clear = clear_0 + clear_1;
+
+        let mut rerandomized = ct_res.clone();
+        pk.rerandomize_assign(&mut rerandomized);
+        assert_ne!(rerandomized.ct, ct_res.ct);
+        assert_eq!(rerandomized.degree, ct_res.degree);
+        assert_eq!(
+            rerandomized.noise_level(),
+            ct_res.noise_level() + NoiseLevel::NOMINAL
+        );
+        assert_eq!(cks.decrypt_message_and_carry(&rerandomized), clear);
+
+        sks.sanitizing_bootstrap_assign(&mut rerandomized);
+        assert_eq!(rerandomized.degree, ct_res.degree);
+        assert_eq!(rerandomized.noise_level(), NoiseLevel::NOMINAL);
+        assert_eq!(cks.decrypt_message_and_carry(&rerandomized), clear);
+    }
+}
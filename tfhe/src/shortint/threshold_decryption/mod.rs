@@ -0,0 +1,199 @@
+//! Module with the threshold decryption of shortint ciphertexts.
+//!
+//! A [`ClientKey`] is split in [`ClientKeyShare`]s handed to several parties. Each party computes
+//! a [`PartialDecryption`] of a [`Ciphertext`] with its share, flooding it with noise so that it
+//! leaks nothing about the share, and the cleartext is recovered by combining enough partial
+//! decryptions with [`combine_partial_decryptions`].
+//!
+//! The flooding noise must be at least $2^{\lambda}$ times larger than a bound on the noise of the
+//! decrypted ciphertext to hide it up to a statistical distance of $2^{-\lambda}$, see
+//! [`lwe_threshold_decryption`](`crate::core_crypto::algorithms::lwe_threshold_decryption`). The
+//! parameters must therefore leave about $\lambda$ bits between the noise of the ciphertexts and
+//! the encoded messages, which rules out ciphertexts with a large noise, e.g. coming out of a
+//! PBS with the default parameters.
+
+#[cfg(test)]
+mod test;
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::Ciphertext;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{
+    SecretSharingScheme, ShortintParameterSet, StandardDev, ThresholdPartyIndex,
+};
+use crate::shortint::{ClientKey, PBSOrder};
+use serde::{Deserialize, Serialize};
+
+/// The share of a [`ClientKey`] held by one party of a threshold decryption.
+///
+/// It can only be used to compute [`PartialDecryption`]s, it is not possible to encrypt with it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientKeyShare {
+    /// The share of the LWE secret key equivalent to the GLWE secret key
+    pub(crate) large_lwe_secret_key_share: LweSecretKeyShareOwned<u64>,
+    /// The share of the key used as the output of the keyswitch operation
+    pub(crate) small_lwe_secret_key_share: LweSecretKeyShareOwned<u64>,
+    pub parameters: ShortintParameterSet,
+}
+
+/// The contribution of one party to the threshold decryption of a [`Ciphertext`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialDecryption(pub(crate) LwePartialDecryption<u64>);
+
+impl PartialDecryption {
+    /// Return the index of the party which computed the [`PartialDecryption`].
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.0.party_index()
+    }
+}
+
+impl ClientKey {
+    /// Split the client key in shares according to the given [`SecretSharingScheme`].
+    ///
+    /// The i-th returned share belongs to the party with index i + 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     SecretSharingScheme, StandardDev, ThresholdPartyCount, PARAM_MESSAGE_2_CARRY_3_KS_PBS,
+    /// };
+    /// use tfhe::shortint::threshold_decryption::combine_partial_decryptions;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let params = PARAM_MESSAGE_2_CARRY_3_KS_PBS;
+    /// let cks = ClientKey::new(params);
+    ///
+    /// let shares = cks.share(SecretSharingScheme::Additive {
+    ///     party_count: ThresholdPartyCount(3),
+    /// });
+    ///
+    /// let msg = 2;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // A fresh encryption under the large key has a noise bounded by 8 standard deviations of the
+    /// // GLWE noise except with a probability below 2^-49, flood it with a noise 2^40 times larger
+    /// // for the partial decryptions to be statistically hiding
+    /// let statistical_security = 40;
+    /// let input_noise_bound = 8.0 * params.glwe_modular_std_dev.0;
+    /// let flooding_noise = StandardDev(2.0f64.powi(statistical_security) * input_noise_bound);
+    /// let partial_decryptions: Vec<_> = shares
+    ///     .iter()
+    ///     .map(|share| share.partial_decrypt(&ct, flooding_noise))
+    ///     .collect();
+    ///
+    /// let dec = combine_partial_decryptions(&ct, &partial_decryptions);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn share(&self, sharing_scheme: SecretSharingScheme) -> Vec<ClientKeyShare> {
+        let ciphertext_modulus = self.parameters.ciphertext_modulus();
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let large_shares = allocate_and_share_lwe_secret_key(
+                &self.large_lwe_secret_key,
+                sharing_scheme,
+                ciphertext_modulus,
+                &mut engine.secret_generator,
+            );
+            let small_shares = allocate_and_share_lwe_secret_key(
+                &self.small_lwe_secret_key,
+                sharing_scheme,
+                ciphertext_modulus,
+                &mut engine.secret_generator,
+            );
+
+            large_shares
+                .into_iter()
+                .zip(small_shares)
+                .map(
+                    |(large_lwe_secret_key_share, small_lwe_secret_key_share)| ClientKeyShare {
+                        large_lwe_secret_key_share,
+                        small_lwe_secret_key_share,
+                        parameters: self.parameters,
+                    },
+                )
+                .collect()
+        })
+    }
+}
+
+impl ClientKeyShare {
+    /// Return the index of the party holding the share.
+    pub fn party_index(&self) -> ThresholdPartyIndex {
+        self.large_lwe_secret_key_share.party_index()
+    }
+
+    /// Return the [`SecretSharingScheme`] used to generate the share.
+    pub fn sharing_scheme(&self) -> SecretSharingScheme {
+        self.large_lwe_secret_key_share.sharing_scheme()
+    }
+
+    /// Compute the partial decryption of a ciphertext with the share.
+    ///
+    /// The partial decryption is flooded with a fresh Gaussian noise of standard deviation
+    /// `flooding_noise`. For a statistical security of $\lambda$ bits it must be at least
+    /// $2^{\lambda}$ times larger than a bound on the noise of the ciphertext for the partial
+    /// decryption not to leak information about the share, while keeping the sum of the flooding
+    /// noises of all the combined parties small enough for the message to be decoded, see the
+    /// [`module documentation`](`self`).
+    pub fn partial_decrypt(
+        &self,
+        ct: &Ciphertext,
+        flooding_noise: StandardDev,
+    ) -> PartialDecryption {
+        let lwe_secret_key_share = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key_share,
+            PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key_share,
+        };
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            PartialDecryption(partial_decrypt_lwe_ciphertext(
+                lwe_secret_key_share,
+                &ct.ct,
+                flooding_noise,
+                &mut engine.encryption_generator,
+            ))
+        })
+    }
+}
+
+/// Combine partial decryptions of a ciphertext and return the decrypted message and carry.
+///
+/// # Panics
+///
+/// Panics if less partial decryptions than the threshold of the [`SecretSharingScheme`] are
+/// provided, or if they do not come from distinct parties.
+pub fn combine_partial_decryptions_message_and_carry(
+    ct: &Ciphertext,
+    partial_decryptions: &[PartialDecryption],
+) -> u64 {
+    let partial_decryptions: Vec<_> = partial_decryptions
+        .iter()
+        .map(|partial_decryption| partial_decryption.0)
+        .collect();
+
+    let decrypted_u64 = combine_lwe_partial_decryptions(&ct.ct, &partial_decryptions).0;
+
+    let delta = (1_u64 << 63) / (ct.message_modulus.0 * ct.carry_modulus.0) as u64;
+
+    //The bit before the message
+    let rounding_bit = delta >> 1;
+
+    //compute the rounding bit
+    let rounding = (decrypted_u64 & rounding_bit) << 1;
+
+    (decrypted_u64.wrapping_add(rounding)) / delta
+}
+
+/// Combine partial decryptions of a ciphertext and return the decrypted message.
+///
+/// See [`combine_partial_decryptions_message_and_carry`] for the requirements on the partial
+/// decryptions.
+pub fn combine_partial_decryptions(
+    ct: &Ciphertext,
+    partial_decryptions: &[PartialDecryption],
+) -> u64 {
+    combine_partial_decryptions_message_and_carry(ct, partial_decryptions)
+        % ct.message_modulus.0 as u64
+}
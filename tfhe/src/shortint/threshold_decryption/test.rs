@@ -0,0 +1,81 @@
+use super::*;
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::{
+    ClassicPBSParameters, ThresholdPartyCount, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+};
+
+const FLOODING_NOISE: StandardDev = StandardDev(0.0000000009313225746154785);
+
+fn shortint_threshold_decryption(params: ClassicPBSParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let party_count = 4;
+    let shares = cks.share(SecretSharingScheme::Additive {
+        party_count: ThresholdPartyCount(party_count),
+    });
+    assert_eq!(shares.len(), party_count);
+
+    let serialized_share = bincode::serialize(&shares[0]).unwrap();
+    let deserialized_share: ClientKeyShare = bincode::deserialize(&serialized_share).unwrap();
+    assert_eq!(deserialized_share, shares[0]);
+
+    let message_modulus = cks.parameters.message_modulus().0 as u64;
+    let lut = sks.generate_lookup_table(|x| (x + 1) % message_modulus);
+
+    for msg in 0..message_modulus {
+        let ct = cks.encrypt(msg);
+        // Check ciphertexts coming out of a PBS as well as fresh ones
+        let ct_pbs = sks.apply_lookup_table(&ct, &lut);
+
+        for (ct, expected) in [(ct, msg), (ct_pbs, (msg + 1) % message_modulus)] {
+            let partial_decryptions: Vec<_> = shares
+                .iter()
+                .map(|share| share.partial_decrypt(&ct, FLOODING_NOISE))
+                .collect();
+
+            for (partial_decryption, share) in partial_decryptions.iter().zip(shares.iter()) {
+                assert_eq!(partial_decryption.party_index(), share.party_index());
+            }
+
+            assert_eq!(
+                combine_partial_decryptions(&ct, &partial_decryptions),
+                expected
+            );
+            assert_eq!(
+                combine_partial_decryptions_message_and_carry(&ct, &partial_decryptions),
+                cks.decrypt_message_and_carry(&ct)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_shortint_threshold_decryption_ks_pbs() {
+    shortint_threshold_decryption(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+}
+
+#[test]
+fn test_shortint_threshold_decryption_pbs_ks() {
+    shortint_threshold_decryption(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+}
+
+#[test]
+#[should_panic(expected = "partial decryptions, 3 are required")]
+fn test_shortint_threshold_decryption_missing_party() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let cks = keys.client_key();
+
+    let shares = cks.share(SecretSharingScheme::Additive {
+        party_count: ThresholdPartyCount(3),
+    });
+
+    let ct = cks.encrypt(1);
+    let partial_decryptions: Vec<_> = shares[..2]
+        .iter()
+        .map(|share| share.partial_decrypt(&ct, FLOODING_NOISE))
+        .collect();
+
+    combine_partial_decryptions(&ct, &partial_decryptions);
+}
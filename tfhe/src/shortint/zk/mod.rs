@@ -0,0 +1,210 @@
+//! Module with the proven encryption of shortint compact ciphertext lists.
+//!
+//! A [`CompactPublicKey`] can encrypt messages in a [`ProvenCompactCiphertextList`], which carries
+//! a zero-knowledge proof for each of its [`CompactCiphertextList`]s. A server receiving such a
+//! list verifies the proofs before expanding the ciphertexts, see [`crate::zk`] for the guarantees
+//! given by the proofs.
+
+#[cfg(test)]
+mod test;
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::shortint::ciphertext::{CompactCiphertextList, Degree, NoiseLevel};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::public_key::compact::to_plaintext_iterator;
+use crate::shortint::{Ciphertext, CompactPublicKey, PBSOrder, ShortintParameterSet};
+use crate::zk::{CompactPkeCrs, CompactPkeProof, InvalidProofError, ZkVerificationOutCome};
+use serde::{Deserialize, Serialize};
+
+impl CompactPkeCrs {
+    /// Create the public parameters to prove the encryption of up to `max_num_message` messages
+    /// with a [`CompactPublicKey`] generated from the given parameters.
+    pub fn from_shortint_params<P>(parameters: P, max_num_message: usize) -> Self
+    where
+        P: Into<ShortintParameterSet>,
+    {
+        let parameters: ShortintParameterSet = parameters.into();
+        let lwe_dimension = match parameters.encryption_key_choice().into() {
+            PBSOrder::KeyswitchBootstrap => parameters
+                .glwe_dimension()
+                .to_equivalent_lwe_dimension(parameters.polynomial_size()),
+            PBSOrder::BootstrapKeyswitch => parameters.lwe_dimension(),
+        };
+
+        let message_modulus = parameters.message_modulus().0 as u64;
+        let carry_modulus = parameters.carry_modulus().0 as u64;
+        // Same encoding as the one used by the compact public key
+        let delta = (1u64 << 63) / (message_modulus * carry_modulus);
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new(
+                lwe_dimension,
+                LweCiphertextCount(max_num_message),
+                parameters.glwe_modular_std_dev(),
+                parameters.lwe_modular_std_dev(),
+                message_modulus,
+                delta,
+                &mut engine.seeder,
+            )
+        })
+    }
+}
+
+/// A list of compact ciphertexts, each compact list coming with the proof of its encryption.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvenCompactCiphertextList {
+    pub(crate) proved_lists: Vec<(CompactCiphertextList, CompactPkeProof)>,
+}
+
+impl ProvenCompactCiphertextList {
+    /// Return the total number of ciphertexts in the list.
+    pub fn ciphertext_count(&self) -> usize {
+        self.proved_lists
+            .iter()
+            .map(|(list, _)| list.ct_list.lwe_ciphertext_count().0)
+            .sum()
+    }
+
+    /// Verify the proofs of the list, and that its metadata is the one of a fresh encryption
+    /// under the given [`CompactPublicKey`].
+    ///
+    /// A [`Valid`](`ZkVerificationOutCome::Valid`) outcome shows that the ciphertexts encrypt
+    /// messages in `[0, message_modulus)` with a bounded noise, provided the [`CompactPkeCrs`] was
+    /// created from the parameters of the key, see [`CompactPkeCrs::from_shortint_params`].
+    pub fn verify(
+        &self,
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+    ) -> ZkVerificationOutCome {
+        let parameters = &public_key.parameters;
+        let expected_degree = Degree(parameters.message_modulus().0 - 1);
+
+        let all_valid = !self.proved_lists.is_empty()
+            && self.proved_lists.iter().all(|(list, proof)| {
+                list.degree == expected_degree
+                    && list.noise_level == NoiseLevel::NOMINAL
+                    && list.message_modulus == parameters.message_modulus()
+                    && list.carry_modulus == parameters.carry_modulus()
+                    && list.pbs_order == public_key.pbs_order
+                    && verify_lwe_compact_ciphertext_list(
+                        &list.ct_list,
+                        &public_key.key,
+                        proof,
+                        crs,
+                    )
+                    .is_valid()
+            });
+
+        if all_valid {
+            ZkVerificationOutCome::Valid
+        } else {
+            ZkVerificationOutCome::Invalid
+        }
+    }
+
+    /// Verify the list and expand it into [`Ciphertext`]s, see [`Self::verify`].
+    ///
+    /// The degree of the expanded ciphertexts is the one of a fresh encryption, which is checked
+    /// by the range proofs of the messages.
+    pub fn verify_and_expand(
+        &self,
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+    ) -> Result<Vec<Ciphertext>, InvalidProofError> {
+        if self.verify(crs, public_key).is_invalid() {
+            return Err(InvalidProofError);
+        }
+
+        Ok(self
+            .proved_lists
+            .iter()
+            .flat_map(|(list, _)| list.expand())
+            .collect())
+    }
+}
+
+impl CompactPublicKey {
+    /// Encrypt the messages in a [`ProvenCompactCiphertextList`].
+    ///
+    /// The messages are split in compact lists of at most
+    /// [`max_num_messages`](CompactPkeCrs::max_num_messages) ciphertexts, each with its own proof.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    /// use tfhe::zk::CompactPkeCrs;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    /// let crs = CompactPkeCrs::from_shortint_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+    ///
+    /// let messages = [0, 1, 2, 3, 1, 2];
+    /// let proven_list = pk.encrypt_and_prove_slice(&messages, &crs);
+    ///
+    /// let cts = proven_list.verify_and_expand(&crs, &pk).unwrap();
+    /// for (ct, &msg) in cts.iter().zip(messages.iter()) {
+    ///     assert_eq!(cks.decrypt(ct), msg);
+    /// }
+    /// ```
+    pub fn encrypt_and_prove_slice(
+        &self,
+        messages: &[u64],
+        crs: &CompactPkeCrs,
+    ) -> ProvenCompactCiphertextList {
+        assert!(!messages.is_empty(), "At least one message is required");
+
+        let proved_lists = messages
+            .chunks(crs.max_num_messages().0)
+            .map(|chunk| self.encrypt_and_prove_chunk(chunk, crs))
+            .collect();
+
+        ProvenCompactCiphertextList { proved_lists }
+    }
+
+    fn encrypt_and_prove_chunk(
+        &self,
+        messages: &[u64],
+        crs: &CompactPkeCrs,
+    ) -> (CompactCiphertextList, CompactPkeProof) {
+        let plaintext_container = to_plaintext_iterator(messages.iter().copied(), &self.parameters)
+            .map(|plaintext| plaintext.0)
+            .collect::<Vec<_>>();
+
+        let plaintext_list = PlaintextList::from_container(plaintext_container);
+        let mut ct_list = LweCompactCiphertextListOwned::new(
+            0u64,
+            self.key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(plaintext_list.plaintext_count().0),
+            self.parameters.ciphertext_modulus(),
+        );
+
+        let proof = ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+                &self.key,
+                &mut ct_list,
+                &plaintext_list,
+                self.parameters.glwe_modular_std_dev(),
+                self.parameters.lwe_modular_std_dev(),
+                crs,
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            )
+        });
+
+        let message_modulus = self.parameters.message_modulus();
+        let list = CompactCiphertextList {
+            ct_list,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus: self.parameters.carry_modulus(),
+            pbs_order: self.pbs_order,
+            noise_level: NoiseLevel::NOMINAL,
+        };
+
+        (list, proof)
+    }
+}
@@ -0,0 +1,38 @@
+use super::*;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use crate::shortint::ClientKey;
+
+#[test]
+fn shortint_proven_compact_list() {
+    let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let pk = CompactPublicKey::new(&cks);
+    let crs = CompactPkeCrs::from_shortint_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 8);
+
+    let message_modulus = cks.parameters.message_modulus().0 as u64;
+    let messages: Vec<u64> = (0..12).map(|msg| msg % message_modulus).collect();
+
+    // The list goes through serialization as it would be sent to the server
+    let proven_list: ProvenCompactCiphertextList = {
+        let serialized = bincode::serialize(&pk.encrypt_and_prove_slice(&messages, &crs)).unwrap();
+        bincode::deserialize(&serialized).unwrap()
+    };
+    assert_eq!(proven_list.proved_lists.len(), 2);
+    assert_eq!(proven_list.ciphertext_count(), messages.len());
+
+    let cts = proven_list.verify_and_expand(&crs, &pk).unwrap();
+    assert_eq!(cts.len(), messages.len());
+    for (ct, &msg) in cts.iter().zip(messages.iter()) {
+        assert_eq!(cks.decrypt(ct), msg);
+    }
+
+    // Lists with a forged degree are rejected
+    let mut forged_list = proven_list.clone();
+    forged_list.proved_lists[0].0.degree = Degree(0);
+    assert!(forged_list.verify_and_expand(&crs, &pk).is_err());
+
+    // Proofs can't be exchanged between lists
+    let mut forged_list = proven_list;
+    let (first, second) = forged_list.proved_lists.split_at_mut(1);
+    std::mem::swap(&mut first[0].1, &mut second[0].1);
+    assert!(forged_list.verify(&crs, &pk).is_invalid());
+}
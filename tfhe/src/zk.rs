@@ -0,0 +1,234 @@
+//! Experimental zero-knowledge proofs for the encryption of compact ciphertext lists.
+//!
+//! A client encrypting a [`LweCompactCiphertextList`] with a [`LweCompactPublicKey`] can attach a
+//! [`CompactPkeProof`] to it, allowing a server to check that the list is a well formed encryption
+//! of in range messages before using it.
+//!
+//! The proof shows the knowledge of a binary vector $\vec{r}$, noise vectors $\vec{e\_1}$ and
+//! $\vec{e\_2}$ and messages $\vec{m}$ such that, for each bin of the list
+//! $$\vec{a} = \vec{a}\_{pk} \circledast \vec{r} + \vec{e\_1}$$
+//! $$\vec{b} = \vec{b}\_{pk} \circledast \vec{r} + \vec{e\_2} + \Delta \vec{m}$$
+//! with $(\vec{a}\_{pk}, \vec{b}\_{pk})$ the public key and $(\vec{a}, \vec{b})$ the masks and
+//! bodies of the bin, where the coefficients of $\vec{e\_1}$ and $\vec{e\_2}$ are bounded by the
+//! noise bounds of the [`CompactPkeCrs`] and the messages are in $[0, \text{message modulus})$.
+//!
+//! The bounded values are decomposed on bits so that the whole witness is a binary vector, which is
+//! proven with a Fiat-Shamir transform of Stern's protocol. As opposed to proofs based on rejection
+//! sampling, the bounds of the witness are exact, which is required as the message range is small
+//! compared to the scaling factor $\Delta$. The downside is the size of the proofs, in the order of
+//! tens of megabytes for a few thousands of messages.
+//!
+//! # Security
+//!
+//! The noise bounds are 12 standard deviations of the noise distributions, honest encryptions
+//! resample the noise values above them. A valid proof guarantees that the decryption noise of the
+//! ciphertexts is bounded as for an honest encryption with these bounds, the server does not need
+//! to trust the client beyond the checks of the verification.
+//!
+//! The public parameters of the proof system are held in a [`CompactPkeCrs`], they do not require
+//! a trusted setup and can be generated locally by the server and sent to the clients.
+//!
+//! [`LweCompactCiphertextList`]: crate::core_crypto::entities::LweCompactCiphertextList
+//! [`LweCompactPublicKey`]: crate::core_crypto::entities::LweCompactPublicKey
+
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::math::random::Seeder;
+use crate::core_crypto::commons::parameters::{LweCiphertextCount, LweDimension};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Number of parallel repetitions of the proof, each repetition has a soundness error of 2/3, the
+/// total soundness error being below 2^-128.
+pub(crate) const PROOF_REPETITIONS: usize = 219;
+
+/// Public parameters used to prove and verify the encryption of compact ciphertext lists.
+///
+/// A CRS is tied to the [`LweDimension`] of the public key, to the encoding of the messages and to
+/// a maximum number of messages encrypted in a single proven list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactPkeCrs {
+    lwe_dimension: LweDimension,
+    max_num_messages: LweCiphertextCount,
+    mask_noise_bound: u64,
+    body_noise_bound: u64,
+    message_modulus: u64,
+    delta: u64,
+    domain_separator: [u8; 16],
+}
+
+impl CompactPkeCrs {
+    /// Create new public parameters for lists of at most `max_num_messages` ciphertexts under
+    /// public keys of dimension `lwe_dimension`.
+    ///
+    /// `mask_noise_parameters` and `body_noise_parameters` are the noise distributions used for the
+    /// mask and the body of the ciphertexts, honest noise values are bounded by 12 standard
+    /// deviations. The proven messages are in `[0, message_modulus)` and encoded as `m * delta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// use tfhe::zk::CompactPkeCrs;
+    ///
+    /// let mut seeder = new_seeder();
+    /// let crs = CompactPkeCrs::new(
+    ///     LweDimension(2048),
+    ///     LweCiphertextCount(16),
+    ///     StandardDev(0.00000000000000029403601535432533),
+    ///     StandardDev(0.00000000000000029403601535432533),
+    ///     4,
+    ///     1 << 59,
+    ///     seeder.as_mut(),
+    /// );
+    ///
+    /// assert_eq!(crs.lwe_dimension(), LweDimension(2048));
+    /// assert_eq!(crs.max_num_messages(), LweCiphertextCount(16));
+    /// assert_eq!(crs.message_modulus(), 4);
+    /// ```
+    pub fn new(
+        lwe_dimension: LweDimension,
+        max_num_messages: LweCiphertextCount,
+        mask_noise_parameters: impl DispersionParameter,
+        body_noise_parameters: impl DispersionParameter,
+        message_modulus: u64,
+        delta: u64,
+        seeder: &mut dyn Seeder,
+    ) -> Self {
+        assert!(
+            lwe_dimension.0.is_power_of_two(),
+            "The LweDimension of a compact public key must be a power of two, got {lwe_dimension:?}"
+        );
+        assert!(
+            max_num_messages.0 > 0,
+            "The CRS must allow at least one message"
+        );
+        assert!(message_modulus > 0, "The message modulus must not be zero");
+        assert!(
+            delta > 0,
+            "The scaling factor of the messages must not be zero"
+        );
+
+        let noise_bound = |modular_std_dev: f64| {
+            let bound = (12.0 * modular_std_dev).ceil();
+            assert!(
+                bound < (1u64 << 62) as f64,
+                "The noise distribution is too wide to be bounded in a proof"
+            );
+            (bound as u64).max(1)
+        };
+
+        Self {
+            lwe_dimension,
+            max_num_messages,
+            mask_noise_bound: noise_bound(
+                mask_noise_parameters.get_modular_standard_dev(u64::BITS),
+            ),
+            body_noise_bound: noise_bound(
+                body_noise_parameters.get_modular_standard_dev(u64::BITS),
+            ),
+            message_modulus,
+            delta,
+            domain_separator: seeder.seed().0.to_le_bytes(),
+        }
+    }
+
+    /// Return the [`LweDimension`] of the public keys the CRS can be used with.
+    pub fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    /// Return the maximum number of messages in a proven list.
+    pub fn max_num_messages(&self) -> LweCiphertextCount {
+        self.max_num_messages
+    }
+
+    /// Return the bound on the absolute value of the mask noise of proven encryptions.
+    pub fn mask_noise_bound(&self) -> u64 {
+        self.mask_noise_bound
+    }
+
+    /// Return the bound on the absolute value of the body noise of proven encryptions.
+    pub fn body_noise_bound(&self) -> u64 {
+        self.body_noise_bound
+    }
+
+    /// Return the modulus of the proven messages, which are in `[0, message_modulus)`.
+    pub fn message_modulus(&self) -> u64 {
+        self.message_modulus
+    }
+
+    /// Return the scaling factor of the messages in the plaintexts.
+    pub fn delta(&self) -> u64 {
+        self.delta
+    }
+
+    pub(crate) fn domain_separator(&self) -> &[u8; 16] {
+        &self.domain_separator
+    }
+}
+
+/// A non-interactive proof attached to a compact ciphertext list, see the [module
+/// documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactPkeProof {
+    pub(crate) challenge: [u8; 32],
+    pub(crate) responses: Vec<CompactPkeProofResponse>,
+}
+
+/// Response of one repetition of the proof, each variant opening two of the three commitments of
+/// the repetition and carrying the hash of the last one.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum CompactPkeProofResponse {
+    /// Permuted witness, packed in 64 bits words, and masking vector, revealed for a zero challenge
+    PermutedWitness {
+        permuted_witness: Vec<u64>,
+        masking_seed: u128,
+        salt: u128,
+        permutation_commitment: [u8; 32],
+    },
+    /// Permutation and witness masked by the masking vector, revealed for a one challenge
+    MaskedWitness {
+        permutation_seed: u128,
+        masked_witness: Vec<u64>,
+        salt: u128,
+        masking_commitment: [u8; 32],
+    },
+    /// Permutation and masking vector, revealed for a two challenge
+    Seeds {
+        permutation_seed: u128,
+        masking_seed: u128,
+        masked_witness_commitment: [u8; 32],
+    },
+}
+
+/// Outcome of the verification of a [`CompactPkeProof`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ZkVerificationOutCome {
+    Valid,
+    Invalid,
+}
+
+impl ZkVerificationOutCome {
+    pub fn is_valid(self) -> bool {
+        self == Self::Valid
+    }
+
+    pub fn is_invalid(self) -> bool {
+        self == Self::Invalid
+    }
+}
+
+/// Error returned when a proven list is expanded with an invalid proof.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct InvalidProofError;
+
+impl Display for InvalidProofError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The zero-knowledge proof attached to the list is invalid"
+        )
+    }
+}
+
+impl std::error::Error for InvalidProofError {}